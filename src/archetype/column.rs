@@ -0,0 +1,190 @@
+//! Operations on a single type-erased component column.
+//!
+//! A column is stored as the pointer and capacity of a `Vec<C>`, with its length being the length
+//! of the archetype it belongs to. These functions reconstruct the `Vec<C>` to modify it, and then
+//! store its new pointer and capacity back into the column.
+//!
+//! A `Vec` of zero-sized components never allocates, and its pointer and capacity never change.
+//! Zero-sized components are therefore special-cased here to skip reconstructing the `Vec`
+//! entirely, which reduces the overhead of marker components.
+
+use crate::component::Component;
+use alloc::vec::Vec;
+use core::mem::{
+    forget,
+    size_of,
+    ManuallyDrop,
+};
+
+/// Push `component` onto the column.
+///
+/// # Safety
+/// `component_column`, together with `length`, must define a valid `Vec<C>`.
+pub(crate) unsafe fn push<C>(component_column: &mut (*mut u8, usize), length: usize, component: C)
+where
+    C: Component,
+{
+    if size_of::<C>() == 0 {
+        // Pushing a zero-sized value only increases the length of the `Vec`, which is tracked by
+        // the archetype. The column takes ownership of the component, and will drop it when the
+        // column's values are dropped.
+        forget(component);
+        return;
+    }
+
+    let mut v = ManuallyDrop::new(
+        // SAFETY: `component_column` is guaranteed to, together with `length`, define a valid
+        // `Vec<C>`.
+        unsafe {
+            Vec::<C>::from_raw_parts(component_column.0.cast::<C>(), length, component_column.1)
+        },
+    );
+    v.push(component);
+    *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+}
+
+/// Reserve capacity for at least `additional` more components in the column.
+///
+/// # Safety
+/// `component_column`, together with `length`, must define a valid `Vec<C>`.
+pub(crate) unsafe fn reserve<C>(
+    component_column: &mut (*mut u8, usize),
+    length: usize,
+    additional: usize,
+) where
+    C: Component,
+{
+    if size_of::<C>() == 0 {
+        // A `Vec` of zero-sized values always has a capacity of `usize::MAX`.
+        return;
+    }
+
+    let mut v = ManuallyDrop::new(
+        // SAFETY: `component_column` is guaranteed to, together with `length`, define a valid
+        // `Vec<C>`.
+        unsafe {
+            Vec::<C>::from_raw_parts(component_column.0.cast::<C>(), length, component_column.1)
+        },
+    );
+    v.reserve(additional);
+    *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+}
+
+/// Reserve capacity for exactly `additional` more components in the column.
+///
+/// # Safety
+/// `component_column`, together with `length`, must define a valid `Vec<C>`.
+pub(crate) unsafe fn reserve_exact<C>(
+    component_column: &mut (*mut u8, usize),
+    length: usize,
+    additional: usize,
+) where
+    C: Component,
+{
+    if size_of::<C>() == 0 {
+        // A `Vec` of zero-sized values always has a capacity of `usize::MAX`.
+        return;
+    }
+
+    let mut v = ManuallyDrop::new(
+        // SAFETY: `component_column` is guaranteed to, together with `length`, define a valid
+        // `Vec<C>`.
+        unsafe {
+            Vec::<C>::from_raw_parts(component_column.0.cast::<C>(), length, component_column.1)
+        },
+    );
+    v.reserve_exact(additional);
+    *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        push,
+        reserve,
+        reserve_exact,
+    };
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+    use core::{
+        mem::ManuallyDrop,
+        sync::atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+    };
+
+    #[test]
+    fn push_sized() {
+        let mut v = ManuallyDrop::new(vec![1_u32, 2]);
+        let mut column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+
+        unsafe { push(&mut column, 2, 3_u32) };
+
+        let v = unsafe { Vec::from_raw_parts(column.0.cast::<u32>(), 3, column.1) };
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn push_zero_sized() {
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct Marker;
+
+        impl Drop for Marker {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let mut v = ManuallyDrop::new(Vec::<Marker>::new());
+        let mut column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+        let original_column = column;
+
+        for length in 0..1000 {
+            unsafe { push(&mut column, length, Marker) };
+        }
+
+        assert_eq!(column, original_column);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 0);
+
+        drop(unsafe { Vec::from_raw_parts(column.0.cast::<Marker>(), 1000, column.1) });
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1000);
+    }
+
+    #[test]
+    fn reserve_sized() {
+        let mut v = ManuallyDrop::new(vec![1_u32]);
+        let mut column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+
+        unsafe { reserve::<u32>(&mut column, 1, 10) };
+
+        assert!(column.1 >= 11);
+        drop(unsafe { Vec::from_raw_parts(column.0.cast::<u32>(), 1, column.1) });
+    }
+
+    #[test]
+    fn reserve_exact_sized() {
+        let mut v = ManuallyDrop::new(vec![1_u32]);
+        let mut column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+
+        unsafe { reserve_exact::<u32>(&mut column, 1, 10) };
+
+        assert_eq!(column.1, 11);
+        drop(unsafe { Vec::from_raw_parts(column.0.cast::<u32>(), 1, column.1) });
+    }
+
+    #[test]
+    fn reserve_zero_sized() {
+        let mut v = ManuallyDrop::new(Vec::<()>::new());
+        let mut column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+        let original_column = column;
+
+        unsafe { reserve::<()>(&mut column, 0, 10) };
+        unsafe { reserve_exact::<()>(&mut column, 0, 10) };
+
+        assert_eq!(column, original_column);
+    }
+}
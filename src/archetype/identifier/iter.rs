@@ -1,4 +1,7 @@
-use crate::registry::Registry;
+use crate::{
+    archetype::IdentifierRef,
+    registry::Registry,
+};
 use core::marker::PhantomData;
 
 /// An iterator over the bits of an [`Identifier`].
@@ -20,6 +23,11 @@ where
     /// [`Registry`]: crate::registry::Registry
     registry: PhantomData<R>,
 
+    /// A pointer to the start of the allocated bits.
+    ///
+    /// Unlike `pointer`, this does not move as iteration progresses, allowing the identifier being
+    /// iterated over to be recovered at any point.
+    identifier: *const u8,
     /// A pointer to the allocated bits.
     ///
     /// Note that this allocation is not owned by this struct. It is owned by an [`Identifier`] and
@@ -55,6 +63,7 @@ where
         Self {
             registry: PhantomData,
 
+            identifier: pointer,
             pointer,
 
             current: if R::LEN > 0 {
@@ -67,6 +76,20 @@ where
             position: 0,
         }
     }
+
+    /// Returns a reference to the identifier being iterated over.
+    ///
+    /// This is independent of how far iteration has progressed.
+    ///
+    /// # Safety
+    /// The caller must ensure the referenced `Identifier` outlives the returned `IdentifierRef`.
+    pub(crate) unsafe fn identifier(&self) -> IdentifierRef<R> {
+        IdentifierRef {
+            registry: PhantomData,
+
+            pointer: self.identifier,
+        }
+    }
 }
 
 impl<R> Iterator for Iter<R>
@@ -300,6 +300,13 @@ where
         unsafe { self.as_slice() }.to_vec()
     }
 
+    /// Returns a pointer to the bytes defining this identifier.
+    ///
+    /// The pointer is unique to the referenced `Identifier` for as long as it exists.
+    pub(crate) fn as_ptr(self) -> *const u8 {
+        self.pointer
+    }
+
     /// Gets the bit at the specified index without performing bounds checks.
     ///
     /// # Safety
@@ -368,6 +375,10 @@ where
 // contracts to not outlive the `Identifier` it references.
 unsafe impl<R> Send for IdentifierRef<R> where R: Registry {}
 
+// SAFETY: This type is safe to share between threads, since it only provides read access to the
+// `Identifier` it references, which is not mutated while it is referenced.
+unsafe impl<R> Sync for IdentifierRef<R> where R: Registry {}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -4,9 +4,11 @@ use crate::{
     component::Component,
     entity,
     registry,
+    registry::Registry,
 };
 use alloc::{
     string::String,
+    vec,
     vec::Vec,
 };
 use core::{
@@ -224,6 +226,362 @@ where
     }
 }
 
+/// A run of entities from a single archetype, containing only the component columns that are to
+/// be serialized.
+///
+/// Chunks are used when serializing a filtered set of components, where the entities of multiple
+/// archetypes can end up being serialized as a single archetype.
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub struct Chunk {
+    entity_identifiers: (*mut entity::Identifier, usize),
+    components: Vec<(*mut u8, usize)>,
+    length: usize,
+}
+
+impl<R> Archetype<R>
+where
+    R: Registry,
+{
+    /// Returns the identifier of this archetype with respect to `Filter`, along with a [`Chunk`]
+    /// containing only the component columns of the components contained in `Filter`.
+    ///
+    /// # Safety
+    /// The returned `Chunk` must not outlive this archetype, and this archetype must not be
+    /// modified while the `Chunk` exists.
+    pub(crate) unsafe fn filtered_chunk<Filter, Indices>(&self) -> (Vec<u8>, Chunk)
+    where
+        Filter: Registry,
+        R: registry::SerializeFiltered<Filter, Indices>,
+    {
+        let mut identifier = vec![0; (Filter::LEN + 7) / 8];
+        let mut components = Vec::new();
+        // SAFETY: `self.components` contains the same number of values as there are set bits in
+        // `self.identifier`, and `identifier` has been allocated with enough bytes for every
+        // component in `Filter`. The `R` upon which the identifier is generic is the same `R`
+        // upon which this function is called.
+        unsafe {
+            R::filter_components(
+                &self.components,
+                self.identifier.iter(),
+                &mut components,
+                &mut identifier,
+                0,
+            );
+        }
+
+        (
+            identifier,
+            Chunk {
+                entity_identifiers: self.entity_identifiers,
+                components,
+                length: self.length,
+            },
+        )
+    }
+}
+
+struct SerializeChunkedEntityIdentifiers<'a> {
+    chunks: &'a [Chunk],
+    length: usize,
+}
+
+impl Serialize for SerializeChunkedEntityIdentifiers<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(self.length)?;
+
+        for chunk in self.chunks {
+            let entity_identifiers = ManuallyDrop::new(
+                // SAFETY: `entity_identifiers` is guaranteed to contain the raw parts for a valid
+                // `Vec<entity::Identifier>` of length `length`.
+                unsafe {
+                    Vec::from_raw_parts(
+                        chunk.entity_identifiers.0,
+                        chunk.length,
+                        chunk.entity_identifiers.1,
+                    )
+                },
+            );
+            for entity_identifier in entity_identifiers.iter() {
+                tuple.serialize_element(entity_identifier)?;
+            }
+        }
+
+        tuple.end()
+    }
+}
+
+/// Serializes the same column of each chunk as a single column.
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub(crate) struct SerializeChunkedColumn<'a, C>
+where
+    C: Component + Serialize,
+{
+    component: PhantomData<C>,
+
+    chunks: &'a [Chunk],
+    column: usize,
+    length: usize,
+}
+
+impl<'a, C> SerializeChunkedColumn<'a, C>
+where
+    C: Component + Serialize,
+{
+    /// # Safety
+    /// The component column at index `column` of each chunk must be the pointer and capacity of
+    /// a `Vec<C>` of the chunk's length. `length` must be the sum of the lengths of all chunks.
+    pub(crate) unsafe fn new(chunks: &'a [Chunk], column: usize, length: usize) -> Self {
+        Self {
+            component: PhantomData,
+
+            chunks,
+            column,
+            length,
+        }
+    }
+}
+
+impl<C> Serialize for SerializeChunkedColumn<'_, C>
+where
+    C: Component + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(self.length)?;
+
+        for chunk in self.chunks {
+            let component_column =
+                // SAFETY: Each chunk is guaranteed to contain a component column at index
+                // `self.column`.
+                unsafe { chunk.components.get_unchecked(self.column) };
+            let components = ManuallyDrop::new(
+                // SAFETY: The pointer, capacity, and length are guaranteed to define a valid
+                // `Vec<C>`.
+                unsafe {
+                    Vec::<C>::from_raw_parts(
+                        component_column.0.cast::<C>(),
+                        chunk.length,
+                        component_column.1,
+                    )
+                },
+            );
+            for component in components.iter() {
+                tuple.serialize_element(component)?;
+            }
+        }
+
+        tuple.end()
+    }
+}
+
+/// A set of chunks serialized as a single archetype identified with respect to the registry `R`.
+///
+/// The serialized form is the same as an `Archetype<R>`.
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub(crate) struct SerializeChunks<R>
+where
+    R: registry::Serialize,
+{
+    identifier: archetype::Identifier<R>,
+    chunks: Vec<Chunk>,
+    length: usize,
+}
+
+impl<R> SerializeChunks<R>
+where
+    R: registry::Serialize,
+{
+    /// # Safety
+    /// `identifier` must be of length `(R::LEN + 7) / 8`.
+    pub(crate) unsafe fn new(identifier: Vec<u8>) -> Self {
+        Self {
+            // SAFETY: `identifier` is guaranteed to be of the correct length.
+            identifier: unsafe { archetype::Identifier::new(identifier) },
+            chunks: Vec::new(),
+            length: 0,
+        }
+    }
+
+    /// # Safety
+    /// `chunk` must contain the component columns of the components identified by this set's
+    /// identifier.
+    pub(crate) unsafe fn push(&mut self, chunk: Chunk) {
+        self.length += chunk.length;
+        self.chunks.push(chunk);
+    }
+}
+
+struct SerializeChunkedColumns<'a, R>(&'a SerializeChunks<R>)
+where
+    R: registry::Serialize;
+
+impl<R> Serialize for SerializeChunkedColumns<'_, R>
+where
+    R: registry::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(self.0.identifier.count() + 1)?;
+        tuple.serialize_element(&SerializeChunkedEntityIdentifiers {
+            chunks: &self.0.chunks,
+            length: self.0.length,
+        })?;
+        // SAFETY: Each chunk contains the component columns for each component `C` identified by
+        // the `identifier`, and `length` is the sum of the lengths of the chunks. Also, the `R`
+        // upon which the identifier is generic is the same `R` upon which this function is
+        // called.
+        unsafe {
+            R::serialize_chunked_components_by_column(
+                &self.0.chunks,
+                0,
+                self.0.length,
+                &mut tuple,
+                self.0.identifier.iter(),
+            )?;
+        }
+        tuple.end()
+    }
+}
+
+struct SerializeChunkedRow<'a, R>
+where
+    R: registry::Serialize,
+{
+    identifier: &'a archetype::Identifier<R>,
+    chunk: &'a Chunk,
+    index: usize,
+}
+
+impl<R> Serialize for SerializeChunkedRow<'_, R>
+where
+    R: registry::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(self.identifier.count() + 1)?;
+
+        tuple.serialize_element(
+            // SAFETY: `entity_identifiers` is guaranteed to contain the raw parts for a valid
+            // `Vec<entity::Identifier>` of length `length`.
+            unsafe {
+                ManuallyDrop::new(Vec::from_raw_parts(
+                    self.chunk.entity_identifiers.0,
+                    self.chunk.length,
+                    self.chunk.entity_identifiers.1,
+                ))
+                .get_unchecked(self.index)
+            },
+        )?;
+
+        // SAFETY: `self.chunk.components` contains the raw parts for `Vec<C>`s of size `length`
+        // for each component `C` identified by the `identifier`. Also, the `R` upon which the
+        // identifier is generic is the same `R` upon which this function is called. Finally,
+        // `self.index` is guaranteed to be less than the chunk's length.
+        unsafe {
+            R::serialize_components_by_row(
+                &self.chunk.components,
+                self.chunk.length,
+                self.index,
+                &mut tuple,
+                self.identifier.iter(),
+            )?;
+        }
+
+        tuple.end()
+    }
+}
+
+struct SerializeChunkedRows<'a, R>(&'a SerializeChunks<R>)
+where
+    R: registry::Serialize;
+
+impl<R> Serialize for SerializeChunkedRows<'_, R>
+where
+    R: registry::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(self.0.length)?;
+        for chunk in &self.0.chunks {
+            for index in 0..chunk.length {
+                tuple.serialize_element(&SerializeChunkedRow::<R> {
+                    identifier: &self.0.identifier,
+                    chunk,
+                    index,
+                })?;
+            }
+        }
+        tuple.end()
+    }
+}
+
+struct SerializeChunksByColumn<'a, R>(&'a SerializeChunks<R>)
+where
+    R: registry::Serialize;
+
+impl<R> Serialize for SerializeChunksByColumn<'_, R>
+where
+    R: registry::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&self.0.identifier)?;
+        tuple.serialize_element(&self.0.length)?;
+        tuple.serialize_element(&SerializeChunkedColumns(self.0))?;
+        tuple.end()
+    }
+}
+
+struct SerializeChunksByRow<'a, R>(&'a SerializeChunks<R>)
+where
+    R: registry::Serialize;
+
+impl<R> Serialize for SerializeChunksByRow<'_, R>
+where
+    R: registry::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&self.0.identifier)?;
+        tuple.serialize_element(&self.0.length)?;
+        tuple.serialize_element(&SerializeChunkedRows(self.0))?;
+        tuple.end()
+    }
+}
+
+impl<R> Serialize for SerializeChunks<R>
+where
+    R: registry::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_newtype_struct("Archetype", &SerializeChunksByRow(self))
+        } else {
+            serializer.serialize_newtype_struct("Archetype", &SerializeChunksByColumn(self))
+        }
+    }
+}
+
 struct DeserializeRow<'a, 'de, R>
 where
     R: registry::Deserialize<'de>,
@@ -7,7 +7,9 @@ mod impl_send;
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 mod impl_serde;
+mod packed;
 
+pub(crate) mod column;
 pub(crate) mod identifier;
 
 pub(crate) use identifier::{
@@ -16,7 +18,10 @@ pub(crate) use identifier::{
 };
 #[cfg(feature = "serde")]
 pub(crate) use impl_serde::{
+    Chunk,
     DeserializeColumn,
+    SerializeChunkedColumn,
+    SerializeChunks,
     SerializeColumn,
 };
 
@@ -44,13 +49,16 @@ use crate::{
             ContainsViewsOuter,
             Sealed as ContainsViewsSealed,
         },
+        Canonical,
         ContainsComponent,
         ContainsViews,
         Registry,
     },
+    world,
 };
 #[cfg(feature = "rayon")]
 use crate::{
+    entities::ParStorage,
     query::view::{
         ParViews,
         ParViewsSeal,
@@ -60,15 +68,20 @@ use crate::{
         ContainsParViews,
     },
 };
-use alloc::vec::Vec;
-#[cfg(feature = "serde")]
-use core::slice;
+use alloc::{
+    vec,
+    vec::Vec,
+};
 use core::{
     marker::PhantomData,
     mem::{
+        size_of,
         ManuallyDrop,
         MaybeUninit,
     },
+    ops::Range,
+    ptr,
+    slice,
 };
 
 pub(crate) struct Archetype<R>
@@ -153,6 +166,87 @@ where
         // components in `entity`.
         unsafe { entity.push_components(&mut self.components, self.length) };
 
+        // SAFETY: `entity_allocator` is guaranteed by the safety contract of this method to not
+        // outlive `self`.
+        unsafe { self.push_entity_identifier(entity_allocator) }
+    }
+
+    /// # Safety
+    /// `entity` must be made up of only components that are identified by this `Archetype`'s
+    /// `Identifier`, in the same order.
+    ///
+    /// `entity_allocator.check_vacant()` must have returned `Ok` for `entity_identifier`, and
+    /// `entity_allocator` must not have been modified since.
+    ///
+    /// The `entity_allocator`, together with its contained `Location`s, must not outlive `self`.
+    pub(crate) unsafe fn push_at<E>(
+        &mut self,
+        entity: E,
+        entity_identifier: entity::Identifier,
+        entity_allocator: &mut entity::Allocator<R>,
+    ) where
+        E: Entity,
+    {
+        // SAFETY: `entity_identifier` is guaranteed by the safety contract of this method to be
+        // vacant within `entity_allocator`.
+        unsafe {
+            entity_allocator.allocate_at_unchecked(
+                entity_identifier,
+                Location {
+                    identifier:
+                        // SAFETY: `entity_allocator` is guaranteed to not outlive `self`.
+                        // Therefore, the `Location` being stored in it will also not outlive
+                        // `self`.
+                        self.identifier.as_ref(),
+                    index: self.length,
+                },
+            );
+        }
+
+        // SAFETY: `self.components`, together with `self.length`, define valid `Vec<C>` for each
+        // component, and the components in `self.components` are in the same order as the
+        // components in `entity`.
+        unsafe { entity.push_components(&mut self.components, self.length) };
+
+        // SAFETY: A component was just pushed onto each component column, and `entity_identifier`
+        // was allocated above with a location pointing to the new row.
+        unsafe { self.push_allocated_entity_identifier(entity_identifier) };
+    }
+
+    /// # Safety
+    /// The components present in `entity` must be exactly the components identified by this
+    /// `Archetype`'s `Identifier`.
+    ///
+    /// The `entity_allocator`, together with its contained `Location`s, must not outlive `self`.
+    pub(crate) unsafe fn push_mixed<E>(
+        &mut self,
+        entity: E,
+        entity_allocator: &mut entity::Allocator<R>,
+    ) -> entity::Identifier
+    where
+        E: registry::contains::mixed_entity::Storage,
+    {
+        // SAFETY: `self.components`, together with `self.length`, define valid `Vec<C>` for each
+        // component, and the components in `self.components` are the same as the components
+        // present in `entity`, in the same order, since both are in the registry's order.
+        unsafe { entity.push_components(&mut self.components, self.length) };
+
+        // SAFETY: `entity_allocator` is guaranteed by the safety contract of this method to not
+        // outlive `self`.
+        unsafe { self.push_entity_identifier(entity_allocator) }
+    }
+
+    /// Allocate an identifier for a new entity whose components have already been pushed onto
+    /// the end of the component columns.
+    ///
+    /// # Safety
+    /// A component must have been pushed onto each component column for the new entity.
+    ///
+    /// The `entity_allocator`, together with its contained `Location`s, must not outlive `self`.
+    unsafe fn push_entity_identifier(
+        &mut self,
+        entity_allocator: &mut entity::Allocator<R>,
+    ) -> entity::Identifier {
         let entity_identifier = entity_allocator.allocate(Location {
             identifier:
                 // SAFETY: `entity_allocator` is guaranteed to not outlive `self`. Therefore, the
@@ -161,6 +255,21 @@ where
             index: self.length,
         });
 
+        // SAFETY: A component has been pushed onto each component column for the new entity, and
+        // `entity_identifier` was just allocated to point to the new row.
+        unsafe { self.push_allocated_entity_identifier(entity_identifier) };
+
+        entity_identifier
+    }
+
+    /// Store an already allocated identifier for a new entity whose components have already been
+    /// pushed onto the end of the component columns.
+    ///
+    /// # Safety
+    /// A component must have been pushed onto each component column for the new entity.
+    ///
+    /// `entity_identifier` must be allocated with a location pointing to the new row.
+    unsafe fn push_allocated_entity_identifier(&mut self, entity_identifier: entity::Identifier) {
         let mut entity_identifiers = ManuallyDrop::new(
             // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts that,
             // together with `self.length`, create a valid `Vec`.
@@ -179,8 +288,6 @@ where
         );
 
         self.length += 1;
-
-        entity_identifier
     }
 
     /// # Safety
@@ -192,8 +299,7 @@ where
         &mut self,
         entities: entities::Batch<E>,
         entity_allocator: &mut entity::Allocator<R>,
-    ) -> Vec<entity::Identifier>
-    where
+    ) where
         E: Entities,
     {
         let component_len = entities.entities.component_len();
@@ -207,14 +313,52 @@ where
                 .extend_components(&mut self.components, self.length);
         }
 
-        let entity_identifiers = entity_allocator.allocate_batch(Locations::new(
-            self.length..(self.length + component_len),
-            // SAFETY: `entity_allocator` is guaranteed to not outlive `self`. Therefore, the
-            // `Location`s being stored in it will also not outlive `self`.
-            unsafe { self.identifier.as_ref() },
-        ));
+        // SAFETY: `entity_allocator` is guaranteed to not outlive `self`.
+        unsafe { self.extend_entity_identifiers(component_len, entity_allocator) }
+    }
+
+    /// # Safety
+    /// `entities` must be made up of only components that are identified by this `Archetype`'s
+    /// `Identifier`, in the same order.
+    ///
+    /// The `entity_allocator`, together with its contained `Location`s, must not outlive `self`.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    pub(crate) unsafe fn par_extend<E>(
+        &mut self,
+        entities: entities::Batch<E>,
+        entity_allocator: &mut entity::Allocator<R>,
+    ) where
+        E: Entities + ParStorage,
+    {
+        let component_len = entities.entities.component_len();
+
+        // SAFETY: `self.components`, together with `self.length`, define valid `Vec<C>` for each
+        // component, and the components in `self.components` are in the same order as the
+        // components in `entities`.
+        unsafe {
+            entities
+                .entities
+                .par_extend_components(&mut self.components, self.length);
+        }
+
+        // SAFETY: `entity_allocator` is guaranteed to not outlive `self`.
+        unsafe { self.extend_entity_identifiers(component_len, entity_allocator) }
+    }
 
-        let mut entity_identifiers_v = ManuallyDrop::new(
+    /// Allocate entity identifiers for `additional` entities whose components were just added to
+    /// the end of the component columns.
+    ///
+    /// # Safety
+    /// The component columns must contain `additional` more components than `self.length`.
+    ///
+    /// The `entity_allocator`, together with its contained `Location`s, must not outlive `self`.
+    unsafe fn extend_entity_identifiers(
+        &mut self,
+        additional: usize,
+        entity_allocator: &mut entity::Allocator<R>,
+    ) {
+        let mut entity_identifiers = ManuallyDrop::new(
             // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts that,
             // together with `self.length`, create a valid `Vec`.
             unsafe {
@@ -225,15 +369,21 @@ where
                 )
             },
         );
-        entity_identifiers_v.extend(entity_identifiers.iter());
+        entity_allocator.allocate_batch(
+            Locations::new(
+                self.length..(self.length + additional),
+                // SAFETY: `entity_allocator` is guaranteed to not outlive `self`. Therefore, the
+                // `Location`s being stored in it will also not outlive `self`.
+                unsafe { self.identifier.as_ref() },
+            ),
+            &mut entity_identifiers,
+        );
         self.entity_identifiers = (
-            entity_identifiers_v.as_mut_ptr(),
-            entity_identifiers_v.capacity(),
+            entity_identifiers.as_mut_ptr(),
+            entity_identifiers.capacity(),
         );
 
-        self.length += component_len;
-
-        entity_identifiers
+        self.length += additional;
     }
 
     /// # Safety
@@ -267,6 +417,29 @@ where
         }
     }
 
+    /// # Safety
+    /// Each component viewed by `V` must also be identified by this archetype's `Identifier`.
+    pub(crate) unsafe fn slices<'a, Views, Indices>(&mut self) -> Views::Slices
+    where
+        Views: view::Views<'a>,
+        R: ContainsViews<'a, Views, Indices>,
+    {
+        // SAFETY: `self.components` contains the raw parts for `Vec<C>`s of size `self.length`
+        // for each component `C` identified in `self.identifier` in the canonical order defined by
+        // the registry.
+        //
+        // `self.entity_identifiers` also contains the raw parts for a valid
+        // `Vec<entity::Identifier>` of size `self.length`.
+        unsafe {
+            <R as ContainsViewsSealed<'a, Views, Indices>>::Viewable::slices(
+                &self.components,
+                self.entity_identifiers,
+                self.length,
+                self.identifier.iter(),
+            )
+        }
+    }
+
     /// # Safety
     /// Each component viewed by `V` must also be identified by this archetype's `Identifier`.
     #[cfg(feature = "rayon")]
@@ -401,6 +574,128 @@ where
         }
     }
 
+    /// Returns a pointer to the component `C` at the given index.
+    ///
+    /// # Safety
+    /// `index` must be a valid index within this archetype (meaning it must be less than
+    /// `self.length`). Also, `C` must be a component type contained in this archetype.
+    pub(crate) unsafe fn component_ptr_unchecked<C, I>(&mut self, index: usize) -> *mut C
+    where
+        C: Component,
+        R: ContainsComponent<C, I>,
+    {
+        // SAFETY: `index` is guaranteed to be less than `length`. Also, `components` is guaranteed
+        // to contain the valid raw parts for `Vec<C>`s for each component identified by
+        // `self.identifier.iter()`. Finally, `C` is guaranteed by the safety contract of this
+        // method to be a component type contained in this archetype.
+        unsafe { R::component_ptr(index, &self.components, self.identifier.iter()) }
+    }
+
+    /// Returns a reference to the component `C` at the given index.
+    ///
+    /// Only the single component is borrowed, meaning other components within the same column may
+    /// be borrowed mutably elsewhere.
+    ///
+    /// # Safety
+    /// `index` must be a valid index within this archetype (meaning it must be less than
+    /// `self.length`). Also, `C` must be a component type contained in this archetype.
+    pub(crate) unsafe fn component_unchecked<C, I>(&self, index: usize) -> &C
+    where
+        C: Component,
+        R: ContainsComponent<C, I>,
+    {
+        // SAFETY: `index` is guaranteed to be less than `length`. Also, `components` is guaranteed
+        // to contain the valid raw parts for `Vec<C>`s for each component identified by
+        // `self.identifier.iter()`. Finally, `C` is guaranteed by the safety contract of this
+        // method to be a component type contained in this archetype.
+        unsafe { &*R::component_ptr(index, &self.components, self.identifier.iter()) }
+    }
+
+    /// Returns whether the component `C` is contained in this archetype.
+    pub(crate) fn contains_component<C, I>(&self) -> bool
+    where
+        C: Component,
+        R: ContainsComponent<C, I>,
+    {
+        // SAFETY: The identifier reference created here does not outlive the archetype. Also, the
+        // index obtained from `R::LEN - R::INDEX - 1` is guaranteed to be a valid index into the
+        // identifier, since an identifier has `R::LEN` bits.
+        unsafe { self.identifier().get_unchecked(R::LEN - R::INDEX - 1) }
+    }
+
+    /// Returns the column of components `C` as a slice, if `C` is contained in this archetype.
+    pub(crate) fn column<C, I>(&self) -> Option<&[C]>
+    where
+        C: Component,
+        R: ContainsComponent<C, I>,
+    {
+        if self.contains_component::<C, I>() {
+            // SAFETY: It was verified above that `C` is contained in this archetype.
+            Some(unsafe { self.column_unchecked::<C, I>() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the column of components `C` as a mutable slice, if `C` is contained in this
+    /// archetype.
+    pub(crate) fn column_mut<C, I>(&mut self) -> Option<&mut [C]>
+    where
+        C: Component,
+        R: ContainsComponent<C, I>,
+    {
+        if self.contains_component::<C, I>() {
+            // SAFETY: It was verified above that `C` is contained in this archetype.
+            Some(unsafe { self.column_mut_unchecked::<C, I>() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the column of components `C` as a slice.
+    ///
+    /// # Safety
+    /// `C` must be a component type contained in this archetype.
+    pub(crate) unsafe fn column_unchecked<C, I>(&self) -> &[C]
+    where
+        C: Component,
+        R: ContainsComponent<C, I>,
+    {
+        // SAFETY: `components` is guaranteed to contain the valid raw parts for `Vec<C>`s of
+        // length `self.length` for each component identified by `self.identifier.iter()`. Also,
+        // `C` is guaranteed by the safety contract of this method to be a component type
+        // contained in this archetype. Offsetting by `0` is valid even for an empty column.
+        unsafe {
+            slice::from_raw_parts(
+                R::component_ptr(0, &self.components, self.identifier.iter()),
+                self.length,
+            )
+        }
+    }
+
+    /// Returns the column of components `C` as a mutable slice.
+    ///
+    /// # Safety
+    /// `C` must be a component type contained in this archetype.
+    pub(crate) unsafe fn column_mut_unchecked<C, I>(&mut self) -> &mut [C]
+    where
+        C: Component,
+        R: ContainsComponent<C, I>,
+    {
+        // SAFETY: `components` is guaranteed to contain the valid raw parts for `Vec<C>`s of
+        // length `self.length` for each component identified by `self.identifier.iter()`. Also,
+        // `C` is guaranteed by the safety contract of this method to be a component type
+        // contained in this archetype. Offsetting by `0` is valid even for an empty column.
+        unsafe {
+            slice::from_raw_parts_mut(
+                R::component_ptr(0, &self.components, self.identifier.iter()),
+                self.length,
+            )
+        }
+    }
+
+    /// If another entity is moved into the removed row, the callbacks in `on_move` are notified.
+    ///
     /// # Safety
     /// `entity_allocator` must contain entries for the entities stored in the archetype. The
     /// `index` must be a valid index to a row in this archetype.
@@ -408,6 +703,7 @@ where
         &mut self,
         index: usize,
         entity_allocator: &mut entity::Allocator<R>,
+        on_move: &mut world::MoveCallbacks,
     ) {
         // SAFETY: `self.components` contains the same number of bits as are set in
         // `self.identifier`. Also, each entry is `self.components` is guaranteed to contain the
@@ -432,23 +728,33 @@ where
 
         // Update swapped index if this isn't the last row.
         if index < self.length - 1 {
+            let moved_entity_identifier =
+                // SAFETY: `entity_identifiers` is guaranteed to be nonempty, because the index is
+                // not for the last row.
+                unsafe { *entity_identifiers.last().unwrap_unchecked() };
             // SAFETY: `entity_allocator` contains an entry for the entity identifiers stored in
             // `entity_identifiers`.
-            //
-            // Additionally, `entity_identifiers` is guaranteed to be nonempty, because the index
-            // is not for the last row.
             unsafe {
-                entity_allocator.modify_location_index_unchecked(
-                    *entity_identifiers.last().unwrap_unchecked(),
-                    index,
-                );
+                entity_allocator.modify_location_index_unchecked(moved_entity_identifier, index);
             }
+            on_move.notify(
+                moved_entity_identifier,
+                self.length - 1,
+                index,
+                view::ArchetypeId::new(
+                    // SAFETY: The `IdentifierRef` created here does not outlive this function
+                    // call, and therefore does not outlive the archetype's identifier.
+                    unsafe { self.identifier() },
+                ),
+            );
         }
         entity_identifiers.swap_remove(index);
 
         self.length -= 1;
     }
 
+    /// If another entity is moved into the removed row, the callbacks in `on_move` are notified.
+    ///
     /// # Safety
     /// `entity_allocator` must contain entries for the entities stored in the archetype. The
     /// `index` must be a valid index to a row in this archetype.
@@ -456,6 +762,7 @@ where
         &mut self,
         index: usize,
         entity_allocator: &mut entity::Allocator<R>,
+        on_move: &mut world::MoveCallbacks,
     ) -> (entity::Identifier, Vec<u8>) {
         let size_of_components = self.identifier.size_of_components();
         let mut bytes = Vec::with_capacity(size_of_components);
@@ -496,17 +803,25 @@ where
         );
         // Update swapped index if this isn't the last row.
         if index < self.length - 1 {
+            let moved_entity_identifier =
+                // SAFETY: `entity_identifiers` is guaranteed to be nonempty, because the index is
+                // not for the last row.
+                unsafe { *entity_identifiers.last().unwrap_unchecked() };
             // SAFETY: `entity_allocator` contains an entry for the entity identifiers stored in
             // `entity_identifiers`.
-            //
-            // Additionally, `entity_identifiers` is guaranteed to be nonempty, because the index
-            // is not for the last row.
             unsafe {
-                entity_allocator.modify_location_index_unchecked(
-                    *entity_identifiers.last().unwrap_unchecked(),
-                    index,
-                );
+                entity_allocator.modify_location_index_unchecked(moved_entity_identifier, index);
             }
+            on_move.notify(
+                moved_entity_identifier,
+                self.length - 1,
+                index,
+                view::ArchetypeId::new(
+                    // SAFETY: The `IdentifierRef` created here does not outlive this function
+                    // call, and therefore does not outlive the archetype's identifier.
+                    unsafe { self.identifier() },
+                ),
+            );
         }
         let entity_identifier = entity_identifiers.swap_remove(index);
 
@@ -515,6 +830,44 @@ where
         (entity_identifier, bytes)
     }
 
+    /// Remove the last row of this archetype, returning its components as the canonical entity
+    /// `E`.
+    ///
+    /// Any components not contained in `E` are dropped. Returns `None` if the archetype is empty.
+    ///
+    /// # Safety
+    /// `entity_allocator` must contain entries for the entities stored in the archetype. Each
+    /// component in `E` must be identified by this archetype's `Identifier`.
+    pub(crate) unsafe fn pop_last<E, P>(
+        &mut self,
+        entity_allocator: &mut entity::Allocator<R>,
+    ) -> Option<E>
+    where
+        R: Canonical<E, P>,
+    {
+        if self.length == 0 {
+            return None;
+        }
+
+        let entity =
+            // SAFETY: `self.components` contains the raw parts for `Vec<C>`s of size `self.length`
+            // for each component `C` identified by `self.identifier`, and `self.length` is
+            // nonzero. Each component in `E` is guaranteed by the safety contract of this method
+            // to be identified by `self.identifier`.
+            unsafe { R::take_last_row(&self.components, self.length, self.identifier.iter()) };
+
+        // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+        // `Vec` of size `self.length`, and `self.length` is nonzero.
+        let entity_identifier = unsafe { *self.entity_identifiers.0.add(self.length - 1) };
+        // SAFETY: `entity_allocator` is guaranteed by the safety contract of this method to
+        // contain `entity_identifier`.
+        unsafe { entity_allocator.free_unchecked(entity_identifier) };
+
+        self.length -= 1;
+
+        Some(entity)
+    }
+
     /// # Safety
     /// `buffer` must be valid for reads and be an allocated buffer of packed, properly initialized
     /// components corresponding to the components identified by this archetype's `identifier`
@@ -636,19 +989,55 @@ where
         self.length - 1
     }
 
+    /// Push a row made up of components from `buffer` and `replacement_buffer`.
+    ///
+    /// Each component identified by this archetype's `identifier` is read from
+    /// `replacement_buffer` if it is identified by `replacement_identifier`, and from `buffer`
+    /// otherwise. Any remaining components in `buffer` are dropped.
+    ///
     /// # Safety
-    /// `entity_allocator` must contain entries for the entities stored in the archetype.
-    pub(crate) unsafe fn clear(&mut self, entity_allocator: &mut entity::Allocator<R>) {
-        // Clear each column.
+    /// `buffer` must be valid for reads and be an allocated buffer of packed, properly initialized
+    /// components corresponding to the components identified by `buffer_identifier`. Likewise,
+    /// `replacement_buffer` must be valid for reads and be an allocated buffer of packed, properly
+    /// initialized components corresponding to the components identified by
+    /// `replacement_identifier`.
+    ///
+    /// Every component identified by `replacement_identifier` must be identified by this
+    /// archetype's `identifier` field, and every component identified by this archetype's
+    /// `identifier` field must be identified by either `buffer_identifier` or
+    /// `replacement_identifier`.
+    pub(crate) unsafe fn push_from_buffers(
+        &mut self,
+        entity_identifier: entity::Identifier,
+        buffer: *const u8,
+        buffer_identifier: identifier::Iter<R>,
+        replacement_buffer: *const u8,
+        replacement_identifier: identifier::Iter<R>,
+    ) -> usize {
         // SAFETY: `self.components` has the same number of values as there are set bits in
         // `self.identifier`. Also, each element in `self.components` defines a `Vec<C>` of size
         // `self.length` for each `C` identified by `self.identifier`.
         //
+        // `buffer` and `replacement_buffer` are valid for reads and are allocated buffers of
+        // packed properly initialized components corresponding to the components identified by
+        // `buffer_identifier` and `replacement_identifier` respectively, and every component
+        // identified by `self.identifier` is provided by one of them, as is guaranteed by the
+        // safety contract of this method.
+        //
         // The `R` over which `self.identifier` is generic is the same `R` on which this function
         // is being called.
-        unsafe { R::clear_components(&mut self.components, self.length, self.identifier.iter()) };
+        unsafe {
+            R::push_components_from_buffers(
+                buffer,
+                buffer_identifier,
+                replacement_buffer,
+                replacement_identifier,
+                &mut self.components,
+                self.length,
+                self.identifier.iter(),
+            );
+        }
 
-        // Free each entity.
         let mut entity_identifiers = ManuallyDrop::new(
             // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
             // `Vec` of size `self.length`.
@@ -660,20 +1049,109 @@ where
                 )
             },
         );
-        for entity_identifier in entity_identifiers.iter() {
-            // SAFETY: `entity_allocator` is guaranteed by the safety contract of this method to
-            // contain `entity_identifier`.
-            unsafe { entity_allocator.free_unchecked(*entity_identifier) };
-        }
-        entity_identifiers.clear();
+        entity_identifiers.push(entity_identifier);
+        self.entity_identifiers = (
+            entity_identifiers.as_mut_ptr(),
+            entity_identifiers.capacity(),
+        );
 
-        self.length = 0;
+        self.length += 1;
+
+        self.length - 1
     }
 
-    /// Clear the archetype as a detached entity.
-    ///
-    /// The difference between this as `clear()` is that this method does not attempt to remove the
-    /// entities from an `entity::Allocator`. It is for use in contexts such as
+    /// # Safety
+    /// `entity_allocator` must contain entries for the entities stored in the archetype.
+    pub(crate) unsafe fn clear(&mut self, entity_allocator: &mut entity::Allocator<R>) {
+        // Clear each column.
+        // SAFETY: `self.components` has the same number of values as there are set bits in
+        // `self.identifier`. Also, each element in `self.components` defines a `Vec<C>` of size
+        // `self.length` for each `C` identified by `self.identifier`.
+        //
+        // The `R` over which `self.identifier` is generic is the same `R` on which this function
+        // is being called.
+        unsafe { R::clear_components(&mut self.components, self.length, self.identifier.iter()) };
+
+        // Free each entity.
+        let mut entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+            // `Vec` of size `self.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    self.entity_identifiers.0,
+                    self.length,
+                    self.entity_identifiers.1,
+                )
+            },
+        );
+        for entity_identifier in entity_identifiers.iter() {
+            // SAFETY: `entity_allocator` is guaranteed by the safety contract of this method to
+            // contain `entity_identifier`.
+            unsafe { entity_allocator.free_unchecked(*entity_identifier) };
+        }
+        entity_identifiers.clear();
+
+        self.length = 0;
+    }
+
+    /// Calls the registered `callbacks` for every component of each row within `rows`.
+    ///
+    /// # Safety
+    /// `rows` must be within the bounds of this archetype, meaning `rows.end` must not be greater
+    /// than `self.length`.
+    pub(crate) unsafe fn notify_rows(&self, rows: Range<usize>, callbacks: &mut world::Callbacks) {
+        if callbacks.is_empty() {
+            return;
+        }
+        // SAFETY: `self.components` has the same number of values as there are set bits in
+        // `self.identifier`. Also, each element in `self.components` defines a `Vec<C>` of size
+        // `self.length` for each `C` identified by `self.identifier`, and there are
+        // `self.length` entity identifiers. `rows` is guaranteed by the safety contract of this
+        // method to not extend past `self.length`.
+        //
+        // The `R` over which `self.identifier` is generic is the same `R` on which this function
+        // is being called.
+        unsafe {
+            R::notify_rows(
+                rows,
+                self.entity_identifiers(),
+                &self.components,
+                self.identifier.iter(),
+                callbacks,
+            );
+        }
+    }
+
+    /// Calls the registered `callbacks` for the component `C` of the row at `index`.
+    ///
+    /// # Safety
+    /// `index` must be a valid index within this archetype (meaning it must be less than
+    /// `self.length`). Also, `C` must be a component type contained in this archetype.
+    pub(crate) unsafe fn notify_component_unchecked<C, I>(
+        &mut self,
+        index: usize,
+        callbacks: &mut world::Callbacks,
+    ) where
+        C: Component,
+        R: ContainsComponent<C, I>,
+    {
+        if callbacks.is_empty() {
+            return;
+        }
+        // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+        // `Vec` of size `self.length`, and `index` is guaranteed to be less than `self.length`.
+        let entity_identifier = unsafe { *self.entity_identifiers.0.add(index) };
+        // SAFETY: `index` is guaranteed to be a valid index within this archetype, and `C` is
+        // guaranteed to be contained in this archetype. The returned pointer therefore points to
+        // a valid, initialized `C`.
+        let component = unsafe { &*self.component_ptr_unchecked::<C, I>(index) };
+        callbacks.notify(entity_identifier, component);
+    }
+
+    /// Clear the archetype as a detached entity.
+    ///
+    /// The difference between this as `clear()` is that this method does not attempt to remove the
+    /// entities from an `entity::Allocator`. It is for use in contexts such as
     /// `Clone::clone_from()`.
     pub(crate) fn clear_detached(&mut self) {
         // Clear each column.
@@ -722,6 +1200,316 @@ where
         );
     }
 
+    /// Decrease the allocated capacity for the component columns and entity identifier column,
+    /// keeping a capacity of at least `min_capacity`.
+    ///
+    /// Columns whose capacity is already at most `min_capacity` are left unchanged. This may not
+    /// decrease to the most optimal capacity, as it is dependent on the allocator.
+    pub(crate) fn shrink_to(&mut self, min_capacity: usize) {
+        // SAFETY: `self.components` has the same number of values as there are set bits in
+        // `self.identifier`. Also, each element in `self.components` defines a `Vec<C>` of size
+        // `self.length` for each `C` identified by `self.identifier`.
+        //
+        // The `R` over which `self.identifier` is generic is the same `R` on which this function
+        // is being called.
+        unsafe {
+            R::shrink_components_to(
+                &mut self.components,
+                self.length,
+                min_capacity,
+                self.identifier.iter(),
+            );
+        }
+
+        let mut entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+            // `Vec` of size `self.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    self.entity_identifiers.0,
+                    self.length,
+                    self.entity_identifiers.1,
+                )
+            },
+        );
+        entity_identifiers.shrink_to(min_capacity);
+        self.entity_identifiers = (
+            entity_identifiers.as_mut_ptr(),
+            entity_identifiers.capacity(),
+        );
+    }
+
+    /// Move all entities from `other` to this `Archetype`, allocating new entity identifiers for
+    /// them.
+    ///
+    /// Returns pairs of each moved entity's identifier within `other` and its newly allocated
+    /// identifier. After this method is called, `other` will be empty.
+    ///
+    /// # Safety
+    /// `other` must be identified by the same `Identifier` as this `Archetype`.
+    ///
+    /// The `entity_allocator`, together with its contained `Location`s, must not outlive `self`.
+    pub(crate) unsafe fn append(
+        &mut self,
+        other: &mut Self,
+        entity_allocator: &mut entity::Allocator<R>,
+    ) -> Vec<(entity::Identifier, entity::Identifier)> {
+        // SAFETY: `self.components` and `other.components` both have the same number of values as
+        // there are set bits in `self.identifier`, since `other` is identified by the same
+        // identifier. Also, each element in `self.components` and `other.components` defines a
+        // `Vec<C>` of size `self.length` and `other.length` respectively for each `C` identified
+        // by `self.identifier`.
+        //
+        // The `R` over which `self.identifier` is generic is the same `R` on which this function
+        // is being called.
+        unsafe {
+            R::append_components(
+                &mut self.components,
+                self.length,
+                &mut other.components,
+                other.length,
+                self.identifier.iter(),
+            );
+        }
+
+        let mut new_entity_identifiers = Vec::new();
+        entity_allocator.allocate_batch(
+            Locations::new(
+                self.length..(self.length + other.length),
+                // SAFETY: `entity_allocator` is guaranteed to not outlive `self`. Therefore, the
+                // `Location`s being stored in it will also not outlive `self`.
+                unsafe { self.identifier.as_ref() },
+            ),
+            &mut new_entity_identifiers,
+        );
+
+        let mut other_entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `other.entity_identifiers` is guaranteed to contain the raw parts for a
+            // valid `Vec` of size `other.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    other.entity_identifiers.0,
+                    other.length,
+                    other.entity_identifiers.1,
+                )
+            },
+        );
+        let entity_identifier_pairs = other_entity_identifiers
+            .iter()
+            .copied()
+            .zip(new_entity_identifiers.iter().copied())
+            .collect();
+        other_entity_identifiers.clear();
+        other.length = 0;
+
+        let mut entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+            // `Vec` of size `self.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    self.entity_identifiers.0,
+                    self.length,
+                    self.entity_identifiers.1,
+                )
+            },
+        );
+        entity_identifiers.extend(new_entity_identifiers.iter());
+        self.entity_identifiers = (
+            entity_identifiers.as_mut_ptr(),
+            entity_identifiers.capacity(),
+        );
+
+        self.length += new_entity_identifiers.len();
+
+        entity_identifier_pairs
+    }
+
+    /// Move all entities from `other` to this `Archetype`, giving each of them a clone of
+    /// `component`.
+    ///
+    /// The moved entities keep their entity identifiers, and their locations are updated within
+    /// `entity_allocator`. Returns the range of rows the entities were moved to. After this method
+    /// is called, `other` will be empty.
+    ///
+    /// # Safety
+    /// This `Archetype` must be identified by the same components as `other`, with the addition of
+    /// the component `C`, which `other` must not contain.
+    ///
+    /// The registry `R` over which this archetype is generic must contain no duplicate components.
+    ///
+    /// `entity_allocator` must contain entries for the entities stored in `other`, and must not
+    /// outlive `self`.
+    pub(crate) unsafe fn append_with_component<C>(
+        &mut self,
+        other: &mut Self,
+        component: &C,
+        entity_allocator: &mut entity::Allocator<R>,
+    ) -> Range<usize>
+    where
+        C: Clone + Component,
+    {
+        // SAFETY: `self.components` has the same number of values as there are set bits in
+        // `self.identifier`, and `other.components` has one less, since `other` is identified by
+        // the same components except for `C`. Also, each element in `self.components` and
+        // `other.components` defines a `Vec<C>` of size `self.length` and `other.length`
+        // respectively for each `C` identified by `self.identifier`.
+        //
+        // `R` contains no duplicate components, as is guaranteed by the safety contract of this
+        // method.
+        //
+        // The `R` over which `self.identifier` is generic is the same `R` on which this function
+        // is being called.
+        unsafe {
+            R::append_components_and_component(
+                component,
+                &mut self.components,
+                self.length,
+                &mut other.components,
+                other.length,
+                self.identifier.iter(),
+            );
+        }
+
+        let mut other_entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `other.entity_identifiers` is guaranteed to contain the raw parts for a
+            // valid `Vec` of size `other.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    other.entity_identifiers.0,
+                    other.length,
+                    other.entity_identifiers.1,
+                )
+            },
+        );
+        let mut entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+            // `Vec` of size `self.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    self.entity_identifiers.0,
+                    self.length,
+                    self.entity_identifiers.1,
+                )
+            },
+        );
+
+        for (index, &entity_identifier) in (self.length..).zip(other_entity_identifiers.iter()) {
+            // SAFETY: `entity_allocator` is guaranteed to contain an entry for every entity in
+            // `other`. Also, `entity_allocator` is guaranteed to not outlive `self`, so the
+            // `Location` stored within it will not outlive `self.identifier`.
+            unsafe {
+                entity_allocator.modify_location_unchecked(
+                    entity_identifier,
+                    Location::new(self.identifier.as_ref(), index),
+                );
+            }
+        }
+        entity_identifiers.append(&mut other_entity_identifiers);
+        self.entity_identifiers = (
+            entity_identifiers.as_mut_ptr(),
+            entity_identifiers.capacity(),
+        );
+        other.entity_identifiers = (
+            other_entity_identifiers.as_mut_ptr(),
+            other_entity_identifiers.capacity(),
+        );
+
+        let start = self.length;
+        self.length += other.length;
+        other.length = 0;
+
+        start..self.length
+    }
+
+    /// Move all entities from `other` to this `Archetype`, dropping their component `C`.
+    ///
+    /// The moved entities keep their entity identifiers, and their locations are updated within
+    /// `entity_allocator`. After this method is called, `other` will be empty.
+    ///
+    /// # Safety
+    /// This `Archetype` must be identified by the same components as `other`, with the exception
+    /// of the component `C`, which `other` must contain and this `Archetype` must not.
+    ///
+    /// The registry `R` over which this archetype is generic must contain no duplicate components.
+    ///
+    /// `entity_allocator` must contain entries for the entities stored in `other`, and must not
+    /// outlive `self`.
+    pub(crate) unsafe fn append_without_component<C>(
+        &mut self,
+        other: &mut Self,
+        entity_allocator: &mut entity::Allocator<R>,
+    ) where
+        C: Component,
+    {
+        // SAFETY: `other.components` has the same number of values as there are set bits in
+        // `other.identifier`, and `self.components` has one less, since `self` is identified by
+        // the same components except for `C`. Also, each element in `self.components` and
+        // `other.components` defines a `Vec<C>` of size `self.length` and `other.length`
+        // respectively for each `C` identified by `other.identifier`.
+        //
+        // `R` contains no duplicate components, as is guaranteed by the safety contract of this
+        // method.
+        //
+        // The `R` over which `other.identifier` is generic is the same `R` on which this function
+        // is being called.
+        unsafe {
+            R::append_components_without_component::<C, R>(
+                &mut self.components,
+                self.length,
+                &mut other.components,
+                other.length,
+                other.identifier.iter(),
+            );
+        }
+
+        let mut other_entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `other.entity_identifiers` is guaranteed to contain the raw parts for a
+            // valid `Vec` of size `other.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    other.entity_identifiers.0,
+                    other.length,
+                    other.entity_identifiers.1,
+                )
+            },
+        );
+        let mut entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+            // `Vec` of size `self.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    self.entity_identifiers.0,
+                    self.length,
+                    self.entity_identifiers.1,
+                )
+            },
+        );
+
+        for (index, &entity_identifier) in (self.length..).zip(other_entity_identifiers.iter()) {
+            // SAFETY: `entity_allocator` is guaranteed to contain an entry for every entity in
+            // `other`. Also, `entity_allocator` is guaranteed to not outlive `self`, so the
+            // `Location` stored within it will not outlive `self.identifier`.
+            unsafe {
+                entity_allocator.modify_location_unchecked(
+                    entity_identifier,
+                    Location::new(self.identifier.as_ref(), index),
+                );
+            }
+        }
+        entity_identifiers.append(&mut other_entity_identifiers);
+        self.entity_identifiers = (
+            entity_identifiers.as_mut_ptr(),
+            entity_identifiers.capacity(),
+        );
+        other.entity_identifiers = (
+            other_entity_identifiers.as_mut_ptr(),
+            other_entity_identifiers.capacity(),
+        );
+
+        self.length += other.length;
+        other.length = 0;
+    }
+
     /// Reserve capacity for `additional` elements in this `Archetype`.
     ///
     /// # Safety
@@ -755,6 +1543,133 @@ where
         );
     }
 
+    /// Reserve capacity for `additional` elements in this `Archetype`, using the components
+    /// identified by this `Archetype`'s `Identifier`.
+    ///
+    /// Unlike [`reserve()`], this does not require the entity type to be known.
+    ///
+    /// [`reserve()`]: Archetype::reserve()
+    pub(crate) fn reserve_by_identifier(&mut self, additional: usize) {
+        // SAFETY: `self.components` has the same number of values as there are set bits in
+        // `self.identifier`. Also, each element in `self.components` defines a `Vec<C>` of size
+        // `self.length` for each `C` identified by `self.identifier`.
+        //
+        // The `R` over which `self.identifier` is generic is the same `R` on which this function
+        // is being called.
+        unsafe {
+            R::reserve_components(
+                &mut self.components,
+                self.length,
+                additional,
+                self.identifier.iter(),
+            );
+        }
+
+        let mut entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+            // `Vec` of size `self.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    self.entity_identifiers.0,
+                    self.length,
+                    self.entity_identifiers.1,
+                )
+            },
+        );
+        entity_identifiers.reserve(additional);
+        self.entity_identifiers = (
+            entity_identifiers.as_mut_ptr(),
+            entity_identifiers.capacity(),
+        );
+    }
+
+    /// Reserve capacity for exactly `additional` elements in this `Archetype`.
+    ///
+    /// # Safety
+    /// `E` must be made up of only components that are identified by this `Archetype`'s
+    /// `Identifier`, in the same order.
+    pub(crate) unsafe fn reserve_exact<E>(&mut self, additional: usize)
+    where
+        E: Entity,
+    {
+        // SAFETY: Since `E` is made up of only components defined in this `Archetype`'s
+        // `Identifier`, in the same order, then the components will also be in the same order as
+        // `E`. Also, `self.components` and `self.length` make up valid `Vec<C>`s for each
+        // component.
+        unsafe { E::reserve_exact_components(&mut self.components, self.length, additional) }
+
+        let mut entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+            // `Vec` of size `self.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    self.entity_identifiers.0,
+                    self.length,
+                    self.entity_identifiers.1,
+                )
+            },
+        );
+        entity_identifiers.reserve_exact(additional);
+        self.entity_identifiers = (
+            entity_identifiers.as_mut_ptr(),
+            entity_identifiers.capacity(),
+        );
+    }
+
+    /// # Safety
+    /// The components present in `entity` must be exactly the components identified by this
+    /// `Archetype`'s `Identifier`.
+    pub(crate) unsafe fn reserve_mixed<E>(&mut self, entity: &E, additional: usize)
+    where
+        E: registry::contains::mixed_entity::Storage,
+    {
+        // SAFETY: The components present in `entity` are the same as the components in
+        // `self.components`, in the same order, since both are in the registry's order. Also,
+        // `self.components` and `self.length` make up valid `Vec<C>`s for each component.
+        unsafe { entity.reserve_components(&mut self.components, self.length, additional) }
+
+        let mut entity_identifiers = ManuallyDrop::new(
+            // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+            // `Vec` of size `self.length`.
+            unsafe {
+                Vec::from_raw_parts(
+                    self.entity_identifiers.0,
+                    self.length,
+                    self.entity_identifiers.1,
+                )
+            },
+        );
+        entity_identifiers.reserve(additional);
+        self.entity_identifiers = (
+            entity_identifiers.as_mut_ptr(),
+            entity_identifiers.capacity(),
+        );
+    }
+
+    /// Returns the number of entities this `Archetype` can hold without reallocating.
+    ///
+    /// This is the smallest capacity of the entity identifier column and the component columns.
+    pub(crate) fn capacity(&self) -> usize {
+        self.components
+            .iter()
+            .map(|&(_, capacity)| capacity)
+            .fold(self.entity_identifiers.1, usize::min)
+    }
+
+    /// Returns an estimate of the number of bytes allocated for the component columns and entity
+    /// identifier column.
+    pub(crate) fn memory_usage(&self) -> usize {
+        // SAFETY: `self.components` has the same number of values as there are set bits in
+        // `self.identifier`. Also, each element in `self.components` is the pointer and capacity
+        // of a `Vec<C>` for each `C` identified by `self.identifier`.
+        //
+        // The `R` over which `self.identifier` is generic is the same `R` on which this function
+        // is being called.
+        let components_size =
+            unsafe { R::size_of_component_columns(&self.components, self.identifier.iter()) };
+        components_size + size_of::<entity::Identifier>() * self.entity_identifiers.1
+    }
+
     /// # Safety
     /// The `Archetype` must outlive the returned `IdentifierRef`.
     pub(crate) unsafe fn identifier(&self) -> IdentifierRef<R> {
@@ -763,12 +1678,28 @@ where
         unsafe { self.identifier.as_ref() }
     }
 
-    #[cfg(feature = "serde")]
-    #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
-    pub(crate) fn entity_identifiers(&self) -> impl Iterator<Item = &entity::Identifier> {
+    /// Returns the identifiers of the entities stored in this archetype, in the same order as
+    /// their components.
+    pub(crate) fn entity_identifiers(&self) -> &[entity::Identifier] {
         // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
         // `Vec` of size `self.length`.
-        unsafe { slice::from_raw_parts(self.entity_identifiers.0, self.length) }.iter()
+        unsafe { slice::from_raw_parts(self.entity_identifiers.0, self.length) }
+    }
+
+    /// Returns the identifiers of the entities stored in this archetype, without tying the
+    /// returned slice to the borrow of `self`.
+    ///
+    /// This allows the identifiers to be read while the archetype's components are viewed
+    /// mutably, since the identifiers are stored separately from the component columns.
+    ///
+    /// # Safety
+    /// The returned slice must not outlive this archetype, and no entities may be added to or
+    /// removed from this archetype while the returned slice is alive.
+    pub(crate) unsafe fn entity_identifiers_unbound<'a>(&self) -> &'a [entity::Identifier] {
+        // SAFETY: `self.entity_identifiers` is guaranteed to contain the raw parts for a valid
+        // `Vec` of size `self.length`. The safety contract of this method guarantees the slice
+        // will remain valid for as long as it is alive.
+        unsafe { slice::from_raw_parts(self.entity_identifiers.0, self.length) }
     }
 
     pub(crate) fn len(&self) -> usize {
@@ -778,6 +1709,123 @@ where
     pub(crate) fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Reinterpret this archetype using the registry `NewRegistry`.
+    ///
+    /// The component columns are moved into the positions defined by `NewRegistry` without
+    /// copying any components. The entities keep the same rows.
+    ///
+    /// # Safety
+    /// `positions` must contain, for each component in `R` in order, the index of that same
+    /// component within `NewRegistry`.
+    pub(crate) unsafe fn migrate<NewRegistry>(self, positions: &[usize]) -> Archetype<NewRegistry>
+    where
+        NewRegistry: Registry,
+    {
+        let archetype = ManuallyDrop::new(self);
+
+        let mut identifier = vec![0; (NewRegistry::LEN + 7) / 8];
+        let mut columns = Vec::with_capacity(archetype.components.len());
+        let mut components = archetype.components.iter();
+        // SAFETY: The `Iter` created here does not outlive `archetype.identifier`.
+        for (contained, &position) in unsafe { archetype.identifier.iter() }.zip(positions) {
+            if contained {
+                // SAFETY: `positions` contains a valid index within `NewRegistry` for each
+                // component, and `identifier` has a bit for each component in `NewRegistry`.
+                *unsafe { identifier.get_unchecked_mut(position / 8) } |= 1 << (position % 8);
+                // SAFETY: There is exactly one column in `components` for each bit set in the
+                // identifier.
+                columns.push((position, *unsafe { components.next().unwrap_unchecked() }));
+            }
+        }
+        // Columns are stored in the order of the components in the registry.
+        columns.sort_unstable_by_key(|(position, _)| *position);
+
+        // SAFETY: The `archetype` is not dropped, so its identifier is only dropped here.
+        drop(unsafe { ptr::read(&archetype.identifier) });
+        // SAFETY: The `archetype` is not dropped, so its components are only dropped here. The
+        // columns themselves have been copied into `columns`.
+        drop(unsafe { ptr::read(&archetype.components) });
+
+        // SAFETY: `identifier` is made up of `(NewRegistry::LEN + 7) / 8` bytes. The columns are
+        // ordered by the positions of their components within `NewRegistry`, and each is the raw
+        // parts of a valid `Vec<C>` of length `archetype.length` for the component `C` at that
+        // position, since `positions` maps each component to the same component within
+        // `NewRegistry`.
+        unsafe {
+            Archetype::from_raw_parts(
+                Identifier::new(identifier),
+                archetype.entity_identifiers,
+                columns.into_iter().map(|(_, column)| column).collect(),
+                archetype.length,
+            )
+        }
+    }
+}
+
+impl<R> Archetype<R>
+where
+    R: registry::Diff,
+{
+    /// Returns the changes to the components of the row at `index`, relative to the row at
+    /// `previous_index` within `previous`, along with whether any component changed.
+    ///
+    /// If `previous` is `None`, every component in the row is considered to be added.
+    ///
+    /// # Safety
+    /// `index` must be a valid index to a row in this archetype. If `previous` is `Some`, its
+    /// index must be a valid index to a row in its archetype.
+    pub(crate) unsafe fn row_changes(
+        &self,
+        index: usize,
+        previous: Option<(&Self, usize)>,
+    ) -> (R::Changes, bool)
+    where
+        R: registry::Diff,
+    {
+        if let Some((previous, previous_index)) = previous {
+            // SAFETY: `previous.components` and `self.components` contain the same number of
+            // values as there are bits set in their respective identifiers, and each contains the
+            // raw parts for valid `Vec<C>`s for each identified component `C`. `previous_index`
+            // and `index` are guaranteed by the safety contract of this method to be within the
+            // lengths of those `Vec`s.
+            //
+            // Both identifiers are generic over the same `R` upon which this function is being
+            // called.
+            unsafe {
+                R::row_changes(
+                    &previous.components,
+                    previous_index,
+                    previous.identifier.iter(),
+                    &self.components,
+                    index,
+                    self.identifier.iter(),
+                )
+            }
+        } else {
+            let empty_identifier =
+                // SAFETY: The buffer is of length `(R::LEN + 7) / 8`.
+                unsafe { Identifier::<R>::new(vec![0; (R::LEN + 7) / 8]) };
+            // SAFETY: `empty_identifier` has no bits set, so it correctly identifies the empty
+            // slice of components. `self.components` contains the same number of values as there
+            // are bits set in `self.identifier`, each containing the raw parts for valid
+            // `Vec<C>`s for each identified component `C`, and `index` is guaranteed by the safety
+            // contract of this method to be within their lengths.
+            //
+            // Both identifiers are generic over the same `R` upon which this function is being
+            // called.
+            unsafe {
+                R::row_changes(
+                    &[],
+                    0,
+                    empty_identifier.iter(),
+                    &self.components,
+                    index,
+                    self.identifier.iter(),
+                )
+            }
+        }
+    }
 }
 
 impl<R> Archetype<R>
@@ -832,4 +1880,55 @@ where
                 )
             }
     }
+
+    /// Compare the components of two `Archetype<R>`s, ignoring row order.
+    ///
+    /// This returns `true` if every row of components in `self` can be paired with a distinct,
+    /// equal row of components in `other`. Entity identifiers are not compared. Like
+    /// `component_eq()`, archetype identifiers are explicitly *not* compared.
+    ///
+    /// This is O(n^2) in the number of entities, since components are only required to implement
+    /// `PartialEq`.
+    ///
+    /// # Safety
+    /// `self.identifier()` must be equal to `other.identifier()`.
+    pub(crate) unsafe fn component_multiset_eq(&self, other: &Self) -> bool {
+        if self.length != other.length {
+            return false;
+        }
+
+        let mut matched = vec![false; other.length];
+        (0..self.length).all(|index| {
+            if let Some(other_index) = (0..other.length).find(|&other_index| {
+                // SAFETY: `other_index` is less than `matched.len()`, which is `other.length`.
+                !unsafe { *matched.get_unchecked(other_index) }
+                    // SAFETY: Since `self.identifier` is equal to `other.identifier`, the
+                    // components Vecs will contain the same number of values as there are bits in
+                    // `self.identifier`.
+                    //
+                    // `self.components` and `other.components` contain raw parts for valid
+                    // `Vec<C>`s for each identified component `C` of size `self.length` and
+                    // `other.length` respectively, and `index` and `other_index` are within those
+                    // bounds.
+                    //
+                    // `self.identifier` is generic over the same `R` upon which this function is
+                    // being called.
+                    && unsafe {
+                        R::component_row_eq(
+                            &self.components,
+                            index,
+                            &other.components,
+                            other_index,
+                            self.identifier.iter(),
+                        )
+                    }
+            }) {
+                // SAFETY: `other_index` is less than `matched.len()`, which is `other.length`.
+                *unsafe { matched.get_unchecked_mut(other_index) } = true;
+                true
+            } else {
+                false
+            }
+        })
+    }
 }
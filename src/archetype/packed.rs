@@ -0,0 +1,111 @@
+use crate::{
+    archetype::{
+        Archetype,
+        Identifier,
+    },
+    entity,
+    packed,
+    packed::{
+        read_bytes,
+        read_u64,
+        read_usize,
+        write_u64,
+        Read,
+        Write,
+    },
+    registry,
+};
+use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
+
+impl<R> Archetype<R>
+where
+    R: registry::Packed,
+{
+    /// Write the identifier, entity identifiers, and component columns of this archetype in the
+    /// packed layout.
+    pub(crate) fn write_packed<W>(&self, writer: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        writer.write_all(
+            // SAFETY: The slice created here does not outlive `self.identifier`.
+            unsafe { self.identifier.as_slice() },
+        )?;
+        write_u64(writer, self.length as u64)?;
+        for entity_identifier in self.entity_identifiers() {
+            write_u64(writer, entity_identifier.index as u64)?;
+            write_u64(writer, entity_identifier.generation)?;
+        }
+        // SAFETY: `self.components` contains the valid raw parts for a `Vec<C>` for each `C`
+        // identified by `self.identifier`, with length `self.length`. The `R` upon which this
+        // function is called is the same `R` that `self.identifier` is generic over.
+        unsafe {
+            R::write_components(
+                &self.components,
+                self.length,
+                self.identifier.iter(),
+                writer,
+            )
+        }
+    }
+
+    /// Read an archetype written by [`write_packed()`].
+    ///
+    /// [`write_packed()`]: Archetype::write_packed()
+    pub(crate) fn read_packed<Rd>(reader: &mut Rd) -> Result<Self, packed::Error<Rd::Error>>
+    where
+        Rd: Read + ?Sized,
+    {
+        let identifier_bytes = read_bytes(reader, (R::LEN + 7) / 8).map_err(packed::Error::Read)?;
+        // Bits beyond the components of the registry must not be set.
+        if R::LEN % 8 != 0
+            && identifier_bytes
+                .last()
+                .map_or(false, |byte| byte >> (R::LEN % 8) != 0)
+        {
+            return Err(packed::Error::Invalid);
+        }
+        // SAFETY: `identifier_bytes` was read with a length of `(R::LEN + 7) / 8`.
+        let identifier = unsafe { Identifier::<R>::new(identifier_bytes) };
+
+        let length = read_usize(reader)?;
+        // The identifiers are pushed as they are read, rather than allocated up front, so that a
+        // corrupted `length` can't cause a large allocation.
+        let mut entity_identifiers = Vec::new();
+        for _ in 0..length {
+            let index = read_usize(reader)?;
+            let generation = read_u64(reader).map_err(packed::Error::Read)?;
+            entity_identifiers.push(entity::Identifier::new(index, generation));
+        }
+
+        let size = identifier
+            .size_of_components()
+            .checked_mul(length)
+            .ok_or(packed::Error::Invalid)?;
+        let bytes = read_bytes(reader, size).map_err(packed::Error::Read)?;
+        let mut components = Vec::with_capacity(identifier.count());
+        // SAFETY: `bytes` is `length` multiplied by the combined size of the components
+        // identified by `identifier`, and was written as those components' columns in order. The
+        // `R` upon which this function is called is the same `R` that `identifier` is generic
+        // over.
+        unsafe {
+            R::read_components(&mut components, &bytes, length, identifier.iter());
+        }
+
+        let mut entity_identifiers = ManuallyDrop::new(entity_identifiers);
+        // SAFETY: `entity_identifiers` contains `length` identifiers, and `components` contains a
+        // column of `length` components for each component identified by `identifier`.
+        Ok(unsafe {
+            Self::from_raw_parts(
+                identifier,
+                (
+                    entity_identifiers.as_mut_ptr(),
+                    entity_identifiers.capacity(),
+                ),
+                components,
+                length,
+            )
+        })
+    }
+}
@@ -5,11 +5,13 @@ use crate::{
 use core::{
     fmt,
     fmt::Debug,
+    hash::BuildHasher,
 };
 
-impl<R> Debug for Archetypes<R>
+impl<R, S> Debug for Archetypes<R, S>
 where
     R: registry::Debug,
+    S: BuildHasher,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_map()
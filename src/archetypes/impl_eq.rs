@@ -2,11 +2,15 @@ use crate::{
     archetypes::Archetypes,
     registry,
 };
-use core::cmp;
+use core::{
+    cmp,
+    hash::BuildHasher,
+};
 
-impl<R> cmp::PartialEq for Archetypes<R>
+impl<R, S> cmp::PartialEq for Archetypes<R, S>
 where
     R: registry::PartialEq,
+    S: BuildHasher,
 {
     fn eq(&self, other: &Self) -> bool {
         if self.raw_archetypes.len() != other.raw_archetypes.len() {
@@ -28,4 +32,9 @@ where
     }
 }
 
-impl<R> cmp::Eq for Archetypes<R> where R: registry::Eq {}
+impl<R, S> cmp::Eq for Archetypes<R, S>
+where
+    R: registry::Eq,
+    S: BuildHasher,
+{
+}
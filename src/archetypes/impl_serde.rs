@@ -1,14 +1,20 @@
 use crate::{
+    archetype,
     archetype::Archetype,
     archetypes::Archetypes,
     registry,
+    registry::Registry,
 };
+use alloc::vec::Vec;
 use core::{
     cmp,
     fmt,
     format_args,
+    hash::BuildHasher,
     marker::PhantomData,
 };
+use fnv::FnvBuildHasher;
+use hashbrown::HashMap;
 use serde::{
     de,
     de::{
@@ -22,9 +28,10 @@ use serde::{
     Serializer,
 };
 
-impl<R> Serialize for Archetypes<R>
+impl<R, H> Serialize for Archetypes<R, H>
 where
     R: registry::Serialize,
+    H: BuildHasher,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -34,43 +41,111 @@ where
     }
 }
 
-pub(crate) struct DeserializeArchetypes<'a, R> {
+/// Serializes only the components contained in `Filter`.
+///
+/// Archetypes whose components are the same after filtering are serialized as a single archetype.
+/// The serialized form is the same as an `Archetypes<Filter>`.
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub(crate) struct SerializeFiltered<'a, R, Filter, Indices, H>
+where
+    R: Registry,
+{
+    archetypes: &'a Archetypes<R, H>,
+
+    filter: PhantomData<Filter>,
+    indices: PhantomData<Indices>,
+}
+
+impl<'a, R, Filter, Indices, H> SerializeFiltered<'a, R, Filter, Indices, H>
+where
+    R: Registry,
+{
+    pub(crate) fn new(archetypes: &'a Archetypes<R, H>) -> Self {
+        Self {
+            archetypes,
+
+            filter: PhantomData,
+            indices: PhantomData,
+        }
+    }
+}
+
+impl<R, Filter, Indices, H> Serialize for SerializeFiltered<'_, R, Filter, Indices, H>
+where
+    R: registry::SerializeFiltered<Filter, Indices>,
+    Filter: registry::Serialize,
+    H: BuildHasher,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut lookup = HashMap::<Vec<u8>, usize, FnvBuildHasher>::default();
+        let mut filtered_archetypes = Vec::<archetype::SerializeChunks<Filter>>::new();
+
+        for archetype in self.archetypes.iter() {
+            // SAFETY: The chunk will not outlive the archetype, and the archetype is not modified
+            // while the chunk exists.
+            let (identifier, chunk) = unsafe { archetype.filtered_chunk::<Filter, Indices>() };
+            let index = *lookup.entry(identifier).or_insert_with_key(|identifier| {
+                // SAFETY: `identifier` is of length `(Filter::LEN + 7) / 8`.
+                filtered_archetypes
+                    .push(unsafe { archetype::SerializeChunks::new(identifier.clone()) });
+                filtered_archetypes.len() - 1
+            });
+            // SAFETY: The chunk contains the columns of the components identified by the
+            // identifier it is grouped under.
+            unsafe {
+                filtered_archetypes.get_unchecked_mut(index).push(chunk);
+            }
+        }
+
+        serializer.collect_seq(filtered_archetypes)
+    }
+}
+
+pub(crate) struct DeserializeArchetypes<'a, R, H = FnvBuildHasher> {
     len: &'a mut usize,
     registry: PhantomData<R>,
+    hash_builder: PhantomData<H>,
 }
 
-impl<'a, R> DeserializeArchetypes<'a, R> {
+impl<'a, R, H> DeserializeArchetypes<'a, R, H> {
     pub(crate) fn new(len: &'a mut usize) -> Self {
         Self {
             len,
             registry: PhantomData,
+            hash_builder: PhantomData,
         }
     }
 }
 
-impl<'a, 'de, R> DeserializeSeed<'de> for DeserializeArchetypes<'a, R>
+impl<'a, 'de, R, H> DeserializeSeed<'de> for DeserializeArchetypes<'a, R, H>
 where
     R: registry::Deserialize<'de>,
+    H: BuildHasher + Clone + Default,
 {
-    type Value = Archetypes<R>;
+    type Value = Archetypes<R, H>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct ArchetypesVisitor<'a, 'de, R>
+        struct ArchetypesVisitor<'a, 'de, R, H>
         where
             R: registry::Deserialize<'de>,
         {
             len: &'a mut usize,
             registry: PhantomData<&'de R>,
+            hash_builder: PhantomData<H>,
         }
 
-        impl<'a, 'de, R> Visitor<'de> for ArchetypesVisitor<'a, 'de, R>
+        impl<'a, 'de, R, H> Visitor<'de> for ArchetypesVisitor<'a, 'de, R, H>
         where
             R: registry::Deserialize<'de>,
+            H: BuildHasher + Clone + Default,
         {
-            type Value = Archetypes<R>;
+            type Value = Archetypes<R, H>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("sequence of `Archetype`s with unique `Identifier`s")
@@ -80,8 +155,10 @@ where
             where
                 S: SeqAccess<'de>,
             {
-                let mut archetypes =
-                    Archetypes::with_capacity(cmp::min(seq.size_hint().unwrap_or(0), 4096));
+                let mut archetypes = Archetypes::with_capacity_and_hasher(
+                    cmp::min(seq.size_hint().unwrap_or(0), 4096),
+                    H::default(),
+                );
                 while let Some(archetype) = seq.next_element::<Archetype<R>>()? {
                     *self.len += archetype.len();
                     if let Err(archetype) = archetypes.insert(archetype) {
@@ -100,6 +177,7 @@ where
         deserializer.deserialize_seq(ArchetypesVisitor {
             len: self.len,
             registry: PhantomData,
+            hash_builder: PhantomData,
         })
     }
 }
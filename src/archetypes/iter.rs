@@ -3,7 +3,10 @@ use crate::{
     registry::Registry,
 };
 use core::marker::PhantomData;
-use hashbrown::raw::RawIter;
+use hashbrown::raw::{
+    RawIter,
+    RawTable,
+};
 
 pub(crate) struct Iter<'a, R>
 where
@@ -12,6 +15,8 @@ where
     lifetime: PhantomData<&'a ()>,
 
     raw_iter: RawIter<Archetype<R>>,
+    /// The number of archetypes remaining to be yielded by `raw_iter`.
+    remaining: usize,
 }
 
 impl<'a, R> Iter<'a, R>
@@ -22,6 +27,7 @@ where
         Self {
             lifetime: PhantomData,
 
+            remaining: raw_iter.len(),
             raw_iter,
         }
     }
@@ -34,7 +40,11 @@ where
     type Item = &'a Archetype<R>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
         self.raw_iter.next().map(|archetype_bucket| {
+            self.remaining -= 1;
             // SAFETY: The reference to the archetype stored in this bucket is guaranteed to be
             // unique.
             unsafe { archetype_bucket.as_ref() }
@@ -42,30 +52,84 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.raw_iter.size_hint()
+        (self.remaining, Some(self.remaining))
     }
 }
 
+/// A mutable iterator over archetypes.
+///
+/// Archetypes are yielded from the front using `raw_iter`, which visits buckets in ascending
+/// order, and from the back by scanning the buckets of `raw_archetypes` in descending order.
+/// Since both ends move toward each other, the iterator is finished once `remaining` archetypes
+/// have been yielded in total.
 pub(crate) struct IterMut<'a, R>
 where
     R: Registry,
 {
-    lifetime: PhantomData<&'a ()>,
+    raw_archetypes: &'a RawTable<Archetype<R>>,
 
     raw_iter: RawIter<Archetype<R>>,
+    /// The number of buckets that have not yet been scanned from the back.
+    back: usize,
+    /// The number of archetypes remaining to be yielded from either end.
+    remaining: usize,
 }
 
 impl<'a, R> IterMut<'a, R>
 where
     R: Registry,
 {
-    pub(super) fn new(raw_iter: RawIter<Archetype<R>>) -> Self {
+    /// # Safety
+    /// `raw_iter` must be an iterator over all of the buckets in `raw_archetypes`, and no other
+    /// references to the archetypes within `raw_archetypes` may exist for the lifetime `'a`.
+    pub(super) unsafe fn new(
+        raw_iter: RawIter<Archetype<R>>,
+        raw_archetypes: &'a RawTable<Archetype<R>>,
+    ) -> Self {
         Self {
-            lifetime: PhantomData,
+            raw_archetypes,
 
+            remaining: raw_iter.len(),
             raw_iter,
+            back: raw_archetypes.buckets(),
+        }
+    }
+}
+
+impl<'a, R> IterMut<'a, R>
+where
+    R: Registry,
+{
+    /// Returns an iterator over shared references to the archetypes that have not yet been
+    /// yielded by this iterator.
+    pub(crate) fn remaining(&self) -> Iter<'_, R> {
+        // The archetypes not yet yielded are not borrowed mutably, and the returned iterator
+        // borrows `self` to prevent them from being yielded while it exists.
+        Iter {
+            lifetime: PhantomData,
+
+            raw_iter: self.raw_iter.clone(),
+            remaining: self.remaining,
         }
     }
+
+    /// Limits this iterator to the first `len` archetypes that have not yet been yielded.
+    ///
+    /// If fewer than `len` archetypes remain, this has no effect.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        if len >= self.remaining {
+            return;
+        }
+        // The first archetype past the limit bounds the buckets that can be scanned from the
+        // back. Since `len` is less than `remaining`, this archetype has not been yielded from
+        // either end.
+        if let Some(archetype_bucket) = self.raw_iter.clone().nth(len) {
+            // SAFETY: `archetype_bucket` was obtained from `raw_iter`, which iterates over the
+            // buckets of `raw_archetypes`.
+            self.back = unsafe { self.raw_archetypes.bucket_index(&archetype_bucket) };
+        }
+        self.remaining = len;
+    }
 }
 
 impl<'a, R> Iterator for IterMut<'a, R>
@@ -75,7 +139,11 @@ where
     type Item = &'a mut Archetype<R>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
         self.raw_iter.next().map(|archetype_bucket| {
+            self.remaining -= 1;
             // SAFETY: The reference to the archetype stored in this bucket is guaranteed to be
             // unique.
             unsafe { archetype_bucket.as_mut() }
@@ -83,6 +151,31 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.raw_iter.size_hint()
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, R> DoubleEndedIterator for IterMut<'a, R>
+where
+    R: Registry,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // Since there is at least one archetype remaining, and `raw_iter` has only yielded
+        // archetypes from buckets below those remaining, a full bucket will be found before
+        // `back` reaches zero.
+        loop {
+            self.back -= 1;
+            // SAFETY: `back` is less than the number of buckets in `raw_archetypes`.
+            if unsafe { self.raw_archetypes.is_bucket_full(self.back) } {
+                self.remaining -= 1;
+                // SAFETY: `back` is less than the number of buckets in `raw_archetypes` and the
+                // bucket is full. The reference to the archetype stored in this bucket is
+                // guaranteed to be unique, since it has not been yielded from the front.
+                return Some(unsafe { self.raw_archetypes.bucket(self.back).as_mut() });
+            }
+        }
     }
 }
@@ -8,7 +8,10 @@ mod iter;
 mod par_iter;
 
 #[cfg(feature = "serde")]
-pub(crate) use impl_serde::DeserializeArchetypes;
+pub(crate) use impl_serde::{
+    DeserializeArchetypes,
+    SerializeFiltered,
+};
 pub(crate) use iter::IterMut;
 #[cfg(feature = "rayon")]
 pub(crate) use par_iter::ParIterMut;
@@ -20,11 +23,21 @@ use crate::{
         self,
         Entity,
     },
+    query::{
+        filter::{
+            And,
+            Signature,
+        },
+        view,
+    },
     registry,
     registry::{
+        contains::filter::Sealed as ContainsFilterSealed,
         Canonical,
+        ContainsQuery,
         Registry,
     },
+    world,
 };
 use alloc::vec::Vec;
 use core::{
@@ -43,52 +56,91 @@ use hashbrown::{
     HashSet,
 };
 use iter::Iter;
+#[cfg(feature = "rayon")]
+use rayon::iter::ParallelIterator;
 
-pub(crate) struct Archetypes<R>
+/// The archetypes stored in a `World`.
+///
+/// The archetypes are looked up by hashing their identifiers, as well as by the `TypeId` of
+/// entities and by foreign identifiers. All of these lookups use the hasher `S`.
+pub(crate) struct Archetypes<R, S = FnvBuildHasher>
 where
     R: Registry,
 {
     raw_archetypes: RawTable<Archetype<R>>,
-    hash_builder: FnvBuildHasher,
+    hash_builder: S,
 
-    type_id_lookup: HashMap<TypeId, archetype::IdentifierRef<R>, FnvBuildHasher>,
-    foreign_identifier_lookup: HashMap<&'static [u8], archetype::IdentifierRef<R>, FnvBuildHasher>,
+    type_id_lookup: HashMap<TypeId, archetype::IdentifierRef<R>, S>,
+    foreign_identifier_lookup: HashMap<&'static [u8], archetype::IdentifierRef<R>, S>,
+
+    /// The archetypes matching each cached query, keyed by the `TypeId` of the query's views and
+    /// filter.
+    ///
+    /// This is cleared whenever an archetype is added or removed.
+    query_cache: HashMap<TypeId, Vec<archetype::IdentifierRef<R>>, S>,
 }
 
 impl<R> Archetypes<R>
 where
     R: Registry,
 {
+    #[cfg(test)]
     pub(crate) fn new() -> Self {
+        Self::with_hasher(FnvBuildHasher::default())
+    }
+}
+
+impl<R, S> Archetypes<R, S>
+where
+    R: Registry,
+    S: Clone,
+{
+    pub(crate) fn with_hasher(hash_builder: S) -> Self {
         Self {
             raw_archetypes: RawTable::new(),
-            hash_builder: FnvBuildHasher::default(),
+            hash_builder: hash_builder.clone(),
 
-            type_id_lookup: HashMap::default(),
-            foreign_identifier_lookup: HashMap::default(),
+            type_id_lookup: HashMap::with_hasher(hash_builder.clone()),
+            foreign_identifier_lookup: HashMap::with_hasher(hash_builder.clone()),
+
+            query_cache: HashMap::with_hasher(hash_builder),
         }
     }
 
-    pub(crate) fn with_capacity(capacity: usize) -> Self {
+    pub(crate) fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
         Self {
             raw_archetypes: RawTable::with_capacity(capacity),
-            hash_builder: FnvBuildHasher::default(),
+            hash_builder: hash_builder.clone(),
 
-            type_id_lookup: HashMap::with_capacity_and_hasher(capacity, FnvBuildHasher::default()),
+            type_id_lookup: HashMap::with_capacity_and_hasher(capacity, hash_builder.clone()),
             foreign_identifier_lookup: HashMap::with_capacity_and_hasher(
                 capacity,
-                FnvBuildHasher::default(),
+                hash_builder.clone(),
             ),
+
+            query_cache: HashMap::with_hasher(hash_builder),
         }
     }
+}
 
-    fn make_hash(identifier: archetype::IdentifierRef<R>, hash_builder: &FnvBuildHasher) -> u64 {
+impl<R, S> Archetypes<R, S>
+where
+    R: Registry,
+    S: BuildHasher,
+{
+    /// Hash an archetype identifier for lookup within `raw_archetypes`.
+    ///
+    /// The bytes of the identifier are hashed, rather than its address, so that the layout of the
+    /// table, and therefore its iteration order, does not depend on where the identifiers happen
+    /// to be allocated.
+    fn make_hash(identifier: archetype::IdentifierRef<R>, hash_builder: &S) -> u64 {
         let mut state = hash_builder.build_hasher();
-        identifier.hash(&mut state);
+        // SAFETY: The slice created here does not outlive `identifier`.
+        unsafe { identifier.as_slice() }.hash(&mut state);
         state.finish()
     }
 
-    fn make_hasher(hash_builder: &FnvBuildHasher) -> impl Fn(&Archetype<R>) -> u64 + '_ {
+    fn make_hasher(hash_builder: &S) -> impl Fn(&Archetype<R>) -> u64 + '_ {
         move |archetype| {
             Self::make_hash(
                 // SAFETY: The `IdentifierRef` obtained here does not live longer than the
@@ -176,6 +228,7 @@ where
                     identifier_buffer.as_ref(),
                 );
             }
+            self.query_cache.clear();
             self.raw_archetypes.insert_entry(
                 // SAFETY: The `IdentifierRef` created here does not outlive the
                 // `identifier_buffer`.
@@ -186,6 +239,49 @@ where
         }
     }
 
+    /// Returns a reference to the `Archetype` storing entities of type `E`.
+    ///
+    /// If no `Archetype` exists for the entity, `None` is returned.
+    pub(crate) fn get_for_entity<E, P>(&self) -> Option<&Archetype<R>>
+    where
+        E: Entity,
+        R: Canonical<E, P>,
+    {
+        if let Some(&identifier) = self.type_id_lookup.get(&TypeId::of::<E>()) {
+            self.get(identifier)
+        } else {
+            // Although type id lookup failed, that doesn't mean the archetype does not exist. We
+            // instead look up by the raw slice using `foreign_identifier_lookup`.
+            let identifier_buffer = R::create_archetype_identifier();
+            self.get(*self.foreign_identifier_lookup.get(
+                // SAFETY: The slice created here does not outlive the `identifier_buffer`.
+                unsafe { identifier_buffer.as_slice() },
+            )?)
+        }
+    }
+
+    /// Returns a mutable reference to the `Archetype` storing entities of type `E`.
+    ///
+    /// If no `Archetype` exists for the entity, `None` is returned.
+    pub(crate) fn get_mut_for_entity<E, P>(&mut self) -> Option<&mut Archetype<R>>
+    where
+        E: Entity,
+        R: Canonical<E, P>,
+    {
+        if let Some(&identifier) = self.type_id_lookup.get(&TypeId::of::<E>()) {
+            self.get_mut(identifier)
+        } else {
+            // Although type id lookup failed, that doesn't mean the archetype does not exist. We
+            // instead look up by the raw slice using `foreign_identifier_lookup`.
+            let identifier_buffer = R::create_archetype_identifier();
+            let identifier = *self.foreign_identifier_lookup.get(
+                // SAFETY: The slice created here does not outlive the `identifier_buffer`.
+                unsafe { identifier_buffer.as_slice() },
+            )?;
+            self.get_mut(identifier)
+        }
+    }
+
     pub(crate) unsafe fn get_mut_or_insert_new_for_entity<E, P>(&mut self) -> &mut Archetype<R>
     where
         E: Entity,
@@ -234,6 +330,7 @@ where
                         identifier_buffer.as_ref(),
                     );
                 }
+                self.query_cache.clear();
                 self.raw_archetypes.insert_entry(
                     // SAFETY: The `IdentifierRef` created here does not outlive the
                     // `identifier_buffer`.
@@ -254,6 +351,45 @@ where
         }
     }
 
+    /// Returns the hash of the given archetype identifier, as used when looking up archetypes.
+    pub(crate) fn hash_identifier(&self, identifier: archetype::IdentifierRef<R>) -> u64 {
+        Self::make_hash(identifier, &self.hash_builder)
+    }
+
+    /// Returns the `Archetype` storing entities of type `E`, first looking it up directly using
+    /// the bytes of its identifier and their precomputed `hash`.
+    ///
+    /// If no archetype is found this way, this falls back to
+    /// [`get_mut_or_insert_new_for_entity()`], inserting a new archetype if necessary.
+    ///
+    /// # Safety
+    /// `identifier` must be the bytes of the archetype identifier for entities of type `E`.
+    ///
+    /// [`get_mut_or_insert_new_for_entity()`]: Archetypes::get_mut_or_insert_new_for_entity()
+    pub(crate) unsafe fn get_mut_or_insert_new_for_entity_hashed<E, P>(
+        &mut self,
+        identifier: &[u8],
+        hash: u64,
+    ) -> &mut Archetype<R>
+    where
+        E: Entity,
+        R: Canonical<E, P>,
+    {
+        if let Some(archetype_bucket) = self.raw_archetypes.find(hash, |archetype| {
+            // SAFETY: The slice created here does not outlive the `archetype`.
+            unsafe { archetype.identifier().as_slice() == identifier }
+        }) {
+            // SAFETY: This reference to the archetype contained in this bucket is unique. Since
+            // its identifier is the identifier for entities of type `E`, it stores entities of
+            // type `E`.
+            unsafe { archetype_bucket.as_mut() }
+        } else {
+            // SAFETY: The archetype obtained here is looked up using the identifier created from
+            // the entity `E`.
+            unsafe { self.get_mut_or_insert_new_for_entity::<E, P>() }
+        }
+    }
+
     /// # Safety
     /// An archetype must be stored with the given `identifier`.
     pub(crate) unsafe fn get_unchecked_mut(
@@ -294,6 +430,7 @@ where
             }
             self.raw_archetypes
                 .insert(hash, archetype, Self::make_hasher(&self.hash_builder));
+            self.query_cache.clear();
             Ok(())
         }
     }
@@ -306,10 +443,71 @@ where
     }
 
     pub(crate) fn iter_mut(&mut self) -> IterMut<R> {
-        IterMut::new(
-            // SAFETY: The `IterMut` containing this `RawIter` is guaranteed to not outlive `self`.
-            unsafe { self.raw_archetypes.iter() },
-        )
+        // SAFETY: The `RawIter` iterates over all buckets of `raw_archetypes`, and the `IterMut`
+        // containing it is guaranteed to not outlive `self`. Since `self` is borrowed mutably, no
+        // other references to the archetypes exist while the `IterMut` exists.
+        unsafe { IterMut::new(self.raw_archetypes.iter(), &self.raw_archetypes) }
+    }
+
+    /// Returns the archetypes matching the query defined by `Views` and `Filter`.
+    ///
+    /// The matching archetypes are cached, so that they only need to be found again after an
+    /// archetype has been added or removed.
+    pub(crate) fn cached_query<'a, Views, Filter, Indices>(
+        &'a mut self,
+    ) -> Vec<&'a mut Archetype<R>>
+    where
+        Views: view::Views<'a> + Signature,
+        Filter: Signature,
+        R: ContainsQuery<'a, Filter, Views, Indices>,
+    {
+        let raw_archetypes = &self.raw_archetypes;
+        let identifiers = self
+            .query_cache
+            .entry(TypeId::of::<(Views::Static, Filter::Static)>())
+            .or_insert_with(|| {
+                // SAFETY: The `RawIter` is not used after `raw_archetypes` is borrowed mutably.
+                unsafe { raw_archetypes.iter() }
+                    .map(|archetype_bucket| {
+                        // SAFETY: The `IdentifierRef` obtained here is removed from the cache
+                        // before its archetype is removed.
+                        unsafe { archetype_bucket.as_ref().identifier() }
+                    })
+                    .filter(|&identifier| {
+                        // SAFETY: The `R` on which `filter()` is called is the same `R` over which
+                        // the identifier is generic over.
+                        unsafe {
+                            <R as ContainsFilterSealed<
+                                And<Views, Filter>,
+                                And<R::ViewsFilterIndices, R::FilterIndices>,
+                            >>::filter(identifier)
+                        }
+                    })
+                    .collect()
+            });
+
+        // All buckets are found before any mutable references are created, so that no archetype
+        // is read while a mutable reference to it exists.
+        let archetype_buckets = identifiers
+            .iter()
+            .map(|&identifier| {
+                match self.raw_archetypes.find(
+                    Self::make_hash(identifier, &self.hash_builder),
+                    Self::equivalent_identifier(identifier),
+                ) {
+                    Some(archetype_bucket) => archetype_bucket,
+                    // SAFETY: The cache is cleared whenever an archetype is removed, so every
+                    // cached identifier is guaranteed to have an associated archetype.
+                    None => unsafe { unreachable_unchecked() },
+                }
+            })
+            .collect::<Vec<_>>();
+        archetype_buckets
+            .into_iter()
+            // SAFETY: Each archetype is cached at most once, so each bucket is distinct and the
+            // reference to the archetype stored in each bucket is unique.
+            .map(|archetype_bucket| unsafe { archetype_bucket.as_mut() })
+            .collect()
     }
 
     /// # Safety
@@ -322,11 +520,181 @@ where
         }
     }
 
+    /// Move all entities from `other` into these `Archetypes`, allocating new entity identifiers
+    /// for them.
+    ///
+    /// Returns pairs of each moved entity's identifier within `other` and its newly allocated
+    /// identifier. The `callbacks` are called for every component of each moved entity. After
+    /// this method is called, every archetype in `other` will be empty.
+    ///
+    /// # Safety
+    /// The `entity_allocator`, together with its contained `Location`s, must not outlive `self`.
+    pub(crate) unsafe fn append<T>(
+        &mut self,
+        other: &mut Archetypes<R, T>,
+        entity_allocator: &mut entity::Allocator<R>,
+        callbacks: &mut world::Callbacks,
+    ) -> Vec<(entity::Identifier, entity::Identifier)>
+    where
+        T: BuildHasher,
+    {
+        let mut entity_identifiers = Vec::new();
+        for other_archetype in other.iter_mut() {
+            if other_archetype.is_empty() {
+                continue;
+            }
+            let identifier_buffer =
+                // SAFETY: The `IdentifierRef` does not outlive `other_archetype`. Also, the bytes
+                // of the identifier are of the correct length, since both identifiers are generic
+                // over the same registry `R`.
+                unsafe { archetype::Identifier::new(other_archetype.identifier().as_vec()) };
+            let archetype = self.get_mut_or_insert_new(identifier_buffer);
+            let start = archetype.len();
+            // SAFETY: The archetype is obtained using the same identifier as `other_archetype`.
+            // Also, `entity_allocator` is guaranteed to not outlive `self`.
+            entity_identifiers
+                .extend(unsafe { archetype.append(other_archetype, entity_allocator) });
+            // SAFETY: The entities were just appended as the last rows of the archetype.
+            unsafe {
+                archetype.notify_rows(start..archetype.len(), callbacks);
+            }
+        }
+        entity_identifiers
+    }
+
+    /// Reserve capacity for at least `additional` more entities in each archetype.
+    pub(crate) fn reserve_all(&mut self, additional: usize) {
+        for archetype in self.iter_mut() {
+            archetype.reserve_by_identifier(additional);
+        }
+    }
+
     /// Decrease the allocated capacity to the smallest amount required for the stored data.
     ///
     /// This may not decrease to the most optimal value, as the shrinking is dependent on the
     /// allocator.
     pub(crate) fn shrink_to_fit(&mut self) {
+        self.shrink_with(Archetype::shrink_to_fit);
+    }
+
+    /// Decrease the allocated capacity to the smallest amount required for the stored data,
+    /// shrinking the archetypes in parallel.
+    ///
+    /// Empty archetypes are removed afterward, as with [`shrink_to_fit()`].
+    ///
+    /// [`shrink_to_fit()`]: Archetypes::shrink_to_fit()
+    #[cfg(feature = "rayon")]
+    pub(crate) fn par_shrink_to_fit(&mut self)
+    where
+        R: Send,
+    {
+        self.par_iter_mut()
+            .filter(|archetype| !archetype.is_empty())
+            .for_each(Archetype::shrink_to_fit);
+        self.shrink_with(|_| {});
+    }
+
+    /// Decrease the allocated capacity of each archetype whose capacity exceeds its length
+    /// multiplied by `min_capacity_ratio`.
+    ///
+    /// Those archetypes are shrunk to a capacity of their length multiplied by
+    /// `min_capacity_ratio`, rounded down, leaving headroom for future growth. Archetypes within
+    /// the ratio are left unchanged. Empty archetypes are removed, as with [`shrink_to_fit()`].
+    ///
+    /// [`shrink_to_fit()`]: Archetypes::shrink_to_fit()
+    // Precision is only lost for archetypes far larger than any that can practically be allocated.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub(crate) fn shrink_to(&mut self, min_capacity_ratio: f32) {
+        // `f32::max()` also replaces a NaN ratio with `1.0`.
+        let min_capacity_ratio = min_capacity_ratio.max(1.0);
+        self.shrink_with(|archetype| {
+            let min_capacity =
+                ((archetype.len() as f32 * min_capacity_ratio) as usize).max(archetype.len());
+            if archetype.capacity() > min_capacity {
+                archetype.shrink_to(min_capacity);
+            }
+        });
+    }
+
+    /// Remove all empty archetypes if they make up more than `max_empty_ratio` of all archetypes.
+    ///
+    /// The capacity of the remaining archetypes is left unchanged.
+    // Precision is only lost for more archetypes than can practically be allocated.
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn compact(&mut self, max_empty_ratio: f32) {
+        let empty = self.iter().filter(|archetype| archetype.is_empty()).count();
+        if empty as f32 > self.raw_archetypes.len() as f32 * max_empty_ratio {
+            self.shrink_with(|_| {});
+        }
+    }
+
+    /// Rebuild the table of archetypes, inserting the archetypes in order of their identifiers.
+    ///
+    /// Since archetypes are hashed by the contents of their identifiers, after this is called the
+    /// order in which the archetypes are iterated depends only on which archetypes are stored and
+    /// on the hasher, not on the order in which the archetypes were created or removed. The table
+    /// is also rebuilt with no excess capacity.
+    pub(crate) fn sort(&mut self) {
+        let mut archetypes = self.raw_archetypes.drain().collect::<Vec<_>>();
+        archetypes.sort_unstable_by(|a, b| {
+            // SAFETY: The slices created here do not outlive their archetypes.
+            unsafe { a.identifier().as_slice().cmp(b.identifier().as_slice()) }
+        });
+
+        let mut raw_archetypes = RawTable::with_capacity(archetypes.len());
+        for archetype in archetypes {
+            let hash = Self::make_hash(
+                // SAFETY: The `IdentifierRef` obtained here does not live longer than the
+                // `archetype`.
+                unsafe { archetype.identifier() },
+                &self.hash_builder,
+            );
+            raw_archetypes.insert(hash, archetype, Self::make_hasher(&self.hash_builder));
+        }
+        self.raw_archetypes = raw_archetypes;
+    }
+
+    /// Reinterpret every archetype using the registry `NewRegistry`.
+    ///
+    /// # Safety
+    /// `positions` must contain, for each component in `R` in order, the index of that same
+    /// component within `NewRegistry`.
+    pub(crate) unsafe fn migrate<NewRegistry>(
+        self,
+        positions: &[usize],
+    ) -> Archetypes<NewRegistry, S>
+    where
+        NewRegistry: Registry,
+        S: Clone,
+    {
+        let Self {
+            mut raw_archetypes,
+            hash_builder,
+            ..
+        } = self;
+        let mut archetypes =
+            Archetypes::with_capacity_and_hasher(raw_archetypes.len(), hash_builder);
+        for archetype in raw_archetypes.drain() {
+            // SAFETY: The safety contract of this method guarantees `positions` maps each
+            // component to the same component within `NewRegistry`.
+            let archetype = unsafe { archetype.migrate(positions) };
+            // SAFETY: Since each component of `R` is mapped to a distinct component of
+            // `NewRegistry`, distinct archetypes are migrated to distinct identifiers. Therefore,
+            // no archetype with the migrated archetype's identifier can already exist.
+            unsafe { archetypes.insert(archetype).unwrap_unchecked() };
+        }
+        archetypes
+    }
+
+    /// Remove all empty archetypes, calling `shrink` on every remaining archetype.
+    fn shrink_with<F>(&mut self, mut shrink: F)
+    where
+        F: FnMut(&mut Archetype<R>),
+    {
         let mut identifiers_to_erase = HashSet::with_hasher(FnvBuildHasher::default());
         let mut archetypes_to_erase = Vec::new();
         // SAFETY: The resulting `RawIter` is guaranteed to not outlive `self.raw_archetypes`.
@@ -343,7 +711,7 @@ where
                 );
                 archetypes_to_erase.push(archetype_bucket);
             } else {
-                archetype.shrink_to_fit();
+                shrink(archetype);
             }
         }
 
@@ -379,6 +747,9 @@ where
             self.foreign_identifier_lookup.remove(slice);
         }
 
+        if !archetypes_to_erase.is_empty() {
+            self.query_cache.clear();
+        }
         for archetype_bucket in archetypes_to_erase {
             // SAFETY: `archetype` is not used again after it is dropped from the table.
             unsafe {
@@ -389,11 +760,104 @@ where
         self.raw_archetypes
             .shrink_to(0, Self::make_hasher(&self.hash_builder));
     }
+
+    /// Decrease the allocated capacity of the `Archetype` storing entities of type `E` as much as
+    /// possible.
+    ///
+    /// If the `Archetype` is empty, it is removed entirely. If no `Archetype` exists for the
+    /// entity, nothing happens.
+    pub(crate) fn shrink_archetype_to_fit<E, P>(&mut self)
+    where
+        E: Entity,
+        R: Canonical<E, P>,
+    {
+        let identifier = if let Some(&identifier) = self.type_id_lookup.get(&TypeId::of::<E>()) {
+            identifier
+        } else {
+            // Although type id lookup failed, that doesn't mean the archetype does not exist. We
+            // instead look up by the raw slice using `foreign_identifier_lookup`.
+            let identifier_buffer = R::create_archetype_identifier();
+            match self.foreign_identifier_lookup.get(
+                // SAFETY: The slice created here does not outlive the `identifier_buffer`.
+                unsafe { identifier_buffer.as_slice() },
+            ) {
+                Some(&identifier) => identifier,
+                None => return,
+            }
+        };
+
+        let Some(archetype_bucket) = self.raw_archetypes.find(
+            Self::make_hash(identifier, &self.hash_builder),
+            Self::equivalent_identifier(identifier),
+        ) else {
+            // SAFETY: Any identifier contained in `type_id_lookup` or `foreign_identifier_lookup`
+            // is guaranteed to have an associated archetype.
+            unsafe { unreachable_unchecked() }
+        };
+        // SAFETY: The reference to the archetype stored in this bucket is guaranteed to be unique.
+        let archetype = unsafe { archetype_bucket.as_mut() };
+        if !archetype.is_empty() {
+            archetype.shrink_to_fit();
+            return;
+        }
+
+        // Removing from the lookups guarantees that the invariant that any entry in them
+        // corresponds to a valid archetype is still upheld.
+        self.type_id_lookup
+            .retain(|_, &mut other_identifier| other_identifier != identifier);
+        self.foreign_identifier_lookup.remove(
+            // SAFETY: The slice created here does not outlive `identifier`, as the archetype is
+            // only erased afterward.
+            unsafe { identifier.as_slice() },
+        );
+        // SAFETY: `archetype` is not used again after it is dropped from the table.
+        unsafe {
+            self.raw_archetypes.erase(archetype_bucket);
+        }
+        self.query_cache.clear();
+    }
 }
 
-impl<R> Archetypes<R>
+impl<R, S> Archetypes<R, S>
+where
+    R: registry::PartialEq,
+    S: BuildHasher,
+{
+    /// Compare the entities stored in two `Archetypes<R>`, ignoring entity identifiers.
+    ///
+    /// Archetypes are compared by the multiset of component rows they contain, meaning entities
+    /// are allowed to be stored in a different order in each. Empty archetypes are ignored.
+    pub(crate) fn component_multiset_eq(&self, other: &Self) -> bool {
+        self.iter()
+            .filter(|archetype| !archetype.is_empty())
+            .count()
+            == other
+                .iter()
+                .filter(|archetype| !archetype.is_empty())
+                .count()
+            && self
+                .iter()
+                .filter(|archetype| !archetype.is_empty())
+                .all(|archetype| {
+                    other
+                        .get_with_foreign(
+                            // SAFETY: The `IdentifierRef` obtained here does not live longer than
+                            // the `archetype`.
+                            unsafe { archetype.identifier() },
+                        )
+                        .map_or(false, |other_archetype|
+                            // SAFETY: Since the `other_archetype` was obtained using the
+                            // identifier from `archetype`, the identifiers are guaranteed to be
+                            // equal.
+                            unsafe { archetype.component_multiset_eq(other_archetype) })
+                })
+    }
+}
+
+impl<R, S> Archetypes<R, S>
 where
     R: registry::Clone,
+    S: BuildHasher + Clone,
 {
     /// Clone the archetypes.
     ///
@@ -410,7 +874,8 @@ where
     ) {
         let mut identifier_map =
             HashMap::with_capacity_and_hasher(self.raw_archetypes.len(), FnvBuildHasher::default());
-        let mut cloned_archetypes = Self::with_capacity(self.raw_archetypes.len());
+        let mut cloned_archetypes =
+            Self::with_capacity_and_hasher(self.raw_archetypes.len(), self.hash_builder.clone());
 
         for archetype in self.iter() {
             let cloned_archetype = archetype.clone();
@@ -535,7 +1000,10 @@ mod tests {
         archetypes::Archetypes,
         Registry,
     };
-    use alloc::vec;
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
 
     macro_rules! create_components {
         ($( $variants:ident ),*) => {
@@ -569,4 +1037,38 @@ mod tests {
 
         let archetype = archetypes.get_mut_or_insert_new(buffer_b);
     }
+
+    #[test]
+    fn sort_iteration_order() {
+        let identifiers = [
+            vec![1, 0, 0, 0],
+            vec![2, 0, 0, 0],
+            vec![0, 4, 0, 0],
+            vec![3, 2, 1, 0],
+        ];
+        let mut archetypes_a = Archetypes::<Registry>::new();
+        for identifier in &identifiers {
+            archetypes_a
+                .get_mut_or_insert_new(unsafe { archetype::Identifier::new(identifier.clone()) });
+        }
+        let mut archetypes_b = Archetypes::<Registry>::new();
+        for identifier in identifiers.iter().rev() {
+            archetypes_b
+                .get_mut_or_insert_new(unsafe { archetype::Identifier::new(identifier.clone()) });
+        }
+
+        archetypes_a.sort();
+        archetypes_b.sort();
+
+        assert_eq!(
+            archetypes_a
+                .iter()
+                .map(|archetype| unsafe { archetype.identifier() }.as_vec())
+                .collect::<Vec<_>>(),
+            archetypes_b
+                .iter()
+                .map(|archetype| unsafe { archetype.identifier() }.as_vec())
+                .collect::<Vec<_>>()
+        );
+    }
 }
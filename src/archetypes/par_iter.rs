@@ -53,7 +53,7 @@ where
     }
 }
 
-impl<R> Archetypes<R>
+impl<R, S> Archetypes<R, S>
 where
     R: Registry,
 {
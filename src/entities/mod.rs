@@ -42,9 +42,12 @@
 mod sealed;
 
 pub(crate) use sealed::Contains;
+#[cfg(feature = "rayon")]
+pub(crate) use sealed::ParStorage;
 
 use crate::{
     component,
+    entity,
     hlist::define_null,
 };
 use alloc::vec::Vec;
@@ -61,8 +64,8 @@ define_null!();
 /// `World`, that `World`'s [`Registry`] must include the `Component`s that make up an entity.
 ///
 /// Note that entities must consist of unique component types. Duplicate components are not
-/// supported. When multiple components of the same type are included in an entity, a `World` will
-/// only store one of those components.
+/// supported. Attempting to store entities containing multiple components of the same type in a
+/// `World` is rejected at compile time.
 ///
 /// # Example
 /// ``` rust
@@ -97,7 +100,9 @@ where
 /// guarantee that all columns are of the same length. In other words, this is a collection of
 /// entities separated column-wise into their components.
 ///
-/// A `Batch` is most often created using the [`entities!`] macro.
+/// A `Batch` is most often created using the [`entities!`] macro. A `Batch` can also be collected
+/// from an iterator of entities created at run-time, in which case each entity is transposed into
+/// the columns as it is yielded.
 ///
 /// # Example
 /// ``` rust
@@ -111,6 +116,32 @@ where
 /// let entities = entities!((Foo(42), Bar(false)), (Foo(100), Bar(true)));
 /// ```
 ///
+/// Collecting a `Batch` from an iterator:
+///
+/// ``` rust
+/// use brood::{
+///     entities::Batch,
+///     entity,
+///     Registry,
+///     World,
+/// };
+///
+/// // Define components.
+/// struct Foo(usize);
+/// struct Bar(bool);
+///
+/// let data = vec![(42, false), (100, true)];
+///
+/// let mut world = World::<Registry!(Foo, Bar)>::new();
+/// world.extend(
+///     data.into_iter()
+///         .map(|(foo, bar)| entity!(Foo(foo), Bar(bar)))
+///         .collect::<Batch<_>>(),
+/// );
+///
+/// assert_eq!(world.len(), 2);
+/// ```
+///
 /// [`Entities`]: crate::entities::Entities
 /// [`entities!`]: crate::entities!
 #[derive(Debug, Eq, PartialEq)]
@@ -195,6 +226,25 @@ impl<Entities> Batch<Entities> {
     }
 }
 
+impl<Entity> FromIterator<Entity> for Batch<<Entity as entity::Transpose>::Columns>
+where
+    Entity: entity::Entity,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Entity>,
+    {
+        let iter = iter.into_iter();
+        let mut entities = Entity::columns_with_capacity(iter.size_hint().0);
+        let mut len = 0;
+        for entity in iter {
+            entity.push_onto(&mut entities);
+            len += 1;
+        }
+        Self { entities, len }
+    }
+}
+
 /// Creates a batch of entities made from the same components.
 ///
 /// This macro allows multiple entities to be defined for efficient storage within a [`World`]. The
@@ -323,6 +373,24 @@ mod tests {
         assert_eq!(entities!((A(42), B('f')); 100).len(), 100);
     }
 
+    #[test]
+    fn batch_from_iter() {
+        assert_eq!(
+            (0..3)
+                .map(|i| crate::entity!(A(i), B('a')))
+                .collect::<Batch<_>>(),
+            entities!((A(0), B('a')), (A(1), B('a')), (A(2), B('a')))
+        );
+    }
+
+    #[test]
+    fn batch_from_iter_empty() {
+        let batch = core::iter::empty::<crate::Entity!(A, B)>().collect::<Batch<_>>();
+
+        assert_eq!(batch.len(), 0);
+        assert_eq!(batch, Batch::new((vec![], (vec![], Null))));
+    }
+
     #[test]
     #[should_panic]
     fn batch_new_unequal_lengths() {
@@ -1,8 +1,12 @@
 mod contains;
 mod length;
+#[cfg(feature = "rayon")]
+mod par_storage;
 mod storage;
 
 pub(crate) use contains::Contains;
+#[cfg(feature = "rayon")]
+pub(crate) use par_storage::ParStorage;
 
 use crate::{
     component::Component,
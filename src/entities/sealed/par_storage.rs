@@ -0,0 +1,68 @@
+use super::storage::extend_column;
+use crate::{
+    component::Component,
+    entities::Null,
+};
+use alloc::vec::Vec;
+
+/// A component column that can be sent to another thread.
+struct SendColumn<'a>(&'a mut (*mut u8, usize));
+
+// SAFETY: The column is only ever accessed as a `Vec<C>` where `C` is `Send`.
+unsafe impl Send for SendColumn<'_> {}
+
+/// A set of component columns that can be sent to another thread.
+struct SendColumns<'a>(&'a mut [(*mut u8, usize)]);
+
+// SAFETY: The columns are only ever accessed as `Vec<C>`s where each `C` is `Send`.
+unsafe impl Send for SendColumns<'_> {}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+pub trait ParStorage: Send {
+    /// Extend the component columns with the components contained in this heterogeneous list,
+    /// extending each column in parallel.
+    ///
+    /// This consumes the entities, moving the components into their appropriate columns.
+    ///
+    /// # Safety
+    /// The components in both the entities and `components` much correspond to the same components
+    /// in the same order.
+    ///
+    /// `components`, together with `length`, must define a valid `Vec<C>` for each component.
+    unsafe fn par_extend_components(self, components: &mut [(*mut u8, usize)], length: usize);
+}
+
+impl ParStorage for Null {
+    unsafe fn par_extend_components(self, _components: &mut [(*mut u8, usize)], _length: usize) {}
+}
+
+impl<C, E> ParStorage for (Vec<C>, E)
+where
+    C: Component + Send,
+    E: ParStorage,
+{
+    unsafe fn par_extend_components(self, components: &mut [(*mut u8, usize)], length: usize) {
+        let (component_column, remaining_columns) =
+            // SAFETY: `components` is guaranteed by the safety contract of this method to contain
+            // a column for component `C` as its first value.
+            unsafe { components.split_first_mut().unwrap_unchecked() };
+        let component_column = SendColumn(component_column);
+        let remaining_columns = SendColumns(remaining_columns);
+
+        rayon::join(
+            move || {
+                let component_column = component_column;
+                // SAFETY: `component_column` is guaranteed to be the column for component `C`,
+                // which together with `length` defines a valid `Vec<C>`.
+                unsafe { extend_column(self.0, component_column.0, length) };
+            },
+            move || {
+                let remaining_columns = remaining_columns;
+                // SAFETY: Since `components` and `length` meet the safety requirements for the
+                // current method body, the remaining columns will meet those same requirements
+                // for this method call.
+                unsafe { E::par_extend_components(self.1, remaining_columns.0, length) };
+            },
+        );
+    }
+}
@@ -3,7 +3,11 @@ use crate::{
     entities::Null,
 };
 use alloc::vec::Vec;
-use core::mem::ManuallyDrop;
+use core::mem::{
+    forget,
+    size_of,
+    ManuallyDrop,
+};
 
 pub trait Storage {
     /// Extend the component columns with the components contained in this heterogeneous list.
@@ -32,32 +36,45 @@ where
 {
     unsafe fn extend_components(self, components: &mut [(*mut u8, usize)], length: usize) {
         // SAFETY: `components` is guaranteed by the safety contract of this method to contain a
-        // column for component `C` as its first value.
-        let component_column = unsafe { components.get_unchecked_mut(0) };
-        // Check both length and capacity to ensure nothing is currently allocated.
-        if length == 0 && component_column.1 == 0 {
-            let mut v = ManuallyDrop::new(self.0);
-            *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
-        } else {
-            let mut v = ManuallyDrop::new(
-                // SAFETY: The `component_column` extracted from `components` is guaranteed to,
-                // together with `length`, define a valid `Vec<C>` for the current `C`, because the
-                // `component_column` extracted is guaranteed by the safety contract to correspond
-                // to the column for component `C`.
-                unsafe {
-                    Vec::<C>::from_raw_parts(
-                        component_column.0.cast::<C>(),
-                        length,
-                        component_column.1,
-                    )
-                },
-            );
-            v.extend(self.0);
-            *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
-        }
+        // column for component `C` as its first value, which together with `length` defines a
+        // valid `Vec<C>`.
+        unsafe { extend_column(self.0, components.get_unchecked_mut(0), length) };
         // SAFETY: Since `component_map`, `components`, and `length` all meet the safety
         // requirements for the current method body, they will meet those same requirements for
         // this method call.
         unsafe { E::extend_components(self.1, components.get_unchecked_mut(1..), length) };
     }
 }
+
+/// Extend a single component column with the components contained in `column`.
+///
+/// # Safety
+/// `component_column`, together with `length`, must define a valid `Vec<C>`.
+pub(super) unsafe fn extend_column<C>(
+    column: Vec<C>,
+    component_column: &mut (*mut u8, usize),
+    length: usize,
+) where
+    C: Component,
+{
+    if size_of::<C>() == 0 {
+        // Extending a `Vec` of zero-sized values only increases its length, which is tracked by
+        // the archetype. The column takes ownership of the components, and will drop them when
+        // the column's values are dropped.
+        forget(column);
+    } else if length == 0 && component_column.1 == 0 {
+        // Both length and capacity are checked to ensure nothing is currently allocated.
+        let mut v = ManuallyDrop::new(column);
+        *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+    } else {
+        let mut v = ManuallyDrop::new(
+            // SAFETY: `component_column` is guaranteed to, together with `length`, define a valid
+            // `Vec<C>`.
+            unsafe {
+                Vec::<C>::from_raw_parts(component_column.0.cast::<C>(), length, component_column.1)
+            },
+        );
+        v.extend(column);
+        *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+    }
+}
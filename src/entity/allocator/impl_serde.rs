@@ -15,8 +15,10 @@ use alloc::{
 };
 use core::{
     fmt,
+    hash::BuildHasher,
     marker::PhantomData,
 };
+use fnv::FnvBuildHasher;
 use serde::{
     de,
     de::{
@@ -68,6 +70,11 @@ where
         S: Serializer,
     {
         // Only serialize the freed slots. The rest are serialized within the archetypes.
+        //
+        // The generations of both the freed slots and the active slots are serialized, and the
+        // order of the freed slots is preserved. Deserializing therefore restores the exact same
+        // identifiers, and any entities inserted afterward are allocated the same identifiers
+        // they would have been allocated before serialization.
         let mut r#struct = serializer.serialize_struct("Allocator", 2)?;
         r#struct.serialize_field("length", &self.slots.len())?;
         r#struct.serialize_field("free", &SerializeFree(self))?;
@@ -76,25 +83,26 @@ where
 }
 
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
-pub(crate) struct DeserializeAllocator<'a, R>
+pub(crate) struct DeserializeAllocator<'a, R, H = FnvBuildHasher>
 where
     R: Registry,
 {
-    archetypes: &'a Archetypes<R>,
+    archetypes: &'a Archetypes<R, H>,
 }
 
-impl<'a, R> DeserializeAllocator<'a, R>
+impl<'a, R, H> DeserializeAllocator<'a, R, H>
 where
     R: Registry,
 {
-    pub(crate) fn new(archetypes: &'a Archetypes<R>) -> Self {
+    pub(crate) fn new(archetypes: &'a Archetypes<R, H>) -> Self {
         Self { archetypes }
     }
 }
 
-impl<'de, R> DeserializeSeed<'de> for DeserializeAllocator<'_, R>
+impl<'de, R, H> DeserializeSeed<'de> for DeserializeAllocator<'_, R, H>
 where
     R: Registry,
+    H: BuildHasher,
 {
     type Value = Allocator<R>;
 
@@ -137,16 +145,17 @@ where
             }
         }
 
-        struct DeserializeAllocatorVisitor<'a, R>
+        struct DeserializeAllocatorVisitor<'a, R, H>
         where
             R: Registry,
         {
-            archetypes: &'a Archetypes<R>,
+            archetypes: &'a Archetypes<R, H>,
         }
 
-        impl<'de, R> Visitor<'de> for DeserializeAllocatorVisitor<'_, R>
+        impl<'de, R, H> Visitor<'de> for DeserializeAllocatorVisitor<'_, R, H>
         where
             R: Registry,
+            H: BuildHasher,
         {
             type Value = Allocator<R>;
 
@@ -213,14 +222,15 @@ impl<R> Allocator<R>
 where
     R: Registry,
 {
-    fn from_serialized_parts<E>(
+    fn from_serialized_parts<E, H>(
         length: usize,
         free: Vec<entity::Identifier>,
-        archetypes: &Archetypes<R>,
+        archetypes: &Archetypes<R, H>,
         _deserializer: PhantomData<E>,
     ) -> Result<Self, E>
     where
         E: de::Error,
+        H: BuildHasher,
     {
         let mut slots = vec![None; length];
         for entity_identifier in &free {
@@ -247,7 +257,7 @@ where
 
         // Populate active slots from archetypes.
         for archetype in archetypes.iter() {
-            for (i, entity_identifier) in archetype.entity_identifiers().enumerate() {
+            for (i, entity_identifier) in archetype.entity_identifiers().iter().enumerate() {
                 let slot = slots.get_mut(entity_identifier.index).ok_or_else(|| {
                     de::Error::custom(format!(
                         "archetype entity index {} is out of bounds",
@@ -311,6 +321,7 @@ mod tests {
         archetype::Archetype,
         archetypes::Archetypes,
         entity,
+        world,
         Registry,
     };
     use alloc::vec;
@@ -438,15 +449,27 @@ mod tests {
                 Archetype::new(unsafe { archetype::Identifier::<Registry>::new(vec![3]) });
             unsafe {
                 let entity_identifier = archetype.push(entity!(A, B), &mut allocator); // index 0.
-                archetype.remove_row_unchecked(entity_identifier.index, &mut allocator); // remove index 0.
+                archetype.remove_row_unchecked(
+                    entity_identifier.index,
+                    &mut allocator,
+                    &mut world::MoveCallbacks::default(),
+                ); // remove index 0.
                 allocator.free_unchecked(entity_identifier);
                 archetype.push(entity!(A, B), &mut allocator); // index 0.
                 archetype.push(entity!(A, B), &mut allocator); // index 1.
                 let entity_identifier = archetype.push(entity!(A, B), &mut allocator); // index 2.
-                archetype.remove_row_unchecked(entity_identifier.index, &mut allocator); // remove index 2.
+                archetype.remove_row_unchecked(
+                    entity_identifier.index,
+                    &mut allocator,
+                    &mut world::MoveCallbacks::default(),
+                ); // remove index 2.
                 allocator.free_unchecked(entity_identifier);
                 let entity_identifier = archetype.push(entity!(A, B), &mut allocator); // index 2.
-                archetype.remove_row_unchecked(entity_identifier.index, &mut allocator); // remove index 2.
+                archetype.remove_row_unchecked(
+                    entity_identifier.index,
+                    &mut allocator,
+                    &mut world::MoveCallbacks::default(),
+                ); // remove index 2.
                 allocator.free_unchecked(entity_identifier);
             }
             assert_ok!(archetypes.insert(archetype));
@@ -514,15 +537,27 @@ mod tests {
                 Archetype::new(unsafe { archetype::Identifier::<Registry>::new(vec![3]) });
             unsafe {
                 let entity_identifier = archetype.push(entity!(A, B), &mut allocator); // index 0.
-                archetype.remove_row_unchecked(entity_identifier.index, &mut allocator); // remove index 0.
+                archetype.remove_row_unchecked(
+                    entity_identifier.index,
+                    &mut allocator,
+                    &mut world::MoveCallbacks::default(),
+                ); // remove index 0.
                 allocator.free_unchecked(entity_identifier);
                 archetype.push(entity!(A, B), &mut allocator); // index 0.
                 archetype.push(entity!(A, B), &mut allocator); // index 1.
                 let entity_identifier = archetype.push(entity!(A, B), &mut allocator); // index 2.
-                archetype.remove_row_unchecked(entity_identifier.index, &mut allocator); // remove index 2.
+                archetype.remove_row_unchecked(
+                    entity_identifier.index,
+                    &mut allocator,
+                    &mut world::MoveCallbacks::default(),
+                ); // remove index 2.
                 allocator.free_unchecked(entity_identifier);
                 let entity_identifier = archetype.push(entity!(A, B), &mut allocator); // index 2.
-                archetype.remove_row_unchecked(entity_identifier.index, &mut allocator); // remove index 2.
+                archetype.remove_row_unchecked(
+                    entity_identifier.index,
+                    &mut allocator,
+                    &mut world::MoveCallbacks::default(),
+                ); // remove index 2.
                 allocator.free_unchecked(entity_identifier);
             }
             assert_ok!(archetypes.insert(archetype));
@@ -13,8 +13,10 @@ pub(crate) use slot::Slot;
 
 use crate::{
     archetype,
+    archetypes::Archetypes,
     entity,
     registry::Registry,
+    world::SpawnError,
 };
 use alloc::{
     collections::VecDeque,
@@ -23,9 +25,16 @@ use alloc::{
 use core::{
     fmt,
     fmt::Debug,
+    hash::BuildHasher,
 };
 use fnv::FnvBuildHasher;
 use hashbrown::HashMap;
+#[cfg(feature = "rayon")]
+use rayon::iter::{
+    IndexedParallelIterator,
+    IntoParallelRefIterator,
+    ParallelIterator,
+};
 
 pub struct Allocator<R>
 where
@@ -65,12 +74,15 @@ where
         entity::Identifier::new(index, generation)
     }
 
+    /// Allocates an entity for each of the `locations`, appending their identifiers to
+    /// `identifiers`.
     #[inline]
     pub(crate) fn allocate_batch(
         &mut self,
         mut locations: Locations<R>,
-    ) -> Vec<entity::Identifier> {
-        let mut identifiers = Vec::with_capacity(locations.len());
+        identifiers: &mut Vec<entity::Identifier>,
+    ) {
+        identifiers.reserve(locations.len());
 
         // First activate slots that are already allocated.
         while let Some(index) = self.free.pop_front() {
@@ -95,8 +107,61 @@ where
         identifiers.extend(
             (0..remaining_locations).map(|index| entity::Identifier::new(slots_len + index, 0)),
         );
+    }
+
+    /// Checks whether an entity can be allocated with exactly the given `identifier`.
+    ///
+    /// Returns [`SpawnError::Occupied`] if the slot at the identifier's index is active, and
+    /// [`SpawnError::GenerationRegression`] if the identifier's generation is older than the next
+    /// generation the slot would otherwise be given. Indices beyond the currently allocated slots
+    /// are always vacant.
+    pub(crate) fn check_vacant(&self, identifier: entity::Identifier) -> Result<(), SpawnError> {
+        if let Some(slot) = self.slots.get(identifier.index) {
+            if slot.is_active() {
+                return Err(SpawnError::Occupied);
+            }
+            if identifier.generation < slot.next_generation() {
+                return Err(SpawnError::GenerationRegression);
+            }
+        }
+        Ok(())
+    }
+
+    /// Allocate an entity with exactly the given `identifier`.
+    ///
+    /// If the identifier's index is beyond the currently allocated slots, vacant slots are created
+    /// to fill the gap and are made available for future allocations.
+    ///
+    /// # Safety
+    /// [`check_vacant()`] must have returned `Ok` for `identifier`, and the allocator must not have
+    /// been modified since.
+    ///
+    /// [`check_vacant()`]: Allocator::check_vacant()
+    pub(crate) unsafe fn allocate_at_unchecked(
+        &mut self,
+        identifier: entity::Identifier,
+        location: Location<R>,
+    ) {
+        if identifier.index >= self.slots.len() {
+            self.free.extend(self.slots.len()..=identifier.index);
+            self.slots.resize_with(identifier.index + 1, Slot::vacant);
+        }
+
+        let slot =
+            // SAFETY: The slots were extended above to include `identifier.index`.
+            unsafe { self.slots.get_unchecked_mut(identifier.index) };
+        // SAFETY: `check_vacant()` is guaranteed to have confirmed that the slot is not active.
+        unsafe { slot.activate_at_unchecked(location, identifier.generation) };
 
-        identifiers
+        // Every inactive slot's index is contained in `self.free`. Slots created to fill a gap are
+        // at the back, so the search is started from there.
+        if let Some(position) = self
+            .free
+            .iter()
+            .rposition(|&index| index == identifier.index)
+        {
+            self.free.remove(position);
+        }
     }
 
     pub(crate) fn get(&self, identifier: entity::Identifier) -> Option<Location<R>> {
@@ -108,6 +173,23 @@ where
         }
     }
 
+    /// Get the location of the entity identified by `identifier`, skipping checks for whether the
+    /// allocation exists.
+    ///
+    /// # Safety
+    /// `identifier` must be for a valid, currently allocated entity.
+    pub(crate) unsafe fn get_unchecked(&self, identifier: entity::Identifier) -> Location<R> {
+        // SAFETY: `identifier` is guaranteed by the safety contract of this method to identify a
+        // valid entity. Therefore, its `index` will correspond to a valid value within
+        // `self.slots`, and that slot will have a location.
+        unsafe {
+            self.slots
+                .get_unchecked(identifier.index)
+                .location
+                .unwrap_unchecked()
+        }
+    }
+
     pub(crate) fn is_active(&self, identifier: entity::Identifier) -> bool {
         if let Some(slot) = self.slots.get(identifier.index) {
             if slot.is_active() && slot.generation == identifier.generation {
@@ -117,6 +199,65 @@ where
         false
     }
 
+    /// Reinterpret this allocator using the registry `NewRegistry`, locating each active entity
+    /// within `archetypes`.
+    ///
+    /// Every slot keeps its generation, and the free slots are kept in the same order, so
+    /// existing identifiers remain valid and future allocations are unchanged.
+    ///
+    /// # Safety
+    /// `archetypes` must contain every active entity of this allocator, with each entity at the
+    /// same row as it was stored at before migration.
+    ///
+    /// The returned `Allocator` must not outlive `archetypes`.
+    pub(crate) unsafe fn migrate<NewRegistry, S>(
+        self,
+        archetypes: &Archetypes<NewRegistry, S>,
+    ) -> Allocator<NewRegistry>
+    where
+        NewRegistry: Registry,
+        S: BuildHasher,
+    {
+        let mut slots = self
+            .slots
+            .into_iter()
+            .map(|slot| Slot {
+                generation: slot.generation,
+                location: None,
+            })
+            .collect::<Vec<_>>();
+        for archetype in archetypes.iter() {
+            for (index, entity_identifier) in archetype.entity_identifiers().iter().enumerate() {
+                // SAFETY: Every entity in `archetypes` is guaranteed to be active within this
+                // allocator, so its index is within the bounds of `slots`.
+                unsafe { slots.get_unchecked_mut(entity_identifier.index) }.location =
+                    Some(Location::new(
+                        // SAFETY: The returned allocator is guaranteed to not outlive
+                        // `archetypes`, so the `IdentifierRef` will not outlive the archetype's
+                        // identifier.
+                        unsafe { archetype.identifier() },
+                        index,
+                    ));
+            }
+        }
+
+        Allocator {
+            slots,
+            free: self.free,
+        }
+    }
+
+    /// Returns a parallel iterator over the identifiers of all active entities.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    pub(crate) fn par_identifiers(&self) -> impl ParallelIterator<Item = entity::Identifier> + '_ {
+        self.slots
+            .par_iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.is_active())
+            .map(|(index, slot)| entity::Identifier::new(index, slot.generation))
+    }
+
     /// Free the entity allocation identified by `identifier`, skipping checks for whether the
     /// allocation exists.
     ///
@@ -175,6 +316,69 @@ where
         .index = index;
     }
 
+    /// Reconstruct an allocator with `length` slots from its freed slots, in the order they will
+    /// be reused, and from the entities stored in `archetypes`.
+    ///
+    /// Returns `None` if the freed slots and the stored entities do not describe each slot
+    /// exactly once.
+    pub(crate) fn from_parts<S>(
+        length: usize,
+        free: Vec<entity::Identifier>,
+        archetypes: &Archetypes<R, S>,
+    ) -> Option<Self>
+    where
+        S: BuildHasher,
+    {
+        // Checking the number of slots first ensures that `length` is not larger than the data
+        // that was actually provided.
+        if archetypes
+            .iter()
+            .map(archetype::Archetype::len)
+            .sum::<usize>()
+            + free.len()
+            != length
+        {
+            return None;
+        }
+
+        let mut slots = Vec::with_capacity(length);
+        slots.resize_with(length, || None);
+        for entity_identifier in &free {
+            let slot = slots.get_mut(entity_identifier.index)?;
+            if slot.is_some() {
+                return None;
+            }
+            *slot = Some(Slot {
+                generation: entity_identifier.generation,
+                location: None,
+            });
+        }
+        for archetype in archetypes.iter() {
+            for (index, entity_identifier) in archetype.entity_identifiers().iter().enumerate() {
+                let slot = slots.get_mut(entity_identifier.index)?;
+                if slot.is_some() {
+                    return None;
+                }
+                *slot = Some(Slot {
+                    generation: entity_identifier.generation,
+                    // SAFETY: The archetype is stored in `archetypes`, which will be stored
+                    // alongside this allocator, and will therefore outlive the location.
+                    location: Some(Location::new(unsafe { archetype.identifier() }, index)),
+                });
+            }
+        }
+
+        Some(Self {
+            // Since every slot was described at most once, and the number of descriptions is
+            // equal to the number of slots, every slot has been populated.
+            slots: slots.into_iter().collect::<Option<_>>()?,
+            free: free
+                .into_iter()
+                .map(|entity_identifier| entity_identifier.index)
+                .collect(),
+        })
+    }
+
     /// Decrease the allocated capacity to the smallest amount required for the stored data.
     ///
     /// This may not decrease to the most optimal value, as the shrinking is dependent on the
@@ -42,6 +42,17 @@ where
         }
     }
 
+    /// Creates an inactive slot that has never stored an entity.
+    ///
+    /// The generation is set so that the first activation of the slot results in a generation of
+    /// `0`, matching the generation of a slot created with [`Slot::new()`].
+    pub(super) fn vacant() -> Self {
+        Self {
+            generation: u64::MAX,
+            location: None,
+        }
+    }
+
     /// Activate this slot without checking whether it is already activated.
     ///
     /// If the current slot is already active, this will lead to invalidation of
@@ -57,6 +68,21 @@ where
         self.location = Some(location);
     }
 
+    /// Activate this slot with the given `generation`, without checking whether it is already
+    /// activated.
+    ///
+    /// # Safety
+    /// A `Slot` this method is called on must not already be active.
+    pub(super) unsafe fn activate_at_unchecked(&mut self, location: Location<R>, generation: u64) {
+        self.generation = generation;
+        self.location = Some(location);
+    }
+
+    /// Returns the generation that the next activation of this slot would use.
+    pub(super) fn next_generation(&self) -> u64 {
+        self.generation.wrapping_add(1)
+    }
+
     pub(super) fn deactivate(&mut self) {
         self.location = None;
     }
@@ -193,4 +219,29 @@ mod tests {
         assert_some_eq!(slot.location, new_location);
         assert!(slot.is_active());
     }
+
+    #[test]
+    fn vacant() {
+        let slot = Slot::<Registry>::vacant();
+
+        assert_none!(slot.location);
+        assert!(!slot.is_active());
+        assert_eq!(slot.next_generation(), 0);
+    }
+
+    #[test]
+    fn activate_at_unchecked() {
+        let identifier = unsafe { Identifier::<Registry>::new(vec![1, 2, 3, 0]) };
+        let location = Location::new(unsafe { identifier.as_ref() }, 42);
+        let mut slot = Slot::vacant();
+
+        unsafe {
+            slot.activate_at_unchecked(location, 5);
+        }
+
+        assert_eq!(slot.generation, 5);
+        assert_some_eq!(slot.location, location);
+        assert!(slot.is_active());
+        assert_eq!(slot.next_generation(), 6);
+    }
 }
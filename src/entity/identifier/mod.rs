@@ -41,6 +41,69 @@ impl Identifier {
     pub(crate) fn new(index: usize, generation: u64) -> Self {
         Self { index, generation }
     }
+
+    /// Returns the index of the entity's slot.
+    ///
+    /// Slots are reused after an entity is removed, meaning multiple `Identifier`s over the life
+    /// of a [`World`] may share the same index. Only one entity will occupy a slot at a time,
+    /// though, so the index is unique among the entities currently stored in a `World`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    ///
+    /// let first = world.insert(entity!(Foo(1)));
+    /// world.remove(first);
+    /// let second = world.insert(entity!(Foo(2)));
+    ///
+    /// // The removed entity's slot was reused.
+    /// assert_eq!(first.index(), second.index());
+    /// ```
+    ///
+    /// [`World`]: crate::world::World
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the generation of the entity's slot.
+    ///
+    /// The generation is incremented each time a slot is reused, distinguishing an `Identifier`
+    /// from any other `Identifier` sharing its [`index()`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    ///
+    /// let first = world.insert(entity!(Foo(1)));
+    /// world.remove(first);
+    /// let second = world.insert(entity!(Foo(2)));
+    ///
+    /// // The reused slot has a new generation.
+    /// assert_ne!(first.generation(), second.generation());
+    /// ```
+    ///
+    /// [`index()`]: Identifier::index()
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
 }
 
 #[cfg(test)]
@@ -60,4 +123,18 @@ mod tests {
 
         assert_eq!(identifier.generation, 2);
     }
+
+    #[test]
+    fn index() {
+        let identifier = Identifier::new(1, 2);
+
+        assert_eq!(identifier.index(), 1);
+    }
+
+    #[test]
+    fn generation() {
+        let identifier = Identifier::new(1, 2);
+
+        assert_eq!(identifier.generation(), 2);
+    }
 }
@@ -50,6 +50,7 @@ mod sealed;
 pub use identifier::Identifier;
 
 pub(crate) use allocator::Allocator;
+pub(crate) use sealed::Transpose;
 
 use crate::{
     component,
@@ -65,8 +66,23 @@ define_null!();
 /// `World`, that `World`'s [`Registry`] must include the `Component`s that make up an entity.
 ///
 /// Note that entities must consist of unique component types. Duplicate components are not
-/// supported. When multiple components of the same type are included in an entity, a `World` will
-/// only store one of those components.
+/// supported. Attempting to store an entity containing multiple components of the same type in a
+/// `World` is rejected at compile time, rather than silently discarding any of the components:
+///
+/// ``` compile_fail
+/// use brood::{
+///     entity,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Foo(usize);
+///
+/// let mut world = World::<Registry!(Foo)>::new();
+///
+/// // This does not compile, because `Foo` is included twice.
+/// world.insert(entity!(Foo(1), Foo(2)));
+/// ```
 ///
 /// # Example
 /// ``` rust
@@ -1,4 +1,7 @@
 mod storage;
+mod transpose;
+
+pub use transpose::Transpose;
 
 use crate::{
     component::Component,
@@ -6,7 +9,7 @@ use crate::{
 };
 use storage::Storage;
 
-pub trait Sealed: Storage {}
+pub trait Sealed: Storage + Transpose {}
 
 impl Sealed for Null {}
 
@@ -1,9 +1,8 @@
 use crate::{
+    archetype::column,
     component::Component,
     entity::Null,
 };
-use alloc::vec::Vec;
-use core::mem::ManuallyDrop;
 
 pub trait Storage {
     /// Push the components contained in this heterogeneous list into component columns.
@@ -32,6 +31,19 @@ pub trait Storage {
         length: usize,
         additional: usize,
     );
+
+    /// Reserve capacity for exactly `additional` more components in each component column.
+    ///
+    /// # Safety
+    /// The components in both the entity and `components` much correspond to the same components
+    /// in the same order.
+    ///
+    /// `components`, together with `length`, must define a valid `Vec<C>` for each component.
+    unsafe fn reserve_exact_components(
+        components: &mut [(*mut u8, usize)],
+        length: usize,
+        additional: usize,
+    );
 }
 
 impl Storage for Null {
@@ -43,6 +55,13 @@ impl Storage for Null {
         _additional: usize,
     ) {
     }
+
+    unsafe fn reserve_exact_components(
+        _components: &mut [(*mut u8, usize)],
+        _length: usize,
+        _additional: usize,
+    ) {
+    }
 }
 
 impl<C, E> Storage for (C, E)
@@ -52,17 +71,9 @@ where
 {
     unsafe fn push_components(self, components: &mut [(*mut u8, usize)], length: usize) {
         // SAFETY: `components` is guaranteed by the safety contract of this method to contain a
-        // column for component `C` as its first value.
-        let component_column = unsafe { components.get_unchecked_mut(0) };
-        let mut v = ManuallyDrop::new(
-            // SAFETY: The `component_column` extracted from `components` is guaranteed to,
-            // together with `length`, define a valid `Vec<C>` for the current `C`.
-            unsafe {
-                Vec::<C>::from_raw_parts(component_column.0.cast::<C>(), length, component_column.1)
-            },
-        );
-        v.push(self.0);
-        *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+        // column for component `C` as its first value, which together with `length` defines a
+        // valid `Vec<C>`.
+        unsafe { column::push(components.get_unchecked_mut(0), length, self.0) };
         // SAFETY: Since `components` and `length` all meet the safety requirements for the current
         // method body, they will meet those same requirements for this method call.
         unsafe { E::push_components(self.1, components.get_unchecked_mut(1..), length) };
@@ -74,19 +85,27 @@ where
         additional: usize,
     ) {
         // SAFETY: `components` is guaranteed by the safety contract of this method to contain a
-        // column for component `C` as its first value.
-        let component_column = unsafe { components.get_unchecked_mut(0) };
-        let mut v = ManuallyDrop::new(
-            // SAFETY: The `component_column` extracted from `components` is guaranteed to,
-            // together with `length`, define a valid `Vec<C>` for the current `C`.
-            unsafe {
-                Vec::<C>::from_raw_parts(component_column.0.cast::<C>(), length, component_column.1)
-            },
-        );
-        v.reserve(additional);
-        *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+        // column for component `C` as its first value, which together with `length` defines a
+        // valid `Vec<C>`.
+        unsafe { column::reserve::<C>(components.get_unchecked_mut(0), length, additional) };
         // SAFETY: Since `components` and `length` all meet the safety requirements for the current
         // method body, they will meet those same requirements for this method call.
         unsafe { E::reserve_components(components.get_unchecked_mut(1..), length, additional) };
     }
+
+    unsafe fn reserve_exact_components(
+        components: &mut [(*mut u8, usize)],
+        length: usize,
+        additional: usize,
+    ) {
+        // SAFETY: `components` is guaranteed by the safety contract of this method to contain a
+        // column for component `C` as its first value, which together with `length` defines a
+        // valid `Vec<C>`.
+        unsafe { column::reserve_exact::<C>(components.get_unchecked_mut(0), length, additional) };
+        // SAFETY: Since `components` and `length` all meet the safety requirements for the current
+        // method body, they will meet those same requirements for this method call.
+        unsafe {
+            E::reserve_exact_components(components.get_unchecked_mut(1..), length, additional);
+        };
+    }
 }
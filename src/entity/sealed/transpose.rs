@@ -0,0 +1,87 @@
+use crate::{
+    component::Component,
+    entities,
+    entity::Null,
+};
+use alloc::vec::Vec;
+
+/// Transposes entities into the columns of an [`Entities`] heterogeneous list.
+///
+/// [`Entities`]: crate::entities::Entities
+pub trait Transpose: Sized {
+    /// The columns that entities of this type are transposed into.
+    type Columns: entities::Entities;
+
+    /// Creates empty columns, each with space for at least `capacity` components.
+    fn columns_with_capacity(capacity: usize) -> Self::Columns;
+
+    /// Pushes each component of this entity onto the end of its column in `columns`.
+    fn push_onto(self, columns: &mut Self::Columns);
+}
+
+impl Transpose for Null {
+    type Columns = entities::Null;
+
+    fn columns_with_capacity(_capacity: usize) -> Self::Columns {
+        entities::Null
+    }
+
+    fn push_onto(self, _columns: &mut Self::Columns) {}
+}
+
+impl<C, E> Transpose for (C, E)
+where
+    C: Component,
+    E: Transpose,
+{
+    type Columns = (Vec<C>, E::Columns);
+
+    fn columns_with_capacity(capacity: usize) -> Self::Columns {
+        (
+            Vec::with_capacity(capacity),
+            E::columns_with_capacity(capacity),
+        )
+    }
+
+    fn push_onto(self, columns: &mut Self::Columns) {
+        columns.0.push(self.0);
+        self.1.push_onto(&mut columns.1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        entity,
+        Entity,
+    };
+    use alloc::vec;
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct A(u32);
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct B(char);
+
+    #[test]
+    fn columns_with_capacity() {
+        let columns = <Entity!(A, B)>::columns_with_capacity(10);
+
+        assert!(columns.0.capacity() >= 10);
+        assert!(columns.1 .0.capacity() >= 10);
+    }
+
+    #[test]
+    fn push_onto() {
+        let mut columns = <Entity!(A, B)>::columns_with_capacity(0);
+
+        entity!(A(1), B('a')).push_onto(&mut columns);
+        entity!(A(2), B('b')).push_onto(&mut columns);
+
+        assert_eq!(
+            columns,
+            (vec![A(1), A(2)], (vec![B('a'), B('b')], entities::Null))
+        );
+    }
+}
@@ -89,6 +89,11 @@
 //! the [`serde`](https://crates.io/crates/serde) library, assuming all components in the `World`'s
 //! [`Registry`] are also serializable and deserialzable.
 //!
+//! Entity identifiers are preserved exactly across serialization. Every [`entity::Identifier`]
+//! that was valid in the serialized `World` refers to the same entity in the deserialized `World`,
+//! and identifiers of removed entities remain invalid. This makes it safe to persist identifiers
+//! alongside a `World`, such as in save files or network messages.
+//!
 //! ## rayon
 //! Enabling the feature flag `rayon` allows for parallel operations on components.
 //!
@@ -96,6 +101,7 @@
 //! `brood` can be used in `no_std` contexts where
 //! [`alloc`](https://doc.rust-lang.org/alloc/index.html) is available.
 //!
+//! [`entity::Identifier`]: crate::entity::Identifier
 //! [`Registry`]: crate::registry::Registry
 
 #![no_std]
@@ -125,6 +131,7 @@ extern crate alloc;
 pub mod component;
 pub mod entities;
 pub mod entity;
+pub mod packed;
 pub mod query;
 pub mod registry;
 pub mod resource;
@@ -0,0 +1,35 @@
+use core::fmt;
+
+/// An error returned by [`World::read_packed()`].
+///
+/// `E` is the error type of the [`Read`]er the `World` was read from.
+///
+/// [`Read`]: crate::packed::Read
+/// [`World::read_packed()`]: crate::World::read_packed()
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error<E> {
+    /// The reader returned an error, such as reaching the end of its input.
+    Read(E),
+    /// The input was written for a registry with a different number of components.
+    RegistryMismatch,
+    /// The input does not describe a valid `World`.
+    ///
+    /// This can occur if the input is corrupted, or if it contains entities that are too large to
+    /// be stored on the current target.
+    Invalid,
+}
+
+impl<E> fmt::Display for Error<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(error) => write!(f, "failed to read packed world: {error}"),
+            Self::RegistryMismatch => {
+                f.write_str("packed world was written for a different registry")
+            }
+            Self::Invalid => f.write_str("packed world is invalid"),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! A compact binary layout for persisting the entities of a [`World`].
+//!
+//! Rather than serializing each component individually, as is done when using `serde`, the packed
+//! layout writes the raw bytes of each component column directly. This makes writing and reading
+//! a `World` little more than a copy of its storage, at the cost of the format being tied to the
+//! `World`'s [`Registry`] and to the memory layout of its components on the current target.
+//!
+//! Since component bytes are read back without any validation, only components implementing
+//! [`Pod`] can be stored in the packed layout. A `World` is written using [`World::write_packed()`]
+//! to any [`Write`]r, and is read using [`World::read_packed()`] from any [`Read`]er. Resources are
+//! not included in the packed layout.
+//!
+//! # Format
+//! All integers are written as little-endian `u64`s. A packed `World` consists of:
+//!
+//! - The number of components in the registry.
+//! - The number of entity slots that have been allocated, followed by the number of freed entity
+//!   slots and the index and generation of each freed slot, in the order they will be reused.
+//! - The number of archetypes, followed by each archetype. An archetype consists of its identifier
+//!   bytes, the number of entities it contains, the index and generation of each of those entities,
+//!   and then the raw bytes of each of its component columns, in registry order.
+//!
+//! # Example
+//! ``` rust
+//! use brood::{
+//!     entity,
+//!     packed::Pod,
+//!     Registry,
+//!     World,
+//! };
+//!
+//! #[derive(Clone, Copy, Debug, PartialEq)]
+//! #[repr(transparent)]
+//! struct Position(f32);
+//!
+//! // SAFETY: `Position` has the same layout as an `f32`.
+//! unsafe impl Pod for Position {}
+//!
+//! type Registry = Registry!(Position);
+//!
+//! let mut world = World::<Registry>::new();
+//! let entity_identifier = world.insert(entity!(Position(1.5)));
+//!
+//! let mut bytes = Vec::new();
+//! world.write_packed(&mut bytes).unwrap();
+//!
+//! let read_world = World::<Registry>::read_packed(&mut bytes.as_slice()).unwrap();
+//! assert_eq!(read_world, world);
+//! assert!(read_world.contains(entity_identifier));
+//! ```
+//!
+//! [`Registry`]: crate::registry::Registry
+//! [`World`]: crate::World
+//! [`World::read_packed()`]: crate::World::read_packed()
+//! [`World::write_packed()`]: crate::World::write_packed()
+
+mod error;
+mod pod;
+mod read;
+mod write;
+
+pub use error::Error;
+pub use pod::Pod;
+pub use read::{
+    Read,
+    UnexpectedEnd,
+};
+pub use write::Write;
+
+pub(crate) use read::{
+    read_bytes,
+    read_u64,
+    read_usize,
+};
+pub(crate) use write::write_u64;
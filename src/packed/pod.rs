@@ -0,0 +1,56 @@
+use crate::component::Component;
+
+/// A component that can be stored as its raw bytes.
+///
+/// Components implementing this trait can be written to and read from the [packed] layout.
+/// Reading a component from the packed layout reinterprets the bytes that were written as the
+/// component, without any validation.
+///
+/// # Safety
+/// Implementors must uphold the following:
+///
+/// - The type must not contain any padding bytes, as padding is not initialized and therefore
+///   cannot be written.
+/// - Every bit pattern of the type's size must be a valid value of the type. For example, `bool`
+///   and `char` do not implement this trait, since not all bit patterns are valid for them.
+/// - The type must not contain any pointers or references, since the memory they point to is not
+///   written alongside them.
+///
+/// A `#[repr(C)]` or `#[repr(transparent)]` struct made up of fields that are `Pod` and that
+/// contains no padding upholds these requirements.
+///
+/// # Example
+/// ``` rust
+/// use brood::packed::Pod;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Velocity {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// // SAFETY: `Velocity` is made up of `f32` fields and contains no padding.
+/// unsafe impl Pod for Velocity {}
+/// ```
+///
+/// [packed]: crate::packed
+pub unsafe trait Pod: Component + Copy {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(
+            // SAFETY: Primitive numeric types have no padding, and every bit pattern is valid.
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+// SAFETY: The unit type has no bytes at all.
+unsafe impl Pod for () {}
+
+// SAFETY: An array has no padding between its elements, and every bit pattern is valid if every
+// bit pattern of each element is valid.
+unsafe impl<T, const N: usize> Pod for [T; N] where T: Pod {}
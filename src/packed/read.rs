@@ -0,0 +1,171 @@
+use crate::packed;
+use alloc::vec::Vec;
+use core::{
+    cmp::min,
+    fmt,
+};
+
+/// A source of the bytes of the [packed] layout.
+///
+/// This serves the same purpose as `std::io::Read`, which is not available in `no_std` contexts.
+/// It is implemented for `&[u8]`, which advances past the bytes that are read, and can be
+/// implemented for any other source, such as a file.
+///
+/// # Example
+/// ``` rust
+/// use brood::packed::Read;
+///
+/// let mut bytes: &[u8] = &[1, 2, 3];
+/// let mut buffer = [0; 2];
+/// bytes.read_exact(&mut buffer).unwrap();
+///
+/// assert_eq!(buffer, [1, 2]);
+/// assert_eq!(bytes, [3]);
+/// ```
+///
+/// [packed]: crate::packed
+pub trait Read {
+    /// The error returned when the bytes cannot be read.
+    type Error;
+
+    /// Read exactly enough bytes to fill `buffer`.
+    ///
+    /// # Errors
+    /// Returns an error if `buffer` could not be filled, such as when the end of the input is
+    /// reached.
+    fn read_exact(&mut self, buffer: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// An error indicating the end of the input was reached before all bytes were read.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct UnexpectedEnd;
+
+impl fmt::Display for UnexpectedEnd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unexpected end of input")
+    }
+}
+
+impl Read for &[u8] {
+    type Error = UnexpectedEnd;
+
+    fn read_exact(&mut self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        if buffer.len() > self.len() {
+            return Err(UnexpectedEnd);
+        }
+        let (read, remaining) = self.split_at(buffer.len());
+        buffer.copy_from_slice(read);
+        *self = remaining;
+        Ok(())
+    }
+}
+
+impl<R> Read for &mut R
+where
+    R: Read + ?Sized,
+{
+    type Error = R::Error;
+
+    fn read_exact(&mut self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).read_exact(buffer)
+    }
+}
+
+/// Read a little-endian `u64`.
+pub(crate) fn read_u64<R>(reader: &mut R) -> Result<u64, R::Error>
+where
+    R: Read + ?Sized,
+{
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Read a little-endian `u64` as a `usize`.
+///
+/// Values that do not fit in a `usize` are invalid.
+pub(crate) fn read_usize<R>(reader: &mut R) -> Result<usize, packed::Error<R::Error>>
+where
+    R: Read + ?Sized,
+{
+    usize::try_from(read_u64(reader).map_err(packed::Error::Read)?)
+        .map_err(|_| packed::Error::Invalid)
+}
+
+/// The largest number of bytes read at once by [`read_bytes()`].
+const CHUNK_SIZE: usize = 4096;
+
+/// Read `length` bytes.
+///
+/// The bytes are read in chunks, so that a corrupted `length` can't cause more memory to be
+/// allocated than is actually provided by `reader`.
+pub(crate) fn read_bytes<R>(reader: &mut R, length: usize) -> Result<Vec<u8>, R::Error>
+where
+    R: Read + ?Sized,
+{
+    let mut bytes = Vec::with_capacity(min(length, CHUNK_SIZE));
+    while bytes.len() < length {
+        let start = bytes.len();
+        bytes.resize(start + min(length - start, CHUNK_SIZE), 0);
+        // SAFETY: `bytes` was just resized to be longer than `start`.
+        reader.read_exact(unsafe { bytes.get_unchecked_mut(start..) })?;
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        read_bytes,
+        read_u64,
+        Read,
+        UnexpectedEnd,
+    };
+    use claims::{
+        assert_err_eq,
+        assert_ok_eq,
+    };
+
+    #[test]
+    fn read_exact_slice() {
+        let mut bytes: &[u8] = &[1, 2, 3];
+        let mut buffer = [0; 3];
+
+        assert_ok_eq!(bytes.read_exact(&mut buffer), ());
+        assert_eq!(buffer, [1, 2, 3]);
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn read_exact_slice_unexpected_end() {
+        let mut bytes: &[u8] = &[1, 2];
+        let mut buffer = [0; 3];
+
+        assert_err_eq!(bytes.read_exact(&mut buffer), UnexpectedEnd);
+        assert_eq!(bytes, [1, 2]);
+    }
+
+    #[test]
+    fn read_u64_little_endian() {
+        let mut bytes: &[u8] = &[1, 2, 0, 0, 0, 0, 0, 0];
+
+        assert_ok_eq!(read_u64(&mut bytes), 0x0201);
+    }
+
+    #[test]
+    fn read_bytes_multiple_chunks() {
+        let source = (0..10_000)
+            .map(|i: u32| i.to_le_bytes()[0])
+            .collect::<alloc::vec::Vec<_>>();
+        let mut bytes = source.as_slice();
+
+        assert_ok_eq!(read_bytes(&mut bytes, 10_000), source);
+    }
+
+    #[test]
+    fn read_bytes_unexpected_end() {
+        let mut bytes: &[u8] = &[1, 2, 3];
+
+        assert_err_eq!(read_bytes(&mut bytes, usize::MAX), UnexpectedEnd);
+    }
+}
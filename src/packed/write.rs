@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+use core::convert::Infallible;
+
+/// A destination for the bytes of the [packed] layout.
+///
+/// This serves the same purpose as `std::io::Write`, which is not available in `no_std`
+/// contexts. It is implemented for `Vec<u8>`, and can be implemented for any other destination,
+/// such as a file.
+///
+/// # Example
+/// ``` rust
+/// use brood::packed::Write;
+///
+/// let mut bytes = Vec::new();
+/// bytes.write_all(&[1, 2, 3]).unwrap();
+///
+/// assert_eq!(bytes, [1, 2, 3]);
+/// ```
+///
+/// [packed]: crate::packed
+pub trait Write {
+    /// The error returned when the bytes cannot be written.
+    type Error;
+
+    /// Write all of `bytes`.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes could not all be written.
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+impl Write for Vec<u8> {
+    type Error = Infallible;
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+impl<W> Write for &mut W
+where
+    W: Write + ?Sized,
+{
+    type Error = W::Error;
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        (**self).write_all(bytes)
+    }
+}
+
+/// Write `value` as a little-endian `u64`.
+pub(crate) fn write_u64<W>(writer: &mut W, value: u64) -> Result<(), W::Error>
+where
+    W: Write + ?Sized,
+{
+    writer.write_all(&value.to_le_bytes())
+}
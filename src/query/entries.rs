@@ -5,8 +5,17 @@
 //!
 //! Access to components of entities is restricted to the specific components viewed. These views
 //! must not conflict with the components being iterated simultaneously.
+//!
+//! Components can also be added to and removed from entities through their entries. Since entities
+//! can't be moved while they are being iterated over, these changes are deferred until they are
+//! applied to the `World` using [`World::apply_deferred()`].
+//!
+//! [`World::apply_deferred()`]: crate::World::apply_deferred()
 
 use crate::{
+    archetype,
+    archetype::Archetype,
+    component,
     entity,
     entity::allocator::Location,
     query::{
@@ -15,14 +24,66 @@ use crate::{
         view::SubSet,
     },
     registry,
-    registry::contains::views::{
-        ContainsViewsOuter,
-        Sealed as ContainsViewsSealed,
+    registry::{
+        contains::views::{
+            ContainsViewsOuter,
+            Sealed as ContainsViewsSealed,
+        },
+        ContainsComponent,
     },
+    world::Deferred,
     Query,
     World,
 };
-use core::marker::PhantomData;
+use core::{
+    hash::BuildHasher,
+    marker::PhantomData,
+};
+
+/// Type-erased access to the parts of a [`World`] required for looking up entries.
+///
+/// This allows [`Entries`] to refer to a `World` without being generic over the hasher the
+/// `World` uses to look up its archetypes.
+pub(crate) trait ErasedWorld<Registry>
+where
+    Registry: registry::Registry,
+{
+    fn entity_allocator(&self) -> &entity::Allocator<Registry>;
+
+    fn archetype(
+        &self,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> Option<&Archetype<Registry>>;
+
+    fn archetype_mut(
+        &mut self,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> Option<&mut Archetype<Registry>>;
+}
+
+impl<Registry, Resources, S> ErasedWorld<Registry> for World<Registry, Resources, S>
+where
+    Registry: registry::Registry,
+    S: BuildHasher,
+{
+    fn entity_allocator(&self) -> &entity::Allocator<Registry> {
+        &self.entity_allocator
+    }
+
+    fn archetype(
+        &self,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> Option<&Archetype<Registry>> {
+        self.archetypes.get(identifier)
+    }
+
+    fn archetype_mut(
+        &mut self,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> Option<&mut Archetype<Registry>> {
+        self.archetypes.get_mut(identifier)
+    }
+}
 
 /// A view into a single entity in a [`World`].
 ///
@@ -32,6 +93,7 @@ where
     Registry: registry::Registry,
 {
     entries: &'b mut Entries<'a, Registry, Resources, Views, Indices>,
+    entity_identifier: entity::Identifier,
     location: Location<Registry>,
 }
 
@@ -41,9 +103,138 @@ where
 {
     fn new(
         entries: &'b mut Entries<'a, Registry, Resources, Views, Indices>,
+        entity_identifier: entity::Identifier,
         location: Location<Registry>,
     ) -> Self {
-        Self { entries, location }
+        Self {
+            entries,
+            entity_identifier,
+            location,
+        }
+    }
+
+    /// Add a component to the entity.
+    ///
+    /// The component is not added immediately. Instead, the addition is recorded in the
+    /// [`Deferred`] changes of the [`Entries`] this entry was obtained from, and is performed once
+    /// those changes are applied using [`World::apply_deferred()`]. This allows components to be
+    /// added to entities while they are being iterated over, including components that are being
+    /// viewed.
+    ///
+    /// If the component already exists, it is updated to the new value when the changes are
+    /// applied.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42)));
+    ///
+    /// let query_result = world.query(Query::<Views!(&Foo, entity::Identifier)>::new());
+    /// let mut entries = query_result.entries;
+    /// for result!(_foo, entity_identifier) in query_result.iter {
+    ///     entries.entry(entity_identifier).unwrap().add(Bar(true));
+    /// }
+    /// let deferred = entries.into_deferred();
+    /// world.apply_deferred(deferred);
+    ///
+    /// assert_eq!(
+    ///     world.query(Query::<Views!(&Foo, &Bar)>::new()).iter.count(),
+    ///     1
+    /// );
+    /// ```
+    ///
+    /// [`Deferred`]: crate::world::Deferred
+    /// [`World::apply_deferred()`]: crate::World::apply_deferred()
+    pub fn add<Component, Index>(&mut self, component: Component)
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.entries
+            .deferred
+            .modifier(self.entity_identifier, self.location.identifier)
+            .add(component);
+    }
+
+    /// Remove a component from the entity.
+    ///
+    /// The component is not removed immediately. Instead, the removal is recorded in the
+    /// [`Deferred`] changes of the [`Entries`] this entry was obtained from, and is performed once
+    /// those changes are applied using [`World::apply_deferred()`]. This allows components to be
+    /// removed from entities while they are being iterated over, including components that are
+    /// being viewed.
+    ///
+    /// If the component is not present within the entity when the changes are applied, nothing
+    /// happens.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// let query_result = world.query(Query::<Views!(&mut Foo, entity::Identifier)>::new());
+    /// let mut entries = query_result.entries;
+    /// for result!(foo, entity_identifier) in query_result.iter {
+    ///     foo.0 += 1;
+    ///     entries.entry(entity_identifier).unwrap().remove::<Bar, _>();
+    /// }
+    /// let deferred = entries.into_deferred();
+    /// world.apply_deferred(deferred);
+    ///
+    /// assert_eq!(
+    ///     world
+    ///         .query(Query::<Views!(&Foo), filter::Not<filter::Has<Bar>>>::new())
+    ///         .iter
+    ///         .count(),
+    ///     1
+    /// );
+    /// ```
+    ///
+    /// [`Deferred`]: crate::world::Deferred
+    /// [`World::apply_deferred()`]: crate::World::apply_deferred()
+    pub fn remove<Component, Index>(&mut self)
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.entries
+            .deferred
+            .modifier(self.entity_identifier, self.location.identifier)
+            .remove::<Component, Index>();
     }
 }
 
@@ -90,8 +281,7 @@ where
             // the entity allocator.
             let super_views = unsafe {
                 (*self.entries.world)
-                    .archetypes
-                    .get_mut(self.location.identifier)?
+                    .archetype_mut(self.location.identifier)?
                     .view_row_maybe_uninit_unchecked::<Views, Indices>(self.location.index)
             };
 
@@ -107,14 +297,20 @@ where
 
 /// Access to entity [`Entry`]s.
 ///
-/// These entity `Entry`s allow access to the components viewed in `Views`.
+/// These entity `Entry`s allow access to the components viewed in `Views`. They also allow
+/// components to be added to and removed from entities, with the changes being recorded until
+/// they are obtained using [`into_deferred()`].
+///
+/// [`into_deferred()`]: Entries::into_deferred()
 pub struct Entries<'a, Registry, Resources, Views, Indices>
 where
     Registry: registry::Registry,
 {
-    world: *mut World<Registry, Resources>,
+    world: *mut (dyn ErasedWorld<Registry> + 'a),
+    deferred: Deferred<Registry>,
 
     lifetime: PhantomData<&'a ()>,
+    resources: PhantomData<Resources>,
     views: PhantomData<Views>,
     indices: PhantomData<Indices>,
 }
@@ -169,11 +365,17 @@ where
     ///     .unwrap();
     /// assert_eq!(a, &A(42));
     /// ```
-    pub(crate) unsafe fn new(world: *mut World<Registry, Resources>) -> Self {
+    pub(crate) unsafe fn new<S>(world: *mut World<Registry, Resources, S>) -> Self
+    where
+        S: BuildHasher + 'a,
+        Resources: 'a,
+    {
         Entries {
             world,
+            deferred: Deferred::new(),
 
             lifetime: PhantomData,
+            resources: PhantomData,
             views: PhantomData,
             indices: PhantomData,
         }
@@ -191,18 +393,144 @@ where
         // SAFETY: The invariants of `Entries` guarantees that `World` won't have any entities
         // added or removed, meaning the `entity_allocator` will not be mutated during this time.
         unsafe { &*self.world }
-            .entity_allocator
+            .entity_allocator()
             .get(entity_identifier)
-            .map(|location| Entry::new(self, location))
+            .map(|location| Entry::new(self, entity_identifier, location))
+    }
+
+    /// Returns the changes recorded through the [`add()`] and [`remove()`] methods of this
+    /// `Entries`'s [`Entry`]s.
+    ///
+    /// The returned changes can be applied using [`World::apply_deferred()`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42)));
+    ///
+    /// let mut entries = world.query(Query::<Views!()>::new()).entries;
+    /// entries.entry(entity_identifier).unwrap().add(Bar(true));
+    /// let deferred = entries.into_deferred();
+    /// world.apply_deferred(deferred);
+    ///
+    /// assert_eq!(world.query(Query::<Views!(&Bar)>::new()).iter.count(), 1);
+    /// ```
+    ///
+    /// [`add()`]: Entry::add()
+    /// [`remove()`]: Entry::remove()
+    /// [`World::apply_deferred()`]: crate::World::apply_deferred()
+    pub fn into_deferred(self) -> Deferred<Registry> {
+        self.deferred
+    }
+}
+
+impl<'a, Registry, Resources, Views, Indices> Entries<'a, Registry, Resources, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: registry::ContainsViews<'a, Views, Indices>,
+{
+    /// Follows an `entity::Identifier` to borrow a `Component` of the entity it identifies.
+    ///
+    /// This is intended for traversing relationships between entities, such as a child entity
+    /// storing the identifier of its parent as a component. `Component` must be viewed, either
+    /// mutably or immutably, by the entry `Views`. Since those views are required to be disjoint
+    /// from the views being iterated, the followed entity may be any entity in the `World`,
+    /// including the entity currently being iterated.
+    ///
+    /// Unlike [`entry()`], this only requires a shared borrow of the `Entries`, so multiple
+    /// followed components can be borrowed at once, for example when walking up a chain of
+    /// parents. No [`Entry`] can be obtained while a followed component is borrowed.
+    ///
+    /// Returns `None` if the entity does not exist or does not contain `Component`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Parent(entity::Identifier);
+    /// struct Position(u32);
+    /// struct Offset(u32);
+    ///
+    /// let mut world = World::<Registry!(Parent, Position, Offset)>::new();
+    /// let parent = world.insert(entity!(Position(10)));
+    /// world.insert(entity!(Parent(parent), Offset(5)));
+    ///
+    /// let query_result = world.query(Query::<
+    ///     Views!(&Parent, &Offset),
+    ///     filter::None,
+    ///     Views!(),
+    ///     Views!(&Position),
+    /// >::new());
+    /// let entries = query_result.entries;
+    /// for result!(parent, offset) in query_result.iter {
+    ///     let position = entries.follow::<Position, _, _>(parent.0).unwrap();
+    ///     assert_eq!(position.0 + offset.0, 15);
+    /// }
+    /// ```
+    ///
+    /// [`entry()`]: Entries::entry()
+    #[must_use]
+    pub fn follow<'b, Component, SubSetIndices, ComponentIndex>(
+        &'b self,
+        entity_identifier: entity::Identifier,
+    ) -> Option<&'b Component>
+    where
+        Component: component::Component,
+        (&'a Component, view::Null): SubSet<'a, Views, SubSetIndices>,
+        Registry: ContainsComponent<Component, ComponentIndex>,
+    {
+        // SAFETY: The invariants of `Entries` guarantees that `World` won't have any entities
+        // added or removed, meaning the `entity_allocator` and `archetypes` will not be mutated
+        // during this time.
+        let world = unsafe { &*self.world };
+        let location = world.entity_allocator().get(entity_identifier)?;
+        let archetype = world.archetype(location.identifier)?;
+        if archetype.contains_component::<Component, ComponentIndex>() {
+            // SAFETY: `location.index` is a valid index into this archetype, as guaranteed by the
+            // entity allocator, and `Component` was verified above to be contained in the
+            // archetype. `Component` is viewed by `Views`, so it is only accessed through these
+            // `Entries`, and no `Entry` can be created while `self` is borrowed. Only this single
+            // component is borrowed, so no other component in the column is aliased.
+            Some(unsafe {
+                archetype.component_unchecked::<Component, ComponentIndex>(location.index)
+            })
+        } else {
+            None
+        }
     }
 }
 
 // SAFETY: Since the access to the viewed components is unique, this can be sent between threads
-// safely.
+// safely. Components recorded in the deferred changes are owned, and are therefore only sent if
+// they are `Send`.
 unsafe impl<'a, Registry, Resources, Views, Indices> Send
     for Entries<'a, Registry, Resources, Views, Indices>
 where
-    Registry: registry::Registry,
+    Registry: registry::Registry + Send,
 {
 }
 
@@ -249,7 +577,7 @@ mod tests {
         let mut world = World::<Registry!()>::new();
         let identifier = world.insert(entity!());
 
-        let mut entries = unsafe { Entries::<_, _, Views!(), _>::new(&mut world) };
+        let mut entries = world.query(Query::<Views!()>::new()).entries;
         let mut entry = assert_some!(entries.entry(identifier));
 
         assert_some!(entry.query(Query::<Views!()>::new()));
@@ -591,4 +919,175 @@ mod tests {
         let result!(a) = assert_some!(entry.query(Query::<Views!(Option<&mut A>)>::new()));
         assert_eq!(a, Some(&mut A(42)));
     }
+
+    #[test]
+    fn add_deferred() {
+        let mut world = World::<Registry>::new();
+        let identifier = world.insert(entity!(A(42)));
+
+        let mut entries = unsafe { Entries::<_, _, Views!(Option<&B>), _>::new(&mut world) };
+        let mut entry = assert_some!(entries.entry(identifier));
+        entry.add(B('a'));
+
+        // The component is not added until the changes are applied.
+        let result!(b) = assert_some!(entry.query(Query::<Views!(Option<&B>)>::new()));
+        assert_none!(b);
+
+        let deferred = entries.into_deferred();
+        assert!(!deferred.is_empty());
+        world.apply_deferred(deferred);
+
+        let mut entry = assert_some!(world.entry(identifier));
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!(a, &A(42));
+        assert_eq!(b, &B('a'));
+    }
+
+    #[test]
+    fn remove_deferred() {
+        let mut world = World::<Registry>::new();
+        let identifier = world.insert(entity!(A(42), B('a')));
+
+        let mut entries = world.query(Query::<Views!()>::new()).entries;
+        assert_some!(entries.entry(identifier)).remove::<B, _>();
+        let deferred = entries.into_deferred();
+        world.apply_deferred(deferred);
+
+        let mut entry = assert_some!(world.entry(identifier));
+        let result!(a) =
+            assert_some!(entry.query(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new()));
+        assert_eq!(a, &A(42));
+    }
+
+    #[test]
+    fn deferred_multiple_entities() {
+        let mut world = World::<Registry>::new();
+        let first = world.insert(entity!(A(1)));
+        let second = world.insert(entity!(A(2), C(3.14)));
+
+        let mut entries = world.query(Query::<Views!()>::new()).entries;
+        assert_some!(entries.entry(first)).add(B('a'));
+        assert_some!(entries.entry(second)).remove::<C, _>();
+        assert_some!(entries.entry(first)).add(C(1.5));
+        let deferred = entries.into_deferred();
+        world.apply_deferred(deferred);
+
+        let mut entry = assert_some!(world.entry(first));
+        let result!(a, b, c) = assert_some!(entry.query(Query::<Views!(&A, &B, &C)>::new()));
+        assert_eq!(a, &A(1));
+        assert_eq!(b, &B('a'));
+        assert_eq!(c, &C(1.5));
+        let mut entry = assert_some!(world.entry(second));
+        let result!(a) =
+            assert_some!(entry.query(Query::<Views!(&A), filter::Not<filter::Has<C>>>::new()));
+        assert_eq!(a, &A(2));
+    }
+
+    #[test]
+    fn deferred_entity_removed() {
+        let mut world = World::<Registry>::new();
+        let identifier = world.insert(entity!(A(42)));
+
+        let mut entries = world.query(Query::<Views!()>::new()).entries;
+        assert_some!(entries.entry(identifier)).add(B('a'));
+        let deferred = entries.into_deferred();
+        world.remove(identifier);
+        world.apply_deferred(deferred);
+
+        assert!(world.is_empty());
+    }
+
+    #[test]
+    fn follow() {
+        let mut world = World::<Registry>::new();
+        let parent = world.insert(entity!(B('a')));
+        world.insert(entity!(A(42)));
+
+        let entries = world
+            .query(Query::<Views!(&A), filter::None, Views!(), Views!(&B)>::new())
+            .entries;
+        assert_eq!(entries.follow::<B, _, _>(parent), Some(&B('a')));
+    }
+
+    #[test]
+    fn follow_mutable_super_view() {
+        let mut world = World::<Registry>::new();
+        let identifier = world.insert(entity!(B('a')));
+
+        let entries = world
+            .query(Query::<Views!(), filter::None, Views!(), Views!(&mut B)>::new())
+            .entries;
+        assert_eq!(entries.follow::<B, _, _>(identifier), Some(&B('a')));
+    }
+
+    #[test]
+    fn follow_optional_super_view_not_present() {
+        let mut world = World::<Registry>::new();
+        let identifier = world.insert(entity!(A(42)));
+
+        let entries = world
+            .query(Query::<Views!(), filter::None, Views!(), Views!(Option<&B>)>::new())
+            .entries;
+        assert_none!(entries.follow::<B, _, _>(identifier));
+    }
+
+    #[test]
+    fn follow_self() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(42), B('a')));
+
+        let result = world.query(Query::<
+            Views!(&mut A, entity::Identifier),
+            filter::None,
+            Views!(),
+            Views!(&B),
+        >::new());
+        let entries = result.entries;
+        for result!(a, identifier) in result.iter {
+            a.0 += 1;
+            assert_eq!(entries.follow::<B, _, _>(identifier), Some(&B('a')));
+        }
+    }
+
+    #[test]
+    fn follow_multiple() {
+        let mut world = World::<Registry>::new();
+        let first = world.insert(entity!(B('a')));
+        let second = world.insert(entity!(B('b')));
+
+        let entries = world
+            .query(Query::<Views!(), filter::None, Views!(), Views!(&B)>::new())
+            .entries;
+        let first_b = assert_some!(entries.follow::<B, _, _>(first));
+        let second_b = assert_some!(entries.follow::<B, _, _>(second));
+        assert_eq!(first_b, &B('a'));
+        assert_eq!(second_b, &B('b'));
+    }
+
+    #[test]
+    fn follow_multiple_mutable_super_view() {
+        let mut world = World::<Registry>::new();
+        let first = world.insert(entity!(B('a')));
+        let second = world.insert(entity!(B('b')));
+
+        let entries = world
+            .query(Query::<Views!(), filter::None, Views!(), Views!(&mut B)>::new())
+            .entries;
+        let first_b = assert_some!(entries.follow::<B, _, _>(first));
+        let second_b = assert_some!(entries.follow::<B, _, _>(second));
+        assert_eq!(first_b, &B('a'));
+        assert_eq!(second_b, &B('b'));
+    }
+
+    #[test]
+    fn follow_removed_entity() {
+        let mut world = World::<Registry>::new();
+        let identifier = world.insert(entity!(B('a')));
+        world.remove(identifier);
+
+        let entries = world
+            .query(Query::<Views!(), filter::None, Views!(), Views!(&B)>::new())
+            .entries;
+        assert_none!(entries.follow::<B, _, _>(identifier));
+    }
 }
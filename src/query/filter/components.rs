@@ -0,0 +1,35 @@
+use crate::{
+    entity,
+    query::filter::{
+        And,
+        Has,
+        None,
+        Not,
+    },
+};
+
+/// A heterogeneous list of components that can be expanded into nested filters.
+///
+/// This is used to define the [`AllOf`] and [`NoneOf`] filters.
+///
+/// [`AllOf`]: crate::query::filter::AllOf
+/// [`NoneOf`]: crate::query::filter::NoneOf
+pub trait Components {
+    /// A filter requiring every component in the list.
+    type AllOf;
+    /// A filter requiring none of the components in the list.
+    type NoneOf;
+}
+
+impl Components for entity::Null {
+    type AllOf = None;
+    type NoneOf = None;
+}
+
+impl<Component, Components> self::Components for (Component, Components)
+where
+    Components: self::Components,
+{
+    type AllOf = And<Has<Component>, Components::AllOf>;
+    type NoneOf = And<Not<Has<Component>>, Components::NoneOf>;
+}
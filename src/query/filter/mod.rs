@@ -8,15 +8,64 @@
 //! `Component` `C` (`&C` and `&mut C`) both implicitly [`And`] a [`Has<C>`] `Filter` with other
 //! `Filter`s.
 //!
+//! # Marker Components as Tags
+//! Since `Filter`s are applied to the set of components an entity has, zero-sized marker
+//! components can be used as tags describing an entity's state. For example, an enum-like state
+//! can be represented by a separate marker component for each variant, with the marker being
+//! swapped out when the state changes. Filtering on these markers is then free on a per-entity
+//! basis, as entities with different tags are stored separately. The [`AllTags!`] macro creates a
+//! `Filter` for entities having every one of a list of tags.
+//!
+//! ``` rust
+//! use brood::{
+//!     entity,
+//!     query::{
+//!         filter,
+//!         result,
+//!         Views,
+//!     },
+//!     Query,
+//!     Registry,
+//!     World,
+//! };
+//!
+//! struct Health(u32);
+//! // Marker components used as tags.
+//! struct Enemy;
+//! struct Stunned;
+//!
+//! type Registry = Registry!(Health, Enemy, Stunned);
+//!
+//! let mut world = World::<Registry>::new();
+//! world.insert(entity!(Health(10), Enemy, Stunned));
+//! world.insert(entity!(Health(10), Enemy));
+//! world.insert(entity!(Health(10)));
+//!
+//! for result!(health) in world
+//!     .query(Query::<Views!(&mut Health), filter::AllTags!(Enemy, Stunned)>::new())
+//!     .iter
+//! {
+//!     health.0 -= 5;
+//! }
+//! ```
+//!
+//! [`AllTags!`]: crate::query::filter::AllTags!
 //! [`And`]: crate::query::filter::And
 //! [`Component`]: crate::component::Component
 //! [`Has<C>`]: crate::query::filter::Has
 //! [`Views`]: trait@crate::query::view::Views
 //! [`World`]: crate::world::World
 
+mod components;
 mod sealed;
+mod signature;
 
 pub(crate) use sealed::Sealed;
+pub(crate) use signature::Signature;
+
+pub use inner::AllTags;
+
+use components::Components;
 
 use crate::{
     component::Component,
@@ -83,6 +132,138 @@ pub struct Has<Component> {
 
 impl<Component> Filter for Has<Component> {}
 
+/// Filter based on whether an entity's [`Component`]s are exactly the given `Component`s.
+///
+/// This filters out any entities which do not have all of the `Components`, as well as any
+/// entities which have other `Component`s in addition to the `Components`. The `Components` are
+/// specified as an [`Entity!`] type. No borrow of the `Component`s from the entity is required.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     query::filter,
+///     Entity,
+/// };
+///
+/// // Define components.
+/// struct Foo(usize);
+/// struct Bar(bool);
+///
+/// // Define a filter for entities made up of only the components above.
+/// type IsFooBar = filter::Is<Entity!(Foo, Bar)>;
+/// ```
+///
+/// [`Component`]: crate::component::Component
+/// [`Entity!`]: crate::Entity!
+pub struct Is<Components> {
+    components: PhantomData<Components>,
+}
+
+impl<Components> Filter for Is<Components> {}
+
+/// Filter based on whether all of the given [`Component`]s are present in an entity.
+///
+/// This filters out any entities which do not have every one of the `Components`. The
+/// `Components` are specified as an [`Entity!`] type. No borrow of the `Component`s from the
+/// entity is required.
+///
+/// This is shorthand for nesting a [`Has`] filter for each `Component` within [`And`] filters,
+/// and resolves to that nested filter at compile time.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     query::filter,
+///     Entity,
+/// };
+///
+/// // Define components.
+/// struct Foo(usize);
+/// struct Bar(bool);
+/// struct Baz(f64);
+///
+/// // Define a filter for entities containing all of the components above.
+/// type HasFooBarBaz = filter::AllOf<Entity!(Foo, Bar, Baz)>;
+/// ```
+///
+/// [`And`]: crate::query::filter::And
+/// [`Component`]: crate::component::Component
+/// [`Entity!`]: crate::Entity!
+/// [`Has`]: crate::query::filter::Has
+pub type AllOf<Components> = <Components as self::Components>::AllOf;
+
+/// Filter based on whether none of the given [`Component`]s are present in an entity.
+///
+/// This filters out any entities which have any of the `Components`. The `Components` are
+/// specified as an [`Entity!`] type. No borrow of the `Component`s from the entity is required.
+///
+/// This is shorthand for nesting a [`Not`]`<`[`Has`]`>` filter for each `Component` within
+/// [`And`] filters, and resolves to that nested filter at compile time.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     query::filter,
+///     Entity,
+/// };
+///
+/// // Define components.
+/// struct Foo(usize);
+/// struct Bar(bool);
+///
+/// // Define a filter for entities containing neither of the components above.
+/// type HasNeitherFooNorBar = filter::NoneOf<Entity!(Foo, Bar)>;
+/// ```
+///
+/// [`And`]: crate::query::filter::And
+/// [`Component`]: crate::component::Component
+/// [`Entity!`]: crate::Entity!
+/// [`Has`]: crate::query::filter::Has
+/// [`Not`]: crate::query::filter::Not
+pub type NoneOf<Components> = <Components as self::Components>::NoneOf;
+
+pub(crate) mod inner {
+    use crate::doc;
+    doc::non_root_macro! {
+        /// Creates a [`Filter`] for entities having all of the given tag [`Component`]s.
+        ///
+        /// This expands to a [`Has`] filter for each `Component`, nested within [`And`] filters,
+        /// making it equivalent to [`AllOf`] with the same `Component`s. It is intended for use
+        /// with zero-sized marker `Component`s that are used as tags, although any `Component`
+        /// can be provided. Providing no `Component`s results in [`filter::None`].
+        ///
+        /// # Example
+        /// ``` rust
+        /// use brood::query::filter;
+        ///
+        /// // Define marker components.
+        /// struct Enemy;
+        /// struct Stunned;
+        ///
+        /// // Define a filter for entities having both of the tags above.
+        /// type StunnedEnemy = filter::AllTags!(Enemy, Stunned);
+        /// ```
+        ///
+        /// [`AllOf`]: crate::query::filter::AllOf
+        /// [`And`]: crate::query::filter::And
+        /// [`Component`]: crate::component::Component
+        /// [`Filter`]: crate::query::filter::Filter
+        /// [`filter::None`]: crate::query::filter::None
+        /// [`Has`]: crate::query::filter::Has
+        macro_rules! AllTags {
+            ($tag:ty $(,$tags:ty)* $(,)?) => (
+                $crate::query::filter::And<
+                    $crate::query::filter::Has<$tag>,
+                    $crate::query::filter::AllTags!($($tags,)*),
+                >
+            );
+            () => (
+                $crate::query::filter::None
+            );
+        }
+    }
+}
+
 /// Filter using the logical inverse of another [`Filter`].
 ///
 /// This filters out any entities which would not have been filtered by the `Filter`.
@@ -184,8 +365,14 @@ impl<C> Filter for Option<&C> where C: Component {}
 
 impl<C> Filter for Option<&mut C> where C: Component {}
 
+impl<C> Filter for view::Copied<C> where C: Component {}
+
 impl Filter for entity::Identifier {}
 
+impl Filter for view::Index {}
+
+impl Filter for view::ArchetypeId {}
+
 impl Filter for view::Null {}
 
 impl<V, W> Filter for (V, W)
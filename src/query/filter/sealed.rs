@@ -6,6 +6,7 @@ use crate::{
             And,
             Filter,
             Has,
+            Is,
             None,
             Not,
             Or,
@@ -20,6 +21,8 @@ impl Sealed for None {}
 
 impl<C> Sealed for Has<C> {}
 
+impl<E> Sealed for Is<E> {}
+
 impl<F> Sealed for Not<F> where F: Filter {}
 
 impl<F1, F2> Sealed for And<F1, F2>
@@ -44,8 +47,14 @@ impl<C> Sealed for Option<&C> where C: Component {}
 
 impl<C> Sealed for Option<&mut C> where C: Component {}
 
+impl<C> Sealed for view::Copied<C> where C: Component {}
+
 impl Sealed for entity::Identifier {}
 
+impl Sealed for view::Index {}
+
+impl Sealed for view::ArchetypeId {}
+
 impl Sealed for view::Null {}
 
 impl<V, W> Sealed for (V, W)
@@ -0,0 +1,130 @@
+use crate::{
+    component::Component,
+    entity,
+    query::{
+        filter::{
+            And,
+            Has,
+            Is,
+            None,
+            Not,
+            Or,
+        },
+        view,
+    },
+};
+
+/// Maps a [`Filter`] to an equivalent `'static` type.
+///
+/// Views borrow the components they view, so they are generally not `'static`. This replaces
+/// every borrow with a `'static` borrow, allowing the `TypeId` of the resulting type to identify
+/// the filter. Filters with the same `Static` type always filter the same archetypes.
+///
+/// This is implemented as a "public in private" trait, and therefore cannot be implemented by
+/// external users of the library.
+///
+/// [`Filter`]: crate::query::filter::Filter
+pub trait Signature {
+    /// The `'static` equivalent of this filter.
+    type Static: 'static;
+}
+
+impl Signature for None {
+    type Static = Self;
+}
+
+impl<C> Signature for Has<C>
+where
+    C: Component,
+{
+    type Static = Self;
+}
+
+impl<E> Signature for Is<E>
+where
+    E: 'static,
+{
+    type Static = Self;
+}
+
+impl<F> Signature for Not<F>
+where
+    F: Signature,
+{
+    type Static = Not<F::Static>;
+}
+
+impl<F1, F2> Signature for And<F1, F2>
+where
+    F1: Signature,
+    F2: Signature,
+{
+    type Static = And<F1::Static, F2::Static>;
+}
+
+impl<F1, F2> Signature for Or<F1, F2>
+where
+    F1: Signature,
+    F2: Signature,
+{
+    type Static = Or<F1::Static, F2::Static>;
+}
+
+impl<C> Signature for &C
+where
+    C: Component,
+{
+    type Static = &'static C;
+}
+
+impl<C> Signature for &mut C
+where
+    C: Component,
+{
+    type Static = &'static mut C;
+}
+
+impl<C> Signature for Option<&C>
+where
+    C: Component,
+{
+    type Static = Option<&'static C>;
+}
+
+impl<C> Signature for Option<&mut C>
+where
+    C: Component,
+{
+    type Static = Option<&'static mut C>;
+}
+
+impl<C> Signature for view::Copied<C>
+where
+    C: Component,
+{
+    type Static = Self;
+}
+
+impl Signature for entity::Identifier {
+    type Static = Self;
+}
+
+impl Signature for view::Index {
+    type Static = Self;
+}
+
+impl Signature for view::ArchetypeId {
+    type Static = Self;
+}
+
+impl Signature for view::Null {
+    type Static = Self;
+}
+
+impl<V, W> Signature for (V, W)
+where
+    V: Signature,
+    W: Signature,
+{
+    type Static = (V::Static, W::Static);
+}
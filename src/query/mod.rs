@@ -6,7 +6,8 @@
 //!
 //! Queries are made up of `Views`, giving access to `Component`s, and [`Filter`]s which can filter
 //! which entities are viewed. Query results are returned as heterogeneous lists, so the
-//! [`result!`] macro is provided to unpack the results.
+//! [`result!`] macro is provided to unpack the results. The [`query!`] macro is provided as a
+//! shorthand for running a query without naming the [`Query`] type.
 //!
 //! # Example
 //! The below example queries mutably for the component `Foo`, immutably for the component `Bar`,
@@ -45,6 +46,8 @@
 //!
 //! [`Component`]: crate::component::Component
 //! [`Filter`]: crate::query::filter::Filter
+//! [`query!`]: crate::query::query!
+//! [`Query`]: crate::query::Query
 //! [`result!`]: crate::query::result!
 //! [`Views`]: trait@crate::query::view::Views
 //! [`World`]: crate::world::World
@@ -64,6 +67,7 @@ pub use result::{
 #[doc(inline)]
 pub use view::inner::Views;
 
+use crate::doc;
 use core::{
     fmt,
     marker::PhantomData,
@@ -175,11 +179,71 @@ impl<Views, Filters, ResourceViews, EntryViews> fmt::Debug
     }
 }
 
+doc::non_root_macro! {
+    /// Runs a [`query()`] over a [`World`] without naming the [`Query`] type.
+    ///
+    /// `query!(world, Views, Filter, ResourceViews, EntryViews)` expands to
+    /// `world.query(Query::<Views, Filter, ResourceViews, EntryViews>::new())`. As with `Query`,
+    /// only the views must be provided; the remaining parameters may be omitted to use their
+    /// defaults.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         query,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// // Define components.
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    /// struct Baz(f64);
+    ///
+    /// type Registry = Registry!(Foo, Bar, Baz);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true), Baz(1.5)));
+    ///
+    /// for result!(foo, bar) in query!(world, Views!(&mut Foo, &Bar), filter::Has<Baz>).iter {
+    ///     // Do something.
+    /// }
+    /// ```
+    ///
+    /// [`query()`]: crate::world::World::query()
+    /// [`Query`]: crate::query::Query
+    /// [`World`]: crate::world::World
+    macro_rules! query {
+        ($world:expr, $views:ty $(,$parameters:ty)* $(,)?) => (
+            $world.query($crate::query::Query::<$views $(,$parameters)*>::new())
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Query;
-    use crate::query::Views;
-    use alloc::format;
+    use crate::{
+        entity,
+        query::{
+            filter,
+            result,
+            Views,
+        },
+        Registry,
+        World,
+    };
+    use alloc::{
+        format,
+        vec,
+        vec::Vec,
+    };
 
     #[test]
     fn query_default() {
@@ -197,4 +261,40 @@ mod tests {
     fn query_debug() {
         assert_eq!(format!("{:?}", Query::<Views!()>::new()), "Query");
     }
+
+    #[test]
+    fn query_macro() {
+        #[derive(Debug, PartialEq)]
+        struct A(u32);
+        struct B;
+        let mut world = World::<Registry!(A, B)>::new();
+        world.insert(entity!(A(1), B));
+        world.insert(entity!(A(2)));
+
+        let mut result = query!(world, Views!(&A))
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[test]
+    fn query_macro_filter() {
+        #[derive(Debug, PartialEq)]
+        struct A(u32);
+        struct B;
+        let mut world = World::<Registry!(A, B)>::new();
+        world.insert(entity!(A(1), B));
+        world.insert(entity!(A(2)));
+
+        assert_eq!(
+            query!(world, Views!(&A), filter::Has<B>,)
+                .iter
+                .map(|result!(a)| a.0)
+                .collect::<Vec<_>>(),
+            vec![1]
+        );
+    }
 }
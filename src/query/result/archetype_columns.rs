@@ -0,0 +1,259 @@
+use crate::{
+    archetypes,
+    query::{
+        filter::And,
+        view,
+    },
+    registry,
+    registry::{
+        contains::filter::Sealed as ContainsFilterSealed,
+        ContainsQuery,
+    },
+};
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+
+/// The viewed components of a single archetype, stored as structure-of-arrays columns.
+///
+/// This trait provides a uniform interface over the columns of any archetype matching a query,
+/// regardless of the components being viewed. Generic algorithms can be written over any
+/// `ArchetypeColumns`, only specializing on the concrete [`Slices`] where the component types
+/// matter.
+///
+/// The columns are a heterogeneous list of slices, as described by [`ArchetypeSlices`]. Every
+/// column has the same [`len()`], and the values at a given index within each column belong to
+/// the same entity.
+///
+/// This trait is implemented for [`Columns`], and cannot be implemented by external types.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         result,
+///         result::ArchetypeColumns,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Position(f32);
+/// struct Velocity(f32);
+///
+/// fn largest_archetype<C>(columns: impl Iterator<Item = C>) -> usize
+/// where
+///     C: ArchetypeColumns,
+/// {
+///     columns.map(|columns| columns.len()).max().unwrap_or(0)
+/// }
+///
+/// let mut world = World::<Registry!(Position, Velocity)>::new();
+/// world.insert(entity!(Position(0.0), Velocity(1.0)));
+/// world.insert(entity!(Position(1.0), Velocity(2.0)));
+/// world.insert(entity!(Position(2.0)));
+///
+/// assert_eq!(
+///     largest_archetype(
+///         world
+///             .query(Query::<Views!(&Position)>::new())
+///             .iter
+///             .archetype_columns()
+///     ),
+///     2
+/// );
+/// ```
+///
+/// [`ArchetypeSlices`]: crate::query::result::ArchetypeSlices
+/// [`len()`]: ArchetypeColumns::len()
+/// [`Slices`]: ArchetypeColumns::Slices
+pub trait ArchetypeColumns: Sealed {
+    /// The heterogeneous list of slices making up the columns.
+    type Slices;
+
+    /// Returns the number of entities in the archetype.
+    ///
+    /// This is the length of every column.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the archetype contains no entities.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the columns.
+    fn slices(&self) -> &Self::Slices;
+
+    /// Returns a mutable reference to the columns.
+    fn slices_mut(&mut self) -> &mut Self::Slices;
+
+    /// Consumes `self`, returning the columns.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         result::ArchetypeColumns,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Position(f32);
+    /// struct Velocity(f32);
+    ///
+    /// let mut world = World::<Registry!(Position, Velocity)>::new();
+    /// world.insert(entity!(Position(0.0), Velocity(1.0)));
+    ///
+    /// for columns in world
+    ///     .query(Query::<Views!(&mut Position, &Velocity)>::new())
+    ///     .iter
+    ///     .archetype_columns()
+    /// {
+    ///     let result!(positions, velocities) = columns.into_slices();
+    ///     for (position, velocity) in positions.iter_mut().zip(velocities) {
+    ///         position.0 += velocity.0;
+    ///     }
+    /// }
+    /// ```
+    fn into_slices(self) -> Self::Slices;
+}
+
+pub trait Sealed {}
+
+/// The columns of a single archetype matching a query.
+///
+/// This is the [`ArchetypeColumns`] implementation yielded by [`ArchetypeColumnsIter`].
+#[derive(Debug)]
+pub struct Columns<Slices> {
+    slices: Slices,
+    len: usize,
+}
+
+impl<Slices> Sealed for Columns<Slices> {}
+
+impl<Slices> ArchetypeColumns for Columns<Slices> {
+    type Slices = Slices;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn slices(&self) -> &Self::Slices {
+        &self.slices
+    }
+
+    fn slices_mut(&mut self) -> &mut Self::Slices {
+        &mut self.slices
+    }
+
+    fn into_slices(self) -> Self::Slices {
+        self.slices
+    }
+}
+
+/// An [`Iterator`] over the results of a query, yielding the [`ArchetypeColumns`] of each
+/// archetype.
+///
+/// This is equivalent to [`ArchetypeSlices`], except that each archetype's slices are yielded
+/// along with the number of entities in the archetype. The number of entities is available even
+/// when no viewed component determines it, such as when every view is optional.
+///
+/// This `struct` is created by the [`archetype_columns`] method on [`result::Iter`].
+///
+/// [`ArchetypeSlices`]: crate::query::result::ArchetypeSlices
+/// [`archetype_columns`]: crate::query::result::Iter::archetype_columns()
+/// [`result::Iter`]: crate::query::result::Iter
+pub struct ArchetypeColumnsIter<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+    archetypes_iter: archetypes::IterMut<'a, Registry>,
+
+    filter: PhantomData<Filter>,
+    views: PhantomData<Views>,
+    indices: PhantomData<Indices>,
+}
+
+impl<'a, Registry, Filter, Views, Indices>
+    ArchetypeColumnsIter<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+    pub(crate) fn new(archetypes_iter: archetypes::IterMut<'a, Registry>) -> Self {
+        Self {
+            archetypes_iter,
+
+            filter: PhantomData,
+            views: PhantomData,
+            indices: PhantomData,
+        }
+    }
+}
+
+impl<'a, Registry, Filter, Views, Indices> Iterator
+    for ArchetypeColumnsIter<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+    type Item = Columns<Views::Slices>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let archetype = self.archetypes_iter.find(|archetype| {
+            // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+            // identifier is generic over. Additionally, the identifier reference created here
+            // will not outlive `archetype`.
+            unsafe {
+                <Registry as ContainsFilterSealed<
+                    And<Views, Filter>,
+                    And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                >>::filter(archetype.identifier())
+            }
+        })?;
+        Some(Columns {
+            len: archetype.len(),
+            // SAFETY: Each component viewed by `V` is guaranteed to be within the `archetype`,
+            // since the archetype was not removed by the `find()` method above which filters out
+            // archetypes that do not contain the viewed components.
+            slices: unsafe {
+                archetype.slices::<Views, (
+                    Registry::ViewsContainments,
+                    Registry::ViewsIndices,
+                    Registry::ViewsCanonicalContainments,
+                )>()
+            },
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.archetypes_iter.size_hint().1)
+    }
+}
+
+impl<'a, Registry, Filter, Views, Indices> FusedIterator
+    for ArchetypeColumnsIter<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+}
+
+// SAFETY: This type is safe to send between threads, as its mutable views are guaranteed to be
+// exclusive.
+unsafe impl<Registry, Filter, Views, Indices> Send
+    for ArchetypeColumnsIter<'_, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+}
@@ -0,0 +1,154 @@
+use crate::{
+    archetypes,
+    query::{
+        filter::And,
+        view,
+    },
+    registry,
+    registry::{
+        contains::filter::Sealed as ContainsFilterSealed,
+        ContainsQuery,
+    },
+};
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+
+/// An [`Iterator`] over the results of a query, yielding the viewed components of each archetype
+/// as slices.
+///
+/// Rather than yielding the [`Views`] `V` of each entity individually, this iterator yields one
+/// heterogeneous list of slices for each archetype matching the query. Each view `&C` or
+/// `view::Copied<C>` is yielded as a `&[C]`, each view `&mut C` is yielded as a `&mut [C]`, and
+/// each view [`entity::Identifier`] is yielded as a `&[entity::Identifier]`. Optional views
+/// `Option<&C>` and `Option<&mut C>` are yielded as `Option<&[C]>` and `Option<&mut [C]>`, being
+/// `None` for archetypes that do not contain the component `C`.
+///
+/// All slices yielded together have the same length, and the values at a given index within each
+/// slice belong to the same entity. This allows tight loops over contiguous component storage,
+/// which is often easier for the compiler to vectorize. The archetypes iterated are not in any
+/// specified order.
+///
+/// This `struct` is created by the [`archetype_slices`] method on [`result::Iter`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         filter,
+///         result,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Position(f32);
+/// struct Velocity(f32);
+///
+/// type Registry = Registry!(Position, Velocity);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Position(0.0), Velocity(1.0)));
+///
+/// for result!(positions, velocities) in world
+///     .query(Query::<Views!(&mut Position, &Velocity)>::new())
+///     .iter
+///     .archetype_slices()
+/// {
+///     for (position, velocity) in positions.iter_mut().zip(velocities) {
+///         position.0 += velocity.0;
+///     }
+/// }
+/// ```
+///
+/// [`archetype_slices`]: crate::query::result::Iter::archetype_slices()
+/// [`entity::Identifier`]: crate::entity::Identifier
+/// [`result::Iter`]: crate::query::result::Iter
+/// [`Views`]: trait@crate::query::view::Views
+pub struct ArchetypeSlices<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+    archetypes_iter: archetypes::IterMut<'a, Registry>,
+
+    filter: PhantomData<Filter>,
+    views: PhantomData<Views>,
+    indices: PhantomData<Indices>,
+}
+
+impl<'a, Registry, Filter, Views, Indices> ArchetypeSlices<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+    pub(crate) fn new(archetypes_iter: archetypes::IterMut<'a, Registry>) -> Self {
+        Self {
+            archetypes_iter,
+
+            filter: PhantomData,
+            views: PhantomData,
+            indices: PhantomData,
+        }
+    }
+}
+
+impl<'a, Registry, Filter, Views, Indices> Iterator
+    for ArchetypeSlices<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+    type Item = Views::Slices;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let archetype = self.archetypes_iter.find(|archetype| {
+            // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+            // identifier is generic over. Additionally, the identifier reference created here
+            // will not outlive `archetype`.
+            unsafe {
+                <Registry as ContainsFilterSealed<
+                    And<Views, Filter>,
+                    And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                >>::filter(archetype.identifier())
+            }
+        })?;
+        Some(
+            // SAFETY: Each component viewed by `V` is guaranteed to be within the `archetype`,
+            // since the archetype was not removed by the `find()` method above which filters out
+            // archetypes that do not contain the viewed components.
+            unsafe {
+                archetype.slices::<Views, (
+                    Registry::ViewsContainments,
+                    Registry::ViewsIndices,
+                    Registry::ViewsCanonicalContainments,
+                )>()
+            },
+        )
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.archetypes_iter.size_hint().1)
+    }
+}
+
+impl<'a, Registry, Filter, Views, Indices> FusedIterator
+    for ArchetypeSlices<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+}
+
+// SAFETY: This type is safe to send between threads, as its mutable views are guaranteed to be
+// exclusive.
+unsafe impl<'a, Registry, Filter, Views, Indices> Send
+    for ArchetypeSlices<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+}
@@ -0,0 +1,167 @@
+use crate::{
+    archetype::Archetype,
+    hlist::Reshape,
+    query::{
+        result::Results,
+        view,
+    },
+    registry,
+    registry::ContainsQuery,
+};
+use alloc::vec::{
+    self,
+    Vec,
+};
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+
+/// An [`Iterator`] over the results of a query, visiting only the archetypes cached for the
+/// query.
+///
+/// Yields the same results as [`result::Iter`], but without checking every archetype in the
+/// [`World`] against the query. Instead, the archetypes matching the query are looked up from a
+/// cache stored in the `World`, which is only refreshed when archetypes have been added or removed
+/// since the query was last run.
+///
+/// This `struct` is created by the [`query_cached`] method on [`World`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         result,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+///
+/// type Registry = Registry!(Foo, Bar);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Foo(1), Bar(true)));
+/// world.insert(entity!(Foo(2)));
+///
+/// let mut foos = world
+///     .query_cached(Query::<Views!(&Foo)>::new())
+///     .iter
+///     .map(|result!(foo)| foo.0)
+///     .collect::<Vec<_>>();
+/// foos.sort();
+/// assert_eq!(foos, vec![1, 2]);
+/// ```
+///
+/// [`query_cached`]: crate::world::World::query_cached()
+/// [`result::Iter`]: crate::query::result::Iter
+/// [`World`]: crate::world::World
+pub struct Cached<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+    Views: view::Views<'a>,
+{
+    archetypes_iter: vec::IntoIter<&'a mut Archetype<Registry>>,
+
+    current_results_iter: Option<<Views::Results as Results>::Iterator>,
+
+    /// The number of results remaining, including those remaining in `current_results_iter`.
+    remaining: usize,
+
+    filter: PhantomData<Filter>,
+    indices: PhantomData<Indices>,
+}
+
+impl<'a, Registry, Filter, Views, Indices> Cached<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+    Views: view::Views<'a>,
+{
+    /// # Safety
+    /// Each archetype in `archetypes` must match the query defined by `Views` and `Filter`.
+    pub(crate) unsafe fn new(archetypes: Vec<&'a mut Archetype<Registry>>) -> Self {
+        let remaining = archetypes.iter().map(|archetype| archetype.len()).sum();
+
+        Self {
+            archetypes_iter: archetypes.into_iter(),
+
+            current_results_iter: None,
+
+            remaining,
+
+            filter: PhantomData,
+            indices: PhantomData,
+        }
+    }
+}
+
+impl<'a, Registry, Filter, Views, Indices> Iterator for Cached<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+    type Item = Views;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut results) = self.current_results_iter {
+                if let result @ Some(_) = results.next() {
+                    self.remaining -= 1;
+                    return result;
+                }
+            }
+            let archetype = self.archetypes_iter.next()?;
+            self.current_results_iter = Some(
+                // SAFETY: Each component viewed by `V` is guaranteed to be within the `archetype`,
+                // since each archetype is guaranteed by the safety contract of `new()` to match
+                // the query.
+                unsafe {
+                    archetype.view::<Views, (
+                        Registry::ViewsContainments,
+                        Registry::ViewsIndices,
+                        Registry::ViewsCanonicalContainments,
+                    )>()
+                }
+                .reshape()
+                .into_iterator(),
+            );
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, Registry, Filter, Views, Indices> ExactSizeIterator
+    for Cached<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+}
+
+impl<'a, Registry, Filter, Views, Indices> FusedIterator
+    for Cached<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+}
+
+// SAFETY: This type is safe to send between threads, as its mutable views are guaranteed to be
+// exclusive.
+unsafe impl<'a, Registry, Filter, Views, Indices> Send
+    for Cached<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+    Views: view::Views<'a>,
+{
+}
@@ -0,0 +1,201 @@
+use crate::{
+    archetypes,
+    entity,
+    hlist::Reshape,
+    query::{
+        filter::And,
+        result::Results,
+        view,
+    },
+    registry,
+    registry::{
+        contains::filter::Sealed as ContainsFilterSealed,
+        ContainsQuery,
+    },
+};
+use core::{
+    hash::BuildHasher,
+    iter,
+    iter::FusedIterator,
+    marker::PhantomData,
+    slice,
+};
+use hashbrown::HashSet;
+
+/// An [`Iterator`] over the results of a query, skipping a set of entities.
+///
+/// Yields the same results as [`result::Iter`], except for the entities whose
+/// [`entity::Identifier`]s are contained in the provided set. The entities iterated are not in any
+/// specified order.
+///
+/// Unlike [`Filter`]s, which are checked once per archetype, the exclusion set is checked once for
+/// every entity matching the query. Each check is a lookup into the set, so iteration has an added
+/// cost proportional to the number of matched entities, regardless of how many entities are
+/// actually excluded.
+///
+/// This `struct` is created by the [`excluding`] method on [`result::Iter`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         result,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+/// use fnv::FnvBuildHasher;
+/// use hashbrown::HashSet;
+///
+/// struct Foo(u32);
+///
+/// type Registry = Registry!(Foo);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Foo(1)));
+/// let entity_identifier = world.insert(entity!(Foo(2)));
+///
+/// let mut excluded = HashSet::with_hasher(FnvBuildHasher::default());
+/// excluded.insert(entity_identifier);
+///
+/// let mut sum = 0;
+/// for result!(foo) in world
+///     .query(Query::<Views!(&Foo)>::new())
+///     .iter
+///     .excluding(&excluded)
+/// {
+///     sum += foo.0;
+/// }
+///
+/// assert_eq!(sum, 1);
+/// ```
+///
+/// [`entity::Identifier`]: crate::entity::Identifier
+/// [`excluding`]: crate::query::result::Iter::excluding()
+/// [`Filter`]: crate::query::filter::Filter
+/// [`result::Iter`]: crate::query::result::Iter
+pub struct Excluding<'a, 's, Registry, Filter, Views, Indices, S>
+where
+    Registry: registry::Registry,
+    Views: view::Views<'a>,
+{
+    archetypes_iter: archetypes::IterMut<'a, Registry>,
+
+    current_results_iter: Option<
+        iter::Zip<<Views::Results as Results>::Iterator, slice::Iter<'a, entity::Identifier>>,
+    >,
+
+    excluded: &'s HashSet<entity::Identifier, S>,
+
+    filter: PhantomData<Filter>,
+    indices: PhantomData<Indices>,
+}
+
+impl<'a, 's, Registry, Filter, Views, Indices, S>
+    Excluding<'a, 's, Registry, Filter, Views, Indices, S>
+where
+    Registry: registry::Registry,
+    Views: view::Views<'a>,
+{
+    pub(crate) fn new(
+        archetypes_iter: archetypes::IterMut<'a, Registry>,
+        excluded: &'s HashSet<entity::Identifier, S>,
+    ) -> Self {
+        Self {
+            archetypes_iter,
+
+            current_results_iter: None,
+
+            excluded,
+
+            filter: PhantomData,
+            indices: PhantomData,
+        }
+    }
+}
+
+impl<'a, 's, Registry, Filter, Views, Indices, S> Iterator
+    for Excluding<'a, 's, Registry, Filter, Views, Indices, S>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+    S: BuildHasher,
+{
+    type Item = Views;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut results) = self.current_results_iter {
+                for (result, entity_identifier) in results.by_ref() {
+                    if !self.excluded.contains(entity_identifier) {
+                        return Some(result);
+                    }
+                }
+            }
+            let archetype = self.archetypes_iter.find(|archetype| {
+                // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+                // identifier is generic over. Additionally, the identifier reference created here
+                // will not outlive `archetype`.
+                unsafe {
+                    <Registry as ContainsFilterSealed<
+                        And<Views, Filter>,
+                        And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                    >>::filter(archetype.identifier())
+                }
+            })?;
+            // SAFETY: Each component viewed by `V` is guaranteed to be within the `archetype`,
+            // since the archetype was not removed by the `find()` method above which filters out
+            // archetypes that do not contain the viewed components.
+            let results = unsafe {
+                archetype.view::<Views, (
+                    Registry::ViewsContainments,
+                    Registry::ViewsIndices,
+                    Registry::ViewsCanonicalContainments,
+                )>()
+            }
+            .reshape()
+            .into_iterator();
+            // The entity identifiers are stored separately from the components, so they do not
+            // alias any of the views.
+            let archetype: &'a _ = archetype;
+            self.current_results_iter = Some(results.zip(archetype.entity_identifiers().iter()));
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let high = self
+            .current_results_iter
+            .as_ref()
+            .map_or(Some(0), |results| results.size_hint().1);
+        match (self.archetypes_iter.size_hint(), high) {
+            ((0, Some(0)), Some(_)) => (0, high),
+            _ => (0, None),
+        }
+    }
+}
+
+impl<'a, 's, Registry, Filter, Views, Indices, S> FusedIterator
+    for Excluding<'a, 's, Registry, Filter, Views, Indices, S>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+    S: BuildHasher,
+{
+}
+
+// SAFETY: This type is safe to send between threads, as its mutable views are guaranteed to be
+// exclusive. The exclusion set is only accessed through a shared reference, which is safe to send
+// as long as the set is `Sync`.
+unsafe impl<'a, 's, Registry, Filter, Views, Indices, S> Send
+    for Excluding<'a, 's, Registry, Filter, Views, Indices, S>
+where
+    Registry: registry::Registry,
+    Views: view::Views<'a>,
+    S: Sync,
+{
+}
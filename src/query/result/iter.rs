@@ -1,9 +1,16 @@
 use crate::{
+    archetype::Archetype,
     archetypes,
+    entity,
     hlist::Reshape,
     query::{
         filter::And,
-        result::Results,
+        result::{
+            ArchetypeColumnsIter,
+            ArchetypeSlices,
+            Excluding,
+            Results,
+        },
         view,
     },
     registry,
@@ -15,7 +22,9 @@ use crate::{
 use core::{
     iter::FusedIterator,
     marker::PhantomData,
+    slice,
 };
+use hashbrown::HashSet;
 
 /// An [`Iterator`] over the results of a query.
 ///
@@ -24,6 +33,15 @@ use core::{
 /// will be heterogeneous lists, so the [`result!`] macro is recommended to create identifiers for
 /// them.
 ///
+/// Since filters are applied to entire archetypes rather than to individual entities, the number
+/// of results is known when the iterator is created. This iterator therefore implements
+/// [`ExactSizeIterator`], allowing the results to be collected without reallocating.
+///
+/// This iterator also implements [`DoubleEndedIterator`], allowing the results to be iterated in
+/// reverse using [`rev()`]. Archetypes are visited in reverse, as are the entities within each
+/// archetype. Note that "reverse" is relative to the forward order, which is itself unspecified;
+/// if a deterministic order is required, use [`query_ordered`] instead.
+///
 /// This `struct` is created by the [`query`] method on [`World`].
 ///
 /// # Example
@@ -58,7 +76,9 @@ use core::{
 /// [`Component`]: crate::component::Component
 /// [`Filter`]: crate::query::filter::Filter
 /// [`query`]: crate::world::World::query()
+/// [`query_ordered`]: crate::world::World::query_ordered()
 /// [`result!`]: crate::query::result!
+/// [`rev()`]: Iterator::rev()
 /// [`Views`]: trait@crate::query::view::Views
 /// [`World`]: crate::world::World
 pub struct Iter<'a, Registry, Filter, Views, Indices>
@@ -69,6 +89,16 @@ where
     archetypes_iter: archetypes::IterMut<'a, Registry>,
 
     current_results_iter: Option<<Views::Results as Results>::Iterator>,
+    current_back_results_iter: Option<<Views::Results as Results>::Iterator>,
+    /// The identifiers of the entities remaining in `current_results_iter`, advanced alongside it.
+    current_entity_identifiers: slice::Iter<'a, entity::Identifier>,
+    /// The identifiers of the entities remaining in `current_back_results_iter`, advanced
+    /// alongside it.
+    current_back_entity_identifiers: slice::Iter<'a, entity::Identifier>,
+
+    /// The number of results remaining, including those remaining in `current_results_iter` and
+    /// `current_back_results_iter`.
+    remaining: usize,
 
     filter: PhantomData<Filter>,
     indices: PhantomData<Indices>,
@@ -79,16 +109,406 @@ where
     Registry: registry::Registry,
     Views: view::Views<'a>,
 {
-    pub(crate) fn new(archetypes_iter: archetypes::IterMut<'a, Registry>) -> Self {
+    pub(crate) fn new(archetypes_iter: archetypes::IterMut<'a, Registry>) -> Self
+    where
+        Registry: ContainsQuery<'a, Filter, Views, Indices>,
+    {
+        let remaining = archetypes_iter
+            .remaining()
+            .filter(|archetype| {
+                // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+                // identifier is generic over. Additionally, the identifier reference created here
+                // will not outlive `archetype`.
+                unsafe {
+                    <Registry as ContainsFilterSealed<
+                        And<Views, Filter>,
+                        And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                    >>::filter(archetype.identifier())
+                }
+            })
+            .map(Archetype::len)
+            .sum();
+
         Self {
             archetypes_iter,
 
             current_results_iter: None,
+            current_back_results_iter: None,
+            current_entity_identifiers: [].iter(),
+            current_back_entity_identifiers: [].iter(),
+
+            remaining,
 
             filter: PhantomData,
             indices: PhantomData,
         }
     }
+
+    /// Returns the identifier of the entity whose views will be yielded by the next call to
+    /// `next()`, without advancing the iterator.
+    ///
+    /// This allows lookahead decisions to be made without creating the next entity's views. While
+    /// this iterator can be wrapped in a [`Peekable`], the peeked views are then held by the
+    /// `Peekable` until they are yielded: any mutable views are only accessible through
+    /// [`peek_mut()`], and the views must be yielded before the following entity can be viewed.
+    /// Peeking only the identifier avoids holding any views at all.
+    ///
+    /// Returns [`None`] if there are no results remaining.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// let skipped = world.insert(entity!(Foo(1)));
+    /// world.insert(entity!(Foo(2)));
+    ///
+    /// let mut iter = world
+    ///     .query(Query::<Views!(&mut Foo, entity::Identifier)>::new())
+    ///     .iter;
+    /// while let Some(entity_identifier) = iter.peek_identifier() {
+    ///     let result!(foo, viewed_identifier) = iter.next().unwrap();
+    ///     assert_eq!(viewed_identifier, entity_identifier);
+    ///     if entity_identifier != skipped {
+    ///         foo.0 += 1;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`None`]: Option::None
+    /// [`Peekable`]: core::iter::Peekable
+    /// [`peek_mut()`]: core::iter::Peekable::peek_mut()
+    #[must_use]
+    pub fn peek_identifier(&self) -> Option<entity::Identifier>
+    where
+        Registry: ContainsQuery<'a, Filter, Views, Indices>,
+    {
+        if let Some(&entity_identifier) = self.current_entity_identifiers.as_slice().first() {
+            return Some(entity_identifier);
+        }
+        self.archetypes_iter
+            .remaining()
+            .find(|archetype| {
+                !archetype.is_empty()
+                    // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+                    // identifier is generic over. Additionally, the identifier reference created
+                    // here will not outlive `archetype`.
+                    && unsafe {
+                        <Registry as ContainsFilterSealed<
+                            And<Views, Filter>,
+                            And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                        >>::filter(archetype.identifier())
+                    }
+            })
+            .map_or_else(
+                || self.current_back_entity_identifiers.as_slice().first(),
+                |archetype| archetype.entity_identifiers().first(),
+            )
+            .copied()
+    }
+
+    /// Converts this iterator into an iterator over the viewed components of each archetype as
+    /// slices.
+    ///
+    /// Rather than yielding views for each entity individually, the returned [`ArchetypeSlices`]
+    /// yields one heterogeneous list of slices per archetype matching the query. This is useful
+    /// for processing components in tight loops over contiguous memory.
+    ///
+    /// Any archetype whose entities have already been partially iterated over by this iterator is
+    /// not included in the returned iterator.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(1), Bar(true)));
+    /// world.insert(entity!(Foo(2), Bar(false)));
+    /// world.insert(entity!(Foo(3)));
+    ///
+    /// let mut sum = 0;
+    /// for result!(foos) in world
+    ///     .query(Query::<Views!(&Foo)>::new())
+    ///     .iter
+    ///     .archetype_slices()
+    /// {
+    ///     sum += foos.iter().map(|foo| foo.0).sum::<u32>();
+    /// }
+    ///
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn archetype_slices(self) -> ArchetypeSlices<'a, Registry, Filter, Views, Indices> {
+        ArchetypeSlices::new(self.archetypes_iter)
+    }
+
+    /// Converts this iterator into an iterator over the [`ArchetypeColumns`] of each archetype.
+    ///
+    /// This is equivalent to [`archetype_slices()`], except that each archetype's slices are
+    /// yielded along with the number of entities in the archetype, allowing them to be processed
+    /// by algorithms that are generic over any `ArchetypeColumns`.
+    ///
+    /// Any archetype whose entities have already been partially iterated over by this iterator is
+    /// not included in the returned iterator.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result::ArchetypeColumns,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// world.insert(entity!(Foo(1), Bar(true)));
+    /// world.insert(entity!(Foo(2)));
+    /// world.insert(entity!(Foo(3)));
+    ///
+    /// let mut lens = world
+    ///     .query(Query::<Views!(Option<&Bar>)>::new())
+    ///     .iter
+    ///     .archetype_columns()
+    ///     .map(|columns| columns.len())
+    ///     .collect::<Vec<_>>();
+    /// lens.sort_unstable();
+    ///
+    /// assert_eq!(lens, vec![1, 2]);
+    /// ```
+    ///
+    /// [`archetype_slices()`]: Iter::archetype_slices()
+    /// [`ArchetypeColumns`]: crate::query::result::ArchetypeColumns
+    pub fn archetype_columns(self) -> ArchetypeColumnsIter<'a, Registry, Filter, Views, Indices> {
+        ArchetypeColumnsIter::new(self.archetypes_iter)
+    }
+
+    /// Converts this iterator into an iterator that skips the entities contained in `excluded`.
+    ///
+    /// This is useful for skipping a set of entities computed at runtime, which cannot be
+    /// expressed using a [`Filter`]. Note that the set is checked once for every entity matching
+    /// the query, so this has a per-entity cost that `Filter`s do not have.
+    ///
+    /// Any archetype whose entities have already been partially iterated over by this iterator is
+    /// not included in the returned iterator.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use fnv::FnvBuildHasher;
+    /// use hashbrown::HashSet;
+    ///
+    /// struct Foo(u32);
+    ///
+    /// type Registry = Registry!(Foo);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(1)));
+    /// world.insert(entity!(Foo(2)));
+    ///
+    /// let mut excluded = HashSet::with_hasher(FnvBuildHasher::default());
+    /// excluded.insert(entity_identifier);
+    ///
+    /// for result!(foo) in world
+    ///     .query(Query::<Views!(&mut Foo)>::new())
+    ///     .iter
+    ///     .excluding(&excluded)
+    /// {
+    ///     foo.0 += 1;
+    /// }
+    /// ```
+    ///
+    /// [`Filter`]: crate::query::filter::Filter
+    pub fn excluding<'s, S>(
+        self,
+        excluded: &'s HashSet<entity::Identifier, S>,
+    ) -> Excluding<'a, 's, Registry, Filter, Views, Indices, S> {
+        Excluding::new(self.archetypes_iter, excluded)
+    }
+
+    /// Skips the first `n` archetypes matching the query.
+    ///
+    /// Together with [`take_archetypes()`], this allows a query's results to be split into
+    /// contiguous shards of archetypes, each of which can be processed by a different worker.
+    /// Shard boundaries are always archetype-aligned: an archetype's entities are never split
+    /// between shards. Archetypes are counted whether or not they contain any entities.
+    ///
+    /// Shards are only consistent with each other as long as no archetypes are added to or
+    /// removed from the `World` between computing them.
+    ///
+    /// Any archetype whose entities have already been partially iterated over by this iterator is
+    /// not included in the returned iterator.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// world.insert(entity!(Foo(1)));
+    /// world.insert(entity!(Foo(2), Bar(true)));
+    ///
+    /// // Process the archetypes in two shards of one archetype each.
+    /// let mut sum = 0;
+    /// for shard in 0..2 {
+    ///     for result!(foo) in world
+    ///         .query(Query::<Views!(&Foo)>::new())
+    ///         .iter
+    ///         .skip_archetypes(shard)
+    ///         .take_archetypes(1)
+    ///     {
+    ///         sum += foo.0;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(sum, 3);
+    /// ```
+    ///
+    /// [`take_archetypes()`]: Iter::take_archetypes()
+    #[must_use]
+    pub fn skip_archetypes(mut self, n: usize) -> Self
+    where
+        Registry: ContainsQuery<'a, Filter, Views, Indices>,
+    {
+        let mut skipped = 0;
+        while skipped < n {
+            let Some(archetype) = self.archetypes_iter.next() else {
+                break;
+            };
+            // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+            // identifier is generic over. Additionally, the identifier reference created here will
+            // not outlive `archetype`.
+            if unsafe {
+                <Registry as ContainsFilterSealed<
+                    And<Views, Filter>,
+                    And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                >>::filter(archetype.identifier())
+            } {
+                skipped += 1;
+            }
+        }
+        Self::new(self.archetypes_iter)
+    }
+
+    /// Limits iteration to the first `n` archetypes matching the query.
+    ///
+    /// See [`skip_archetypes()`] for how this can be used to split a query's results into
+    /// archetype-aligned shards.
+    ///
+    /// Any archetype whose entities have already been partially iterated over by this iterator is
+    /// not included in the returned iterator.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::Views,
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// world.insert(entity!(Foo(1)));
+    /// world.insert(entity!(Foo(2)));
+    /// world.insert(entity!(Foo(3), Bar(true)));
+    ///
+    /// let first_shard = world
+    ///     .query(Query::<Views!(&Foo)>::new())
+    ///     .iter
+    ///     .take_archetypes(1)
+    ///     .count();
+    /// let second_shard = world
+    ///     .query(Query::<Views!(&Foo)>::new())
+    ///     .iter
+    ///     .skip_archetypes(1)
+    ///     .count();
+    ///
+    /// assert_eq!(first_shard + second_shard, 3);
+    /// ```
+    ///
+    /// [`skip_archetypes()`]: Iter::skip_archetypes()
+    #[must_use]
+    pub fn take_archetypes(mut self, n: usize) -> Self
+    where
+        Registry: ContainsQuery<'a, Filter, Views, Indices>,
+    {
+        let len = match n.checked_sub(1) {
+            Some(last) => self
+                .archetypes_iter
+                .remaining()
+                .enumerate()
+                .filter(|(_, archetype)| {
+                    // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+                    // identifier is generic over. Additionally, the identifier reference created
+                    // here will not outlive `archetype`.
+                    unsafe {
+                        <Registry as ContainsFilterSealed<
+                            And<Views, Filter>,
+                            And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                        >>::filter(archetype.identifier())
+                    }
+                })
+                .nth(last)
+                .map_or(usize::MAX, |(position, _)| position + 1),
+            None => 0,
+        };
+        self.archetypes_iter.truncate(len);
+        Self::new(self.archetypes_iter)
+    }
 }
 
 impl<'a, Registry, Filter, Views, Indices> Iterator for Iter<'a, Registry, Filter, Views, Indices>
@@ -103,10 +523,12 @@ where
         loop {
             if let Some(ref mut results) = self.current_results_iter {
                 if let result @ Some(_) = results.next() {
+                    self.current_entity_identifiers.next();
+                    self.remaining -= 1;
                     return result;
                 }
             }
-            let archetype = self.archetypes_iter.find(|archetype| {
+            if let Some(archetype) = self.archetypes_iter.find(|archetype| {
                 // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
                 // identifier is generic over. Additionally, the identifier reference created here
                 // will not outlive `archetype`.
@@ -116,34 +538,41 @@ where
                         And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
                     >>::filter(archetype.identifier())
                 }
-            })?;
-            self.current_results_iter = Some(
-                // SAFETY: Each component viewed by `V` is guaranteed to be within the `archetype`,
-                // since the archetype was not removed by the `find()` method above which filters
-                // out archetypes that do not contain the viewed components.
-                unsafe {
-                    archetype.view::<Views, (
-                        Registry::ViewsContainments,
-                        Registry::ViewsIndices,
-                        Registry::ViewsCanonicalContainments,
-                    )>()
+            }) {
+                // SAFETY: The slice is only used while `archetype` is borrowed by this iterator,
+                // during which no entities can be added to or removed from it.
+                self.current_entity_identifiers =
+                    unsafe { archetype.entity_identifiers_unbound() }.iter();
+                self.current_results_iter = Some(
+                    // SAFETY: Each component viewed by `V` is guaranteed to be within the
+                    // `archetype`, since the archetype was not removed by the `find()` method
+                    // above which filters out archetypes that do not contain the viewed
+                    // components.
+                    unsafe {
+                        archetype.view::<Views, (
+                            Registry::ViewsContainments,
+                            Registry::ViewsIndices,
+                            Registry::ViewsCanonicalContainments,
+                        )>()
+                    }
+                    .reshape()
+                    .into_iterator(),
+                );
+            } else {
+                // The only results remaining are in the archetype being iterated from the back.
+                let result = self.current_back_results_iter.as_mut()?.next();
+                if result.is_some() {
+                    self.current_back_entity_identifiers.next();
+                    self.remaining -= 1;
                 }
-                .reshape()
-                .into_iterator(),
-            );
+                return result;
+            }
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (low, high) = self.current_results_iter.as_ref().map_or(
-            (0, Some(0)),
-            <Views::Results as Results>::Iterator::size_hint,
-        );
-        match (self.archetypes_iter.size_hint(), high) {
-            ((0, Some(0)), Some(_)) => (low, high),
-            _ => (low, None),
-        }
+        (self.remaining, Some(self.remaining))
     }
 
     #[inline]
@@ -155,7 +584,7 @@ where
             init = results.fold(init, &mut fold);
         }
 
-        self.archetypes_iter.fold(init, |acc, archetype| {
+        init = self.archetypes_iter.fold(init, |acc, archetype| {
             // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
             // identifier is generic over. Additionally, the identifier reference created here will
             // not outlive `archetype`.
@@ -180,10 +609,83 @@ where
             } else {
                 acc
             }
-        })
+        });
+
+        if let Some(results) = self.current_back_results_iter {
+            init = results.fold(init, &mut fold);
+        }
+
+        init
+    }
+}
+
+impl<'a, Registry, Filter, Views, Indices> DoubleEndedIterator
+    for Iter<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut results) = self.current_back_results_iter {
+                if let result @ Some(_) = results.next_back() {
+                    self.current_back_entity_identifiers.next_back();
+                    self.remaining -= 1;
+                    return result;
+                }
+            }
+            if let Some(archetype) = self.archetypes_iter.rfind(|archetype| {
+                // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+                // identifier is generic over. Additionally, the identifier reference created here
+                // will not outlive `archetype`.
+                unsafe {
+                    <Registry as ContainsFilterSealed<
+                        And<Views, Filter>,
+                        And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                    >>::filter(archetype.identifier())
+                }
+            }) {
+                // SAFETY: The slice is only used while `archetype` is borrowed by this iterator,
+                // during which no entities can be added to or removed from it.
+                self.current_back_entity_identifiers =
+                    unsafe { archetype.entity_identifiers_unbound() }.iter();
+                self.current_back_results_iter = Some(
+                    // SAFETY: Each component viewed by `V` is guaranteed to be within the
+                    // `archetype`, since the archetype was not removed by the `rfind()` method
+                    // above which filters out archetypes that do not contain the viewed
+                    // components.
+                    unsafe {
+                        archetype.view::<Views, (
+                            Registry::ViewsContainments,
+                            Registry::ViewsIndices,
+                            Registry::ViewsCanonicalContainments,
+                        )>()
+                    }
+                    .reshape()
+                    .into_iterator(),
+                );
+            } else {
+                // The only results remaining are in the archetype being iterated from the front.
+                let result = self.current_results_iter.as_mut()?.next_back();
+                if result.is_some() {
+                    self.current_entity_identifiers.next_back();
+                    self.remaining -= 1;
+                }
+                return result;
+            }
+        }
     }
 }
 
+impl<'a, Registry, Filter, Views, Indices> ExactSizeIterator
+    for Iter<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+}
+
 impl<'a, Registry, Filter, Views, Indices> FusedIterator
     for Iter<'a, Registry, Filter, Views, Indices>
 where
@@ -52,14 +52,39 @@
 #[cfg(feature = "rayon")]
 pub(crate) mod archetype_claims;
 
+mod archetype_columns;
+mod archetype_slices;
+mod cached;
+mod excluding;
 mod iter;
+mod named;
+mod ordered;
+#[cfg(feature = "rayon")]
+mod par_archetype_slices;
 #[cfg(feature = "rayon")]
 mod par_iter;
+mod presence;
 mod sealed;
 
+pub use archetype_columns::{
+    ArchetypeColumns,
+    ArchetypeColumnsIter,
+    Columns,
+};
+pub use archetype_slices::ArchetypeSlices;
+pub use cached::Cached;
+pub use excluding::Excluding;
 pub use iter::Iter;
+pub use named::{
+    named,
+    Named,
+};
+pub use ordered::Ordered;
+#[cfg(feature = "rayon")]
+pub use par_archetype_slices::ParArchetypeSlices;
 #[cfg(feature = "rayon")]
 pub use par_iter::ParIter;
+pub use presence::Presence;
 
 #[cfg(feature = "rayon")]
 pub(crate) use archetype_claims::ArchetypeClaims;
@@ -72,6 +97,13 @@ use crate::{
     query::Entries,
     registry,
 };
+use core::{
+    fmt,
+    iter::Take,
+    ops::ControlFlow,
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::ParallelIterator;
 
 /// The result of a query.
 ///
@@ -125,6 +157,363 @@ where
     pub entries: Entries<'a, Registry, Resources, EntryViews, EntryIndices>,
 }
 
+impl<'a, Registry, Resources, Iterator, ResourceViews, EntryViews, EntryIndices>
+    Result<'a, Registry, Resources, Iterator, ResourceViews, EntryViews, EntryIndices>
+where
+    Registry: registry::Registry,
+{
+    /// Separates the viewed resources from the iterator over the viewed entities.
+    ///
+    /// The resource views and the iterator borrow disjoint parts of the `World`: resources are
+    /// stored separately from the components of entities, so no component can be viewed by both.
+    /// This means the resource views can be held and modified while the iterator is consumed,
+    /// such as when accumulating data from the viewed entities into a resource. This is the same
+    /// as accessing the [`resources`] and [`iter`] fields directly, but makes the separation
+    /// explicit.
+    ///
+    /// The [`entries`] are discarded. If entry access is needed, the fields should be accessed
+    /// directly instead.
+    ///
+    /// This works for both regular and parallel queries.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     resources,
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// // Component
+    /// struct Score(u32);
+    ///
+    /// // Resource
+    /// #[derive(Debug, PartialEq)]
+    /// struct TotalScore(u32);
+    ///
+    /// let mut world = World::<Registry!(Score), _>::with_resources(resources!(TotalScore(0)));
+    /// world.insert(entity!(Score(1)));
+    /// world.insert(entity!(Score(2)));
+    ///
+    /// let (result!(total_score), iter) = world
+    ///     .query(Query::<Views!(&Score), filter::None, Views!(&mut TotalScore)>::new())
+    ///     .split();
+    /// for result!(score) in iter {
+    ///     total_score.0 += score.0;
+    /// }
+    ///
+    /// assert_eq!(world.get::<TotalScore, _>(), &TotalScore(3));
+    /// ```
+    ///
+    /// [`entries`]: Result::entries
+    /// [`iter`]: Result::iter
+    /// [`resources`]: Result::resources
+    pub fn split(self) -> (ResourceViews, Iterator) {
+        (self.resources, self.iter)
+    }
+}
+
+impl<'a, Registry, Resources, Iterator, ResourceViews, EntryViews, EntryIndices>
+    Result<'a, Registry, Resources, Iterator, ResourceViews, EntryViews, EntryIndices>
+where
+    Registry: registry::Registry,
+    Iterator: core::iter::Iterator,
+{
+    /// Returns the first entity viewed by the query, if there is one.
+    ///
+    /// Iteration stops as soon as a matching entity is found. This is most useful for queries that
+    /// are expected to match at most one entity, such as a single player or camera entity. Note
+    /// that the order in which entities are viewed is not specified.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Player(u32);
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::<Registry!(Player, Health)>::new();
+    /// world.insert(entity!(Player(0), Health(100)));
+    ///
+    /// let result!(health) = world
+    ///     .query(Query::<Views!(&Health)>::new())
+    ///     .first()
+    ///     .unwrap();
+    /// assert_eq!(health.0, 100);
+    /// ```
+    pub fn first(mut self) -> Option<Iterator::Item> {
+        self.iter.next()
+    }
+
+    /// Returns the single entity viewed by the query.
+    ///
+    /// This is useful for asserting invariants on entities that are expected to exist exactly
+    /// once. Iteration stops after a second matching entity is found.
+    ///
+    /// # Errors
+    /// Returns [`SingleError::NoMatches`] if the query did not view any entities, and
+    /// [`SingleError::MultipleMatches`] if the query viewed more than one entity.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         result::SingleError,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Camera(f32);
+    ///
+    /// let mut world = World::<Registry!(Camera)>::new();
+    /// world.insert(entity!(Camera(1.0)));
+    ///
+    /// let result!(camera) = world
+    ///     .query(Query::<Views!(&Camera)>::new())
+    ///     .single()
+    ///     .unwrap();
+    /// assert_eq!(camera.0, 1.0);
+    ///
+    /// world.insert(entity!(Camera(2.0)));
+    ///
+    /// assert_eq!(
+    ///     world.query(Query::<Views!(&Camera)>::new()).single().err(),
+    ///     Some(SingleError::MultipleMatches)
+    /// );
+    /// ```
+    pub fn single(mut self) -> core::result::Result<Iterator::Item, SingleError> {
+        let item = self.iter.next().ok_or(SingleError::NoMatches)?;
+        if self.iter.next().is_some() {
+            return Err(SingleError::MultipleMatches);
+        }
+        Ok(item)
+    }
+
+    /// Calls `f` on each entity viewed by the query, stopping as soon as `f` returns
+    /// [`ControlFlow::Break`].
+    ///
+    /// This mirrors [`Iterator::try_for_each()`]. Iteration short-circuits, so no further
+    /// entities or archetypes are visited after `f` breaks. The value `f` broke with is returned.
+    /// If `f` never breaks, [`ControlFlow::Continue`] is returned after all entities are viewed.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use core::ops::ControlFlow;
+    ///
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::<Registry!(Health)>::new();
+    /// world.insert(entity!(Health(100)));
+    /// world.insert(entity!(Health(0)));
+    ///
+    /// let found = world
+    ///     .query(Query::<Views!(&Health)>::new())
+    ///     .try_for_each(|result!(health)| {
+    ///         if health.0 == 0 {
+    ///             ControlFlow::Break(health.0)
+    ///         } else {
+    ///             ControlFlow::Continue(())
+    ///         }
+    ///     });
+    /// assert_eq!(found, ControlFlow::Break(0));
+    /// ```
+    pub fn try_for_each<B, F>(mut self, f: F) -> ControlFlow<B>
+    where
+        F: FnMut(Iterator::Item) -> ControlFlow<B>,
+    {
+        self.iter.try_for_each(f)
+    }
+
+    /// Limits the query to viewing at most `n` entities.
+    ///
+    /// Iteration stops as soon as `n` entities have been viewed, even if it is partway through an
+    /// archetype. No further entities or archetypes are visited after that point. This is useful
+    /// for spreading expensive work over multiple frames, processing only a fixed number of
+    /// entities each time.
+    ///
+    /// The viewed resources and [`entries`] are kept, and can still be accessed while iterating
+    /// over the limited entities.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entities,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone)]
+    /// struct Pending(bool);
+    ///
+    /// let mut world = World::<Registry!(Pending)>::new();
+    /// world.extend(entities!((Pending(true)); 10));
+    ///
+    /// let mut processed = 0;
+    /// for result!(pending) in world
+    ///     .query(Query::<Views!(&mut Pending)>::new())
+    ///     .limited(4)
+    ///     .iter
+    /// {
+    ///     pending.0 = false;
+    ///     processed += 1;
+    /// }
+    ///
+    /// assert_eq!(processed, 4);
+    /// ```
+    ///
+    /// [`entries`]: Result::entries
+    pub fn limited(
+        self,
+        n: usize,
+    ) -> Result<'a, Registry, Resources, Take<Iterator>, ResourceViews, EntryViews, EntryIndices>
+    {
+        Result {
+            iter: self.iter.take(n),
+            resources: self.resources,
+            entries: self.entries,
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+impl<'a, Registry, Resources, Iterator, ResourceViews, EntryViews, EntryIndices>
+    Result<'a, Registry, Resources, Iterator, ResourceViews, EntryViews, EntryIndices>
+where
+    Registry: registry::Registry,
+    Iterator: ParallelIterator,
+{
+    /// Returns any entity viewed by the parallel query, if there is one.
+    ///
+    /// The search stops as soon as any matching entity is found. Which entity is returned when
+    /// multiple entities match is not specified.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Player(u32);
+    ///
+    /// let mut world = World::<Registry!(Player)>::new();
+    /// world.insert(entity!(Player(0)));
+    ///
+    /// let result!(player) = world
+    ///     .par_query(Query::<Views!(&Player)>::new())
+    ///     .find_any()
+    ///     .unwrap();
+    /// assert_eq!(player.0, 0);
+    /// ```
+    pub fn find_any(self) -> Option<Iterator::Item> {
+        self.iter.find_any(|_| true)
+    }
+
+    /// Applies `f` to the entities viewed by the parallel query, returning any value for which `f`
+    /// returns `Some`.
+    ///
+    /// This is the parallel counterpart to [`try_for_each()`]. The search stops as soon as `f`
+    /// returns `Some` for any entity. Which value is returned when `f` returns `Some` for multiple
+    /// entities is not specified.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::<Registry!(Health)>::new();
+    /// world.insert(entity!(Health(100)));
+    /// world.insert(entity!(Health(0)));
+    ///
+    /// let found = world
+    ///     .par_query(Query::<Views!(&Health)>::new())
+    ///     .find_map_any(|result!(health)| (health.0 == 0).then_some(health.0));
+    /// assert_eq!(found, Some(0));
+    /// ```
+    ///
+    /// [`try_for_each()`]: Result::try_for_each()
+    pub fn find_map_any<T, F>(self, f: F) -> Option<T>
+    where
+        F: Fn(Iterator::Item) -> Option<T> + Sync + Send,
+        T: Send,
+    {
+        self.iter.find_map_any(f)
+    }
+}
+
+/// An error returned by [`Result::single()`].
+///
+/// This indicates that a query did not view exactly one entity.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SingleError {
+    /// The query did not view any entities.
+    NoMatches,
+    /// The query viewed more than one entity.
+    MultipleMatches,
+}
+
+impl fmt::Display for SingleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoMatches => f.write_str("query did not view any entities"),
+            Self::MultipleMatches => f.write_str("query viewed more than one entity"),
+        }
+    }
+}
+
 doc::non_root_macro! {
     /// Defines identifiers to match items returned by a [`result::Iter`] iterator.
     ///
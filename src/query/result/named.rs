@@ -0,0 +1,256 @@
+use crate::{
+    doc,
+    query::view::Views,
+};
+
+/// A struct binding each [`View`] of a query result to a named field.
+///
+/// Query results are heterogeneous lists, which are usually unpacked positionally using the
+/// [`result!`] macro. For queries over many components, binding each view by name is less
+/// error-prone. A struct implementing `Named` defines which [`Views`] to query for, and can be
+/// created from each result using its `From` implementation.
+///
+/// This trait is not usually implemented manually. Instead, the [`named!`] macro defines a struct
+/// and implements this trait for it, using the types of the struct's fields as the `Views`.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         result,
+///         result::Named,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Position(f32);
+/// struct Velocity(f32);
+///
+/// result::named! {
+///     struct Movement<'a> {
+///         position: &'a mut Position,
+///         velocity: &'a Velocity,
+///     }
+/// }
+///
+/// let mut world = World::<Registry!(Position, Velocity)>::new();
+/// world.insert(entity!(Position(1.0), Velocity(0.5)));
+///
+/// for Movement { position, velocity } in world
+///     .query(Query::<<Movement as Named>::Views>::new())
+///     .iter
+///     .map(Movement::from)
+/// {
+///     position.0 += velocity.0;
+/// }
+/// ```
+///
+/// [`named!`]: crate::query::result::named!
+/// [`result!`]: crate::query::result!
+/// [`View`]: crate::query::view::View
+/// [`Views`]: trait@crate::query::view::Views
+pub trait Named<'a>: Sized {
+    /// The views queried for, in the order of the struct's fields.
+    type Views: Views<'a>;
+}
+
+doc::non_root_macro! {
+    /// Defines a struct whose fields are bound by name to the results of a query.
+    ///
+    /// Each field's type must be a [`View`]. The struct implements [`Named`], with its `Views` being
+    /// the types of the fields in order, and implements `From` for the query results of those
+    /// `Views`. Attributes and visibility can be provided for the struct and its fields as usual.
+    ///
+    /// If any of the views borrow components, the struct must declare a single lifetime to be used
+    /// by those views.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         result::Named,
+    ///         view,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone, Copy)]
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// result::named! {
+    ///     struct FooBar<'a> {
+    ///         foo: view::Copied<Foo>,
+    ///         bar: Option<&'a mut Bar>,
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// world.insert(entity!(Foo(42), Bar(false)));
+    ///
+    /// for FooBar { foo, bar } in world
+    ///     .query(Query::<<FooBar as Named>::Views>::new())
+    ///     .iter
+    ///     .map(FooBar::from)
+    /// {
+    ///     if let Some(bar) = bar {
+    ///         bar.0 = (*foo).0 == 42;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Named`]: crate::query::result::Named
+    /// [`View`]: crate::query::view::View
+    macro_rules! named {
+        (
+            $(#[$meta:meta])*
+            $vis:vis struct $name:ident<$lifetime:lifetime> {
+                $($(#[$field_meta:meta])* $field_vis:vis $field:ident: $view:ty),* $(,)?
+            }
+        ) => (
+            $(#[$meta])*
+            $vis struct $name<$lifetime> {
+                $($(#[$field_meta])* $field_vis $field: $view),*
+            }
+
+            impl<$lifetime> $crate::query::result::Named<$lifetime> for $name<$lifetime> {
+                type Views = $crate::query::Views!($($view),*);
+            }
+
+            impl<$lifetime> ::core::convert::From<$crate::query::Views!($($view),*)> for $name<$lifetime> {
+                fn from($crate::query::result!($($field),*): $crate::query::Views!($($view),*)) -> Self {
+                    Self {
+                        $($field),*
+                    }
+                }
+            }
+        );
+        (
+            $(#[$meta:meta])*
+            $vis:vis struct $name:ident {
+                $($(#[$field_meta:meta])* $field_vis:vis $field:ident: $view:ty),* $(,)?
+            }
+        ) => (
+            $(#[$meta])*
+            $vis struct $name {
+                $($(#[$field_meta])* $field_vis $field: $view),*
+            }
+
+            impl<'a> $crate::query::result::Named<'a> for $name {
+                type Views = $crate::query::Views!($($view),*);
+            }
+
+            impl ::core::convert::From<$crate::query::Views!($($view),*)> for $name {
+                fn from($crate::query::result!($($field),*): $crate::query::Views!($($view),*)) -> Self {
+                    Self {
+                        $($field),*
+                    }
+                }
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Named;
+    use crate::{
+        entity,
+        query::view,
+        Query,
+        Registry,
+        World,
+    };
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct A(u32);
+    #[derive(Debug, PartialEq)]
+    struct B(char);
+
+    type Registry = Registry!(A, B);
+
+    named! {
+        #[derive(Debug, PartialEq)]
+        struct Borrowed<'a> {
+            a: &'a mut A,
+            b: Option<&'a B>,
+        }
+    }
+
+    named! {
+        #[derive(Debug, PartialEq)]
+        struct Owned {
+            a: view::Copied<A>,
+            identifier: crate::entity::Identifier,
+        }
+    }
+
+    named! {
+        struct Empty {}
+    }
+
+    #[test]
+    fn named_borrowed() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut result = world
+            .query(Query::<<Borrowed as Named>::Views>::new())
+            .iter
+            .map(Borrowed::from)
+            .map(|Borrowed { a, b }| {
+                a.0 += 1;
+                (a.0, b.map(|b| b.0))
+            })
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+
+        assert_eq!(result, vec![(2, Some('a')), (3, None)]);
+    }
+
+    #[test]
+    fn named_owned() {
+        let mut world = World::<Registry>::new();
+        let identifier = world.insert(entity!(A(1), B('a')));
+
+        assert_eq!(
+            world
+                .query(Query::<<Owned as Named>::Views>::new())
+                .iter
+                .map(Owned::from)
+                .collect::<Vec<_>>(),
+            vec![Owned {
+                a: view::Copied(A(1)),
+                identifier,
+            }]
+        );
+    }
+
+    #[test]
+    fn named_empty() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        assert_eq!(
+            world
+                .query(Query::<<Empty as Named>::Views>::new())
+                .iter
+                .map(Empty::from)
+                .count(),
+            2
+        );
+    }
+}
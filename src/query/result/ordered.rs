@@ -0,0 +1,195 @@
+use crate::{
+    archetype::Archetype,
+    archetypes,
+    hlist::Reshape,
+    query::{
+        filter::And,
+        result::Results,
+        view,
+    },
+    registry,
+    registry::{
+        contains::filter::Sealed as ContainsFilterSealed,
+        ContainsQuery,
+    },
+};
+use alloc::vec::{
+    self,
+    Vec,
+};
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+
+/// An [`Iterator`] over the results of a query, visiting archetypes in a deterministic order.
+///
+/// Yields the same results as [`result::Iter`], but the archetypes matching the query are visited
+/// in order of their identifiers rather than in the unspecified order in which they are stored.
+/// Within each archetype, entities are yielded in the order in which they are stored. This means
+/// that two `World`s which had the same sequence of operations performed on them will yield the
+/// results of the same query in the same order, which is useful for snapshot testing and
+/// deterministic simulations.
+///
+/// Creating this iterator requires collecting and sorting the matching archetypes, which requires
+/// an allocation. Therefore, [`result::Iter`] should be preferred when the order of iteration does
+/// not matter.
+///
+/// This `struct` is created by the [`query_ordered`] method on [`World`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         result,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+///
+/// type Registry = Registry!(Foo, Bar);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Foo(1), Bar(true)));
+/// world.insert(entity!(Foo(2)));
+///
+/// let foos = world
+///     .query_ordered(Query::<Views!(&Foo)>::new())
+///     .iter
+///     .map(|result!(foo)| foo.0)
+///     .collect::<Vec<_>>();
+/// ```
+///
+/// [`query_ordered`]: crate::world::World::query_ordered()
+/// [`result::Iter`]: crate::query::result::Iter
+/// [`World`]: crate::world::World
+pub struct Ordered<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+    Views: view::Views<'a>,
+{
+    archetypes_iter: vec::IntoIter<&'a mut Archetype<Registry>>,
+
+    current_results_iter: Option<<Views::Results as Results>::Iterator>,
+
+    /// The number of results remaining, including those remaining in `current_results_iter`.
+    remaining: usize,
+
+    filter: PhantomData<Filter>,
+    indices: PhantomData<Indices>,
+}
+
+impl<'a, Registry, Filter, Views, Indices> Ordered<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+    Views: view::Views<'a>,
+{
+    pub(crate) fn new(archetypes_iter: archetypes::IterMut<'a, Registry>) -> Self
+    where
+        Registry: ContainsQuery<'a, Filter, Views, Indices>,
+    {
+        let mut archetypes = archetypes_iter
+            .filter(|archetype| {
+                // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+                // identifier is generic over. Additionally, the identifier reference created here
+                // will not outlive `archetype`.
+                unsafe {
+                    <Registry as ContainsFilterSealed<
+                        And<Views, Filter>,
+                        And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                    >>::filter(archetype.identifier())
+                }
+            })
+            .collect::<Vec<_>>();
+        // SAFETY: The slices created here do not outlive the archetypes whose identifiers they are
+        // created from.
+        archetypes.sort_unstable_by(|a, b| unsafe {
+            a.identifier().as_slice().cmp(b.identifier().as_slice())
+        });
+        let remaining = archetypes.iter().map(|archetype| archetype.len()).sum();
+
+        Self {
+            archetypes_iter: archetypes.into_iter(),
+
+            current_results_iter: None,
+
+            remaining,
+
+            filter: PhantomData,
+            indices: PhantomData,
+        }
+    }
+}
+
+impl<'a, Registry, Filter, Views, Indices> Iterator
+    for Ordered<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+    type Item = Views;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut results) = self.current_results_iter {
+                if let result @ Some(_) = results.next() {
+                    self.remaining -= 1;
+                    return result;
+                }
+            }
+            let archetype = self.archetypes_iter.next()?;
+            self.current_results_iter = Some(
+                // SAFETY: Each component viewed by `V` is guaranteed to be within the `archetype`,
+                // since archetypes that do not contain the viewed components were filtered out
+                // when this iterator was created.
+                unsafe {
+                    archetype.view::<Views, (
+                        Registry::ViewsContainments,
+                        Registry::ViewsIndices,
+                        Registry::ViewsCanonicalContainments,
+                    )>()
+                }
+                .reshape()
+                .into_iterator(),
+            );
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, Registry, Filter, Views, Indices> ExactSizeIterator
+    for Ordered<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+}
+
+impl<'a, Registry, Filter, Views, Indices> FusedIterator
+    for Ordered<'a, Registry, Filter, Views, Indices>
+where
+    Views: view::Views<'a>,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+}
+
+// SAFETY: This type is safe to send between threads, as its mutable views are guaranteed to be
+// exclusive.
+unsafe impl<'a, Registry, Filter, Views, Indices> Send
+    for Ordered<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+    Views: view::Views<'a>,
+{
+}
@@ -0,0 +1,159 @@
+use crate::{
+    archetypes,
+    query::{
+        filter::And,
+        view::ParViews,
+    },
+    registry,
+    registry::{
+        contains::filter::Sealed as ContainsFilterSealed,
+        ContainsQuery,
+    },
+};
+use core::marker::PhantomData;
+use rayon::iter::{
+    plumbing::UnindexedConsumer,
+    ParallelIterator,
+};
+
+/// A [`ParallelIterator`] over the results of a query, yielding the viewed components of each
+/// archetype as slices.
+///
+/// This is the parallel equivalent of [`ArchetypeSlices`]. Rather than splitting work across
+/// individual entities, each archetype matching the query is yielded as a single item, allowing
+/// its rows to be processed serially in a tight loop within a single task. This has less overhead
+/// than [`ParIter`] when the work done for each entity is cheap and many archetypes are viewed.
+///
+/// The slices are yielded in the same form as [`ArchetypeSlices`]: all slices yielded together
+/// have the same length, and the values at a given index within each slice belong to the same
+/// entity.
+///
+/// This `struct` is created by the [`archetype_slices`] method on [`result::ParIter`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         filter,
+///         result,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+/// use rayon::iter::ParallelIterator;
+///
+/// struct Position(f32);
+/// struct Velocity(f32);
+///
+/// type Registry = Registry!(Position, Velocity);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Position(0.0), Velocity(1.0)));
+///
+/// world
+///     .par_query(Query::<Views!(&mut Position, &Velocity)>::new())
+///     .iter
+///     .archetype_slices()
+///     .for_each(|result!(positions, velocities)| {
+///         for (position, velocity) in positions.iter_mut().zip(velocities) {
+///             position.0 += velocity.0;
+///         }
+///     });
+/// ```
+///
+/// [`ArchetypeSlices`]: crate::query::result::ArchetypeSlices
+/// [`archetype_slices`]: crate::query::result::ParIter::archetype_slices()
+/// [`ParallelIterator`]: rayon::iter::ParallelIterator
+/// [`ParIter`]: crate::query::result::ParIter
+/// [`result::ParIter`]: crate::query::result::ParIter
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+pub struct ParArchetypeSlices<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+    archetypes_iter: archetypes::ParIterMut<'a, Registry>,
+
+    filter: PhantomData<Filter>,
+    views: PhantomData<Views>,
+    indices: PhantomData<Indices>,
+}
+
+impl<'a, Registry, Filter, Views, Indices> ParArchetypeSlices<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+    pub(crate) fn new(archetypes_iter: archetypes::ParIterMut<'a, Registry>) -> Self {
+        Self {
+            archetypes_iter,
+
+            filter: PhantomData,
+            views: PhantomData,
+            indices: PhantomData,
+        }
+    }
+}
+
+// SAFETY: This type is safe to send between threads, as its mutable views are guaranteed to be
+// exclusive.
+unsafe impl<'a, Registry, Filter, Views, Indices> Send
+    for ParArchetypeSlices<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+}
+
+// SAFETY: This type is safe to share between threads, as its mutable views are guaranteed to be
+// exclusive.
+unsafe impl<'a, Registry, Filter, Views, Indices> Sync
+    for ParArchetypeSlices<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+}
+
+impl<'a, Registry, Filter, Views, Indices> ParallelIterator
+    for ParArchetypeSlices<'a, Registry, Filter, Views, Indices>
+where
+    Views: ParViews<'a>,
+    Views::Slices: Send,
+    Registry: ContainsQuery<'a, Filter, Views, Indices>,
+{
+    type Item = Views::Slices;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.archetypes_iter
+            .filter_map(|archetype| {
+                // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+                // identifier is generic over. Additionally, the identifier reference created here
+                // will not outlive `archetype`.
+                if unsafe {
+                    <Registry as ContainsFilterSealed<
+                        And<Views, Filter>,
+                        And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                    >>::filter(archetype.identifier())
+                } {
+                    Some(
+                        // SAFETY: Each component viewed by `V` is guaranteed to be within the
+                        // `archetype`, since the `filter` function in the if-statement returned
+                        // `true`.
+                        unsafe {
+                            archetype.slices::<Views, (
+                                Registry::ViewsContainments,
+                                Registry::ViewsIndices,
+                                Registry::ViewsCanonicalContainments,
+                            )>()
+                        },
+                    )
+                } else {
+                    None
+                }
+            })
+            .drive_unindexed(consumer)
+    }
+}
@@ -4,7 +4,10 @@ use crate::{
     hlist::Reshape,
     query::{
         filter::And,
-        result::ParResults,
+        result::{
+            ParArchetypeSlices,
+            ParResults,
+        },
         view::ParViews,
     },
     registry,
@@ -21,6 +24,7 @@ use rayon::iter::{
         Reducer,
         UnindexedConsumer,
     },
+    IndexedParallelIterator,
     ParallelIterator,
 };
 
@@ -79,6 +83,11 @@ where
 {
     archetypes_iter: archetypes::ParIterMut<'a, Registry>,
 
+    /// The minimum number of entities of an archetype processed by a single task.
+    min_len: usize,
+    /// The maximum number of entities of an archetype processed by a single task.
+    max_len: usize,
+
     filter: PhantomData<Filter>,
     views: PhantomData<Views>,
     indices: PhantomData<Indices>,
@@ -92,6 +101,9 @@ where
         Self {
             archetypes_iter,
 
+            min_len: 1,
+            max_len: usize::MAX,
+
             filter: PhantomData,
             views: PhantomData,
             indices: PhantomData,
@@ -99,6 +111,147 @@ where
     }
 }
 
+impl<'a, Registry, Filter, Views, Indices> ParIter<'a, Registry, Filter, Views, Indices>
+where
+    Registry: registry::Registry,
+{
+    /// Converts this iterator into a parallel iterator over the slices of viewed components of
+    /// each archetype.
+    ///
+    /// Each archetype matching the query is yielded as a single item, rather than parallelizing
+    /// over each individual entity. See [`ParArchetypeSlices`] for more details.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// world.insert(entity!(Foo(1)));
+    /// world.insert(entity!(Foo(2)));
+    /// world.insert(entity!(Foo(3)));
+    ///
+    /// let sum = world
+    ///     .par_query(Query::<Views!(&Foo)>::new())
+    ///     .iter
+    ///     .archetype_slices()
+    ///     .map(|result!(foos)| foos.iter().map(|foo| foo.0).sum::<u32>())
+    ///     .sum::<u32>();
+    ///
+    /// assert_eq!(sum, 6);
+    /// ```
+    ///
+    /// [`ParArchetypeSlices`]: crate::query::result::ParArchetypeSlices
+    #[must_use]
+    pub fn archetype_slices(self) -> ParArchetypeSlices<'a, Registry, Filter, Views, Indices> {
+        ParArchetypeSlices::new(self.archetypes_iter)
+    }
+
+    /// Sets the minimum number of entities within an archetype that will be processed by a
+    /// single task.
+    ///
+    /// The entities of each archetype matching the query are split into chunks to be processed in
+    /// parallel. Raising the minimum length of these chunks avoids splitting cheap work more than
+    /// is worthwhile. Note that an archetype containing fewer entities than this will still be
+    /// processed as its own chunk.
+    ///
+    /// By default, the minimum length is `1`, leaving the splitting to `rayon`. This is
+    /// equivalent to [`IndexedParallelIterator::with_min_len()`] applied to the entities of each
+    /// archetype.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// for i in 0..1000 {
+    ///     world.insert(entity!(Foo(i)));
+    /// }
+    ///
+    /// world
+    ///     .par_query(Query::<Views!(&mut Foo)>::new())
+    ///     .iter
+    ///     .with_min_len(256)
+    ///     .for_each(|result!(foo)| foo.0 += 1);
+    /// ```
+    ///
+    /// [`IndexedParallelIterator::with_min_len()`]: rayon::iter::IndexedParallelIterator::with_min_len()
+    #[must_use]
+    pub fn with_min_len(mut self, min_len: usize) -> Self {
+        self.min_len = min_len;
+        self
+    }
+
+    /// Sets the maximum number of entities within an archetype that will be processed by a
+    /// single task.
+    ///
+    /// The entities of each archetype matching the query are split into chunks to be processed in
+    /// parallel. Lowering the maximum length of these chunks ensures expensive work is split
+    /// enough to be spread across threads.
+    ///
+    /// By default, the maximum length is [`usize::MAX`], leaving the splitting to `rayon`. This is
+    /// equivalent to [`IndexedParallelIterator::with_max_len()`] applied to the entities of each
+    /// archetype.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// for i in 0..100 {
+    ///     world.insert(entity!(Foo(i)));
+    /// }
+    ///
+    /// world
+    ///     .par_query(Query::<Views!(&mut Foo)>::new())
+    ///     .iter
+    ///     .with_max_len(1)
+    ///     .for_each(|result!(foo)| foo.0 += 1);
+    /// ```
+    ///
+    /// [`IndexedParallelIterator::with_max_len()`]: rayon::iter::IndexedParallelIterator::with_max_len()
+    #[must_use]
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+}
+
 // SAFETY: This type is safe to send between threads, as its mutable views are guaranteed to be
 // exclusive.
 unsafe impl<'a, Registry, Filter, Views, Indices> Send
@@ -129,7 +282,8 @@ where
     where
         C: UnindexedConsumer<Self::Item>,
     {
-        let consumer = ResultsConsumer::<_, Filter, Views, Indices>::new(consumer);
+        let consumer =
+            ResultsConsumer::<_, Filter, Views, Indices>::new(consumer, self.min_len, self.max_len);
         self.archetypes_iter.drive_unindexed(consumer)
     }
 }
@@ -137,16 +291,22 @@ where
 struct ResultsConsumer<Consumer, Filter, Views, Indices> {
     base: Consumer,
 
+    min_len: usize,
+    max_len: usize,
+
     filter: PhantomData<Filter>,
     views: PhantomData<Views>,
     indices: PhantomData<Indices>,
 }
 
 impl<Consumer, Filter, Views, Indices> ResultsConsumer<Consumer, Filter, Views, Indices> {
-    fn new(base: Consumer) -> Self {
+    fn new(base: Consumer, min_len: usize, max_len: usize) -> Self {
         Self {
             base,
 
+            min_len,
+            max_len,
+
             filter: PhantomData,
             views: PhantomData,
             indices: PhantomData,
@@ -183,8 +343,8 @@ where
     fn split_at(self, index: usize) -> (Self, Self, _Consumer::Reducer) {
         let (left, right, reducer) = self.base.split_at(index);
         (
-            ResultsConsumer::new(left),
-            ResultsConsumer::new(right),
+            ResultsConsumer::new(left, self.min_len, self.max_len),
+            ResultsConsumer::new(right, self.min_len, self.max_len),
             reducer,
         )
     }
@@ -194,6 +354,9 @@ where
             base: self.base,
             previous: None,
 
+            min_len: self.min_len,
+            max_len: self.max_len,
+
             filter: PhantomData,
             views: PhantomData,
             indices: PhantomData,
@@ -214,7 +377,7 @@ where
     Registry: ContainsParQuery<'a, Filter, Views, Indices>,
 {
     fn split_off_left(&self) -> Self {
-        ResultsConsumer::new(self.base.split_off_left())
+        ResultsConsumer::new(self.base.split_off_left(), self.min_len, self.max_len)
     }
 
     fn to_reducer(&self) -> Self::Reducer {
@@ -226,6 +389,9 @@ struct ResultsFolder<Consumer, Previous, Filter, Views, Indices> {
     base: Consumer,
     previous: Option<Previous>,
 
+    min_len: usize,
+    max_len: usize,
+
     filter: PhantomData<Filter>,
     views: PhantomData<Views>,
     indices: PhantomData<Indices>,
@@ -255,7 +421,7 @@ where
             let result =
                 // SAFETY: Each component viewed by `V` is guaranteed to be within the `archetype`
                 // since the `filter` function in the if-statement returned `true`.
-                unsafe { archetype.par_view::<Views, _, _, _>() }.reshape().into_parallel_iterator().drive_unindexed(consumer);
+                unsafe { archetype.par_view::<Views, _, _, _>() }.reshape().into_parallel_iterator().with_min_len(self.min_len).with_max_len(self.max_len).drive_unindexed(consumer);
 
             let previous = match self.previous {
                 None => Some(result),
@@ -269,6 +435,9 @@ where
                 base: self.base,
                 previous,
 
+                min_len: self.min_len,
+                max_len: self.max_len,
+
                 filter: self.filter,
                 views: self.views,
                 indices: self.indices,
@@ -0,0 +1,308 @@
+use crate::{
+    component::Component,
+    entity,
+    query::view,
+};
+
+/// Inspects which components are present within a single query result.
+///
+/// A query result is a heterogeneous list of the values returned for each [`View`]. Optional
+/// views, such as `Option<&C>` and `Option<&mut C>`, may or may not contain a component, while
+/// every other view is always present. When many optional components are viewed at once, this
+/// trait allows reasoning about their presence as a whole before unpacking the result with
+/// [`result!`].
+///
+/// This trait is implemented for every query result, and cannot be implemented by external
+/// types.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         result,
+///         result::Presence,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+/// struct Baz(char);
+///
+/// type Registry = Registry!(Foo, Bar, Baz);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Foo(1), Bar(true), Baz('a')));
+/// world.insert(entity!(Foo(2), Bar(false)));
+/// world.insert(entity!(Foo(3)));
+///
+/// let fully_present = world
+///     .query(Query::<Views!(&Foo, Option<&Bar>, Option<&Baz>)>::new())
+///     .iter
+///     .filter(Presence::all_present)
+///     .map(|result!(foo, _bar, _baz)| foo.0)
+///     .collect::<Vec<_>>();
+/// assert_eq!(fully_present, vec![1]);
+/// ```
+///
+/// [`result!`]: crate::query::result!
+/// [`View`]: trait@crate::query::view::View
+pub trait Presence: Sealed {
+    /// Returns the number of viewed values that are present.
+    ///
+    /// Views that are not optional are always counted.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result::Presence,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// world.insert(entity!(Foo(42)));
+    ///
+    /// let mut query_result = world.query(Query::<Views!(&Foo, Option<&Bar>)>::new()).iter;
+    ///
+    /// assert_eq!(query_result.next().unwrap().count_present(), 1);
+    /// ```
+    fn count_present(&self) -> usize;
+
+    /// Returns `true` if every viewed value is present.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result::Presence,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// let mut query_result = world.query(Query::<Views!(&Foo, Option<&Bar>)>::new()).iter;
+    ///
+    /// assert!(query_result.next().unwrap().all_present());
+    /// ```
+    fn all_present(&self) -> bool;
+
+    /// Returns `true` if at least one viewed value is present.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result::Presence,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// world.insert(entity!());
+    ///
+    /// let mut query_result = world
+    ///     .query(Query::<Views!(Option<&Foo>, Option<&Bar>)>::new())
+    ///     .iter;
+    ///
+    /// assert!(!query_result.next().unwrap().any_present());
+    /// ```
+    fn any_present(&self) -> bool;
+}
+
+pub trait Sealed {}
+
+impl Sealed for view::Null {}
+
+impl Presence for view::Null {
+    fn count_present(&self) -> usize {
+        0
+    }
+
+    fn all_present(&self) -> bool {
+        true
+    }
+
+    fn any_present(&self) -> bool {
+        false
+    }
+}
+
+impl<V, W> Sealed for (V, W)
+where
+    V: Slot,
+    W: Presence,
+{
+}
+
+impl<V, W> Presence for (V, W)
+where
+    V: Slot,
+    W: Presence,
+{
+    fn count_present(&self) -> usize {
+        usize::from(self.0.is_present()) + self.1.count_present()
+    }
+
+    fn all_present(&self) -> bool {
+        self.0.is_present() && self.1.all_present()
+    }
+
+    fn any_present(&self) -> bool {
+        self.0.is_present() || self.1.any_present()
+    }
+}
+
+/// A single value within a query result.
+pub trait Slot {
+    /// Returns whether the viewed value is present.
+    fn is_present(&self) -> bool;
+}
+
+impl<C> Slot for &C
+where
+    C: Component,
+{
+    fn is_present(&self) -> bool {
+        true
+    }
+}
+
+impl<C> Slot for &mut C
+where
+    C: Component,
+{
+    fn is_present(&self) -> bool {
+        true
+    }
+}
+
+impl<C> Slot for Option<&C>
+where
+    C: Component,
+{
+    fn is_present(&self) -> bool {
+        self.is_some()
+    }
+}
+
+impl<C> Slot for Option<&mut C>
+where
+    C: Component,
+{
+    fn is_present(&self) -> bool {
+        self.is_some()
+    }
+}
+
+impl<C> Slot for view::Copied<C>
+where
+    C: Component,
+{
+    fn is_present(&self) -> bool {
+        true
+    }
+}
+
+impl Slot for entity::Identifier {
+    fn is_present(&self) -> bool {
+        true
+    }
+}
+
+impl Slot for view::Index {
+    fn is_present(&self) -> bool {
+        true
+    }
+}
+
+impl Slot for view::ArchetypeId {
+    fn is_present(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Presence;
+    use crate::{
+        entity,
+        query::{
+            view,
+            Views,
+        },
+    };
+
+    struct A(u32);
+    struct B(char);
+
+    #[test]
+    fn empty() {
+        let result: Views!() = view::Null;
+
+        assert_eq!(result.count_present(), 0);
+        assert!(result.all_present());
+        assert!(!result.any_present());
+    }
+
+    #[test]
+    fn all_present() {
+        let a = A(42);
+        let mut b = B('a');
+        let result: Views!(&A, Option<&mut B>, entity::Identifier) = (
+            &a,
+            (Some(&mut b), (entity::Identifier::new(0, 0), view::Null)),
+        );
+
+        assert_eq!(result.count_present(), 3);
+        assert!(result.all_present());
+        assert!(result.any_present());
+    }
+
+    #[test]
+    fn some_present() {
+        let a = A(42);
+        let result: Views!(Option<&A>, Option<&B>) = (Some(&a), (None, view::Null));
+
+        assert_eq!(result.count_present(), 1);
+        assert!(!result.all_present());
+        assert!(result.any_present());
+    }
+
+    #[test]
+    fn none_present() {
+        let result: Views!(Option<&A>, Option<&mut B>) = (None, (None, view::Null));
+
+        assert_eq!(result.count_present(), 0);
+        assert!(!result.all_present());
+        assert!(!result.any_present());
+    }
+}
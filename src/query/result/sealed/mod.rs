@@ -4,17 +4,20 @@ mod par;
 #[cfg(feature = "rayon")]
 pub(crate) use par::ParResults;
 
-use crate::query::view;
+use crate::query::{
+    view,
+    view::RepeatN,
+};
 use core::iter;
 
 pub trait Results {
     type View;
-    type Iterator: Iterator<Item = Self::View>;
+    type Iterator: DoubleEndedIterator<Item = Self::View> + ExactSizeIterator;
 
     fn into_iterator(self) -> Self::Iterator;
 }
 
-impl Results for iter::Take<iter::Repeat<view::Null>> {
+impl Results for RepeatN<view::Null> {
     type View = view::Null;
     type Iterator = Self;
 
@@ -25,7 +28,7 @@ impl Results for iter::Take<iter::Repeat<view::Null>> {
 
 impl<C, I, R> Results for (I, R)
 where
-    I: Iterator<Item = C>,
+    I: DoubleEndedIterator<Item = C> + ExactSizeIterator,
     R: Results,
 {
     type View = (C, R::View);
@@ -0,0 +1,162 @@
+use crate::system::schedule::claim::{
+    self,
+    decision,
+    Claims,
+};
+
+/// Returns whether two sets of [`Views`] conflict with each other.
+///
+/// Two sets of views conflict when one of them accesses a component mutably and the other accesses
+/// the same component at all. This is the same check used to decide whether two tasks in a
+/// [`Schedule`] can run in the same stage: tasks whose views conflict are split into separate
+/// stages, while tasks whose views do not conflict are run in parallel.
+///
+/// Since this is a `const fn`, it can be used to assert at compile time that two systems will be
+/// run in parallel. Unlike [`Disjoint`], which can only be used as a bound, this returns a value
+/// for any pair of views.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     query::{
+///         view,
+///         Views,
+///     },
+///     Registry,
+/// };
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+///
+/// type Registry = Registry!(Foo, Bar);
+///
+/// const _: () = assert!(!view::conflicts::<
+///     Views!(&mut Foo, &Bar),
+///     Views!(&Bar),
+///     Registry,
+///     _,
+/// >());
+/// const _: () = assert!(view::conflicts::<Views!(&mut Foo), Views!(&Foo), Registry, _>());
+/// ```
+///
+/// [`Disjoint`]: crate::query::view::Disjoint
+/// [`Schedule`]: trait@crate::system::schedule::Schedule
+/// [`Views`]: trait@crate::query::view::Views
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+#[must_use]
+pub const fn conflicts<'a, Views, OtherViews, Registry, Indices>() -> bool
+where
+    Views: Sealed<'a, OtherViews, Registry, Indices>,
+{
+    Views::CONFLICTS
+}
+
+pub trait Sealed<'a, OtherViews, Registry, Indices> {
+    /// Whether the views conflict with `OtherViews`.
+    const CONFLICTS: bool;
+}
+
+impl<'a, Views, OtherViews, Registry, ViewsIndices, Parameters, InverseIndices>
+    Sealed<'a, OtherViews, Registry, (ViewsIndices, Parameters, InverseIndices)> for Views
+where
+    (OtherViews, claim::Null):
+        Claims<'a, Views, ViewsIndices, Parameters, Registry, (InverseIndices, claim::Null)>,
+    <(OtherViews, claim::Null) as Claims<
+        'a,
+        Views,
+        ViewsIndices,
+        Parameters,
+        Registry,
+        (InverseIndices, claim::Null),
+    >>::Decision: decision::Decision,
+{
+    const CONFLICTS: bool = <<(OtherViews, claim::Null) as Claims<
+        'a,
+        Views,
+        ViewsIndices,
+        Parameters,
+        Registry,
+        (InverseIndices, claim::Null),
+    >>::Decision as decision::Decision>::CUT;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::conflicts;
+    use crate::{
+        entity,
+        query::{
+            view,
+            Views,
+        },
+        Registry,
+    };
+
+    // Define components.
+    struct A;
+    struct B;
+    struct C;
+
+    type Registry = Registry!(A, B, C);
+
+    #[test]
+    fn empty() {
+        assert!(!conflicts::<Views!(), Views!(), Registry, _>());
+    }
+
+    #[test]
+    fn shared_immutable_views() {
+        assert!(!conflicts::<
+            Views!(&A, Option<&B>),
+            Views!(&A, &B),
+            Registry,
+            _,
+        >());
+    }
+
+    #[test]
+    fn disjoint_mutable_views() {
+        assert!(!conflicts::<
+            Views!(&mut A, &B),
+            Views!(&mut C, &B),
+            Registry,
+            _,
+        >());
+    }
+
+    #[test]
+    fn mutable_and_immutable_views() {
+        assert!(conflicts::<Views!(&mut A), Views!(&A), Registry, _>());
+        assert!(conflicts::<Views!(&A), Views!(&mut A), Registry, _>());
+    }
+
+    #[test]
+    fn mutable_and_mutable_views() {
+        assert!(conflicts::<
+            Views!(Option<&mut A>),
+            Views!(&mut A),
+            Registry,
+            _,
+        >());
+    }
+
+    #[test]
+    fn copied_and_mutable_views() {
+        assert!(conflicts::<
+            Views!(view::Copied<A>),
+            Views!(&mut A),
+            Registry,
+            _,
+        >());
+    }
+
+    #[test]
+    fn entity_identifiers() {
+        assert!(!conflicts::<
+            Views!(entity::Identifier, &mut A),
+            Views!(entity::Identifier, &mut B),
+            Registry,
+            _,
+        >());
+    }
+}
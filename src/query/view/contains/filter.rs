@@ -122,6 +122,26 @@ where
     }
 }
 
+impl<'a, Component, Views> Sealed<'a, Has<Component>, index::Index>
+    for (view::Copied<Component>, Views)
+where
+    Component: component::Component,
+    Views: view::Views<'a>,
+    Self: view::Views<'a, Indices = (usize, Views::Indices)>,
+{
+    unsafe fn filter<Registry>(
+        indices: &Self::Indices,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> bool
+    where
+        Registry: registry::Registry,
+    {
+        let index = indices.0;
+        // SAFETY: `index` is guaranteed to be a valid index into the `Registry`.
+        unsafe { identifier.get_unchecked(index) }
+    }
+}
+
 impl<'a, Component, View, Views, Index> Sealed<'a, Has<Component>, (Index,)> for (View, Views)
 where
     Component: component::Component,
@@ -356,6 +376,86 @@ where
     }
 }
 
+impl<'a, Component, Views> Sealed<'a, &'a Component, index::Index>
+    for (view::Copied<Component>, Views)
+where
+    Component: component::Component,
+    Views: view::Views<'a>,
+    Self: view::Views<'a, Indices = (usize, Views::Indices)>,
+{
+    unsafe fn filter<Registry>(
+        indices: &Self::Indices,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> bool
+    where
+        Registry: registry::Registry,
+    {
+        let index = indices.0;
+        // SAFETY: `index` is guaranteed to be a valid index into the `Registry`.
+        unsafe { identifier.get_unchecked(index) }
+    }
+}
+
+impl<'a, Component, Views> Sealed<'a, view::Copied<Component>, index::Index>
+    for (view::Copied<Component>, Views)
+where
+    Component: component::Component,
+    Views: view::Views<'a>,
+    Self: view::Views<'a, Indices = (usize, Views::Indices)>,
+{
+    unsafe fn filter<Registry>(
+        indices: &Self::Indices,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> bool
+    where
+        Registry: registry::Registry,
+    {
+        let index = indices.0;
+        // SAFETY: `index` is guaranteed to be a valid index into the `Registry`.
+        unsafe { identifier.get_unchecked(index) }
+    }
+}
+
+impl<'a, Component, Views> Sealed<'a, view::Copied<Component>, index::Index>
+    for (&'a Component, Views)
+where
+    Component: component::Component,
+    Views: view::Views<'a>,
+    Self: view::Views<'a, Indices = (usize, Views::Indices)>,
+{
+    unsafe fn filter<Registry>(
+        indices: &Self::Indices,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> bool
+    where
+        Registry: registry::Registry,
+    {
+        let index = indices.0;
+        // SAFETY: `index` is guaranteed to be a valid index into the `Registry`.
+        unsafe { identifier.get_unchecked(index) }
+    }
+}
+
+impl<'a, Component, Views> Sealed<'a, view::Copied<Component>, index::Index>
+    for (&'a mut Component, Views)
+where
+    Component: component::Component,
+    Views: view::Views<'a>,
+    Self: view::Views<'a, Indices = (usize, Views::Indices)>,
+{
+    unsafe fn filter<Registry>(
+        indices: &Self::Indices,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> bool
+    where
+        Registry: registry::Registry,
+    {
+        let index = indices.0;
+        // SAFETY: `index` is guaranteed to be a valid index into the `Registry`.
+        unsafe { identifier.get_unchecked(index) }
+    }
+}
+
 impl<'a, Component, View, Views, Index> Sealed<'a, &'a Component, (Index,)> for (View, Views)
 where
     Component: component::Component,
@@ -394,6 +494,26 @@ where
     }
 }
 
+impl<'a, Component, View, Views, Index> Sealed<'a, view::Copied<Component>, (Index,)>
+    for (View, Views)
+where
+    Component: component::Component,
+    View: view::View<'a>,
+    Views: Sealed<'a, view::Copied<Component>, Index>,
+    Self: view::Views<'a, Indices = (View::Index, Views::Indices)>,
+{
+    unsafe fn filter<Registry>(
+        indices: &Self::Indices,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> bool
+    where
+        Registry: registry::Registry,
+    {
+        // SAFETY: `indices.1` is guaranteed to contain valid indices into `Registry`.
+        unsafe { Views::filter(&indices.1, identifier) }
+    }
+}
+
 impl<'a, Views> Sealed<'a, entity::Identifier, index::Index> for Views
 where
     Self: view::Views<'a>,
@@ -409,6 +529,36 @@ where
     }
 }
 
+impl<'a, Views> Sealed<'a, view::Index, index::Index> for Views
+where
+    Self: view::Views<'a>,
+{
+    unsafe fn filter<Registry>(
+        _indices: &Self::Indices,
+        _identifier: archetype::IdentifierRef<Registry>,
+    ) -> bool
+    where
+        Registry: registry::Registry,
+    {
+        true
+    }
+}
+
+impl<'a, Views> Sealed<'a, view::ArchetypeId, index::Index> for Views
+where
+    Self: view::Views<'a>,
+{
+    unsafe fn filter<Registry>(
+        _indices: &Self::Indices,
+        _identifier: archetype::IdentifierRef<Registry>,
+    ) -> bool
+    where
+        Registry: registry::Registry,
+    {
+        true
+    }
+}
+
 impl<'a, Views> Sealed<'a, view::Null, index::Index> for Views
 where
     Self: view::Views<'a>,
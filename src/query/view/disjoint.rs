@@ -84,6 +84,14 @@ where
     type Result = <Views as MutableInverse<Registry, Indices>>::Result;
 }
 
+impl<Component, Views, Registry, Indices> MutableInverse<Registry, Indices>
+    for (view::Copied<Component>, Views)
+where
+    Views: MutableInverse<Registry, Indices>,
+{
+    type Result = <Views as MutableInverse<Registry, Indices>>::Result;
+}
+
 impl<Views, Registry, Indices> MutableInverse<Registry, Indices> for (entity::Identifier, Views)
 where
     Views: MutableInverse<Registry, Indices>,
@@ -91,6 +99,20 @@ where
     type Result = <Views as MutableInverse<Registry, Indices>>::Result;
 }
 
+impl<Views, Registry, Indices> MutableInverse<Registry, Indices> for (view::Index, Views)
+where
+    Views: MutableInverse<Registry, Indices>,
+{
+    type Result = <Views as MutableInverse<Registry, Indices>>::Result;
+}
+
+impl<Views, Registry, Indices> MutableInverse<Registry, Indices> for (view::ArchetypeId, Views)
+where
+    Views: MutableInverse<Registry, Indices>,
+{
+    type Result = <Views as MutableInverse<Registry, Indices>>::Result;
+}
+
 impl<Component, Views, Registry, Index, Indices> MutableInverse<Registry, (Index, Indices)>
     for (&mut Component, Views)
 where
@@ -116,7 +138,10 @@ mod tests {
     use super::Disjoint;
     use crate::{
         entity,
-        query::Views,
+        query::{
+            view,
+            Views,
+        },
         Registry,
     };
 
@@ -182,4 +207,19 @@ mod tests {
     fn entity_identifier() {
         is_disjoint::<Views!(entity::Identifier), Views!(entity::Identifier), Registry, _>();
     }
+
+    #[test]
+    fn index() {
+        is_disjoint::<Views!(&mut A, view::Index), Views!(&mut B, view::Index), Registry, _>();
+    }
+
+    #[test]
+    fn archetype_id() {
+        is_disjoint::<
+            Views!(&mut A, view::ArchetypeId),
+            Views!(&mut B, view::ArchetypeId),
+            Registry,
+            _,
+        >();
+    }
 }
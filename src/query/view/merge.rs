@@ -30,6 +30,117 @@ impl Merge<view::Null, view::Null, Null> for registry::Null {
     type Merged = view::Null;
 }
 
+// Index.
+//
+// `view::Index` is always canonically ordered last, after all components have been merged.
+impl Merge<(view::Index, view::Null), view::Null, (Left, Null)> for registry::Null {
+    type Merged = (view::Index, view::Null);
+}
+
+impl Merge<view::Null, (view::Index, view::Null), (Right, Null)> for registry::Null {
+    type Merged = (view::Index, view::Null);
+}
+
+impl Merge<(view::Index, view::Null), (view::Index, view::Null), (Both, Null)> for registry::Null {
+    type Merged = (view::Index, view::Null);
+}
+
+// Archetype identifier.
+//
+// `view::ArchetypeId` is always canonically ordered last, after `view::Index`.
+impl Merge<(view::ArchetypeId, view::Null), view::Null, (Neither, (Left, Null))>
+    for registry::Null
+{
+    type Merged = (view::ArchetypeId, view::Null);
+}
+
+impl Merge<view::Null, (view::ArchetypeId, view::Null), (Neither, (Right, Null))>
+    for registry::Null
+{
+    type Merged = (view::ArchetypeId, view::Null);
+}
+
+impl
+    Merge<(view::ArchetypeId, view::Null), (view::ArchetypeId, view::Null), (Neither, (Both, Null))>
+    for registry::Null
+{
+    type Merged = (view::ArchetypeId, view::Null);
+}
+
+impl Merge<(view::Index, (view::ArchetypeId, view::Null)), view::Null, (Left, (Left, Null))>
+    for registry::Null
+{
+    type Merged = (view::Index, (view::ArchetypeId, view::Null));
+}
+
+impl Merge<(view::Index, view::Null), (view::ArchetypeId, view::Null), (Left, (Right, Null))>
+    for registry::Null
+{
+    type Merged = (view::Index, (view::ArchetypeId, view::Null));
+}
+
+impl
+    Merge<
+        (view::Index, (view::ArchetypeId, view::Null)),
+        (view::ArchetypeId, view::Null),
+        (Left, (Both, Null)),
+    > for registry::Null
+{
+    type Merged = (view::Index, (view::ArchetypeId, view::Null));
+}
+
+impl Merge<(view::ArchetypeId, view::Null), (view::Index, view::Null), (Right, (Left, Null))>
+    for registry::Null
+{
+    type Merged = (view::Index, (view::ArchetypeId, view::Null));
+}
+
+impl Merge<view::Null, (view::Index, (view::ArchetypeId, view::Null)), (Right, (Right, Null))>
+    for registry::Null
+{
+    type Merged = (view::Index, (view::ArchetypeId, view::Null));
+}
+
+impl
+    Merge<
+        (view::ArchetypeId, view::Null),
+        (view::Index, (view::ArchetypeId, view::Null)),
+        (Right, (Both, Null)),
+    > for registry::Null
+{
+    type Merged = (view::Index, (view::ArchetypeId, view::Null));
+}
+
+impl
+    Merge<
+        (view::Index, (view::ArchetypeId, view::Null)),
+        (view::Index, view::Null),
+        (Both, (Left, Null)),
+    > for registry::Null
+{
+    type Merged = (view::Index, (view::ArchetypeId, view::Null));
+}
+
+impl
+    Merge<
+        (view::Index, view::Null),
+        (view::Index, (view::ArchetypeId, view::Null)),
+        (Both, (Right, Null)),
+    > for registry::Null
+{
+    type Merged = (view::Index, (view::ArchetypeId, view::Null));
+}
+
+impl
+    Merge<
+        (view::Index, (view::ArchetypeId, view::Null)),
+        (view::Index, (view::ArchetypeId, view::Null)),
+        (Both, (Both, Null)),
+    > for registry::Null
+{
+    type Merged = (view::Index, (view::ArchetypeId, view::Null));
+}
+
 // Neither.
 impl<Component, Registry, Views, OtherViews, Containments>
     Merge<Views, OtherViews, (Neither, Containments)> for (Component, Registry)
@@ -86,6 +197,18 @@ where
     );
 }
 
+impl<Component, Registry, Views, OtherViews, Containments>
+    Merge<(view::Copied<Component>, Views), OtherViews, (Left, Containments)>
+    for (Component, Registry)
+where
+    Registry: Merge<Views, OtherViews, Containments>,
+{
+    type Merged = (
+        view::Copied<Component>,
+        <Registry as Merge<Views, OtherViews, Containments>>::Merged,
+    );
+}
+
 impl<Registry, Views, OtherViews, Containments>
     Merge<(entity::Identifier, Views), OtherViews, (Left, Containments)>
     for (EntityIdentifierMarker, Registry)
@@ -145,6 +268,18 @@ where
     );
 }
 
+impl<Component, Registry, Views, OtherViews, Containments>
+    Merge<Views, (view::Copied<Component>, OtherViews), (Right, Containments)>
+    for (Component, Registry)
+where
+    Registry: Merge<Views, OtherViews, Containments>,
+{
+    type Merged = (
+        view::Copied<Component>,
+        <Registry as Merge<Views, OtherViews, Containments>>::Merged,
+    );
+}
+
 impl<Registry, Views, OtherViews, Containments>
     Merge<Views, (entity::Identifier, OtherViews), (Right, Containments)>
     for (EntityIdentifierMarker, Registry)
@@ -206,6 +341,75 @@ where
     );
 }
 
+impl<Component, Registry, Views, OtherViews, Containments>
+    Merge<
+        (view::Copied<Component>, Views),
+        (view::Copied<Component>, OtherViews),
+        (Both, Containments),
+    > for (Component, Registry)
+where
+    Registry: Merge<Views, OtherViews, Containments>,
+{
+    type Merged = (
+        view::Copied<Component>,
+        <Registry as Merge<Views, OtherViews, Containments>>::Merged,
+    );
+}
+
+impl<'a, Component, Registry, Views, OtherViews, Containments>
+    Merge<(view::Copied<Component>, Views), (&'a Component, OtherViews), (Both, Containments)>
+    for (Component, Registry)
+where
+    Registry: Merge<Views, OtherViews, Containments>,
+{
+    type Merged = (
+        &'a Component,
+        <Registry as Merge<Views, OtherViews, Containments>>::Merged,
+    );
+}
+
+impl<'a, Component, Registry, Views, OtherViews, Containments>
+    Merge<(&'a Component, Views), (view::Copied<Component>, OtherViews), (Both, Containments)>
+    for (Component, Registry)
+where
+    Registry: Merge<Views, OtherViews, Containments>,
+{
+    type Merged = (
+        &'a Component,
+        <Registry as Merge<Views, OtherViews, Containments>>::Merged,
+    );
+}
+
+impl<'a, Component, Registry, Views, OtherViews, Containments>
+    Merge<
+        (view::Copied<Component>, Views),
+        (Option<&'a Component>, OtherViews),
+        (Both, Containments),
+    > for (Component, Registry)
+where
+    Registry: Merge<Views, OtherViews, Containments>,
+{
+    type Merged = (
+        &'a Component,
+        <Registry as Merge<Views, OtherViews, Containments>>::Merged,
+    );
+}
+
+impl<'a, Component, Registry, Views, OtherViews, Containments>
+    Merge<
+        (Option<&'a Component>, Views),
+        (view::Copied<Component>, OtherViews),
+        (Both, Containments),
+    > for (Component, Registry)
+where
+    Registry: Merge<Views, OtherViews, Containments>,
+{
+    type Merged = (
+        &'a Component,
+        <Registry as Merge<Views, OtherViews, Containments>>::Merged,
+    );
+}
+
 impl<Registry, Views, OtherViews, Containments>
     Merge<(entity::Identifier, Views), (entity::Identifier, OtherViews), (Both, Containments)>
     for (EntityIdentifierMarker, Registry)
@@ -223,7 +427,10 @@ mod tests {
     use super::Merge;
     use crate::{
         entity,
-        query::Views,
+        query::{
+            view,
+            Views,
+        },
         registry::contains::EntityIdentifierMarker,
         Registry,
     };
@@ -331,6 +538,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn left_copied() {
+        assert_eq!(
+            TypeId::of::<Views!(view::Copied<A>)>(),
+            TypeId::of::<<Registry as Merge<Views!(view::Copied<A>), Views!(), _>>::Merged>()
+        );
+    }
+
+    #[test]
+    fn right_copied() {
+        assert_eq!(
+            TypeId::of::<Views!(view::Copied<A>)>(),
+            TypeId::of::<<Registry as Merge<Views!(), Views!(view::Copied<A>), _>>::Merged>()
+        );
+    }
+
+    #[test]
+    fn both_views_contain_copied() {
+        assert_eq!(
+            TypeId::of::<Views!(view::Copied<A>)>(),
+            TypeId::of::<
+                <Registry as Merge<Views!(view::Copied<A>), Views!(view::Copied<A>), _>>::Merged,
+            >()
+        );
+    }
+
+    #[test]
+    fn copied_and_immutable_reference() {
+        assert_eq!(
+            TypeId::of::<Views!(&A)>(),
+            TypeId::of::<<Registry as Merge<Views!(view::Copied<A>), Views!(&A), _>>::Merged>()
+        );
+    }
+
     #[test]
     fn different_views() {
         assert_eq!(
@@ -380,4 +621,61 @@ mod tests {
             >()
         );
     }
+
+    #[test]
+    fn index_left() {
+        assert_eq!(
+            TypeId::of::<Views!(&A, view::Index)>(),
+            TypeId::of::<<Registry as Merge<Views!(&A, view::Index), Views!(&A), _>>::Merged>()
+        );
+    }
+
+    #[test]
+    fn index_right() {
+        assert_eq!(
+            TypeId::of::<Views!(&A, view::Index)>(),
+            TypeId::of::<<Registry as Merge<Views!(&A), Views!(&A, view::Index), _>>::Merged>()
+        );
+    }
+
+    #[test]
+    fn index_both() {
+        assert_eq!(
+            TypeId::of::<Views!(view::Index)>(),
+            TypeId::of::<<Registry as Merge<Views!(view::Index), Views!(view::Index), _>>::Merged>(
+            )
+        );
+    }
+
+    #[test]
+    fn archetype_id_left() {
+        assert_eq!(
+            TypeId::of::<Views!(&A, view::ArchetypeId)>(),
+            TypeId::of::<<Registry as Merge<Views!(&A, view::ArchetypeId), Views!(&A), _>>::Merged>(
+            )
+        );
+    }
+
+    #[test]
+    fn archetype_id_right() {
+        assert_eq!(
+            TypeId::of::<Views!(&A, view::ArchetypeId)>(),
+            TypeId::of::<<Registry as Merge<Views!(&A), Views!(&A, view::ArchetypeId), _>>::Merged>(
+            )
+        );
+    }
+
+    #[test]
+    fn archetype_id_and_index() {
+        assert_eq!(
+            TypeId::of::<Views!(view::Index, view::ArchetypeId)>(),
+            TypeId::of::<
+                <Registry as Merge<
+                    Views!(view::Index),
+                    Views!(view::Index, view::ArchetypeId),
+                    _,
+                >>::Merged,
+            >()
+        );
+    }
 }
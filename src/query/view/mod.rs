@@ -4,7 +4,7 @@
 //! [`World`]. `Views` are how queries specify what [`Component`]s should be borrowed within query
 //! results.
 //!
-//! There are five types of [`View`]s that can be used when defining a query:
+//! There are eight types of [`View`]s that can be used when defining a query:
 //! - **`&C`** - Borrows the `Component` `C` immutably, filtering out any entities that do not
 //! contain `C`.
 //! - **`&mut C`** - Borrows the `Component` `C` mutably, filtering out any entities that do not
@@ -13,8 +13,12 @@
 //! [`None`] otherwise.
 //! - **`Option<&mut C>`** - Borrows the `Component` `C` mutably if present in the entity. Returns
 //! [`None`] otherwise.
+//! - **[`Copied<C>`]** - Copies the `Component` `C` out of the entity, filtering out any entities
+//! that do not contain `C`. Only usable when `C` implements [`Copy`].
 //! - **[`entity::Identifier`]** - Returns the `entity::Identifier` of each entity in the query
 //! results.
+//! - **[`Index`]** - Returns the index of each entity within the archetype storing it.
+//! - **[`ArchetypeId`]** - Returns an identifier of the archetype storing each entity.
 //!
 //! `Views` is a heterogeneous list of individual `View`s. Therefore, it is easiest to define them
 //! using the [`Views!`] macro.
@@ -37,9 +41,43 @@
 //! Note that the lifetime `'a` can often be omitted when [`query`]ing a [`World`], but is required
 //! when defining a [`System`].
 //!
+//! # Interior Mutability
+//! Components that are mutated through a shared reference, such as atomics, should be viewed
+//! with `&C`. Since `&C` only borrows the component immutably, any number of `&C` views can borrow
+//! it at once, including in separate [`System`]s scheduled to run in parallel. Only `Component`s
+//! that implement [`Sync`] can be borrowed across threads this way, which guarantees their
+//! interior mutability is safe to use concurrently.
+//!
+//! ``` rust
+//! use brood::{
+//!     entity,
+//!     query::{
+//!         result,
+//!         Views,
+//!     },
+//!     Query,
+//!     Registry,
+//!     World,
+//! };
+//! use core::sync::atomic::{
+//!     AtomicU32,
+//!     Ordering,
+//! };
+//!
+//! let mut world = World::<Registry!(AtomicU32)>::new();
+//! world.insert(entity!(AtomicU32::new(0)));
+//!
+//! for result!(counter) in world.query(Query::<Views!(&AtomicU32)>::new()).iter {
+//!     counter.fetch_add(1, Ordering::Relaxed);
+//! }
+//! ```
+//!
+//! [`ArchetypeId`]: crate::query::view::ArchetypeId
 //! [`Component`]: crate::component::Component
+//! [`Copied<C>`]: crate::query::view::Copied
 //! [`entity::Identifier`]: crate::entity::Identifier
 //! [`Filter`]: crate::query::filter::Filter
+//! [`Index`]: crate::query::view::Index
 //! [`query`]: crate::world::World::query()
 //! [`System`]: crate::system::System
 //! [`View`]: crate::query::view::View
@@ -51,15 +89,20 @@
 pub(crate) mod claim;
 pub(crate) mod resource;
 
+#[cfg(feature = "rayon")]
+mod conflicts;
 mod contains;
 mod disjoint;
 #[cfg(feature = "rayon")]
 mod merge;
 #[cfg(feature = "rayon")]
 mod par;
+mod repeat;
 mod sealed;
 mod subset;
 
+#[cfg(feature = "rayon")]
+pub use conflicts::conflicts;
 pub use contains::ContainsFilter;
 pub use disjoint::Disjoint;
 #[cfg(feature = "rayon")]
@@ -81,19 +124,26 @@ pub(crate) use par::{
     ParViewsSeal,
     RepeatNone,
 };
+pub(crate) use repeat::RepeatN;
 pub(crate) use sealed::ViewsSealed;
 
 use crate::{
+    archetype,
     component,
     entity,
     hlist::define_null,
+    registry,
+};
+use core::ops::{
+    Deref,
+    DerefMut,
 };
 use sealed::ViewSealed;
 
 /// A view over a single aspect of an entity.
 ///
 /// Here, the world "aspect" means either a [`Component`] or the entity's [`Identifier`].
-/// Specifically, `View` is implemented for each of the following five types, providing the
+/// Specifically, `View` is implemented for each of the following eight types, providing the
 /// specified view into the entity:
 /// - **`&C`** - Borrows the `Component` `C` immutably, filtering out any entities that do not
 /// contain `C`.
@@ -103,8 +153,12 @@ use sealed::ViewSealed;
 /// [`None`] otherwise.
 /// - **`Option<&mut C>`** - Borrows the `Component` `C` mutably if present in the entity. Returns
 /// [`None`] otherwise.
+/// - **[`Copied<C>`]** - Copies the `Component` `C` out of the entity, filtering out any entities
+/// that do not contain `C`. Only usable when `C` implements [`Copy`].
 /// - **[`entity::Identifier`]** - Returns the `entity::Identifier` of each entity in the query
 /// results.
+/// - **[`Index`]** - Returns the index of each entity within the archetype storing it.
+/// - **[`ArchetypeId`]** - Returns an identifier of the archetype storing each entity.
 ///
 /// # Example
 /// ``` rust
@@ -129,8 +183,11 @@ use sealed::ViewSealed;
 /// type Views<'a> = Views!(&'a mut Foo, &'a Bar);
 /// ```
 ///
+/// [`ArchetypeId`]: crate::query::view::ArchetypeId
 /// [`Component`]: crate::component::Component
+/// [`Copied<C>`]: crate::query::view::Copied
 /// [`Identifier`]: crate::entity::Identifier
+/// [`Index`]: crate::query::view::Index
 /// [`Views`]: trait@crate::query::view::Views
 /// [`Views!`]: crate::query::Views!
 /// [`World`]: crate::world::World
@@ -144,8 +201,168 @@ impl<'a, Component> View<'a> for Option<&'a Component> where Component: componen
 
 impl<'a, Component> View<'a> for Option<&'a mut Component> where Component: component::Component {}
 
+impl<'a, Component> View<'a> for Copied<Component> where Component: component::Component + Copy {}
+
 impl<'a> View<'a> for entity::Identifier {}
 
+impl<'a> View<'a> for Index {}
+
+impl<'a> View<'a> for ArchetypeId {}
+
+/// A [`View`] that yields a copy of a [`Component`] by value.
+///
+/// Rather than borrowing the `Component` `C` from each entity, `Copied<C>` copies the value out of
+/// the entity, filtering out any entities that do not contain `C`. This avoids holding a borrow on
+/// the component for the lifetime of the query results.
+///
+/// `Copied<C>` is only valid for `Component`s that implement [`Copy`]. For any other `Component`,
+/// use `&C` instead. In terms of borrowing, `Copied<C>` is treated exactly like `&C`.
+///
+/// The copied value can be accessed either through the public field or through [`Deref`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         result,
+///         view,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Foo(u32);
+///
+/// let mut world = World::<Registry!(Foo)>::new();
+/// world.insert(entity!(Foo(42)));
+///
+/// for result!(foo) in world.query(Query::<Views!(view::Copied<Foo>)>::new()).iter {
+///     assert_eq!(foo.0, Foo(42));
+/// }
+/// ```
+///
+/// [`Component`]: crate::component::Component
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Copied<C>(pub C);
+
+impl<C> Deref for Copied<C> {
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<C> DerefMut for Copied<C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// A [`View`] that yields the index of an entity within the archetype storing it.
+///
+/// Entities with the same set of components are stored together in an archetype, each at a
+/// specific row. `Index` yields that row, which can be used to correlate query results with
+/// external data laid out in the same order as the archetype's storage. Note that these indices
+/// are only stable until the `World` is structurally modified, as adding or removing entities or
+/// components can move entities between rows and archetypes.
+///
+/// Viewing an `Index` does not borrow any components, and it does not filter out any entities.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         result,
+///         view,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Foo(u32);
+///
+/// let mut world = World::<Registry!(Foo)>::new();
+/// world.insert(entity!(Foo(0)));
+/// world.insert(entity!(Foo(1)));
+///
+/// for result!(foo, index) in world.query(Query::<Views!(&Foo, view::Index)>::new()).iter {
+///     assert_eq!(foo.0 as usize, index.0);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Index(pub usize);
+
+/// A [`View`] that yields an identifier of the archetype storing an entity.
+///
+/// Entities with the same set of components are stored together in an archetype. `ArchetypeId`
+/// yields a cheap, hashable key identifying that archetype, which is the same for every entity
+/// stored in it. Combined with an [`Index`], this uniquely locates the storage slot of each
+/// entity, allowing side tables to be maintained without hashing [`entity::Identifier`]s.
+///
+/// An `ArchetypeId` is only unique among the archetypes of a single `World`, and is only stable
+/// until the `World` is structurally modified. In particular, an archetype removed by
+/// [`World::shrink_to_fit()`] may have its `ArchetypeId` reused by a later archetype.
+///
+/// Viewing an `ArchetypeId` does not borrow any components, and it does not filter out any
+/// entities.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         result,
+///         view,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+///
+/// let mut world = World::<Registry!(Foo, Bar)>::new();
+/// world.insert(entity!(Foo(0)));
+/// world.insert(entity!(Foo(1)));
+/// world.insert(entity!(Foo(2), Bar(true)));
+///
+/// let mut slots = world
+///     .query(Query::<Views!(view::ArchetypeId, view::Index)>::new())
+///     .iter
+///     .map(|result!(archetype_id, index)| (archetype_id, index))
+///     .collect::<Vec<_>>();
+/// slots.sort();
+/// slots.dedup();
+///
+/// // Every entity occupies a distinct slot.
+/// assert_eq!(slots.len(), 3);
+/// ```
+///
+/// [`entity::Identifier`]: crate::entity::Identifier
+/// [`World::shrink_to_fit()`]: crate::world::World::shrink_to_fit()
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ArchetypeId(usize);
+
+impl ArchetypeId {
+    /// Creates the `ArchetypeId` for the archetype with the given identifier.
+    pub(crate) fn new<Registry>(identifier: archetype::IdentifierRef<Registry>) -> Self
+    where
+        Registry: registry::Registry,
+    {
+        Self(identifier.as_ptr() as usize)
+    }
+}
+
 define_null!();
 
 /// A heterogeneous list of [`View`]s.
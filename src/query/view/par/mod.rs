@@ -8,7 +8,10 @@ pub(crate) use seal::{
 use crate::{
     component,
     entity,
-    query::view::Null,
+    query::{
+        view,
+        view::Null,
+    },
 };
 use seal::ParViewSeal;
 
@@ -54,8 +57,17 @@ impl<'a, Component> ParView<'a> for Option<&'a mut Component> where
 {
 }
 
+impl<'a, Component> ParView<'a> for view::Copied<Component> where
+    Component: component::Component + Copy + Send + Sync
+{
+}
+
 impl<'a> ParView<'a> for entity::Identifier {}
 
+impl<'a> ParView<'a> for view::Index {}
+
+impl<'a> ParView<'a> for view::ArchetypeId {}
+
 /// A heterogeneous list of [`ParView`]s.
 ///
 /// The main difference between this trait and the standard [`Views`] trait is that these views can
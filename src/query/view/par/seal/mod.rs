@@ -8,6 +8,9 @@ use crate::{
     query::{
         result::ParResults,
         view::{
+            ArchetypeId,
+            Copied,
+            Index,
             Null,
             View,
             Views,
@@ -20,6 +23,7 @@ use rayon::{
         Either,
         IndexedParallelIterator,
     },
+    range,
     slice,
 };
 
@@ -61,10 +65,25 @@ where
     >;
 }
 
+impl<'a, C> ParViewSeal<'a> for Copied<C>
+where
+    C: Component + Copy + Send + Sync,
+{
+    type ParResult = iter::Map<slice::Iter<'a, C>, fn(&'a C) -> Copied<C>>;
+}
+
 impl<'a> ParViewSeal<'a> for entity::Identifier {
     type ParResult = iter::Cloned<slice::Iter<'a, Self>>;
 }
 
+impl<'a> ParViewSeal<'a> for Index {
+    type ParResult = iter::Map<range::Iter<usize>, fn(usize) -> Self>;
+}
+
+impl<'a> ParViewSeal<'a> for ArchetypeId {
+    type ParResult = iter::RepeatN<Self>;
+}
+
 pub trait ParViewsSeal<'a>: Views<'a> {
     type ParResults: ParResults<View = Self>;
 }
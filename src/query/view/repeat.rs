@@ -0,0 +1,101 @@
+use core::iter::FusedIterator;
+
+/// An iterator that yields a clone of a value a fixed number of times.
+///
+/// Unlike `iter::repeat(value).take(count)`, this iterator implements both
+/// [`DoubleEndedIterator`] and [`ExactSizeIterator`], allowing it to be zipped with slice
+/// iterators and iterated in reverse.
+#[derive(Clone)]
+pub struct RepeatN<T> {
+    value: T,
+    count: usize,
+}
+
+impl<T> RepeatN<T> {
+    pub(crate) fn new(value: T, count: usize) -> Self {
+        Self { value, count }
+    }
+}
+
+impl<T> Iterator for RepeatN<T>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count > 0 {
+            self.count -= 1;
+            Some(self.value.clone())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
+}
+
+impl<T> DoubleEndedIterator for RepeatN<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next()
+    }
+}
+
+impl<T> ExactSizeIterator for RepeatN<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.count
+    }
+}
+
+impl<T> FusedIterator for RepeatN<T> where T: Clone {}
+
+#[cfg(test)]
+mod tests {
+    use super::RepeatN;
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn forward() {
+        assert_eq!(
+            RepeatN::new('a', 3).collect::<Vec<_>>(),
+            vec!['a', 'a', 'a']
+        );
+    }
+
+    #[test]
+    fn reverse() {
+        assert_eq!(
+            RepeatN::new('a', 2).rev().collect::<Vec<_>>(),
+            vec!['a', 'a']
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(RepeatN::new('a', 0).next(), None);
+    }
+
+    #[test]
+    fn len() {
+        let mut repeat = RepeatN::new('a', 3);
+        repeat.next();
+        repeat.next_back();
+
+        assert_eq!(repeat.len(), 1);
+    }
+}
@@ -4,21 +4,34 @@ use crate::{
     query::{
         filter,
         result::Results,
-        view::Null,
+        view::{
+            ArchetypeId,
+            Copied,
+            Index,
+            Null,
+            RepeatN,
+        },
     },
+    system::Access,
 };
+use alloc::vec::Vec;
 use core::{
     iter,
     mem::MaybeUninit,
+    ops,
     slice,
 };
 use either::Either;
 
 pub trait ViewSealed<'a> {
-    type Result: Iterator<Item = Self>;
+    type Result: DoubleEndedIterator<Item = Self> + ExactSizeIterator;
+    type Slice;
     type Index;
     type MaybeUninit;
     type EntryFilter;
+
+    /// Returns the access to a component made by this view, if there is one.
+    fn access() -> Option<Access>;
 }
 
 impl<'a, C> ViewSealed<'a> for &'a C
@@ -26,9 +39,14 @@ where
     C: Component,
 {
     type Result = slice::Iter<'a, C>;
+    type Slice = &'a [C];
     type Index = usize;
     type MaybeUninit = MaybeUninit<Self>;
     type EntryFilter = filter::Has<C>;
+
+    fn access() -> Option<Access> {
+        Some(Access::shared::<C>())
+    }
 }
 
 impl<'a, C> ViewSealed<'a> for &'a mut C
@@ -36,22 +54,30 @@ where
     C: Component,
 {
     type Result = slice::IterMut<'a, C>;
+    type Slice = &'a mut [C];
     type Index = usize;
     type MaybeUninit = MaybeUninit<Self>;
     type EntryFilter = filter::Has<C>;
+
+    fn access() -> Option<Access> {
+        Some(Access::exclusive::<C>())
+    }
 }
 
 impl<'a, C> ViewSealed<'a> for Option<&'a C>
 where
     C: Component,
 {
-    type Result = Either<
-        iter::Take<iter::Repeat<Option<&'a C>>>,
-        iter::Map<slice::Iter<'a, C>, fn(&'a C) -> Option<&'a C>>,
-    >;
+    type Result =
+        Either<RepeatN<Option<&'a C>>, iter::Map<slice::Iter<'a, C>, fn(&'a C) -> Option<&'a C>>>;
+    type Slice = Option<&'a [C]>;
     type Index = usize;
     type MaybeUninit = Self;
     type EntryFilter = filter::Has<C>;
+
+    fn access() -> Option<Access> {
+        Some(Access::shared::<C>())
+    }
 }
 
 impl<'a, C> ViewSealed<'a> for Option<&'a mut C>
@@ -59,33 +85,89 @@ where
     C: Component,
 {
     type Result = Either<
-        iter::Take<iter::RepeatWith<fn() -> Option<&'a mut C>>>,
+        iter::Map<ops::Range<usize>, fn(usize) -> Option<&'a mut C>>,
         iter::Map<slice::IterMut<'a, C>, fn(&'a mut C) -> Option<&'a mut C>>,
     >;
+    type Slice = Option<&'a mut [C]>;
     type Index = usize;
     type MaybeUninit = Self;
     type EntryFilter = filter::Has<C>;
+
+    fn access() -> Option<Access> {
+        Some(Access::exclusive::<C>())
+    }
+}
+
+impl<'a, C> ViewSealed<'a> for Copied<C>
+where
+    C: Component + Copy,
+{
+    type Result = iter::Map<slice::Iter<'a, C>, fn(&'a C) -> Copied<C>>;
+    type Slice = &'a [C];
+    type Index = usize;
+    type MaybeUninit = MaybeUninit<Self>;
+    type EntryFilter = filter::Has<C>;
+
+    fn access() -> Option<Access> {
+        Some(Access::shared::<C>())
+    }
 }
 
 impl<'a> ViewSealed<'a> for entity::Identifier {
     type Result = iter::Copied<slice::Iter<'a, Self>>;
+    type Slice = &'a [Self];
     type Index = Null;
     type MaybeUninit = Self;
     type EntryFilter = filter::Not<filter::None>;
+
+    fn access() -> Option<Access> {
+        None
+    }
+}
+
+impl<'a> ViewSealed<'a> for Index {
+    type Result = iter::Map<ops::Range<usize>, fn(usize) -> Self>;
+    type Slice = ops::Range<usize>;
+    type Index = Null;
+    type MaybeUninit = Self;
+    type EntryFilter = filter::Not<filter::None>;
+
+    fn access() -> Option<Access> {
+        None
+    }
+}
+
+impl<'a> ViewSealed<'a> for ArchetypeId {
+    type Result = RepeatN<Self>;
+    type Slice = Self;
+    type Index = Null;
+    type MaybeUninit = Self;
+    type EntryFilter = filter::Not<filter::None>;
+
+    fn access() -> Option<Access> {
+        None
+    }
 }
 
 pub trait ViewsSealed<'a> {
     type Results: Results<View = Self>;
+    type Slices;
     type Indices;
     type MaybeUninit;
     type EntryFilter;
+
+    /// Appends the accesses to components made by these views to `accesses`.
+    fn accesses(accesses: &mut Vec<Access>);
 }
 
 impl<'a> ViewsSealed<'a> for Null {
-    type Results = iter::Take<iter::Repeat<Null>>;
+    type Results = RepeatN<Null>;
+    type Slices = Null;
     type Indices = Null;
     type MaybeUninit = Null;
     type EntryFilter = filter::Not<filter::None>;
+
+    fn accesses(_accesses: &mut Vec<Access>) {}
 }
 
 impl<'a, V, W> ViewsSealed<'a> for (V, W)
@@ -94,7 +176,13 @@ where
     W: ViewsSealed<'a>,
 {
     type Results = (V::Result, W::Results);
+    type Slices = (V::Slice, W::Slices);
     type Indices = (V::Index, W::Indices);
     type MaybeUninit = (V::MaybeUninit, W::MaybeUninit);
     type EntryFilter = filter::Or<W::EntryFilter, V::EntryFilter>;
+
+    fn accesses(accesses: &mut Vec<Access>) {
+        accesses.extend(V::access());
+        W::accesses(accesses);
+    }
 }
@@ -570,6 +570,114 @@ where
     }
 }
 
+impl<'a, Component, Views> SubViewable<'a, view::Copied<Component>, index::Index>
+    for (view::Copied<Component>, Views)
+where
+    Component: Copy,
+    Self: view::Views<
+        'a,
+        MaybeUninit = (MaybeUninit<view::Copied<Component>>, Views::MaybeUninit),
+        Indices = (usize, Views::Indices),
+    >,
+    Views: view::Views<'a>,
+{
+    type Remainder = Views;
+
+    unsafe fn view<Registry>(
+        views: Self::MaybeUninit,
+        indices: Self::Indices,
+        _identifier: archetype::IdentifierRef<Registry>,
+    ) -> (
+        view::Copied<Component>,
+        (
+            <Self::Remainder as view::ViewsSealed<'a>>::MaybeUninit,
+            <Self::Remainder as view::ViewsSealed<'a>>::Indices,
+        ),
+    )
+    where
+        Registry: registry::Registry,
+    {
+        (
+            // SAFETY: This view has already been filtered for the archetype, so this component is
+            // guaranteed to exist.
+            unsafe { views.0.assume_init() },
+            (views.1, indices.1),
+        )
+    }
+}
+
+impl<'a, Component, Views> SubViewable<'a, view::Copied<Component>, index::Index>
+    for (&'a Component, Views)
+where
+    Component: Copy,
+    Self: view::Views<
+        'a,
+        MaybeUninit = (MaybeUninit<&'a Component>, Views::MaybeUninit),
+        Indices = (usize, Views::Indices),
+    >,
+    Views: view::Views<'a>,
+{
+    type Remainder = Views;
+
+    unsafe fn view<Registry>(
+        views: Self::MaybeUninit,
+        indices: Self::Indices,
+        _identifier: archetype::IdentifierRef<Registry>,
+    ) -> (
+        view::Copied<Component>,
+        (
+            <Self::Remainder as view::ViewsSealed<'a>>::MaybeUninit,
+            <Self::Remainder as view::ViewsSealed<'a>>::Indices,
+        ),
+    )
+    where
+        Registry: registry::Registry,
+    {
+        (
+            // SAFETY: This view has already been filtered for the archetype, so this component is
+            // guaranteed to exist.
+            view::Copied(*unsafe { views.0.assume_init() }),
+            (views.1, indices.1),
+        )
+    }
+}
+
+impl<'a, Component, Views> SubViewable<'a, view::Copied<Component>, index::Index>
+    for (&'a mut Component, Views)
+where
+    Component: Copy,
+    Self: view::Views<
+        'a,
+        MaybeUninit = (MaybeUninit<&'a mut Component>, Views::MaybeUninit),
+        Indices = (usize, Views::Indices),
+    >,
+    Views: view::Views<'a>,
+{
+    type Remainder = Views;
+
+    unsafe fn view<Registry>(
+        views: Self::MaybeUninit,
+        indices: Self::Indices,
+        _identifier: archetype::IdentifierRef<Registry>,
+    ) -> (
+        view::Copied<Component>,
+        (
+            <Self::Remainder as view::ViewsSealed<'a>>::MaybeUninit,
+            <Self::Remainder as view::ViewsSealed<'a>>::Indices,
+        ),
+    )
+    where
+        Registry: registry::Registry,
+    {
+        (
+            // SAFETY: This view has already been filtered for the archetype, so this component is
+            // guaranteed to exist.
+            view::Copied(*unsafe { views.0.assume_init() }),
+            (views.1, indices.1),
+        )
+    }
+}
+
 impl<'a, Views> SubViewable<'a, entity::Identifier, index::Index> for (entity::Identifier, Views)
 where
     Self: view::Views<
@@ -599,6 +707,64 @@ where
     }
 }
 
+impl<'a, Views> SubViewable<'a, view::Index, index::Index> for (view::Index, Views)
+where
+    Self: view::Views<
+        'a,
+        MaybeUninit = (view::Index, Views::MaybeUninit),
+        Indices = (view::Null, Views::Indices),
+    >,
+    Views: view::Views<'a>,
+{
+    type Remainder = Views;
+
+    unsafe fn view<Registry>(
+        views: Self::MaybeUninit,
+        indices: Self::Indices,
+        _identifier: archetype::IdentifierRef<Registry>,
+    ) -> (
+        view::Index,
+        (
+            <Self::Remainder as view::ViewsSealed<'a>>::MaybeUninit,
+            <Self::Remainder as view::ViewsSealed<'a>>::Indices,
+        ),
+    )
+    where
+        Registry: registry::Registry,
+    {
+        (views.0, (views.1, indices.1))
+    }
+}
+
+impl<'a, Views> SubViewable<'a, view::ArchetypeId, index::Index> for (view::ArchetypeId, Views)
+where
+    Self: view::Views<
+        'a,
+        MaybeUninit = (view::ArchetypeId, Views::MaybeUninit),
+        Indices = (view::Null, Views::Indices),
+    >,
+    Views: view::Views<'a>,
+{
+    type Remainder = Views;
+
+    unsafe fn view<Registry>(
+        views: Self::MaybeUninit,
+        indices: Self::Indices,
+        _identifier: archetype::IdentifierRef<Registry>,
+    ) -> (
+        view::ArchetypeId,
+        (
+            <Self::Remainder as view::ViewsSealed<'a>>::MaybeUninit,
+            <Self::Remainder as view::ViewsSealed<'a>>::Indices,
+        ),
+    )
+    where
+        Registry: registry::Registry,
+    {
+        (views.0, (views.1, indices.1))
+    }
+}
+
 impl<'a, View, OtherView, Views, Index> SubViewable<'a, View, (Index,)> for (OtherView, Views)
 where
     Self: view::Views<
@@ -651,8 +817,11 @@ mod tests {
     };
 
     // Components.
+    #[derive(Clone, Copy)]
     struct A;
+    #[derive(Clone, Copy)]
     struct B;
+    #[derive(Clone, Copy)]
     struct C;
 
     fn is_subset<'a, ViewsSubSet, Views, Indices>()
@@ -707,11 +876,36 @@ mod tests {
         is_subset::<Views!(Option<&mut B>), Views!(&A, &mut B), _>();
     }
 
+    #[test]
+    fn copied_subset_of_copied() {
+        is_subset::<Views!(view::Copied<A>), Views!(view::Copied<A>, &mut B), _>();
+    }
+
+    #[test]
+    fn copied_subset_of_immutable() {
+        is_subset::<Views!(view::Copied<A>), Views!(&A, &mut B), _>();
+    }
+
+    #[test]
+    fn copied_subset_of_mutable() {
+        is_subset::<Views!(view::Copied<B>), Views!(&A, &mut B), _>();
+    }
+
     #[test]
     fn entity_identifier() {
         is_subset::<Views!(entity::Identifier), Views!(&A, entity::Identifier, &C), _>();
     }
 
+    #[test]
+    fn index() {
+        is_subset::<Views!(view::Index), Views!(&A, view::Index, &C), _>();
+    }
+
+    #[test]
+    fn archetype_id() {
+        is_subset::<Views!(view::ArchetypeId), Views!(&A, view::ArchetypeId, &C), _>();
+    }
+
     #[test]
     fn multiple_subset_views() {
         is_subset::<Views!(&A, Option<&mut B>, &mut C), Views!(&A, &mut B, &mut C), _>();
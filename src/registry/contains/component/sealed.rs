@@ -32,6 +32,24 @@ pub trait Sealed<C, I> {
         identifier_iter: archetype::identifier::Iter<R>,
     ) where
         R: Registry;
+
+    /// Returns a pointer to the component at the given index within a column of components for an
+    /// archetype.
+    ///
+    /// # Safety
+    /// `index` must be less than the length of the column. `components` must contain the valid raw
+    /// parts for a `Vec<C>` for each component `C` identified by `identifier_iter`. `C` must be
+    /// one of the components identified by `identifier_iter`.
+    ///
+    /// The `R` over which this function is generic must be the same `R` over which the registry
+    /// this trait is implemented on, when called externally.
+    unsafe fn component_ptr<R>(
+        index: usize,
+        components: &[(*mut u8, usize)],
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) -> *mut C
+    where
+        R: Registry;
 }
 
 impl<C, R> Sealed<C, Contained> for (C, R)
@@ -57,6 +75,21 @@ where
                 .get_unchecked_mut(index) = component;
         }
     }
+
+    unsafe fn component_ptr<R_>(
+        index: usize,
+        components: &[(*mut u8, usize)],
+        _identifier_iter: archetype::identifier::Iter<R_>,
+    ) -> *mut C
+    where
+        R_: Registry,
+    {
+        // SAFETY: Since `C` is identified by `identifier_iter` (guaranteed by the safety
+        // contract), then this next component is guaranteed to be the valid raw parts for a
+        // `Vec<C>`. `index` is also guaranteed to be a valid index into that `Vec<C>`, so the
+        // offset pointer remains within the same allocation.
+        unsafe { components.get_unchecked(0).0.cast::<C>().add(index) }
+    }
 }
 
 impl<C, C_, I, R> Sealed<C_, (I,)> for (C, R)
@@ -88,6 +121,27 @@ where
             R::set_component(index, component, components, length, identifier_iter);
         }
     }
+
+    unsafe fn component_ptr<R_>(
+        index: usize,
+        mut components: &[(*mut u8, usize)],
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) -> *mut C_
+    where
+        R_: Registry,
+    {
+        // SAFETY: `identifier_iter` is guaranteed to have exactly the same number of bits as there
+        // are components in this registry.
+        if unsafe { identifier_iter.next().unwrap_unchecked() } {
+            // SAFETY: There are guaranteed to be as many entries in `components` as there are bits
+            // set in `identifier_iter`.
+            components = unsafe { components.get_unchecked(1..) };
+        }
+
+        // SAFETY: The safety invariants of this function call are upheld by the safety contract of
+        // this current function.
+        unsafe { R::component_ptr(index, components, identifier_iter) }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,18 @@
+mod sealed;
+
+pub(crate) use sealed::Sealed;
+
+/// Indicates that all of an entity's components are contained in the registry, and that entities
+/// can be moved out of a [`World`] in that form.
+///
+/// This allows reordering the components of the entity into the canonical ordering defined by the
+/// registry, as well as reordering components stored in canonical order back into the entity's
+/// original form.
+///
+/// [`World`]: crate::World
+pub trait ContainsDrain<Entity, Indices>: Sealed<Entity, Indices> {}
+
+impl<Registry, Entity, Indices> ContainsDrain<Entity, Indices> for Registry where
+    Registry: Sealed<Entity, Indices>
+{
+}
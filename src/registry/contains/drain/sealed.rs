@@ -0,0 +1,33 @@
+use crate::{
+    entity,
+    hlist::Reshape,
+    registry,
+    registry::contains::entity::Sealed as ContainsEntitySealed,
+};
+
+pub trait Sealed<Entity, Indices>:
+    registry::Canonical<Self::Canonical, Self::CanonicalContainments>
+{
+    /// The canonical form of the entity.
+    ///
+    /// This is the same type as the canonical form defined by `ContainsEntity`.
+    type Canonical: entity::Entity;
+    type CanonicalContainments;
+
+    /// Returns the entity in its original form, consuming its canonical form.
+    fn reshape(canonical: Self::Canonical) -> Entity;
+}
+
+impl<Registry, Entity, EntityIndices, ReshapeIndices>
+    Sealed<Entity, (EntityIndices, ReshapeIndices)> for Registry
+where
+    Registry: ContainsEntitySealed<Entity, EntityIndices>,
+    Registry::Canonical: Reshape<Entity, ReshapeIndices, entity::Null>,
+{
+    type Canonical = <Registry as ContainsEntitySealed<Entity, EntityIndices>>::Canonical;
+    type CanonicalContainments = Registry::CanonicalContainments;
+
+    fn reshape(canonical: Self::Canonical) -> Entity {
+        canonical.reshape()
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
         filter::{
             And,
             Has,
+            Is,
             None,
             Not,
             Or,
@@ -14,9 +15,11 @@ use crate::{
     },
     registry::{
         contains::{
+            entity::Sealed as ContainsEntitySealed,
             Contained,
             Null,
         },
+        Canonical,
         Registry,
     },
 };
@@ -59,6 +62,31 @@ where
     }
 }
 
+impl<E, I, R> Sealed<Is<E>, Is<I>> for R
+where
+    R: ContainsEntitySealed<E, I>,
+{
+    unsafe fn filter<R_>(identifier: archetype::IdentifierRef<R_>) -> bool
+    where
+        R_: Registry,
+    {
+        // SAFETY: Since `R` is an ordered subset of `R_`, `identifier` has at least `R::LEN` bits.
+        let mut identifier_iter = unsafe { identifier.iter() };
+        // Any components in `R_` preceding the components in `R` must not be present.
+        (0..(R_::LEN - R::LEN)).all(|_| {
+            // SAFETY: `identifier_iter` has `R_::LEN` bits, and fewer than `R_::LEN - R::LEN`
+            // have been consumed.
+            !unsafe { identifier_iter.next().unwrap_unchecked() }
+        })
+            // SAFETY: `identifier_iter` now has exactly `R::LEN` bits remaining.
+            && unsafe {
+                <R as Canonical<R::Canonical, R::CanonicalContainments>>::identifies_exactly(
+                    identifier_iter,
+                )
+            }
+    }
+}
+
 impl<F0, F1, I0, I1, R> Sealed<And<F0, F1>, And<I0, I1>> for R
 where
     R: Sealed<F0, I0> + Sealed<F1, I1>,
@@ -177,6 +205,36 @@ where
     }
 }
 
+impl<C, R> Sealed<view::Copied<C>, Contained> for (C, R)
+where
+    C: Component,
+    R: Registry,
+{
+    unsafe fn filter<R_>(identifier: archetype::IdentifierRef<R_>) -> bool
+    where
+        R_: Registry,
+    {
+        // SAFETY: `identifier` will have exactly `R_::LEN` bits set. Also, `R_::LEN - R::LEN` will
+        // always be at least 1.
+        unsafe { identifier.get_unchecked(R_::LEN - R::LEN - 1) }
+    }
+}
+
+impl<C, C_, I, R> Sealed<view::Copied<C_>, (I,)> for (C, R)
+where
+    C: Component,
+    C_: Component,
+    R: Sealed<Has<C_>, I>,
+{
+    unsafe fn filter<R_>(identifier: archetype::IdentifierRef<R_>) -> bool
+    where
+        R_: Registry,
+    {
+        // SAFETY: `R` is an ordered subset of `(C, R)`.
+        unsafe { R::filter(identifier) }
+    }
+}
+
 impl<C, C_, R> Sealed<Option<&C_>, Null> for (C, R)
 where
     C: Component,
@@ -215,6 +273,30 @@ where
     }
 }
 
+impl<R> Sealed<view::Index, Null> for R
+where
+    R: Registry,
+{
+    unsafe fn filter<R_>(_identifier: archetype::IdentifierRef<R_>) -> bool
+    where
+        R_: Registry,
+    {
+        true
+    }
+}
+
+impl<R> Sealed<view::ArchetypeId, Null> for R
+where
+    R: Registry,
+{
+    unsafe fn filter<R_>(_identifier: archetype::IdentifierRef<R_>) -> bool
+    where
+        R_: Registry,
+    {
+        true
+    }
+}
+
 impl<R> Sealed<view::Null, Null> for R
 where
     R: Registry,
@@ -246,6 +328,7 @@ mod tests {
     use super::*;
     use crate::{
         query::Views,
+        Entity,
         Registry,
     };
     use alloc::vec;
@@ -282,6 +365,51 @@ mod tests {
         });
     }
 
+    #[test]
+    fn filter_is_true() {
+        assert!(unsafe {
+            <Registry as Sealed<Is<Entity!(A)>, _>>::filter(
+                archetype::Identifier::<Registry>::new(vec![1]).as_ref(),
+            )
+        });
+    }
+
+    #[test]
+    fn filter_is_true_reordered() {
+        assert!(unsafe {
+            <Registry as Sealed<Is<Entity!(B, A)>, _>>::filter(
+                archetype::Identifier::<Registry>::new(vec![3]).as_ref(),
+            )
+        });
+    }
+
+    #[test]
+    fn filter_is_false_superset() {
+        assert!(!unsafe {
+            <Registry as Sealed<Is<Entity!(A)>, _>>::filter(
+                archetype::Identifier::<Registry>::new(vec![3]).as_ref(),
+            )
+        });
+    }
+
+    #[test]
+    fn filter_is_false_subset() {
+        assert!(!unsafe {
+            <Registry as Sealed<Is<Entity!(A, B)>, _>>::filter(
+                archetype::Identifier::<Registry>::new(vec![1]).as_ref(),
+            )
+        });
+    }
+
+    #[test]
+    fn filter_is_empty() {
+        assert!(unsafe {
+            <Registry as Sealed<Is<Entity!()>, _>>::filter(
+                archetype::Identifier::<Registry>::new(vec![0]).as_ref(),
+            )
+        });
+    }
+
     #[test]
     fn not() {
         assert!(!unsafe {
@@ -0,0 +1,29 @@
+//! Provides a `ContainsMixedEntity` trait to indicate that a registry contains all components that
+//! may be present in a mixed entity.
+//!
+//! A mixed entity is a heterogeneous list of optional components, where the components actually
+//! present are only known at runtime. Its canonical form, with respect to the registry, contains
+//! an optional value for every component in the registry, in the registry's order.
+
+mod sealed;
+
+pub(crate) use sealed::{
+    Sealed,
+    Storage,
+};
+
+/// Indicates that all of a mixed entity's optional components are contained in the registry.
+///
+/// A mixed entity is a heterogeneous list of `Option<C>`s, where each `C` is a component. This
+/// allows reordering the optional components of the entity into the canonical ordering defined by
+/// the registry.
+///
+/// If the entity contains optional components not in this registry, attempting to use this trait
+/// will result in a compiler error, since the trait won't be implemented for the combination of
+/// entity and registry.
+pub trait ContainsMixedEntity<Entity, Indices>: Sealed<Entity, Indices> {}
+
+impl<Registry, Entity, Indices> ContainsMixedEntity<Entity, Indices> for Registry where
+    Registry: Sealed<Entity, Indices>
+{
+}
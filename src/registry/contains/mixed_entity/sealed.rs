@@ -0,0 +1,216 @@
+use crate::{
+    archetype::column,
+    component::Component,
+    entity,
+    hlist::Get,
+    registry,
+    registry::contains::{
+        Contained,
+        NotContained,
+        Null,
+    },
+};
+
+pub trait Sealed<Entity, Indices>: registry::Registry {
+    /// The canonical form of the mixed entity.
+    ///
+    /// This contains an `Option<C>` for every component `C` in the registry, in the same order as
+    /// the registry. Components not contained in the original entity are always `None`.
+    type Canonical: Storage;
+
+    /// Returns the canonical form of the mixed entity, consuming the original entity.
+    fn canonical(entity: Entity) -> Self::Canonical;
+}
+
+impl Sealed<entity::Null, Null> for registry::Null {
+    type Canonical = entity::Null;
+
+    fn canonical(_entity: entity::Null) -> Self::Canonical {
+        entity::Null
+    }
+}
+
+impl<Component, Registry, Entity, Index, Indices> Sealed<Entity, (Contained, (Index, Indices))>
+    for (Component, Registry)
+where
+    Component: self::Component,
+    Registry: Sealed<<Entity as Get<Option<Component>, Index>>::Remainder, Indices>,
+    Entity: Get<Option<Component>, Index>,
+{
+    type Canonical = (Option<Component>, Registry::Canonical);
+
+    fn canonical(entity: Entity) -> Self::Canonical {
+        let (component, remainder) = entity.get();
+        (component, Registry::canonical(remainder))
+    }
+}
+
+impl<Component, Registry, Entity, Indices> Sealed<Entity, (NotContained, Indices)>
+    for (Component, Registry)
+where
+    Component: self::Component,
+    Registry: Sealed<Entity, Indices>,
+{
+    type Canonical = (Option<Component>, Registry::Canonical);
+
+    fn canonical(entity: Entity) -> Self::Canonical {
+        (None, Registry::canonical(entity))
+    }
+}
+
+/// Storage operations on the canonical form of a mixed entity.
+///
+/// Only the components that are present within the mixed entity are stored. Therefore, the
+/// component columns operated on correspond only to the components that are `Some`.
+pub trait Storage {
+    /// Set the bit in `identifier` for each component that is present, beginning with the bit at
+    /// `index`.
+    ///
+    /// # Safety
+    /// `identifier` must contain enough bytes to hold a bit for each component in this
+    /// heterogeneous list, beginning with the bit at `index`.
+    unsafe fn set_identifier_bits(&self, identifier: &mut [u8], index: usize);
+
+    /// Reserve capacity for `additional` components in the component columns of each component
+    /// that is present.
+    ///
+    /// # Safety
+    /// `components` must contain a column for each present component, in the same order as this
+    /// heterogeneous list.
+    ///
+    /// `components`, together with `length`, must define a valid `Vec<C>` for each component.
+    unsafe fn reserve_components(
+        &self,
+        components: &mut [(*mut u8, usize)],
+        length: usize,
+        additional: usize,
+    );
+
+    /// Push each component that is present into its component column.
+    ///
+    /// # Safety
+    /// `components` must contain a column for each present component, in the same order as this
+    /// heterogeneous list.
+    ///
+    /// `components`, together with `length`, must define a valid `Vec<C>` for each component.
+    unsafe fn push_components(self, components: &mut [(*mut u8, usize)], length: usize);
+}
+
+impl Storage for entity::Null {
+    unsafe fn set_identifier_bits(&self, _identifier: &mut [u8], _index: usize) {}
+
+    unsafe fn reserve_components(
+        &self,
+        _components: &mut [(*mut u8, usize)],
+        _length: usize,
+        _additional: usize,
+    ) {
+    }
+
+    unsafe fn push_components(self, _components: &mut [(*mut u8, usize)], _length: usize) {}
+}
+
+impl<C, E> Storage for (Option<C>, E)
+where
+    C: Component,
+    E: Storage,
+{
+    unsafe fn set_identifier_bits(&self, identifier: &mut [u8], index: usize) {
+        if self.0.is_some() {
+            // SAFETY: `identifier` is guaranteed by the safety contract of this method to contain
+            // a bit at `index`.
+            *unsafe { identifier.get_unchecked_mut(index / 8) } |= 1 << (index % 8);
+        }
+        // SAFETY: Since `identifier` contains a bit for each component in this list beginning at
+        // `index`, it contains a bit for each component in `E` beginning at `index + 1`.
+        unsafe { self.1.set_identifier_bits(identifier, index + 1) };
+    }
+
+    unsafe fn reserve_components(
+        &self,
+        mut components: &mut [(*mut u8, usize)],
+        length: usize,
+        additional: usize,
+    ) {
+        if self.0.is_some() {
+            // SAFETY: `components` is guaranteed by the safety contract of this method to contain
+            // a column for component `C` as its first value, since `C` is present.
+            let component_column = unsafe { components.get_unchecked_mut(0) };
+            // SAFETY: The `component_column` extracted from `components` is guaranteed to,
+            // together with `length`, define a valid `Vec<C>` for the current `C`.
+            unsafe { column::reserve::<C>(component_column, length, additional) };
+            // SAFETY: `components` contains at least the column for `C`.
+            components = unsafe { components.get_unchecked_mut(1..) };
+        }
+        // SAFETY: Since `components` and `length` all meet the safety requirements for the current
+        // method body, with the column for `C` removed if it was present, they will meet those
+        // same requirements for this method call.
+        unsafe { self.1.reserve_components(components, length, additional) };
+    }
+
+    unsafe fn push_components(self, mut components: &mut [(*mut u8, usize)], length: usize) {
+        if let Some(component) = self.0 {
+            // SAFETY: `components` is guaranteed by the safety contract of this method to contain
+            // a column for component `C` as its first value, since `C` is present.
+            let component_column = unsafe { components.get_unchecked_mut(0) };
+            // SAFETY: The `component_column` extracted from `components` is guaranteed to,
+            // together with `length`, define a valid `Vec<C>` for the current `C`.
+            unsafe { column::push(component_column, length, component) };
+            // SAFETY: `components` contains at least the column for `C`.
+            components = unsafe { components.get_unchecked_mut(1..) };
+        }
+        // SAFETY: Since `components` and `length` all meet the safety requirements for the current
+        // method body, with the column for `C` removed if it was present, they will meet those
+        // same requirements for this method call.
+        unsafe { self.1.push_components(components, length) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Sealed,
+        Storage,
+    };
+    use crate::{
+        entity,
+        Registry,
+    };
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct A;
+    #[derive(Debug, Eq, PartialEq)]
+    struct B;
+    #[derive(Debug, Eq, PartialEq)]
+    struct C;
+
+    type Registry = Registry!(A, B, C);
+
+    #[test]
+    fn canonical_empty() {
+        assert_eq!(
+            Registry::canonical(entity!()),
+            entity!(None::<A>, None::<B>, None::<C>)
+        );
+    }
+
+    #[test]
+    fn canonical_reordered() {
+        assert_eq!(
+            Registry::canonical(entity!(Some(C), None::<A>)),
+            entity!(None::<A>, None::<B>, Some(C))
+        );
+    }
+
+    #[test]
+    fn set_identifier_bits() {
+        let mut identifier = [0];
+
+        unsafe {
+            Registry::canonical(entity!(Some(A), None::<B>, Some(C)))
+                .set_identifier_bits(&mut identifier, 0);
+        }
+
+        assert_eq!(identifier, [0b101]);
+    }
+}
@@ -7,12 +7,18 @@
 //!
 //! [`Registry`]: crate::registry::Registry
 
+pub(crate) mod drain;
 pub(crate) mod entities;
 pub(crate) mod entity;
 pub(crate) mod filter;
+pub(crate) mod mixed_entity;
+#[cfg(feature = "rayon")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+pub(crate) mod par_entities;
 #[cfg(feature = "rayon")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
 pub(crate) mod par_views;
+pub(crate) mod registry;
 pub(crate) mod views;
 
 mod component;
@@ -22,11 +28,16 @@ mod par_query;
 mod query;
 
 pub use component::ContainsComponent;
+pub use drain::ContainsDrain;
 pub use entities::ContainsEntities;
 pub use entity::ContainsEntity;
+pub use mixed_entity::ContainsMixedEntity;
+#[cfg(feature = "rayon")]
+pub use par_entities::ContainsParEntities;
 #[cfg(feature = "rayon")]
 pub use par_query::ContainsParQuery;
 pub use query::ContainsQuery;
+pub use registry::ContainsRegistry;
 pub use views::ContainsViews;
 
 pub(crate) use filter::ContainsFilter;
@@ -0,0 +1,18 @@
+mod sealed;
+
+pub(crate) use sealed::Sealed;
+
+/// Indicates that all of an entities' components are contained in the registry, and that the
+/// entities can be stored in parallel.
+///
+/// This is implemented when the registry implements [`ContainsEntities`] and all of the entities'
+/// components are [`Send`].
+///
+/// [`ContainsEntities`]: crate::registry::ContainsEntities
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+pub trait ContainsParEntities<Entities, Indices>: Sealed<Entities, Indices> {}
+
+impl<Registry, Entities, Indices> ContainsParEntities<Entities, Indices> for Registry where
+    Registry: Sealed<Entities, Indices>
+{
+}
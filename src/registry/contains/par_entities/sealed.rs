@@ -0,0 +1,31 @@
+use crate::{
+    entities,
+    registry::contains::entities::Sealed as ContainsEntitiesSealed,
+};
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+pub trait Sealed<Entities, Indices>: ContainsEntitiesSealed<Entities, Indices> {
+    /// The canonical form of the entities, able to be stored in parallel.
+    ///
+    /// This is the same type as the canonical form defined by `ContainsEntities`.
+    type ParCanonical: entities::Entities
+        + entities::ParStorage
+        + entities::Contains<
+            Entity = <<Self as ContainsEntitiesSealed<Entities, Indices>>::Canonical as entities::Contains>::Entity,
+        >;
+
+    /// Returns the canonical form of the entities, consuming the original entities.
+    fn par_canonical(entities: Entities) -> Self::ParCanonical;
+}
+
+impl<Registry, Entities, Indices> Sealed<Entities, Indices> for Registry
+where
+    Registry: ContainsEntitiesSealed<Entities, Indices>,
+    Registry::Canonical: entities::ParStorage,
+{
+    type ParCanonical = Registry::Canonical;
+
+    fn par_canonical(entities: Entities) -> Self::ParCanonical {
+        Registry::canonical(entities)
+    }
+}
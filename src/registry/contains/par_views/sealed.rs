@@ -183,6 +183,29 @@ impl ContainsParViewsInner<'_, view::Null, Null, Null> for registry::Null {
     type Canonical = view::Null;
 }
 
+impl<I, V> ContainsParViewsInner<'_, V, (Contained, Null), I> for registry::Null
+where
+    V: Get<view::Index, I, Remainder = view::Null>,
+{
+    type Canonical = (view::Index, view::Null);
+}
+
+impl<I, V> ContainsParViewsInner<'_, V, (NotContained, (Contained, Null)), I> for registry::Null
+where
+    V: Get<view::ArchetypeId, I, Remainder = view::Null>,
+{
+    type Canonical = (view::ArchetypeId, view::Null);
+}
+
+impl<I, IS, V> ContainsParViewsInner<'_, V, (Contained, (Contained, Null)), (I, IS)>
+    for registry::Null
+where
+    V: Get<view::Index, I>,
+    V::Remainder: Get<view::ArchetypeId, IS, Remainder = view::Null>,
+{
+    type Canonical = (view::Index, (view::ArchetypeId, view::Null));
+}
+
 impl<'a, C, I, IS, P, R, V> ContainsParViewsInner<'a, V, (&'a Contained, P), (I, IS)> for (C, R)
 where
     C: Component + Sync,
@@ -238,6 +261,19 @@ where
     );
 }
 
+impl<'a, C, I, IS, P, R, V> ContainsParViewsInner<'a, V, (view::Copied<Contained>, P), (I, IS)>
+    for (C, R)
+where
+    C: Component + Copy + Send + Sync,
+    R: ContainsParViewsInner<'a, <V as Get<view::Copied<C>, I>>::Remainder, P, IS>,
+    V: Get<view::Copied<C>, I>,
+{
+    type Canonical = (
+        view::Copied<C>,
+        <R as ContainsParViewsInner<'a, <V as Get<view::Copied<C>, I>>::Remainder, P, IS>>::Canonical,
+    );
+}
+
 impl<'a, C, I, P, R, V> ContainsParViewsInner<'a, V, (NotContained, P), I> for (C, R)
 where
     R: ContainsParViewsInner<'a, V, P, I>,
@@ -0,0 +1,23 @@
+//! Provides a `ContainsRegistry` trait to indicate that a registry contains every component of
+//! another registry.
+
+mod sealed;
+
+pub(crate) use sealed::Sealed;
+
+/// Indicates that every component of `Registry` is contained in this registry.
+///
+/// This allows the components of entities stored using `Registry` to be reinterpreted using this
+/// registry instead, which is what [`World::migrate()`] does.
+///
+/// If `Registry` contains components not in this registry, attempting to use this trait will
+/// result in a compiler error, since the trait won't be implemented for the combination of
+/// registries.
+///
+/// [`World::migrate()`]: crate::World::migrate()
+pub trait ContainsRegistry<Registry, Indices>: Sealed<Registry, Indices> {}
+
+impl<NewRegistry, Registry, Indices> ContainsRegistry<Registry, Indices> for NewRegistry where
+    NewRegistry: Sealed<Registry, Indices>
+{
+}
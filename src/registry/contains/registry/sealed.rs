@@ -0,0 +1,81 @@
+use crate::{
+    component::Component,
+    registry,
+    registry::{
+        contains::Null,
+        ContainsComponent,
+    },
+};
+use alloc::vec::Vec;
+
+pub trait Sealed<Registry, Indices>: registry::Registry {
+    /// Push the position of each component of `Registry` within this registry, in the order the
+    /// components are defined in `Registry`.
+    ///
+    /// The position of a component is the index of its bit within an archetype identifier.
+    fn extend_component_positions(positions: &mut Vec<usize>);
+}
+
+impl<NewRegistry> Sealed<registry::Null, Null> for NewRegistry
+where
+    NewRegistry: registry::Registry,
+{
+    fn extend_component_positions(_positions: &mut Vec<usize>) {}
+}
+
+impl<NewRegistry, C, Registry, Index, Indices> Sealed<(C, Registry), (Index, Indices)>
+    for NewRegistry
+where
+    NewRegistry: ContainsComponent<C, Index> + Sealed<Registry, Indices>,
+    C: Component,
+{
+    fn extend_component_positions(positions: &mut Vec<usize>) {
+        positions.push(NewRegistry::LEN - NewRegistry::INDEX - 1);
+        NewRegistry::extend_component_positions(positions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sealed;
+    use crate::Registry;
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+
+    struct A;
+    struct B;
+    struct C;
+
+    #[test]
+    fn extend_component_positions_same() {
+        let mut positions = Vec::new();
+
+        <Registry!(A, B, C) as Sealed<Registry!(A, B, C), _>>::extend_component_positions(
+            &mut positions,
+        );
+
+        assert_eq!(positions, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn extend_component_positions_superset_reordered() {
+        let mut positions = Vec::new();
+
+        <Registry!(C, A, B) as Sealed<Registry!(B, C), _>>::extend_component_positions(
+            &mut positions,
+        );
+
+        assert_eq!(positions, vec![2, 0]);
+    }
+
+    #[test]
+    fn extend_component_positions_empty() {
+        let mut positions = Vec::new();
+
+        <Registry!(A, B) as Sealed<Registry!(), _>>::extend_component_positions(&mut positions);
+
+        assert!(positions.is_empty());
+    }
+}
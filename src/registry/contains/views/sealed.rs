@@ -90,7 +90,7 @@ where
         + Reshape<V, Q, view::Null>;
     /// The canonical form of the results of the views `V`. Equivalent to
     /// `Self::Canonical::Results`.
-    type CanonicalResults: Reshape<V::Results, Q, iter::Take<iter::Repeat<view::Null>>>;
+    type CanonicalResults: Reshape<V::Results, Q, view::RepeatN<view::Null>>;
 
     /// # Safety
     ///
@@ -137,6 +137,22 @@ where
     where
         R: Registry;
 
+    /// # Safety
+    ///
+    /// Each tuple in `columns` must contain the raw parts for a valid `Vec<C>` of size `length`
+    /// for components `C`, ordered for the archetype identified by `archetype_identifier`.
+    ///
+    /// Additionally, `entity_identifiers` must contain the raw parts for a valid
+    /// `Vec<entity::Identifier>` of length `length`.
+    unsafe fn slices<R>(
+        columns: &[(*mut u8, usize)],
+        entity_identifiers: (*mut entity::Identifier, usize),
+        length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> V::Slices
+    where
+        R: Registry;
+
     /// Return the dynamic claims over the components borrowed by the `Views`.
     #[cfg(feature = "rayon")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
@@ -168,7 +184,7 @@ where
             P,
             IS,
         >>::Canonical,
-    ) as ViewsSealed<'a>>::Results: Reshape<<V as ViewsSealed<'a>>::Results, Q, iter::Take<iter::Repeat<view::Null>>>,
+    ) as ViewsSealed<'a>>::Results: Reshape<<V as ViewsSealed<'a>>::Results, Q, view::RepeatN<view::Null>>,
     (
         entity::Identifier,
         <R as ContainsViewsInner<
@@ -207,6 +223,15 @@ where
                     IS,
                 >>::Canonical as ViewsSealed<'a>>::MaybeUninit,
             ),
+            Slices = (
+                &'a [entity::Identifier],
+                <<R as ContainsViewsInner<
+                    'a,
+                    <V as Get<entity::Identifier, I>>::Remainder,
+                    P,
+                    IS,
+                >>::Canonical as ViewsSealed<'a>>::Slices,
+            ),
         >,
         <(
             entity::Identifier,
@@ -226,6 +251,15 @@ where
                 IS,
             >>::Canonical,
         ) as ViewsSealed<'a>>::MaybeUninit: Reshape<V::MaybeUninit, Q, view::Null>,
+        <(
+            entity::Identifier,
+            <R as ContainsViewsInner<
+                'a,
+                <V as Get<entity::Identifier, I>>::Remainder,
+                P,
+                IS,
+            >>::Canonical,
+        ) as ViewsSealed<'a>>::Slices: Reshape<V::Slices, Q, view::Null>,
 {
     type Registry = R;
     type Canonical = (
@@ -313,6 +347,29 @@ where
         ).reshape()
     }
 
+    unsafe fn slices<R_>(
+        columns: &[(*mut u8, usize)],
+        entity_identifiers: (*mut entity::Identifier, usize),
+        length: usize,
+        archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> V::Slices
+    where
+        R_: Registry,
+    {
+        (
+            // SAFETY: `entity_identifiers` contains the raw parts for a valid
+            // `Vec<entity::Identifier>` of length `length`.
+            unsafe {
+                slice::from_raw_parts::<'a, entity::Identifier>(entity_identifiers.0, length)
+            },
+            // SAFETY: The components in `columns` are guaranteed to contain raw parts for valid
+            // `Vec<C>`s of length `length` for each of the components identified by
+            // `archetype_identifier`.
+            unsafe { R::slices(columns, length, archetype_identifier) },
+        )
+            .reshape()
+    }
+
     #[cfg(feature = "rayon")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
     fn claims() -> <Self::Registry as registry::sealed::Claims>::Claims {
@@ -342,12 +399,14 @@ where
     R: CanonicalViews<'a, <R as ContainsViewsInner<'a, V, P, I>>::Canonical, P>
         + ContainsViewsInner<'a, V, P, I>,
     <<R as ContainsViewsInner<'a, V, P, I>>::Canonical as ViewsSealed<'a>>::Results:
-        Reshape<<V as ViewsSealed<'a>>::Results, Q, iter::Take<iter::Repeat<view::Null>>>,
+        Reshape<<V as ViewsSealed<'a>>::Results, Q, view::RepeatN<view::Null>>,
     <R as ContainsViewsInner<'a, V, P, I>>::Canonical: Reshape<V, Q, view::Null>,
     <<R as ContainsViewsInner<'a, V, P, I>>::Canonical as ViewsSealed<'a>>::Indices:
         Reshape<V::Indices, Q, view::Null>,
     <<R as ContainsViewsInner<'a, V, P, I>>::Canonical as ViewsSealed<'a>>::MaybeUninit:
         Reshape<V::MaybeUninit, Q, view::Null>,
+    <<R as ContainsViewsInner<'a, V, P, I>>::Canonical as ViewsSealed<'a>>::Slices:
+        Reshape<V::Slices, Q, view::Null>,
     V: Views<'a>,
 {
     type Registry = R;
@@ -399,6 +458,19 @@ where
         unsafe { R::view_one_maybe_uninit(index, columns, length, archetype_identifier) }.reshape()
     }
 
+    unsafe fn slices<R_>(
+        columns: &[(*mut u8, usize)],
+        _entity_identifiers: (*mut entity::Identifier, usize),
+        length: usize,
+        archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> V::Slices
+    where
+        R_: Registry,
+    {
+        // SAFETY: The safety contract of this function applies to this function call.
+        unsafe { R::slices(columns, length, archetype_identifier) }.reshape()
+    }
+
     #[cfg(feature = "rayon")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
     fn claims() -> <Self::Registry as registry::sealed::Claims>::Claims {
@@ -421,6 +493,29 @@ impl<'a> ContainsViewsInner<'a, view::Null, Null, Null> for registry::Null {
     type Canonical = view::Null;
 }
 
+impl<'a, I, V> ContainsViewsInner<'a, V, (Contained, Null), I> for registry::Null
+where
+    V: Views<'a> + Get<view::Index, I, Remainder = view::Null>,
+{
+    type Canonical = (view::Index, view::Null);
+}
+
+impl<'a, I, V> ContainsViewsInner<'a, V, (NotContained, (Contained, Null)), I> for registry::Null
+where
+    V: Views<'a> + Get<view::ArchetypeId, I, Remainder = view::Null>,
+{
+    type Canonical = (view::ArchetypeId, view::Null);
+}
+
+impl<'a, I, IS, V> ContainsViewsInner<'a, V, (Contained, (Contained, Null)), (I, IS)>
+    for registry::Null
+where
+    V: Views<'a> + Get<view::Index, I>,
+    V::Remainder: Get<view::ArchetypeId, IS, Remainder = view::Null>,
+{
+    type Canonical = (view::Index, (view::ArchetypeId, view::Null));
+}
+
 impl<'a, C, I, IS, P, R, V> ContainsViewsInner<'a, V, (&'a Contained, P), (I, IS)> for (C, R)
 where
     C: Component,
@@ -480,6 +575,20 @@ where
     );
 }
 
+impl<'a, C, I, IS, P, R, V> ContainsViewsInner<'a, V, (view::Copied<Contained>, P), (I, IS)>
+    for (C, R)
+where
+    C: Component + Copy,
+    R: ContainsViewsInner<'a, <V as Get<view::Copied<C>, I>>::Remainder, P, IS>,
+    V: Views<'a> + Get<view::Copied<C>, I>,
+    V::Remainder: Views<'a>,
+{
+    type Canonical = (
+        view::Copied<C>,
+        <R as ContainsViewsInner<'a, <V as Get<view::Copied<C>, I>>::Remainder, P, IS>>::Canonical,
+    );
+}
+
 impl<'a, I, IS, P, V, R> ContainsViewsInner<'a, V, (Contained, P), (I, IS)>
     for (EntityIdentifierMarker, R)
 where
@@ -0,0 +1,23 @@
+mod sealed;
+
+use crate::{
+    hlist::define_null,
+    registry::{
+        contains::Contained,
+        Registry,
+    },
+};
+use sealed::Sealed;
+
+define_null!();
+
+/// A registry whose components can be compared between [`World`]s to create a [`WorldDiff`].
+///
+/// This is a supertrait to the `Clone` and `PartialEq` traits. It is always implemented when all
+/// components implement both `Clone` and `PartialEq`.
+///
+/// [`World`]: crate::World
+/// [`WorldDiff`]: crate::world::WorldDiff
+pub trait Diff: Registry + Sealed<Self, Contained> + Sized {}
+
+impl<R> Diff for R where R: Registry + Sealed<R, Contained> {}
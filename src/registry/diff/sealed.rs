@@ -0,0 +1,315 @@
+//! Functions for computing and applying the changes between two versions of an entity.
+//!
+//! These traits are implemented on `Registry`s whose components implement `Clone` and
+//! `PartialEq`, allowing `World`s to be diffed if and only if the components of the `Registry`
+//! can be.
+//!
+//! These are implemented as "public in private" traits, and therefore cannot be implemented by
+//! external users of the library. The functions defined here are not considered part of the public
+//! API.
+
+use crate::{
+    archetype,
+    component::Component,
+    registry,
+    registry::{
+        diff,
+        ContainsComponent,
+        Null,
+    },
+    world::{
+        ComponentChange,
+        Modifier,
+    },
+};
+use core::any::Any;
+
+/// Component-wise diffing of an entity's components.
+///
+/// `Registry` is the full registry the components are stored with, and `Index` is the index of
+/// the first component of this registry within `Registry`. This allows each component's changes
+/// to be applied to a [`Modifier`] for the full registry.
+pub trait Sealed<Registry, Index>: registry::Registry
+where
+    Registry: registry::Registry,
+{
+    /// A heterogeneous list containing a [`ComponentChange`] for each component in the registry.
+    type Changes;
+
+    /// Returns the changes between the row `previous_index` in `previous_components` and the row
+    /// `index` in `components`, along with whether any component changed.
+    ///
+    /// # Safety
+    /// `previous_components` must contain the same number of values as there are set bits in
+    /// `previous_identifier_iter`, and each `(*mut u8, usize)` must be the pointer and capacity of
+    /// a `Vec<C>` whose length is greater than `previous_index`, where `C` is the component
+    /// corresponding to the set bit. The same must hold for `components`, `index`, and
+    /// `identifier_iter`.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, both identifier iterators must have the same amount of bits left
+    /// as there are components remaining.
+    unsafe fn row_changes<R>(
+        previous_components: &[(*mut u8, usize)],
+        previous_index: usize,
+        previous_identifier_iter: archetype::identifier::Iter<R>,
+        components: &[(*mut u8, usize)],
+        index: usize,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) -> (Self::Changes, bool)
+    where
+        R: registry::Registry;
+
+    /// Records each change in `changes` on `modifier`.
+    fn apply_changes(changes: Self::Changes, modifier: &mut Modifier<Registry>);
+
+    /// Returns the change to `C` within `changes`, if `C` is in this registry.
+    fn change<C>(changes: &Self::Changes) -> Option<&ComponentChange<C>>
+    where
+        C: Component;
+}
+
+impl<Registry, Index> Sealed<Registry, Index> for Null
+where
+    Registry: registry::Registry,
+{
+    type Changes = diff::Null;
+
+    unsafe fn row_changes<R>(
+        _previous_components: &[(*mut u8, usize)],
+        _previous_index: usize,
+        _previous_identifier_iter: archetype::identifier::Iter<R>,
+        _components: &[(*mut u8, usize)],
+        _index: usize,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) -> (Self::Changes, bool)
+    where
+        R: registry::Registry,
+    {
+        (diff::Null, false)
+    }
+
+    fn apply_changes(_changes: Self::Changes, _modifier: &mut Modifier<Registry>) {}
+
+    fn change<C>(_changes: &Self::Changes) -> Option<&ComponentChange<C>>
+    where
+        C: Component,
+    {
+        None
+    }
+}
+
+impl<C, R, Registry, Index> Sealed<Registry, Index> for (C, R)
+where
+    C: Component + Clone + PartialEq,
+    R: Sealed<Registry, (Index,)>,
+    Registry: ContainsComponent<C, Index> + registry::Registry,
+{
+    type Changes = (ComponentChange<C>, R::Changes);
+
+    unsafe fn row_changes<R_>(
+        mut previous_components: &[(*mut u8, usize)],
+        previous_index: usize,
+        mut previous_identifier_iter: archetype::identifier::Iter<R_>,
+        mut components: &[(*mut u8, usize)],
+        index: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) -> (Self::Changes, bool)
+    where
+        R_: registry::Registry,
+    {
+        // SAFETY: `previous_identifier_iter` is guaranteed by the safety contract of this method
+        // to return a value for every component within the registry.
+        let previous_contains = unsafe { previous_identifier_iter.next().unwrap_unchecked() };
+        let previous_component = if previous_contains {
+            let component_column =
+                // SAFETY: `previous_components` is guaranteed to have the same number of values as
+                // there are set bits in `previous_identifier_iter`. Since a bit must have been set
+                // to enter this block, there must be at least one component column.
+                unsafe { previous_components.get_unchecked(0) };
+            previous_components =
+                // SAFETY: As above, there is at least one component column.
+                unsafe { previous_components.get_unchecked(1..) };
+            Some(
+                // SAFETY: The pointer is guaranteed by the safety contract of this method to point
+                // to a `Vec<C>` whose length is greater than `previous_index`, so offsetting by
+                // that index results in a pointer to a valid, initialized `C`.
+                unsafe { &*component_column.0.cast::<C>().add(previous_index) },
+            )
+        } else {
+            None
+        };
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to return
+        // a value for every component within the registry.
+        let contains = unsafe { identifier_iter.next().unwrap_unchecked() };
+        let component = if contains {
+            let component_column =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // are set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked(0) };
+            components =
+                // SAFETY: As above, there is at least one component column.
+                unsafe { components.get_unchecked(1..) };
+            Some(
+                // SAFETY: The pointer is guaranteed by the safety contract of this method to point
+                // to a `Vec<C>` whose length is greater than `index`, so offsetting by that index
+                // results in a pointer to a valid, initialized `C`.
+                unsafe { &*component_column.0.cast::<C>().add(index) },
+            )
+        } else {
+            None
+        };
+
+        let change = match (previous_component, component) {
+            (None, None) => ComponentChange::Unchanged,
+            (Some(_), None) => ComponentChange::Removed,
+            (None, Some(component)) => ComponentChange::Added(component.clone()),
+            (Some(previous_component), Some(component)) => {
+                if previous_component == component {
+                    ComponentChange::Unchanged
+                } else {
+                    ComponentChange::Changed(component.clone())
+                }
+            }
+        };
+        let component_changed = !matches!(change, ComponentChange::Unchanged);
+
+        // SAFETY: At this point, one bit of each identifier iterator has been consumed. If it was
+        // set, the corresponding component column was removed from the front of the matching
+        // components slice, meaning both slices still contain the same number of values as there
+        // are set bits remaining in their iterators, each of which still corresponds to the
+        // component identified by its bit.
+        //
+        // Since each component is visited once, both iterators have the same number of bits
+        // remaining as `R` has components remaining.
+        let (changes, remaining_changed) = unsafe {
+            R::row_changes(
+                previous_components,
+                previous_index,
+                previous_identifier_iter,
+                components,
+                index,
+                identifier_iter,
+            )
+        };
+
+        ((change, changes), component_changed || remaining_changed)
+    }
+
+    fn apply_changes(changes: Self::Changes, modifier: &mut Modifier<Registry>) {
+        match changes.0 {
+            ComponentChange::Added(component) | ComponentChange::Changed(component) => {
+                modifier.add::<C, Index>(component);
+            }
+            ComponentChange::Removed => modifier.remove::<C, Index>(),
+            ComponentChange::Unchanged => {}
+        }
+        R::apply_changes(changes.1, modifier);
+    }
+
+    fn change<C_>(changes: &Self::Changes) -> Option<&ComponentChange<C_>>
+    where
+        C_: Component,
+    {
+        (&changes.0 as &dyn Any)
+            .downcast_ref()
+            .or_else(|| R::change(&changes.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sealed;
+    use crate::{
+        archetype::Identifier,
+        registry::contains::Contained,
+        world::ComponentChange,
+        Registry,
+    };
+    use alloc::vec;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct A(usize);
+    #[derive(Clone, Debug, PartialEq)]
+    struct B(bool);
+    #[derive(Clone, Debug, PartialEq)]
+    struct C;
+
+    type Registry = Registry!(A, B, C);
+
+    #[test]
+    fn row_changes() {
+        let previous_identifier = unsafe { Identifier::<Registry>::new(vec![3]) };
+        let mut previous_a_column = vec![A(0), A(1)];
+        let mut previous_b_column = vec![B(false), B(true)];
+        let previous_components = vec![
+            (
+                previous_a_column.as_mut_ptr().cast::<u8>(),
+                previous_a_column.capacity(),
+            ),
+            (
+                previous_b_column.as_mut_ptr().cast::<u8>(),
+                previous_b_column.capacity(),
+            ),
+        ];
+        let identifier = unsafe { Identifier::<Registry>::new(vec![6]) };
+        let mut b_column = vec![B(false)];
+        let mut c_column = vec![C];
+        let components = vec![
+            (b_column.as_mut_ptr().cast::<u8>(), b_column.capacity()),
+            (c_column.as_mut_ptr().cast::<u8>(), c_column.capacity()),
+        ];
+
+        let (changes, changed) = unsafe {
+            <Registry as Sealed<Registry, Contained>>::row_changes(
+                &previous_components,
+                1,
+                previous_identifier.iter(),
+                &components,
+                0,
+                identifier.iter(),
+            )
+        };
+
+        assert!(changed);
+        assert_eq!(
+            <Registry as Sealed<Registry, Contained>>::change::<A>(&changes),
+            Some(&ComponentChange::Removed)
+        );
+        assert_eq!(
+            <Registry as Sealed<Registry, Contained>>::change::<B>(&changes),
+            Some(&ComponentChange::Changed(B(false)))
+        );
+        assert_eq!(
+            <Registry as Sealed<Registry, Contained>>::change::<C>(&changes),
+            Some(&ComponentChange::Added(C))
+        );
+    }
+
+    #[test]
+    fn row_changes_unchanged() {
+        let identifier = unsafe { Identifier::<Registry>::new(vec![1]) };
+        let mut a_column = vec![A(0), A(1), A(0)];
+        let components = vec![(a_column.as_mut_ptr().cast::<u8>(), a_column.capacity())];
+
+        let (changes, changed) = unsafe {
+            <Registry as Sealed<Registry, Contained>>::row_changes(
+                &components,
+                0,
+                identifier.iter(),
+                &components,
+                2,
+                identifier.iter(),
+            )
+        };
+
+        assert!(!changed);
+        assert_eq!(
+            <Registry as Sealed<Registry, Contained>>::change::<A>(&changes),
+            Some(&ComponentChange::Unchanged)
+        );
+    }
+}
@@ -55,6 +55,34 @@ pub trait Sealed: Registry {
     ) -> bool
     where
         R: Registry;
+
+    /// Returns whether the components of row `index_a` in `components_a` are equal to the
+    /// components of row `index_b` in `components_b`, where `components_a` and `components_b` are
+    /// lists of pointer-capacity tuples defining `Vec<C>`s for each `C` component type identified
+    /// by the `identifier_iter`.
+    ///
+    /// # Safety
+    /// `components_a` and `components_b` must both contain the same number of values as there are
+    /// set bits in the `identifier_iter`.
+    ///
+    /// Each `(*mut u8, usize)` in `components_a` must be the pointer and capacity respectively of
+    /// a `Vec<C>` whose length is greater than `index_a`, where `C` is the component corresponding
+    /// to the set bit in `identifier_iter`. The same must hold for `components_b` and `index_b`.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn component_row_eq<R>(
+        components_a: &[(*mut u8, usize)],
+        index_a: usize,
+        components_b: &[(*mut u8, usize)],
+        index_b: usize,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) -> bool
+    where
+        R: Registry;
 }
 
 impl Sealed for Null {
@@ -69,6 +97,19 @@ impl Sealed for Null {
     {
         true
     }
+
+    unsafe fn component_row_eq<R>(
+        _components_a: &[(*mut u8, usize)],
+        _index_a: usize,
+        _components_b: &[(*mut u8, usize)],
+        _index_b: usize,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) -> bool
+    where
+        R: Registry,
+    {
+        true
+    }
 }
 
 impl<C, R> Sealed for (C, R)
@@ -156,6 +197,73 @@ where
         // same number of bits remaining as `R` has components remaining.
         unsafe { R::component_eq(components_a, components_b, length, identifier_iter) }
     }
+
+    unsafe fn component_row_eq<R_>(
+        mut components_a: &[(*mut u8, usize)],
+        index_a: usize,
+        mut components_b: &[(*mut u8, usize)],
+        index_b: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) -> bool
+    where
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let component_column_a =
+                // SAFETY: `components_a` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components_a.get_unchecked(0) };
+            let component_column_b =
+                // SAFETY: `components_b` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components_b.get_unchecked(0) };
+
+            if
+            // SAFETY: The pointers are guaranteed by the safety contract of this method to point
+            // to `Vec<C>`s whose lengths are greater than `index_a` and `index_b` respectively,
+            // so offsetting by those indices results in pointers to valid, initialized `C`s.
+            unsafe {
+                *component_column_a.0.cast::<C>().add(index_a)
+                    != *component_column_b.0.cast::<C>().add(index_b)
+            } {
+                return false;
+            }
+
+            components_a =
+                // SAFETY: `components_a` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components_a.get_unchecked(1..) };
+            components_b =
+                // SAFETY: `components_b` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components_b.get_unchecked(1..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. If it was set,
+        // the corresponding component columns were removed from the front of `components_a` and
+        // `components_b`, meaning both slices still contain the same number of values as there
+        // are set bits remaining in `identifier_iter`, each of which still corresponds to the
+        // component identified by its bit.
+        //
+        // Since each component is visited once, `identifier_iter` has the same number of bits
+        // remaining as `R` has components remaining.
+        unsafe {
+            R::component_row_eq(
+                components_a,
+                index_a,
+                components_b,
+                index_b,
+                identifier_iter,
+            )
+        }
+    }
 }
 
 #[cfg(test)]
@@ -230,4 +338,33 @@ mod tests {
             Registry::component_eq(&components_a, &components_b, 3, identifier.iter())
         });
     }
+
+    #[test]
+    fn component_rows_equal() {
+        #[derive(PartialEq)]
+        struct A(usize);
+        #[derive(PartialEq)]
+        struct B(bool);
+        type Registry = Registry!(A, B);
+        let identifier = unsafe { Identifier::<Registry>::new(vec![3]) };
+        let mut a_column_a = vec![A(0), A(1)];
+        let mut b_column_a = vec![B(false), B(true)];
+        let components_a = vec![
+            (a_column_a.as_mut_ptr().cast::<u8>(), a_column_a.capacity()),
+            (b_column_a.as_mut_ptr().cast::<u8>(), b_column_a.capacity()),
+        ];
+        let mut a_column_b = vec![A(1), A(0)];
+        let mut b_column_b = vec![B(true), B(false)];
+        let components_b = vec![
+            (a_column_b.as_mut_ptr().cast::<u8>(), a_column_b.capacity()),
+            (b_column_b.as_mut_ptr().cast::<u8>(), b_column_b.capacity()),
+        ];
+
+        assert!(unsafe {
+            Registry::component_row_eq(&components_a, 0, &components_b, 1, identifier.iter())
+        });
+        assert!(!unsafe {
+            Registry::component_row_eq(&components_a, 0, &components_b, 0, identifier.iter())
+        });
+    }
 }
@@ -0,0 +1,94 @@
+//! Concatenation of [`Registry`]s.
+//!
+//! [`Registry`]: crate::registry::Registry
+
+use crate::{
+    component::Component,
+    registry::{
+        Null,
+        Registry,
+    },
+};
+
+/// Concatenates this registry with another registry.
+///
+/// The resulting [`Output`] registry contains every component of this registry, followed by every
+/// component of `Other`. This is what [`Registry!`] uses to merge registries when invoked with
+/// `@merge`.
+///
+/// Components are not deduplicated. Registries being merged should therefore not share any
+/// components, as a component included twice can not be resolved unambiguously by a [`World`].
+///
+/// [`Output`]: Merge::Output
+/// [`Registry!`]: crate::Registry!
+/// [`World`]: crate::World
+pub trait Merge<Other>: Registry {
+    /// The registry containing the components of both registries.
+    type Output: Registry;
+}
+
+impl<Other> Merge<Other> for Null
+where
+    Other: Registry,
+{
+    type Output = Other;
+}
+
+impl<C, R, Other> Merge<Other> for (C, R)
+where
+    C: Component,
+    R: Merge<Other>,
+{
+    type Output = (C, R::Output);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Merge;
+    use crate::{
+        registry::Length,
+        Registry,
+    };
+    use core::any::TypeId;
+
+    struct A;
+    struct B;
+    struct C;
+
+    #[test]
+    fn merge_empty() {
+        assert_eq!(
+            TypeId::of::<<Registry!() as Merge<Registry!()>>::Output>(),
+            TypeId::of::<Registry!()>()
+        );
+    }
+
+    #[test]
+    fn merge() {
+        assert_eq!(
+            TypeId::of::<<Registry!(A, B) as Merge<Registry!(C)>>::Output>(),
+            TypeId::of::<Registry!(A, B, C)>()
+        );
+    }
+
+    #[test]
+    fn merge_macro() {
+        assert_eq!(
+            TypeId::of::<Registry!(@merge Registry!(A), Registry!(), Registry!(B, C))>(),
+            TypeId::of::<Registry!(A, B, C)>()
+        );
+    }
+
+    #[test]
+    fn merge_macro_empty() {
+        assert_eq!(
+            TypeId::of::<Registry!(@merge)>(),
+            TypeId::of::<Registry!()>()
+        );
+    }
+
+    #[test]
+    fn merge_len() {
+        assert_eq!(<<Registry!(A) as Merge<Registry!(B, C)>>::Output>::LEN, 3);
+    }
+}
@@ -31,7 +31,10 @@ pub(crate) mod contains;
 
 mod clone;
 mod debug;
+mod diff;
 mod eq;
+mod merge;
+mod packed;
 mod sealed;
 #[cfg(feature = "serde")]
 mod serde;
@@ -39,23 +42,34 @@ mod serde;
 #[cfg(feature = "serde")]
 pub use self::serde::{
     Deserialize,
+    DeserializeDefaults,
     Serialize,
+    SerializeFiltered,
 };
 pub use clone::Clone;
-#[cfg(feature = "rayon")]
-pub use contains::ContainsParQuery;
 pub use contains::{
     ContainsComponent,
+    ContainsDrain,
     ContainsEntities,
     ContainsEntity,
+    ContainsMixedEntity,
     ContainsQuery,
+    ContainsRegistry,
     ContainsViews,
 };
+#[cfg(feature = "rayon")]
+pub use contains::{
+    ContainsParEntities,
+    ContainsParQuery,
+};
 pub use debug::Debug;
+pub use diff::Diff;
 pub use eq::{
     Eq,
     PartialEq,
 };
+pub use merge::Merge;
+pub use packed::Packed;
 
 #[cfg(feature = "rayon")]
 pub(crate) use contains::{
@@ -72,6 +86,7 @@ pub(crate) use sealed::{
 
 use crate::{
     component,
+    doc,
     hlist::define_null_uninstantiable,
 };
 use sealed::Sealed;
@@ -140,9 +155,66 @@ where
 /// let world = World::<Registry>::new();
 /// ```
 ///
+/// # Merging Registries
+/// Existing registries can be combined into a single registry by prefixing the list with `@merge`.
+/// Every argument is then interpreted as a registry rather than a component, and the resulting
+/// registry contains the components of each registry in order. Extra components can be included by
+/// wrapping them in their own registry.
+///
+/// Components are not deduplicated when merging, so the registries being merged must not share any
+/// components. A component included more than once can not be resolved unambiguously, so using it
+/// within a `World` will fail to compile:
+///
+/// ``` compile_fail
+/// use brood::{
+///     entity,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Position(f32, f32);
+/// struct Velocity(f32, f32);
+///
+/// type PhysicsRegistry = Registry!(Position, Velocity);
+/// type RenderRegistry = Registry!(Position);
+///
+/// // `Position` is included in both registries.
+/// type Registry = Registry!(@merge PhysicsRegistry, RenderRegistry);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Position(0.0, 0.0)));
+/// ```
+///
+/// ``` rust
+/// use brood::{
+///     Registry,
+///     World,
+/// };
+///
+/// struct Position(f32, f32);
+/// struct Velocity(f32, f32);
+/// struct Health(u32);
+/// struct Player;
+///
+/// // Registries defined by separate features.
+/// type PhysicsRegistry = Registry!(Position, Velocity);
+/// type CombatRegistry = Registry!(Health);
+///
+/// // The combined registry, along with an extra component.
+/// type Registry = Registry!(@merge PhysicsRegistry, CombatRegistry, Registry!(Player));
+///
+/// let world = World::<Registry>::new();
+/// ```
+///
 /// [`World`]: crate::World
 #[macro_export]
 macro_rules! Registry {
+    (@merge $registry:ty $(,$registries:ty)* $(,)?) => {
+        <$registry as $crate::registry::Merge<$crate::Registry!(@merge $($registries,)*)>>::Output
+    };
+    (@merge) => {
+        $crate::registry::Null
+    };
     ($component:ty $(,$components:ty)* $(,)?) => {
         ($component, $crate::Registry!($($components,)*))
     };
@@ -150,3 +222,79 @@ macro_rules! Registry {
         $crate::registry::Null
     };
 }
+
+doc::non_root_macro! {
+    /// Asserts at compile time that every component in a registry implements the given bounds.
+    ///
+    /// This is useful when a registry is part of a public API that requires its components to
+    /// implement certain traits, such as `Clone` for cloning a [`World`]. Normally, a missing
+    /// implementation would only be reported where the trait is first required, often as a deeply
+    /// nested trait resolution failure. This macro instead reports an error naming the component
+    /// that does not satisfy the bounds at the point where the registry is defined.
+    ///
+    /// The macro is invoked with the registry type, followed by a colon and the bounds to check,
+    /// using the same syntax as a `where` clause. It can be used wherever an item can be defined.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     registry,
+    ///     Registry,
+    /// };
+    ///
+    /// #[derive(Clone, Debug)]
+    /// struct Foo(u32);
+    /// #[derive(Clone, Debug)]
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// registry::assert_all!(Registry: Clone + core::fmt::Debug);
+    /// ```
+    ///
+    /// A registry containing a component that does not satisfy the bounds will fail to compile,
+    /// with the error identifying the component:
+    ///
+    /// ``` compile_fail
+    /// use brood::{
+    ///     registry,
+    ///     Registry,
+    /// };
+    ///
+    /// #[derive(Clone)]
+    /// struct Foo(u32);
+    /// // Forgot to derive `Clone`.
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// // This does not compile, because `Bar` does not implement `Clone`.
+    /// registry::assert_all!(Registry: Clone);
+    /// ```
+    ///
+    /// [`World`]: crate::World
+    macro_rules! assert_all {
+        ($registry:ty: $($bounds:tt)+) => (
+            const _: () = {
+                trait AssertAll {}
+
+                impl AssertAll for $crate::registry::Null {}
+
+                impl<Component, Registry> AssertAll for (Component, Registry)
+                where
+                    Component: $($bounds)+,
+                    Registry: AssertAll,
+                {
+                }
+
+                const fn assert_all<Registry>()
+                where
+                    Registry: AssertAll,
+                {
+                }
+
+                assert_all::<$registry>();
+            };
+        );
+    }
+}
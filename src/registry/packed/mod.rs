@@ -0,0 +1,26 @@
+mod sealed;
+
+use crate::{
+    packed::Pod,
+    registry::Null,
+};
+use sealed::Sealed;
+
+/// A registry whose components implement [`Pod`].
+///
+/// This is a supertrait to the `Pod` trait. It is always implemented when all components
+/// implement `Pod`, and allows a [`World`] to be written to and read from the [packed] layout.
+///
+/// [packed]: crate::packed
+/// [`Pod`]: crate::packed::Pod
+/// [`World`]: crate::World
+pub trait Packed: Sealed {}
+
+impl Packed for Null {}
+
+impl<Component, Registry> Packed for (Component, Registry)
+where
+    Component: Pod,
+    Registry: Packed,
+{
+}
@@ -0,0 +1,184 @@
+use crate::{
+    archetype,
+    packed::{
+        Pod,
+        Write,
+    },
+    registry::{
+        Null,
+        Registry,
+    },
+};
+use alloc::vec::Vec;
+use core::{
+    mem::{
+        size_of,
+        ManuallyDrop,
+    },
+    ptr,
+    slice,
+};
+
+pub trait Sealed: Registry {
+    /// Write the raw bytes of each component column in `components` to `writer`.
+    ///
+    /// # Safety
+    /// `components` must contain the same number of values as there are set bits in the
+    /// `identifier_iter`.
+    ///
+    /// Each `(*mut u8, usize)` in `components` must be the pointer and capacity respectively of a
+    /// `Vec<C>` of length `length`, where `C` is the component corresponding to the set bit in
+    /// `identifier_iter`.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn write_components<R, W>(
+        components: &[(*mut u8, usize)],
+        length: usize,
+        identifier_iter: archetype::identifier::Iter<R>,
+        writer: &mut W,
+    ) -> Result<(), W::Error>
+    where
+        R: Registry,
+        W: Write + ?Sized;
+
+    /// Create a component column for each set bit in `identifier_iter` from the raw bytes in
+    /// `bytes`, appending them to `components`.
+    ///
+    /// # Safety
+    /// `bytes` must be exactly `length` multiplied by the combined size of the components
+    /// identified by `identifier_iter`, and must contain the columns for those components in
+    /// order.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn read_components<R>(
+        components: &mut Vec<(*mut u8, usize)>,
+        bytes: &[u8],
+        length: usize,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry;
+}
+
+impl Sealed for Null {
+    unsafe fn write_components<R, W>(
+        _components: &[(*mut u8, usize)],
+        _length: usize,
+        _identifier_iter: archetype::identifier::Iter<R>,
+        _writer: &mut W,
+    ) -> Result<(), W::Error>
+    where
+        R: Registry,
+        W: Write + ?Sized,
+    {
+        Ok(())
+    }
+
+    unsafe fn read_components<R>(
+        _components: &mut Vec<(*mut u8, usize)>,
+        _bytes: &[u8],
+        _length: usize,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry,
+    {
+    }
+}
+
+impl<C, R> Sealed for (C, R)
+where
+    C: Pod,
+    R: Sealed,
+{
+    unsafe fn write_components<R_, W>(
+        mut components: &[(*mut u8, usize)],
+        length: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+        writer: &mut W,
+    ) -> Result<(), W::Error>
+    where
+        R_: Registry,
+        W: Write + ?Sized,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let component_column =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked(0) };
+            writer.write_all(
+                // SAFETY: `component_column` is guaranteed to point to a `Vec<C>` of length
+                // `length`. Since `C` is `Pod`, it contains no padding, so every byte of the
+                // column is initialized.
+                unsafe { slice::from_raw_parts(component_column.0, length * size_of::<C>()) },
+            )?;
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked(1..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. If the bit was
+        // set, the first column, which corresponded to the component identified by the consumed
+        // bit, has been removed from `components`. Otherwise, `components` is unaltered. Either
+        // way, `components` still contains a column for each remaining set bit in
+        // `identifier_iter`.
+        //
+        // Furthermore, `R` is one component smaller than `(C, R)`, and since `identifier_iter`
+        // has had one bit consumed, it still has the same number of bits remaining as `R` has
+        // components remaining.
+        unsafe { R::write_components(components, length, identifier_iter, writer) }
+    }
+
+    unsafe fn read_components<R_>(
+        components: &mut Vec<(*mut u8, usize)>,
+        mut bytes: &[u8],
+        length: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) where
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let size = length * size_of::<C>();
+            let mut v = ManuallyDrop::new(Vec::<C>::with_capacity(length));
+            // SAFETY: `bytes` is guaranteed to begin with the column for `C`, which is `size`
+            // bytes long, and `v` has been allocated with enough capacity to hold `length`
+            // components of type `C`.
+            unsafe {
+                ptr::copy_nonoverlapping(bytes.as_ptr(), v.as_mut_ptr().cast::<u8>(), size);
+            }
+            // SAFETY: The first `length` components of `v` were initialized above. Since `C` is
+            // `Pod`, any bytes are a valid `C`.
+            unsafe { v.set_len(length) };
+            components.push((v.as_mut_ptr().cast::<u8>(), v.capacity()));
+            bytes =
+                // SAFETY: `bytes` is guaranteed to contain the column for `C`, which is `size`
+                // bytes long.
+                unsafe { bytes.get_unchecked(size..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. If the bit was
+        // set, the column for the component identified by the consumed bit has been removed from
+        // `bytes`. Either way, `bytes` contains exactly the columns for the remaining set bits in
+        // `identifier_iter`.
+        //
+        // Furthermore, `R` is one component smaller than `(C, R)`, and since `identifier_iter`
+        // has had one bit consumed, it still has the same number of bits remaining as `R` has
+        // components remaining.
+        unsafe { R::read_components(components, bytes, length, identifier_iter) }
+    }
+}
@@ -19,6 +19,7 @@ use alloc::{
     vec,
     vec::Vec,
 };
+use core::ptr;
 
 /// Type marker for a component contained in an entity.
 pub enum Contained {}
@@ -55,6 +56,35 @@ pub trait Canonical<E, P>: Registry + Sized {
     /// `identifier` must be a properly-initialized buffer containing at least `(length + 7) / 8`
     /// bytes.
     unsafe fn populate_archetype_identifier(identifier: &mut [u8], length: usize);
+
+    /// Returns whether the archetype identified by `identifier_iter` contains exactly the
+    /// components of the canonical entity `E`, and no others.
+    ///
+    /// # Safety
+    /// `identifier_iter` must have exactly as many bits remaining as there are components in this
+    /// registry.
+    unsafe fn identifies_exactly<R_>(identifier_iter: archetype::identifier::Iter<R_>) -> bool
+    where
+        R_: Registry;
+
+    /// Move the last row of an archetype's component columns out as the canonical entity `E`.
+    ///
+    /// Any components in the row that are not contained in `E` are dropped.
+    ///
+    /// # Safety
+    /// `components` must contain the raw parts for a valid `Vec<C>` of size `length` for each
+    /// component `C` identified by `identifier_iter`, and `length` must be nonzero. Each component
+    /// contained in `E` must also be identified by `identifier_iter`.
+    ///
+    /// After this call, the last row of each column is no longer initialized. The caller must
+    /// reduce the length of the columns by one.
+    unsafe fn take_last_row<R_>(
+        components: &[(*mut u8, usize)],
+        length: usize,
+        identifier_iter: archetype::identifier::Iter<R_>,
+    ) -> E
+    where
+        R_: Registry;
 }
 
 impl Canonical<entity::Null, Null> for registry::Null {
@@ -65,6 +95,24 @@ impl Canonical<entity::Null, Null> for registry::Null {
     }
 
     unsafe fn populate_archetype_identifier(_identifier: &mut [u8], _length: usize) {}
+
+    unsafe fn identifies_exactly<R_>(_identifier_iter: archetype::identifier::Iter<R_>) -> bool
+    where
+        R_: Registry,
+    {
+        true
+    }
+
+    unsafe fn take_last_row<R_>(
+        _components: &[(*mut u8, usize)],
+        _length: usize,
+        _identifier_iter: archetype::identifier::Iter<R_>,
+    ) -> entity::Null
+    where
+        R_: Registry,
+    {
+        entity::Null
+    }
 }
 
 impl<C, E, P, R> Canonical<(C, E), (Contained, P)> for (C, R)
@@ -97,6 +145,37 @@ where
             R::populate_archetype_identifier(identifier, length);
         }
     }
+    unsafe fn identifies_exactly<R_>(mut identifier_iter: archetype::identifier::Iter<R_>) -> bool
+    where
+        R_: Registry,
+    {
+        // SAFETY: `identifier_iter` is guaranteed to have at least one bit remaining. The
+        // remaining bits are guaranteed to be the same number as the components in `R`.
+        unsafe {
+            identifier_iter.next().unwrap_unchecked() && R::identifies_exactly(identifier_iter)
+        }
+    }
+
+    unsafe fn take_last_row<R_>(
+        components: &[(*mut u8, usize)],
+        length: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) -> (C, E)
+    where
+        R_: Registry,
+    {
+        identifier_iter.next();
+        (
+            // SAFETY: `C` is guaranteed to be identified by `identifier_iter`, so the first entry
+            // in `components` is the raw parts for a valid `Vec<C>` of size `length`. Since
+            // `length` is nonzero, `length - 1` is a valid index into that `Vec<C>`.
+            unsafe { ptr::read(components.get_unchecked(0).0.cast::<C>().add(length - 1)) },
+            // SAFETY: The remaining components in `components` are guaranteed to contain raw
+            // parts for valid `Vec<C>`s of length `length` for each of the remaining components
+            // identified by `identifier_iter`.
+            unsafe { R::take_last_row(components.get_unchecked(1..), length, identifier_iter) },
+        )
+    }
 }
 
 impl<C, E, P, R> Canonical<E, (NotContained, P)> for (C, R)
@@ -125,6 +204,40 @@ where
             R::populate_archetype_identifier(identifier, length);
         }
     }
+    unsafe fn identifies_exactly<R_>(mut identifier_iter: archetype::identifier::Iter<R_>) -> bool
+    where
+        R_: Registry,
+    {
+        // SAFETY: `identifier_iter` is guaranteed to have at least one bit remaining. The
+        // remaining bits are guaranteed to be the same number as the components in `R`.
+        unsafe {
+            !identifier_iter.next().unwrap_unchecked() && R::identifies_exactly(identifier_iter)
+        }
+    }
+
+    unsafe fn take_last_row<R_>(
+        mut components: &[(*mut u8, usize)],
+        length: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) -> E
+    where
+        R_: Registry,
+    {
+        // SAFETY: `identifier_iter` is guaranteed to have at least one element remaining.
+        if unsafe { identifier_iter.next().unwrap_unchecked() } {
+            // SAFETY: Since `identifier_iter` has this component set, the first entry in
+            // `components` is the raw parts for a valid `Vec<C>` of size `length`. Since `length`
+            // is nonzero, `length - 1` is a valid index into that `Vec<C>`.
+            unsafe {
+                ptr::drop_in_place(components.get_unchecked(0).0.cast::<C>().add(length - 1));
+                components = components.get_unchecked(1..);
+            }
+        }
+        // SAFETY: The remaining components in `components` are guaranteed to contain raw parts
+        // for valid `Vec<C>`s of length `length` for each of the remaining components identified
+        // by `identifier_iter`.
+        unsafe { R::take_last_row(components, length, identifier_iter) }
+    }
 }
 
 #[cfg(test)]
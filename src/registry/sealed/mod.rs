@@ -16,6 +16,8 @@ mod canonical;
 #[cfg(feature = "rayon")]
 mod claim;
 mod length;
+mod names;
+mod observe;
 #[cfg(feature = "rayon")]
 mod par_view;
 mod storage;
@@ -25,6 +27,7 @@ pub(crate) use canonical::Canonical;
 #[cfg(feature = "rayon")]
 pub(crate) use claim::Claims;
 pub(crate) use length::Length;
+pub(crate) use names::Names;
 #[cfg(feature = "rayon")]
 pub(crate) use par_view::CanonicalParViews;
 pub(crate) use view::CanonicalViews;
@@ -34,6 +37,7 @@ use crate::{
     registry::Null,
 };
 use assertions::Assertions;
+use observe::Observe;
 use storage::Storage;
 
 /// A trait that is public but defined within a private module.
@@ -46,9 +50,9 @@ use storage::Storage;
 /// do. See the modules where they are defined for more details on the internal functionality
 /// defined through these sealed traits.
 #[cfg(feature = "rayon")]
-pub trait Sealed: Assertions + Claims + Length + Storage {}
+pub trait Sealed: Assertions + Claims + Length + Names + Observe + Storage {}
 #[cfg(not(feature = "rayon"))]
-pub trait Sealed: Assertions + Length + Storage {}
+pub trait Sealed: Assertions + Length + Names + Observe + Storage {}
 
 impl Sealed for Null {}
 
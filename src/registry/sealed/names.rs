@@ -0,0 +1,70 @@
+//! Defines and implements a trait for obtaining the names of the components of a [`Registry`].
+//!
+//! [`Registry`]: crate::registry::Registry
+
+use crate::{
+    component::Component,
+    registry::Null,
+};
+use alloc::vec::Vec;
+use core::any::type_name;
+
+/// Provides the names of the components within the heterogeneous list.
+pub trait Names {
+    /// Pushes the type name of each component onto `names`, in the order the components are
+    /// defined.
+    fn extend_component_names(names: &mut Vec<&'static str>);
+}
+
+impl Names for Null {
+    fn extend_component_names(_names: &mut Vec<&'static str>) {}
+}
+
+impl<C, R> Names for (C, R)
+where
+    C: Component,
+    R: Names,
+{
+    fn extend_component_names(names: &mut Vec<&'static str>) {
+        names.push(type_name::<C>());
+        R::extend_component_names(names);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Names;
+    use crate::Registry;
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+    use core::any::type_name;
+
+    #[test]
+    fn empty() {
+        type Registry = Registry!();
+
+        let mut names = Vec::new();
+        Registry::extend_component_names(&mut names);
+
+        assert!(names.is_empty());
+    }
+
+    #[test]
+    fn non_empty() {
+        struct A;
+        struct B;
+        struct C;
+
+        type Registry = Registry!(A, B, C);
+
+        let mut names = Vec::new();
+        Registry::extend_component_names(&mut names);
+
+        assert_eq!(
+            names,
+            vec![type_name::<A>(), type_name::<B>(), type_name::<C>()]
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Notification of component observers for rows within a [`Registry`]'s archetype tables.
+//!
+//! Since the component columns of an archetype are type-erased, notifying the observers of each
+//! component requires recursing over the registry to recover the component types, similar to the
+//! functions defined for [`World`] storage.
+//!
+//! [`Registry`]: crate::registry::Registry
+//! [`World`]: crate::world::World
+
+use crate::{
+    archetype,
+    component::Component,
+    entity,
+    registry::{
+        Null,
+        Registry,
+    },
+    world::Callbacks,
+};
+use core::ops::Range;
+
+pub trait Observe {
+    /// Calls the callbacks registered for each component identified by `identifier_iter`, for
+    /// every row in `rows`.
+    ///
+    /// # Safety
+    /// `components` must contain the same number of values as there are set bits in the
+    /// `identifier_iter`.
+    ///
+    /// Each `(*mut u8, usize)` in `components` must be the pointer and capacity respectively of a
+    /// `Vec<C>` whose length is at least `rows.end`, where `C` is the component corresponding to
+    /// the set bit in `identifier_iter`. `entity_identifiers` must also have a length of at least
+    /// `rows.end`.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn notify_rows<R>(
+        rows: Range<usize>,
+        entity_identifiers: &[entity::Identifier],
+        components: &[(*mut u8, usize)],
+        identifier_iter: archetype::identifier::Iter<R>,
+        callbacks: &mut Callbacks,
+    ) where
+        R: Registry;
+}
+
+impl Observe for Null {
+    unsafe fn notify_rows<R>(
+        _rows: Range<usize>,
+        _entity_identifiers: &[entity::Identifier],
+        _components: &[(*mut u8, usize)],
+        _identifier_iter: archetype::identifier::Iter<R>,
+        _callbacks: &mut Callbacks,
+    ) where
+        R: Registry,
+    {
+    }
+}
+
+impl<C, R> Observe for (C, R)
+where
+    C: Component,
+    R: Observe,
+{
+    unsafe fn notify_rows<R_>(
+        rows: Range<usize>,
+        entity_identifiers: &[entity::Identifier],
+        mut components: &[(*mut u8, usize)],
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+        callbacks: &mut Callbacks,
+    ) where
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let component_column =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked(0) };
+            if let Some(callbacks) = callbacks.get_mut::<C>() {
+                for index in rows.clone() {
+                    // SAFETY: `entity_identifiers` is guaranteed to have a length of at least
+                    // `rows.end`, and `index` is less than `rows.end`.
+                    let entity_identifier = *unsafe { entity_identifiers.get_unchecked(index) };
+                    let component =
+                        // SAFETY: The pointer is guaranteed to point to a `Vec<C>` of length at
+                        // least `rows.end`, so offsetting by `index` results in a pointer to a
+                        // valid, initialized `C`.
+                        unsafe { &*component_column.0.cast::<C>().add(index) };
+                    for callback in callbacks.iter_mut() {
+                        callback(entity_identifier, component);
+                    }
+                }
+            }
+
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked(1..) };
+        }
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. If it was set,
+        // the corresponding component column was removed from the front of `components`, meaning
+        // `components` still contains the same number of values as there are set bits remaining
+        // in `identifier_iter`, each of which still corresponds to the component identified by its
+        // bit.
+        //
+        // Since each component is visited once, `identifier_iter` has the same number of bits
+        // remaining as `R` has components remaining.
+        unsafe {
+            R::notify_rows(
+                rows,
+                entity_identifiers,
+                components,
+                identifier_iter,
+                callbacks,
+            );
+        }
+    }
+}
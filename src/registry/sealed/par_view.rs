@@ -23,6 +23,7 @@ use rayon::{
     iter,
     iter::{
         Either,
+        IntoParallelIterator,
         IntoParallelRefIterator,
         IntoParallelRefMutIterator,
         ParallelIterator,
@@ -59,6 +60,83 @@ impl<'a> CanonicalParViews<'a, view::Null, Null> for registry::Null {
     }
 }
 
+impl<'a> CanonicalParViews<'a, (view::Index, view::Null), (Contained, Null)> for registry::Null {
+    unsafe fn par_view<R>(
+        _columns: &[(*mut u8, usize)],
+        length: usize,
+        _archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> <(view::Index, view::Null) as ParViewsSeal<'a>>::ParResults
+    where
+        R: Registry,
+    {
+        (
+            (0..length)
+                .into_par_iter()
+                .map(view::Index as fn(usize) -> view::Index),
+            iter::repeatn(view::Null, length),
+        )
+    }
+}
+
+impl<'a> CanonicalParViews<'a, (view::ArchetypeId, view::Null), (NotContained, (Contained, Null))>
+    for registry::Null
+{
+    unsafe fn par_view<R>(
+        _columns: &[(*mut u8, usize)],
+        length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> <(view::ArchetypeId, view::Null) as ParViewsSeal<'a>>::ParResults
+    where
+        R: Registry,
+    {
+        (
+            iter::repeatn(
+                view::ArchetypeId::new(
+                    // SAFETY: The `IdentifierRef` created here does not outlive this function
+                    // call, and therefore does not outlive the archetype's identifier.
+                    unsafe { archetype_identifier.identifier() },
+                ),
+                length,
+            ),
+            iter::repeatn(view::Null, length),
+        )
+    }
+}
+
+impl<'a>
+    CanonicalParViews<
+        'a,
+        (view::Index, (view::ArchetypeId, view::Null)),
+        (Contained, (Contained, Null)),
+    > for registry::Null
+{
+    unsafe fn par_view<R>(
+        _columns: &[(*mut u8, usize)],
+        length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> <(view::Index, (view::ArchetypeId, view::Null)) as ParViewsSeal<'a>>::ParResults
+    where
+        R: Registry,
+    {
+        (
+            (0..length)
+                .into_par_iter()
+                .map(view::Index as fn(usize) -> view::Index),
+            (
+                iter::repeatn(
+                    view::ArchetypeId::new(
+                        // SAFETY: The `IdentifierRef` created here does not outlive this function
+                        // call, and therefore does not outlive the archetype's identifier.
+                        unsafe { archetype_identifier.identifier() },
+                    ),
+                    length,
+                ),
+                iter::repeatn(view::Null, length),
+            ),
+        )
+    }
+}
+
 impl<'a, C, P, R, V> CanonicalParViews<'a, (&'a C, V), (&'a Contained, P)> for (C, R)
 where
     C: Component + Sync,
@@ -227,6 +305,45 @@ where
     }
 }
 
+fn copy<C>(val: &C) -> view::Copied<C>
+where
+    C: Copy,
+{
+    view::Copied(*val)
+}
+
+impl<'a, C, P, R, V> CanonicalParViews<'a, (view::Copied<C>, V), (view::Copied<Contained>, P)>
+    for (C, R)
+where
+    C: Component + Copy + Send + Sync,
+    R: CanonicalParViews<'a, V, P>,
+    V: ParViews<'a>,
+{
+    unsafe fn par_view<R_>(
+        columns: &[(*mut u8, usize)],
+        length: usize,
+        mut archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> <(view::Copied<C>, V) as ParViewsSeal<'a>>::ParResults
+    where
+        R_: Registry,
+    {
+        archetype_identifier.next();
+        (
+            // SAFETY: `columns` is guaranteed to contain raw parts for a valid `Vec<C>` of size
+            // `length` for the currently viewed component `C`.
+            unsafe {
+                core::slice::from_raw_parts::<'a, C>(columns.get_unchecked(0).0.cast::<C>(), length)
+            }
+            .par_iter()
+            .map(copy),
+            // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
+            // for valid `Vec<C>`s of length `length` for each of the remaining components
+            // identified by `archetype_identifier`.
+            unsafe { R::par_view(columns.get_unchecked(1..), length, archetype_identifier) },
+        )
+    }
+}
+
 impl<'a, C, P, R, V> CanonicalParViews<'a, V, (NotContained, P)> for (C, R)
 where
     C: Component,
@@ -14,6 +14,7 @@
 
 use crate::{
     archetype,
+    archetype::column,
     component::Component,
     registry::{
         Null,
@@ -70,6 +71,28 @@ pub trait Storage {
     where
         R: Registry;
 
+    /// Returns the number of bytes allocated for the component columns indicated within the
+    /// `Registry` by an identifier.
+    ///
+    /// # Safety
+    /// `components` must contain the same number of values as there are set bits in the
+    /// `identifier_iter`.
+    ///
+    /// Each `(*mut u8, usize)` in `components` must be the pointer and capacity respectively of a
+    /// `Vec<C>`, where `C` is the component corresponding to the set bit in `identifier_iter`.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn size_of_component_columns<R>(
+        components: &[(*mut u8, usize)],
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) -> usize
+    where
+        R: Registry;
+
     /// Remove the component at the given index from each component column.
     ///
     /// # Safety
@@ -175,7 +198,7 @@ pub trait Storage {
         R: Registry;
 
     /// Push components from a bit buffer, skipping the component `C`, onto the end of their
-    /// corresponding component columns.
+    /// corresponding component columns. The skipped component is dropped.
     ///
     /// Note that the components stored in `buffer` are expected to be unaligned, being packed one
     /// immediately after another, and will be read as such.
@@ -215,6 +238,58 @@ pub trait Storage {
         C: Component,
         R: Registry;
 
+    /// Push components from two bit buffers onto the end of their corresponding component
+    /// columns, dropping any components from `buffer` that are not pushed.
+    ///
+    /// For each component identified by `identifier_iter`, the component is read from
+    /// `replacement_buffer` if it is identified by `replacement_identifier_iter`, and otherwise it
+    /// is read from `buffer`. Any component in `buffer` that is either replaced or not identified
+    /// by `identifier_iter` is dropped.
+    ///
+    /// Note that the components stored in both buffers are expected to be unaligned, being packed
+    /// one immediately after another, and will be read as such.
+    ///
+    /// # Safety
+    /// `components` must contain the same number of values as there are set bits in the
+    /// `identifier_iter`.
+    ///
+    /// Each `(*mut u8, usize)` in `components` must be the pointer and capacity respectively of a
+    /// `Vec<C>` of length `length`, where `C` is the component corresponding to the set bit in
+    /// `identifier_iter`.
+    ///
+    /// `buffer` and `replacement_buffer` must both be
+    /// [valid](https://doc.rust-lang.org/std/ptr/index.html#safety) for reads. Note that even if
+    /// the combined size of components being stored is of size zero, these pointers still must be
+    /// non-null.
+    ///
+    /// `buffer` must point to an allocated buffer of packed, properly initialized components
+    /// corresponding with the components identified by `buffer_identifier_iter`, and
+    /// `replacement_buffer` must point to an allocated buffer of packed, properly initialized
+    /// components corresponding with the components identified by `replacement_identifier_iter`,
+    /// both in the same order as they are specified by the `Registry` on which this method is
+    /// being called.
+    ///
+    /// Every component identified by `replacement_identifier_iter` must also be identified by
+    /// `identifier_iter`, and every component identified by `identifier_iter` must be identified
+    /// by either `buffer_identifier_iter` or `replacement_identifier_iter`.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter`, `buffer_identifier_iter`, and
+    /// `replacement_identifier_iter` must each have the same amount of bits left as there are
+    /// components remaining.
+    unsafe fn push_components_from_buffers<R>(
+        buffer: *const u8,
+        buffer_identifier_iter: archetype::identifier::Iter<R>,
+        replacement_buffer: *const u8,
+        replacement_identifier_iter: archetype::identifier::Iter<R>,
+        components: &mut [(*mut u8, usize)],
+        length: usize,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry;
+
     /// Free the allocated memory for each component column.
     ///
     /// This converts all component columns back into `Vec<C>` for each component `C`, and then
@@ -319,6 +394,161 @@ pub trait Storage {
     ) where
         R: Registry;
 
+    /// Shrink the component columns to a capacity of at least `min_capacity`.
+    ///
+    /// Columns whose capacity is already at most `min_capacity` are left unchanged. As with
+    /// [`shrink_components_to_fit()`], the resulting allocation is dependent on the allocator. The
+    /// logic here relies on the implementation of `Vec::shrink_to()`.
+    ///
+    /// # Safety
+    /// `components` must contain the same number of values as there are set bits in the
+    /// `identifier_iter`.
+    ///
+    /// Each `(*mut u8, usize)` in `components` must be the pointer and capacity respectively of a
+    /// `Vec<C>` of length `length`, where `C` is the component corresponding to the set bit in
+    /// `identifier_iter`.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    ///
+    /// [`shrink_components_to_fit()`]: Storage::shrink_components_to_fit()
+    unsafe fn shrink_components_to<R>(
+        components: &mut [(*mut u8, usize)],
+        length: usize,
+        min_capacity: usize,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry;
+
+    /// Reserve capacity for at least `additional` more components in each component column.
+    ///
+    /// The logic here relies on the implementation of `Vec::reserve()`.
+    ///
+    /// # Safety
+    /// `components` must contain the same number of values as there are set bits in the
+    /// `identifier_iter`.
+    ///
+    /// Each `(*mut u8, usize)` in `components` must be the pointer and capacity respectively of a
+    /// `Vec<C>` of length `length`, where `C` is the component corresponding to the set bit in
+    /// `identifier_iter`.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn reserve_components<R>(
+        components: &mut [(*mut u8, usize)],
+        length: usize,
+        additional: usize,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry;
+
+    /// Move all components from the component columns in `other_components` to the end of the
+    /// component columns in `components`.
+    ///
+    /// After this method is called, the columns in `other_components` will be empty, meaning they
+    /// will be of length `0`. The columns in `components` will be of length
+    /// `length + other_length`.
+    ///
+    /// # Safety
+    /// `components` and `other_components` must both contain the same number of values as there
+    /// are set bits in the `identifier_iter`.
+    ///
+    /// Each `(*mut u8, usize)` in `components` must be the pointer and capacity respectively of a
+    /// `Vec<C>` of length `length`, and each `(*mut u8, usize)` in `other_components` must be the
+    /// pointer and capacity respectively of a `Vec<C>` of length `other_length`, where `C` is the
+    /// component corresponding to the set bit in `identifier_iter`.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn append_components<R>(
+        components: &mut [(*mut u8, usize)],
+        length: usize,
+        other_components: &mut [(*mut u8, usize)],
+        other_length: usize,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry;
+
+    /// Move all components from the component columns in `other_components` to the end of the
+    /// component columns in `components`, filling the column for the component `C` with clones of
+    /// `component`.
+    ///
+    /// `other_components` does not contain a column for `C`. After this method is called, the
+    /// columns in `other_components` will be empty, meaning they will be of length `0`. The columns
+    /// in `components` will be of length `length + other_length`.
+    ///
+    /// # Safety
+    /// `components` must contain the same number of values as there are set bits in the
+    /// `identifier_iter`, and `other_components` must contain one less value, omitting the column
+    /// for `C`. The bit corresponding to `C` must be set in `identifier_iter`.
+    ///
+    /// Each `(*mut u8, usize)` in `components` must be the pointer and capacity respectively of a
+    /// `Vec<C>` of length `length`, and each `(*mut u8, usize)` in `other_components` must be the
+    /// pointer and capacity respectively of a `Vec<C>` of length `other_length`, where `C` is the
+    /// component corresponding to the set bit in `identifier_iter`.
+    ///
+    /// The `Registry` `R` must not contain any duplicate component types.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn append_components_and_component<C, R>(
+        component: &C,
+        components: &mut [(*mut u8, usize)],
+        length: usize,
+        other_components: &mut [(*mut u8, usize)],
+        other_length: usize,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        C: Clone + Component,
+        R: Registry;
+
+    /// Move all components from the component columns in `other_components` to the end of the
+    /// component columns in `components`, dropping the components in the column for the component
+    /// `C`.
+    ///
+    /// `components` does not contain a column for `C`. After this method is called, the columns in
+    /// `other_components` will be empty, meaning they will be of length `0`. The columns in
+    /// `components` will be of length `length + other_length`.
+    ///
+    /// # Safety
+    /// `other_components` must contain the same number of values as there are set bits in the
+    /// `identifier_iter`, and `components` must contain one less value, omitting the column for
+    /// `C`. The bit corresponding to `C` must be set in `identifier_iter`.
+    ///
+    /// Each `(*mut u8, usize)` in `components` must be the pointer and capacity respectively of a
+    /// `Vec<C>` of length `length`, and each `(*mut u8, usize)` in `other_components` must be the
+    /// pointer and capacity respectively of a `Vec<C>` of length `other_length`, where `C` is the
+    /// component corresponding to the set bit in `identifier_iter`.
+    ///
+    /// The `Registry` `R` must not contain any duplicate component types.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn append_components_without_component<C, R>(
+        components: &mut [(*mut u8, usize)],
+        length: usize,
+        other_components: &mut [(*mut u8, usize)],
+        other_length: usize,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        C: Component,
+        R: Registry;
+
     /// Populate a [`DebugList`] with string forms of the names of every component type identified
     /// by `identifier_iter`.
     ///
@@ -338,6 +568,22 @@ pub trait Storage {
         identifier_iter: archetype::identifier::Iter<R>,
     ) where
         R: Registry;
+
+    /// Push the [`TypeId`] of each component identified by `identifier_iter` to `type_ids`.
+    ///
+    /// The `TypeId`s are pushed in the order the components appear within the registry.
+    ///
+    /// # Safety
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn extend_component_type_ids<R>(
+        type_ids: &mut Vec<TypeId>,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry;
 }
 
 impl Storage for Null {
@@ -359,6 +605,16 @@ impl Storage for Null {
         0
     }
 
+    unsafe fn size_of_component_columns<R>(
+        _components: &[(*mut u8, usize)],
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) -> usize
+    where
+        R: Registry,
+    {
+        0
+    }
+
     unsafe fn remove_component_row<R>(
         _index: usize,
         _components: &[(*mut u8, usize)],
@@ -404,6 +660,19 @@ impl Storage for Null {
     {
     }
 
+    unsafe fn push_components_from_buffers<R>(
+        _buffer: *const u8,
+        _buffer_identifier_iter: archetype::identifier::Iter<R>,
+        _replacement_buffer: *const u8,
+        _replacement_identifier_iter: archetype::identifier::Iter<R>,
+        _components: &mut [(*mut u8, usize)],
+        _length: usize,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry,
+    {
+    }
+
     unsafe fn free_components<R>(
         _components: &[(*mut u8, usize)],
         _length: usize,
@@ -440,6 +709,62 @@ impl Storage for Null {
     {
     }
 
+    unsafe fn shrink_components_to<R>(
+        _components: &mut [(*mut u8, usize)],
+        _length: usize,
+        _min_capacity: usize,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry,
+    {
+    }
+
+    unsafe fn reserve_components<R>(
+        _components: &mut [(*mut u8, usize)],
+        _length: usize,
+        _additional: usize,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry,
+    {
+    }
+
+    unsafe fn append_components<R>(
+        _components: &mut [(*mut u8, usize)],
+        _length: usize,
+        _other_components: &mut [(*mut u8, usize)],
+        _other_length: usize,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry,
+    {
+    }
+
+    unsafe fn append_components_and_component<C, R>(
+        _component: &C,
+        _components: &mut [(*mut u8, usize)],
+        _length: usize,
+        _other_components: &mut [(*mut u8, usize)],
+        _other_length: usize,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        C: Clone + Component,
+        R: Registry,
+    {
+    }
+
+    unsafe fn append_components_without_component<C, R>(
+        _components: &mut [(*mut u8, usize)],
+        _length: usize,
+        _other_components: &mut [(*mut u8, usize)],
+        _other_length: usize,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        C: Component,
+        R: Registry,
+    {
+    }
+
     unsafe fn debug_identifier<R>(
         _debug_list: &mut DebugList,
         _identifier_iter: archetype::identifier::Iter<R>,
@@ -447,6 +772,14 @@ impl Storage for Null {
         R: Registry,
     {
     }
+
+    unsafe fn extend_component_type_ids<R>(
+        _type_ids: &mut Vec<TypeId>,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) where
+        R: Registry,
+    {
+    }
 }
 
 impl<C, R> Storage for (C, R)
@@ -491,6 +824,43 @@ where
             unsafe { R::size_of_components_for_identifier(identifier_iter) }
     }
 
+    unsafe fn size_of_component_columns<R_>(
+        mut components: &[(*mut u8, usize)],
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) -> usize
+    where
+        R_: Registry,
+    {
+        let mut size = 0;
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let component_column =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked(0) };
+            size = size_of::<C>() * component_column.1;
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked(1..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. If the bit was
+        // set, the first column, which corresponded to the component identified by the consumed
+        // bit, has been removed from `components`. Otherwise, `components` is unaltered. Either
+        // way, `components` still contains a column for each remaining set bit in
+        // `identifier_iter`.
+        //
+        // Furthermore, `R` is one component smaller than `(C, R)`, and since `identifier_iter`
+        // has had one bit consumed, it still has the same number of bits remaining as `R` has
+        // components remaining.
+        size + unsafe { R::size_of_component_columns(components, identifier_iter) }
+    }
+
     unsafe fn remove_component_row<R_>(
         index: usize,
         mut components: &[(*mut u8, usize)],
@@ -642,48 +1012,26 @@ where
                 unsafe { components.get_unchecked_mut(0) };
 
             if TypeId::of::<C>() == TypeId::of::<C_>() {
-                let mut v = ManuallyDrop::new(
-                    // SAFETY: The pointer, capacity, and length are guaranteed by the safety
-                    // contract of this method to define a valid `Vec<C>`.
-                    unsafe {
-                        Vec::<C_>::from_raw_parts(
-                            component_column.0.cast::<C_>(),
-                            length,
-                            component_column.1,
-                        )
-                    },
-                );
-                v.push(
+                let value =
                     // SAFETY: Since each component within a registry must be unique, we can
                     // guarantee that we will only read `component` one time, at which point it
                     // be a valid properly initialized value.
-                    unsafe { component.assume_init() },
-                );
+                    unsafe { component.assume_init() };
                 // Since the component won't be read again, we can set it to an uninitialized
                 // value.
                 component = MaybeUninit::uninit();
 
-                *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+                // SAFETY: The pointer, capacity, and length are guaranteed by the safety contract
+                // of this method to define a valid `Vec<C>`, and `C_` is the same type as `C`.
+                unsafe { column::push(component_column, length, value) };
             } else {
-                let mut v = ManuallyDrop::new(
-                    // SAFETY: The pointer, capacity, and length are guaranteed by the safety
-                    // contract of this method to define a valid `Vec<C>`.
-                    unsafe {
-                        Vec::<C>::from_raw_parts(
-                            component_column.0.cast::<C>(),
-                            length,
-                            component_column.1,
-                        )
-                    },
-                );
-                v.push(
+                let value =
                     // SAFETY: `buffer` is guaranteed by the safety contract of the method to be
                     // valid for reads and to point to all components identified by
                     // `identifier_iter` (except for the `component` parameter) in the order they
                     // are specified in the `Registry`. Therefore, the pointer must point to a
                     // valid, properly initialized value of type `C`.
-                    unsafe { buffer.cast::<C>().read_unaligned() },
-                );
+                    unsafe { buffer.cast::<C>().read_unaligned() };
                 buffer =
                     // SAFETY: `buffer` is guaranteed by the safety contract of the method to point
                     // to a packed buffer of components corresponding to all components identified
@@ -693,7 +1041,9 @@ where
                     // past the end of the allocation if no more components are in the buffer.
                     unsafe { buffer.add(size_of::<C>()) };
 
-                *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+                // SAFETY: The pointer, capacity, and length are guaranteed by the safety contract
+                // of this method to define a valid `Vec<C>`.
+                unsafe { column::push(component_column, length, value) };
             }
 
             components =
@@ -752,6 +1102,14 @@ where
         R_: Registry,
     {
         if TypeId::of::<C>() == TypeId::of::<C_>() {
+            // The skipped component is being removed, so it is dropped.
+            drop(
+                // SAFETY: The bit buffer is guaranteed to have a valid, properly initialized value
+                // of type `C` at this point because the components within the bit buffer are
+                // guaranteed to be ordered in the same order as the registry. The value is not
+                // read again, since the buffer is offset past it below.
+                unsafe { buffer.cast::<C>().read_unaligned() },
+            );
             // Skip this component in the buffer.
             buffer =
                 // SAFETY: The bit buffer is guaranteed to have a value of type `C` at this point
@@ -772,25 +1130,13 @@ where
                 // set bits in `identifier_iter`. Since a bit must have been set to enter this
                 // block, there must be at least one component column.
                 unsafe { components.get_unchecked_mut(0) };
-            let mut v = ManuallyDrop::new(
-                // SAFETY: The pointer, capacity, and length are guaranteed by the safety
-                // contract of this method to define a valid `Vec<C>`.
-                unsafe {
-                    Vec::<C>::from_raw_parts(
-                        component_column.0.cast::<C>(),
-                        length,
-                        component_column.1,
-                    )
-                },
-            );
-            v.push(
+            let value =
                 // SAFETY: `buffer` is guaranteed by the safety contract of the method to be
                 // valid for reads and to point to all components identified by
                 // `identifier_iter` (except for the `component` parameter) in the order they
                 // are specified in the `Registry`. Therefore, the pointer must point to a
                 // valid, properly initialized value of type `C`.
-                unsafe { buffer.cast::<C>().read_unaligned() },
-            );
+                unsafe { buffer.cast::<C>().read_unaligned() };
             buffer =
                 // SAFETY: `buffer` is guaranteed by the safety contract of the method to point
                 // to a packed buffer of components corresponding to all components identified
@@ -800,7 +1146,9 @@ where
                 // past the end of the allocation if no more components are in the buffer.
                 unsafe { buffer.add(size_of::<C>()) };
 
-            *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+            // SAFETY: The pointer, capacity, and length are guaranteed by the safety contract
+            // of this method to define a valid `Vec<C>`.
+            unsafe { column::push(component_column, length, value) };
 
             components =
                 // SAFETY: `components` is guaranteed to have the same number of values as there
@@ -849,6 +1197,118 @@ where
         }
     }
 
+    unsafe fn push_components_from_buffers<R_>(
+        mut buffer: *const u8,
+        mut buffer_identifier_iter: archetype::identifier::Iter<R_>,
+        mut replacement_buffer: *const u8,
+        mut replacement_identifier_iter: archetype::identifier::Iter<R_>,
+        mut components: &mut [(*mut u8, usize)],
+        length: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) where
+        R_: Registry,
+    {
+        let mut component = None;
+        if
+        // SAFETY: `buffer_identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { buffer_identifier_iter.next().unwrap_unchecked() } {
+            component = Some(
+                // SAFETY: `buffer` is guaranteed by the safety contract of the method to be valid
+                // for reads and to point to all components identified by
+                // `buffer_identifier_iter` in the order they are specified in the `Registry`.
+                // Therefore, the pointer must point to a valid, properly initialized value of type
+                // `C`.
+                unsafe { buffer.cast::<C>().read_unaligned() },
+            );
+            buffer =
+                // SAFETY: `buffer` is guaranteed by the safety contract of the method to point to
+                // a packed buffer of components corresponding to all components identified by
+                // `buffer_identifier_iter` within the registry. Therefore, offsetting the buffer
+                // by `size_of::<C>()` will point it to the next component within the same
+                // allocation, or it will point it to one byte past the end of the allocation if no
+                // more components are in the buffer.
+                unsafe { buffer.add(size_of::<C>()) };
+        }
+        if
+        // SAFETY: `replacement_identifier_iter` is guaranteed by the safety contract of this
+        // method to return a value for every component within the registry.
+        unsafe { replacement_identifier_iter.next().unwrap_unchecked() } {
+            // Any component read from `buffer` is dropped here, since it is being replaced.
+            component = Some(
+                // SAFETY: `replacement_buffer` is guaranteed by the safety contract of the method
+                // to be valid for reads and to point to all components identified by
+                // `replacement_identifier_iter` in the order they are specified in the
+                // `Registry`. Therefore, the pointer must point to a valid, properly initialized
+                // value of type `C`.
+                unsafe { replacement_buffer.cast::<C>().read_unaligned() },
+            );
+            replacement_buffer =
+                // SAFETY: `replacement_buffer` is guaranteed by the safety contract of the method
+                // to point to a packed buffer of components corresponding to all components
+                // identified by `replacement_identifier_iter` within the registry. Therefore,
+                // offsetting the buffer by `size_of::<C>()` will point it to the next component
+                // within the same allocation, or it will point it to one byte past the end of the
+                // allocation if no more components are in the buffer.
+                unsafe { replacement_buffer.add(size_of::<C>()) };
+        }
+
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let component_column =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(0) };
+            let value =
+                // SAFETY: Every component identified by `identifier_iter` is guaranteed to be
+                // identified by either `buffer_identifier_iter` or `replacement_identifier_iter`,
+                // so a component must have been read above.
+                unsafe { component.unwrap_unchecked() };
+            // SAFETY: The pointer, capacity, and length are guaranteed by the safety contract
+            // of this method to define a valid `Vec<C>`.
+            unsafe { column::push(component_column, length, value) };
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(1..) };
+        } else {
+            // The component is not being pushed, so it is dropped.
+            drop(component);
+        }
+
+        // SAFETY: At this point, one bit of each of `identifier_iter`, `buffer_identifier_iter`,
+        // and `replacement_identifier_iter` has been consumed.
+        //
+        // If the bit of `identifier_iter` was set, then the `components` slice will no longer
+        // include the first value, which corresponded to the component identified by the consumed
+        // bit. Otherwise, `components` is unaltered. In both cases, all remaining component values
+        // will still correspond to valid `Vec<C>`s identified by the remaining set bits in
+        // `identifier_iter`.
+        //
+        // Similarly, `buffer` and `replacement_buffer` have each been offset past the component
+        // `C` if and only if their respective identifier bit was set, meaning they still point to
+        // the remaining components identified by their identifier iterators.
+        //
+        // Furthermore, `R` is one component smaller than `(C, R)`, and since each identifier
+        // iterator has had one bit consumed, they still have the same number of bits remaining as
+        // `R` has components remaining.
+        unsafe {
+            R::push_components_from_buffers(
+                buffer,
+                buffer_identifier_iter,
+                replacement_buffer,
+                replacement_identifier_iter,
+                components,
+                length,
+                identifier_iter,
+            );
+        }
+    }
+
     unsafe fn free_components<R_>(
         mut components: &[(*mut u8, usize)],
         length: usize,
@@ -999,70 +1459,464 @@ where
         // bit, all remaining component values will still correspond to valid `Vec<C>`s identified
         // by the remaining set bits in `identifier_iter`.
         //
-        // If the bit was not set, then `components` is unaltered, and there are still up to the
-        // same number of elements as there are set bits in `identifier_iter`, which still make
-        // valid `Vec<C>`s for each `C` identified by the remaining set bits in `identifier_iter`.
+        // If the bit was not set, then `components` is unaltered, and there are still up to the
+        // same number of elements as there are set bits in `identifier_iter`, which still make
+        // valid `Vec<C>`s for each `C` identified by the remaining set bits in `identifier_iter`.
+        //
+        // Furthermore, regardless of whether the bit was set or not, `R` is one component smaller
+        // than `(C, R)`, and since `identifier_iter` has had one bit consumed, it still has the
+        // same number of bits remaining as `R` has components remaining.
+        unsafe { R::clear_components(components, length, identifier_iter) };
+    }
+
+    unsafe fn shrink_components_to_fit<R_>(
+        mut components: &mut [(*mut u8, usize)],
+        length: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) where
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let component_column =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(0) };
+            let mut v = ManuallyDrop::new(
+                // SAFETY: The pointer, capacity, and length are guaranteed by the safety
+                // contract of this method to define a valid `Vec<C>`.
+                unsafe {
+                    Vec::<C>::from_raw_parts(
+                        component_column.0.cast::<C>(),
+                        length,
+                        component_column.1,
+                    )
+                },
+            );
+            v.shrink_to_fit();
+            *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(1..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. There are two
+        // possibilities here: either the bit was set or it was not.
+        //
+        // If the bit was set, then the `components` slice will no longer include the first value,
+        // which means the slice will still contain up to the number of pointer and capacity tuples
+        // as there are set bits in `identifier_iter`. Additionally, since the first value was
+        // removed from the slice, which corresponded to the component identified by the consumed
+        // bit, all remaining component values will still correspond to valid `Vec<C>`s identified
+        // by the remaining set bits in `identifier_iter`.
+        //
+        // If the bit was not set, then `components` is unaltered, and there are still up to the
+        // same number of elements as there are set bits in `identifier_iter`, which still make
+        // valid `Vec<C>`s for each `C` identified by the remaining set bits in `identifier_iter`.
+        //
+        // Furthermore, regardless of whether the bit was set or not, `R` is one component smaller
+        // than `(C, R)`, and since `identifier_iter` has had one bit consumed, it still has the
+        // same number of bits remaining as `R` has components remaining.
+        unsafe { R::shrink_components_to_fit(components, length, identifier_iter) }
+    }
+
+    unsafe fn shrink_components_to<R_>(
+        mut components: &mut [(*mut u8, usize)],
+        length: usize,
+        min_capacity: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) where
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let component_column =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(0) };
+            let mut v = ManuallyDrop::new(
+                // SAFETY: The pointer, capacity, and length are guaranteed by the safety
+                // contract of this method to define a valid `Vec<C>`.
+                unsafe {
+                    Vec::<C>::from_raw_parts(
+                        component_column.0.cast::<C>(),
+                        length,
+                        component_column.1,
+                    )
+                },
+            );
+            v.shrink_to(min_capacity);
+            *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(1..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. There are two
+        // possibilities here: either the bit was set or it was not.
+        //
+        // If the bit was set, then the `components` slice will no longer include the first value,
+        // which means the slice will still contain up to the number of pointer and capacity tuples
+        // as there are set bits in `identifier_iter`. Additionally, since the first value was
+        // removed from the slice, which corresponded to the component identified by the consumed
+        // bit, all remaining component values will still correspond to valid `Vec<C>`s identified
+        // by the remaining set bits in `identifier_iter`.
+        //
+        // If the bit was not set, then `components` is unaltered, and there are still up to the
+        // same number of elements as there are set bits in `identifier_iter`, which still make
+        // valid `Vec<C>`s for each `C` identified by the remaining set bits in `identifier_iter`.
+        //
+        // Furthermore, regardless of whether the bit was set or not, `R` is one component smaller
+        // than `(C, R)`, and since `identifier_iter` has had one bit consumed, it still has the
+        // same number of bits remaining as `R` has components remaining.
+        unsafe { R::shrink_components_to(components, length, min_capacity, identifier_iter) }
+    }
+
+    unsafe fn reserve_components<R_>(
+        mut components: &mut [(*mut u8, usize)],
+        length: usize,
+        additional: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) where
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let component_column =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(0) };
+            // SAFETY: The pointer, capacity, and length are guaranteed by the safety contract of
+            // this method to define a valid `Vec<C>`.
+            unsafe { column::reserve::<C>(component_column, length, additional) };
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(1..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. There are two
+        // possibilities here: either the bit was set or it was not.
+        //
+        // If the bit was set, then the `components` slice will no longer include the first value,
+        // which means the slice will still contain up to the number of pointer and capacity tuples
+        // as there are set bits in `identifier_iter`. Additionally, since the first value was
+        // removed from the slice, which corresponded to the component identified by the consumed
+        // bit, all remaining component values will still correspond to valid `Vec<C>`s identified
+        // by the remaining set bits in `identifier_iter`.
+        //
+        // If the bit was not set, then `components` is unaltered, and there are still up to the
+        // same number of elements as there are set bits in `identifier_iter`, which still make
+        // valid `Vec<C>`s for each `C` identified by the remaining set bits in `identifier_iter`.
+        //
+        // Furthermore, regardless of whether the bit was set or not, `R` is one component smaller
+        // than `(C, R)`, and since `identifier_iter` has had one bit consumed, it still has the
+        // same number of bits remaining as `R` has components remaining.
+        unsafe { R::reserve_components(components, length, additional, identifier_iter) }
+    }
+
+    unsafe fn append_components<R_>(
+        mut components: &mut [(*mut u8, usize)],
+        length: usize,
+        mut other_components: &mut [(*mut u8, usize)],
+        other_length: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) where
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let component_column =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(0) };
+            let other_component_column =
+                // SAFETY: `other_components` is guaranteed to have the same number of values as
+                // there set bits in `identifier_iter`. Since a bit must have been set to enter
+                // this block, there must be at least one component column.
+                unsafe { other_components.get_unchecked_mut(0) };
+            let mut v = ManuallyDrop::new(
+                // SAFETY: The pointer, capacity, and length are guaranteed by the safety
+                // contract of this method to define a valid `Vec<C>`.
+                unsafe {
+                    Vec::<C>::from_raw_parts(
+                        component_column.0.cast::<C>(),
+                        length,
+                        component_column.1,
+                    )
+                },
+            );
+            let mut other_v = ManuallyDrop::new(
+                // SAFETY: The pointer, capacity, and length are guaranteed by the safety
+                // contract of this method to define a valid `Vec<C>`.
+                unsafe {
+                    Vec::<C>::from_raw_parts(
+                        other_component_column.0.cast::<C>(),
+                        other_length,
+                        other_component_column.1,
+                    )
+                },
+            );
+            v.append(&mut other_v);
+            *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+            *other_component_column = (other_v.as_mut_ptr().cast::<u8>(), other_v.capacity());
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(1..) };
+            other_components =
+                // SAFETY: `other_components` is guaranteed to have the same number of values as
+                // there set bits in `identifier_iter`. Since a bit must have been set to enter
+                // this block, there must be at least one component column.
+                unsafe { other_components.get_unchecked_mut(1..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. There are two
+        // possibilities here: either the bit was set or it was not.
+        //
+        // If the bit was set, then the `components` and `other_components` slices will no longer
+        // include their first values, which means the slices will still contain the same number
+        // of pointer and capacity tuples as there are set bits in `identifier_iter`.
+        // Additionally, since the first values were removed from the slices, which corresponded
+        // to the component identified by the consumed bit, all remaining component values will
+        // still correspond to valid `Vec<C>`s identified by the remaining set bits in
+        // `identifier_iter`.
+        //
+        // If the bit was not set, then `components` and `other_components` are unaltered, and
+        // there are still the same number of elements as there are set bits in
+        // `identifier_iter`, which still make valid `Vec<C>`s for each `C` identified by the
+        // remaining set bits in `identifier_iter`.
+        //
+        // Furthermore, regardless of whether the bit was set or not, `R` is one component smaller
+        // than `(C, R)`, and since `identifier_iter` has had one bit consumed, it still has the
+        // same number of bits remaining as `R` has components remaining.
+        unsafe {
+            R::append_components(
+                components,
+                length,
+                other_components,
+                other_length,
+                identifier_iter,
+            );
+        }
+    }
+
+    unsafe fn append_components_and_component<C_, R_>(
+        component: &C_,
+        mut components: &mut [(*mut u8, usize)],
+        length: usize,
+        mut other_components: &mut [(*mut u8, usize)],
+        other_length: usize,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) where
+        C_: Clone + Component,
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            let component_column =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(0) };
+
+            if TypeId::of::<C>() == TypeId::of::<C_>() {
+                let mut v = ManuallyDrop::new(
+                    // SAFETY: The pointer, capacity, and length are guaranteed by the safety
+                    // contract of this method to define a valid `Vec<C>`.
+                    unsafe {
+                        Vec::<C_>::from_raw_parts(
+                            component_column.0.cast::<C_>(),
+                            length,
+                            component_column.1,
+                        )
+                    },
+                );
+                v.resize(length + other_length, component.clone());
+                *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+            } else {
+                let other_component_column =
+                    // SAFETY: `other_components` is guaranteed to have a value for every set bit
+                    // in `identifier_iter` except the bit for `C_`. Since a bit other than the
+                    // bit for `C_` must have been set to enter this block, there must be at least
+                    // one component column.
+                    unsafe { other_components.get_unchecked_mut(0) };
+                let mut v = ManuallyDrop::new(
+                    // SAFETY: The pointer, capacity, and length are guaranteed by the safety
+                    // contract of this method to define a valid `Vec<C>`.
+                    unsafe {
+                        Vec::<C>::from_raw_parts(
+                            component_column.0.cast::<C>(),
+                            length,
+                            component_column.1,
+                        )
+                    },
+                );
+                let mut other_v = ManuallyDrop::new(
+                    // SAFETY: The pointer, capacity, and length are guaranteed by the safety
+                    // contract of this method to define a valid `Vec<C>`.
+                    unsafe {
+                        Vec::<C>::from_raw_parts(
+                            other_component_column.0.cast::<C>(),
+                            other_length,
+                            other_component_column.1,
+                        )
+                    },
+                );
+                v.append(&mut other_v);
+                *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+                *other_component_column = (other_v.as_mut_ptr().cast::<u8>(), other_v.capacity());
+                other_components =
+                    // SAFETY: `other_components` is guaranteed to have a value for every set bit
+                    // in `identifier_iter` except the bit for `C_`. Since a bit other than the
+                    // bit for `C_` must have been set to enter this block, there must be at least
+                    // one component column.
+                    unsafe { other_components.get_unchecked_mut(1..) };
+            }
+
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked_mut(1..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. If the bit was
+        // set, then the first value of `components` was removed, along with the first value of
+        // `other_components` if the bit did not correspond to `C_`. Therefore `components` still
+        // contains a value for each set bit remaining in `identifier_iter`, and `other_components`
+        // contains a value for each set bit remaining except for the bit corresponding to `C_`, if
+        // it has not yet been consumed. Since `R_` contains no duplicate components, the bit for
+        // `C_` is consumed exactly once.
+        //
+        // If the bit was not set, then `components` and `other_components` are unaltered.
         //
         // Furthermore, regardless of whether the bit was set or not, `R` is one component smaller
         // than `(C, R)`, and since `identifier_iter` has had one bit consumed, it still has the
         // same number of bits remaining as `R` has components remaining.
-        unsafe { R::clear_components(components, length, identifier_iter) };
+        unsafe {
+            R::append_components_and_component(
+                component,
+                components,
+                length,
+                other_components,
+                other_length,
+                identifier_iter,
+            );
+        }
     }
 
-    unsafe fn shrink_components_to_fit<R_>(
+    unsafe fn append_components_without_component<C_, R_>(
         mut components: &mut [(*mut u8, usize)],
         length: usize,
+        mut other_components: &mut [(*mut u8, usize)],
+        other_length: usize,
         mut identifier_iter: archetype::identifier::Iter<R_>,
     ) where
+        C_: Component,
         R_: Registry,
     {
         if
         // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
         // return a value for every component within the registry.
         unsafe { identifier_iter.next().unwrap_unchecked() } {
-            let component_column =
-                // SAFETY: `components` is guaranteed to have the same number of values as there
-                // set bits in `identifier_iter`. Since a bit must have been set to enter this
-                // block, there must be at least one component column.
-                unsafe { components.get_unchecked_mut(0) };
-            let mut v = ManuallyDrop::new(
-                // SAFETY: The pointer, capacity, and length are guaranteed by the safety
-                // contract of this method to define a valid `Vec<C>`.
+            let other_component_column =
+                // SAFETY: `other_components` is guaranteed to have the same number of values as
+                // there set bits in `identifier_iter`. Since a bit must have been set to enter
+                // this block, there must be at least one component column.
+                unsafe { other_components.get_unchecked_mut(0) };
+            let mut other_v = ManuallyDrop::new(
+                // SAFETY: The pointer, capacity, and length are guaranteed by the safety contract
+                // of this method to define a valid `Vec<C>`.
                 unsafe {
                     Vec::<C>::from_raw_parts(
-                        component_column.0.cast::<C>(),
-                        length,
-                        component_column.1,
+                        other_component_column.0.cast::<C>(),
+                        other_length,
+                        other_component_column.1,
                     )
                 },
             );
-            v.shrink_to_fit();
-            *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
-            components =
-                // SAFETY: `components` is guaranteed to have the same number of values as there
-                // set bits in `identifier_iter`. Since a bit must have been set to enter this
-                // block, there must be at least one component column.
-                unsafe { components.get_unchecked_mut(1..) };
+
+            if TypeId::of::<C>() == TypeId::of::<C_>() {
+                other_v.clear();
+            } else {
+                let component_column =
+                    // SAFETY: `components` is guaranteed to have a value for every set bit in
+                    // `identifier_iter` except the bit for `C_`. Since a bit other than the bit
+                    // for `C_` must have been set to enter this block, there must be at least one
+                    // component column.
+                    unsafe { components.get_unchecked_mut(0) };
+                let mut v = ManuallyDrop::new(
+                    // SAFETY: The pointer, capacity, and length are guaranteed by the safety
+                    // contract of this method to define a valid `Vec<C>`.
+                    unsafe {
+                        Vec::<C>::from_raw_parts(
+                            component_column.0.cast::<C>(),
+                            length,
+                            component_column.1,
+                        )
+                    },
+                );
+                v.append(&mut other_v);
+                *component_column = (v.as_mut_ptr().cast::<u8>(), v.capacity());
+                components =
+                    // SAFETY: `components` is guaranteed to have a value for every set bit in
+                    // `identifier_iter` except the bit for `C_`. Since a bit other than the bit
+                    // for `C_` must have been set to enter this block, there must be at least one
+                    // component column.
+                    unsafe { components.get_unchecked_mut(1..) };
+            }
+            *other_component_column = (other_v.as_mut_ptr().cast::<u8>(), other_v.capacity());
+
+            other_components =
+                // SAFETY: `other_components` is guaranteed to have the same number of values as
+                // there set bits in `identifier_iter`. Since a bit must have been set to enter
+                // this block, there must be at least one component column.
+                unsafe { other_components.get_unchecked_mut(1..) };
         }
 
-        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. There are two
-        // possibilities here: either the bit was set or it was not.
-        //
-        // If the bit was set, then the `components` slice will no longer include the first value,
-        // which means the slice will still contain up to the number of pointer and capacity tuples
-        // as there are set bits in `identifier_iter`. Additionally, since the first value was
-        // removed from the slice, which corresponded to the component identified by the consumed
-        // bit, all remaining component values will still correspond to valid `Vec<C>`s identified
-        // by the remaining set bits in `identifier_iter`.
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. If the bit was
+        // set, then the first value of `other_components` was removed, along with the first value
+        // of `components` if the bit did not correspond to `C_`. Therefore `other_components`
+        // still contains a value for each set bit remaining in `identifier_iter`, and
+        // `components` contains a value for each set bit remaining except for the bit
+        // corresponding to `C_`, if it has not yet been consumed. Since `R_` contains no
+        // duplicate components, the bit for `C_` is consumed exactly once.
         //
-        // If the bit was not set, then `components` is unaltered, and there are still up to the
-        // same number of elements as there are set bits in `identifier_iter`, which still make
-        // valid `Vec<C>`s for each `C` identified by the remaining set bits in `identifier_iter`.
+        // If the bit was not set, then `components` and `other_components` are unaltered.
         //
         // Furthermore, regardless of whether the bit was set or not, `R` is one component smaller
         // than `(C, R)`, and since `identifier_iter` has had one bit consumed, it still has the
         // same number of bits remaining as `R` has components remaining.
-        unsafe { R::shrink_components_to_fit(components, length, identifier_iter) }
+        unsafe {
+            R::append_components_without_component::<C_, R_>(
+                components,
+                length,
+                other_components,
+                other_length,
+                identifier_iter,
+            );
+        }
     }
 
     unsafe fn debug_identifier<R_>(
@@ -1083,6 +1937,25 @@ where
         // has components remaining.
         unsafe { R::debug_identifier(debug_list, identifier_iter) };
     }
+
+    unsafe fn extend_component_type_ids<R_>(
+        type_ids: &mut Vec<TypeId>,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) where
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            type_ids.push(TypeId::of::<C>());
+        }
+
+        // SAFETY: One bit of `identifier_iter` has been consumed, and since `R` is one component
+        // smaller than `(C, R)`, `identifier_iter` has the same number of bits remaining as `R`
+        // has components remaining.
+        unsafe { R::extend_component_type_ids(type_ids, identifier_iter) };
+    }
 }
 
 #[cfg(test)]
@@ -1097,6 +1970,7 @@ mod tests {
         vec::Vec,
     };
     use core::{
+        any::TypeId,
         marker::PhantomData,
         mem::{
             size_of,
@@ -1696,6 +2570,82 @@ mod tests {
         assert_eq!(new_c_column, vec![C(1.0), C(1.1), C(1.2), C(1.3)]);
     }
 
+    #[test]
+    fn push_components_from_buffers() {
+        #[derive(Debug, PartialEq)]
+        struct A(usize);
+        #[derive(Debug, PartialEq)]
+        struct B(bool);
+        #[derive(Debug, PartialEq)]
+        struct C(f32);
+        type Registry = Registry!(A, B, C);
+        let identifier = unsafe { Identifier::<Registry>::new(vec![5]) };
+        let mut a_column = ManuallyDrop::new(vec![A(0), A(1), A(2)]);
+        let mut c_column = ManuallyDrop::new(vec![C(1.0), C(1.1), C(1.2)]);
+        let mut components = vec![
+            (a_column.as_mut_ptr().cast::<u8>(), a_column.capacity()),
+            (c_column.as_mut_ptr().cast::<u8>(), c_column.capacity()),
+        ];
+
+        // Initialize input buffer, containing `A` and `B`.
+        let buffer_identifier = unsafe { Identifier::<Registry>::new(vec![3]) };
+        let buffer_size =
+            unsafe { Registry::size_of_components_for_identifier(buffer_identifier.iter()) };
+        let mut buffer = Vec::<u8>::with_capacity(buffer_size);
+        unsafe { buffer.set_len(buffer_size) };
+        let buffer_ptr = buffer.as_mut_ptr();
+        unsafe { buffer_ptr.cast::<A>().write_unaligned(A(3)) };
+        unsafe {
+            buffer_ptr
+                .add(size_of::<A>())
+                .cast::<B>()
+                .write_unaligned(B(false))
+        };
+        // Initialize replacement buffer, containing `A` and `C`.
+        let replacement_identifier = unsafe { Identifier::<Registry>::new(vec![5]) };
+        let replacement_size =
+            unsafe { Registry::size_of_components_for_identifier(replacement_identifier.iter()) };
+        let mut replacement = Vec::<u8>::with_capacity(replacement_size);
+        unsafe { replacement.set_len(replacement_size) };
+        let replacement_ptr = replacement.as_mut_ptr();
+        unsafe { replacement_ptr.cast::<A>().write_unaligned(A(4)) };
+        unsafe {
+            replacement_ptr
+                .add(size_of::<A>())
+                .cast::<C>()
+                .write_unaligned(C(1.3))
+        };
+
+        unsafe {
+            Registry::push_components_from_buffers(
+                buffer_ptr,
+                buffer_identifier.iter(),
+                replacement_ptr,
+                replacement_identifier.iter(),
+                &mut components,
+                3,
+                identifier.iter(),
+            )
+        };
+
+        let new_a_column = unsafe {
+            Vec::from_raw_parts(
+                components.get(0).unwrap().0.cast::<A>(),
+                4,
+                components.get(0).unwrap().1,
+            )
+        };
+        let new_c_column = unsafe {
+            Vec::from_raw_parts(
+                components.get(1).unwrap().0.cast::<C>(),
+                4,
+                components.get(1).unwrap().1,
+            )
+        };
+        assert_eq!(new_a_column, vec![A(0), A(1), A(2), A(4)]);
+        assert_eq!(new_c_column, vec![C(1.0), C(1.1), C(1.2), C(1.3)]);
+    }
+
     #[test]
     fn free_components_empty_registry() {
         type Registry = Registry!();
@@ -1996,4 +2946,165 @@ mod tests {
         assert_eq!(new_a_column.capacity(), 3);
         assert_eq!(new_b_column.capacity(), 3);
     }
+
+    #[test]
+    fn shrink_components_to() {
+        struct A(usize);
+        struct B(bool);
+        type Registry = Registry!(A, B);
+        let identifier = unsafe { Identifier::<Registry>::new(vec![3]) };
+        let mut a_column = ManuallyDrop::new(Vec::with_capacity(100));
+        a_column.extend(vec![A(0), A(1), A(2)]);
+        let mut b_column = ManuallyDrop::new(Vec::with_capacity(5));
+        b_column.extend(vec![B(false), B(true), B(true)]);
+        let mut components = vec![
+            (a_column.as_mut_ptr().cast::<u8>(), a_column.capacity()),
+            (b_column.as_mut_ptr().cast::<u8>(), b_column.capacity()),
+        ];
+
+        unsafe { Registry::shrink_components_to(&mut components, 3, 10, identifier.iter()) };
+
+        let new_a_column = unsafe {
+            Vec::from_raw_parts(
+                components.get(0).unwrap().0.cast::<A>(),
+                0,
+                components.get(0).unwrap().1,
+            )
+        };
+        let new_b_column = unsafe {
+            Vec::from_raw_parts(
+                components.get(1).unwrap().0.cast::<B>(),
+                0,
+                components.get(1).unwrap().1,
+            )
+        };
+        assert_eq!(new_a_column.capacity(), 10);
+        assert_eq!(new_b_column.capacity(), 5);
+    }
+
+    #[test]
+    fn reserve_components_empty_registry() {
+        type Registry = Registry!();
+        let identifier = unsafe { Identifier::<Registry>::new(Vec::new()) };
+        let mut components = Vec::new();
+
+        unsafe { Registry::reserve_components(&mut components, 0, 10, identifier.iter()) };
+
+        assert!(components.is_empty());
+    }
+
+    #[test]
+    fn reserve_components_some() {
+        struct A(usize);
+        struct B(bool);
+        struct C(char);
+        type Registry = Registry!(A, B, C);
+        let identifier = unsafe { Identifier::<Registry>::new(vec![5]) };
+        let mut a_column = ManuallyDrop::new(vec![A(0), A(1), A(2)]);
+        let mut c_column = ManuallyDrop::new(vec![C('a'), C('b'), C('c')]);
+        let mut components = vec![
+            (a_column.as_mut_ptr().cast::<u8>(), a_column.capacity()),
+            (c_column.as_mut_ptr().cast::<u8>(), c_column.capacity()),
+        ];
+
+        unsafe { Registry::reserve_components(&mut components, 3, 10, identifier.iter()) };
+
+        let new_a_column = unsafe {
+            Vec::from_raw_parts(
+                components.get(0).unwrap().0.cast::<A>(),
+                3,
+                components.get(0).unwrap().1,
+            )
+        };
+        let new_c_column = unsafe {
+            Vec::from_raw_parts(
+                components.get(1).unwrap().0.cast::<C>(),
+                3,
+                components.get(1).unwrap().1,
+            )
+        };
+        assert!(new_a_column.capacity() >= 13);
+        assert!(new_c_column.capacity() >= 13);
+    }
+
+    #[test]
+    fn extend_component_type_ids_empty_registry() {
+        type Registry = Registry!();
+        let identifier = unsafe { Identifier::<Registry>::new(Vec::new()) };
+        let mut type_ids = Vec::new();
+
+        unsafe { Registry::extend_component_type_ids(&mut type_ids, identifier.iter()) };
+
+        assert!(type_ids.is_empty());
+    }
+
+    #[test]
+    fn extend_component_type_ids_all_components() {
+        struct A;
+        struct B;
+        struct C;
+        type Registry = Registry!(A, B, C);
+        let identifier = unsafe { Identifier::<Registry>::new(vec![7]) };
+        let mut type_ids = Vec::new();
+
+        unsafe { Registry::extend_component_type_ids(&mut type_ids, identifier.iter()) };
+
+        assert_eq!(
+            type_ids,
+            vec![TypeId::of::<A>(), TypeId::of::<B>(), TypeId::of::<C>()]
+        );
+    }
+
+    #[test]
+    fn extend_component_type_ids_some_components() {
+        struct A;
+        struct B;
+        struct C;
+        type Registry = Registry!(A, B, C);
+        let identifier = unsafe { Identifier::<Registry>::new(vec![5]) };
+        let mut type_ids = Vec::new();
+
+        unsafe { Registry::extend_component_type_ids(&mut type_ids, identifier.iter()) };
+
+        assert_eq!(type_ids, vec![TypeId::of::<A>(), TypeId::of::<C>()]);
+    }
+
+    #[test]
+    fn extend_component_type_ids_no_components() {
+        struct A;
+        struct B;
+        struct C;
+        type Registry = Registry!(A, B, C);
+        let identifier = unsafe { Identifier::<Registry>::new(vec![0]) };
+        let mut type_ids = Vec::new();
+
+        unsafe { Registry::extend_component_type_ids(&mut type_ids, identifier.iter()) };
+
+        assert!(type_ids.is_empty());
+    }
+
+    #[test]
+    fn size_of_component_columns_some_components() {
+        struct A(u64);
+        struct B(u8);
+        struct C(u32);
+        type Registry = Registry!(A, B, C);
+        let identifier = unsafe { Identifier::<Registry>::new(vec![5]) };
+        let mut components = Vec::new();
+        unsafe { Registry::new_components_with_capacity(&mut components, 4, identifier.iter()) };
+
+        let size = unsafe { Registry::size_of_component_columns(&components, identifier.iter()) };
+
+        assert_eq!(
+            size,
+            components
+                .iter()
+                .zip([size_of::<A>(), size_of::<C>()])
+                .map(|(&(_, capacity), size)| capacity * size)
+                .sum::<usize>()
+        );
+        assert!(size >= 4 * (size_of::<A>() + size_of::<C>()));
+
+        unsafe { Registry::free_components(&components, 0, identifier.iter()) };
+    }
 }
@@ -24,8 +24,8 @@ use crate::{
     },
 };
 use core::{
-    iter,
     mem::MaybeUninit,
+    ops,
     slice,
 };
 use either::Either;
@@ -61,6 +61,18 @@ where
     where
         R: Registry;
 
+    /// # Safety
+    ///
+    /// Each tuple in `columns` must contain the raw parts for a valid `Vec<C>` of size `length`
+    /// for components `C`, ordered for the archetype identified by `archetype_identifier`.
+    unsafe fn slices<R>(
+        columns: &[(*mut u8, usize)],
+        length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> V::Slices
+    where
+        R: Registry;
+
     unsafe fn view_one_maybe_uninit<R>(
         index: usize,
         columns: &[(*mut u8, usize)],
@@ -90,7 +102,7 @@ impl<'a> CanonicalViews<'a, view::Null, Null> for registry::Null {
     where
         R: Registry,
     {
-        iter::repeat(view::Null).take(length)
+        view::RepeatN::new(view::Null, length)
     }
 
     unsafe fn view_one<R>(
@@ -105,6 +117,17 @@ impl<'a> CanonicalViews<'a, view::Null, Null> for registry::Null {
         view::Null
     }
 
+    unsafe fn slices<R>(
+        _columns: &[(*mut u8, usize)],
+        _length: usize,
+        _archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> view::Null
+    where
+        R: Registry,
+    {
+        view::Null
+    }
+
     unsafe fn view_one_maybe_uninit<R>(
         _index: usize,
         _columns: &[(*mut u8, usize)],
@@ -131,6 +154,279 @@ impl<'a> CanonicalViews<'a, view::Null, Null> for registry::Null {
     }
 }
 
+impl<'a> CanonicalViews<'a, (view::Index, view::Null), (Contained, Null)> for registry::Null {
+    unsafe fn view<R>(
+        _columns: &[(*mut u8, usize)],
+        length: usize,
+        _archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> <(view::Index, view::Null) as ViewsSealed<'a>>::Results
+    where
+        R: Registry,
+    {
+        (
+            (0..length).map(view::Index as fn(usize) -> view::Index),
+            view::RepeatN::new(view::Null, length),
+        )
+    }
+
+    unsafe fn view_one<R>(
+        index: usize,
+        _columns: &[(*mut u8, usize)],
+        _length: usize,
+        _archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> (view::Index, view::Null)
+    where
+        R: Registry,
+    {
+        (view::Index(index), view::Null)
+    }
+
+    unsafe fn slices<R>(
+        _columns: &[(*mut u8, usize)],
+        length: usize,
+        _archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> (ops::Range<usize>, view::Null)
+    where
+        R: Registry,
+    {
+        (0..length, view::Null)
+    }
+
+    unsafe fn view_one_maybe_uninit<R>(
+        index: usize,
+        _columns: &[(*mut u8, usize)],
+        _length: usize,
+        _archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> (view::Index, view::Null)
+    where
+        R: Registry,
+    {
+        (view::Index(index), view::Null)
+    }
+
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    fn claims() -> Self::Claims {
+        claim::Null
+    }
+
+    fn indices<R>() -> (view::Null, view::Null)
+    where
+        R: registry::Length,
+    {
+        (view::Null, view::Null)
+    }
+}
+
+impl<'a> CanonicalViews<'a, (view::ArchetypeId, view::Null), (NotContained, (Contained, Null))>
+    for registry::Null
+{
+    unsafe fn view<R>(
+        _columns: &[(*mut u8, usize)],
+        length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> <(view::ArchetypeId, view::Null) as ViewsSealed<'a>>::Results
+    where
+        R: Registry,
+    {
+        (
+            view::RepeatN::new(
+                view::ArchetypeId::new(
+                    // SAFETY: The `IdentifierRef` created here does not outlive this function
+                    // call, and therefore does not outlive the archetype's
+                    // identifier.
+                    unsafe { archetype_identifier.identifier() },
+                ),
+                length,
+            ),
+            view::RepeatN::new(view::Null, length),
+        )
+    }
+
+    unsafe fn view_one<R>(
+        _index: usize,
+        _columns: &[(*mut u8, usize)],
+        _length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> (view::ArchetypeId, view::Null)
+    where
+        R: Registry,
+    {
+        (
+            view::ArchetypeId::new(
+                // SAFETY: The `IdentifierRef` created here does not outlive this function call,
+                // and therefore does not outlive the archetype's identifier.
+                unsafe { archetype_identifier.identifier() },
+            ),
+            view::Null,
+        )
+    }
+
+    unsafe fn slices<R>(
+        _columns: &[(*mut u8, usize)],
+        _length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> (view::ArchetypeId, view::Null)
+    where
+        R: Registry,
+    {
+        (
+            view::ArchetypeId::new(
+                // SAFETY: The `IdentifierRef` created here does not outlive this function call,
+                // and therefore does not outlive the archetype's identifier.
+                unsafe { archetype_identifier.identifier() },
+            ),
+            view::Null,
+        )
+    }
+
+    unsafe fn view_one_maybe_uninit<R>(
+        _index: usize,
+        _columns: &[(*mut u8, usize)],
+        _length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> (view::ArchetypeId, view::Null)
+    where
+        R: Registry,
+    {
+        (
+            view::ArchetypeId::new(
+                // SAFETY: The `IdentifierRef` created here does not outlive this function call,
+                // and therefore does not outlive the archetype's identifier.
+                unsafe { archetype_identifier.identifier() },
+            ),
+            view::Null,
+        )
+    }
+
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    fn claims() -> Self::Claims {
+        claim::Null
+    }
+
+    fn indices<R>() -> (view::Null, view::Null)
+    where
+        R: registry::Length,
+    {
+        (view::Null, view::Null)
+    }
+}
+
+impl<'a>
+    CanonicalViews<
+        'a,
+        (view::Index, (view::ArchetypeId, view::Null)),
+        (Contained, (Contained, Null)),
+    > for registry::Null
+{
+    unsafe fn view<R>(
+        _columns: &[(*mut u8, usize)],
+        length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> <(view::Index, (view::ArchetypeId, view::Null)) as ViewsSealed<'a>>::Results
+    where
+        R: Registry,
+    {
+        (
+            (0..length).map(view::Index as fn(usize) -> view::Index),
+            (
+                view::RepeatN::new(
+                    view::ArchetypeId::new(
+                        // SAFETY: The `IdentifierRef` created here does not outlive this function
+                        // call, and therefore does not outlive the archetype's
+                        // identifier.
+                        unsafe { archetype_identifier.identifier() },
+                    ),
+                    length,
+                ),
+                view::RepeatN::new(view::Null, length),
+            ),
+        )
+    }
+
+    unsafe fn view_one<R>(
+        index: usize,
+        _columns: &[(*mut u8, usize)],
+        _length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> (view::Index, (view::ArchetypeId, view::Null))
+    where
+        R: Registry,
+    {
+        (
+            view::Index(index),
+            (
+                view::ArchetypeId::new(
+                    // SAFETY: The `IdentifierRef` created here does not outlive this function
+                    // call, and therefore does not outlive the archetype's
+                    // identifier.
+                    unsafe { archetype_identifier.identifier() },
+                ),
+                view::Null,
+            ),
+        )
+    }
+
+    unsafe fn slices<R>(
+        _columns: &[(*mut u8, usize)],
+        length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> (ops::Range<usize>, (view::ArchetypeId, view::Null))
+    where
+        R: Registry,
+    {
+        (
+            0..length,
+            (
+                view::ArchetypeId::new(
+                    // SAFETY: The `IdentifierRef` created here does not outlive this function
+                    // call, and therefore does not outlive the archetype's
+                    // identifier.
+                    unsafe { archetype_identifier.identifier() },
+                ),
+                view::Null,
+            ),
+        )
+    }
+
+    unsafe fn view_one_maybe_uninit<R>(
+        index: usize,
+        _columns: &[(*mut u8, usize)],
+        _length: usize,
+        archetype_identifier: archetype::identifier::Iter<R>,
+    ) -> (view::Index, (view::ArchetypeId, view::Null))
+    where
+        R: Registry,
+    {
+        (
+            view::Index(index),
+            (
+                view::ArchetypeId::new(
+                    // SAFETY: The `IdentifierRef` created here does not outlive this function
+                    // call, and therefore does not outlive the archetype's
+                    // identifier.
+                    unsafe { archetype_identifier.identifier() },
+                ),
+                view::Null,
+            ),
+        )
+    }
+
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    fn claims() -> Self::Claims {
+        claim::Null
+    }
+
+    fn indices<R>() -> (view::Null, (view::Null, view::Null))
+    where
+        R: registry::Length,
+    {
+        (view::Null, (view::Null, view::Null))
+    }
+}
+
 impl<'a, C, P, R, V> CanonicalViews<'a, (&'a C, V), (&'a Contained, P)> for (C, R)
 where
     C: Component,
@@ -193,6 +489,28 @@ where
         )
     }
 
+    unsafe fn slices<R_>(
+        columns: &[(*mut u8, usize)],
+        length: usize,
+        mut archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> <(&'a C, V) as ViewsSealed<'a>>::Slices
+    where
+        R_: Registry,
+    {
+        archetype_identifier.next();
+        (
+            // SAFETY: `columns` is guaranteed to contain raw parts for a valid `Vec<C>` of size
+            // `length` for the currently viewed component `C`.
+            unsafe {
+                slice::from_raw_parts::<'a, C>(columns.get_unchecked(0).0.cast::<C>(), length)
+            },
+            // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
+            // for valid `Vec<C>`s of length `length` for each of the remaining components
+            // identified by `archetype_identifier`.
+            unsafe { R::slices(columns.get_unchecked(1..), length, archetype_identifier) },
+        )
+    }
+
     unsafe fn view_one_maybe_uninit<R_>(
         index: usize,
         mut columns: &[(*mut u8, usize)],
@@ -308,6 +626,28 @@ where
         )
     }
 
+    unsafe fn slices<R_>(
+        columns: &[(*mut u8, usize)],
+        length: usize,
+        mut archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> <(&'a mut C, V) as ViewsSealed<'a>>::Slices
+    where
+        R_: Registry,
+    {
+        archetype_identifier.next();
+        (
+            // SAFETY: `columns` is guaranteed to contain raw parts for a valid `Vec<C>` of size
+            // `length` for the currently viewed component `C`.
+            unsafe {
+                slice::from_raw_parts_mut::<'a, C>(columns.get_unchecked(0).0.cast::<C>(), length)
+            },
+            // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
+            // for valid `Vec<C>`s of length `length` for each of the remaining components
+            // identified by `archetype_identifier`.
+            unsafe { R::slices(columns.get_unchecked(1..), length, archetype_identifier) },
+        )
+    }
+
     unsafe fn view_one_maybe_uninit<R_>(
         index: usize,
         mut columns: &[(*mut u8, usize)],
@@ -402,7 +742,7 @@ where
                     .map(wrap_some),
                 )
             } else {
-                Either::Left(iter::repeat(None).take(length))
+                Either::Left(view::RepeatN::new(None, length))
             },
             // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
             // for valid `Vec<C>`s of length `length` for each of the remaining components
@@ -447,6 +787,41 @@ where
         )
     }
 
+    unsafe fn slices<R_>(
+        mut columns: &[(*mut u8, usize)],
+        length: usize,
+        mut archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> <(Option<&'a C>, V) as ViewsSealed<'a>>::Slices
+    where
+        R_: Registry,
+    {
+        (
+            // SAFETY: `archetype_identifier` is guaranteed to have at least one element remaining.
+            if unsafe { archetype_identifier.next().unwrap_unchecked() } {
+                // SAFETY: `columns` is guaranteed to contain raw parts for a valid `Vec<C>` of
+                // size `length` for the currently viewed component `C`.
+                Some(unsafe {
+                    slice::from_raw_parts(
+                        {
+                            let column = columns.get_unchecked(0);
+                            columns = columns.get_unchecked(1..);
+                            column
+                        }
+                        .0
+                        .cast::<C>(),
+                        length,
+                    )
+                })
+            } else {
+                None
+            },
+            // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
+            // for valid `Vec<C>`s of length `length` for each of the remaining components
+            // identified by `archetype_identifier`.
+            unsafe { R::slices(columns, length, archetype_identifier) },
+        )
+    }
+
     unsafe fn view_one_maybe_uninit<R_>(
         index: usize,
         mut columns: &[(*mut u8, usize)],
@@ -512,7 +887,7 @@ where
     where
         R_: Registry,
     {
-        fn none<'a, C>() -> Option<&'a mut C> {
+        fn none<'a, C>(_index: usize) -> Option<&'a mut C> {
             None
         }
 
@@ -538,7 +913,7 @@ where
                     .map(wrap_some),
                 )
             } else {
-                Either::Left(iter::repeat_with(none as fn() -> Option<&'a mut C>).take(length))
+                Either::Left((0..length).map(none as fn(usize) -> Option<&'a mut C>))
             },
             // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
             // for valid `Vec<C>`s of length `length` for each of the remaining components
@@ -583,6 +958,41 @@ where
         )
     }
 
+    unsafe fn slices<R_>(
+        mut columns: &[(*mut u8, usize)],
+        length: usize,
+        mut archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> <(Option<&'a mut C>, V) as ViewsSealed<'a>>::Slices
+    where
+        R_: Registry,
+    {
+        (
+            // SAFETY: `archetype_identifier` is guaranteed to have at least one element remaining.
+            if unsafe { archetype_identifier.next().unwrap_unchecked() } {
+                // SAFETY: `columns` is guaranteed to contain raw parts for a valid `Vec<C>` of
+                // size `length` for the currently viewed component `C`.
+                Some(unsafe {
+                    slice::from_raw_parts_mut(
+                        {
+                            let column = columns.get_unchecked(0);
+                            columns = columns.get_unchecked(1..);
+                            column
+                        }
+                        .0
+                        .cast::<C>(),
+                        length,
+                    )
+                })
+            } else {
+                None
+            },
+            // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
+            // for valid `Vec<C>`s of length `length` for each of the remaining components
+            // identified by `archetype_identifier`.
+            unsafe { R::slices(columns, length, archetype_identifier) },
+        )
+    }
+
     unsafe fn view_one_maybe_uninit<R_>(
         index: usize,
         mut columns: &[(*mut u8, usize)],
@@ -633,6 +1043,152 @@ where
     }
 }
 
+fn copy<C>(val: &C) -> view::Copied<C>
+where
+    C: Copy,
+{
+    view::Copied(*val)
+}
+
+impl<'a, C, P, R, V> CanonicalViews<'a, (view::Copied<C>, V), (view::Copied<Contained>, P)>
+    for (C, R)
+where
+    C: Component + Copy,
+    R: CanonicalViews<'a, V, P>,
+    V: Views<'a>,
+{
+    unsafe fn view<R_>(
+        columns: &[(*mut u8, usize)],
+        length: usize,
+        mut archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> <(view::Copied<C>, V) as ViewsSealed<'a>>::Results
+    where
+        R_: Registry,
+    {
+        archetype_identifier.next();
+        (
+            // SAFETY: `columns` is guaranteed to contain raw parts for a valid `Vec<C>` of size
+            // `length` for the currently viewed component `C`.
+            unsafe {
+                slice::from_raw_parts::<'a, C>(columns.get_unchecked(0).0.cast::<C>(), length)
+            }
+            .iter()
+            .map(copy),
+            // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
+            // for valid `Vec<C>`s of length `length` for each of the remaining components
+            // identified by `archetype_identifier`.
+            unsafe { R::view(columns.get_unchecked(1..), length, archetype_identifier) },
+        )
+    }
+
+    unsafe fn view_one<R_>(
+        index: usize,
+        columns: &[(*mut u8, usize)],
+        length: usize,
+        mut archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> (view::Copied<C>, V)
+    where
+        R_: Registry,
+    {
+        archetype_identifier.next();
+        (
+            // SAFETY: `columns` is guaranteed to contain raw parts for a valid `Vec<C>` of size
+            // `length` for the currently viewed component `C`. Consequentially, `index` is a valid
+            // index into this `Vec<C>`.
+            copy(unsafe {
+                slice::from_raw_parts::<'a, C>(columns.get_unchecked(0).0.cast::<C>(), length)
+                    .get_unchecked(index)
+            }),
+            // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
+            // for valid `Vec<C>`s of length `length` for each of the remaining components
+            // identified by `archetype_identifier`. `index` is guaranteed to be less than
+            // `length`.
+            unsafe {
+                R::view_one(
+                    index,
+                    columns.get_unchecked(1..),
+                    length,
+                    archetype_identifier,
+                )
+            },
+        )
+    }
+
+    unsafe fn slices<R_>(
+        columns: &[(*mut u8, usize)],
+        length: usize,
+        mut archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> <(view::Copied<C>, V) as ViewsSealed<'a>>::Slices
+    where
+        R_: Registry,
+    {
+        archetype_identifier.next();
+        (
+            // SAFETY: `columns` is guaranteed to contain raw parts for a valid `Vec<C>` of size
+            // `length` for the currently viewed component `C`.
+            unsafe {
+                slice::from_raw_parts::<'a, C>(columns.get_unchecked(0).0.cast::<C>(), length)
+            },
+            // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
+            // for valid `Vec<C>`s of length `length` for each of the remaining components
+            // identified by `archetype_identifier`.
+            unsafe { R::slices(columns.get_unchecked(1..), length, archetype_identifier) },
+        )
+    }
+
+    unsafe fn view_one_maybe_uninit<R_>(
+        index: usize,
+        mut columns: &[(*mut u8, usize)],
+        length: usize,
+        mut archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> (MaybeUninit<view::Copied<C>>, V::MaybeUninit)
+    where
+        R_: Registry,
+    {
+        (
+            // SAFETY: `archetype_identifier` is guaranteed to have at least one element remaining.
+            if unsafe { archetype_identifier.next().unwrap_unchecked() } {
+                // SAFETY: `columns` is guaranteed to contain raw parts for a valid `Vec<C>` of
+                // size `length` for the currently viewed component `C`. Consequentially, `index`
+                // is a valid index into this `Vec<C>`.
+                MaybeUninit::new(copy(unsafe {
+                    slice::from_raw_parts(
+                        {
+                            let column = columns.get_unchecked(0);
+                            columns = columns.get_unchecked(1..);
+                            column
+                        }
+                        .0
+                        .cast::<C>(),
+                        length,
+                    )
+                    .get_unchecked(index)
+                }))
+            } else {
+                MaybeUninit::uninit()
+            },
+            // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
+            // for valid `Vec<C>`s of length `length` for each of the remaining components
+            // identified by `archetype_identifier`. `index` is guaranteed to be less than
+            // `length`.
+            unsafe { R::view_one_maybe_uninit(index, columns, length, archetype_identifier) },
+        )
+    }
+
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    fn claims() -> Self::Claims {
+        (Claim::Immutable, R::claims())
+    }
+
+    fn indices<R_>() -> (usize, V::Indices)
+    where
+        R_: registry::Length,
+    {
+        (R_::LEN - R::LEN - 1, R::indices::<R_>())
+    }
+}
+
 impl<'a, C, P, R, V> CanonicalViews<'a, V, (NotContained, P)> for (C, R)
 where
     C: Component,
@@ -684,6 +1240,28 @@ where
         unsafe { R::view_one(index, columns, length, archetype_identifier) }
     }
 
+    unsafe fn slices<R_>(
+        mut columns: &[(*mut u8, usize)],
+        length: usize,
+        mut archetype_identifier: archetype::identifier::Iter<R_>,
+    ) -> V::Slices
+    where
+        R_: Registry,
+    {
+        // SAFETY: `archetype_identifier` is guaranteed to have at least one element remaining.
+        if unsafe { archetype_identifier.next().unwrap_unchecked() } {
+            // SAFETY: Since `archetype_identifier` has this component set, there is guaranteed to
+            // be at least one entry in `columns`.
+            unsafe {
+                columns = columns.get_unchecked(1..);
+            }
+        }
+        // SAFETY: The remaining components in `columns` are guaranteed to contain raw parts
+        // for valid `Vec<C>`s of length `length` for each of the remaining components
+        // identified by `archetype_identifier`.
+        unsafe { R::slices(columns, length, archetype_identifier) }
+    }
+
     unsafe fn view_one_maybe_uninit<R_>(
         index: usize,
         mut columns: &[(*mut u8, usize)],
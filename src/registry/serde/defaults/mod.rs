@@ -0,0 +1,23 @@
+//! Provides a `DeserializeDefaults` trait to indicate that a registry can fill in missing
+//! components with their default values after deserialization.
+
+mod sealed;
+
+pub(crate) use sealed::Sealed;
+
+/// Indicates that the components of `Defaults` are contained in the registry and implement
+/// [`Default`] and [`Clone`].
+///
+/// This allows entities deserialized without any of the components in `Defaults` to have them
+/// filled in with their default values. The components of `Defaults` may be listed in any order.
+///
+/// If `Defaults` contains components not in this registry, or components that do not implement
+/// `Default` and `Clone`, attempting to use this trait will result in a compiler error, since the
+/// trait won't be implemented for the combination of defaults and registry.
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub trait DeserializeDefaults<Defaults, Indices>: Sealed<Defaults, Indices> {}
+
+impl<Registry, Defaults, Indices> DeserializeDefaults<Defaults, Indices> for Registry where
+    Registry: Sealed<Defaults, Indices>
+{
+}
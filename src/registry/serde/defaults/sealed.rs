@@ -0,0 +1,45 @@
+use crate::{
+    component::Component,
+    registry,
+    registry::{
+        contains::Null,
+        ContainsComponent,
+        Registry,
+    },
+    World,
+};
+use core::hash::BuildHasher;
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub trait Sealed<Defaults, Indices>: Registry + Sized {
+    /// Adds the default value of each component in `Defaults` to every entity in `world` that
+    /// does not contain it.
+    fn add_defaults<Resources, H>(world: &mut World<Self, Resources, H>)
+    where
+        H: BuildHasher;
+}
+
+impl<R> Sealed<registry::Null, Null> for R
+where
+    R: Registry,
+{
+    fn add_defaults<Resources, H>(_world: &mut World<Self, Resources, H>)
+    where
+        H: BuildHasher,
+    {
+    }
+}
+
+impl<C, Defaults, I, Indices, R> Sealed<(C, Defaults), (I, Indices)> for R
+where
+    C: Clone + Component + Default,
+    R: ContainsComponent<C, I> + Sealed<Defaults, Indices>,
+{
+    fn add_defaults<Resources, H>(world: &mut World<Self, Resources, H>)
+    where
+        H: BuildHasher,
+    {
+        world.add_component_where_missing::<C, I>(C::default());
+        <R as Sealed<Defaults, Indices>>::add_defaults(world);
+    }
+}
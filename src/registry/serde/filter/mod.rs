@@ -0,0 +1,22 @@
+//! Provides a `SerializeFiltered` trait to indicate that a registry can serialize a subset of its
+//! components.
+
+mod sealed;
+
+pub(crate) use sealed::Sealed;
+
+/// Indicates that the components of `Filter` are an ordered subset of the registry's components.
+///
+/// This allows serializing only the components contained in `Filter`, skipping the rest. The
+/// components of `Filter` must be listed in the same relative order as they are in the registry.
+///
+/// If `Filter` contains components not in this registry, or if its components are not in the
+/// same order as they are in this registry, attempting to use this trait will result in a compiler
+/// error, since the trait won't be implemented for the combination of filter and registry.
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub trait SerializeFiltered<Filter, Indices>: Sealed<Filter, Indices> {}
+
+impl<Registry, Filter, Indices> SerializeFiltered<Filter, Indices> for Registry where
+    Registry: Sealed<Filter, Indices>
+{
+}
@@ -0,0 +1,158 @@
+use crate::{
+    archetype,
+    component::Component,
+    registry,
+    registry::{
+        contains::{
+            Contained,
+            NotContained,
+            Null,
+        },
+        Registry,
+    },
+};
+use alloc::vec::Vec;
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub trait Sealed<Filter, Indices>: Registry {
+    /// Collects the component columns of the components contained in `Filter`, as well as the
+    /// identifier of those components with respect to `Filter`.
+    ///
+    /// The columns are pushed to `filtered_components` in the order of the components within
+    /// `Filter`, and the corresponding bits are set within `filtered_identifier`, starting at
+    /// bit `position`.
+    ///
+    /// # Safety
+    /// `components` must contain the same number of values as there are set bits in the
+    /// `identifier_iter`.
+    ///
+    /// `filtered_identifier` must contain at least `(Filter::LEN + 7) / 8` bytes, and `position`
+    /// must be the number of components of `Filter` that have already been visited.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn filter_components<R>(
+        components: &[(*mut u8, usize)],
+        identifier_iter: archetype::identifier::Iter<R>,
+        filtered_components: &mut Vec<(*mut u8, usize)>,
+        filtered_identifier: &mut [u8],
+        position: usize,
+    ) where
+        R: Registry;
+}
+
+impl Sealed<registry::Null, Null> for registry::Null {
+    unsafe fn filter_components<R>(
+        _components: &[(*mut u8, usize)],
+        _identifier_iter: archetype::identifier::Iter<R>,
+        _filtered_components: &mut Vec<(*mut u8, usize)>,
+        _filtered_identifier: &mut [u8],
+        _position: usize,
+    ) where
+        R: Registry,
+    {
+    }
+}
+
+impl<C, F, I, R> Sealed<(C, F), (Contained, I)> for (C, R)
+where
+    C: Component,
+    R: Sealed<F, I>,
+{
+    unsafe fn filter_components<R_>(
+        mut components: &[(*mut u8, usize)],
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+        filtered_components: &mut Vec<(*mut u8, usize)>,
+        filtered_identifier: &mut [u8],
+        position: usize,
+    ) where
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            filtered_components.push(
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { *components.get_unchecked(0) },
+            );
+            // SAFETY: `filtered_identifier` is guaranteed to contain enough bytes for every
+            // component in the filter, and `position` is the index of this component within the
+            // filter.
+            unsafe {
+                *filtered_identifier.get_unchecked_mut(position / 8) |= 1 << (position % 8);
+            }
+
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked(1..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. If it was set,
+        // the corresponding column was removed from `components`, so `components` still contains
+        // the same number of values as there are set bits in `identifier_iter`. `R` is one
+        // component smaller than `(C, R)`, so `identifier_iter` has the same number of bits
+        // remaining as `R` has components remaining.
+        //
+        // One component of the filter has been visited, so the position is incremented.
+        unsafe {
+            R::filter_components(
+                components,
+                identifier_iter,
+                filtered_components,
+                filtered_identifier,
+                position + 1,
+            );
+        }
+    }
+}
+
+impl<C, F, I, R> Sealed<F, (NotContained, I)> for (C, R)
+where
+    C: Component,
+    R: Sealed<F, I>,
+{
+    unsafe fn filter_components<R_>(
+        mut components: &[(*mut u8, usize)],
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+        filtered_components: &mut Vec<(*mut u8, usize)>,
+        filtered_identifier: &mut [u8],
+        position: usize,
+    ) where
+        R_: Registry,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            // The column is skipped, since the component is not contained in the filter.
+            components =
+                // SAFETY: `components` is guaranteed to have the same number of values as there
+                // set bits in `identifier_iter`. Since a bit must have been set to enter this
+                // block, there must be at least one component column.
+                unsafe { components.get_unchecked(1..) };
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. If it was set,
+        // the corresponding column was removed from `components`, so `components` still contains
+        // the same number of values as there are set bits in `identifier_iter`. `R` is one
+        // component smaller than `(C, R)`, so `identifier_iter` has the same number of bits
+        // remaining as `R` has components remaining.
+        unsafe {
+            R::filter_components(
+                components,
+                identifier_iter,
+                filtered_components,
+                filtered_identifier,
+                position,
+            );
+        }
+    }
+}
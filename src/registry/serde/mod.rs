@@ -1,5 +1,9 @@
 mod de;
+mod defaults;
+mod filter;
 mod ser;
 
 pub use de::Deserialize;
+pub use defaults::DeserializeDefaults;
+pub use filter::SerializeFiltered;
 pub use ser::Serialize;
@@ -1,6 +1,10 @@
 use crate::{
     archetype,
-    archetype::SerializeColumn,
+    archetype::{
+        Chunk,
+        SerializeChunkedColumn,
+        SerializeColumn,
+    },
     component::Component,
     registry::{
         Null,
@@ -64,6 +68,35 @@ pub trait Sealed: Registry {
     where
         R: Registry,
         S: SerializeTuple;
+
+    /// Serializes each component column as a single column made up of the corresponding columns
+    /// of every chunk.
+    ///
+    /// # Safety
+    /// Each chunk in `chunks` must contain the same number of component columns as there are set
+    /// bits in the `identifier_iter`, starting at index `column`.
+    ///
+    /// Each `(*mut u8, usize)` in each chunk's components must be the pointer and capacity
+    /// respectively of a `Vec<C>` of the chunk's length, where `C` is the component corresponding
+    /// to the set bit in `identifier_iter`.
+    ///
+    /// `length` must be the sum of the lengths of all chunks.
+    ///
+    /// When called externally, the `Registry` `R` provided to the method must by the same as the
+    /// `Registry` on which this method is being called.
+    ///
+    /// When called internally, the `identifier_iter` must have the same amount of bits left as
+    /// there are components remaining.
+    unsafe fn serialize_chunked_components_by_column<R, S>(
+        chunks: &[Chunk],
+        column: usize,
+        length: usize,
+        tuple: &mut S,
+        identifier_iter: archetype::identifier::Iter<R>,
+    ) -> Result<(), S::Error>
+    where
+        R: Registry,
+        S: SerializeTuple;
 }
 
 impl Sealed for Null {
@@ -93,6 +126,20 @@ impl Sealed for Null {
     {
         Ok(())
     }
+
+    unsafe fn serialize_chunked_components_by_column<R, S>(
+        _chunks: &[Chunk],
+        _column: usize,
+        _length: usize,
+        _tuple: &mut S,
+        _identifier_iter: archetype::identifier::Iter<R>,
+    ) -> Result<(), S::Error>
+    where
+        R: Registry,
+        S: SerializeTuple,
+    {
+        Ok(())
+    }
 }
 
 impl<C, R> Sealed for (C, R)
@@ -223,4 +270,47 @@ where
         // method.
         unsafe { R::serialize_components_by_row(components, length, index, tuple, identifier_iter) }
     }
+
+    unsafe fn serialize_chunked_components_by_column<R_, S>(
+        chunks: &[Chunk],
+        mut column: usize,
+        length: usize,
+        tuple: &mut S,
+        mut identifier_iter: archetype::identifier::Iter<R_>,
+    ) -> Result<(), S::Error>
+    where
+        R_: Registry,
+        S: SerializeTuple,
+    {
+        if
+        // SAFETY: `identifier_iter` is guaranteed by the safety contract of this method to
+        // return a value for every component within the registry.
+        unsafe { identifier_iter.next().unwrap_unchecked() } {
+            tuple.serialize_element(
+                // SAFETY: Each chunk is guaranteed to contain a column for the component `C` at
+                // index `column`, and `length` is the sum of the lengths of the chunks.
+                &unsafe { SerializeChunkedColumn::<C>::new(chunks, column, length) },
+            )?;
+
+            column += 1;
+        }
+
+        // SAFETY: At this point, one bit of `identifier_iter` has been consumed. If the bit was
+        // set, `column` has been incremented past the column corresponding to the consumed bit,
+        // so each chunk still contains the same number of columns, starting at `column`, as there
+        // are set bits in `identifier_iter`. If the bit was not set, nothing has changed.
+        //
+        // Furthermore, regardless of whether the bit was set or not, `R` is one component smaller
+        // than `(C, R)`, and since `identifier_iter` has had one bit consumed, it still has the
+        // same number of bits remaining as `R` has components remaining.
+        unsafe {
+            R::serialize_chunked_components_by_column(
+                chunks,
+                column,
+                length,
+                tuple,
+                identifier_iter,
+            )
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use crate::resource::Null;
+
+/// Appends a resource to the end of a heterogeneous list of resources.
+pub trait Append<Resource> {
+    /// The list of resources with `Resource` appended.
+    type Output;
+
+    fn append(self, resource: Resource) -> Self::Output;
+}
+
+impl<Resource> Append<Resource> for Null {
+    type Output = (Resource, Null);
+
+    fn append(self, resource: Resource) -> Self::Output {
+        (resource, Null)
+    }
+}
+
+impl<Resource, R, Resources> Append<Resource> for (R, Resources)
+where
+    Resources: Append<Resource>,
+{
+    type Output = (R, Resources::Output);
+
+    fn append(self, resource: Resource) -> Self::Output {
+        (self.0, self.1.append(resource))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Append;
+    use crate::{
+        resource::Null,
+        resources,
+    };
+
+    #[test]
+    fn append_empty() {
+        assert_eq!(Null.append(42_u32), resources!(42_u32));
+    }
+
+    #[test]
+    fn append_multiple() {
+        assert_eq!(
+            resources!(1_u8, 'a').append(true),
+            resources!(1_u8, 'a', true)
+        );
+    }
+}
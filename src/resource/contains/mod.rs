@@ -4,6 +4,8 @@ mod views;
 pub use resource::ContainsResource;
 pub use views::ContainsViews;
 
+pub(crate) use views::Expanded;
+
 use crate::hlist::define_null_uninstantiable;
 
 define_null_uninstantiable!();
@@ -0,0 +1,600 @@
+//! Resources stored dynamically at runtime.
+//!
+//! Unlike the heterogeneous lists created with [`resources!`], the set of resources stored within
+//! a [`Dynamic`] container is not encoded in the type. This allows resources to be inserted and
+//! removed at runtime, at the cost of lookups being checked at runtime rather than at
+//! compile-time.
+//!
+//! [`resources!`]: crate::resources!
+
+#[cfg(feature = "rayon")]
+use crate::query::view::claim;
+use crate::{
+    query::view,
+    resource,
+    resource::{
+        contains::{
+            Expanded,
+            Null,
+        },
+        length::Length,
+        Resource,
+    },
+};
+use alloc::boxed::Box;
+use core::{
+    any::{
+        Any,
+        TypeId,
+    },
+    fmt,
+};
+use fnv::FnvBuildHasher;
+use hashbrown::HashMap;
+
+/// The storage of resources, keyed by the `TypeId` of each resource's type.
+type Map = HashMap<TypeId, Box<dyn Any + Send + Sync>, FnvBuildHasher>;
+
+/// A container of resources whose types are determined at runtime.
+///
+/// Each resource is stored by its type, meaning at most one resource of each type can be stored
+/// at a time. Inserting a resource of a type that is already stored replaces the old resource.
+///
+/// Resources are required to be [`Send`] and [`Sync`] when inserted, so that a `Dynamic` container
+/// can itself be sent and shared between threads.
+///
+/// A `Dynamic` container can be used as the resources of a [`World`], allowing resources to be
+/// inserted and removed through [`World::insert_resource()`] and [`World::remove_resource()`].
+///
+/// # Example
+/// ```
+/// use brood::resource;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Foo(u32);
+///
+/// let mut resources = resource::Dynamic::new();
+///
+/// resources.insert(Foo(42));
+/// assert_eq!(resources.get::<Foo>(), Some(&Foo(42)));
+///
+/// assert_eq!(resources.remove::<Foo>(), Some(Foo(42)));
+/// assert_eq!(resources.get::<Foo>(), None);
+/// ```
+///
+/// [`World`]: crate::World
+/// [`World::insert_resource()`]: crate::World::insert_resource()
+/// [`World::remove_resource()`]: crate::World::remove_resource()
+#[derive(Default)]
+pub struct Dynamic {
+    resources: Map,
+}
+
+impl Dynamic {
+    /// Creates an empty container of resources.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::resource;
+    ///
+    /// let resources = resource::Dynamic::new();
+    ///
+    /// assert!(resources.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a resource, returning the previously stored resource of the same type, if one
+    /// existed.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::resource;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    ///
+    /// let mut resources = resource::Dynamic::new();
+    ///
+    /// assert_eq!(resources.insert(Foo(1)), None);
+    /// assert_eq!(resources.insert(Foo(2)), Some(Foo(1)));
+    /// ```
+    pub fn insert<Resource>(&mut self, resource: Resource) -> Option<Resource>
+    where
+        Resource: self::Resource + Send + Sync,
+    {
+        self.resources
+            .insert(TypeId::of::<Resource>(), Box::new(resource))
+            .map(|previous| {
+                // SAFETY: Resources are always stored under the `TypeId` of their own type.
+                *unsafe { previous.downcast::<Resource>().unwrap_unchecked() }
+            })
+    }
+
+    /// Removes the resource of type `Resource`, returning it if it was stored.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::resource;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    ///
+    /// let mut resources = resource::Dynamic::new();
+    /// resources.insert(Foo(42));
+    ///
+    /// assert_eq!(resources.remove::<Foo>(), Some(Foo(42)));
+    /// assert_eq!(resources.remove::<Foo>(), None);
+    /// ```
+    pub fn remove<Resource>(&mut self) -> Option<Resource>
+    where
+        Resource: self::Resource,
+    {
+        self.resources
+            .remove(&TypeId::of::<Resource>())
+            .map(|resource| {
+                // SAFETY: Resources are always stored under the `TypeId` of their own type.
+                *unsafe { resource.downcast::<Resource>().unwrap_unchecked() }
+            })
+    }
+
+    /// Returns a reference to the resource of type `Resource`, if it is stored.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::resource;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    ///
+    /// let mut resources = resource::Dynamic::new();
+    /// resources.insert(Foo(42));
+    ///
+    /// assert_eq!(resources.get::<Foo>(), Some(&Foo(42)));
+    /// ```
+    #[must_use]
+    pub fn get<Resource>(&self) -> Option<&Resource>
+    where
+        Resource: self::Resource,
+    {
+        self.resources
+            .get(&TypeId::of::<Resource>())
+            .map(|resource| {
+                // SAFETY: Resources are always stored under the `TypeId` of their own type.
+                unsafe { resource.downcast_ref::<Resource>().unwrap_unchecked() }
+            })
+    }
+
+    /// Returns a mutable reference to the resource of type `Resource`, if it is stored.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::resource;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    ///
+    /// let mut resources = resource::Dynamic::new();
+    /// resources.insert(Foo(42));
+    ///
+    /// resources.get_mut::<Foo>().unwrap().0 = 100;
+    /// assert_eq!(resources.get::<Foo>(), Some(&Foo(100)));
+    /// ```
+    #[must_use]
+    pub fn get_mut<Resource>(&mut self) -> Option<&mut Resource>
+    where
+        Resource: self::Resource,
+    {
+        self.resources
+            .get_mut(&TypeId::of::<Resource>())
+            .map(|resource| {
+                // SAFETY: Resources are always stored under the `TypeId` of their own type.
+                unsafe { resource.downcast_mut::<Resource>().unwrap_unchecked() }
+            })
+    }
+
+    /// Views multiple resources at once, returning [`None`] if the views cannot be borrowed.
+    ///
+    /// This is the runtime-checked counterpart of viewing resources stored in a heterogeneous list.
+    /// The `Views` are borrowed only if every viewed resource is stored and the views are
+    /// disjoint. Views are disjoint if no resource is viewed mutably more than once, and no
+    /// resource is viewed both mutably and immutably. A resource may be viewed immutably any
+    /// number of times.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     resource,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    /// #[derive(Debug, PartialEq)]
+    /// struct Bar(char);
+    ///
+    /// let mut resources = resource::Dynamic::new();
+    /// resources.insert(Foo(42));
+    /// resources.insert(Bar('a'));
+    ///
+    /// let result!(foo, bar) = resources.try_view::<Views!(&Foo, &mut Bar)>().unwrap();
+    /// assert_eq!(foo, &Foo(42));
+    /// bar.0 = 'b';
+    ///
+    /// // The same resource cannot be viewed mutably twice.
+    /// assert!(resources.try_view::<Views!(&mut Foo, &mut Foo)>().is_none());
+    /// ```
+    ///
+    /// [`None`]: Option::None
+    #[must_use]
+    pub fn try_view<'a, Views>(&'a mut self) -> Option<Views>
+    where
+        Views: DynamicViews<'a>,
+    {
+        if !Views::is_disjoint() {
+            return None;
+        }
+        let resources: *mut _ = &mut self.resources;
+        // SAFETY: The views were checked to be disjoint above, so no resource will be borrowed
+        // mutably while any other borrow of it exists. `resources` was created from a unique
+        // borrow of the map, which lives for `'a`.
+        unsafe { Views::view(resources) }
+    }
+
+    /// Returns `true` if a resource of type `Resource` is stored.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::resource;
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut resources = resource::Dynamic::new();
+    /// assert!(!resources.contains::<Foo>());
+    ///
+    /// resources.insert(Foo(42));
+    /// assert!(resources.contains::<Foo>());
+    /// ```
+    #[must_use]
+    pub fn contains<Resource>(&self) -> bool
+    where
+        Resource: self::Resource,
+    {
+        self.resources.contains_key(&TypeId::of::<Resource>())
+    }
+
+    /// Returns the number of stored resources.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::resource;
+    ///
+    /// let mut resources = resource::Dynamic::new();
+    /// resources.insert(42_u32);
+    /// resources.insert('a');
+    ///
+    /// assert_eq!(resources.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    /// Returns `true` if no resources are stored.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::resource;
+    ///
+    /// let mut resources = resource::Dynamic::new();
+    /// assert!(resources.is_empty());
+    ///
+    /// resources.insert(42_u32);
+    /// assert!(!resources.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+}
+
+/// Views on resources that can be borrowed from a [`Dynamic`] container of resources.
+///
+/// This is implemented for heterogeneous lists of `&Resource` and `&mut Resource` views, such as
+/// those created by the [`Views!`] macro. Whether the views can actually be borrowed is checked
+/// at runtime by [`Dynamic::try_view()`].
+///
+/// [`Views!`]: crate::query::Views!
+pub trait DynamicViews<'a>: Sealed<'a> {}
+
+impl<'a, Views> DynamicViews<'a> for Views where Views: Sealed<'a> {}
+
+pub trait Sealed<'a>: Sized {
+    /// Returns `true` if no resource is borrowed mutably alongside any other borrow of itself.
+    fn is_disjoint() -> bool;
+
+    /// Returns `true` if any of these views conflict with a borrow of the resource identified by
+    /// `type_id`.
+    fn conflicts(type_id: TypeId, mutable: bool) -> bool;
+
+    /// Borrows the views from the stored resources, returning `None` if any viewed resource is not
+    /// stored.
+    ///
+    /// # Safety
+    /// `resources` must be valid for reads and writes for the lifetime `'a`, and must not be
+    /// accessed through any other pointer during that lifetime. The views must be disjoint, as
+    /// checked by `is_disjoint()`.
+    unsafe fn view(resources: *mut Map) -> Option<Self>;
+}
+
+impl<'a> Sealed<'a> for view::Null {
+    fn is_disjoint() -> bool {
+        true
+    }
+
+    fn conflicts(_type_id: TypeId, _mutable: bool) -> bool {
+        false
+    }
+
+    unsafe fn view(_resources: *mut Map) -> Option<Self> {
+        Some(view::Null)
+    }
+}
+
+impl<'a, Resource, Views> Sealed<'a> for (&'a Resource, Views)
+where
+    Resource: self::Resource,
+    Views: Sealed<'a>,
+{
+    fn is_disjoint() -> bool {
+        !Views::conflicts(TypeId::of::<Resource>(), false) && Views::is_disjoint()
+    }
+
+    fn conflicts(type_id: TypeId, mutable: bool) -> bool {
+        (mutable && type_id == TypeId::of::<Resource>()) || Views::conflicts(type_id, mutable)
+    }
+
+    unsafe fn view(resources: *mut Map) -> Option<Self> {
+        // SAFETY: `resources` is valid for reads for `'a`, and the resource is not borrowed
+        // mutably by any other view, since the views are disjoint.
+        let resource = unsafe { &*resources }.get(&TypeId::of::<Resource>())?;
+        Some((
+            // SAFETY: Resources are always stored under the `TypeId` of their own type.
+            unsafe { resource.downcast_ref::<Resource>().unwrap_unchecked() },
+            // SAFETY: The safety contract of this method is upheld by the caller.
+            unsafe { Views::view(resources) }?,
+        ))
+    }
+}
+
+impl<'a, Resource, Views> Sealed<'a> for (&'a mut Resource, Views)
+where
+    Resource: self::Resource,
+    Views: Sealed<'a>,
+{
+    fn is_disjoint() -> bool {
+        !Views::conflicts(TypeId::of::<Resource>(), true) && Views::is_disjoint()
+    }
+
+    fn conflicts(type_id: TypeId, mutable: bool) -> bool {
+        type_id == TypeId::of::<Resource>() || Views::conflicts(type_id, mutable)
+    }
+
+    unsafe fn view(resources: *mut Map) -> Option<Self> {
+        // SAFETY: `resources` is valid for writes for `'a`, and the resource is not borrowed by
+        // any other view, since the views are disjoint.
+        let resource = unsafe { &mut *resources }.get_mut(&TypeId::of::<Resource>())?;
+        Some((
+            // SAFETY: Resources are always stored under the `TypeId` of their own type.
+            unsafe { resource.downcast_mut::<Resource>().unwrap_unchecked() },
+            // SAFETY: The safety contract of this method is upheld by the caller.
+            unsafe { Views::view(resources) }?,
+        ))
+    }
+}
+
+impl fmt::Debug for Dynamic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dynamic")
+            .field("len", &self.resources.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl resource::Resources for Dynamic {}
+
+impl resource::sealed::Sealed for Dynamic {}
+
+// No resources can be borrowed through compile-time views, since their presence cannot be proven.
+impl Length for Dynamic {
+    const LEN: usize = 0;
+}
+
+#[cfg(feature = "rayon")]
+impl resource::Claims for Dynamic {
+    type Claims = claim::Null;
+}
+
+impl<'a, ReshapeIndices> Expanded<'a, view::Null, Null, Null, Null, ReshapeIndices> for Dynamic
+where
+    view::Null: view::resource::Reshape<view::Null, ReshapeIndices>,
+{
+    type Canonical = view::Null;
+
+    fn view(&'a mut self) -> view::Null {
+        view::Null
+    }
+
+    #[cfg(feature = "rayon")]
+    fn claims() -> Self::Claims {
+        claim::Null
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dynamic;
+    use crate::query::{
+        result,
+        Views,
+    };
+    use claims::{
+        assert_none,
+        assert_some,
+        assert_some_eq,
+    };
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct A(u32);
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct B(char);
+
+    #[test]
+    fn new_is_empty() {
+        let resources = Dynamic::new();
+
+        assert!(resources.is_empty());
+        assert_eq!(resources.len(), 0);
+    }
+
+    #[test]
+    fn insert() {
+        let mut resources = Dynamic::new();
+
+        assert_none!(resources.insert(A(42)));
+        assert_none!(resources.insert(B('a')));
+
+        assert_eq!(resources.len(), 2);
+        assert_some_eq!(resources.get::<A>(), &A(42));
+        assert_some_eq!(resources.get::<B>(), &B('a'));
+    }
+
+    #[test]
+    fn insert_replaces() {
+        let mut resources = Dynamic::new();
+        resources.insert(A(1));
+
+        assert_some_eq!(resources.insert(A(2)), A(1));
+        assert_eq!(resources.len(), 1);
+        assert_some_eq!(resources.get::<A>(), &A(2));
+    }
+
+    #[test]
+    fn remove() {
+        let mut resources = Dynamic::new();
+        resources.insert(A(42));
+
+        assert_some_eq!(resources.remove::<A>(), A(42));
+        assert!(resources.is_empty());
+    }
+
+    #[test]
+    fn remove_not_present() {
+        let mut resources = Dynamic::new();
+        resources.insert(A(42));
+
+        assert_none!(resources.remove::<B>());
+        assert_eq!(resources.len(), 1);
+    }
+
+    #[test]
+    fn get_not_present() {
+        let resources = Dynamic::new();
+
+        assert_none!(resources.get::<A>());
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut resources = Dynamic::new();
+        resources.insert(A(42));
+
+        resources.get_mut::<A>().unwrap().0 = 100;
+
+        assert_some_eq!(resources.get::<A>(), &A(100));
+    }
+
+    #[test]
+    fn contains() {
+        let mut resources = Dynamic::new();
+        resources.insert(A(42));
+
+        assert!(resources.contains::<A>());
+        assert!(!resources.contains::<B>());
+    }
+
+    #[test]
+    fn try_view() {
+        let mut resources = Dynamic::new();
+        resources.insert(A(42));
+        resources.insert(B('a'));
+
+        let result!(a, b) = resources.try_view::<Views!(&mut A, &B)>().unwrap();
+        a.0 = 100;
+
+        assert_eq!(b, &B('a'));
+        assert_some_eq!(resources.get::<A>(), &A(100));
+    }
+
+    #[test]
+    fn try_view_empty() {
+        let mut resources = Dynamic::new();
+
+        assert_some!(resources.try_view::<Views!()>());
+    }
+
+    #[test]
+    fn try_view_not_present() {
+        let mut resources = Dynamic::new();
+        resources.insert(A(42));
+
+        assert_none!(resources.try_view::<Views!(&mut A, &B)>());
+    }
+
+    #[test]
+    fn try_view_shared_twice() {
+        let mut resources = Dynamic::new();
+        resources.insert(A(42));
+
+        let result!(a1, a2) = resources.try_view::<Views!(&A, &A)>().unwrap();
+
+        assert_eq!(a1, &A(42));
+        assert_eq!(a2, &A(42));
+    }
+
+    #[test]
+    fn try_view_mutable_twice() {
+        let mut resources = Dynamic::new();
+        resources.insert(A(42));
+
+        assert_none!(resources.try_view::<Views!(&mut A, &mut A)>());
+    }
+
+    #[test]
+    fn try_view_mutable_and_shared() {
+        let mut resources = Dynamic::new();
+        resources.insert(A(42));
+
+        assert_none!(resources.try_view::<Views!(&A, &mut A)>());
+    }
+
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T>(_: &T)
+        where
+            T: Send + Sync,
+        {
+        }
+
+        let mut resources = Dynamic::new();
+        resources.insert(A(42));
+
+        assert_send_sync(&resources);
+    }
+}
@@ -37,11 +37,13 @@
 //! [`System`]: crate::system::System
 //! [`World`]: crate::World
 
+mod append;
 #[cfg(feature = "rayon")]
 mod claim;
 #[cfg(feature = "serde")]
 mod de;
 mod debug;
+mod dynamic;
 mod length;
 #[cfg(feature = "serde")]
 mod ser;
@@ -56,9 +58,14 @@ pub use contains::{
 #[cfg(feature = "serde")]
 pub use de::Deserialize;
 pub use debug::Debug;
+pub use dynamic::{
+    Dynamic,
+    DynamicViews,
+};
 #[cfg(feature = "serde")]
 pub use ser::Serialize;
 
+pub(crate) use append::Append;
 #[cfg(feature = "rayon")]
 pub(crate) use claim::Claims;
 #[cfg(feature = "serde")]
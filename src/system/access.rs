@@ -0,0 +1,349 @@
+use crate::query::view::Views;
+use alloc::vec::Vec;
+use core::any::{
+    type_name,
+    Any,
+    TypeId,
+};
+
+/// The kind of borrow made by an [`Access`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AccessKind {
+    /// The type is borrowed immutably, and may be borrowed immutably by other systems at the same
+    /// time.
+    Shared,
+    /// The type is borrowed mutably, and may not be borrowed by any other system at the same time.
+    Exclusive,
+}
+
+/// A single type accessed by a [`System`].
+///
+/// This is used to describe the components and resources accessed by a `System` within a
+/// [`SystemAccess`].
+///
+/// [`System`]: crate::system::System
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Access {
+    type_id: TypeId,
+    type_name: &'static str,
+    kind: AccessKind,
+}
+
+impl Access {
+    pub(crate) fn shared<T>() -> Self
+    where
+        T: Any,
+    {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            kind: AccessKind::Shared,
+        }
+    }
+
+    pub(crate) fn exclusive<T>() -> Self
+    where
+        T: Any,
+    {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            kind: AccessKind::Exclusive,
+        }
+    }
+
+    /// Returns the [`TypeId`] of the accessed type.
+    #[must_use]
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns the name of the accessed type.
+    ///
+    /// This is obtained using [`core::any::type_name()`], and is therefore only intended for
+    /// diagnostics.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns whether the type is borrowed immutably or mutably.
+    #[must_use]
+    pub fn kind(&self) -> AccessKind {
+        self.kind
+    }
+
+    /// Returns `true` if this access cannot be made at the same time as `other`.
+    ///
+    /// Two accesses conflict if they access the same type and at least one of them is
+    /// [`Exclusive`].
+    ///
+    /// [`Exclusive`]: AccessKind::Exclusive
+    #[must_use]
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        self.type_id == other.type_id
+            && (self.kind == AccessKind::Exclusive || other.kind == AccessKind::Exclusive)
+    }
+}
+
+/// The components and resources accessed by a [`System`].
+///
+/// This is a runtime description of the borrows declared by a `System`'s `Views`, `EntryViews`,
+/// and `ResourceViews`. It is intended for diagnostics, such as determining why two systems are
+/// not run in parallel within a [`Schedule`].
+///
+/// Note that a `Schedule` may still run two systems with conflicting accesses in parallel if the
+/// archetypes they view at runtime are disjoint. Conflicts reported here are therefore
+/// conservative.
+///
+/// This `struct` is returned by [`System::access()`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     query::{
+///         filter,
+///         result,
+///         Result,
+///         Views,
+///     },
+///     registry,
+///     system::System,
+/// };
+///
+/// struct Foo(usize);
+/// struct Bar(bool);
+///
+/// struct A;
+///
+/// impl System for A {
+///     type Views<'a> = Views!(&'a mut Foo);
+///     type Filter = filter::None;
+///     type ResourceViews<'a> = Views!();
+///     type EntryViews<'a> = Views!();
+///
+///     fn run<'a, R, S, I, E>(
+///         &mut self,
+///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+///     ) where
+///         R: registry::Registry,
+///         I: Iterator<Item = Self::Views<'a>>,
+///     {
+///     }
+/// }
+///
+/// struct B;
+///
+/// impl System for B {
+///     type Views<'a> = Views!(&'a Foo, &'a Bar);
+///     type Filter = filter::None;
+///     type ResourceViews<'a> = Views!();
+///     type EntryViews<'a> = Views!();
+///
+///     fn run<'a, R, S, I, E>(
+///         &mut self,
+///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+///     ) where
+///         R: registry::Registry,
+///         I: Iterator<Item = Self::Views<'a>>,
+///     {
+///     }
+/// }
+///
+/// let a = A::access();
+/// let b = B::access();
+///
+/// let conflicts = a.conflicts(&b).collect::<Vec<_>>();
+/// assert_eq!(conflicts.len(), 1);
+/// assert!(conflicts[0].0.type_name().ends_with("Foo"));
+/// ```
+///
+/// [`Schedule`]: trait@crate::system::schedule::Schedule
+/// [`System`]: crate::system::System
+/// [`System::access()`]: crate::system::System::access()
+#[derive(Clone, Debug)]
+pub struct SystemAccess {
+    components: Vec<Access>,
+    resources: Vec<Access>,
+}
+
+impl SystemAccess {
+    pub(crate) fn new<'a, Views, EntryViews, ResourceViews>() -> Self
+    where
+        Views: self::Views<'a>,
+        EntryViews: self::Views<'a>,
+        ResourceViews: self::Views<'a>,
+    {
+        let mut components = Vec::new();
+        Views::accesses(&mut components);
+        EntryViews::accesses(&mut components);
+        let mut resources = Vec::new();
+        ResourceViews::accesses(&mut resources);
+
+        Self {
+            components: merge(components),
+            resources: merge(resources),
+        }
+    }
+
+    /// Returns the components accessed.
+    ///
+    /// Each component type is listed at most once. If a component is borrowed both immutably and
+    /// mutably, it is listed as [`Exclusive`].
+    ///
+    /// [`Exclusive`]: AccessKind::Exclusive
+    #[must_use]
+    pub fn components(&self) -> &[Access] {
+        &self.components
+    }
+
+    /// Returns the resources accessed.
+    ///
+    /// Each resource type is listed at most once. If a resource is borrowed both immutably and
+    /// mutably, it is listed as [`Exclusive`].
+    ///
+    /// [`Exclusive`]: AccessKind::Exclusive
+    #[must_use]
+    pub fn resources(&self) -> &[Access] {
+        &self.resources
+    }
+
+    /// Returns an iterator over the pairs of accesses that conflict between `self` and `other`.
+    ///
+    /// Each pair contains the access made by `self` followed by the access made by `other`.
+    pub fn conflicts<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> impl Iterator<Item = (&'a Access, &'a Access)> {
+        conflicts(&self.components, &other.components)
+            .chain(conflicts(&self.resources, &other.resources))
+    }
+
+    /// Returns `true` if none of the accesses of `self` conflict with those of `other`.
+    #[must_use]
+    pub fn is_compatible_with(&self, other: &Self) -> bool {
+        self.conflicts(other).next().is_none()
+    }
+}
+
+/// Combines the accesses to the same type into a single access.
+fn merge(accesses: Vec<Access>) -> Vec<Access> {
+    let mut merged: Vec<Access> = Vec::with_capacity(accesses.len());
+    for access in accesses {
+        if let Some(existing) = merged
+            .iter_mut()
+            .find(|existing| existing.type_id == access.type_id)
+        {
+            if access.kind == AccessKind::Exclusive {
+                existing.kind = AccessKind::Exclusive;
+            }
+        } else {
+            merged.push(access);
+        }
+    }
+    merged
+}
+
+fn conflicts<'a>(
+    a: &'a [Access],
+    b: &'a [Access],
+) -> impl Iterator<Item = (&'a Access, &'a Access)> {
+    a.iter().flat_map(move |a_access| {
+        b.iter()
+            .filter(move |b_access| a_access.conflicts_with(b_access))
+            .map(move |b_access| (a_access, b_access))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Access,
+        AccessKind,
+        SystemAccess,
+    };
+    use crate::query::{
+        view,
+        Views,
+    };
+    use alloc::vec;
+    use core::any::TypeId;
+
+    struct A;
+    struct B;
+    struct C;
+
+    #[test]
+    fn access_conflicts_with() {
+        assert!(Access::exclusive::<A>().conflicts_with(&Access::shared::<A>()));
+        assert!(Access::shared::<A>().conflicts_with(&Access::exclusive::<A>()));
+        assert!(Access::exclusive::<A>().conflicts_with(&Access::exclusive::<A>()));
+        assert!(!Access::shared::<A>().conflicts_with(&Access::shared::<A>()));
+        assert!(!Access::exclusive::<A>().conflicts_with(&Access::exclusive::<B>()));
+    }
+
+    #[test]
+    fn system_access_components() {
+        let access = SystemAccess::new::<
+            Views!(&A, &mut B, crate::entity::Identifier),
+            Views!(&C),
+            Views!(),
+        >();
+
+        assert_eq!(
+            access
+                .components()
+                .iter()
+                .map(|access| (access.type_id(), access.kind()))
+                .collect::<alloc::vec::Vec<_>>(),
+            vec![
+                (TypeId::of::<A>(), AccessKind::Shared),
+                (TypeId::of::<B>(), AccessKind::Exclusive),
+                (TypeId::of::<C>(), AccessKind::Shared),
+            ]
+        );
+        assert!(access.resources().is_empty());
+    }
+
+    #[test]
+    fn system_access_merges_duplicates() {
+        let access = SystemAccess::new::<Views!(&A, Option<&A>), Views!(&mut A), view::Null>();
+
+        assert_eq!(access.components(), &[Access::exclusive::<A>()]);
+    }
+
+    #[test]
+    fn system_access_resources() {
+        let access = SystemAccess::new::<Views!(), Views!(), Views!(&A, &mut B)>();
+
+        assert!(access.components().is_empty());
+        assert_eq!(
+            access.resources(),
+            &[Access::shared::<A>(), Access::exclusive::<B>()]
+        );
+    }
+
+    #[test]
+    fn system_access_conflicts() {
+        let a = SystemAccess::new::<Views!(&mut A, &B), Views!(), Views!(&C)>();
+        let b = SystemAccess::new::<Views!(&A, &B), Views!(), Views!(&mut C)>();
+
+        assert_eq!(
+            a.conflicts(&b).collect::<alloc::vec::Vec<_>>(),
+            vec![
+                (&Access::exclusive::<A>(), &Access::shared::<A>()),
+                (&Access::shared::<C>(), &Access::exclusive::<C>()),
+            ]
+        );
+        assert!(!a.is_compatible_with(&b));
+    }
+
+    #[test]
+    fn system_access_compatible() {
+        let a = SystemAccess::new::<Views!(&mut A, &B), Views!(), Views!(&C)>();
+        let b = SystemAccess::new::<Views!(&mut C, &B), Views!(), Views!(&C)>();
+
+        assert_eq!(a.conflicts(&b).count(), 0);
+        assert!(a.is_compatible_with(&b));
+    }
+}
@@ -53,16 +53,23 @@
 //! [`System`]: crate::system::System
 //! [`World`]: crate::world::World
 
-#[cfg(feature = "rayon")]
-#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
 pub mod schedule;
 
+mod access;
 #[cfg(feature = "rayon")]
 mod par;
+#[cfg(feature = "rayon")]
+mod par_reduce;
 
+pub use access::{
+    Access,
+    AccessKind,
+    SystemAccess,
+};
 #[cfg(feature = "rayon")]
 pub use par::ParSystem;
 #[cfg(feature = "rayon")]
+pub use par_reduce::ParReduceSystem;
 #[doc(inline)]
 pub use schedule::{
     inner::Schedule,
@@ -74,8 +81,11 @@ use crate::{
         view::Views,
         Result,
     },
+    registry,
     registry::ContainsViews,
+    World,
 };
+use core::hash::BuildHasher;
 
 /// An executable type which operates over the entities within a [`World`].
 ///
@@ -206,4 +216,98 @@ pub trait System {
     ) where
         R: ContainsViews<'a, Self::EntryViews<'a>, E>,
         I: Iterator<Item = Self::Views<'a>>;
+
+    /// Returns the components and resources accessed by this system.
+    ///
+    /// The returned [`SystemAccess`] describes the borrows declared by `Self::Views`,
+    /// `Self::EntryViews`, and `Self::ResourceViews`. This is useful for diagnosing why two
+    /// systems are not run in parallel within a [`Schedule`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Result,
+    ///         Views,
+    ///     },
+    ///     registry,
+    ///     system::{
+    ///         AccessKind,
+    ///         System,
+    ///     },
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// struct MySystem;
+    ///
+    /// impl System for MySystem {
+    ///     type Views<'a> = Views!(&'a mut Foo, &'a Bar);
+    ///     type Filter = filter::None;
+    ///     type ResourceViews<'a> = Views!();
+    ///     type EntryViews<'a> = Views!();
+    ///
+    ///     fn run<'a, R, S, I, E>(
+    ///         &mut self,
+    ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+    ///     ) where
+    ///         R: registry::Registry,
+    ///         I: Iterator<Item = Self::Views<'a>>,
+    ///     {
+    ///     }
+    /// }
+    ///
+    /// let access = MySystem::access();
+    /// assert_eq!(access.components()[0].kind(), AccessKind::Exclusive);
+    /// assert_eq!(access.components()[1].kind(), AccessKind::Shared);
+    /// ```
+    ///
+    /// [`Schedule`]: trait@crate::system::schedule::Schedule
+    /// [`SystemAccess`]: crate::system::SystemAccess
+    #[must_use]
+    fn access<'a>() -> SystemAccess
+    where
+        Self::ResourceViews<'a>: Views<'a>,
+    {
+        SystemAccess::new::<Self::Views<'a>, Self::EntryViews<'a>, Self::ResourceViews<'a>>()
+    }
+
+    /// Logic to be run before a schedule containing this system is run.
+    ///
+    /// This is called by [`World::run_schedule()`] on every task in the schedule before the first
+    /// stage is run. The [`World`] is borrowed mutably, allowing structural changes such as
+    /// inserting or removing entities. These calls are made serially.
+    ///
+    /// By default, this method does nothing.
+    ///
+    /// [`World`]: crate::world::World
+    /// [`World::run_schedule()`]: crate::world::World::run_schedule()
+    #[allow(unused_variables)]
+    fn initialize<Registry, Resources, H>(&mut self, world: &mut World<Registry, Resources, H>)
+    where
+        Registry: registry::Registry,
+        H: BuildHasher,
+    {
+    }
+
+    /// Logic to be run after a schedule containing this system is run.
+    ///
+    /// This is called by [`World::run_schedule()`] on every task in the schedule after the last
+    /// stage is run. The [`World`] is borrowed mutably, allowing structural changes such as
+    /// inserting or removing entities. These calls are made serially.
+    ///
+    /// By default, this method does nothing.
+    ///
+    /// [`World`]: crate::world::World
+    /// [`World::run_schedule()`]: crate::world::World::run_schedule()
+    #[allow(unused_variables)]
+    fn finalize<Registry, Resources, H>(&mut self, world: &mut World<Registry, Resources, H>)
+    where
+        Registry: registry::Registry,
+        H: BuildHasher,
+    {
+    }
 }
@@ -6,8 +6,11 @@ use crate::{
         },
         Result,
     },
+    registry,
     registry::ContainsViews,
+    World,
 };
+use core::hash::BuildHasher;
 use rayon::iter::ParallelIterator;
 
 /// An executable type which operates over the entities within a [`World`] in parallel.
@@ -142,4 +145,40 @@ pub trait ParSystem {
     ) where
         R: ContainsViews<'a, Self::EntryViews<'a>, E>,
         I: ParallelIterator<Item = Self::Views<'a>>;
+
+    /// Logic to be run before a schedule containing this system is run.
+    ///
+    /// This is called by [`World::run_schedule()`] on every task in the schedule before the first
+    /// stage is run. The [`World`] is borrowed mutably, allowing structural changes such as
+    /// inserting or removing entities. These calls are made serially.
+    ///
+    /// By default, this method does nothing.
+    ///
+    /// [`World`]: crate::world::World
+    /// [`World::run_schedule()`]: crate::world::World::run_schedule()
+    #[allow(unused_variables)]
+    fn initialize<Registry, Resources, H>(&mut self, world: &mut World<Registry, Resources, H>)
+    where
+        Registry: registry::Registry,
+        H: BuildHasher,
+    {
+    }
+
+    /// Logic to be run after a schedule containing this system is run.
+    ///
+    /// This is called by [`World::run_schedule()`] on every task in the schedule after the last
+    /// stage is run. The [`World`] is borrowed mutably, allowing structural changes such as
+    /// inserting or removing entities. These calls are made serially.
+    ///
+    /// By default, this method does nothing.
+    ///
+    /// [`World`]: crate::world::World
+    /// [`World::run_schedule()`]: crate::world::World::run_schedule()
+    #[allow(unused_variables)]
+    fn finalize<Registry, Resources, H>(&mut self, world: &mut World<Registry, Resources, H>)
+    where
+        Registry: registry::Registry,
+        H: BuildHasher,
+    {
+    }
 }
@@ -0,0 +1,96 @@
+use crate::query::view::ParViews;
+
+/// An executable type which reduces the entities within a [`World`] to a single value in
+/// parallel.
+///
+/// Where a [`ParSystem`] operates on the query results for their side effects, a
+/// `ParReduceSystem` maps the views of each entity to a partial [`Output`] and then folds those
+/// partial values together using [`reduce`]. This allows parallel aggregations, such as summing a
+/// component over all entities, without needing to accumulate into a shared resource.
+///
+/// The result of the reduction is returned by [`World::run_par_reduce_system()`]. If no entities
+/// match the query, the result is [`identity`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         filter,
+///         result,
+///         Views,
+///     },
+///     system::ParReduceSystem,
+///     Registry,
+///     World,
+/// };
+///
+/// // Define components.
+/// struct Foo(usize);
+/// struct Bar(bool);
+///
+/// // Define parallel system to sum the `Foo`s of entities whose `Bar` is set.
+/// struct SumFoo;
+///
+/// impl ParReduceSystem for SumFoo {
+///     type Views<'a> = Views!(&'a Foo, &'a Bar);
+///     type Filter = filter::None;
+///     type Output = usize;
+///
+///     fn identity(&self) -> Self::Output {
+///         0
+///     }
+///
+///     fn map<'a>(&self, result!(foo, bar): Self::Views<'a>) -> Self::Output {
+///         if bar.0 {
+///             foo.0
+///         } else {
+///             0
+///         }
+///     }
+///
+///     fn reduce(&self, a: Self::Output, b: Self::Output) -> Self::Output {
+///         a + b
+///     }
+/// }
+///
+/// let mut world = World::<Registry!(Foo, Bar)>::new();
+/// world.insert(entity!(Foo(1), Bar(true)));
+/// world.insert(entity!(Foo(2), Bar(false)));
+/// world.insert(entity!(Foo(3), Bar(true)));
+///
+/// assert_eq!(world.run_par_reduce_system(&SumFoo), 4);
+/// ```
+///
+/// [`identity`]: crate::system::ParReduceSystem::identity()
+/// [`Output`]: crate::system::ParReduceSystem::Output
+/// [`ParSystem`]: crate::system::ParSystem
+/// [`reduce`]: crate::system::ParReduceSystem::reduce()
+/// [`World`]: crate::world::World
+/// [`World::run_par_reduce_system()`]: crate::world::World::run_par_reduce_system()
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+pub trait ParReduceSystem: Sync {
+    /// The filter to apply to queries run by this system.
+    type Filter;
+    /// The views on components this system should operate on.
+    type Views<'a>: ParViews<'a>;
+    /// The value this system reduces the query results to.
+    type Output: Send;
+
+    /// Returns the identity value of the reduction.
+    ///
+    /// This may be called any number of times, and must always return a value that leaves any
+    /// other value unchanged when passed to [`reduce`] with it.
+    ///
+    /// [`reduce`]: crate::system::ParReduceSystem::reduce()
+    fn identity(&self) -> Self::Output;
+
+    /// Maps the views of a single entity to a partial value.
+    fn map<'a>(&self, views: Self::Views<'a>) -> Self::Output;
+
+    /// Combines two partial values into one.
+    ///
+    /// Since partial values are combined in an unspecified order, this operation should be
+    /// associative.
+    fn reduce(&self, a: Self::Output, b: Self::Output) -> Self::Output;
+}
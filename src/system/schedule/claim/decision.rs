@@ -1,2 +1,16 @@
 pub struct Append;
 pub struct Cut;
+
+/// Indicates whether a decision splits tasks into separate stages.
+pub trait Decision {
+    /// Whether this decision is a `Cut`.
+    const CUT: bool;
+}
+
+impl Decision for Append {
+    const CUT: bool = false;
+}
+
+impl Decision for Cut {
+    const CUT: bool = true;
+}
@@ -51,9 +51,31 @@ where
     type Result = <U as Inverse<<R as Get<T, I>>::Remainder, IS>>::Result;
 }
 
+impl<I, IS, R, T, U> Inverse<R, (I, IS)> for (view::Copied<T>, U)
+where
+    R: Get<T, I>,
+    U: Inverse<<R as Get<T, I>>::Remainder, IS>,
+{
+    type Result = <U as Inverse<<R as Get<T, I>>::Remainder, IS>>::Result;
+}
+
 impl<I, R, U> Inverse<R, I> for (entity::Identifier, U)
 where
     U: Inverse<R, I>,
 {
     type Result = <U as Inverse<R, I>>::Result;
 }
+
+impl<I, R, U> Inverse<R, I> for (view::Index, U)
+where
+    U: Inverse<R, I>,
+{
+    type Result = <U as Inverse<R, I>>::Result;
+}
+
+impl<I, R, U> Inverse<R, I> for (view::ArchetypeId, U)
+where
+    U: Inverse<R, I>,
+{
+    type Result = <U as Inverse<R, I>>::Result;
+}
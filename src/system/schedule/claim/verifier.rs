@@ -20,6 +20,8 @@ pub struct MutImmut;
 pub struct MutOptionImmut;
 pub struct MutMut;
 pub struct MutOptionMut;
+pub struct ImmutCopied;
+pub struct MutCopied;
 
 /// Verifies whether a set of views, when compared with a claim, should result in an
 /// `Append` or a `Cut` for the task.
@@ -112,6 +114,64 @@ where
     type Decision = <U as Verifier<'a, R, C, IS, P>>::Decision;
 }
 
+/// Not present in the claims.
+impl<'a, R, C, I, IS, T, U, P> Verifier<'a, R, C, (I, IS), (NotPresent, P)> for (view::Copied<T>, U)
+where
+    R: Get<T, I>,
+    U: Verifier<'a, R, C, IS, P>,
+{
+    type Decision = <U as Verifier<'a, R, C, IS, P>>::Decision;
+}
+
+/// Multiple immutable references are acceptable.
+impl<'a, R, C, I, IS, T, U, P> Verifier<'a, R, C, (I, IS), (ImmutImmut, P)> for (view::Copied<T>, U)
+where
+    C: Get<&'a T, I>,
+    T: 'a,
+    U: Verifier<'a, R, C, IS, P>,
+{
+    type Decision = <U as Verifier<'a, R, C, IS, P>>::Decision;
+}
+
+/// Multiple immutable references are acceptable.
+impl<'a, R, C, I, IS, T, U, P> Verifier<'a, R, C, (I, IS), (ImmutOptionImmut, P)>
+    for (view::Copied<T>, U)
+where
+    C: Get<Option<&'a T>, I>,
+    T: 'a,
+    U: Verifier<'a, R, C, IS, P>,
+{
+    type Decision = <U as Verifier<'a, R, C, IS, P>>::Decision;
+}
+
+/// Multiple immutable references are acceptable.
+impl<'a, R, C, I, IS, T, U, P> Verifier<'a, R, C, (I, IS), (ImmutCopied, P)>
+    for (view::Copied<T>, U)
+where
+    C: Get<view::Copied<T>, I>,
+    U: Verifier<'a, R, C, IS, P>,
+{
+    type Decision = <U as Verifier<'a, R, C, IS, P>>::Decision;
+}
+
+/// Multiple immutable references are acceptable.
+impl<'a, R, C, I, IS, T, U, P> Verifier<'a, R, C, (I, IS), (ImmutCopied, P)> for (&'a T, U)
+where
+    C: Get<view::Copied<T>, I>,
+    U: Verifier<'a, R, C, IS, P>,
+{
+    type Decision = <U as Verifier<'a, R, C, IS, P>>::Decision;
+}
+
+/// Multiple immutable references are acceptable.
+impl<'a, R, C, I, IS, T, U, P> Verifier<'a, R, C, (I, IS), (ImmutCopied, P)> for (Option<&'a T>, U)
+where
+    C: Get<view::Copied<T>, I>,
+    U: Verifier<'a, R, C, IS, P>,
+{
+    type Decision = <U as Verifier<'a, R, C, IS, P>>::Decision;
+}
+
 /// Skip entity identifiers.
 impl<'a, R, C, I, U, P> Verifier<'a, R, C, I, P> for (entity::Identifier, U)
 where
@@ -120,6 +180,22 @@ where
     type Decision = <U as Verifier<'a, R, C, I, P>>::Decision;
 }
 
+/// Skip indices.
+impl<'a, R, C, I, U, P> Verifier<'a, R, C, I, P> for (view::Index, U)
+where
+    U: Verifier<'a, R, C, I, P>,
+{
+    type Decision = <U as Verifier<'a, R, C, I, P>>::Decision;
+}
+
+/// Skip archetype identifiers.
+impl<'a, R, C, I, U, P> Verifier<'a, R, C, I, P> for (view::ArchetypeId, U)
+where
+    U: Verifier<'a, R, C, I, P>,
+{
+    type Decision = <U as Verifier<'a, R, C, I, P>>::Decision;
+}
+
 // ------------
 // "Fail" cases
 // ------------
@@ -234,3 +310,43 @@ where
 {
     type Decision = decision::Cut;
 }
+
+/// Previously borrowed as mutable.
+impl<'a, R, C, I, IS, T, U, P> Verifier<'a, R, C, (I, IS), (ImmutMut, P)> for (view::Copied<T>, U)
+where
+    C: Get<&'a mut T, I>,
+    T: 'a,
+    U: Verifier<'a, R, C, IS, P>,
+{
+    type Decision = decision::Cut;
+}
+
+/// Previously borrowed as mutable.
+impl<'a, R, C, I, IS, T, U, P> Verifier<'a, R, C, (I, IS), (ImmutOptionMut, P)>
+    for (view::Copied<T>, U)
+where
+    C: Get<Option<&'a mut T>, I>,
+    T: 'a,
+    U: Verifier<'a, R, C, IS, P>,
+{
+    type Decision = decision::Cut;
+}
+
+/// Previously borrowed as immutable.
+impl<'a, R, C, I, IS, T, U, P> Verifier<'a, R, C, (I, IS), (MutCopied, P)> for (&'a mut T, U)
+where
+    C: Get<view::Copied<T>, I>,
+    U: Verifier<'a, R, C, IS, P>,
+{
+    type Decision = decision::Cut;
+}
+
+/// Previously borrowed as immutable.
+impl<'a, R, C, I, IS, T, U, P> Verifier<'a, R, C, (I, IS), (MutCopied, P)>
+    for (Option<&'a mut T>, U)
+where
+    C: Get<view::Copied<T>, I>,
+    U: Verifier<'a, R, C, IS, P>,
+{
+    type Decision = decision::Cut;
+}
@@ -0,0 +1,308 @@
+//! Timing instrumentation for running a [`Schedule`].
+//!
+//! [`Schedule`]: trait@crate::system::schedule::Schedule
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+/// A source of time used to measure how long the tasks of a [`Schedule`] take to run.
+///
+/// Since brood is `no_std`, it has no way of reading the time itself. Instead, the time source is
+/// provided by the caller of [`World::run_schedule_instrumented()`]. The only requirement is that
+/// the returned times never decrease; the point from which they are measured does not matter.
+///
+/// When the `rayon` feature is enabled, `now()` may be called from multiple threads at once.
+///
+/// # Example
+/// ``` rust
+/// use brood::system::schedule::Clock;
+/// use core::time::Duration;
+/// use std::time::Instant;
+///
+/// struct StdClock(Instant);
+///
+/// impl Clock for StdClock {
+///     fn now(&self) -> Duration {
+///         self.0.elapsed()
+///     }
+/// }
+/// ```
+///
+/// [`Schedule`]: trait@crate::system::schedule::Schedule
+/// [`World::run_schedule_instrumented()`]: crate::World::run_schedule_instrumented()
+pub trait Clock: Sync {
+    /// Returns the current time, measured from an arbitrary but fixed point.
+    fn now(&self) -> Duration;
+}
+
+/// The time taken by a single task while running a [`Schedule`].
+///
+/// [`Schedule`]: trait@crate::system::schedule::Schedule
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TaskTiming {
+    name: &'static str,
+    duration: Duration,
+}
+
+impl TaskTiming {
+    /// Returns the name of the task's system.
+    ///
+    /// This is obtained using [`core::any::type_name()`], and is therefore only intended for
+    /// diagnostics.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns how long the task took to run.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// Timings recorded while running a [`Schedule`].
+///
+/// This `struct` is returned by [`World::run_schedule_instrumented()`].
+///
+/// [`Schedule`]: trait@crate::system::schedule::Schedule
+/// [`World::run_schedule_instrumented()`]: crate::World::run_schedule_instrumented()
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleReport {
+    tasks: Vec<TaskTiming>,
+    stages: Vec<Duration>,
+}
+
+impl ScheduleReport {
+    /// Returns the time taken by each task, in the order the tasks finished running.
+    ///
+    /// When tasks are run in parallel, their durations overlap. The sum of the durations may
+    /// therefore exceed the time taken to run the whole schedule.
+    #[must_use]
+    pub fn tasks(&self) -> &[TaskTiming] {
+        &self.tasks
+    }
+
+    /// Returns the time taken by each stage, in the order the stages were run.
+    ///
+    /// A stage's time includes any tasks from the following stage that were able to run alongside
+    /// it. Without the `rayon` feature, every task is run in its own stage.
+    #[must_use]
+    pub fn stages(&self) -> &[Duration] {
+        &self.stages
+    }
+
+    /// Returns the total time taken by all stages.
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.stages.iter().sum()
+    }
+}
+
+/// Records timings while a schedule is run.
+pub struct Recorder<'a> {
+    clock: &'a dyn Clock,
+    tasks: Log<TaskTiming>,
+    stages: Log<Duration>,
+}
+
+impl<'a> Recorder<'a> {
+    pub(crate) fn new(clock: &'a dyn Clock) -> Self {
+        Self {
+            clock,
+            tasks: Log::new(),
+            stages: Log::new(),
+        }
+    }
+
+    pub(crate) fn now(&self) -> Duration {
+        self.clock.now()
+    }
+
+    /// Record a task named `name` that started running at `start`.
+    pub(crate) fn record_task(&self, name: &'static str, start: Duration) {
+        self.tasks.push(TaskTiming {
+            name,
+            duration: self.now().saturating_sub(start),
+        });
+    }
+
+    /// Record a stage that started running at `start`.
+    pub(crate) fn record_stage(&self, start: Duration) {
+        self.stages.push(self.now().saturating_sub(start));
+    }
+
+    pub(crate) fn into_report(self) -> ScheduleReport {
+        ScheduleReport {
+            tasks: self.tasks.into_vec(),
+            stages: self.stages.into_vec(),
+        }
+    }
+}
+
+/// An append-only list of values that can be pushed to from multiple threads at once.
+///
+/// This is a lock-free stack of heap-allocated nodes. Since nodes are never removed while the list
+/// is shared, pushing only requires swapping in a new head.
+#[cfg(feature = "rayon")]
+struct Log<T> {
+    head: core::sync::atomic::AtomicPtr<Node<T>>,
+}
+
+#[cfg(feature = "rayon")]
+struct Node<T> {
+    value: T,
+    next: *mut Node<T>,
+}
+
+#[cfg(feature = "rayon")]
+impl<T> Log<T> {
+    fn new() -> Self {
+        Self {
+            head: core::sync::atomic::AtomicPtr::new(core::ptr::null_mut()),
+        }
+    }
+
+    fn push(&self, value: T) {
+        use core::sync::atomic::Ordering;
+
+        let node = alloc::boxed::Box::into_raw(alloc::boxed::Box::new(Node {
+            value,
+            next: core::ptr::null_mut(),
+        }));
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            // SAFETY: `node` was just allocated and has not yet been shared with any other thread.
+            unsafe { (*node).next = head };
+            match self
+                .head
+                .compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => break,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Returns the values in the order they were pushed.
+    fn into_vec(mut self) -> Vec<T> {
+        let mut values = Vec::new();
+        let mut node = core::mem::replace(self.head.get_mut(), core::ptr::null_mut());
+        while !node.is_null() {
+            // SAFETY: Every node in the list was allocated using `Box` and is owned only by the
+            // list, which is now exclusively borrowed.
+            let boxed = unsafe { alloc::boxed::Box::from_raw(node) };
+            node = boxed.next;
+            values.push(boxed.value);
+        }
+        // The list is stored with the most recently pushed value first.
+        values.reverse();
+        values
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> Drop for Log<T> {
+    fn drop(&mut self) {
+        let mut node = *self.head.get_mut();
+        while !node.is_null() {
+            // SAFETY: Every node in the list was allocated using `Box` and is owned only by the
+            // list, which is now exclusively borrowed.
+            let boxed = unsafe { alloc::boxed::Box::from_raw(node) };
+            node = boxed.next;
+        }
+    }
+}
+
+// SAFETY: The values are only accessed by the thread pushing them or through an exclusive
+// reference to the list, so sharing the list only requires that the values can be sent.
+#[cfg(feature = "rayon")]
+unsafe impl<T> Sync for Log<T> where T: Send {}
+
+// SAFETY: The list owns its values, so it can be sent as long as they can be sent.
+#[cfg(feature = "rayon")]
+unsafe impl<T> Send for Log<T> where T: Send {}
+
+/// An append-only list of values.
+///
+/// Without the `rayon` feature, tasks are only run on a single thread, so no synchronization is
+/// required.
+#[cfg(not(feature = "rayon"))]
+struct Log<T> {
+    values: core::cell::RefCell<Vec<T>>,
+}
+
+#[cfg(not(feature = "rayon"))]
+impl<T> Log<T> {
+    fn new() -> Self {
+        Self {
+            values: core::cell::RefCell::new(Vec::new()),
+        }
+    }
+
+    fn push(&self, value: T) {
+        self.values.borrow_mut().push(value);
+    }
+
+    /// Returns the values in the order they were pushed.
+    fn into_vec(self) -> Vec<T> {
+        self.values.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Clock,
+        Recorder,
+    };
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+    use core::{
+        cell::Cell,
+        time::Duration,
+    };
+
+    struct StepClock(Cell<u64>);
+
+    // SAFETY: The clock is only used on a single thread within these tests.
+    unsafe impl Sync for StepClock {}
+
+    impl Clock for StepClock {
+        fn now(&self) -> Duration {
+            let now = self.0.get();
+            self.0.set(now + 1);
+            Duration::from_secs(now)
+        }
+    }
+
+    #[test]
+    fn recorder_report() {
+        let clock = StepClock(Cell::new(0));
+        let recorder = Recorder::new(&clock);
+
+        let stage_start = recorder.now();
+        let task_start = recorder.now();
+        recorder.record_task("foo", task_start);
+        recorder.record_task("bar", recorder.now());
+        recorder.record_stage(stage_start);
+
+        let report = recorder.into_report();
+
+        assert_eq!(
+            report
+                .tasks()
+                .iter()
+                .map(|task| (task.name(), task.duration()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("foo", Duration::from_secs(1)),
+                ("bar", Duration::from_secs(1))
+            ]
+        );
+        assert_eq!(report.stages(), &[Duration::from_secs(5)]);
+        assert_eq!(report.total(), Duration::from_secs(5));
+    }
+}
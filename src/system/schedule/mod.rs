@@ -4,6 +4,9 @@
 //! will proceed through its tasks in order and run as many of them as possible in parallel.
 //! `System`s can run in parallel as long as their [`Views`] can be borrowed simulatenously.
 //!
+//! Parallel execution requires the `rayon` feature. Without it, a `Schedule` runs each of its
+//! tasks sequentially in the order they were provided.
+//!
 //! # Example
 //! The below example will define a schedule that will execute both `SystemA` and `SystemB` in
 //! parallel, since their views can be borrowed simultaneously.
@@ -82,14 +85,28 @@
 
 pub mod task;
 
-mod claim;
+#[cfg(feature = "rayon")]
+pub(crate) mod claim;
+mod instrument;
+#[cfg(feature = "rayon")]
 mod scheduler;
 mod sealed;
 mod sendable;
+#[cfg(feature = "rayon")]
 mod stage;
+#[cfg(feature = "rayon")]
 mod stager;
+#[cfg(feature = "rayon")]
 mod stages;
 
+pub use instrument::{
+    Clock,
+    ScheduleReport,
+    TaskTiming,
+};
+
+pub(crate) use instrument::Recorder;
+#[cfg(feature = "rayon")]
 pub(crate) use stages::Stages;
 
 use crate::{
@@ -97,9 +114,12 @@ use crate::{
     registry,
     resource,
 };
+#[cfg(feature = "rayon")]
 use scheduler::Scheduler;
 use sealed::Sealed;
+#[cfg(feature = "rayon")]
 use stage::Stage;
+#[cfg(feature = "rayon")]
 use stager::Stager;
 use task::Task;
 
@@ -151,11 +171,9 @@ doc::non_root_macro! {
     ///     system::{
     ///         schedule,
     ///         schedule::task,
-    ///         ParSystem,
     ///         System,
     ///     },
     /// };
-    /// use rayon::iter::ParallelIterator;
     ///
     /// // Define components.
     /// struct Foo(usize);
@@ -175,7 +193,7 @@ doc::non_root_macro! {
     ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
     ///     ) where
     ///         R: registry::Registry,
-    ///            I: Iterator<Item = Self::Views<'a>>,
+    ///         I: Iterator<Item = Self::Views<'a>>,
     ///     {
     ///         // Do something..
     ///     }
@@ -183,7 +201,7 @@ doc::non_root_macro! {
     ///
     /// struct SystemB;
     ///
-    /// impl ParSystem for SystemB {
+    /// impl System for SystemB {
     ///     type Views<'a> = Views!(&'a mut Baz, &'a Bar);
     ///     type Filter = filter::None;
     ///     type ResourceViews<'a> = Views!();
@@ -194,7 +212,7 @@ doc::non_root_macro! {
     ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
     ///     ) where
     ///         R: registry::Registry,
-    ///         I: ParallelIterator<Item = Self::Views<'a>>,
+    ///         I: Iterator<Item = Self::Views<'a>>,
     ///     {
     ///         // Do something..
     ///     }
@@ -237,12 +255,10 @@ pub(crate) mod inner {
         ///     registry,
         ///     system::{
         ///         schedule::task,
-        ///         ParSystem,
         ///         Schedule,
         ///         System,
         ///     },
         /// };
-        /// use rayon::iter::ParallelIterator;
         ///
         /// // Define components.
         /// struct Foo(usize);
@@ -270,7 +286,7 @@ pub(crate) mod inner {
         ///
         /// struct SystemB;
         ///
-        /// impl ParSystem for SystemB {
+        /// impl System for SystemB {
         ///     type Views<'a> = Views!(&'a mut Baz, &'a Bar);
         ///     type Filter = filter::None;
         ///     type ResourceViews<'a> = Views!();
@@ -281,7 +297,7 @@ pub(crate) mod inner {
         ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
         ///     ) where
         ///         R: registry::Registry,
-        ///         I: ParallelIterator<Item = Self::Views<'a>>,
+        ///         I: Iterator<Item = Self::Views<'a>>,
         ///     {
         ///         // Do something..
         ///     }
@@ -302,7 +318,7 @@ pub(crate) mod inner {
 
 pub use inner::Schedule;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rayon"))]
 mod tests {
     use super::Sealed as Schedule;
     use crate::{
@@ -1,12 +1,23 @@
+#[cfg(not(feature = "rayon"))]
+use crate::system::schedule::{
+    sendable::SendableWorld,
+    task,
+    Task,
+};
+#[cfg(feature = "rayon")]
+use crate::system::schedule::{
+    Scheduler,
+    Stages,
+};
 use crate::{
     registry::Registry,
     resource,
-    system::schedule::{
-        Scheduler,
-        Stages,
-    },
+    system::schedule::instrument::Recorder,
+    World,
 };
+use core::hash::BuildHasher;
 
+#[cfg(feature = "rayon")]
 pub trait Sealed<'a, R, Resources, Indices>
 where
     R: Registry,
@@ -29,8 +40,23 @@ where
     >;
 
     fn as_stages(&'a mut self) -> Self::Stages;
+
+    /// Run the schedule on the given world.
+    ///
+    /// Every task is initialized, then the stages are run, and finally every task is finalized.
+    /// If a `recorder` is provided, the time taken by each task and stage is recorded in it.
+    fn run<H>(&'a mut self, world: &mut World<R, Resources, H>, recorder: Option<&Recorder<'_>>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        let mut stages = self.as_stages();
+        stages.initialize(world);
+        stages.run(world, recorder, Self::Stages::new_has_run());
+        stages.finalize(world);
+    }
 }
 
+#[cfg(feature = "rayon")]
 impl<
         'a,
         R,
@@ -117,3 +143,136 @@ where
         self.as_stages()
     }
 }
+
+#[cfg(not(feature = "rayon"))]
+pub trait Sealed<'a, R, Resources, Indices>
+where
+    R: Registry,
+    Resources: resource::Resources,
+{
+    /// Initialize every task within the schedule, in order.
+    fn initialize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a;
+
+    /// Run every task within the schedule sequentially, in order.
+    ///
+    /// If a `recorder` is provided, the time taken by each task is recorded in it, with each task
+    /// counted as its own stage.
+    fn run_tasks<H>(&mut self, world: &mut World<R, Resources, H>, recorder: Option<&Recorder<'_>>)
+    where
+        H: BuildHasher + Sync + 'a;
+
+    /// Finalize every task within the schedule, in order.
+    fn finalize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a;
+
+    /// Run the schedule on the given world.
+    ///
+    /// Every task is initialized, then run, and finally finalized. If a `recorder` is provided,
+    /// the time taken by each task is recorded in it.
+    fn run<H>(&'a mut self, world: &mut World<R, Resources, H>, recorder: Option<&Recorder<'_>>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.initialize(world);
+        self.run_tasks(world, recorder);
+        self.finalize(world);
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+impl<'a, R, Resources> Sealed<'a, R, Resources, task::Null> for task::Null
+where
+    R: Registry,
+    Resources: resource::Resources,
+{
+    fn initialize<H>(&mut self, _world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+    }
+
+    fn run_tasks<H>(
+        &mut self,
+        _world: &mut World<R, Resources, H>,
+        _recorder: Option<&Recorder<'_>>,
+    ) where
+        H: BuildHasher + Sync + 'a,
+    {
+    }
+
+    fn finalize<H>(&mut self, _world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+impl<
+        'a,
+        R,
+        Resources,
+        T,
+        U,
+        QueryIndices,
+        ResourceViewsIndices,
+        DisjointIndices,
+        EntryIndices,
+        Indices,
+    >
+    Sealed<
+        'a,
+        R,
+        Resources,
+        (
+            (
+                QueryIndices,
+                ResourceViewsIndices,
+                DisjointIndices,
+                EntryIndices,
+            ),
+            Indices,
+        ),
+    > for (T, U)
+where
+    R: Registry,
+    Resources: resource::Resources,
+    T: Task<'a, R, Resources, QueryIndices, ResourceViewsIndices, DisjointIndices, EntryIndices>,
+    U: Sealed<'a, R, Resources, Indices>,
+{
+    fn initialize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.0.initialize(world);
+        self.1.initialize(world);
+    }
+
+    fn run_tasks<H>(&mut self, world: &mut World<R, Resources, H>, recorder: Option<&Recorder<'_>>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        // Each task is run sequentially, in the order it was provided.
+        let start = recorder.map(Recorder::now);
+        self.0.run(
+            // SAFETY: The pointer provided here is unique, being created from a mutable reference.
+            // Also, the `SendableWorld` does not outlive `recorder`.
+            unsafe { SendableWorld::new(world, recorder) },
+        );
+        if let (Some(recorder), Some(start)) = (recorder, start) {
+            recorder.record_stage(start);
+        }
+        self.1.run_tasks(world, recorder);
+    }
+
+    fn finalize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.0.finalize(world);
+        self.1.finalize(world);
+    }
+}
@@ -1,31 +1,66 @@
 use crate::{
     registry::Registry,
+    system::schedule::instrument::Recorder,
     world::World,
 };
+use core::ptr;
 
-pub struct SendableWorld<R, Resources>(*mut World<R, Resources>)
+pub struct SendableWorld<R, Resources, H>(*mut World<R, Resources, H>, *const Recorder<'static>)
 where
     R: Registry;
 
-impl<R, Resources> SendableWorld<R, Resources>
+impl<R, Resources, H> SendableWorld<R, Resources, H>
 where
     R: Registry,
 {
+    /// Tasks run on the returned `SendableWorld` are timed using `recorder`, if one is provided.
+    ///
     /// # Safety
     /// The `world` pointer passed here must be exclusively.
-    pub(crate) unsafe fn new(world: *mut World<R, Resources>) -> Self {
-        Self(world)
+    ///
+    /// The returned `SendableWorld`, along with any copies of it, must not outlive `recorder`.
+    pub(crate) unsafe fn new(
+        world: *mut World<R, Resources, H>,
+        recorder: Option<&Recorder<'_>>,
+    ) -> Self {
+        Self(
+            world,
+            recorder.map_or(ptr::null(), |recorder| {
+                (recorder as *const Recorder<'_>).cast::<Recorder<'static>>()
+            }),
+        )
     }
 
     /// # Safety
     /// The pointer returned here must only be used for access to components that follow Rust's
     /// borrowing rules.
-    pub(crate) unsafe fn get(self) -> *mut World<R, Resources> {
+    pub(crate) unsafe fn get(self) -> *mut World<R, Resources, H> {
         self.0
     }
+
+    /// Returns the recorder that tasks run on this world are timed with, if there is one.
+    pub(crate) fn recorder(&self) -> Option<&Recorder<'_>> {
+        // SAFETY: The recorder is guaranteed by the safety contract of `new()` to
+        // outlive `self`.
+        unsafe { self.1.as_ref() }
+    }
+
+    /// Run `task`, recording its duration under `name` if this world has a recorder.
+    pub(crate) fn run_task<F>(self, name: &'static str, task: F)
+    where
+        F: FnOnce(Self),
+    {
+        if let Some(recorder) = self.recorder() {
+            let start = recorder.now();
+            task(self);
+            recorder.record_task(name, start);
+        } else {
+            task(self);
+        }
+    }
 }
 
-impl<R, Resources> Clone for SendableWorld<R, Resources>
+impl<R, Resources, H> Clone for SendableWorld<R, Resources, H>
 where
     R: Registry,
 {
@@ -34,13 +69,25 @@ where
     }
 }
 
-impl<R, Resources> Copy for SendableWorld<R, Resources> where R: Registry {}
+impl<R, Resources, H> Copy for SendableWorld<R, Resources, H> where R: Registry {}
 
 // SAFETY: This type can be safely sent between threads as long as the safety contracts of its
-// methods are upheld, because the data accessed will be accessed uniquely.
-unsafe impl<R, Resources> Send for SendableWorld<R, Resources> where R: Registry {}
+// methods are upheld, because the data accessed will be accessed uniquely. The recorder is only
+// accessed through shared references, and is safe to share between threads.
+unsafe impl<R, Resources, H> Send for SendableWorld<R, Resources, H>
+where
+    R: Registry,
+    H: Sync,
+{
+}
 
 // SAFETY: This type can be safely shared between threads as long as the safety contracts of its
 // methods are upheld, because the data accessed will be accessed uniquely, including mutable
-// reference access.
-unsafe impl<R, Resources> Sync for SendableWorld<R, Resources> where R: Registry {}
+// reference access. The recorder is only accessed through shared references, and is safe to share
+// between threads.
+unsafe impl<R, Resources, H> Sync for SendableWorld<R, Resources, H>
+where
+    R: Registry,
+    H: Sync,
+{
+}
@@ -19,7 +19,9 @@ use crate::{
         sendable::SendableWorld,
         Task,
     },
+    World,
 };
+use core::hash::BuildHasher;
 use fnv::FnvBuildHasher;
 use hashbrown::{
     hash_map,
@@ -62,15 +64,17 @@ pub trait Stage<
         NextDisjointIndicesList,
         NextEntryIndicesList,
         NextEntryViewsFilterIndicesList,
+        H,
     >(
         &mut self,
-        world: SendableWorld<R, Resources>,
+        world: SendableWorld<R, Resources, H>,
         borrowed_archetypes: HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
         resource_claims: Resources::Claims,
         has_run: Self::HasRun,
         next_stage: &mut N,
     ) -> N::HasRun
     where
+        H: BuildHasher + Sync + 'a + 'b,
         N: Stages<
             'b,
             R,
@@ -92,19 +96,31 @@ pub trait Stage<
     /// # Safety
     /// `borrowed_archetypes` must accurately represent the dynamic claims already made on the
     /// component columns within `world`.
-    unsafe fn run_add_ons(
+    unsafe fn run_add_ons<H>(
         &mut self,
-        world: SendableWorld<R, Resources>,
+        world: SendableWorld<R, Resources, H>,
         borrowed_archetypes: HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
         resource_claims: Resources::Claims,
-    ) -> Self::HasRun;
+    ) -> Self::HasRun
+    where
+        H: BuildHasher + Sync + 'a;
 
     /// Creates a new default set of booleans to indicate that each task within the stage has not
     /// been run.
     fn new_has_run() -> Self::HasRun;
+
+    /// Initialize each task within this stage.
+    fn initialize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a;
+
+    /// Finalize each task within this stage.
+    fn finalize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a;
 }
 
-impl<R, Resources> Stage<'_, R, Resources, Null, Null, Null, Null, Null> for Null
+impl<'a, R, Resources> Stage<'a, R, Resources, Null, Null, Null, Null, Null> for Null
 where
     R: Registry,
     Resources: resource::Resources,
@@ -119,15 +135,17 @@ where
         NextDisjointIndicesList,
         NextEntryIndicesList,
         NextEntryViewsFilterIndicesList,
+        H,
     >(
         &mut self,
-        world: SendableWorld<R, Resources>,
+        world: SendableWorld<R, Resources, H>,
         borrowed_archetypes: HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
         resource_claims: Resources::Claims,
         _has_run: Self::HasRun,
         next_stage: &mut N,
     ) -> N::HasRun
     where
+        H: BuildHasher + Sync + 'a + 'b,
         N: Stages<
             'b,
             R,
@@ -151,18 +169,33 @@ where
         }
     }
 
-    unsafe fn run_add_ons(
+    unsafe fn run_add_ons<H>(
         &mut self,
-        _world: SendableWorld<R, Resources>,
+        _world: SendableWorld<R, Resources, H>,
         _borrowed_archetypes: HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
         _resource_claims: Resources::Claims,
-    ) -> Self::HasRun {
+    ) -> Self::HasRun
+    where
+        H: BuildHasher + Sync + 'a,
+    {
         Null
     }
 
     fn new_has_run() -> Self::HasRun {
         Null
     }
+
+    fn initialize<H>(&mut self, _world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+    }
+
+    fn finalize<H>(&mut self, _world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+    }
 }
 
 fn query_archetype_identifiers<
@@ -175,11 +208,13 @@ fn query_archetype_identifiers<
     DisjointIndices,
     EntryIndices,
     EntryViewsFilterIndices,
+    H,
 >(
-    world: SendableWorld<R, Resources>,
+    world: SendableWorld<R, Resources, H>,
     borrowed_archetypes: &mut HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
 ) -> bool
 where
+    H: BuildHasher + Sync + 'a,
     R: ContainsFilter<
             Or<And<T::Views, T::Filter>, T::EntryViewsFilter>,
             Or<And<R::ViewsFilterIndices, R::FilterIndices>, EntryViewsFilterIndices>,
@@ -226,10 +261,12 @@ fn query_archetype_identifiers_unchecked<
     DisjointIndices,
     EntryIndices,
     EntryViewsFilterIndices,
+    H,
 >(
-    world: SendableWorld<R, Resources>,
+    world: SendableWorld<R, Resources, H>,
     borrowed_archetypes: &mut HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
 ) where
+    H: BuildHasher + Sync + 'a,
     R: ContainsFilter<
             Or<And<T::Views, T::Filter>, T::EntryViewsFilter>,
             Or<And<R::ViewsFilterIndices, R::FilterIndices>, EntryViewsFilterIndices>,
@@ -309,15 +346,17 @@ where
         NextDisjointIndicesList,
         NextEntryIndices,
         NextEntryViewsFilterIndicesList,
+        H,
     >(
         &mut self,
-        world: SendableWorld<R, Resources>,
+        world: SendableWorld<R, Resources, H>,
         mut borrowed_archetypes: HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
         resource_claims: Resources::Claims,
         has_run: Self::HasRun,
         next_stage: &mut N,
     ) -> N::HasRun
     where
+        H: BuildHasher + Sync + 'a + 'b,
         N: Stages<
             'b,
             R,
@@ -354,6 +393,7 @@ where
                         DisjointIndices,
                         EntryIndices,
                         EntryViewsFilterIndices,
+                        H,
                     >(world, &mut borrowed_archetypes);
 
                     let resource_claims =
@@ -376,12 +416,15 @@ where
         }
     }
 
-    unsafe fn run_add_ons(
+    unsafe fn run_add_ons<H>(
         &mut self,
-        world: SendableWorld<R, Resources>,
+        world: SendableWorld<R, Resources, H>,
         mut borrowed_archetypes: HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
         resource_claims: Resources::Claims,
-    ) -> Self::HasRun {
+    ) -> Self::HasRun
+    where
+        H: BuildHasher + Sync + 'a,
+    {
         if let Some(resource_claims) = Resources::claims().try_merge(&resource_claims) {
             if query_archetype_identifiers::<
                 R,
@@ -392,6 +435,7 @@ where
                 DisjointIndices,
                 EntryIndices,
                 EntryViewsFilterIndices,
+                H,
             >(world, &mut borrowed_archetypes)
             {
                 rayon::join(
@@ -436,4 +480,20 @@ where
     fn new_has_run() -> Self::HasRun {
         (false, U::new_has_run())
     }
+
+    fn initialize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.0.initialize(world);
+        self.1.initialize(world);
+    }
+
+    fn finalize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.0.finalize(world);
+        self.1.finalize(world);
+    }
 }
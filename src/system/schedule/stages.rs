@@ -4,11 +4,13 @@ use crate::{
     registry::Registry,
     resource,
     system::schedule::{
+        instrument::Recorder,
         sendable::SendableWorld,
         Stage,
     },
     World,
 };
+use core::hash::BuildHasher;
 use fnv::FnvBuildHasher;
 use hashbrown::HashMap;
 
@@ -41,7 +43,15 @@ pub trait Stages<
     /// `World` are tracked when scheduling a single stage. Then, any tasks within the next stage
     /// whose borrowed components do not interfere with the tasks in the current stage's dynamic
     /// claims are run as well.
-    fn run(&mut self, world: &mut World<R, Resources>, has_run: Self::HasRun);
+    ///
+    /// If a `recorder` is provided, the time taken by each task and stage is recorded in it.
+    fn run<H>(
+        &mut self,
+        world: &mut World<R, Resources, H>,
+        recorder: Option<&Recorder<'_>>,
+        has_run: Self::HasRun,
+    ) where
+        H: BuildHasher + Sync + 'a;
 
     /// Attempt to run as many tasks within the first stage in the list as possible as add-ons to
     /// the previous stage.
@@ -53,39 +63,78 @@ pub trait Stages<
     /// # Safety
     /// `borrowed_archetypes` must accurately represent the dynamic claims already made on the
     /// component columns within `world`.
-    unsafe fn run_add_ons(
+    unsafe fn run_add_ons<H>(
         &mut self,
-        world: SendableWorld<R, Resources>,
+        world: SendableWorld<R, Resources, H>,
         borrowed_archetypes: HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
         resource_claims: Resources::Claims,
-    ) -> Self::HasRun;
+    ) -> Self::HasRun
+    where
+        H: BuildHasher + Sync + 'a;
 
     /// Creates a new default set of booleans to indicate that each task within the first stage has
     /// not been run.
     fn new_has_run() -> Self::HasRun;
+
+    /// Initialize every task within each of the stages.
+    ///
+    /// This is run serially, before any of the stages are run.
+    fn initialize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a;
+
+    /// Finalize every task within each of the stages.
+    ///
+    /// This is run serially, after all of the stages are run.
+    fn finalize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a;
 }
 
-impl<R, Resources> Stages<'_, R, Resources, Null, Null, Null, Null, Null> for Null
+impl<'a, R, Resources> Stages<'a, R, Resources, Null, Null, Null, Null, Null> for Null
 where
     R: Registry,
     Resources: resource::Resources,
 {
     type HasRun = Null;
 
-    fn run(&mut self, _world: &mut World<R, Resources>, _has_run: Self::HasRun) {}
+    fn run<H>(
+        &mut self,
+        _world: &mut World<R, Resources, H>,
+        _recorder: Option<&Recorder<'_>>,
+        _has_run: Self::HasRun,
+    ) where
+        H: BuildHasher + Sync + 'a,
+    {
+    }
 
-    unsafe fn run_add_ons(
+    unsafe fn run_add_ons<H>(
         &mut self,
-        _world: SendableWorld<R, Resources>,
+        _world: SendableWorld<R, Resources, H>,
         _borrowed_archetypes: HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
         _resource_claims: Resources::Claims,
-    ) -> Self::HasRun {
+    ) -> Self::HasRun
+    where
+        H: BuildHasher + Sync + 'a,
+    {
         Null
     }
 
     fn new_has_run() -> Self::HasRun {
         Null
     }
+
+    fn initialize<H>(&mut self, _world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+    }
+
+    fn finalize<H>(&mut self, _world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+    }
 }
 
 impl<
@@ -141,25 +190,40 @@ where
 {
     type HasRun = T::HasRun;
 
-    fn run(&mut self, world: &mut World<R, Resources>, has_run: Self::HasRun) {
+    fn run<H>(
+        &mut self,
+        world: &mut World<R, Resources, H>,
+        recorder: Option<&Recorder<'_>>,
+        has_run: Self::HasRun,
+    ) where
+        H: BuildHasher + Sync + 'a,
+    {
         // Each stage is run sequentially. The tasks within a stage are parallelized.
+        let start = recorder.map(Recorder::now);
         let next_has_run = self.0.run(
             // SAFETY: The pointer provided here is unique, being created from a mutable reference.
-            unsafe { SendableWorld::new(world) },
+            // Also, the `SendableWorld` does not outlive `recorder`.
+            unsafe { SendableWorld::new(world, recorder) },
             HashMap::default(),
             Resources::Claims::default(),
             has_run,
             &mut self.1,
         );
-        self.1.run(world, next_has_run);
+        if let (Some(recorder), Some(start)) = (recorder, start) {
+            recorder.record_stage(start);
+        }
+        self.1.run(world, recorder, next_has_run);
     }
 
-    unsafe fn run_add_ons(
+    unsafe fn run_add_ons<H>(
         &mut self,
-        world: SendableWorld<R, Resources>,
+        world: SendableWorld<R, Resources, H>,
         borrowed_archetypes: HashMap<archetype::IdentifierRef<R>, R::Claims, FnvBuildHasher>,
         resource_claims: Resources::Claims,
-    ) -> Self::HasRun {
+    ) -> Self::HasRun
+    where
+        H: BuildHasher + Sync + 'a,
+    {
         // SAFETY: The safety contract of this method call is upheld by the safety contract of this
         // method.
         unsafe {
@@ -171,4 +235,20 @@ where
     fn new_has_run() -> Self::HasRun {
         T::new_has_run()
     }
+
+    fn initialize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.0.initialize(world);
+        self.1.initialize(world);
+    }
+
+    fn finalize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.0.finalize(world);
+        self.1.finalize(world);
+    }
 }
@@ -14,4 +14,6 @@ define_null!();
 pub struct System<System>(pub System);
 
 /// A task that implements [`ParSystem`].
+#[cfg(feature = "rayon")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
 pub struct ParSystem<ParSystem>(pub ParSystem);
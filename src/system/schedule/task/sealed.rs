@@ -1,9 +1,10 @@
 //! Common interface for tasks.
 
-use super::{
-    ParSystem,
-    System,
-};
+#[cfg(feature = "rayon")]
+use super::ParSystem;
+use super::System;
+#[cfg(feature = "rayon")]
+use crate::registry::ContainsParQuery;
 use crate::{
     query::{
         view,
@@ -15,13 +16,17 @@ use crate::{
     },
     registry,
     registry::{
-        ContainsParQuery,
         ContainsQuery,
         Registry,
     },
     resource::ContainsViews,
     system,
     system::schedule::sendable::SendableWorld,
+    World,
+};
+use core::{
+    any::type_name,
+    hash::BuildHasher,
 };
 
 /// A task that can be run in a schedule.
@@ -41,7 +46,17 @@ where
     type EntryViewsFilter;
 
     /// Executes the task over the given world.
-    fn run(&mut self, world: SendableWorld<R, Resources>);
+    fn run<H>(&mut self, world: SendableWorld<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a;
+
+    fn initialize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a;
+
+    fn finalize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a;
 }
 
 impl<'a, R, Resources, S, QueryIndices, ResourceViewsIndices, DisjointIndices, EntryIndices>
@@ -63,16 +78,36 @@ where
     type EntryViews = S::EntryViews<'a>;
     type EntryViewsFilter = <S::EntryViews<'a> as ViewsSealed<'a>>::EntryFilter;
 
-    fn run(&mut self, world: SendableWorld<R, Resources>) {
-        // Query world using system.
-        let result =
-            // SAFETY: The access to the world's components follows Rust's borrowing rules.
-            unsafe { (*world.get()).query(Query::<S::Views<'a>, S::Filter, S::ResourceViews<'a>, S::EntryViews<'a>>::new()) };
-        // Run system using the query result.
-        self.0.run(result);
+    fn run<H>(&mut self, world: SendableWorld<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        world.run_task(type_name::<S>(), |world| {
+            // Query world using system.
+            let result =
+                // SAFETY: The access to the world's components follows Rust's borrowing rules.
+                unsafe { (*world.get()).query(Query::<S::Views<'a>, S::Filter, S::ResourceViews<'a>, S::EntryViews<'a>>::new()) };
+            // Run system using the query result.
+            self.0.run(result);
+        });
+    }
+
+    fn initialize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.0.initialize(world);
+    }
+
+    fn finalize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.0.finalize(world);
     }
 }
 
+#[cfg(feature = "rayon")]
 impl<'a, P, R, Resources, QueryIndices, ResourceViewsIndices, DisjointIndices, EntryIndices>
     Task<'a, R, Resources, QueryIndices, ResourceViewsIndices, DisjointIndices, EntryIndices>
     for ParSystem<P>
@@ -92,12 +127,31 @@ where
     type EntryViews = P::EntryViews<'a>;
     type EntryViewsFilter = <P::EntryViews<'a> as ViewsSealed<'a>>::EntryFilter;
 
-    fn run(&mut self, world: SendableWorld<R, Resources>) {
-        // Query world using system.
-        let result =
-            // SAFETY: The access to the world's components follows Rust's borrowing rules.
-            unsafe { (*world.get()).par_query(Query::<P::Views<'a>, P::Filter, P::ResourceViews<'a>, P::EntryViews<'a>>::new()) };
-        // Run system using the query result.
-        self.0.run(result);
+    fn run<H>(&mut self, world: SendableWorld<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        world.run_task(type_name::<P>(), |world| {
+            // Query world using system.
+            let result =
+                // SAFETY: The access to the world's components follows Rust's borrowing rules.
+                unsafe { (*world.get()).par_query(Query::<P::Views<'a>, P::Filter, P::ResourceViews<'a>, P::EntryViews<'a>>::new()) };
+            // Run system using the query result.
+            self.0.run(result);
+        });
+    }
+
+    fn initialize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.0.initialize(world);
+    }
+
+    fn finalize<H>(&mut self, world: &mut World<R, Resources, H>)
+    where
+        H: BuildHasher + Sync + 'a,
+    {
+        self.0.finalize(world);
     }
 }
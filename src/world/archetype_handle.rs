@@ -0,0 +1,80 @@
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    marker::PhantomData,
+};
+
+/// A cached lookup of the archetype storing entities of type `Entity`.
+///
+/// Every call to [`World::insert()`] looks up the archetype storing the inserted entity's
+/// components. When inserting many entities of the same type one at a time, an `ArchetypeHandle`
+/// obtained once through [`World::archetype_handle()`] allows [`World::insert_into()`] to find the
+/// archetype directly, skipping most of that lookup.
+///
+/// A handle never becomes invalid. If the archetype it refers to is removed, such as by
+/// [`World::shrink_to_fit()`], `insert_into()` falls back to a regular lookup, recreating the
+/// archetype, after which the handle is able to find it directly again. A handle can also be used
+/// with `World`s other than the one it was obtained from, although it will only skip the lookup
+/// if the `World`s use identically-seeded hashers.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     Entity,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+///
+/// let mut world = World::<Registry!(Foo, Bar)>::new();
+///
+/// let handle = world.archetype_handle::<Entity!(Foo, Bar), _>();
+/// for i in 0..10 {
+///     world.insert_into(&handle, entity!(Foo(i), Bar(i % 2 == 0)));
+/// }
+///
+/// assert_eq!(world.len(), 10);
+/// ```
+///
+/// [`World::archetype_handle()`]: crate::World::archetype_handle()
+/// [`World::insert()`]: crate::World::insert()
+/// [`World::insert_into()`]: crate::World::insert_into()
+/// [`World::shrink_to_fit()`]: crate::World::shrink_to_fit()
+pub struct ArchetypeHandle<Registry, Entity> {
+    /// The bytes of the archetype's identifier.
+    pub(crate) identifier: Vec<u8>,
+    /// The hash of the archetype's identifier, using the hasher of the `World` the handle was
+    /// obtained from.
+    pub(crate) hash: u64,
+
+    entity: PhantomData<fn() -> (Registry, Entity)>,
+}
+
+impl<Registry, Entity> ArchetypeHandle<Registry, Entity> {
+    pub(crate) fn new(identifier: Vec<u8>, hash: u64) -> Self {
+        Self {
+            identifier,
+            hash,
+
+            entity: PhantomData,
+        }
+    }
+}
+
+impl<Registry, Entity> Clone for ArchetypeHandle<Registry, Entity> {
+    fn clone(&self) -> Self {
+        Self::new(self.identifier.clone(), self.hash)
+    }
+}
+
+impl<Registry, Entity> fmt::Debug for ArchetypeHandle<Registry, Entity> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArchetypeHandle")
+            .field("identifier", &self.identifier)
+            .field("hash", &self.hash)
+            .finish()
+    }
+}
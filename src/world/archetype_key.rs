@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+
+/// An owned key identifying an archetype.
+///
+/// Entities with the same set of components are stored together in an archetype. An
+/// `ArchetypeKey` identifies an archetype by that set of components, making it suitable as a key
+/// for caches that store data per archetype, such as in reflection or serialization tooling.
+///
+/// Unlike a [`view::ArchetypeId`], an `ArchetypeKey` does not borrow anything from the `World`,
+/// and remains the same for as long as the set of components it identifies does, even if the
+/// archetype is removed and later recreated. Keys from `World`s with the same [`Registry`] can
+/// be compared with each other.
+///
+/// An `ArchetypeKey` can be obtained from an [`Entry`], from an [`ArchetypeView`], or from a
+/// `view::ArchetypeId` using [`World::archetype_key()`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     Registry,
+///     World,
+/// };
+/// use std::collections::HashMap;
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+///
+/// let mut world = World::<Registry!(Foo, Bar)>::new();
+/// let first = world.insert(entity!(Foo(1)));
+/// let second = world.insert(entity!(Foo(2)));
+/// let third = world.insert(entity!(Foo(3), Bar(true)));
+///
+/// let mut counts = HashMap::new();
+/// for entity_identifier in [first, second, third] {
+///     let key = world.entry(entity_identifier).unwrap().archetype_key();
+///     *counts.entry(key).or_insert(0) += 1;
+/// }
+///
+/// assert_eq!(counts.len(), 2);
+/// ```
+///
+/// [`ArchetypeView`]: crate::world::ArchetypeView
+/// [`Entry`]: crate::world::Entry
+/// [`Registry`]: trait@crate::registry::Registry
+/// [`view::ArchetypeId`]: crate::query::view::ArchetypeId
+/// [`World::archetype_key()`]: crate::world::World::archetype_key()
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ArchetypeKey(Vec<u8>);
+
+impl ArchetypeKey {
+    pub(crate) fn new(identifier: Vec<u8>) -> Self {
+        Self(identifier)
+    }
+
+    /// Returns the bytes identifying the archetype.
+    ///
+    /// The bytes are a bit set over the components of the [`Registry`], in the order the
+    /// components were defined, with the least significant bit of the first byte corresponding to
+    /// the first component. These are the same bytes returned by
+    /// [`World::archetype_identifier()`].
+    ///
+    /// [`Registry`]: trait@crate::registry::Registry
+    /// [`World::archetype_identifier()`]: crate::world::World::archetype_identifier()
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
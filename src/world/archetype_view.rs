@@ -0,0 +1,126 @@
+use crate::{
+    archetype::Archetype,
+    component,
+    entity,
+    registry,
+    registry::ContainsComponent,
+    world::ArchetypeKey,
+};
+
+/// A view into a single archetype of a [`World`].
+///
+/// An archetype stores every entity made up of exactly the same set of components. Each of its
+/// components is stored in its own contiguous column, with the values at a given index within each
+/// column belonging to the same entity. This allows direct, typed access to the columns of
+/// components, for algorithms not covered by the built-in query iterators.
+///
+/// This `struct` is yielded by the [`MatchingArchetypes`] iterator.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::filter,
+///     Registry,
+///     World,
+/// };
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Position(f32);
+/// struct Velocity(f32);
+///
+/// type Registry = Registry!(Position, Velocity);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Position(0.0), Velocity(1.0)));
+///
+/// for mut archetype in world.matching_archetypes::<filter::Has<Position>, _>() {
+///     for position in archetype.column_mut::<Position, _>().unwrap() {
+///         position.0 += 1.0;
+///     }
+/// }
+/// ```
+///
+/// [`MatchingArchetypes`]: crate::world::MatchingArchetypes
+/// [`World`]: crate::World
+pub struct ArchetypeView<'a, Registry>
+where
+    Registry: registry::Registry,
+{
+    archetype: &'a mut Archetype<Registry>,
+}
+
+impl<'a, Registry> ArchetypeView<'a, Registry>
+where
+    Registry: registry::Registry,
+{
+    pub(crate) fn new(archetype: &'a mut Archetype<Registry>) -> Self {
+        Self { archetype }
+    }
+
+    /// Returns the number of entities stored in the archetype.
+    ///
+    /// This is the length of each of the archetype's columns.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.archetype.len()
+    }
+
+    /// Returns `true` if the archetype stores no entities.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.archetype.is_empty()
+    }
+
+    /// Returns the [`ArchetypeKey`] identifying the archetype.
+    #[must_use]
+    pub fn key(&self) -> ArchetypeKey {
+        ArchetypeKey::new(
+            // SAFETY: The identifier does not outlive the archetype, as it is only used to copy
+            // its bytes.
+            unsafe { self.archetype.identifier() }.as_vec(),
+        )
+    }
+
+    /// Returns the identifiers of the entities stored in the archetype.
+    ///
+    /// The identifiers are in the same order as the components in each column.
+    #[must_use]
+    pub fn entity_identifiers(&self) -> &[entity::Identifier] {
+        self.archetype.entity_identifiers()
+    }
+
+    /// Returns `true` if the archetype's entities contain a `Component`.
+    #[must_use]
+    pub fn contains<Component, Index>(&self) -> bool
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.archetype.contains_component::<Component, Index>()
+    }
+
+    /// Returns the column of `Component`s stored in the archetype.
+    ///
+    /// Returns `None` if the archetype's entities do not contain a `Component`.
+    #[must_use]
+    pub fn column<Component, Index>(&self) -> Option<&[Component]>
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.archetype.column::<Component, Index>()
+    }
+
+    /// Returns the column of `Component`s stored in the archetype mutably.
+    ///
+    /// Returns `None` if the archetype's entities do not contain a `Component`.
+    #[must_use]
+    pub fn column_mut<Component, Index>(&mut self) -> Option<&mut [Component]>
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.archetype.column_mut::<Component, Index>()
+    }
+}
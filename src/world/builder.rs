@@ -0,0 +1,158 @@
+use crate::{
+    registry,
+    resource,
+    resource::Append,
+    World,
+};
+use core::{
+    hash::BuildHasher,
+    marker::PhantomData,
+};
+use fnv::FnvBuildHasher;
+
+/// Constructs a [`World`] by adding its resources one at a time.
+///
+/// This is created by [`World::builder()`]. Each call to [`resource()`] appends a resource to the
+/// end of the resource list, changing the type of the builder. The resources end up in the same
+/// order they would be in if they were all passed to [`resources!`] at once. This allows a
+/// `World`'s resources to be assembled step by step, with arbitrary logic between each step.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     Registry,
+///     Resources,
+///     World,
+/// };
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+///
+/// let debug = true;
+///
+/// let builder = World::<Registry!()>::builder().resource(Foo(42));
+/// let bar = if debug { Bar(true) } else { Bar(false) };
+/// let world: World<Registry!(), Resources!(Foo, Bar)> = builder.resource(bar).build();
+///
+/// assert_eq!(world.get::<Foo, _>().0, 42);
+/// ```
+///
+/// [`resource()`]: Builder::resource()
+/// [`resources!`]: crate::resources!
+/// [`World`]: crate::World
+/// [`World::builder()`]: crate::World::builder()
+#[must_use = "a `Builder` does nothing unless `build()` is called"]
+pub struct Builder<Registry, Resources = resource::Null, S = FnvBuildHasher>
+where
+    Registry: registry::Registry,
+{
+    resources: Resources,
+    hasher: S,
+
+    registry: PhantomData<Registry>,
+}
+
+impl<Registry> Builder<Registry>
+where
+    Registry: registry::Registry,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            resources: resource::Null,
+            hasher: FnvBuildHasher::default(),
+
+            registry: PhantomData,
+        }
+    }
+}
+
+impl<Registry, Resources, S> Builder<Registry, Resources, S>
+where
+    Registry: registry::Registry,
+{
+    /// Add a resource to the `World` being built.
+    ///
+    /// The resource is appended after all previously added resources.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let world = World::<Registry!()>::builder().resource(Foo(42)).build();
+    ///
+    /// assert_eq!(world.get::<Foo, _>().0, 42);
+    /// ```
+    pub fn resource<Resource>(self, resource: Resource) -> Builder<Registry, Resources::Output, S>
+    where
+        Resources: Append<Resource>,
+    {
+        Builder {
+            resources: self.resources.append(resource),
+            hasher: self.hasher,
+
+            registry: PhantomData,
+        }
+    }
+
+    /// Use the given hash builder to look up archetypes in the `World` being built.
+    ///
+    /// See [`World::with_hasher()`] for details on when a different hasher may be beneficial.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let world = World::<Registry!()>::builder()
+    ///     .hasher(RandomState::new())
+    ///     .resource(Foo(42))
+    ///     .build();
+    /// ```
+    ///
+    /// [`World::with_hasher()`]: crate::World::with_hasher()
+    pub fn hasher<T>(self, hash_builder: T) -> Builder<Registry, Resources, T> {
+        Builder {
+            resources: self.resources,
+            hasher: hash_builder,
+
+            registry: PhantomData,
+        }
+    }
+
+    /// Creates the `World`, containing the added resources.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let world = World::<Registry!()>::builder()
+    ///     .resource(Foo(42))
+    ///     .resource(Bar(true))
+    ///     .build();
+    ///
+    /// assert!(world.get::<Bar, _>().0);
+    /// ```
+    #[must_use]
+    pub fn build(self) -> World<Registry, Resources, S>
+    where
+        S: BuildHasher + Clone,
+    {
+        World::with_resources_and_hasher(self.resources, self.hasher)
+    }
+}
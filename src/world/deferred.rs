@@ -0,0 +1,132 @@
+use crate::{
+    archetype,
+    entity,
+    registry,
+    world::Modifier,
+};
+use alloc::vec::Vec;
+
+/// Structural changes to entities, recorded to be applied to a [`World`] later.
+///
+/// Components can't be added to or removed from entities while iterating over a query, since doing
+/// so would move entities that are currently being viewed. Instead, the changes can be recorded
+/// through the [`add()`] and [`remove()`] methods on the [`Entry`]s provided by [`Entries`]. Once
+/// iteration is complete, the recorded changes are obtained using [`Entries::into_deferred()`] and
+/// applied using [`World::apply_deferred()`].
+///
+/// If a `Deferred` is dropped without being applied, the recorded changes are discarded.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         filter,
+///         result,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+///
+/// type Registry = Registry!(Foo, Bar);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Foo(42)));
+/// world.insert(entity!(Foo(100)));
+///
+/// let query_result = world.query(Query::<Views!(&mut Foo, entity::Identifier)>::new());
+/// let mut entries = query_result.entries;
+/// for result!(foo, entity_identifier) in query_result.iter {
+///     foo.0 += 1;
+///     if foo.0 > 50 {
+///         entries.entry(entity_identifier).unwrap().add(Bar(true));
+///     }
+/// }
+/// let deferred = entries.into_deferred();
+/// world.apply_deferred(deferred);
+///
+/// assert_eq!(
+///     world
+///         .query(Query::<Views!(&Foo), filter::Has<Bar>>::new())
+///         .iter
+///         .count(),
+///     1
+/// );
+/// ```
+///
+/// [`add()`]: crate::query::entries::Entry::add()
+/// [`Entries`]: crate::query::Entries
+/// [`Entries::into_deferred()`]: crate::query::Entries::into_deferred()
+/// [`Entry`]: crate::query::entries::Entry
+/// [`remove()`]: crate::query::entries::Entry::remove()
+/// [`World`]: crate::World
+/// [`World::apply_deferred()`]: crate::World::apply_deferred()
+#[must_use = "deferred changes are discarded unless applied using `World::apply_deferred()`"]
+pub struct Deferred<Registry>
+where
+    Registry: registry::Registry,
+{
+    /// The changes recorded for each entity, in the order they were recorded.
+    pub(crate) modifiers: Vec<(entity::Identifier, Modifier<Registry>)>,
+}
+
+impl<Registry> Deferred<Registry>
+where
+    Registry: registry::Registry,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Returns the [`Modifier`] recording changes to the entity identified by `entity_identifier`.
+    ///
+    /// Consecutive changes to the same entity are recorded in the same `Modifier`, allowing them
+    /// to be applied with a single move. `identifier` identifies the entity's current components.
+    pub(crate) fn modifier(
+        &mut self,
+        entity_identifier: entity::Identifier,
+        identifier: archetype::IdentifierRef<Registry>,
+    ) -> &mut Modifier<Registry> {
+        if self.modifiers.last().map_or(true, |(last_identifier, _)| {
+            *last_identifier != entity_identifier
+        }) {
+            self.modifiers
+                .push((entity_identifier, Modifier::new(identifier.as_vec())));
+        }
+        // SAFETY: A `Modifier` was pushed above if `self.modifiers` was empty.
+        &mut unsafe { self.modifiers.last_mut().unwrap_unchecked() }.1
+    }
+
+    /// Returns `true` if no changes have been recorded.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     query::Views,
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    ///
+    /// let deferred = world
+    ///     .query(Query::<Views!(&Foo)>::new())
+    ///     .entries
+    ///     .into_deferred();
+    /// assert!(deferred.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.modifiers.is_empty()
+    }
+}
@@ -0,0 +1,268 @@
+use super::{
+    ComponentChange,
+    WorldDiff,
+};
+use crate::registry;
+use core::{
+    fmt,
+    marker::PhantomData,
+};
+use serde::{
+    de::{
+        self,
+        EnumAccess,
+        MapAccess,
+        SeqAccess,
+        VariantAccess,
+        Visitor,
+    },
+    ser::SerializeStruct,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+impl<Component> Serialize for ComponentChange<Component>
+where
+    Component: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Unchanged => serializer.serialize_unit_variant("ComponentChange", 0, "Unchanged"),
+            Self::Added(component) => {
+                serializer.serialize_newtype_variant("ComponentChange", 1, "Added", component)
+            }
+            Self::Changed(component) => {
+                serializer.serialize_newtype_variant("ComponentChange", 2, "Changed", component)
+            }
+            Self::Removed => serializer.serialize_unit_variant("ComponentChange", 3, "Removed"),
+        }
+    }
+}
+
+impl<'de, Component> Deserialize<'de> for ComponentChange<Component>
+where
+    Component: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        enum Variant {
+            Unchanged,
+            Added,
+            Changed,
+            Removed,
+        }
+
+        impl<'de> Deserialize<'de> for Variant {
+            fn deserialize<D>(deserializer: D) -> Result<Variant, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct VariantVisitor;
+
+                impl<'de> Visitor<'de> for VariantVisitor {
+                    type Value = Variant;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str("`Unchanged`, `Added`, `Changed`, or `Removed`")
+                    }
+
+                    fn visit_u64<E>(self, value: u64) -> Result<Variant, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            0 => Ok(Variant::Unchanged),
+                            1 => Ok(Variant::Added),
+                            2 => Ok(Variant::Changed),
+                            3 => Ok(Variant::Removed),
+                            _ => Err(de::Error::invalid_value(
+                                de::Unexpected::Unsigned(value),
+                                &"variant index 0 <= i < 4",
+                            )),
+                        }
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<Variant, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            "Unchanged" => Ok(Variant::Unchanged),
+                            "Added" => Ok(Variant::Added),
+                            "Changed" => Ok(Variant::Changed),
+                            "Removed" => Ok(Variant::Removed),
+                            _ => Err(de::Error::unknown_variant(value, VARIANTS)),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(VariantVisitor)
+            }
+        }
+
+        struct ComponentChangeVisitor<Component>(PhantomData<Component>);
+
+        impl<'de, Component> Visitor<'de> for ComponentChangeVisitor<Component>
+        where
+            Component: Deserialize<'de>,
+        {
+            type Value = ComponentChange<Component>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ComponentChange")
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant, access) = data.variant()?;
+                match variant {
+                    Variant::Unchanged => {
+                        access.unit_variant()?;
+                        Ok(ComponentChange::Unchanged)
+                    }
+                    Variant::Added => access.newtype_variant().map(ComponentChange::Added),
+                    Variant::Changed => access.newtype_variant().map(ComponentChange::Changed),
+                    Variant::Removed => {
+                        access.unit_variant()?;
+                        Ok(ComponentChange::Removed)
+                    }
+                }
+            }
+        }
+
+        const VARIANTS: &[&str] = &["Unchanged", "Added", "Changed", "Removed"];
+        deserializer.deserialize_enum(
+            "ComponentChange",
+            VARIANTS,
+            ComponentChangeVisitor(PhantomData),
+        )
+    }
+}
+
+impl<Registry> Serialize for WorldDiff<Registry>
+where
+    Registry: registry::Diff,
+    Registry::Changes: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("WorldDiff", 2)?;
+        state.serialize_field("removed", &self.removed)?;
+        state.serialize_field("changed", &self.changed)?;
+        state.end()
+    }
+}
+
+impl<'de, Registry> Deserialize<'de> for WorldDiff<Registry>
+where
+    Registry: registry::Diff,
+    Registry::Changes: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        enum Field {
+            Removed,
+            Changed,
+        }
+
+        impl<'de> Deserialize<'de> for Field {
+            fn deserialize<D>(deserializer: D) -> Result<Field, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct FieldVisitor;
+
+                impl<'de> Visitor<'de> for FieldVisitor {
+                    type Value = Field;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str("`removed` or `changed`")
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<Field, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            "removed" => Ok(Field::Removed),
+                            "changed" => Ok(Field::Changed),
+                            _ => Err(de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(FieldVisitor)
+            }
+        }
+
+        struct WorldDiffVisitor<Registry>(PhantomData<Registry>);
+
+        impl<'de, Registry> Visitor<'de> for WorldDiffVisitor<Registry>
+        where
+            Registry: registry::Diff,
+            Registry::Changes: Deserialize<'de>,
+        {
+            type Value = WorldDiff<Registry>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct WorldDiff")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let removed = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let changed = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                Ok(WorldDiff { removed, changed })
+            }
+
+            fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+            where
+                V: MapAccess<'de>,
+            {
+                let mut removed = None;
+                let mut changed = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Removed => {
+                            if removed.is_some() {
+                                return Err(de::Error::duplicate_field("removed"));
+                            }
+                            removed = Some(map.next_value()?);
+                        }
+                        Field::Changed => {
+                            if changed.is_some() {
+                                return Err(de::Error::duplicate_field("changed"));
+                            }
+                            changed = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let removed = removed.ok_or_else(|| de::Error::missing_field("removed"))?;
+                let changed = changed.ok_or_else(|| de::Error::missing_field("changed"))?;
+                Ok(WorldDiff { removed, changed })
+            }
+        }
+
+        const FIELDS: &[&str] = &["removed", "changed"];
+        deserializer.deserialize_struct("WorldDiff", FIELDS, WorldDiffVisitor(PhantomData))
+    }
+}
@@ -0,0 +1,220 @@
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+mod impl_serde;
+
+use crate::{
+    component,
+    entity,
+    registry,
+    registry::ContainsComponent,
+};
+use alloc::vec::Vec;
+
+/// The change made to a single component of an entity, as recorded in a [`WorldDiff`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ComponentChange<Component> {
+    /// The component is unchanged, or the entity does not contain it in either `World`.
+    Unchanged,
+    /// The component was added to the entity, with the given value.
+    Added(Component),
+    /// The component's value was changed to the given value.
+    Changed(Component),
+    /// The component was removed from the entity.
+    Removed,
+}
+
+/// The changes between two versions of a [`World`].
+///
+/// This is created by [`World::diff()`], recording which entities were removed and how the
+/// components of every other entity changed. Applying it to a `World` equal to the previous version
+/// using [`World::apply_diff()`] brings that `World` up to date. When the `serde` feature is
+/// enabled, a `WorldDiff` can be serialized, allowing it to be sent in place of the entire
+/// `World`.
+///
+/// Only entities and their components are recorded. Resources are not.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     world::ComponentChange,
+///     Registry,
+///     World,
+/// };
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Position(u32);
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Health(u32);
+///
+/// type Registry = Registry!(Position, Health);
+///
+/// let mut world = World::<Registry>::new();
+/// let entity_identifier = world.insert(entity!(Position(0), Health(100)));
+/// let previous = world.snapshot();
+///
+/// world
+///     .entry(entity_identifier)
+///     .unwrap()
+///     .remove::<Health, _>();
+///
+/// let diff = world.diff(&previous);
+/// assert_eq!(
+///     diff.get::<Health, _>(entity_identifier),
+///     Some(&ComponentChange::Removed)
+/// );
+/// ```
+///
+/// [`World`]: crate::World
+/// [`World::apply_diff()`]: crate::World::apply_diff()
+/// [`World::diff()`]: crate::World::diff()
+pub struct WorldDiff<Registry>
+where
+    Registry: registry::Diff,
+{
+    /// The identifiers of the removed entities.
+    pub(crate) removed: Vec<entity::Identifier>,
+    /// The changes to each added or modified entity.
+    pub(crate) changed: Vec<(entity::Identifier, Registry::Changes)>,
+}
+
+impl<Registry> WorldDiff<Registry>
+where
+    Registry: registry::Diff,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            removed: Vec::new(),
+            changed: Vec::new(),
+        }
+    }
+
+    /// Returns the identifiers of the entities that were removed.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone, PartialEq)]
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42)));
+    /// let previous = world.snapshot();
+    ///
+    /// world.remove(entity_identifier);
+    ///
+    /// assert_eq!(world.diff(&previous).removed(), &[entity_identifier]);
+    /// ```
+    #[must_use]
+    pub fn removed(&self) -> &[entity::Identifier] {
+        &self.removed
+    }
+
+    /// Returns an iterator over the identifiers of the entities that were added or whose
+    /// components changed.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone, PartialEq)]
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// world.insert(entity!(Foo(42)));
+    /// let previous = world.snapshot();
+    ///
+    /// let entity_identifier = world.insert(entity!(Foo(100)));
+    ///
+    /// assert_eq!(
+    ///     world.diff(&previous).changed().collect::<Vec<_>>(),
+    ///     vec![entity_identifier]
+    /// );
+    /// ```
+    pub fn changed(&self) -> impl Iterator<Item = entity::Identifier> + '_ {
+        self.changed
+            .iter()
+            .map(|(entity_identifier, _)| *entity_identifier)
+    }
+
+    /// Returns the change to the entity's `Component`.
+    ///
+    /// Returns `None` if the entity was neither added nor changed.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     world::ComponentChange,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Foo(u32);
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    /// let previous = world.snapshot();
+    ///
+    /// world.entry(entity_identifier).unwrap().add(Foo(100));
+    ///
+    /// let diff = world.diff(&previous);
+    /// assert_eq!(
+    ///     diff.get::<Foo, _>(entity_identifier),
+    ///     Some(&ComponentChange::Changed(Foo(100)))
+    /// );
+    /// assert_eq!(
+    ///     diff.get::<Bar, _>(entity_identifier),
+    ///     Some(&ComponentChange::Unchanged)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn get<Component, Index>(
+        &self,
+        entity_identifier: entity::Identifier,
+    ) -> Option<&ComponentChange<Component>>
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.changed
+            .iter()
+            .find(|(identifier, _)| *identifier == entity_identifier)
+            .and_then(|(_, changes)| Registry::change(changes))
+    }
+
+    /// Returns `true` if no entities were removed, added, or changed.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone, PartialEq)]
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// world.insert(entity!(Foo(42)));
+    ///
+    /// assert!(world.diff(&world.snapshot()).is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.changed.is_empty()
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{
+    archetype,
+    archetype::Archetype,
+    archetypes,
+    archetypes::Archetypes,
+    entity,
+    registry,
+    registry::{
+        contains::drain::Sealed as ContainsDrainSealed,
+        ContainsDrain,
+    },
+    world,
+};
+use core::{
+    hash::BuildHasher,
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+use fnv::FnvBuildHasher;
+
+/// A draining [`Iterator`] over the entities of a [`World`].
+///
+/// Each entity containing all of the components of `Entity` is moved out of the `World` and
+/// yielded as an `Entity`, with any of its other components being dropped. Entities that do not
+/// contain all of the components of `Entity` are removed and dropped without being yielded. The
+/// entities are not yielded in any specified order.
+///
+/// When this iterator is dropped, all remaining entities are removed from the `World`, even if the
+/// iterator was not fully consumed.
+///
+/// Callbacks registered through [`World::on_remove()`] are called for the components of every
+/// entity removed, whether it is yielded or dropped.
+///
+/// This `struct` is created by the [`drain`] method on [`World`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     Entity,
+///     Registry,
+///     World,
+/// };
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Foo(u32);
+/// #[derive(Debug, PartialEq)]
+/// struct Bar(bool);
+///
+/// type Registry = Registry!(Foo, Bar);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Foo(42), Bar(true)));
+///
+/// let entities = world.drain::<Entity!(Bar, Foo), _>().collect::<Vec<_>>();
+///
+/// assert_eq!(entities, vec![entity!(Bar(true), Foo(42))]);
+/// assert!(world.is_empty());
+/// ```
+///
+/// [`drain`]: crate::World::drain()
+/// [`World`]: crate::World
+/// [`World::on_remove()`]: crate::World::on_remove()
+pub struct Drain<'a, Registry, Entity, Indices, S = FnvBuildHasher>
+where
+    Registry: registry::Registry,
+    S: BuildHasher,
+{
+    /// The archetypes iterated by `archetypes_iter`, used to remove empty archetypes once every
+    /// entity has been removed.
+    archetypes: *mut Archetypes<Registry, S>,
+    archetypes_iter: archetypes::IterMut<'a, Registry>,
+    current_archetype: Option<&'a mut Archetype<Registry>>,
+    entity_allocator: &'a mut entity::Allocator<Registry>,
+    len: &'a mut usize,
+    on_remove: &'a mut world::Callbacks,
+    auto_compact: Option<f32>,
+
+    /// Identifies the components contained in `Entity`.
+    identifier: archetype::Identifier<Registry>,
+
+    entity: PhantomData<Entity>,
+    indices: PhantomData<Indices>,
+}
+
+impl<'a, Registry, Entity, Indices, S> Drain<'a, Registry, Entity, Indices, S>
+where
+    Registry: ContainsDrain<Entity, Indices>,
+    S: BuildHasher,
+{
+    pub(crate) fn new(
+        archetypes: &'a mut Archetypes<Registry, S>,
+        entity_allocator: &'a mut entity::Allocator<Registry>,
+        len: &'a mut usize,
+        on_remove: &'a mut world::Callbacks,
+        auto_compact: Option<f32>,
+    ) -> Self {
+        let archetypes = archetypes as *mut Archetypes<Registry, S>;
+        Self {
+            archetypes,
+            // SAFETY: `archetypes` is borrowed mutably for `'a`, and is only otherwise accessed
+            // once this iterator is no longer used.
+            archetypes_iter: unsafe { &mut *archetypes }.iter_mut(),
+            current_archetype: None,
+            entity_allocator,
+            len,
+            on_remove,
+            auto_compact,
+
+            identifier: <Registry as registry::Canonical<
+                <Registry as ContainsDrainSealed<Entity, Indices>>::Canonical,
+                <Registry as ContainsDrainSealed<Entity, Indices>>::CanonicalContainments,
+            >>::create_archetype_identifier(),
+
+            entity: PhantomData,
+            indices: PhantomData,
+        }
+    }
+}
+
+impl<'a, Registry, Entity, Indices, S> Drain<'a, Registry, Entity, Indices, S>
+where
+    Registry: registry::Registry,
+    S: BuildHasher,
+{
+    /// Returns whether `archetype` contains all of the components identified by
+    /// `self.identifier`.
+    fn contains_components(&self, archetype: &Archetype<Registry>) -> bool {
+        // SAFETY: Neither of the slices created here outlive the identifiers they are created
+        // from.
+        let (archetype_bytes, bytes) = unsafe {
+            (
+                archetype.identifier().as_slice(),
+                self.identifier.as_slice(),
+            )
+        };
+        archetype_bytes
+            .iter()
+            .zip(bytes)
+            .all(|(archetype_byte, byte)| archetype_byte & byte == *byte)
+    }
+
+    /// Removes all entities from `archetype`.
+    fn clear_archetype(&mut self, archetype: &mut Archetype<Registry>) {
+        // SAFETY: The rows `0..archetype.len()` are all within the bounds of the archetype.
+        unsafe {
+            archetype.notify_rows(0..archetype.len(), self.on_remove);
+        }
+        *self.len -= archetype.len();
+        // SAFETY: `self.entity_allocator` contains entries for the entities stored in the
+        // `World`'s archetypes.
+        unsafe {
+            archetype.clear(self.entity_allocator);
+        }
+    }
+}
+
+impl<'a, Registry, Entity, Indices, S> Iterator for Drain<'a, Registry, Entity, Indices, S>
+where
+    Registry: ContainsDrain<Entity, Indices>,
+    S: BuildHasher,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(archetype) = &mut self.current_archetype {
+                if let Some(last) = archetype.len().checked_sub(1) {
+                    // SAFETY: `last` is the index of the last row of the archetype.
+                    unsafe {
+                        archetype.notify_rows(last..archetype.len(), self.on_remove);
+                    }
+                }
+                // SAFETY: `self.entity_allocator` contains entries for the entities stored in the
+                // `World`'s archetypes. Additionally, `current_archetype` is only set to
+                // archetypes containing all of the components in `Entity`.
+                if let Some(entity) = unsafe {
+                    archetype.pop_last::<
+                        <Registry as ContainsDrainSealed<Entity, Indices>>::Canonical,
+                        <Registry as ContainsDrainSealed<Entity, Indices>>::CanonicalContainments,
+                    >(self.entity_allocator)
+                } {
+                    *self.len -= 1;
+                    return Some(Registry::reshape(entity));
+                }
+            }
+            let archetype = self.archetypes_iter.next()?;
+            if self.contains_components(archetype) {
+                self.current_archetype = Some(archetype);
+            } else {
+                self.clear_archetype(archetype);
+                self.current_archetype = None;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(*self.len))
+    }
+}
+
+impl<'a, Registry, Entity, Indices, S> FusedIterator for Drain<'a, Registry, Entity, Indices, S>
+where
+    Registry: ContainsDrain<Entity, Indices>,
+    S: BuildHasher,
+{
+}
+
+impl<'a, Registry, Entity, Indices, S> Drop for Drain<'a, Registry, Entity, Indices, S>
+where
+    Registry: registry::Registry,
+    S: BuildHasher,
+{
+    fn drop(&mut self) {
+        if let Some(archetype) = self.current_archetype.take() {
+            self.clear_archetype(archetype);
+        }
+        while let Some(archetype) = self.archetypes_iter.next() {
+            self.clear_archetype(archetype);
+        }
+
+        if let Some(max_empty_ratio) = self.auto_compact {
+            // SAFETY: `archetypes_iter` has been exhausted and no archetype is referenced by
+            // `current_archetype`, so no other references into `archetypes` are used after this.
+            unsafe { &mut *self.archetypes }.compact(max_empty_ratio);
+        }
+    }
+}
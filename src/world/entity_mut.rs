@@ -0,0 +1,269 @@
+use crate::{
+    component,
+    entity,
+    registry,
+    registry::ContainsComponent,
+    world::Entry,
+};
+use core::{
+    fmt,
+    hash::BuildHasher,
+};
+use fnv::FnvBuildHasher;
+
+/// A handle to a single entity in a [`World`], supporting chained modification.
+///
+/// This is a thin wrapper around an [`Entry`]. Where the methods of `Entry` modify the entity in
+/// place, the modifying methods of `EntityMut` take the handle by value and return it, allowing
+/// calls to be chained. This is useful for setting up an entity over multiple statements.
+///
+/// This struct is constructed by the [`spawn`] and [`entity_mut`] methods on `World`.
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     Registry,
+///     World,
+/// };
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Foo(u32);
+/// struct Bar(bool);
+/// struct Baz(f64);
+///
+/// type Registry = Registry!(Foo, Bar, Baz);
+///
+/// let mut world = World::<Registry>::new();
+///
+/// let entity_identifier = world
+///     .spawn(entity!(Foo(42)))
+///     .add(Bar(true))
+///     .add(Baz(1.5))
+///     .remove::<Bar, _>()
+///     .identifier();
+///
+/// let entity = world.entity_mut(entity_identifier).unwrap();
+/// assert_eq!(entity.get::<Foo, _>(), Some(&Foo(42)));
+/// assert!(!entity.contains::<Bar, _>());
+/// ```
+///
+/// [`entity_mut`]: crate::World::entity_mut()
+/// [`Entry`]: crate::world::Entry
+/// [`spawn`]: crate::World::spawn()
+/// [`World`]: crate::World
+pub struct EntityMut<'a, Registry, Resources, S = FnvBuildHasher>
+where
+    Registry: registry::Registry,
+{
+    entry: Entry<'a, Registry, Resources, S>,
+}
+
+impl<'a, Registry, Resources, S> EntityMut<'a, Registry, Resources, S>
+where
+    Registry: registry::Registry,
+    S: BuildHasher,
+{
+    pub(crate) fn new(entry: Entry<'a, Registry, Resources, S>) -> Self {
+        Self { entry }
+    }
+
+    /// Returns the [`entity::Identifier`] of the entity.
+    ///
+    /// The identifier can be used to access the entity again after the handle is dropped.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// let entity_identifier = world.spawn(entity!(Foo(42))).identifier();
+    ///
+    /// assert!(world.contains(entity_identifier));
+    /// ```
+    ///
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    #[must_use]
+    pub fn identifier(&self) -> entity::Identifier {
+        self.entry.entity_identifier()
+    }
+
+    /// Returns `true` if the entity contains a `Component`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// let entity = world.spawn(entity!(Foo(42)));
+    ///
+    /// assert!(entity.contains::<Foo, _>());
+    /// assert!(!entity.contains::<Bar, _>());
+    /// ```
+    #[must_use]
+    pub fn contains<Component, Index>(&self) -> bool
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.entry.component::<Component, Index>().is_some()
+    }
+
+    /// Returns a reference to the entity's `Component`, if it contains one.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// let entity = world.spawn(entity!(Foo(42)));
+    ///
+    /// assert_eq!(entity.get::<Foo, _>(), Some(&Foo(42)));
+    /// assert!(entity.get::<Bar, _>().is_none());
+    /// ```
+    #[must_use]
+    pub fn get<Component, Index>(&self) -> Option<&Component>
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.entry.component::<Component, Index>()
+    }
+
+    /// Returns a mutable reference to the entity's `Component`, if it contains one.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// let mut entity = world.spawn(entity!(Foo(42)));
+    ///
+    /// entity.get_mut::<Foo, _>().unwrap().0 = 100;
+    /// assert_eq!(entity.get::<Foo, _>(), Some(&Foo(100)));
+    /// ```
+    #[must_use]
+    pub fn get_mut<Component, Index>(&mut self) -> Option<&mut Component>
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.entry.component_mut::<Component, Index>()
+    }
+
+    /// Add a component to the entity, returning the handle.
+    ///
+    /// If the component already exists, it is updated to the new value. See [`Entry::add()`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    /// struct Baz(f64);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar, Baz)>::new();
+    /// let entity = world.spawn(entity!(Foo(42))).add(Bar(true)).add(Baz(1.5));
+    ///
+    /// assert!(entity.contains::<Bar, _>());
+    /// assert!(entity.contains::<Baz, _>());
+    /// ```
+    ///
+    /// [`Entry::add()`]: crate::world::Entry::add()
+    #[allow(clippy::should_implement_trait)] // Named to match `Entry::add()`.
+    #[must_use]
+    pub fn add<Component, Index>(mut self, component: Component) -> Self
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.entry.add::<Component, Index>(component);
+        self
+    }
+
+    /// Remove a component from the entity, returning the handle.
+    ///
+    /// If the component is not present within the entity, nothing happens. See
+    /// [`Entry::remove()`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// let entity = world.spawn(entity!(Foo(42), Bar(true))).remove::<Foo, _>();
+    ///
+    /// assert!(!entity.contains::<Foo, _>());
+    /// ```
+    ///
+    /// [`Entry::remove()`]: crate::world::Entry::remove()
+    #[must_use]
+    pub fn remove<Component, Index>(mut self) -> Self
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.entry.remove::<Component, Index>();
+        self
+    }
+
+    /// Converts the handle into the [`Entry`] it wraps.
+    ///
+    /// [`Entry`]: crate::world::Entry
+    #[must_use]
+    pub fn into_entry(self) -> Entry<'a, Registry, Resources, S> {
+        self.entry
+    }
+}
+
+impl<'a, Registry, Resources, S> fmt::Debug for EntityMut<'a, Registry, Resources, S>
+where
+    Registry: registry::Registry,
+    Entry<'a, Registry, Resources, S>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EntityMut")
+            .field("entry", &self.entry)
+            .finish()
+    }
+}
@@ -1,9 +1,11 @@
 use crate::{
     archetype,
     component,
+    entity,
     entity::allocator::Location,
     hlist::Reshape,
     query::{
+        filter,
         filter::And,
         view,
         Query,
@@ -15,9 +17,24 @@ use crate::{
         ContainsQuery,
     },
     resource,
-    world::World,
+    world::{
+        ArchetypeKey,
+        Modifier,
+        World,
+    },
+};
+use alloc::{
+    vec,
+    vec::Vec,
 };
-use core::fmt;
+use core::{
+    any::TypeId,
+    fmt,
+    hash::BuildHasher,
+    mem,
+    ptr,
+};
+use fnv::FnvBuildHasher;
 
 /// A view into a single entity in a [`World`].
 ///
@@ -47,23 +64,30 @@ use core::fmt;
 /// [`entity::Identifier`]: crate::entity::Identifier
 /// [`entry`]: crate::World::entry()
 /// [`World`]: crate::World
-pub struct Entry<'a, Registry, Resources>
+pub struct Entry<'a, Registry, Resources, S = FnvBuildHasher>
 where
     Registry: registry::Registry,
 {
-    world: &'a mut World<Registry, Resources>,
+    world: &'a mut World<Registry, Resources, S>,
+    entity_identifier: entity::Identifier,
     location: Location<Registry>,
 }
 
-impl<'a, Registry, Resources> Entry<'a, Registry, Resources>
+impl<'a, Registry, Resources, S> Entry<'a, Registry, Resources, S>
 where
     Registry: registry::Registry,
+    S: BuildHasher,
 {
     pub(crate) fn new(
-        world: &'a mut World<Registry, Resources>,
+        world: &'a mut World<Registry, Resources, S>,
+        entity_identifier: entity::Identifier,
         location: Location<Registry>,
     ) -> Self {
-        Self { world, location }
+        Self {
+            world,
+            entity_identifier,
+            location,
+        }
     }
 
     /// Add a component to the entity.
@@ -115,20 +139,26 @@ where
             }
         } else {
             // The component needs to be added to the entity.
+            // SAFETY: An archetype with this identifier is guaranteed to exist, since there is an
+            // allocated location for it in the entity allocator.
+            let archetype = unsafe {
+                self.world
+                    .archetypes
+                    .get_unchecked_mut(self.location.identifier)
+            };
             let (entity_identifier, current_component_bytes) =
-                // SAFETY: An archetype with this identifier is guaranteed to exist, since there is an
-                // allocated location for it in the entity allocator.
-                //
-                // `self.world.entity_allocator` contains entries for entities stored in
+                // SAFETY: `self.world.entity_allocator` contains entries for entities stored in
                 // `self.world.archetypes`. As such, `self.location.index` is guaranteed to be a
                 // valid index to a row within this archetype, since they share the same archetype
                 // identifier.
                 unsafe {
-                self.world
-                    .archetypes
-                    .get_unchecked_mut(self.location.identifier)
-                    .pop_row_unchecked(self.location.index, &mut self.world.entity_allocator)
+                archetype.pop_row_unchecked(
+                    self.location.index,
+                    &mut self.world.entity_allocator,
+                    &mut self.world.observers.moves,
+                )
             };
+            let archetype_emptied = archetype.is_empty();
             // Create new identifier buffer.
             let mut raw_identifier_buffer = self.location.identifier.as_vec();
             // Set the component's bit.
@@ -161,6 +191,14 @@ where
                     component,
                 )
             };
+            // SAFETY: `index` is the row the entity was just pushed to, and `Component` is
+            // contained in the archetype, since its bit was set in the archetype's identifier.
+            unsafe {
+                archetype.notify_component_unchecked::<Component, Index>(
+                    index,
+                    &mut self.world.observers.on_add,
+                );
+            }
 
             // Update the location.
             // SAFETY: The archetype is guaranteed to outlive the location, as archetype is stored
@@ -175,6 +213,71 @@ where
                     .modify_location_unchecked(entity_identifier, location);
             }
             self.location = location;
+
+            if archetype_emptied {
+                self.world.auto_compact();
+            }
+        }
+    }
+
+    /// Replace a component of the entity, returning the old value.
+    ///
+    /// If the component already exists, it is swapped with the new value without moving the
+    /// entity, and the old value is returned. Otherwise, the component is added to the entity as
+    /// with [`add()`], and `None` is returned.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    /// #[derive(Debug, PartialEq)]
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42)));
+    /// let mut entry = world.entry(entity_identifier).unwrap();
+    ///
+    /// assert_eq!(entry.replace(Foo(100)), Some(Foo(42)));
+    /// assert_eq!(entry.replace(Bar(true)), None);
+    /// ```
+    ///
+    /// [`add()`]: Entry::add()
+    pub fn replace<Component, Index>(&mut self, component: Component) -> Option<Component>
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        let component_index = Registry::LEN - Registry::INDEX - 1;
+        if
+        // SAFETY: The `component_index` obtained from `R::LEN - R::INDEX - 1` is guaranteed to be
+        // a valid index into `self.location.identifier`, since an identifier has `R::LEN` bits.
+        unsafe { self.location.identifier.get_unchecked(component_index) } {
+            // SAFETY: An archetype with this identifier is guaranteed to exist, since there is an
+            // allocated location for it in the entity allocator.
+            //
+            // `C` is verified by the above if-statement to be contained within the identified
+            // archetype. Also, `self.location.index` is invariantly guaranteed to be a valid index
+            // within the archetype.
+            let component_ptr = unsafe {
+                self.world
+                    .archetypes
+                    .get_unchecked_mut(self.location.identifier)
+                    .component_ptr_unchecked::<Component, Index>(self.location.index)
+            };
+            // SAFETY: `component_ptr` points to a valid, initialized `Component` that is uniquely
+            // borrowed through `self`.
+            Some(mem::replace(unsafe { &mut *component_ptr }, component))
+        } else {
+            self.add(component);
+            None
         }
     }
 
@@ -212,20 +315,39 @@ where
         // a valid index into `self.location.identifier`, since an identifier has `R::LEN` bits.
         unsafe { self.location.identifier.get_unchecked(component_index) } {
             // The component exists and needs to be removed.
+            // SAFETY: An archetype with this identifier is guaranteed to exist, since there is an
+            // allocated location for it in the entity allocator. `self.location.index` is
+            // invariantly guaranteed to be a valid index within the archetype, and `Component` is
+            // verified by the above if-statement to be contained within the archetype.
+            unsafe {
+                self.world
+                    .archetypes
+                    .get_unchecked_mut(self.location.identifier)
+                    .notify_component_unchecked::<Component, Index>(
+                        self.location.index,
+                        &mut self.world.observers.on_remove,
+                    );
+            }
+            // SAFETY: An archetype with this identifier is guaranteed to exist, since there is an
+            // allocated location for it in the entity allocator.
+            let archetype = unsafe {
+                self.world
+                    .archetypes
+                    .get_unchecked_mut(self.location.identifier)
+            };
             let (entity_identifier, current_component_bytes) =
-                // SAFETY: An archetype with this identifier is guaranteed to exist, since there is an
-                // allocated location for it in the entity allocator.
-                //
-                // `self.world.entity_allocator` contains entries for entities stored in
+                // SAFETY: `self.world.entity_allocator` contains entries for entities stored in
                 // `self.world.archetypes`. As such, `self.location.index` is guaranteed to be a
                 // valid index to a row within this archetype, since they share the same archetype
                 // identifier.
                 unsafe {
-                self.world
-                    .archetypes
-                    .get_unchecked_mut(self.location.identifier)
-                    .pop_row_unchecked(self.location.index, &mut self.world.entity_allocator)
+                archetype.pop_row_unchecked(
+                    self.location.index,
+                    &mut self.world.entity_allocator,
+                    &mut self.world.observers.moves,
+                )
             };
+            let archetype_emptied = archetype.is_empty();
             // Create new identifier buffer.
             let mut raw_identifier_buffer = self.location.identifier.as_vec();
             // Unset the component's bit.
@@ -272,6 +394,237 @@ where
                     .modify_location_unchecked(entity_identifier, location);
             }
             self.location = location;
+
+            if archetype_emptied {
+                self.world.auto_compact();
+            }
+        }
+    }
+
+    /// Add and remove multiple components at once.
+    ///
+    /// The provided closure records the changes to be made using the given [`Modifier`]. Once the
+    /// closure returns, the entity is moved directly to the storage for its final set of
+    /// components. This avoids the cost of moving the entity once for every component added or
+    /// removed, as would happen when calling [`add()`] and [`remove()`] separately.
+    ///
+    /// Observers are notified based on the net change to the entity's components. A component
+    /// that is removed and then added again, for example, is treated as being replaced.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    /// struct Baz(f64);
+    ///
+    /// type Registry = Registry!(Foo, Bar, Baz);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    /// let mut entry = world.entry(entity_identifier).unwrap();
+    ///
+    /// entry.modify(|modifier| {
+    ///     modifier.add(Foo(100));
+    ///     modifier.remove::<Bar, _>();
+    ///     modifier.add(Baz(1.5));
+    /// });
+    ///
+    /// let result!(foo, baz) = entry.query(Query::<Views!(&Foo, &Baz)>::new()).unwrap();
+    /// assert_eq!(foo.0, 100);
+    /// assert_eq!(baz.0, 1.5);
+    /// ```
+    ///
+    /// [`add()`]: Entry::add()
+    /// [`Modifier`]: crate::world::Modifier
+    /// [`remove()`]: Entry::remove()
+    pub fn modify<Function>(&mut self, function: Function)
+    where
+        Function: FnOnce(&mut Modifier<Registry>),
+    {
+        let mut modifier = Modifier::new(self.location.identifier.as_vec());
+        function(&mut modifier);
+        self.apply(modifier);
+    }
+
+    /// Applies the changes recorded in `modifier`, which may have been recorded when the entity
+    /// had a different set of components.
+    pub(crate) fn apply_deferred(&mut self, mut modifier: Modifier<Registry>) {
+        modifier.rebase(self.location.identifier.as_vec());
+        self.apply(modifier);
+    }
+
+    /// Applies the changes recorded in `modifier` to the entity.
+    ///
+    /// The identifier of `modifier` must describe the entity's components after the changes are
+    /// applied.
+    #[allow(clippy::too_many_lines)]
+    fn apply(&mut self, mut modifier: Modifier<Registry>) {
+        // SAFETY: The slice created here does not outlive the identifier.
+        let current_identifier = unsafe { self.location.identifier.as_slice() };
+        if modifier.identifier == current_identifier {
+            // The entity's set of components is unchanged, so any added components replace the
+            // existing components in place.
+            let archetype =
+                // SAFETY: An archetype with this identifier is guaranteed to exist, since there is
+                // an allocated location for it in the entity allocator.
+                unsafe { self.world.archetypes.get_unchecked_mut(self.location.identifier) };
+            for addition in modifier.additions.drain(..) {
+                // SAFETY: The added component is contained in the archetype, since its bit is set
+                // in the unchanged identifier. `self.location.index` is invariantly guaranteed to
+                // be a valid index within the archetype. The component stored in `addition.bytes`
+                // is read exactly once, since the addition is discarded afterward.
+                unsafe { (addition.set)(archetype, self.location.index, addition.bytes.as_ptr()) };
+            }
+            return;
+        }
+
+        let archetype =
+            // SAFETY: An archetype with this identifier is guaranteed to exist, since there is an
+            // allocated location for it in the entity allocator.
+            unsafe { self.world.archetypes.get_unchecked_mut(self.location.identifier) };
+        for removal in &modifier.removals {
+            if
+            // SAFETY: `removal.component_index` was obtained from the registry, and is therefore
+            // a valid index into `self.location.identifier`.
+            unsafe {
+                self.location
+                    .identifier
+                    .get_unchecked(removal.component_index)
+            } && !modifier.contains_index(removal.component_index)
+            {
+                // SAFETY: The removed component is contained in the archetype, as verified above.
+                // `self.location.index` is invariantly guaranteed to be a valid index within the
+                // archetype.
+                unsafe {
+                    (removal.notify)(
+                        archetype,
+                        self.location.index,
+                        &mut self.world.observers.on_remove,
+                    );
+                }
+            }
+        }
+
+        // Pack the added components into a single buffer, in the registry's order.
+        modifier
+            .additions
+            .sort_unstable_by_key(|addition| addition.component_index);
+        let mut raw_replacement_identifier = vec![0; modifier.identifier.len()];
+        let mut replacement_bytes = Vec::<u8>::with_capacity(
+            modifier
+                .additions
+                .iter()
+                .map(|addition| addition.bytes.len())
+                .sum(),
+        );
+        let mut added_notifies = Vec::new();
+        for addition in modifier.additions.drain(..) {
+            // SAFETY: `addition.component_index` was obtained from the registry, and is therefore
+            // a valid index to a bit in `raw_replacement_identifier`.
+            *unsafe {
+                raw_replacement_identifier.get_unchecked_mut(addition.component_index / 8)
+            } |= 1 << (addition.component_index % 8);
+            // SAFETY: `replacement_bytes` was allocated with enough capacity for the bytes of
+            // every addition. The component is moved out of `addition.bytes`, which is discarded
+            // afterward without dropping the component.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    addition.bytes.as_ptr(),
+                    replacement_bytes.as_mut_ptr().add(replacement_bytes.len()),
+                    addition.bytes.len(),
+                );
+                replacement_bytes.set_len(replacement_bytes.len() + addition.bytes.len());
+            }
+            if
+            // SAFETY: `addition.component_index` was obtained from the registry, and is therefore
+            // a valid index into `self.location.identifier`.
+            !unsafe {
+                self.location
+                    .identifier
+                    .get_unchecked(addition.component_index)
+            } {
+                added_notifies.push(addition.notify);
+            }
+        }
+        let replacement_identifier =
+            // SAFETY: `raw_replacement_identifier` is the same length as a valid identifier, which
+            // is `(R::LEN + 7) / 8`.
+            unsafe { archetype::Identifier::<Registry>::new(raw_replacement_identifier) };
+
+        let (entity_identifier, current_component_bytes) =
+            // SAFETY: `self.world.entity_allocator` contains entries for entities stored in
+            // `self.world.archetypes`. As such, `self.location.index` is guaranteed to be a valid
+            // index to a row within this archetype, since they share the same archetype
+            // identifier.
+            unsafe {
+                archetype.pop_row_unchecked(
+                    self.location.index,
+                    &mut self.world.entity_allocator,
+                    &mut self.world.observers.moves,
+                )
+            };
+        let archetype_emptied = archetype.is_empty();
+        let identifier_buffer =
+            // SAFETY: Since the modifier's identifier was obtained from a valid identifier, it is
+            // of the proper length (which is `(R::LEN + 7) / 8`).
+            unsafe { archetype::Identifier::<Registry>::new(mem::take(&mut modifier.identifier)) };
+
+        // Insert to the corresponding archetype using the bytes and the added components.
+        let archetype = self
+            .world
+            .archetypes
+            .get_mut_or_insert_new(identifier_buffer);
+        let index =
+            // SAFETY: `current_component_bytes` is guaranteed to be an allocated buffer of packed,
+            // properly initialized components that were contained in the old archetype's row,
+            // corresponding to the components identified by `self.location.identifier`.
+            // `replacement_bytes` is a buffer of packed, properly initialized components
+            // corresponding to the components identified by `replacement_identifier`, each of
+            // which is identified by the new archetype's identifier. Every other component in the
+            // new archetype's identifier was already contained in the entity, since bits are only
+            // set in the modifier's identifier when a component is added.
+            unsafe {
+                archetype.push_from_buffers(
+                    entity_identifier,
+                    current_component_bytes.as_ptr(),
+                    self.location.identifier.iter(),
+                    replacement_bytes.as_ptr(),
+                    replacement_identifier.iter(),
+                )
+            };
+        for notify in added_notifies {
+            // SAFETY: `index` is the row the entity was just pushed to, and the added component is
+            // contained in the archetype, since its bit was set in the archetype's identifier.
+            unsafe { notify(archetype, index, &mut self.world.observers.on_add) };
+        }
+
+        // Update the location.
+        // SAFETY: The archetype is guaranteed to outlive the location, as archetype is stored in
+        // the same world where the location is stored. Additionally, the location stored in this
+        // entry will be outlived by archetype due to its lifetime guarantees.
+        let location = Location::new(unsafe { archetype.identifier() }, index);
+        // SAFETY: `entity_identifier` is guaranteed at creation of this `Entry` to be contained in
+        // `self.world.entity_allocator`.
+        unsafe {
+            self.world
+                .entity_allocator
+                .modify_location_unchecked(entity_identifier, location);
+        }
+        self.location = location;
+
+        if archetype_emptied {
+            self.world.auto_compact();
         }
     }
 
@@ -353,12 +706,172 @@ where
             None
         }
     }
+
+    /// Returns mutable references to the entity's `A` and `B` components at the same time.
+    ///
+    /// This is a shorthand for querying the entry with `Views!(&mut A, &mut B)`. Returns `None` if
+    /// the entity does not contain both components. `A` and `B` must be distinct components, as
+    /// borrowing the same component mutably twice will fail to compile:
+    ///
+    /// ``` compile_fail
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42)));
+    /// let mut entry = world.entry(entity_identifier).unwrap();
+    ///
+    /// // This does not compile, because `Foo` is borrowed mutably twice.
+    /// entry.get_disjoint_mut::<Foo, Foo, _>();
+    /// ```
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Position(f32);
+    /// struct Velocity(f32);
+    ///
+    /// type Registry = Registry!(Position, Velocity);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Position(1.0), Velocity(2.0)));
+    /// let mut entry = world.entry(entity_identifier).unwrap();
+    ///
+    /// let (position, velocity) = entry.get_disjoint_mut::<Position, Velocity, _>().unwrap();
+    /// position.0 += velocity.0;
+    /// velocity.0 = 0.0;
+    /// ```
+    pub fn get_disjoint_mut<'b, A, B, Indices>(&'b mut self) -> Option<(&'b mut A, &'b mut B)>
+    where
+        A: component::Component,
+        B: component::Component,
+        Registry: ContainsQuery<'b, filter::None, (&'b mut A, (&'b mut B, view::Null)), Indices>,
+    {
+        self.query(Query::<(&'b mut A, (&'b mut B, view::Null)), filter::None>::new())
+            .map(|(a, (b, view::Null))| (a, b))
+    }
+
+    /// Returns an iterator over the [`TypeId`]s of the components contained in the entity.
+    ///
+    /// The `TypeId`s are yielded in the order the components are defined in the [`Registry`]. This
+    /// is useful for tooling that needs to inspect the component types of an entity dynamically.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use core::any::TypeId;
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    /// struct Baz(f64);
+    ///
+    /// type Registry = Registry!(Foo, Bar, Baz);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42), Baz(1.5)));
+    /// let entry = world.entry(entity_identifier).unwrap();
+    ///
+    /// assert_eq!(
+    ///     entry.component_type_ids().collect::<Vec<_>>(),
+    ///     vec![TypeId::of::<Foo>(), TypeId::of::<Baz>()]
+    /// );
+    /// ```
+    ///
+    /// [`Registry`]: trait@crate::registry::Registry
+    pub fn component_type_ids(&self) -> impl Iterator<Item = TypeId> {
+        let mut type_ids = Vec::new();
+        // SAFETY: The iterator returned by `self.location.identifier.iter()` is generic over the
+        // same `Registry` on which `extend_component_type_ids()` is called. Additionally, the
+        // iterator does not outlive the archetype identifier, as the archetype is stored within
+        // `self.world`.
+        unsafe {
+            Registry::extend_component_type_ids(&mut type_ids, self.location.identifier.iter());
+        }
+        type_ids.into_iter()
+    }
+
+    /// Returns the [`ArchetypeKey`] of the archetype storing the entity.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
+    /// let first = world.insert(entity!(Foo(1), Bar(true)));
+    /// let second = world.insert(entity!(Foo(2), Bar(false)));
+    ///
+    /// let key = world.entry(first).unwrap().archetype_key();
+    /// assert_eq!(world.entry(second).unwrap().archetype_key(), key);
+    /// ```
+    #[must_use]
+    pub fn archetype_key(&self) -> ArchetypeKey {
+        ArchetypeKey::new(self.location.identifier.as_vec())
+    }
+
+    /// Returns the identifier of the entity.
+    pub(crate) fn entity_identifier(&self) -> entity::Identifier {
+        self.entity_identifier
+    }
+
+    /// Returns a reference to the entity's `Component`, if it contains one.
+    pub(crate) fn component<Component, Index>(&self) -> Option<&Component>
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        let column = self
+            .world
+            .archetypes
+            .get(self.location.identifier)?
+            .column::<Component, Index>()?;
+        // SAFETY: `self.location.index` is invariantly guaranteed to be a valid index within the
+        // archetype, and therefore within each of its columns.
+        Some(unsafe { column.get_unchecked(self.location.index) })
+    }
+
+    /// Returns a mutable reference to the entity's `Component`, if it contains one.
+    pub(crate) fn component_mut<Component, Index>(&mut self) -> Option<&mut Component>
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        let column = self
+            .world
+            .archetypes
+            .get_mut(self.location.identifier)?
+            .column_mut::<Component, Index>()?;
+        // SAFETY: `self.location.index` is invariantly guaranteed to be a valid index within the
+        // archetype, and therefore within each of its columns.
+        Some(unsafe { column.get_unchecked_mut(self.location.index) })
+    }
 }
 
-impl<'a, Registry, Resources> fmt::Debug for Entry<'a, Registry, Resources>
+impl<'a, Registry, Resources, S> fmt::Debug for Entry<'a, Registry, Resources, S>
 where
     Registry: registry::Debug,
     Resources: resource::Debug,
+    S: BuildHasher,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Entry")
@@ -0,0 +1,327 @@
+use crate::{
+    component,
+    entity,
+    query::{
+        result,
+        view,
+        Query,
+        Result,
+    },
+    registry,
+    registry::{
+        ContainsComponent,
+        ContainsQuery,
+    },
+    resource,
+    resource::{
+        ContainsResource,
+        ContainsViews,
+    },
+    World,
+};
+#[cfg(feature = "rayon")]
+use crate::{
+    query::view::ParViews,
+    registry::ContainsParQuery,
+};
+use core::{
+    fmt,
+    hash::BuildHasher,
+};
+use fnv::FnvBuildHasher;
+
+/// A [`World`] whose entities can no longer be inserted, removed, or restructured.
+///
+/// A `FrozenWorld` is intended for read-heavy phases, such as rendering, where the set of entities
+/// and their components does not change. Only queries and other read operations are available.
+/// Component values can still be modified through mutable views within a query, but no entity can
+/// gain or lose components.
+///
+/// When frozen, the `World`'s storage is shrunk to fit its contents, and its archetypes are
+/// reordered so that iteration order depends only on which archetypes are stored, rather than on
+/// the history of insertions and removals, as long as the `World`'s hasher is deterministic. Since
+/// the structure of the `World` can not change, queries do not provide [`Entries`]; any [`Query`]
+/// with `EntryViews` other than [`Views!()`] is rejected at compile time.
+///
+/// This `struct` is created by the [`freeze`] method on `World`, and can be converted back into a
+/// `World` using [`thaw`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::{
+///         result,
+///         Views,
+///     },
+///     Query,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Position(f32);
+/// struct Velocity(f32);
+///
+/// type Registry = Registry!(Position, Velocity);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Position(0.0), Velocity(1.0)));
+/// world.insert(entity!(Position(2.0)));
+///
+/// let mut frozen_world = world.freeze();
+///
+/// let mut sum = 0.0;
+/// for result!(position) in frozen_world.query(Query::<Views!(&Position)>::new()).iter {
+///     sum += position.0;
+/// }
+/// assert_eq!(sum, 2.0);
+///
+/// let mut world = frozen_world.thaw();
+/// world.insert(entity!(Velocity(3.0)));
+/// ```
+///
+/// [`Entries`]: crate::query::Entries
+/// [`freeze`]: crate::World::freeze()
+/// [`thaw`]: FrozenWorld::thaw()
+/// [`Views!()`]: crate::query::Views!
+/// [`World`]: crate::World
+pub struct FrozenWorld<Registry, Resources = resource::Null, S = FnvBuildHasher>
+where
+    Registry: registry::Registry,
+{
+    world: World<Registry, Resources, S>,
+}
+
+impl<Registry, Resources, S> FrozenWorld<Registry, Resources, S>
+where
+    Registry: registry::Registry,
+    S: BuildHasher,
+{
+    pub(crate) fn new(world: World<Registry, Resources, S>) -> Self {
+        Self { world }
+    }
+
+    /// Converts back into a [`World`], allowing it to be structurally modified again.
+    ///
+    /// [`World`]: crate::World
+    #[must_use]
+    pub fn thaw(self) -> World<Registry, Resources, S> {
+        self.world
+    }
+
+    /// Query for components contained within the `FrozenWorld` using the given `Views` `V` and
+    /// `Filter` `F`, returning an [`Iterator`] over all components of entities matching the
+    /// query.
+    ///
+    /// This is the same as [`World::query()`], except that no `EntryViews` can be specified.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    /// world.insert(entity!(Foo(100)));
+    ///
+    /// let mut frozen_world = world.freeze();
+    ///
+    /// for result!(foo) in frozen_world
+    ///     .query(Query::<Views!(&mut Foo), filter::Has<Bar>>::new())
+    ///     .iter
+    /// {
+    ///     foo.0 += 1;
+    /// }
+    /// ```
+    ///
+    /// [`Iterator`]: core::iter::Iterator
+    /// [`World::query()`]: crate::World::query()
+    pub fn query<
+        'a,
+        Views,
+        Filter,
+        ResourceViews,
+        QueryIndices,
+        ResourceViewsIndices,
+        DisjointIndices,
+        EntryIndices,
+    >(
+        &'a mut self,
+        query: Query<Views, Filter, ResourceViews>,
+    ) -> Result<
+        'a,
+        Registry,
+        Resources,
+        result::Iter<'a, Registry, Filter, Views, QueryIndices>,
+        ResourceViews,
+        view::Null,
+        EntryIndices,
+    >
+    where
+        Views: view::Views<'a>,
+        Registry: ContainsQuery<'a, Filter, Views, QueryIndices>
+            + registry::ContainsViews<'a, view::Null, EntryIndices>,
+        Resources: ContainsViews<'a, ResourceViews, ResourceViewsIndices>,
+        view::Null: view::Disjoint<Views, Registry, DisjointIndices>,
+    {
+        self.world.query(query)
+    }
+
+    /// Query for components contained within the `FrozenWorld` using the given `ParViews` `V` and
+    /// `Filter` `F`, returning a [`ParallelIterator`] over all components of entities matching
+    /// the query.
+    ///
+    /// This is the same as [`World::par_query()`], except that no `EntryViews` can be specified.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    /// world.insert(entity!(Foo(100)));
+    ///
+    /// let mut frozen_world = world.freeze();
+    ///
+    /// frozen_world
+    ///     .par_query(Query::<Views!(&mut Foo)>::new())
+    ///     .iter
+    ///     .for_each(|result!(foo)| {
+    ///         foo.0 += 1;
+    ///     });
+    /// ```
+    ///
+    /// [`ParallelIterator`]: rayon::iter::ParallelIterator
+    /// [`World::par_query()`]: crate::World::par_query()
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    pub fn par_query<
+        'a,
+        Views,
+        Filter,
+        ResourceViews,
+        QueryIndices,
+        ResourceViewsIndices,
+        DisjointIndices,
+        EntryIndices,
+    >(
+        &'a mut self,
+        query: Query<Views, Filter, ResourceViews>,
+    ) -> Result<
+        'a,
+        Registry,
+        Resources,
+        result::ParIter<'a, Registry, Filter, Views, QueryIndices>,
+        ResourceViews,
+        view::Null,
+        EntryIndices,
+    >
+    where
+        Views: ParViews<'a>,
+        Registry: ContainsParQuery<'a, Filter, Views, QueryIndices>
+            + registry::ContainsViews<'a, view::Null, EntryIndices>,
+        Resources: ContainsViews<'a, ResourceViews, ResourceViewsIndices>,
+        view::Null: view::Disjoint<Views, Registry, DisjointIndices>,
+    {
+        self.world.par_query(query)
+    }
+
+    /// Returns `true` if the frozen world contains an entity identified by `entity_identifier`.
+    ///
+    /// See [`World::contains()`].
+    ///
+    /// [`World::contains()`]: crate::World::contains()
+    #[must_use]
+    pub fn contains(&self, entity_identifier: entity::Identifier) -> bool {
+        self.world.contains(entity_identifier)
+    }
+
+    /// Returns the number of entities in the frozen world.
+    ///
+    /// See [`World::len()`].
+    ///
+    /// [`World::len()`]: crate::World::len()
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.world.len()
+    }
+
+    /// Returns `true` if the frozen world contains no entities.
+    ///
+    /// See [`World::is_empty()`].
+    ///
+    /// [`World::is_empty()`]: crate::World::is_empty()
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.world.is_empty()
+    }
+
+    /// Returns the number of entities in the frozen world that contain a `Component`.
+    ///
+    /// See [`World::count_component()`].
+    ///
+    /// [`World::count_component()`]: crate::World::count_component()
+    #[must_use]
+    pub fn count_component<Component, Index>(&self) -> usize
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.world.count_component::<Component, Index>()
+    }
+
+    /// View a single resource immutably.
+    ///
+    /// See [`World::get()`].
+    ///
+    /// [`World::get()`]: crate::World::get()
+    #[must_use]
+    pub fn get<Resource, Index>(&self) -> &Resource
+    where
+        Resources: ContainsResource<Resource, Index>,
+    {
+        self.world.get()
+    }
+}
+
+impl<Registry, Resources, S> fmt::Debug for FrozenWorld<Registry, Resources, S>
+where
+    Registry: registry::Registry,
+    World<Registry, Resources, S>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FrozenWorld")
+            .field("world", &self.world)
+            .finish()
+    }
+}
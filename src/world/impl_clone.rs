@@ -1,17 +1,22 @@
 use crate::{
     registry,
-    world::World,
+    world::{
+        Observers,
+        World,
+    },
 };
+use core::hash::BuildHasher;
 
-impl<Registry, Resources> Clone for World<Registry, Resources>
+impl<Registry, Resources, S> Clone for World<Registry, Resources, S>
 where
     Registry: registry::Clone,
     Resources: Clone,
+    S: BuildHasher + Clone,
 {
     /// Performs a full clone of the `World` and all of its components.
     ///
     /// Any `entity::Identifier`s that were valid for the old `World` will be valid for the
-    /// newly-cloned `World`.
+    /// newly-cloned `World`. Observers registered on the old `World` are not cloned.
     fn clone(&self) -> Self {
         // SAFETY: `identifier_map` will be outlived by both the current and the cloned `World`,
         // and therefore will be outlived by the archetypes it references as well.
@@ -26,6 +31,12 @@ where
             len: self.len,
 
             resources: self.resources.clone(),
+
+            observers: Observers::default(),
+
+            auto_compact: self.auto_compact,
+
+            component_type_ids: self.component_type_ids.clone(),
         }
     }
 
@@ -33,7 +44,8 @@ where
     ///
     /// Any `entity::Identifier`s that were valid for the `source` `World` will be valid for `self`
     /// after this. Old `entity::Identifier`s that were valid for `self` before this clone will no
-    /// longer be valid.
+    /// longer be valid. Observers registered on `self` are kept, and observers registered on
+    /// `source` are not cloned.
     ///
     /// This method reuses the existing allocations for the clone. In some cases, this can be more
     /// efficient than calling `clone()` directly.
@@ -3,12 +3,16 @@ use crate::{
     registry,
     resource,
 };
-use core::fmt;
+use core::{
+    fmt,
+    hash::BuildHasher,
+};
 
-impl<Registry, Resources> fmt::Debug for World<Registry, Resources>
+impl<Registry, Resources, S> fmt::Debug for World<Registry, Resources, S>
 where
     Registry: registry::Debug,
     Resources: resource::Debug,
+    S: BuildHasher,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("World")
@@ -16,6 +20,6 @@ where
             .field("entity_allocator", &self.entity_allocator)
             .field("len", &self.len)
             .field("resources", &resource::Debugger(&self.resources))
-            .finish()
+            .finish_non_exhaustive()
     }
 }
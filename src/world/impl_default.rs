@@ -3,14 +3,16 @@ use crate::{
     resource,
     World,
 };
+use core::hash::BuildHasher;
 
-impl<Registry, Resources> Default for World<Registry, Resources>
+impl<Registry, Resources, S> Default for World<Registry, Resources, S>
 where
     Registry: registry::Registry,
     Resources: resource::Resources + Default,
+    S: BuildHasher + Clone + Default,
 {
     fn default() -> Self {
-        Self::with_resources(Resources::default())
+        Self::with_resources_and_hasher(Resources::default(), S::default())
     }
 }
 
@@ -1,11 +1,15 @@
 use super::World;
 use crate::registry;
-use core::cmp;
+use core::{
+    cmp,
+    hash::BuildHasher,
+};
 
-impl<Registry, Resources> cmp::PartialEq for World<Registry, Resources>
+impl<Registry, Resources, S> cmp::PartialEq for World<Registry, Resources, S>
 where
     Registry: registry::PartialEq,
     Resources: cmp::PartialEq,
+    S: BuildHasher,
 {
     fn eq(&self, other: &Self) -> bool {
         self.len == other.len
@@ -15,10 +19,11 @@ where
     }
 }
 
-impl<Registry, Resources> cmp::Eq for World<Registry, Resources>
+impl<Registry, Resources, S> cmp::Eq for World<Registry, Resources, S>
 where
     Registry: registry::Eq,
     Resources: cmp::Eq,
+    S: BuildHasher,
 {
 }
 
@@ -5,9 +5,10 @@ use crate::{
 
 // SAFETY: This type is safe to send between threads, since all pointers are owned and cannot be
 // mutated without mutable access.
-unsafe impl<Registry, Resources> Send for World<Registry, Resources>
+unsafe impl<Registry, Resources, S> Send for World<Registry, Resources, S>
 where
     Registry: registry::Registry + Send,
     Resources: Send,
+    S: Send,
 {
 }
@@ -1,14 +1,23 @@
 use crate::{
-    archetypes::DeserializeArchetypes,
+    archetypes::{
+        DeserializeArchetypes,
+        SerializeFiltered,
+    },
+    component,
+    entity,
     entity::allocator::DeserializeAllocator,
     registry,
+    registry::ContainsComponent,
     resource,
     World,
 };
+use alloc::vec::Vec;
 use core::{
     fmt,
+    hash::BuildHasher,
     marker::PhantomData,
 };
+use fnv::FnvBuildHasher;
 use serde::{
     de,
     de::{
@@ -20,10 +29,11 @@ use serde::{
     Serializer,
 };
 
-impl<Registry, Resources> serde::Serialize for World<Registry, Resources>
+impl<Registry, Resources, H> serde::Serialize for World<Registry, Resources, H>
 where
     Registry: registry::Serialize,
     Resources: resource::Resources + resource::Serialize,
+    H: BuildHasher,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -37,30 +47,258 @@ where
     }
 }
 
-impl<'de, Registry, Resources> serde::Deserialize<'de> for World<Registry, Resources>
+impl<Registry, Resources, H> World<Registry, Resources, H>
+where
+    Registry: registry::Registry,
+    Resources: resource::Resources + resource::Serialize,
+    H: BuildHasher,
+{
+    /// Serializes the `World`, only including the components contained in `Filter`.
+    ///
+    /// This is useful for skipping transient components that do not need to be persisted, such as
+    /// caches. Only the components in `Filter` are required to implement [`Serialize`].
+    ///
+    /// The components in `Filter` must be listed in the same order as they are listed in the
+    /// `World`'s `Registry`. The serialized output is the same as what would be output by a
+    /// `World<Filter, Resources>` containing the same entities with the non-selected components
+    /// removed, and can therefore be deserialized as such a `World`. Entity identifiers are
+    /// preserved.
+    ///
+    /// # Errors
+    /// Returns an error if the serializer fails to serialize any of the values.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use serde::Serializer;
+    /// use serde_derive::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Position(f32, f32);
+    /// struct Cache(Vec<u8>);
+    ///
+    /// type Registry = Registry!(Position, Cache);
+    ///
+    /// fn save<S>(world: &World<Registry>, serializer: S) -> Result<S::Ok, S::Error>
+    /// where
+    ///     S: Serializer,
+    /// {
+    ///     // Only `Position` components are serialized.
+    ///     world.serialize_with_filter::<Registry!(Position), _, _>(serializer)
+    /// }
+    /// ```
+    ///
+    /// [`Serialize`]: serde::Serialize
+    pub fn serialize_with_filter<Filter, Indices, S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Registry: registry::SerializeFiltered<Filter, Indices>,
+        Filter: registry::Serialize,
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&SerializeFiltered::<Registry, Filter, Indices, H>::new(
+            &self.archetypes,
+        ))?;
+        tuple.serialize_element(&self.entity_allocator)?;
+        tuple.serialize_element(&resource::Serializer(&self.resources))?;
+        tuple.end()
+    }
+}
+
+impl<Registry, Resources, H> World<Registry, Resources, H>
+where
+    Registry: registry::Registry,
+    Resources: resource::Resources,
+    H: BuildHasher,
+{
+    /// Deserializes a `World` and moves all of its entities into this `World`.
+    ///
+    /// The input is expected to be in the same format as a serialized `World<Registry,
+    /// Resources>`. Rather than restoring the serialized entity identifiers, new
+    /// [`entity::Identifier`]s are allocated for each deserialized entity. The returned pairs map
+    /// each serialized identifier to its newly allocated identifier, allowing external references
+    /// to the deserialized entities to be updated.
+    ///
+    /// Any resources contained in the serialized `World` are discarded. The resources of this
+    /// `World` are left unchanged. This is equivalent to deserializing a `World` and calling
+    /// [`merge()`] with it.
+    ///
+    /// # Errors
+    /// Returns an error if the input cannot be deserialized as a `World<Registry, Resources>`. In
+    /// that case, this `World` is left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use serde::Deserializer;
+    /// use serde_derive::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Position(f32, f32);
+    ///
+    /// type Registry = Registry!(Position);
+    ///
+    /// fn load_chunk<'de, D>(world: &mut World<Registry>, deserializer: D) -> Result<(), D::Error>
+    /// where
+    ///     D: Deserializer<'de>,
+    /// {
+    ///     for (old_identifier, new_identifier) in world.deserialize_extend(deserializer)? {
+    ///         // Update any external references to `old_identifier` here.
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`merge()`]: World::merge()
+    pub fn deserialize_extend<'de, D>(
+        &mut self,
+        deserializer: D,
+    ) -> Result<Vec<(entity::Identifier, entity::Identifier)>, D::Error>
+    where
+        Registry: registry::Deserialize<'de>,
+        Resources: resource::Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let world =
+            <World<Registry, Resources, FnvBuildHasher> as serde::Deserialize>::deserialize(
+                deserializer,
+            )?;
+        Ok(self.merge(world))
+    }
+}
+
+impl<Registry, Resources, H> World<Registry, Resources, H>
+where
+    Registry: registry::Registry,
+    Resources: resource::Resources,
+    H: BuildHasher + Clone + Default,
+{
+    /// Deserializes a `World`, giving default values to entities missing any of the components in
+    /// `Defaults`.
+    ///
+    /// This is useful for loading data serialized before new components were added to the
+    /// `Registry`. After the `World` is deserialized, every entity that does not contain a
+    /// component listed in `Defaults` is given that component's [`Default`] value, migrating it to
+    /// the current shape of entities. Components not listed in `Defaults` are not required to
+    /// implement `Default`, and are left missing from entities that were serialized without them.
+    ///
+    /// Note that every entity missing a component in `Defaults` is given the component, not just
+    /// those serialized before the component was added. Therefore, only components that every
+    /// entity is expected to contain should be listed.
+    ///
+    /// For the serialized data to be readable, new components must be added to the end of the
+    /// `Registry`, and the number of components in the `Registry` must not cross a multiple of
+    /// eight, since the serialized archetype identifiers store one bit per component, rounded up
+    /// to the nearest byte.
+    ///
+    /// # Errors
+    /// Returns an error if the input cannot be deserialized as a `World<Registry, Resources>`.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use serde::Deserializer;
+    /// use serde_derive::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Position(f32, f32);
+    /// #[derive(Clone, Default, Deserialize)]
+    /// struct Velocity(f32, f32);
+    ///
+    /// type Registry = Registry!(Position, Velocity);
+    ///
+    /// fn load<'de, D>(deserializer: D) -> Result<World<Registry>, D::Error>
+    /// where
+    ///     D: Deserializer<'de>,
+    /// {
+    ///     // Entities saved without a `Velocity` are given `Velocity::default()`.
+    ///     World::deserialize_lenient::<Registry!(Velocity), _, _>(deserializer)
+    /// }
+    /// ```
+    pub fn deserialize_lenient<'de, Defaults, Indices, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        Registry: registry::Deserialize<'de> + registry::DeserializeDefaults<Defaults, Indices>,
+        Resources: resource::Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let mut world = <Self as serde::Deserialize>::deserialize(deserializer)?;
+        Registry::add_defaults(&mut world);
+        Ok(world)
+    }
+}
+
+impl<Registry, Resources, H> World<Registry, Resources, H>
+where
+    Registry: registry::Registry,
+    H: BuildHasher,
+{
+    /// Gives a clone of `component` to every entity that does not contain a `Component`.
+    pub(crate) fn add_component_where_missing<Component, Index>(&mut self, component: Component)
+    where
+        Component: Clone + component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        let component_index = Registry::LEN - Registry::INDEX - 1;
+        let identifiers = self
+            .archetypes
+            .iter()
+            .filter(|archetype| !archetype.is_empty())
+            // SAFETY: Archetypes are not removed by this method, so the identifiers will not
+            // outlive their archetypes.
+            .map(|archetype| unsafe { archetype.identifier() })
+            // SAFETY: `component_index` is guaranteed to be a valid index into the identifier,
+            // since an identifier has `Registry::LEN` bits.
+            .filter(|identifier| !unsafe { identifier.get_unchecked(component_index) })
+            .collect();
+        // SAFETY: Each identifier was obtained from an archetype within this `World` that does not
+        // contain `Component`. No archetypes have been removed since.
+        unsafe {
+            self.move_archetypes_adding_component::<Component, Index>(identifiers, &component);
+        }
+    }
+}
+
+impl<'de, Registry, Resources, H> serde::Deserialize<'de> for World<Registry, Resources, H>
 where
     Registry: registry::Deserialize<'de>,
     Resources: resource::Resources + resource::Deserialize<'de>,
+    H: BuildHasher + Clone + Default,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct WorldVisitor<'de, Registry, Resources>
+        struct WorldVisitor<'de, Registry, Resources, H>
         where
             Registry: registry::Deserialize<'de>,
         {
             lifetime: PhantomData<&'de ()>,
             registry: PhantomData<Registry>,
             resources: PhantomData<Resources>,
+            hash_builder: PhantomData<H>,
         }
 
-        impl<'de, Registry, Resources> Visitor<'de> for WorldVisitor<'de, Registry, Resources>
+        impl<'de, Registry, Resources, H> Visitor<'de> for WorldVisitor<'de, Registry, Resources, H>
         where
             Registry: registry::Deserialize<'de>,
             Resources: resource::Resources + resource::Deserialize<'de>,
+            H: BuildHasher + Clone + Default,
         {
-            type Value = World<Registry, Resources>;
+            type Value = World<Registry, Resources, H>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("serialized World")
@@ -91,10 +329,11 @@ where
 
         deserializer.deserialize_tuple(
             3,
-            WorldVisitor::<Registry, Resources> {
+            WorldVisitor::<Registry, Resources, H> {
                 lifetime: PhantomData,
                 registry: PhantomData,
                 resources: PhantomData,
+                hash_builder: PhantomData,
             },
         )
     }
@@ -105,15 +344,24 @@ mod tests {
     use super::World;
     use crate::{
         entity,
+        query::{
+            result,
+            Views,
+        },
         resources,
+        Query,
         Registry,
         Resources,
     };
-    use alloc::vec;
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
     use claims::{
         assert_err_eq,
         assert_ok,
         assert_ok_eq,
+        assert_some,
     };
     use serde::{
         de::Error as _,
@@ -513,4 +761,353 @@ mod tests {
 
         assert_eq!(world, deserialized_world);
     }
+
+    #[test]
+    fn serialize_deserialize_preserves_entity_identifiers() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier_a = world.insert(entity!(A(1), B('a')));
+        let removed_entity_identifier = world.insert(entity!(A(2)));
+        let entity_identifier_b = world.insert(entity!(B('b')));
+        world.remove(removed_entity_identifier);
+        let entity_identifier_c = world.insert(entity!(A(3)));
+        let entity_identifier_d = world.insert(entity!(A(4), B('d')));
+        world.remove(entity_identifier_a);
+        let entity_identifier_e = world.insert(entity!(A(5), B('e')));
+
+        let serializer = Serializer::builder().build();
+        let tokens = assert_ok!(world.serialize(&serializer));
+
+        let mut deserializer = Deserializer::builder().tokens(tokens).build();
+        let mut deserialized_world = assert_ok!(World::<Registry, Resources!()>::deserialize(
+            &mut deserializer
+        ));
+
+        assert!(!deserialized_world.contains(removed_entity_identifier));
+        assert!(!deserialized_world.contains(entity_identifier_a));
+        assert_eq!(
+            assert_some!(deserialized_world.entry(entity_identifier_b))
+                .query(Query::<Views!(&B)>::new())
+                .map(|result!(b)| b.0),
+            Some('b')
+        );
+        assert_eq!(
+            assert_some!(deserialized_world.entry(entity_identifier_c))
+                .query(Query::<Views!(&A)>::new())
+                .map(|result!(a)| a.0),
+            Some(3)
+        );
+        assert_eq!(
+            assert_some!(deserialized_world.entry(entity_identifier_d))
+                .query(Query::<Views!(&A, &B)>::new())
+                .map(|result!(a, b)| (a.0, b.0)),
+            Some((4, 'd'))
+        );
+        assert_eq!(
+            assert_some!(deserialized_world.entry(entity_identifier_e))
+                .query(Query::<Views!(&A, &B)>::new())
+                .map(|result!(a, b)| (a.0, b.0)),
+            Some((5, 'e'))
+        );
+
+        // Newly inserted entities are allocated the same identifiers in both worlds.
+        for _ in 0..3 {
+            assert_eq!(
+                deserialized_world.insert(entity!(A(6))),
+                world.insert(entity!(A(6)))
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_deserialize_preserves_entity_identifiers_inserted_at() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1)));
+        world.remove(entity_identifier);
+        let inserted_entity_identifier =
+            entity::Identifier::new(3, entity_identifier.generation() + 2);
+        assert_ok!(world.insert_at(inserted_entity_identifier, entity!(B('b'))));
+
+        let serializer = Serializer::builder().build();
+        let tokens = assert_ok!(world.serialize(&serializer));
+
+        let mut deserializer = Deserializer::builder().tokens(tokens).build();
+        let mut deserialized_world = assert_ok!(World::<Registry, Resources!()>::deserialize(
+            &mut deserializer
+        ));
+
+        assert!(!deserialized_world.contains(entity_identifier));
+        assert!(deserialized_world.contains(inserted_entity_identifier));
+        for _ in 0..4 {
+            assert_eq!(
+                deserialized_world.insert(entity!(A(2))),
+                world.insert(entity!(A(2)))
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_with_filter_by_row() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        let entity_identifier = world.insert(entity!(B('c')));
+        world.remove(entity_identifier);
+
+        let serializer = Serializer::builder().build();
+        let tokens = assert_ok!(world.serialize_with_filter::<Registry!(A), _, _>(&serializer));
+
+        let mut expected_world = World::<Registry!(A)>::new();
+        expected_world.insert(entity!(A(1)));
+        expected_world.insert(entity!(A(2)));
+        let entity_identifier = expected_world.insert(entity!());
+        expected_world.remove(entity_identifier);
+
+        let mut deserializer = Deserializer::builder().tokens(tokens).build();
+        assert_ok_eq!(
+            World::<Registry!(A), Resources!()>::deserialize(&mut deserializer),
+            expected_world
+        );
+    }
+
+    #[test]
+    fn serialize_with_filter_by_column() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        let entity_identifier = world.insert(entity!(B('c')));
+        world.remove(entity_identifier);
+
+        let serializer = Serializer::builder().is_human_readable(false).build();
+        let tokens = assert_ok!(world.serialize_with_filter::<Registry!(A), _, _>(&serializer));
+
+        let mut expected_world = World::<Registry!(A)>::new();
+        expected_world.insert(entity!(A(1)));
+        expected_world.insert(entity!(A(2)));
+        let entity_identifier = expected_world.insert(entity!());
+        expected_world.remove(entity_identifier);
+
+        let mut deserializer = Deserializer::builder()
+            .tokens(tokens)
+            .is_human_readable(false)
+            .build();
+        assert_ok_eq!(
+            World::<Registry!(A), Resources!()>::deserialize(&mut deserializer),
+            expected_world
+        );
+    }
+
+    #[test]
+    fn serialize_with_filter_merges_archetypes() {
+        let mut world = World::<Registry>::new();
+        let entity_identifier_a = world.insert(entity!(A(1), B('a')));
+        let entity_identifier_b = world.insert(entity!(A(2)));
+        let entity_identifier_c = world.insert(entity!(B('c')));
+        let entity_identifier_d = world.insert(entity!());
+
+        let serializer = Serializer::builder().is_human_readable(false).build();
+        let tokens = assert_ok!(world.serialize_with_filter::<Registry!(A), _, _>(&serializer));
+
+        let mut deserializer = Deserializer::builder()
+            .tokens(tokens)
+            .is_human_readable(false)
+            .build();
+        let mut deserialized_world = assert_ok!(World::<Registry!(A), Resources!()>::deserialize(
+            &mut deserializer
+        ));
+
+        assert_eq!(deserialized_world.len(), 4);
+        let mut result = deserialized_world
+            .query(Query::<Views!(&A, entity::Identifier)>::new())
+            .iter
+            .map(|result!(a, entity_identifier)| (a.0, entity_identifier))
+            .collect::<Vec<_>>();
+        result.sort_by_key(|(a, _)| *a);
+        assert_eq!(
+            result,
+            vec![(1, entity_identifier_a), (2, entity_identifier_b)]
+        );
+        assert!(deserialized_world.contains(entity_identifier_c));
+        assert!(deserialized_world.contains(entity_identifier_d));
+    }
+
+    #[test]
+    fn deserialize_extend() {
+        let mut chunk = World::<Registry>::new();
+        let chunk_entity_identifier_a = chunk.insert(entity!(A(1), B('a')));
+        let chunk_entity_identifier_b = chunk.insert(entity!(A(2)));
+
+        let serializer = Serializer::builder().build();
+        let tokens = assert_ok!(chunk.serialize(&serializer));
+
+        let mut world = World::<Registry>::new();
+        let entity_identifier = world.insert(entity!(A(0)));
+
+        let mut deserializer = Deserializer::builder().tokens(tokens).build();
+        let entity_identifiers = assert_ok!(world.deserialize_extend(&mut deserializer));
+
+        assert_eq!(world.len(), 3);
+        assert_eq!(entity_identifiers.len(), 2);
+        assert!(world.contains(entity_identifier));
+        for (old_entity_identifier, new_entity_identifier) in entity_identifiers {
+            assert_ne!(new_entity_identifier, entity_identifier);
+            let mut entry = world.entry(new_entity_identifier).unwrap();
+            if old_entity_identifier == chunk_entity_identifier_a {
+                assert_eq!(
+                    entry
+                        .query(Query::<Views!(&A, &B)>::new())
+                        .map(|result!(a, b)| (a.0, b.0)),
+                    Some((1, 'a'))
+                );
+            } else {
+                assert_eq!(old_entity_identifier, chunk_entity_identifier_b);
+                assert_eq!(
+                    entry
+                        .query(Query::<Views!(&A)>::new())
+                        .map(|result!(a)| a.0),
+                    Some(2)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_extend_by_column() {
+        let mut chunk = World::<Registry>::new();
+        chunk.insert(entity!(A(1), B('a')));
+        chunk.insert(entity!(A(2), B('b')));
+
+        let serializer = Serializer::builder().is_human_readable(false).build();
+        let tokens = assert_ok!(chunk.serialize(&serializer));
+
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(0), B('z')));
+
+        let mut deserializer = Deserializer::builder()
+            .tokens(tokens)
+            .is_human_readable(false)
+            .build();
+        assert_ok!(world.deserialize_extend(&mut deserializer));
+
+        assert_eq!(world.len(), 3);
+        let mut result = world
+            .query(Query::<Views!(&A, &B)>::new())
+            .iter
+            .map(|result!(a, b)| (a.0, b.0))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![(0, 'z'), (1, 'a'), (2, 'b')]);
+    }
+
+    #[test]
+    fn deserialize_extend_error_leaves_world_unchanged() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(0)));
+
+        let mut deserializer = Deserializer::builder()
+            .tokens(Tokens(vec![Token::Tuple { len: 0 }, Token::TupleEnd]))
+            .build();
+        assert_err_eq!(
+            world.deserialize_extend(&mut deserializer),
+            Error::invalid_length(0, &"serialized World")
+        );
+
+        assert_eq!(world.len(), 1);
+    }
+
+    #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+    struct C(u32);
+
+    #[test]
+    fn deserialize_lenient_fills_missing_components() {
+        let mut old_world = World::<Registry!(A, B)>::new();
+        let entity_identifier_a = old_world.insert(entity!(A(1), B('a')));
+        let entity_identifier_b = old_world.insert(entity!(A(2)));
+        let entity_identifier_c = old_world.insert(entity!());
+
+        let serializer = Serializer::builder().is_human_readable(false).build();
+        let tokens = assert_ok!(old_world.serialize(&serializer));
+
+        let mut deserializer = Deserializer::builder()
+            .tokens(tokens)
+            .is_human_readable(false)
+            .build();
+        let mut world = assert_ok!(World::<Registry!(A, B, C)>::deserialize_lenient::<
+            Registry!(C),
+            _,
+            _,
+        >(&mut deserializer));
+
+        assert_eq!(world.len(), 3);
+        assert_eq!(world.query(Query::<Views!(&C)>::new()).iter.count(), 3);
+        let mut entry = assert_some!(world.entry(entity_identifier_a));
+        assert_eq!(
+            entry
+                .query(Query::<Views!(&A, &B, &C)>::new())
+                .map(|result!(a, b, c)| (a.0, b.0, c.0)),
+            Some((1, 'a', 0))
+        );
+        let mut entry = assert_some!(world.entry(entity_identifier_b));
+        assert_eq!(
+            entry
+                .query(Query::<Views!(&A, Option<&B>, &C)>::new())
+                .map(|result!(a, b, c)| (a.0, b.is_some(), c.0)),
+            Some((2, false, 0))
+        );
+        let mut entry = assert_some!(world.entry(entity_identifier_c));
+        assert_eq!(
+            entry
+                .query(Query::<Views!(&C)>::new())
+                .map(|result!(c)| c.0),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn deserialize_lenient_keeps_present_components() {
+        let mut old_world = World::<Registry!(A, B, C)>::new();
+        old_world.insert(entity!(A(1), C(5)));
+        old_world.insert(entity!(A(2)));
+
+        let serializer = Serializer::builder().is_human_readable(false).build();
+        let tokens = assert_ok!(old_world.serialize(&serializer));
+
+        let mut deserializer = Deserializer::builder()
+            .tokens(tokens)
+            .is_human_readable(false)
+            .build();
+        let mut world = assert_ok!(World::<Registry!(A, B, C)>::deserialize_lenient::<
+            Registry!(C),
+            _,
+            _,
+        >(&mut deserializer));
+
+        let mut result = world
+            .query(Query::<Views!(&A, &C)>::new())
+            .iter
+            .map(|result!(a, c)| (a.0, c.0))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![(1, 5), (2, 0)]);
+    }
+
+    #[test]
+    fn deserialize_lenient_no_defaults() {
+        let mut old_world = World::<Registry>::new();
+        old_world.insert(entity!(A(1), B('a')));
+        old_world.insert(entity!(A(2)));
+
+        let serializer = Serializer::builder().is_human_readable(false).build();
+        let tokens = assert_ok!(old_world.serialize(&serializer));
+
+        let mut deserializer = Deserializer::builder()
+            .tokens(tokens)
+            .is_human_readable(false)
+            .build();
+        assert_ok_eq!(
+            World::<Registry>::deserialize_lenient::<Registry!(), _, _>(&mut deserializer),
+            old_world
+        );
+    }
 }
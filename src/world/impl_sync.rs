@@ -5,9 +5,10 @@ use crate::{
 
 // SAFETY: This type is safe to share between multiple threads as you can't mutate it without a
 // &mut reference.
-unsafe impl<Registry, Resources> Sync for World<Registry, Resources>
+unsafe impl<Registry, Resources, S> Sync for World<Registry, Resources, S>
 where
     Registry: registry::Registry + Sync,
     Resources: Sync,
+    S: Sync,
 {
 }
@@ -0,0 +1,113 @@
+use crate::{
+    archetypes,
+    query::{
+        filter::And,
+        view,
+    },
+    registry,
+    registry::{
+        contains::filter::Sealed as ContainsFilterSealed,
+        ContainsQuery,
+    },
+    world::ArchetypeView,
+};
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+
+/// An [`Iterator`] over the archetypes of a [`World`] matching a [`Filter`].
+///
+/// Each archetype is yielded as an [`ArchetypeView`], providing typed access to its columns of
+/// components. Only archetypes containing at least one entity are yielded. The archetypes are not
+/// yielded in any specified order.
+///
+/// This `struct` is created by the [`matching_archetypes`] method on [`World`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     query::filter,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+///
+/// type Registry = Registry!(Foo, Bar);
+///
+/// let mut world = World::<Registry>::new();
+/// world.insert(entity!(Foo(1), Bar(true)));
+/// world.insert(entity!(Foo(2)));
+/// world.insert(entity!(Bar(false)));
+///
+/// let len: usize = world
+///     .matching_archetypes::<filter::Has<Foo>, _>()
+///     .map(|archetype| archetype.len())
+///     .sum();
+/// assert_eq!(len, 2);
+/// ```
+///
+/// [`Filter`]: crate::query::filter::Filter
+/// [`matching_archetypes`]: crate::World::matching_archetypes()
+/// [`World`]: crate::World
+pub struct MatchingArchetypes<'a, Registry, Filter, Indices>
+where
+    Registry: registry::Registry,
+{
+    archetypes_iter: archetypes::IterMut<'a, Registry>,
+
+    filter: PhantomData<Filter>,
+    indices: PhantomData<Indices>,
+}
+
+impl<'a, Registry, Filter, Indices> MatchingArchetypes<'a, Registry, Filter, Indices>
+where
+    Registry: registry::Registry,
+{
+    pub(crate) fn new(archetypes_iter: archetypes::IterMut<'a, Registry>) -> Self {
+        Self {
+            archetypes_iter,
+
+            filter: PhantomData,
+            indices: PhantomData,
+        }
+    }
+}
+
+impl<'a, Registry, Filter, Indices> Iterator for MatchingArchetypes<'a, Registry, Filter, Indices>
+where
+    Registry: ContainsQuery<'a, Filter, view::Null, Indices>,
+{
+    type Item = ArchetypeView<'a, Registry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.archetypes_iter
+            .find(|archetype| {
+                !archetype.is_empty()
+                    // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+                    // identifier is generic over. Additionally, the identifier reference created
+                    // here will not outlive `archetype`.
+                    && unsafe {
+                        <Registry as ContainsFilterSealed<
+                            And<view::Null, Filter>,
+                            And<Registry::ViewsFilterIndices, Registry::FilterIndices>,
+                        >>::filter(archetype.identifier())
+                    }
+            })
+            .map(ArchetypeView::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.archetypes_iter.size_hint().1)
+    }
+}
+
+impl<'a, Registry, Filter, Indices> FusedIterator
+    for MatchingArchetypes<'a, Registry, Filter, Indices>
+where
+    Registry: ContainsQuery<'a, Filter, view::Null, Indices>,
+{
+}
@@ -4,7 +4,16 @@
 //! stores entities made with a combination of components contained in the `World`'s component
 //! `Registry`.
 
+mod archetype_handle;
+mod archetype_key;
+mod archetype_view;
+mod builder;
+mod deferred;
+mod diff;
+mod drain;
+mod entity_mut;
 mod entry;
+mod frozen_world;
 mod impl_clone;
 mod impl_debug;
 mod impl_default;
@@ -14,26 +23,64 @@ mod impl_send;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 mod impl_serde;
 mod impl_sync;
-
+mod matching_archetypes;
+mod modifier;
+mod observers;
+mod packed;
+mod spawn_error;
+
+pub use archetype_handle::ArchetypeHandle;
+pub use archetype_key::ArchetypeKey;
+pub use archetype_view::ArchetypeView;
+pub use builder::Builder;
+pub use deferred::Deferred;
+pub use diff::{
+    ComponentChange,
+    WorldDiff,
+};
+pub use drain::Drain;
+pub use entity_mut::EntityMut;
 pub use entry::Entry;
+pub use frozen_world::FrozenWorld;
+pub use matching_archetypes::MatchingArchetypes;
+pub use modifier::Modifier;
+pub use spawn_error::SpawnError;
+
+pub(crate) use observers::{
+    Callbacks,
+    MoveCallbacks,
+};
+
+use observers::Observers;
 
 use crate::{
+    archetype,
+    archetype::Archetype,
     archetypes::Archetypes,
+    component,
     entities,
     entity,
-    query,
+    hlist::Reshape,
     query::{
+        filter,
+        filter::And,
         result,
         view,
+        Entries,
         Query,
         Result,
     },
     registry,
     registry::{
         contains,
+        contains::filter::ContainsFilter,
+        ContainsComponent,
+        ContainsDrain,
         ContainsEntities,
         ContainsEntity,
+        ContainsMixedEntity,
         ContainsQuery,
+        ContainsRegistry,
     },
     resource,
     resource::{
@@ -41,22 +88,32 @@ use crate::{
         ContainsViews,
     },
     system,
+    system::schedule,
 };
 #[cfg(feature = "rayon")]
 use crate::{
     query::view::ParViews,
     registry::{
-        contains::filter::ContainsFilter,
+        ContainsParEntities,
         ContainsParQuery,
     },
-    system::{
-        schedule,
-        schedule::Stages,
-    },
 };
-use alloc::vec::Vec;
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use core::{
+    any::TypeId,
+    hash::BuildHasher,
+    ptr,
+};
 use fnv::FnvBuildHasher;
-use hashbrown::HashSet;
+use hashbrown::{
+    HashMap,
+    HashSet,
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::ParallelIterator;
 
 /// A container of entities.
 ///
@@ -90,18 +147,32 @@ use hashbrown::HashSet;
 /// Components of entities can be queried using the [`query()`] method. [`System`]s can also be run
 /// over components of entities using the various `run` methods.
 ///
+/// The hasher `S` is used for looking up the archetypes entities are stored in. It defaults to
+/// [`FnvBuildHasher`], which can be replaced with a faster hasher for `World`s containing many
+/// distinct archetypes. See [`with_hasher()`] for an example.
+///
+/// [`FnvBuildHasher`]: fnv::FnvBuildHasher
 /// [`query()`]: crate::World::query()
 /// [`Registry`]: crate::registry::Registry
 /// [`System`]: crate::system::System
-pub struct World<Registry, Resources = resource::Null>
+/// [`with_hasher()`]: crate::World::with_hasher()
+pub struct World<Registry, Resources = resource::Null, S = FnvBuildHasher>
 where
     Registry: registry::Registry,
 {
-    pub(crate) archetypes: Archetypes<Registry>,
+    pub(crate) archetypes: Archetypes<Registry, S>,
     pub(crate) entity_allocator: entity::Allocator<Registry>,
     len: usize,
 
     resources: Resources,
+
+    observers: Observers,
+
+    /// The ratio of empty archetypes above which they are automatically removed.
+    auto_compact: Option<f32>,
+
+    /// The `TypeId`s of the components in `Registry`.
+    component_type_ids: HashSet<TypeId, FnvBuildHasher>,
 }
 
 impl<Registry> World<Registry, resource::Null>
@@ -133,22 +204,107 @@ where
     pub fn new() -> Self {
         Self::with_resources(resource::Null)
     }
+
+    /// Creates a [`Builder`] for constructing a `World` one resource at a time.
+    ///
+    /// This is an alternative to [`with_resources()`] for when the resources are more easily
+    /// assembled step by step, such as when they are constructed conditionally.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let world = World::<Registry!()>::builder()
+    ///     .resource(Foo(42))
+    ///     .resource(Bar(true))
+    ///     .build();
+    /// ```
+    ///
+    /// [`with_resources()`]: World::with_resources()
+    pub fn builder() -> Builder<Registry> {
+        Builder::new()
+    }
+}
+
+impl<Registry, S> World<Registry, resource::Null, S>
+where
+    Registry: registry::Registry,
+    S: BuildHasher + Clone,
+{
+    /// Creates an empty `World` which will use the given hash builder to look up archetypes.
+    ///
+    /// Archetypes are looked up whenever entities are inserted, removed, or change shape. For
+    /// `World`s with many distinct archetypes, a faster hasher than the default can reduce the
+    /// cost of these lookups.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     resource,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use fnv::FnvBuildHasher;
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let world =
+    ///     World::<Registry, resource::Null, FnvBuildHasher>::with_hasher(FnvBuildHasher::default());
+    /// ```
+    #[must_use]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_resources_and_hasher(resource::Null, hash_builder)
+    }
 }
 
 impl<Registry, Resources> World<Registry, Resources>
 where
     Registry: registry::Registry,
+{
+    /// Creates an empty world containing the given resources.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     resources,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct ResourceA(u32);
+    /// struct ResourceB(char);
+    ///
+    /// let world = World::<Registry!(), _>::with_resources(resources!(ResourceA(0), ResourceB('a')));
+    /// ```
+    #[must_use]
+    pub fn with_resources(resources: Resources) -> Self {
+        Self::with_resources_and_hasher(resources, FnvBuildHasher::default())
+    }
+}
+
+impl<Registry, Resources, S> World<Registry, Resources, S>
+where
+    Registry: registry::Registry,
+    S: BuildHasher,
 {
     fn from_raw_parts(
-        archetypes: Archetypes<Registry>,
+        archetypes: Archetypes<Registry, S>,
         entity_allocator: entity::Allocator<Registry>,
         len: usize,
         resources: Resources,
     ) -> Self {
-        Registry::assert_no_duplicates(&mut HashSet::with_capacity_and_hasher(
-            Registry::LEN,
-            FnvBuildHasher::default(),
-        ));
+        let mut component_type_ids =
+            HashSet::with_capacity_and_hasher(Registry::LEN, FnvBuildHasher::default());
+        Registry::assert_no_duplicates(&mut component_type_ids);
 
         Self {
             archetypes,
@@ -156,10 +312,17 @@ where
             len,
 
             resources,
+
+            observers: Observers::default(),
+
+            auto_compact: None,
+
+            component_type_ids,
         }
     }
 
-    /// Creates an empty world containing the given resources.
+    /// Creates an empty world containing the given resources, which will use the given hash
+    /// builder to look up archetypes.
     ///
     /// # Example
     /// ```
@@ -168,19 +331,51 @@ where
     ///     Registry,
     ///     World,
     /// };
+    /// use fnv::FnvBuildHasher;
     ///
     /// struct ResourceA(u32);
     /// struct ResourceB(char);
     ///
-    /// let world = World::<Registry!(), _>::with_resources(resources!(ResourceA(0), ResourceB('a')));
+    /// let world = World::<Registry!(), _, _>::with_resources_and_hasher(
+    ///     resources!(ResourceA(0), ResourceB('a')),
+    ///     FnvBuildHasher::default(),
+    /// );
     /// ```
     #[must_use]
-    pub fn with_resources(resources: Resources) -> Self {
-        Self::from_raw_parts(Archetypes::new(), entity::Allocator::new(), 0, resources)
+    pub fn with_resources_and_hasher(resources: Resources, hash_builder: S) -> Self
+    where
+        S: Clone,
+    {
+        Self::from_raw_parts(
+            Archetypes::with_hasher(hash_builder),
+            entity::Allocator::new(),
+            0,
+            resources,
+        )
     }
 
     /// Insert an entity, returning an [`entity::Identifier`].
     ///
+    /// This method never fails. Every component of `Entity` must be contained in the `World`'s
+    /// [`Registry`], which is verified at compile time, so an entity containing a component
+    /// outside of the registry is rejected before it can ever be inserted:
+    ///
+    /// ``` compile_fail
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    ///
+    /// // This does not compile, because `Bar` is not in the registry.
+    /// world.insert(entity!(Foo(42), Bar(false)));
+    /// ```
+    ///
     /// # Example
     /// ``` rust
     /// use brood::{
@@ -198,6 +393,8 @@ where
     ///
     /// let entity_identifier = world.insert(entity!(Foo(42), Bar(false)));
     /// ```
+    ///
+    /// [`Registry`]: crate::registry::Registry
     pub fn insert<Entity, Indices>(&mut self, entity: Entity) -> entity::Identifier
     where
         Registry: ContainsEntity<Entity, Indices>,
@@ -211,20 +408,37 @@ where
         // by the archetype's identifier.
         //
         // `self.entity_allocator` is guaranteed to live as long as the archetype.
-        unsafe {
+        let archetype = unsafe {
             self.archetypes
                 .get_mut_or_insert_new_for_entity::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>()
-                .push(canonical_entity, &mut self.entity_allocator)
+        };
+        // SAFETY: The archetype is guaranteed to be made up of the components of `Entity`, and
+        // `self.entity_allocator` is guaranteed to live as long as the archetype.
+        let entity_identifier =
+            unsafe { archetype.push(canonical_entity, &mut self.entity_allocator) };
+        // SAFETY: The entity was just pushed as the last row of the archetype.
+        unsafe {
+            archetype.notify_rows(
+                archetype.len() - 1..archetype.len(),
+                &mut self.observers.on_add,
+            );
         }
+
+        entity_identifier
     }
 
-    /// Insert multiple entities made from the same components, returning a [`Vec`] of
-    /// [`entity::Identifier`]s.
+    /// Returns an [`ArchetypeHandle`] for inserting entities of type `Entity` using
+    /// [`insert_into()`].
+    ///
+    /// The handle caches the lookup of the archetype storing entities of type `Entity`, allowing
+    /// it to be skipped when inserting many entities of the same type one at a time. Obtaining a
+    /// handle does not modify the `World`.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
-    ///     entities,
+    ///     entity,
+    ///     Entity,
     ///     Registry,
     ///     World,
     /// };
@@ -232,557 +446,587 @@ where
     /// struct Foo(u32);
     /// struct Bar(bool);
     ///
-    /// type Registry = Registry!(Foo, Bar);
-    ///
-    /// let mut world = World::<Registry>::new();
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
     ///
-    /// let entity_identiifers = world.extend(entities![(Foo(1), Bar(false)), (Foo(2), Bar(true))]);
+    /// let handle = world.archetype_handle::<Entity!(Foo, Bar), _>();
     /// ```
-    pub fn extend<Entities, Indices>(
-        &mut self,
-        entities: entities::Batch<Entities>,
-    ) -> Vec<entity::Identifier>
+    ///
+    /// [`insert_into()`]: World::insert_into()
+    #[must_use]
+    pub fn archetype_handle<Entity, Indices>(&self) -> ArchetypeHandle<Registry, Entity>
     where
-        Registry: ContainsEntities<Entities, Indices>,
+        Registry: ContainsEntity<Entity, Indices>,
     {
-        self.len += entities.len();
-
-        let canonical_entities =
-            // SAFETY: Since `entities` is already a `Batch`, then the canonical entities derived
-            // from `entities` can safely be converted into a batch as well, since the components
-            // will be of the same length.
-            unsafe { entities::Batch::new_unchecked(Registry::canonical(entities.entities)) };
-
-        // SAFETY: Since the archetype was obtained using the `identifier_buffer` created from the
-        // entities `E`, then the entities are guaranteed to be made up of componpents identified
-        // by the archetype's identifier.
-        //
-        // `self.entity_allocator` is guaranteed to live as long as the archetype.
+        let identifier_buffer = <Registry as registry::Canonical<
+            <Registry as contains::entity::Sealed<Entity, Indices>>::Canonical,
+            <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments,
+        >>::create_archetype_identifier();
+        // SAFETY: The `IdentifierRef` and slice created here do not outlive the
+        // `identifier_buffer`.
         unsafe {
-            self.archetypes
-                .get_mut_or_insert_new_for_entity::<<<Registry as contains::entities::Sealed<Entities, Indices>>::Canonical as entities::Contains>::Entity, <Registry as contains::entities::Sealed<Entities, Indices>>::CanonicalContainments>()
-                .extend(canonical_entities, &mut self.entity_allocator)
+            ArchetypeHandle::new(
+                identifier_buffer.as_slice().to_vec(),
+                self.archetypes.hash_identifier(identifier_buffer.as_ref()),
+            )
         }
     }
 
-    /// Query for components contained within the `World` using the given [`Views`] `V` and
-    /// [`Filter`] `F`, returning an [`Iterator`] over all components of entities matching the
-    /// query.
+    /// Insert an entity into the archetype cached by an [`ArchetypeHandle`].
     ///
-    /// Note that the order of the entities returned by a query is not specified.
+    /// This behaves exactly like [`insert()`], but uses `handle` to skip most of the lookup of the
+    /// entity's archetype. This is useful for inserting many entities of the same type one at a
+    /// time. To insert many entities at once, use [`extend()`] instead.
+    ///
+    /// If the handle's archetype is no longer stored in the `World`, such as after calling
+    /// [`shrink_to_fit()`], it is looked up and recreated as it would be by `insert()`.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
     ///     entity,
-    ///     query::{
-    ///         filter,
-    ///         result,
-    ///         Views,
-    ///     },
-    ///     Query,
+    ///     Entity,
     ///     Registry,
     ///     World,
     /// };
     ///
     /// struct Foo(u32);
     /// struct Bar(bool);
-    /// struct Baz(u32);
     ///
-    /// type Registry = Registry!(Foo, Bar, Baz);
-    ///
-    /// let mut world = World::<Registry>::new();
-    /// let inserted_entity_identifier = world.insert(entity!(Foo(42), Bar(true), Baz(100)));
+    /// let mut world = World::<Registry!(Foo, Bar)>::new();
     ///
-    /// // Note that the views provide implicit filters.
-    /// for result!(foo, baz, entity_identifier) in world
-    ///     .query(Query::<
-    ///         Views!(&mut Foo, &Baz, entity::Identifier),
-    ///         filter::Has<Bar>,
-    ///     >::new())
-    ///     .iter
-    /// {
-    ///     // Allows immutable or mutable access to queried components.
-    ///     foo.0 = baz.0;
-    ///     // Also allows access to entity identifiers.
-    ///     assert_eq!(entity_identifier, inserted_entity_identifier);
-    /// }
+    /// let handle = world.archetype_handle::<Entity!(Foo, Bar), _>();
+    /// let entity_identifier = world.insert_into(&handle, entity!(Foo(42), Bar(false)));
     /// ```
     ///
-    /// For more information about `Views` and `Filter`, see the [`query`] module documentaion.
-    ///
-    /// [`Filter`]: crate::query::filter::Filter
-    /// [`Iterator`]: core::iter::Iterator
-    /// [`query`]: crate::query
-    /// [`Views`]: trait@crate::query::view::Views
-    pub fn query<
-        'a,
-        Views,
-        Filter,
-        ResourceViews,
-        EntryViews,
-        QueryIndices,
-        ResourceViewsIndices,
-        DisjointIndices,
-        EntryIndices,
-    >(
-        &'a mut self,
-        #[allow(unused_variables)] query: Query<Views, Filter, ResourceViews, EntryViews>,
-    ) -> Result<
-        Registry,
-        Resources,
-        result::Iter<'a, Registry, Filter, Views, QueryIndices>,
-        ResourceViews,
-        EntryViews,
-        EntryIndices,
-    >
+    /// [`extend()`]: World::extend()
+    /// [`insert()`]: World::insert()
+    /// [`shrink_to_fit()`]: World::shrink_to_fit()
+    pub fn insert_into<Entity, Indices>(
+        &mut self,
+        handle: &ArchetypeHandle<Registry, Entity>,
+        entity: Entity,
+    ) -> entity::Identifier
     where
-        Views: view::Views<'a>,
-        Registry: ContainsQuery<'a, Filter, Views, QueryIndices>
-            + registry::ContainsViews<'a, EntryViews, EntryIndices>,
-        Resources: ContainsViews<'a, ResourceViews, ResourceViewsIndices>,
-        EntryViews: view::Disjoint<Views, Registry, DisjointIndices> + view::Views<'a>,
+        Registry: ContainsEntity<Entity, Indices>,
     {
-        let world = self as *mut Self;
-        Result {
-            // SAFETY: The views used here are verified to not conflict with the views used for
-            // `entries`.
-            iter: result::Iter::new(unsafe { &mut *world }.archetypes.iter_mut()),
-            resources: self.resources.view(),
-            // SAFETY: The views used here are verified to not conflict with the views used for
-            // `iter`.
-            entries: unsafe { query::Entries::new(world) },
+        self.len += 1;
+
+        let canonical_entity = Registry::canonical(entity);
+
+        // SAFETY: The handle's identifier was created from the entity `Entity`, so the archetype
+        // obtained here is guaranteed to be made up of the components of `Entity`.
+        let archetype = unsafe {
+            self.archetypes.get_mut_or_insert_new_for_entity_hashed::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>(
+                &handle.identifier,
+                handle.hash,
+            )
+        };
+        // SAFETY: The archetype is guaranteed to be made up of the components of `Entity`, and
+        // `self.entity_allocator` is guaranteed to live as long as the archetype.
+        let entity_identifier =
+            unsafe { archetype.push(canonical_entity, &mut self.entity_allocator) };
+        // SAFETY: The entity was just pushed as the last row of the archetype.
+        unsafe {
+            archetype.notify_rows(
+                archetype.len() - 1..archetype.len(),
+                &mut self.observers.on_add,
+            );
         }
+
+        entity_identifier
     }
 
-    /// Query for components contained within the `World` using the given [`ParViews`] `V` and
-    /// [`Filter`] `F`, returning a [`ParallelIterator`] over all components of entities matching
-    /// the query.
+    /// Insert an entity at a specific [`entity::Identifier`].
     ///
-    /// The difference between this method and [`query()`] is that this method allow results to be
-    /// operated on in parallel rather than sequentially.
+    /// Rather than letting the `World` choose the entity's identifier, the entity is stored with
+    /// exactly the provided identifier, including both its index and generation. This is useful
+    /// for deterministically rebuilding a `World`, such as when replaying a log of insertions, so
+    /// that the rebuilt entities have the same identifiers as the originals.
+    ///
+    /// If the identifier's index is beyond any index previously used, the unused indices before it
+    /// become available to future calls to [`insert()`] and similar methods.
+    ///
+    /// # Errors
+    /// Returns [`SpawnError::Occupied`] if an entity is already stored at the identifier's index.
+    ///
+    /// Returns [`SpawnError::GenerationRegression`] if the identifier's generation is not newer
+    /// than the generation of an entity previously stored at the same index. This ensures that
+    /// identifiers of removed entities never refer to a new entity.
+    ///
+    /// In either case, the entity is dropped and the `World` is not modified.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
     ///     entity,
-    ///     query::{
-    ///         filter,
-    ///         result,
-    ///         Views,
-    ///     },
-    ///     Query,
+    ///     world::SpawnError,
     ///     Registry,
     ///     World,
     /// };
-    /// use rayon::iter::ParallelIterator;
     ///
+    /// #[derive(Debug, PartialEq)]
     /// struct Foo(u32);
     /// struct Bar(bool);
-    /// struct Baz(u32);
     ///
-    /// type Registry = Registry!(Foo, Bar, Baz);
+    /// type Registry = Registry!(Foo, Bar);
     ///
     /// let mut world = World::<Registry>::new();
-    /// let inserted_entity_identifier = world.insert(entity!(Foo(42), Bar(true), Baz(100)));
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(false)));
     ///
-    /// // Note that the views provide implicit filters.
-    /// world
-    ///     .par_query(Query::<
-    ///         Views!(&mut Foo, &Baz, entity::Identifier),
-    ///         filter::Has<Bar>,
-    ///     >::new())
-    ///     .iter
-    ///     .for_each(|result!(foo, baz, entity_identifier)| {
-    ///         // Allows immutable or mutable access to queried components.
-    ///         foo.0 = baz.0;
-    ///         // Also allows access to entity identifiers.
-    ///         assert_eq!(entity_identifier, inserted_entity_identifier);
-    ///     });
+    /// let mut rebuilt_world = World::<Registry>::new();
+    /// assert_eq!(
+    ///     rebuilt_world.insert_at(entity_identifier, entity!(Foo(42), Bar(false))),
+    ///     Ok(())
+    /// );
+    /// assert_eq!(
+    ///     rebuilt_world.insert_at(entity_identifier, entity!(Foo(100))),
+    ///     Err(SpawnError::Occupied)
+    /// );
+    ///
+    /// let entity = rebuilt_world.entity_mut(entity_identifier).unwrap();
+    /// assert_eq!(entity.get::<Foo, _>(), Some(&Foo(42)));
     /// ```
     ///
-    /// For more information about `ParViews` and `Filter`, see the [`query`] module documentaion.
-    ///
-    /// [`Filter`]: crate::query::filter::Filter
-    /// [`ParallelIterator`]: rayon::iter::ParallelIterator
-    /// [`ParViews`]: crate::query::view::ParViews
-    /// [`query`]: crate::query
-    /// [`query()`]: World::query()
-    #[cfg(feature = "rayon")]
-    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
-    pub fn par_query<
-        'a,
-        Views,
-        Filter,
-        ResourceViews,
-        EntryViews,
-        QueryIndices,
-        ResourceViewsIndices,
-        DisjointIndices,
-        EntryIndices,
-    >(
-        &'a mut self,
-        #[allow(unused_variables)] query: Query<Views, Filter, ResourceViews, EntryViews>,
-    ) -> Result<
-        Registry,
-        Resources,
-        result::ParIter<'a, Registry, Filter, Views, QueryIndices>,
-        ResourceViews,
-        EntryViews,
-        EntryIndices,
-    >
+    /// [`insert()`]: World::insert()
+    pub fn insert_at<Entity, Indices>(
+        &mut self,
+        entity_identifier: entity::Identifier,
+        entity: Entity,
+    ) -> core::result::Result<(), SpawnError>
     where
-        Views: ParViews<'a>,
-        Registry: ContainsParQuery<'a, Filter, Views, QueryIndices>
-            + registry::ContainsViews<'a, EntryViews, EntryIndices>,
-        Resources: ContainsViews<'a, ResourceViews, ResourceViewsIndices>,
-        EntryViews: view::Disjoint<Views, Registry, DisjointIndices> + view::Views<'a>,
+        Registry: ContainsEntity<Entity, Indices>,
     {
-        let world = self as *mut Self;
-        Result {
-            // SAFETY: The views used here are verified to not conflict with the views used for
-            // `entries`.
-            iter: result::ParIter::new(unsafe { &mut *world }.archetypes.par_iter_mut()),
-            resources: self.resources.view(),
-            // SAFETY: The views used here are verified to not conflict with the views used for
-            // `iter`.
-            entries: unsafe { query::Entries::new(world) },
+        self.entity_allocator.check_vacant(entity_identifier)?;
+
+        self.len += 1;
+
+        let canonical_entity = Registry::canonical(entity);
+
+        // SAFETY: Since the archetype was obtained using the `identifier_buffer` created from the
+        // entity `Entity`, then the entity is guaranteed to be made up of componpents identified
+        // by the archetype's identifier.
+        //
+        // `self.entity_allocator` is guaranteed to live as long as the archetype.
+        let archetype = unsafe {
+            self.archetypes
+                .get_mut_or_insert_new_for_entity::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>()
+        };
+        // SAFETY: The archetype is guaranteed to be made up of the components of `Entity`, and
+        // `self.entity_allocator` is guaranteed to live as long as the archetype. Additionally,
+        // `entity_identifier` was checked to be vacant above.
+        unsafe {
+            archetype.push_at(
+                canonical_entity,
+                entity_identifier,
+                &mut self.entity_allocator,
+            );
+        };
+        // SAFETY: The entity was just pushed as the last row of the archetype.
+        unsafe {
+            archetype.notify_rows(
+                archetype.len() - 1..archetype.len(),
+                &mut self.observers.on_add,
+            );
         }
+
+        Ok(())
     }
 
-    /// Return the claims on each archetype touched by the given query.
+    /// Insert an entity, first reserving capacity for at least `expected_total` entities of the
+    /// same type.
     ///
-    /// # Safety
-    /// The `archetype::IdentifierRef`s over which this iterator iterates must not outlive the
-    /// `Archetypes` to which they belong.
+    /// This behaves the same as [`insert()`], except that if fewer than `expected_total` entities
+    /// of type `Entity` can be stored without reallocating, capacity is reserved for them before
+    /// the entity is inserted, as if by [`reserve()`]. This is useful when entities are produced
+    /// one at a time, such as in a loop, and so can't be inserted together using [`extend()`]. By
+    /// providing the expected total number of entities, reallocation only occurs once rather than
+    /// repeatedly as the entities are inserted.
     ///
-    /// The views and entry views must be compatible with each other.
-    #[cfg(feature = "rayon")]
-    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
-    pub(crate) unsafe fn query_archetype_claims<
-        'a,
-        Views,
-        QueryFilter,
-        Filter,
-        EntryViews,
-        QueryIndices,
-        FilterIndices,
-        EntryViewsIndices,
-    >(
-        &'a mut self,
-    ) -> result::ArchetypeClaims<
-        'a,
-        Registry,
-        Views,
-        QueryFilter,
-        Filter,
-        EntryViews,
-        QueryIndices,
-        FilterIndices,
-        EntryViewsIndices,
-    >
-    where
-        Views: view::Views<'a>,
-        EntryViews: view::Views<'a>,
-        Registry: ContainsFilter<Filter, FilterIndices>
-            + ContainsQuery<'a, QueryFilter, Views, QueryIndices>
-            + registry::ContainsViews<'a, EntryViews, EntryViewsIndices>,
-    {
-        // SAFETY: The safety contract here is upheld by the safety contract of this method.
-        unsafe { result::ArchetypeClaims::new(self.archetypes.iter_mut()) }
-    }
-
-    /// Run a [`System`] over the entities in this `World`.
+    /// Note that `expected_total` is the total number of entities of type `Entity` expected to be
+    /// stored, not the number of additional entities.
+    ///
+    /// # Panics
+    /// Panics if the new capacity for entities of type `Entity` exceeds `isize::MAX` bytes.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
     ///     entity,
-    ///     query::{
-    ///         filter,
-    ///         filter::Filter,
-    ///         result,
-    ///         Result,
-    ///         Views,
-    ///     },
-    ///     registry,
-    ///     system::System,
+    ///     Entity,
     ///     Registry,
     ///     World,
     /// };
     ///
-    /// // Define components.
-    /// struct Foo(usize);
-    /// struct Bar(usize);
+    /// struct Foo(u32);
+    /// struct Bar(bool);
     ///
     /// type Registry = Registry!(Foo, Bar);
     ///
-    /// // Define system.
-    /// struct MySystem;
-    ///
-    /// impl System for MySystem {
-    ///     type Views<'a> = Views!(&'a mut Foo, &'a Bar);
-    ///     type Filter = filter::None;
-    ///     type ResourceViews<'a> = Views!();
-    ///     type EntryViews<'a> = Views!();
+    /// let mut world = World::<Registry>::new();
     ///
-    ///     fn run<'a, R, S, I, E>(
-    ///         &mut self,
-    ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-    ///     ) where
-    ///         R: registry::Registry,
-    ///         I: Iterator<Item = Self::Views<'a>>,
-    ///     {
-    ///         for result!(foo, bar) in query_results.iter {
-    ///             // Increment `Foo` by `Bar`.
-    ///             foo.0 += bar.0;
-    ///         }
-    ///     }
+    /// for i in 0..100 {
+    ///     world.insert_with_hint(entity!(Foo(i), Bar(false)), 100);
     /// }
     ///
-    /// let mut world = World::<Registry>::new();
-    /// world.insert(entity!(Foo(42), Bar(100)));
-    ///
-    /// world.run_system(&mut MySystem);
+    /// assert!(world.capacity::<Entity!(Foo, Bar), _>() >= 100);
     /// ```
     ///
-    /// [`System`]: crate::system::System
-    pub fn run_system<
-        'a,
-        System,
-        QueryIndices,
-        ResourceViewsIndices,
-        DisjointIndices,
-        EntryIndices,
-    >(
-        &'a mut self,
-        system: &mut System,
-    ) where
-        System: system::System,
-        Registry: ContainsQuery<'a, System::Filter, System::Views<'a>, QueryIndices>
-            + registry::ContainsViews<'a, System::EntryViews<'a>, EntryIndices>,
-        Resources: ContainsViews<'a, System::ResourceViews<'a>, ResourceViewsIndices>,
-        System::EntryViews<'a>:
-            view::Disjoint<System::Views<'a>, Registry, DisjointIndices> + view::Views<'a>,
+    /// [`extend()`]: World::extend()
+    /// [`insert()`]: World::insert()
+    /// [`reserve()`]: World::reserve()
+    pub fn insert_with_hint<Entity, Indices>(
+        &mut self,
+        entity: Entity,
+        expected_total: usize,
+    ) -> entity::Identifier
+    where
+        Registry: ContainsEntity<Entity, Indices>,
     {
-        let result = self.query(Query::<
-            System::Views<'a>,
-            System::Filter,
-            System::ResourceViews<'a>,
-            System::EntryViews<'a>,
-        >::new());
-        system.run(result);
+        self.len += 1;
+
+        let canonical_entity = Registry::canonical(entity);
+
+        // SAFETY: Since the archetype was obtained using the `identifier_buffer` created from the
+        // entity `Entity`, then the entity is guaranteed to be made up of componpents identified
+        // by the archetype's identifier.
+        //
+        // `self.entity_allocator` is guaranteed to live as long as the archetype.
+        let archetype = unsafe {
+            self.archetypes
+                .get_mut_or_insert_new_for_entity::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>()
+        };
+        if archetype.capacity() < expected_total {
+            // SAFETY: The archetype was obtained using the canonical form of `Entity`, meaning the
+            // set of components in the canonical entity is the same set as those in the
+            // archetype, in the same order.
+            unsafe {
+                archetype
+                    .reserve::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical>(
+                        expected_total - archetype.len(),
+                    );
+            }
+        }
+        // SAFETY: The archetype is guaranteed to be made up of the components of `Entity`, and
+        // `self.entity_allocator` is guaranteed to live as long as the archetype.
+        let entity_identifier =
+            unsafe { archetype.push(canonical_entity, &mut self.entity_allocator) };
+        // SAFETY: The entity was just pushed as the last row of the archetype.
+        unsafe {
+            archetype.notify_rows(
+                archetype.len() - 1..archetype.len(),
+                &mut self.observers.on_add,
+            );
+        }
+
+        entity_identifier
     }
 
-    /// Run a [`ParSystem`] over the entities in this `World`.
+    /// Insert multiple entities made from the same components, returning a [`Vec`] of
+    /// [`entity::Identifier`]s.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
-    ///     entity,
-    ///     query::{
-    ///         filter,
-    ///         filter::Filter,
-    ///         result,
-    ///         Result,
-    ///         Views,
-    ///     },
-    ///     registry,
-    ///     system::ParSystem,
+    ///     entities,
     ///     Registry,
     ///     World,
     /// };
-    /// use rayon::iter::ParallelIterator;
     ///
-    /// // Define components.
-    /// struct Foo(usize);
-    /// struct Bar(usize);
+    /// struct Foo(u32);
+    /// struct Bar(bool);
     ///
     /// type Registry = Registry!(Foo, Bar);
     ///
-    /// // Define system.
-    /// struct MySystem;
+    /// let mut world = World::<Registry>::new();
     ///
-    /// impl ParSystem for MySystem {
-    ///     type Views<'a> = Views!(&'a mut Foo, &'a Bar);
-    ///     type Filter = filter::None;
-    ///     type ResourceViews<'a> = Views!();
-    ///     type EntryViews<'a> = Views!();
+    /// let entity_identiifers = world.extend(entities![(Foo(1), Bar(false)), (Foo(2), Bar(true))]);
+    /// ```
+    pub fn extend<Entities, Indices>(
+        &mut self,
+        entities: entities::Batch<Entities>,
+    ) -> Vec<entity::Identifier>
+    where
+        Registry: ContainsEntities<Entities, Indices>,
+    {
+        self.extend_iter(entities).collect()
+    }
+
+    /// Insert multiple entities made from the same components, returning an [`Iterator`] over
+    /// their [`entity::Identifier`]s.
     ///
-    ///     fn run<'a, R, S, I, E>(
-    ///         &mut self,
-    ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-    ///     ) where
-    ///         R: registry::Registry,
-    ///         I: ParallelIterator<Item = Self::Views<'a>>,
-    ///     {
-    ///         query_results
-    ///             .iter
-    ///             .for_each(|result!(foo, bar)| foo.0 += bar.0);
-    ///     }
-    /// }
+    /// This behaves the same as [`extend()`], except that the identifiers are not collected into
+    /// a [`Vec`]. Instead, the returned iterator reads them directly from where they are stored
+    /// within the `World`, avoiding an allocation when the identifiers are only needed once or not
+    /// at all. The entities are inserted regardless of whether the iterator is consumed.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entities,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
     ///
     /// let mut world = World::<Registry>::new();
-    /// world.insert(entity!(Foo(42), Bar(100)));
     ///
-    /// world.run_par_system(&mut MySystem);
+    /// let entity_identifiers =
+    ///     world.extend_iter(entities![(Foo(1), Bar(false)), (Foo(2), Bar(true))]);
+    /// assert_eq!(entity_identifiers.len(), 2);
     /// ```
     ///
-    /// [`ParSystem`]: crate::system::ParSystem
-    #[cfg(feature = "rayon")]
-    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
-    pub fn run_par_system<
-        'a,
-        ParSystem,
-        QueryIndices,
-        ResourceViewsIndices,
-        DisjointIndices,
-        EntryIndices,
-    >(
-        &'a mut self,
-        par_system: &mut ParSystem,
-    ) where
-        ParSystem: system::ParSystem,
-        Registry: ContainsParQuery<'a, ParSystem::Filter, ParSystem::Views<'a>, QueryIndices>
-            + registry::ContainsViews<'a, ParSystem::EntryViews<'a>, EntryIndices>,
-        Resources: ContainsViews<'a, ParSystem::ResourceViews<'a>, ResourceViewsIndices>,
-        ParSystem::EntryViews<'a>:
-            view::Disjoint<ParSystem::Views<'a>, Registry, DisjointIndices> + view::Views<'a>,
+    /// [`extend()`]: World::extend()
+    pub fn extend_iter<Entities, Indices>(
+        &mut self,
+        entities: entities::Batch<Entities>,
+    ) -> impl ExactSizeIterator<Item = entity::Identifier> + '_
+    where
+        Registry: ContainsEntities<Entities, Indices>,
     {
-        let result = self.par_query(Query::<
-            ParSystem::Views<'a>,
-            ParSystem::Filter,
-            ParSystem::ResourceViews<'a>,
-            ParSystem::EntryViews<'a>,
-        >::new());
-        par_system.run(result);
+        self.len += entities.len();
+
+        let canonical_entities =
+            // SAFETY: Since `entities` is already a `Batch`, then the canonical entities derived
+            // from `entities` can safely be converted into a batch as well, since the components
+            // will be of the same length.
+            unsafe { entities::Batch::new_unchecked(Registry::canonical(entities.entities)) };
+
+        // SAFETY: Since the archetype was obtained using the `identifier_buffer` created from the
+        // entities `E`, then the entities are guaranteed to be made up of componpents identified
+        // by the archetype's identifier.
+        let archetype = unsafe {
+            self.archetypes
+                .get_mut_or_insert_new_for_entity::<<<Registry as contains::entities::Sealed<Entities, Indices>>::Canonical as entities::Contains>::Entity, <Registry as contains::entities::Sealed<Entities, Indices>>::CanonicalContainments>()
+        };
+        let start = archetype.len();
+        // SAFETY: The archetype is guaranteed to be made up of the components of `Entities`, and
+        // `self.entity_allocator` is guaranteed to live as long as the archetype.
+        unsafe { archetype.extend(canonical_entities, &mut self.entity_allocator) };
+        // SAFETY: The entities were just appended as the last rows of the archetype.
+        unsafe {
+            archetype.notify_rows(start..archetype.len(), &mut self.observers.on_add);
+        }
+
+        // The identifiers of the new entities were appended as the last rows of the archetype.
+        archetype.entity_identifiers().iter().skip(start).copied()
     }
 
-    /// Run a [`Schedule`] over the entities in this `World`.
+    /// Insert multiple entities made from the same components in parallel, returning a [`Vec`] of
+    /// [`entity::Identifier`]s.
+    ///
+    /// This behaves the same as [`extend()`], except that the components are moved into their
+    /// columns in parallel, with each component column being written independently. Allocation of
+    /// the entity identifiers is still done serially. This is most useful for very large batches
+    /// of entities, where the cost of moving the components outweighs the cost of parallelizing.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
-    ///     entity,
-    ///     query::{
-    ///         filter,
-    ///         filter::Filter,
-    ///         result,
-    ///         Result,
-    ///         Views,
-    ///     },
-    ///     registry,
-    ///     system::{
-    ///         schedule,
-    ///         schedule::task,
-    ///         Schedule,
-    ///         System,
-    ///     },
+    ///     entities,
     ///     Registry,
     ///     World,
     /// };
     ///
-    /// // Define components.
-    /// struct Foo(usize);
-    /// struct Bar(usize);
+    /// #[derive(Clone)]
+    /// struct Foo(u32);
+    /// #[derive(Clone)]
+    /// struct Bar(bool);
     ///
     /// type Registry = Registry!(Foo, Bar);
     ///
-    /// // Define systems.
-    /// struct SystemA;
-    /// struct SystemB;
-    ///
-    /// impl System for SystemA {
-    ///     type Views<'a> = Views!(&'a mut Foo);
-    ///     type Filter = filter::None;
-    ///     type ResourceViews<'a> = Views!();
-    ///     type EntryViews<'a> = Views!();
-    ///
-    ///     fn run<'a, R, S, I, E>(
-    ///         &mut self,
-    ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-    ///     ) where
-    ///         R: registry::Registry,
-    ///         I: Iterator<Item = Self::Views<'a>>,
-    ///     {
-    ///         for result!(foo) in query_results.iter {
-    ///             foo.0 += 1;
-    ///         }
-    ///     }
-    /// }
-    ///
-    /// impl System for SystemB {
-    ///     type Views<'a> = Views!(&'a mut Bar);
-    ///     type Filter = filter::None;
-    ///     type ResourceViews<'a> = Views!();
-    ///     type EntryViews<'a> = Views!();
-    ///
-    ///     fn run<'a, R, S, I, E>(
-    ///         &mut self,
-    ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-    ///     ) where
-    ///         R: registry::Registry,
-    ///         I: Iterator<Item = Self::Views<'a>>,
-    ///     {
-    ///         for result!(bar) in query_results.iter {
-    ///             bar.0 += 1;
-    ///         }
-    ///     }
-    /// }
-    ///
-    /// // Define schedule.
-    /// let mut schedule = schedule!(task::System(SystemA), task::System(SystemB));
-    ///
     /// let mut world = World::<Registry>::new();
-    /// world.insert(entity!(Foo(42), Bar(100)));
     ///
-    /// world.run_schedule(&mut schedule);
+    /// let entity_identifiers = world.par_extend(entities!((Foo(42), Bar(true)); 100_000));
     /// ```
     ///
-    /// [`Schedule`]: trait@crate::system::schedule::Schedule
+    /// [`extend()`]: World::extend()
     #[cfg(feature = "rayon")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
-    pub fn run_schedule<'a, Schedule, Indices>(&mut self, schedule: &'a mut Schedule)
+    pub fn par_extend<Entities, Indices>(
+        &mut self,
+        entities: entities::Batch<Entities>,
+    ) -> Vec<entity::Identifier>
     where
-        Resources: resource::Resources,
-        Schedule: schedule::Schedule<'a, Registry, Resources, Indices>,
+        Registry: ContainsParEntities<Entities, Indices>,
     {
-        schedule
-            .as_stages()
-            .run(self, Schedule::Stages::new_has_run());
+        self.len += entities.len();
+
+        let canonical_entities =
+            // SAFETY: Since `entities` is already a `Batch`, then the canonical entities derived
+            // from `entities` can safely be converted into a batch as well, since the components
+            // will be of the same length.
+            unsafe { entities::Batch::new_unchecked(Registry::par_canonical(entities.entities)) };
+
+        // SAFETY: Since the archetype was obtained using the `identifier_buffer` created from the
+        // entities `E`, then the entities are guaranteed to be made up of componpents identified
+        // by the archetype's identifier.
+        let archetype = unsafe {
+            self.archetypes
+                .get_mut_or_insert_new_for_entity::<<<Registry as contains::entities::Sealed<Entities, Indices>>::Canonical as entities::Contains>::Entity, <Registry as contains::entities::Sealed<Entities, Indices>>::CanonicalContainments>()
+        };
+        let start = archetype.len();
+        // SAFETY: The archetype is guaranteed to be made up of the components of `Entities`, and
+        // `self.entity_allocator` is guaranteed to live as long as the archetype.
+        unsafe { archetype.par_extend(canonical_entities, &mut self.entity_allocator) };
+        // SAFETY: The entities were just appended as the last rows of the archetype.
+        unsafe {
+            archetype.notify_rows(start..archetype.len(), &mut self.observers.on_add);
+        }
+
+        archetype
+            .entity_identifiers()
+            .iter()
+            .skip(start)
+            .copied()
+            .collect()
     }
 
-    /// Returns `true` if the world contains an entity identified by `entity_identifier`.
+    /// Insert entities made up of differing sets of components, returning their
+    /// [`entity::Identifier`]s.
+    ///
+    /// Each entity is a mixed entity: a heterogeneous list of `Option`al components, where only
+    /// the components that are `Some` are stored for the entity. This allows a single source of
+    /// entities to produce entities of different shapes, which are each stored alongside other
+    /// entities with the same components. The returned identifiers are in the same order as the
+    /// provided entities.
+    ///
+    /// Entities are grouped by their components before being inserted, so that storage for each
+    /// group is located and reserved only once. However, since the components present in each
+    /// entity are only known at runtime, every entity must first be inspected and sorted into its
+    /// group, which requires collecting the entities into a temporary buffer. When all entities
+    /// are made up of the same components, [`extend()`] avoids this overhead and should be
+    /// preferred.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
     ///     entity,
+    ///     Entity,
     ///     Registry,
     ///     World,
     /// };
     ///
-    /// struct Foo(usize);
+    /// struct Foo(u32);
     /// struct Bar(bool);
     ///
     /// type Registry = Registry!(Foo, Bar);
     ///
     /// let mut world = World::<Registry>::new();
-    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
     ///
-    /// assert!(world.contains(entity_identifier));
-    /// world.remove(entity_identifier);
-    /// assert!(!world.contains(entity_identifier));
+    /// let entity_identifiers = world.insert_batch_mixed((0..10).map(|i| {
+    ///     entity!(
+    ///         Some(Foo(i)),
+    ///         if i % 2 == 0 { Some(Bar(true)) } else { None }
+    ///     )
+    /// }));
+    ///
+    /// assert_eq!(entity_identifiers.len(), 10);
+    /// assert_eq!(world.len_of::<Entity!(Foo, Bar), _>(), 5);
+    /// assert_eq!(world.len_of::<Entity!(Foo), _>(), 5);
     /// ```
-    #[must_use]
-    pub fn contains(&self, entity_identifier: entity::Identifier) -> bool {
-        self.entity_allocator.is_active(entity_identifier)
+    ///
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    /// [`extend()`]: World::extend()
+    pub fn insert_batch_mixed<Entities, Entity, Indices>(
+        &mut self,
+        entities: Entities,
+    ) -> Vec<entity::Identifier>
+    where
+        Entities: IntoIterator<Item = Entity>,
+        Registry: ContainsMixedEntity<Entity, Indices>,
+    {
+        let mut canonical_entities = entities
+            .into_iter()
+            .map(|entity| {
+                let canonical_entity = <Registry as contains::mixed_entity::Sealed<
+                    Entity,
+                    Indices,
+                >>::canonical(entity);
+                let mut raw_identifier_buffer = vec![0; (Registry::LEN + 7) / 8];
+                // SAFETY: `raw_identifier_buffer` contains a bit for each component in the
+                // registry, and `canonical_entity` contains an optional value for each component
+                // in the registry.
+                unsafe {
+                    contains::mixed_entity::Storage::set_identifier_bits(
+                        &canonical_entity,
+                        &mut raw_identifier_buffer,
+                        0,
+                    );
+                }
+                (raw_identifier_buffer, canonical_entity)
+            })
+            .enumerate()
+            .collect::<Vec<_>>();
+        // Group entities with the same components together.
+        canonical_entities.sort_by(|(_, (a, _)), (_, (b, _))| a.cmp(b));
+
+        let mut entity_identifiers = Vec::with_capacity(canonical_entities.len());
+        let mut canonical_entities = canonical_entities.into_iter().peekable();
+        while let Some((index, (raw_identifier_buffer, canonical_entity))) =
+            canonical_entities.next()
+        {
+            let mut group = vec![(index, canonical_entity)];
+            while let Some((index, (_, canonical_entity))) =
+                canonical_entities.next_if(|(_, (next_raw_identifier_buffer, _))| {
+                    *next_raw_identifier_buffer == raw_identifier_buffer
+                })
+            {
+                group.push((index, canonical_entity));
+            }
+            self.len += group.len();
+
+            let archetype = self.archetypes.get_mut_or_insert_new(
+                // SAFETY: `raw_identifier_buffer` has a length of `(Registry::LEN + 7) / 8`, and
+                // only contains set bits for components within the registry.
+                unsafe { archetype::Identifier::new(raw_identifier_buffer) },
+            );
+            let start = archetype.len();
+            // SAFETY: The components present in each entity in `group` are exactly the
+            // components identified by the archetype's identifier, since the identifier was
+            // created from them.
+            unsafe {
+                archetype.reserve_mixed(&group.first().unwrap_unchecked().1, group.len());
+            }
+            for (index, canonical_entity) in group {
+                entity_identifiers.push((
+                    index,
+                    // SAFETY: The components present in `canonical_entity` are exactly the
+                    // components identified by the archetype's identifier. Also,
+                    // `self.entity_allocator` is guaranteed to live as long as the archetype.
+                    unsafe { archetype.push_mixed(canonical_entity, &mut self.entity_allocator) },
+                ));
+            }
+            // SAFETY: The entities were just pushed as the last rows of the archetype.
+            unsafe {
+                archetype.notify_rows(start..archetype.len(), &mut self.observers.on_add);
+            }
+        }
+
+        // Return the identifiers in the order the entities were provided.
+        entity_identifiers.sort_unstable_by_key(|&(index, _)| index);
+        entity_identifiers
+            .into_iter()
+            .map(|(_, entity_identifier)| entity_identifier)
+            .collect()
     }
 
-    /// Gets an [`Entry`] for the entity associated with an [`entity::Identifier`] for
-    /// component-level manipulation.
+    /// Moves all entities from `other` into this `World`, consuming `other`.
     ///
-    /// If no such entity exists, [`None`] is returned.
+    /// New [`entity::Identifier`]s are allocated for each moved entity. The returned pairs map
+    /// each entity's identifier within `other` to its newly allocated identifier, allowing
+    /// external references to the moved entities to be updated. Entities are moved an entire
+    /// archetype at a time, with each component column appended to the matching archetype of this
+    /// `World`.
+    ///
+    /// This is useful for combining entities spawned in separate staging `World`s, such as
+    /// `World`s built on separate threads, into a single `World`.
+    ///
+    /// The resources and observers of `other` are discarded. The resources of this `World` are
+    /// left unchanged.
     ///
     /// # Example
     /// ``` rust
@@ -792,509 +1036,10554 @@ where
     ///     World,
     /// };
     ///
+    /// #[derive(Debug, PartialEq)]
     /// struct Foo(u32);
-    /// struct Bar(bool);
     ///
-    /// type Registry = Registry!(Foo, Bar);
+    /// type Registry = Registry!(Foo);
     ///
     /// let mut world = World::<Registry>::new();
-    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    /// world.insert(entity!(Foo(1)));
     ///
-    /// let mut entry = world.entry(entity_identifier).unwrap();
-    /// // Remove the `Bar` component.
-    /// entry.remove::<Bar, _>();
-    /// ```
+    /// let mut staging = World::<Registry>::new();
+    /// let staged_entity_identifier = staging.insert(entity!(Foo(2)));
     ///
-    /// [`Entry`]: crate::world::Entry
-    /// [`None`]: Option::None
-    #[must_use]
-    pub fn entry(
+    /// let mapping = world.merge(staging);
+    ///
+    /// assert_eq!(world.len(), 2);
+    /// assert_eq!(mapping.len(), 1);
+    /// assert_eq!(mapping[0].0, staged_entity_identifier);
+    /// assert!(world.contains(mapping[0].1));
+    /// ```
+    pub fn merge<OtherResources, OtherS>(
         &mut self,
-        entity_identifier: entity::Identifier,
-    ) -> Option<Entry<Registry, Resources>> {
-        self.entity_allocator
-            .get(entity_identifier)
-            .map(|location| Entry::new(self, location))
+        mut other: World<Registry, OtherResources, OtherS>,
+    ) -> Vec<(entity::Identifier, entity::Identifier)>
+    where
+        OtherS: BuildHasher,
+    {
+        self.len += other.len;
+        other.len = 0;
+        // SAFETY: `self.entity_allocator` is guaranteed to live as long as `self.archetypes`.
+        unsafe {
+            self.archetypes.append(
+                &mut other.archetypes,
+                &mut self.entity_allocator,
+                &mut self.observers.on_add,
+            )
+        }
     }
 
-    /// Remove the entity associated with an [`entity::Identifier`].
+    /// Converts this `World` into a `World` using the registry `NewRegistry`, which must contain
+    /// every component of this `World`'s `Registry`.
     ///
-    /// If the entity has already been removed, this method will do nothing.
+    /// This is useful when refactoring, such as when new components are added to a `Registry`.
+    /// Every entity keeps the same components and the same [`entity::Identifier`]. Components in
+    /// `NewRegistry` that are not in the old `Registry` are not present on any existing entity;
+    /// they can be added afterward, for example using [`add_component_to_all()`]. The components
+    /// of `NewRegistry` may be in a different order than in the old `Registry`.
+    ///
+    /// Entities are migrated an entire archetype at a time, with each component column moved
+    /// into place without copying the components themselves. The resources and observers are
+    /// kept.
+    ///
+    /// Migrating to a registry that does not contain every component of the old `Registry` fails
+    /// to compile.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
     ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
     ///     Registry,
     ///     World,
     /// };
     ///
-    /// struct Foo(u32);
-    /// struct Bar(bool);
+    /// struct Position(f32);
+    /// struct Velocity(f32);
+    /// struct Acceleration(f32);
     ///
-    /// type Registry = Registry!(Foo, Bar);
+    /// let mut world = World::<Registry!(Position, Velocity)>::new();
+    /// let entity_identifier = world.insert(entity!(Position(1.0), Velocity(2.0)));
     ///
-    /// let mut world = World::<Registry>::new();
-    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    /// let mut world = world.migrate::<Registry!(Acceleration, Velocity, Position), _>();
+    /// world
+    ///     .entry(entity_identifier)
+    ///     .unwrap()
+    ///     .add(Acceleration(0.5));
     ///
-    /// world.remove(entity_identifier);
+    /// for result!(position, velocity, acceleration) in world
+    ///     .query(Query::<Views!(&Position, &Velocity, &Acceleration)>::new())
+    ///     .iter
+    /// {
+    ///     assert_eq!(position.0, 1.0);
+    ///     assert_eq!(velocity.0, 2.0);
+    ///     assert_eq!(acceleration.0, 0.5);
+    /// }
     /// ```
-    pub fn remove(&mut self, entity_identifier: entity::Identifier) {
-        // Get location of entity.
-        if let Some(location) = self.entity_allocator.get(entity_identifier) {
-            // Remove row from Archetype.
-            // SAFETY: `self.entity_allocator` contains entries for the entities stored in this
-            // world's archetypes. Also, `location.index` is invariantly guaranteed to be a valid
-            // index in the archetype.
-            unsafe {
-                self.archetypes
-                    .get_unchecked_mut(location.identifier)
-                    .remove_row_unchecked(location.index, &mut self.entity_allocator);
-            }
-            // Free slot in entity allocator.
-            // SAFETY: It was verified above that `self.entity_allocator` contains a valid slot for
-            // `entity_identifier`.
-            unsafe {
-                self.entity_allocator.free_unchecked(entity_identifier);
-            }
+    ///
+    /// [`add_component_to_all()`]: World::add_component_to_all()
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    #[must_use]
+    pub fn migrate<NewRegistry, Indices>(self) -> World<NewRegistry, Resources, S>
+    where
+        NewRegistry: ContainsRegistry<Registry, Indices>,
+        S: Clone,
+    {
+        let mut positions = Vec::with_capacity(Registry::LEN);
+        <NewRegistry as contains::registry::Sealed<Registry, Indices>>::extend_component_positions(
+            &mut positions,
+        );
 
-            self.len -= 1;
-        }
+        // SAFETY: `positions` contains, for each component in `Registry` in order, the index of
+        // that component within `NewRegistry`.
+        let archetypes = unsafe { self.archetypes.migrate::<NewRegistry>(&positions) };
+        // SAFETY: Migrating the archetypes keeps every entity at the same row. Also, the
+        // allocator and the archetypes are stored together in the returned `World`, so the
+        // allocator does not outlive the archetypes.
+        let entity_allocator = unsafe { self.entity_allocator.migrate(&archetypes) };
+
+        let mut world =
+            World::from_raw_parts(archetypes, entity_allocator, self.len, self.resources);
+        world.observers = self.observers;
+        world.auto_compact = self.auto_compact;
+        world
     }
 
-    /// Removes all entities.
+    /// Query for components contained within the `World` using the given [`Views`] `V` and
+    /// [`Filter`] `F`, returning an [`Iterator`] over all components of entities matching the
+    /// query.
     ///
-    /// Keeps the allocated memory for reuse.
+    /// Note that the order of the entities returned by a query is not specified.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
     ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
     ///     Registry,
     ///     World,
     /// };
     ///
-    /// struct Foo(usize);
+    /// struct Foo(u32);
     /// struct Bar(bool);
+    /// struct Baz(u32);
     ///
-    /// type Registry = Registry!(Foo, Bar);
+    /// type Registry = Registry!(Foo, Bar, Baz);
     ///
     /// let mut world = World::<Registry>::new();
-    /// world.insert(entity!(Foo(42), Bar(true)));
+    /// let inserted_entity_identifier = world.insert(entity!(Foo(42), Bar(true), Baz(100)));
     ///
-    /// world.clear();
+    /// // Note that the views provide implicit filters.
+    /// for result!(foo, baz, entity_identifier) in world
+    ///     .query(Query::<
+    ///         Views!(&mut Foo, &Baz, entity::Identifier),
+    ///         filter::Has<Bar>,
+    ///     >::new())
+    ///     .iter
+    /// {
+    ///     // Allows immutable or mutable access to queried components.
+    ///     foo.0 = baz.0;
+    ///     // Also allows access to entity identifiers.
+    ///     assert_eq!(entity_identifier, inserted_entity_identifier);
+    /// }
     /// ```
-    pub fn clear(&mut self) {
-        // SAFETY: `self.entity_allocator` contains entries for the entities stored in this world's
-        // archetypes.
-        unsafe {
-            self.archetypes.clear(&mut self.entity_allocator);
-        }
-        self.len = 0;
-    }
-
-    /// Returns the number of entities in the world.
-    ///
-    /// # Example
-    /// ``` rust
-    /// use brood::{entities, Registry, World};
     ///
-    /// #[derive(Clone)]
-    /// struct Foo(usize);
-    /// #[derive(Clone)]
-    /// struct Bar(bool);
-    ///
-    /// type Registry = Registry!(Foo, Bar);
-    ///
-    /// let mut world = World::<Registry>::new();
-    /// world.extend(entities!((Foo(42), Bar(false)); 100));
+    /// For more information about `Views` and `Filter`, see the [`query`] module documentaion.
     ///
-    /// assert_eq!(world.len(), 100);
-    /// ```
-    #[must_use]
-    pub fn len(&self) -> usize {
-        self.len
+    /// [`Filter`]: crate::query::filter::Filter
+    /// [`Iterator`]: core::iter::Iterator
+    /// [`query`]: crate::query
+    /// [`Views`]: trait@crate::query::view::Views
+    pub fn query<
+        'a,
+        Views,
+        Filter,
+        ResourceViews,
+        EntryViews,
+        QueryIndices,
+        ResourceViewsIndices,
+        DisjointIndices,
+        EntryIndices,
+    >(
+        &'a mut self,
+        #[allow(unused_variables)] query: Query<Views, Filter, ResourceViews, EntryViews>,
+    ) -> Result<
+        Registry,
+        Resources,
+        result::Iter<'a, Registry, Filter, Views, QueryIndices>,
+        ResourceViews,
+        EntryViews,
+        EntryIndices,
+    >
+    where
+        Views: view::Views<'a>,
+        Registry: ContainsQuery<'a, Filter, Views, QueryIndices>
+            + registry::ContainsViews<'a, EntryViews, EntryIndices>,
+        Resources: ContainsViews<'a, ResourceViews, ResourceViewsIndices>,
+        EntryViews: view::Disjoint<Views, Registry, DisjointIndices> + view::Views<'a>,
+    {
+        let world = self as *mut Self;
+        Result {
+            // SAFETY: The views used here are verified to not conflict with the views used for
+            // `entries`.
+            iter: result::Iter::new(unsafe { &mut *world }.archetypes.iter_mut()),
+            resources: self.resources.view(),
+            // SAFETY: The views used here are verified to not conflict with the views used for
+            // `iter`.
+            entries: unsafe { Entries::new(world) },
+        }
     }
 
-    /// Returns `true` if the world contains no entities.
+    /// Query for components contained within the `World`, only viewing entities if `gate` returns
+    /// `true`.
+    ///
+    /// `gate` is called once with the viewed resources of the query before any archetypes are
+    /// iterated. If it returns `false`, the returned iterator will not yield any entities. The
+    /// viewed resources and [`Entries`] are still returned as normal. This allows a query to be
+    /// made conditional on the state of the `World`'s resources without any early-return
+    /// boilerplate.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
     ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     resources,
+    ///     Query,
     ///     Registry,
     ///     World,
     /// };
     ///
-    /// struct Foo(usize);
-    /// struct Bar(bool);
-    ///
-    /// type Registry = Registry!(Foo, Bar);
-    ///
-    /// let mut world = World::<Registry>::new();
+    /// struct Position(u32);
+    /// struct Paused(bool);
     ///
-    /// assert!(world.is_empty());
+    /// let mut world = World::<Registry!(Position), _>::with_resources(resources!(Paused(true)));
+    /// world.insert(entity!(Position(0)));
     ///
-    /// world.insert(entity!(Foo(42), Bar(false)));
+    /// let count = world
+    ///     .query_gated(
+    ///         Query::<Views!(&mut Position), filter::None, Views!(&Paused)>::new(),
+    ///         |result!(paused)| !paused.0,
+    ///     )
+    ///     .iter
+    ///     .count();
     ///
-    /// assert!(!world.is_empty());
+    /// assert_eq!(count, 0);
     /// ```
-    #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-
-    /// Shrinks the allocated capacity of the internal storage as much as possible.
-    ///
-    /// # Example
-    /// ``` rust
-    /// use brood::{entities, Registry, World};
-    ///
-    /// #[derive(Clone)]
-    /// struct Foo(usize);
-    /// #[derive(Clone)]
-    /// struct Bar(bool);
-    ///
-    /// type Registry = Registry!(Foo, Bar);
-    ///
-    /// let mut world = World::<Registry>::new();
-    ///
-    /// world.extend(entities!((Foo(42), Bar(false)); 10));
-    /// world.clear();
-    /// world.extend(entities!((Foo(42), Bar(false)); 3));
     ///
-    /// // This will reduce the current allocation.
-    /// world.shrink_to_fit();
-    /// ```
-    pub fn shrink_to_fit(&mut self) {
-        self.archetypes.shrink_to_fit();
-        self.entity_allocator.shrink_to_fit();
+    /// [`Entries`]: crate::query::Entries
+    pub fn query_gated<
+        'a,
+        Views,
+        Filter,
+        ResourceViews,
+        EntryViews,
+        QueryIndices,
+        ResourceViewsIndices,
+        DisjointIndices,
+        EntryIndices,
+        Gate,
+    >(
+        &'a mut self,
+        #[allow(unused_variables)] query: Query<Views, Filter, ResourceViews, EntryViews>,
+        gate: Gate,
+    ) -> Result<
+        Registry,
+        Resources,
+        result::Iter<'a, Registry, Filter, Views, QueryIndices>,
+        ResourceViews,
+        EntryViews,
+        EntryIndices,
+    >
+    where
+        Views: view::Views<'a>,
+        Registry: ContainsQuery<'a, Filter, Views, QueryIndices>
+            + registry::ContainsViews<'a, EntryViews, EntryIndices>,
+        Resources: ContainsViews<'a, ResourceViews, ResourceViewsIndices>,
+        EntryViews: view::Disjoint<Views, Registry, DisjointIndices> + view::Views<'a>,
+        Gate: FnOnce(&ResourceViews) -> bool,
+    {
+        let world = self as *mut Self;
+        // SAFETY: The views used here are verified to not conflict with the views used for
+        // `entries`.
+        let mut archetypes_iter = unsafe { &mut *world }.archetypes.iter_mut();
+        let resources = self.resources.view();
+        if !gate(&resources) {
+            // No archetypes are visited if the gate is closed.
+            archetypes_iter.truncate(0);
+        }
+        Result {
+            iter: result::Iter::new(archetypes_iter),
+            resources,
+            // SAFETY: The views used here are verified to not conflict with the views used for
+            // `iter`.
+            entries: unsafe { Entries::new(world) },
+        }
     }
 
-    /// Reserve capacity for at least `additional` more entities of type `E`.
+    /// Query for components contained within the `World`, visiting archetypes in a deterministic
+    /// order.
     ///
-    /// Note that the capacity is reserved for all future entities that contain the components of
-    /// `E`, regardless of order.
+    /// This behaves the same as [`query()`], except that the returned [`result::Ordered`]
+    /// iterator visits the archetypes matching the query in order of their identifiers. Entities
+    /// within an archetype are visited in the order in which they are stored. Therefore, the order
+    /// of the results only depends on the sequence of operations performed on the `World`, and not
+    /// on the internal hashing of archetypes.
     ///
-    /// # Panics
-    /// Panics if the new capacity for entities of type `E` exceeds `isize::MAX` bytes.
+    /// This requires collecting and sorting the matching archetypes before iterating, so
+    /// [`query()`] should be preferred when the order of the results does not matter.
     ///
     /// # Example
     /// ``` rust
     /// use brood::{
-    ///     Entity,
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
     ///     Registry,
     ///     World,
     /// };
     ///
-    /// struct Foo(usize);
+    /// struct Foo(u32);
     /// struct Bar(bool);
     ///
     /// type Registry = Registry!(Foo, Bar);
     ///
-    /// let mut world = World::<Registry>::new();
+    /// let mut world_a = World::<Registry>::new();
+    /// world_a.insert(entity!(Foo(1), Bar(true)));
+    /// world_a.insert(entity!(Foo(2)));
     ///
-    /// world.reserve::<Entity!(Foo, Bar), _>(10);
+    /// let mut world_b = World::<Registry>::new();
+    /// world_b.insert(entity!(Foo(1), Bar(true)));
+    /// world_b.insert(entity!(Foo(2)));
+    ///
+    /// let foos_a = world_a
+    ///     .query_ordered(Query::<Views!(&Foo)>::new())
+    ///     .iter
+    ///     .map(|result!(foo)| foo.0)
+    ///     .collect::<Vec<_>>();
+    /// let foos_b = world_b
+    ///     .query_ordered(Query::<Views!(&Foo)>::new())
+    ///     .iter
+    ///     .map(|result!(foo)| foo.0)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(foos_a, foos_b);
     /// ```
-    pub fn reserve<Entity, Indices>(&mut self, additional: usize)
+    ///
+    /// [`query()`]: crate::World::query()
+    /// [`result::Ordered`]: crate::query::result::Ordered
+    pub fn query_ordered<
+        'a,
+        Views,
+        Filter,
+        ResourceViews,
+        EntryViews,
+        QueryIndices,
+        ResourceViewsIndices,
+        DisjointIndices,
+        EntryIndices,
+    >(
+        &'a mut self,
+        #[allow(unused_variables)] query: Query<Views, Filter, ResourceViews, EntryViews>,
+    ) -> Result<
+        Registry,
+        Resources,
+        result::Ordered<'a, Registry, Filter, Views, QueryIndices>,
+        ResourceViews,
+        EntryViews,
+        EntryIndices,
+    >
     where
-        Registry: ContainsEntity<Entity, Indices>,
+        Views: view::Views<'a>,
+        Registry: ContainsQuery<'a, Filter, Views, QueryIndices>
+            + registry::ContainsViews<'a, EntryViews, EntryIndices>,
+        Resources: ContainsViews<'a, ResourceViews, ResourceViewsIndices>,
+        EntryViews: view::Disjoint<Views, Registry, DisjointIndices> + view::Views<'a>,
     {
-        // SAFETY: Since the canonical entity form is used, the archetype obtained is guaranteed to
-        // be the unique archetype for entities of type `Entity`.
-        //
-        // Additionally, the same entity type is used for the call to `reserve`, meaning that the
-        // set of components in the entity are guaranteed to be the same set as those in the
-        // archetype.
-        unsafe {
-            self.archetypes
-                .get_mut_or_insert_new_for_entity::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>()
-                .reserve::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical>(additional);
+        let world = self as *mut Self;
+        Result {
+            // SAFETY: The views used here are verified to not conflict with the views used for
+            // `entries`.
+            iter: result::Ordered::new(unsafe { &mut *world }.archetypes.iter_mut()),
+            resources: self.resources.view(),
+            // SAFETY: The views used here are verified to not conflict with the views used for
+            // `iter`.
+            entries: unsafe { Entries::new(world) },
         }
     }
 
-    /// View a single resource immutably.
+    /// Query for components contained within the `World`, using a cache to find the matching
+    /// archetypes.
     ///
-    /// The `Index` parameter can be inferred.
+    /// This behaves the same as [`query()`], except that the archetypes matching the query are
+    /// cached within the `World`. Running the same query again, such as once every frame, then
+    /// looks up the cached archetypes rather than checking every archetype in the `World`. The
+    /// cache is keyed by the query's views and filter, and is cleared whenever an archetype is
+    /// added to or removed from the `World`.
+    ///
+    /// The cache requires an allocation for each distinct query it is used with, so [`query()`]
+    /// should be preferred for queries that are only run once.
     ///
     /// # Example
-    /// ```
+    /// ``` rust
     /// use brood::{
-    ///     resources,
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
     ///     Registry,
     ///     World,
     /// };
     ///
-    /// #[derive(Debug, PartialEq)]
-    /// struct Resource(u32);
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    /// struct Baz(f64);
     ///
-    /// let world = World::<Registry!(), _>::with_resources(resources!(Resource(100)));
+    /// type Registry = Registry!(Foo, Bar, Baz);
     ///
-    /// assert_eq!(world.get::<Resource, _>(), &Resource(100));
-    /// ```
-    pub fn get<Resource, Index>(&self) -> &Resource
-    where
-        Resources: ContainsResource<Resource, Index>,
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(1), Bar(true)));
+    /// world.insert(entity!(Foo(2), Baz(1.5)));
+    ///
+    /// for result!(foo) in world
+    ///     .query_cached(Query::<Views!(&mut Foo), filter::Has<Bar>>::new())
+    ///     .iter
+    /// {
+    ///     foo.0 += 1;
+    /// }
+    ///
+    /// // Adding an entity with a new archetype clears the cache.
+    /// world.insert(entity!(Foo(3), Bar(false), Baz(2.5)));
+    ///
+    /// assert_eq!(
+    ///     world
+    ///         .query_cached(Query::<Views!(&mut Foo), filter::Has<Bar>>::new())
+    ///         .iter
+    ///         .count(),
+    ///     2
+    /// );
+    /// ```
+    ///
+    /// [`query()`]: crate::World::query()
+    pub fn query_cached<
+        'a,
+        Views,
+        Filter,
+        ResourceViews,
+        EntryViews,
+        QueryIndices,
+        ResourceViewsIndices,
+        DisjointIndices,
+        EntryIndices,
+    >(
+        &'a mut self,
+        #[allow(unused_variables)] query: Query<Views, Filter, ResourceViews, EntryViews>,
+    ) -> Result<
+        'a,
+        Registry,
+        Resources,
+        result::Cached<'a, Registry, Filter, Views, QueryIndices>,
+        ResourceViews,
+        EntryViews,
+        EntryIndices,
+    >
+    where
+        Views: view::Views<'a> + filter::Signature,
+        Filter: filter::Signature,
+        Registry: ContainsQuery<'a, Filter, Views, QueryIndices>
+            + registry::ContainsViews<'a, EntryViews, EntryIndices>,
+        Resources: ContainsViews<'a, ResourceViews, ResourceViewsIndices>,
+        EntryViews: view::Disjoint<Views, Registry, DisjointIndices> + view::Views<'a>,
     {
-        self.resources.get()
+        let world = self as *mut Self;
+        Result {
+            // SAFETY: The views used here are verified to not conflict with the views used for
+            // `entries`. Additionally, the cached archetypes are guaranteed to match the query.
+            iter: unsafe {
+                result::Cached::new(
+                    (*world)
+                        .archetypes
+                        .cached_query::<Views, Filter, QueryIndices>(),
+                )
+            },
+            resources: self.resources.view(),
+            // SAFETY: The views used here are verified to not conflict with the views used for
+            // `iter`.
+            entries: unsafe { Entries::new(world) },
+        }
     }
 
-    /// View a single resource mutably.
+    /// Calls `function` on the components of each entity matching the given [`Views`] `V` and
+    /// [`Filter`] `F`.
     ///
-    /// The `Index` parameter can be inferred.
+    /// This is a shortcut for calling `for_each()` on the iterator returned by [`query()`], for
+    /// when neither resources nor [`Entries`] need to be viewed.
     ///
     /// # Example
-    /// ```
+    /// ``` rust
     /// use brood::{
-    ///     resources,
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
     ///     Registry,
     ///     World,
     /// };
     ///
-    /// #[derive(Debug, PartialEq)]
-    /// struct Resource(u32);
+    /// struct Foo(u32);
+    /// struct Bar(bool);
     ///
-    /// let mut world = World::<Registry!(), _>::with_resources(resources!(Resource(100)));
+    /// type Registry = Registry!(Foo, Bar);
     ///
-    /// world.get_mut::<Resource, _>().0 *= 2;
-    /// assert_eq!(world.get::<Resource, _>(), &Resource(200));
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// world.for_each::<Views!(&mut Foo, &Bar), filter::None, _, _>(|result!(foo, bar)| {
+    ///     if bar.0 {
+    ///         foo.0 += 1;
+    ///     }
+    /// });
     /// ```
-    pub fn get_mut<Resource, Index>(&mut self) -> &mut Resource
+    ///
+    /// [`Entries`]: crate::query::Entries
+    /// [`Filter`]: crate::query::filter::Filter
+    /// [`query()`]: World::query()
+    /// [`Views`]: trait@crate::query::view::Views
+    pub fn for_each<'a, Views, Filter, Function, Indices>(&'a mut self, function: Function)
     where
-        Resources: ContainsResource<Resource, Index>,
+        Views: view::Views<'a>,
+        Registry: ContainsQuery<'a, Filter, Views, Indices>,
+        Function: FnMut(Views),
     {
-        self.resources.get_mut()
+        result::Iter::<Registry, Filter, Views, Indices>::new(self.archetypes.iter_mut())
+            .for_each(function);
     }
 
-    /// View multiple resources at once.
+    /// Calls `function` on every pairing of the components of an entity matching one query with
+    /// the components of an entity matching another query.
     ///
-    /// All generic parameters besides `Views` can be omitted.
+    /// This is useful for interactions between two groups of entities, such as checking every
+    /// projectile against every target. The results of `other_query` are collected first, and are
+    /// then paired with each result of `query` in turn. As such, `function` is called once for
+    /// every combination of results, meaning the cost is proportional to the product of the number
+    /// of entities matching each query.
+    ///
+    /// Both sets of views are accessed at the same time, so `OtherViews` must be disjoint with
+    /// `Views`: the two sets of views cannot both view the same component if either of them views
+    /// it mutably. This is verified at compile time. An entity matching both queries is paired with
+    /// itself; an [`entity::Identifier`] can be viewed in both queries to skip such pairs.
     ///
     /// # Example
-    /// ```
+    /// ``` rust
     /// use brood::{
+    ///     entity,
     ///     query::{
+    ///         filter,
     ///         result,
     ///         Views,
     ///     },
-    ///     resources,
     ///     Query,
     ///     Registry,
     ///     World,
     /// };
     ///
-    /// #[derive(Debug, PartialEq)]
-    /// struct ResourceA(u32);
-    /// #[derive(Debug, PartialEq)]
-    /// struct ResourceB(char);
+    /// struct Health(u32);
+    /// struct Position(u32);
+    /// struct Explosion(u32);
     ///
-    /// let mut world =
-    ///     World::<Registry!(), _>::with_resources(resources!(ResourceA(0), ResourceB('a')));
+    /// type Registry = Registry!(Health, Position, Explosion);
     ///
-    /// let result!(a, b) = world.view_resources::<Views!(&ResourceA, &mut ResourceB), _>();
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Health(100), Position(0)));
+    /// world.insert(entity!(Health(100), Position(10)));
+    /// world.insert(entity!(Explosion(1)));
+    /// world.insert(entity!(Explosion(9)));
+    ///
+    /// world.query_pairs(
+    ///     Query::<Views!(&mut Health, &Position)>::new(),
+    ///     Query::<Views!(&Explosion)>::new(),
+    ///     |result!(health, position), result!(explosion)| {
+    ///         if position.0.abs_diff(explosion.0) <= 2 {
+    ///             health.0 -= 10;
+    ///         }
+    ///     },
+    /// );
     ///
-    /// assert_eq!(a, &ResourceA(0));
+    /// world.for_each::<Views!(&Health), filter::None, _, _>(|result!(health)| {
+    ///     assert_eq!(health.0, 90);
+    /// });
+    /// ```
     ///
-    /// b.0 = 'b';
-    /// assert_eq!(b, &mut ResourceB('b'));
+    /// Views that conflict are rejected:
+    ///
+    /// ``` compile_fail
+    /// use brood::{
+    ///     query::Views,
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    ///
+    /// world.query_pairs(
+    ///     Query::<Views!(&mut Foo)>::new(),
+    ///     Query::<Views!(&Foo)>::new(),
+    ///     |_, _| {},
+    /// );
     /// ```
-    pub fn view_resources<'a, Views, Indices>(&'a mut self) -> Views
-    where
-        Resources: ContainsViews<'a, Views, Indices>,
+    pub fn query_pairs<
+        'a,
+        Views,
+        Filter,
+        OtherViews,
+        OtherFilter,
+        Function,
+        Indices,
+        OtherIndices,
+        DisjointIndices,
+    >(
+        &'a mut self,
+        #[allow(unused_variables)] query: Query<Views, Filter>,
+        #[allow(unused_variables)] other_query: Query<OtherViews, OtherFilter>,
+        mut function: Function,
+    ) where
+        Views: view::Views<'a>,
+        OtherViews: view::Disjoint<Views, Registry, DisjointIndices> + view::Views<'a>,
+        Registry: ContainsQuery<'a, Filter, Views, Indices>
+            + ContainsQuery<'a, OtherFilter, OtherViews, OtherIndices>,
+        Function: FnMut(&mut Views, &mut OtherViews),
     {
-        self.resources.view()
+        let archetypes = ptr::addr_of_mut!(self.archetypes);
+        let mut other_results =
+            result::Iter::<Registry, OtherFilter, OtherViews, OtherIndices>::new(
+                // SAFETY: `OtherViews` is verified to not conflict with `Views`, so the results
+                // collected here do not alias the results of `query` below.
+                unsafe { &mut *archetypes }.iter_mut(),
+            )
+            .collect::<Vec<_>>();
+        for mut result in result::Iter::<Registry, Filter, Views, Indices>::new(
+            // SAFETY: `Views` is verified to not conflict with `OtherViews`, so the results
+            // created here do not alias the results collected above.
+            unsafe { &mut *archetypes }.iter_mut(),
+        ) {
+            for other_result in &mut other_results {
+                function(&mut result, other_result);
+            }
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::World;
-    #[cfg(feature = "rayon")]
-    use crate::system::ParSystem;
+    /// Query for components contained within the `World` using the given [`ParViews`] `V` and
+    /// [`Filter`] `F`, returning a [`ParallelIterator`] over all components of entities matching
+    /// the query.
+    ///
+    /// The difference between this method and [`query()`] is that this method allow results to be
+    /// operated on in parallel rather than sequentially.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    /// struct Baz(u32);
+    ///
+    /// type Registry = Registry!(Foo, Bar, Baz);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let inserted_entity_identifier = world.insert(entity!(Foo(42), Bar(true), Baz(100)));
+    ///
+    /// // Note that the views provide implicit filters.
+    /// world
+    ///     .par_query(Query::<
+    ///         Views!(&mut Foo, &Baz, entity::Identifier),
+    ///         filter::Has<Bar>,
+    ///     >::new())
+    ///     .iter
+    ///     .for_each(|result!(foo, baz, entity_identifier)| {
+    ///         // Allows immutable or mutable access to queried components.
+    ///         foo.0 = baz.0;
+    ///         // Also allows access to entity identifiers.
+    ///         assert_eq!(entity_identifier, inserted_entity_identifier);
+    ///     });
+    /// ```
+    ///
+    /// For more information about `ParViews` and `Filter`, see the [`query`] module documentaion.
+    ///
+    /// [`Filter`]: crate::query::filter::Filter
+    /// [`ParallelIterator`]: rayon::iter::ParallelIterator
+    /// [`ParViews`]: crate::query::view::ParViews
+    /// [`query`]: crate::query
+    /// [`query()`]: World::query()
     #[cfg(feature = "rayon")]
-    use crate::system::{
-        schedule,
-        schedule::task,
-    };
-    use crate::{
-        entities,
-        entity,
-        query::{
-            filter,
-            result,
-            view,
-            Result,
-            Views,
-        },
-        registry,
-        resources,
-        system::System,
-        Entity,
-        Query,
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    pub fn par_query<
+        'a,
+        Views,
+        Filter,
+        ResourceViews,
+        EntryViews,
+        QueryIndices,
+        ResourceViewsIndices,
+        DisjointIndices,
+        EntryIndices,
+    >(
+        &'a mut self,
+        #[allow(unused_variables)] query: Query<Views, Filter, ResourceViews, EntryViews>,
+    ) -> Result<
         Registry,
-    };
-    use alloc::{
-        vec,
-        vec::Vec,
-    };
-    use claims::{
-        assert_none,
-        assert_some,
-    };
-    #[cfg(feature = "rayon")]
-    use rayon::iter::ParallelIterator;
-
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    struct A(u32);
-
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    struct B(char);
-
-    type Registry = Registry!(A, B);
-
-    #[test]
-    fn insert() {
-        let mut world = World::<Registry>::new();
-
-        world.insert(entity!(A(42), B('f')));
+        Resources,
+        result::ParIter<'a, Registry, Filter, Views, QueryIndices>,
+        ResourceViews,
+        EntryViews,
+        EntryIndices,
+    >
+    where
+        Views: ParViews<'a>,
+        Registry: ContainsParQuery<'a, Filter, Views, QueryIndices>
+            + registry::ContainsViews<'a, EntryViews, EntryIndices>,
+        Resources: ContainsViews<'a, ResourceViews, ResourceViewsIndices>,
+        EntryViews: view::Disjoint<Views, Registry, DisjointIndices> + view::Views<'a>,
+    {
+        let world = self as *mut Self;
+        Result {
+            // SAFETY: The views used here are verified to not conflict with the views used for
+            // `entries`.
+            iter: result::ParIter::new(unsafe { &mut *world }.archetypes.par_iter_mut()),
+            resources: self.resources.view(),
+            // SAFETY: The views used here are verified to not conflict with the views used for
+            // `iter`.
+            entries: unsafe { Entries::new(world) },
+        }
+    }
+
+    /// Calls `function` in parallel on the components of each entity matching the given
+    /// [`ParViews`] `V` and [`Filter`] `F`.
+    ///
+    /// This is a shortcut for calling `for_each()` on the [`ParallelIterator`] returned by
+    /// [`par_query()`], for when neither resources nor [`Entries`] need to be viewed.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// world.par_for_each::<Views!(&mut Foo, &Bar), filter::None, _, _>(|result!(foo, bar)| {
+    ///     if bar.0 {
+    ///         foo.0 += 1;
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// [`Entries`]: crate::query::Entries
+    /// [`Filter`]: crate::query::filter::Filter
+    /// [`ParallelIterator`]: rayon::iter::ParallelIterator
+    /// [`ParViews`]: crate::query::view::ParViews
+    /// [`par_query()`]: World::par_query()
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    pub fn par_for_each<'a, Views, Filter, Function, Indices>(&'a mut self, function: Function)
+    where
+        Views: ParViews<'a>,
+        Registry: ContainsParQuery<'a, Filter, Views, Indices>,
+        Function: Fn(Views) + Sync + Send,
+    {
+        result::ParIter::<Registry, Filter, Views, Indices>::new(self.archetypes.par_iter_mut())
+            .for_each(function);
+    }
+
+    /// Calls `function` in parallel on the [`entity::Identifier`] of each entity in the world.
+    ///
+    /// Unlike [`par_for_each()`], which views the components of entities matching a query,
+    /// `function` is only given each entity's identifier. This allows arbitrary read-only logic to
+    /// be performed per entity, such as looking up other entities while building an external
+    /// index. The entities are visited in no particular order.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use std::sync::Mutex;
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42)));
+    /// world.insert(entity!(Foo(100)));
+    ///
+    /// let identifiers = Mutex::new(Vec::new());
+    /// world.par_for_each_entity(|entity_identifier| {
+    ///     identifiers.lock().unwrap().push(entity_identifier);
+    /// });
+    ///
+    /// let identifiers = identifiers.into_inner().unwrap();
+    /// assert_eq!(identifiers.len(), 2);
+    /// assert!(identifiers.contains(&entity_identifier));
+    /// ```
+    ///
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    /// [`par_for_each()`]: World::par_for_each()
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    pub fn par_for_each_entity<Function>(&self, function: Function)
+    where
+        Function: Fn(entity::Identifier) + Sync + Send,
+    {
+        self.entity_allocator.par_identifiers().for_each(function);
+    }
+
+    /// Returns an iterator over the archetypes containing entities matching the given
+    /// [`Filter`].
+    ///
+    /// Rather than iterating over entities, this iterates over the archetypes storing them,
+    /// providing typed access to each archetype's contiguous columns of components through an
+    /// [`ArchetypeView`]. This is a lower-level alternative to [`query()`] for algorithms that
+    /// need to operate on whole columns at once.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::filter,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Position(f32);
+    /// struct Velocity(f32);
+    ///
+    /// type Registry = Registry!(Position, Velocity);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Position(0.0), Velocity(1.0)));
+    /// world.insert(entity!(Position(0.0)));
+    ///
+    /// for archetype in world.matching_archetypes::<filter::Has<Velocity>, _>() {
+    ///     assert_eq!(archetype.len(), 1);
+    ///     assert!(archetype.column::<Velocity, _>().is_some());
+    /// }
+    /// ```
+    ///
+    /// [`ArchetypeView`]: crate::world::ArchetypeView
+    /// [`Filter`]: crate::query::filter::Filter
+    /// [`query()`]: World::query()
+    pub fn matching_archetypes<'a, Filter, Indices>(
+        &'a mut self,
+    ) -> MatchingArchetypes<'a, Registry, Filter, Indices>
+    where
+        Registry: ContainsQuery<'a, Filter, view::Null, Indices>,
+    {
+        MatchingArchetypes::new(self.archetypes.iter_mut())
+    }
+
+    /// Return the claims on each archetype touched by the given query.
+    ///
+    /// # Safety
+    /// The `archetype::IdentifierRef`s over which this iterator iterates must not outlive the
+    /// `Archetypes` to which they belong.
+    ///
+    /// The views and entry views must be compatible with each other.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    pub(crate) unsafe fn query_archetype_claims<
+        'a,
+        Views,
+        QueryFilter,
+        Filter,
+        EntryViews,
+        QueryIndices,
+        FilterIndices,
+        EntryViewsIndices,
+    >(
+        &'a mut self,
+    ) -> result::ArchetypeClaims<
+        'a,
+        Registry,
+        Views,
+        QueryFilter,
+        Filter,
+        EntryViews,
+        QueryIndices,
+        FilterIndices,
+        EntryViewsIndices,
+    >
+    where
+        Views: view::Views<'a>,
+        EntryViews: view::Views<'a>,
+        Registry: ContainsFilter<Filter, FilterIndices>
+            + ContainsQuery<'a, QueryFilter, Views, QueryIndices>
+            + registry::ContainsViews<'a, EntryViews, EntryViewsIndices>,
+    {
+        // SAFETY: The safety contract here is upheld by the safety contract of this method.
+        unsafe { result::ArchetypeClaims::new(self.archetypes.iter_mut()) }
+    }
+
+    /// Run a [`System`] over the entities in this `World`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         filter::Filter,
+    ///         result,
+    ///         Result,
+    ///         Views,
+    ///     },
+    ///     registry,
+    ///     system::System,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// // Define components.
+    /// struct Foo(usize);
+    /// struct Bar(usize);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// // Define system.
+    /// struct MySystem;
+    ///
+    /// impl System for MySystem {
+    ///     type Views<'a> = Views!(&'a mut Foo, &'a Bar);
+    ///     type Filter = filter::None;
+    ///     type ResourceViews<'a> = Views!();
+    ///     type EntryViews<'a> = Views!();
+    ///
+    ///     fn run<'a, R, S, I, E>(
+    ///         &mut self,
+    ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+    ///     ) where
+    ///         R: registry::Registry,
+    ///         I: Iterator<Item = Self::Views<'a>>,
+    ///     {
+    ///         for result!(foo, bar) in query_results.iter {
+    ///             // Increment `Foo` by `Bar`.
+    ///             foo.0 += bar.0;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(100)));
+    ///
+    /// world.run_system(&mut MySystem);
+    /// ```
+    ///
+    /// [`System`]: crate::system::System
+    pub fn run_system<
+        'a,
+        System,
+        QueryIndices,
+        ResourceViewsIndices,
+        DisjointIndices,
+        EntryIndices,
+    >(
+        &'a mut self,
+        system: &mut System,
+    ) where
+        System: system::System,
+        Registry: ContainsQuery<'a, System::Filter, System::Views<'a>, QueryIndices>
+            + registry::ContainsViews<'a, System::EntryViews<'a>, EntryIndices>,
+        Resources: ContainsViews<'a, System::ResourceViews<'a>, ResourceViewsIndices>,
+        System::EntryViews<'a>:
+            view::Disjoint<System::Views<'a>, Registry, DisjointIndices> + view::Views<'a>,
+    {
+        let result = self.query(Query::<
+            System::Views<'a>,
+            System::Filter,
+            System::ResourceViews<'a>,
+            System::EntryViews<'a>,
+        >::new());
+        system.run(result);
+    }
+
+    /// Run a [`ParSystem`] over the entities in this `World`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         filter::Filter,
+    ///         result,
+    ///         Result,
+    ///         Views,
+    ///     },
+    ///     registry,
+    ///     system::ParSystem,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// // Define components.
+    /// struct Foo(usize);
+    /// struct Bar(usize);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// // Define system.
+    /// struct MySystem;
+    ///
+    /// impl ParSystem for MySystem {
+    ///     type Views<'a> = Views!(&'a mut Foo, &'a Bar);
+    ///     type Filter = filter::None;
+    ///     type ResourceViews<'a> = Views!();
+    ///     type EntryViews<'a> = Views!();
+    ///
+    ///     fn run<'a, R, S, I, E>(
+    ///         &mut self,
+    ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+    ///     ) where
+    ///         R: registry::Registry,
+    ///         I: ParallelIterator<Item = Self::Views<'a>>,
+    ///     {
+    ///         query_results
+    ///             .iter
+    ///             .for_each(|result!(foo, bar)| foo.0 += bar.0);
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(100)));
+    ///
+    /// world.run_par_system(&mut MySystem);
+    /// ```
+    ///
+    /// [`ParSystem`]: crate::system::ParSystem
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    pub fn run_par_system<
+        'a,
+        ParSystem,
+        QueryIndices,
+        ResourceViewsIndices,
+        DisjointIndices,
+        EntryIndices,
+    >(
+        &'a mut self,
+        par_system: &mut ParSystem,
+    ) where
+        ParSystem: system::ParSystem,
+        Registry: ContainsParQuery<'a, ParSystem::Filter, ParSystem::Views<'a>, QueryIndices>
+            + registry::ContainsViews<'a, ParSystem::EntryViews<'a>, EntryIndices>,
+        Resources: ContainsViews<'a, ParSystem::ResourceViews<'a>, ResourceViewsIndices>,
+        ParSystem::EntryViews<'a>:
+            view::Disjoint<ParSystem::Views<'a>, Registry, DisjointIndices> + view::Views<'a>,
+    {
+        let result = self.par_query(Query::<
+            ParSystem::Views<'a>,
+            ParSystem::Filter,
+            ParSystem::ResourceViews<'a>,
+            ParSystem::EntryViews<'a>,
+        >::new());
+        par_system.run(result);
+    }
+
+    /// Run a [`ParReduceSystem`] over the entities in this `World`, returning the reduced value.
+    ///
+    /// Each entity matching the system's query is mapped in parallel to a partial value using
+    /// [`ParReduceSystem::map()`], and the partial values are then combined using
+    /// [`ParReduceSystem::reduce()`]. If no entities match the query,
+    /// [`ParReduceSystem::identity()`] is returned.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     system::ParReduceSystem,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// // Define components.
+    /// struct Foo(usize);
+    ///
+    /// type Registry = Registry!(Foo);
+    ///
+    /// // Define system.
+    /// struct MaxFoo;
+    ///
+    /// impl ParReduceSystem for MaxFoo {
+    ///     type Views<'a> = Views!(&'a Foo);
+    ///     type Filter = filter::None;
+    ///     type Output = Option<usize>;
+    ///
+    ///     fn identity(&self) -> Self::Output {
+    ///         None
+    ///     }
+    ///
+    ///     fn map<'a>(&self, result!(foo): Self::Views<'a>) -> Self::Output {
+    ///         Some(foo.0)
+    ///     }
+    ///
+    ///     fn reduce(&self, a: Self::Output, b: Self::Output) -> Self::Output {
+    ///         a.max(b)
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42)));
+    /// world.insert(entity!(Foo(100)));
+    ///
+    /// assert_eq!(world.run_par_reduce_system(&MaxFoo), Some(100));
+    /// ```
+    ///
+    /// [`ParReduceSystem`]: crate::system::ParReduceSystem
+    /// [`ParReduceSystem::identity()`]: crate::system::ParReduceSystem::identity()
+    /// [`ParReduceSystem::map()`]: crate::system::ParReduceSystem::map()
+    /// [`ParReduceSystem::reduce()`]: crate::system::ParReduceSystem::reduce()
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    pub fn run_par_reduce_system<'a, ParReduceSystem, Indices>(
+        &'a mut self,
+        par_reduce_system: &ParReduceSystem,
+    ) -> ParReduceSystem::Output
+    where
+        ParReduceSystem: system::ParReduceSystem,
+        Registry:
+            ContainsParQuery<'a, ParReduceSystem::Filter, ParReduceSystem::Views<'a>, Indices>,
+    {
+        result::ParIter::<Registry, ParReduceSystem::Filter, ParReduceSystem::Views<'a>, Indices>::new(
+            self.archetypes.par_iter_mut(),
+        )
+        .map(|views| par_reduce_system.map(views))
+        .reduce(
+            || par_reduce_system.identity(),
+            |a, b| par_reduce_system.reduce(a, b),
+        )
+    }
+
+    /// Run a [`Schedule`] over the entities in this `World`.
+    ///
+    /// When the `rayon` feature is enabled, the tasks of the `Schedule` are run in parallel stages.
+    /// Otherwise, each task is run sequentially in the order it was provided.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         filter::Filter,
+    ///         result,
+    ///         Result,
+    ///         Views,
+    ///     },
+    ///     registry,
+    ///     system::{
+    ///         schedule,
+    ///         schedule::task,
+    ///         Schedule,
+    ///         System,
+    ///     },
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// // Define components.
+    /// struct Foo(usize);
+    /// struct Bar(usize);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// // Define systems.
+    /// struct SystemA;
+    /// struct SystemB;
+    ///
+    /// impl System for SystemA {
+    ///     type Views<'a> = Views!(&'a mut Foo);
+    ///     type Filter = filter::None;
+    ///     type ResourceViews<'a> = Views!();
+    ///     type EntryViews<'a> = Views!();
+    ///
+    ///     fn run<'a, R, S, I, E>(
+    ///         &mut self,
+    ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+    ///     ) where
+    ///         R: registry::Registry,
+    ///         I: Iterator<Item = Self::Views<'a>>,
+    ///     {
+    ///         for result!(foo) in query_results.iter {
+    ///             foo.0 += 1;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl System for SystemB {
+    ///     type Views<'a> = Views!(&'a mut Bar);
+    ///     type Filter = filter::None;
+    ///     type ResourceViews<'a> = Views!();
+    ///     type EntryViews<'a> = Views!();
+    ///
+    ///     fn run<'a, R, S, I, E>(
+    ///         &mut self,
+    ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+    ///     ) where
+    ///         R: registry::Registry,
+    ///         I: Iterator<Item = Self::Views<'a>>,
+    ///     {
+    ///         for result!(bar) in query_results.iter {
+    ///             bar.0 += 1;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // Define schedule.
+    /// let mut schedule = schedule!(task::System(SystemA), task::System(SystemB));
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(100)));
+    ///
+    /// world.run_schedule(&mut schedule);
+    /// ```
+    ///
+    /// [`Schedule`]: trait@crate::system::schedule::Schedule
+    pub fn run_schedule<'a, Schedule, Indices>(&mut self, schedule: &'a mut Schedule)
+    where
+        Resources: resource::Resources,
+        S: Sync + 'a,
+        Schedule: schedule::Schedule<'a, Registry, Resources, Indices>,
+    {
+        schedule.run(self, None);
+    }
+
+    /// Run a [`Schedule`] over the entities in this `World`, measuring how long each of its tasks
+    /// and stages take.
+    ///
+    /// This behaves the same as [`run_schedule()`], except that `clock` is read before and after
+    /// each task and stage is run. Since brood is `no_std`, the time source must be provided by
+    /// the caller. The recorded timings are returned in a [`ScheduleReport`].
+    ///
+    /// Tasks are named by their system's type, as given by [`core::any::type_name()`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Result,
+    ///         Views,
+    ///     },
+    ///     registry,
+    ///     system::{
+    ///         schedule,
+    ///         schedule::{
+    ///             task,
+    ///             Clock,
+    ///         },
+    ///         System,
+    ///     },
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use core::time::Duration;
+    /// use std::time::Instant;
+    ///
+    /// struct StdClock(Instant);
+    ///
+    /// impl Clock for StdClock {
+    ///     fn now(&self) -> Duration {
+    ///         self.0.elapsed()
+    ///     }
+    /// }
+    ///
+    /// struct Foo(usize);
+    ///
+    /// type Registry = Registry!(Foo);
+    ///
+    /// struct Increment;
+    ///
+    /// impl System for Increment {
+    ///     type Views<'a> = Views!(&'a mut Foo);
+    ///     type Filter = filter::None;
+    ///     type ResourceViews<'a> = Views!();
+    ///     type EntryViews<'a> = Views!();
+    ///
+    ///     fn run<'a, R, S, I, E>(
+    ///         &mut self,
+    ///         query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+    ///     ) where
+    ///         R: registry::Registry,
+    ///         I: Iterator<Item = Self::Views<'a>>,
+    ///     {
+    ///         for result!(foo) in query_results.iter {
+    ///             foo.0 += 1;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut schedule = schedule!(task::System(Increment));
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42)));
+    ///
+    /// let report = world.run_schedule_instrumented(&mut schedule, &StdClock(Instant::now()));
+    ///
+    /// assert_eq!(report.tasks().len(), 1);
+    /// assert!(report.tasks()[0].name().ends_with("Increment"));
+    /// ```
+    ///
+    /// [`run_schedule()`]: World::run_schedule()
+    /// [`Schedule`]: trait@crate::system::schedule::Schedule
+    /// [`ScheduleReport`]: crate::system::schedule::ScheduleReport
+    pub fn run_schedule_instrumented<'a, Schedule, Indices, Clock>(
+        &mut self,
+        schedule: &'a mut Schedule,
+        clock: &Clock,
+    ) -> schedule::ScheduleReport
+    where
+        Resources: resource::Resources,
+        S: Sync + 'a,
+        Schedule: schedule::Schedule<'a, Registry, Resources, Indices>,
+        Clock: schedule::Clock,
+    {
+        let recorder = schedule::Recorder::new(clock);
+        schedule.run(self, Some(&recorder));
+        recorder.into_report()
+    }
+
+    /// Returns `true` if the world contains an entity identified by `entity_identifier`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// assert!(world.contains(entity_identifier));
+    /// world.remove(entity_identifier);
+    /// assert!(!world.contains(entity_identifier));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, entity_identifier: entity::Identifier) -> bool {
+        self.entity_allocator.is_active(entity_identifier)
+    }
+
+    /// Returns a copy of the bytes identifying the archetype of the entity associated with
+    /// `entity_identifier`.
+    ///
+    /// Each bit of the returned bytes corresponds to a component of the `Registry`, in the order
+    /// the components were defined, with the least significant bit of the first byte corresponding
+    /// to the first component. A bit is set if the entity contains the corresponding component.
+    /// This is mostly useful for debugging why an entity is or isn't matched by a query.
+    ///
+    /// If no such entity exists, [`None`] is returned.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Bar(true)));
+    ///
+    /// assert_eq!(
+    ///     world.archetype_identifier(entity_identifier),
+    ///     Some(vec![0b10])
+    /// );
+    /// ```
+    ///
+    /// [`None`]: Option::None
+    #[must_use]
+    pub fn archetype_identifier(&self, entity_identifier: entity::Identifier) -> Option<Vec<u8>> {
+        self.entity_allocator
+            .get(entity_identifier)
+            .map(|location| location.identifier.as_vec())
+    }
+
+    /// Returns the [`ArchetypeKey`] of the archetype identified by a [`view::ArchetypeId`].
+    ///
+    /// If no archetype in this `World` is identified by `archetype_id`, [`None`] is returned.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         view,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42)));
+    ///
+    /// let result!(archetype_id) = world
+    ///     .query(Query::<Views!(view::ArchetypeId)>::new())
+    ///     .iter
+    ///     .next()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     world.archetype_key(archetype_id),
+    ///     Some(world.entry(entity_identifier).unwrap().archetype_key())
+    /// );
+    /// ```
+    ///
+    /// [`None`]: Option::None
+    #[must_use]
+    pub fn archetype_key(&self, archetype_id: view::ArchetypeId) -> Option<ArchetypeKey> {
+        self.archetypes
+            .iter()
+            .map(|archetype|
+                // SAFETY: The identifier does not outlive the archetype, as it is only used within
+                // this method.
+                unsafe { archetype.identifier() })
+            .find(|&identifier| view::ArchetypeId::new(identifier) == archetype_id)
+            .map(|identifier| ArchetypeKey::new(identifier.as_vec()))
+    }
+
+    /// Gets an [`Entry`] for the entity associated with an [`entity::Identifier`] for
+    /// component-level manipulation.
+    ///
+    /// If no such entity exists, [`None`] is returned.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// let mut entry = world.entry(entity_identifier).unwrap();
+    /// // Remove the `Bar` component.
+    /// entry.remove::<Bar, _>();
+    /// ```
+    ///
+    /// [`Entry`]: crate::world::Entry
+    /// [`None`]: Option::None
+    #[must_use]
+    pub fn entry(
+        &mut self,
+        entity_identifier: entity::Identifier,
+    ) -> Option<Entry<Registry, Resources, S>> {
+        self.entity_allocator
+            .get(entity_identifier)
+            .map(|location| Entry::new(self, entity_identifier, location))
+    }
+
+    /// Gets an [`Entry`] for the entity associated with an [`entity::Identifier`], without
+    /// checking that the entity exists.
+    ///
+    /// This skips the check performed by [`entry()`], which is useful in hot loops where the
+    /// identifier is already known to be valid. In most cases, [`entry()`] should be preferred.
+    ///
+    /// # Safety
+    /// `entity_identifier` must identify an entity that is currently contained in this `World`.
+    /// Calling this method with an identifier for an entity that has been removed, or that was
+    /// never inserted, is undefined behavior.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// // SAFETY: The entity was just inserted and has not been removed.
+    /// let mut entry = unsafe { world.entry_unchecked(entity_identifier) };
+    /// // Remove the `Bar` component.
+    /// entry.remove::<Bar, _>();
+    /// ```
+    ///
+    /// [`Entry`]: crate::world::Entry
+    /// [`entry()`]: World::entry()
+    #[must_use]
+    pub unsafe fn entry_unchecked(
+        &mut self,
+        entity_identifier: entity::Identifier,
+    ) -> Entry<Registry, Resources, S> {
+        // SAFETY: `entity_identifier` is guaranteed by the safety contract of this method to
+        // identify an entity that is currently allocated.
+        let location = unsafe { self.entity_allocator.get_unchecked(entity_identifier) };
+        Entry::new(self, entity_identifier, location)
+    }
+
+    /// Applies structural changes that were recorded while iterating over a query.
+    ///
+    /// The changes are applied in the order they were recorded. Consecutive changes to the same
+    /// entity are applied with a single move, in the same way as [`Entry::modify()`]. Changes to
+    /// entities that are no longer contained in the `World` are discarded.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    /// world.insert(entity!(Foo(100), Bar(false)));
+    ///
+    /// let query_result = world.query(Query::<Views!(&Bar, entity::Identifier)>::new());
+    /// let mut entries = query_result.entries;
+    /// for result!(bar, entity_identifier) in query_result.iter {
+    ///     if !bar.0 {
+    ///         entries.entry(entity_identifier).unwrap().remove::<Bar, _>();
+    ///     }
+    /// }
+    /// let deferred = entries.into_deferred();
+    /// world.apply_deferred(deferred);
+    ///
+    /// assert_eq!(
+    ///     world
+    ///         .query(Query::<Views!(&Foo), filter::Has<Bar>>::new())
+    ///         .iter
+    ///         .count(),
+    ///     1
+    /// );
+    /// ```
+    ///
+    /// [`Entry::modify()`]: crate::world::Entry::modify()
+    pub fn apply_deferred(&mut self, deferred: Deferred<Registry>) {
+        for (entity_identifier, modifier) in deferred.modifiers {
+            if let Some(mut entry) = self.entry(entity_identifier) {
+                entry.apply_deferred(modifier);
+            }
+        }
+    }
+
+    /// Gets an [`EntityMut`] handle for the entity associated with an [`entity::Identifier`].
+    ///
+    /// The handle supports the same modifications as an [`Entry`], but allows them to be chained.
+    ///
+    /// If no such entity exists, [`None`] is returned.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// let entity = world
+    ///     .entity_mut(entity_identifier)
+    ///     .unwrap()
+    ///     .remove::<Bar, _>();
+    /// assert_eq!(entity.get::<Foo, _>(), Some(&Foo(42)));
+    /// ```
+    ///
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    /// [`EntityMut`]: crate::world::EntityMut
+    /// [`Entry`]: crate::world::Entry
+    /// [`None`]: Option::None
+    #[must_use]
+    pub fn entity_mut(
+        &mut self,
+        entity_identifier: entity::Identifier,
+    ) -> Option<EntityMut<Registry, Resources, S>> {
+        self.entry(entity_identifier).map(EntityMut::new)
+    }
+
+    /// Insert an entity, returning an [`EntityMut`] handle to it.
+    ///
+    /// This behaves the same as [`insert()`], but allows further modification of the new entity
+    /// to be chained. The entity's [`entity::Identifier`] can be obtained from the handle using
+    /// [`EntityMut::identifier()`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    /// struct Baz(f64);
+    ///
+    /// type Registry = Registry!(Foo, Bar, Baz);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world
+    ///     .spawn(entity!(Foo(42)))
+    ///     .add(Bar(true))
+    ///     .add(Baz(1.5))
+    ///     .identifier();
+    ///
+    /// assert!(world.contains(entity_identifier));
+    /// ```
+    ///
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    /// [`EntityMut`]: crate::world::EntityMut
+    /// [`EntityMut::identifier()`]: crate::world::EntityMut::identifier()
+    /// [`insert()`]: World::insert()
+    pub fn spawn<Entity, Indices>(&mut self, entity: Entity) -> EntityMut<Registry, Resources, S>
+    where
+        Registry: ContainsEntity<Entity, Indices>,
+    {
+        let entity_identifier = self.insert(entity);
+        // SAFETY: The entity was just inserted, so it is guaranteed to have an allocated location.
+        let location = unsafe {
+            self.entity_allocator
+                .get(entity_identifier)
+                .unwrap_unchecked()
+        };
+        EntityMut::new(Entry::new(self, entity_identifier, location))
+    }
+
+    /// Swap the `Component` values of the entities associated with two [`entity::Identifier`]s.
+    ///
+    /// Returns `true` if the components were swapped. If either entity does not exist or does not
+    /// contain a `Component`, nothing is swapped and `false` is returned. The two entities may be
+    /// of different shapes.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let a = world.insert(entity!(Foo(1), Bar(true)));
+    /// let b = world.insert(entity!(Foo(2)));
+    ///
+    /// assert!(world.swap_component::<Foo, _>(a, b));
+    ///
+    /// let mut entry = world.entry(a).unwrap();
+    /// let result!(foo) = entry.query(Query::<Views!(&Foo)>::new()).unwrap();
+    /// assert_eq!(foo, &Foo(2));
+    /// ```
+    pub fn swap_component<Component, Index>(
+        &mut self,
+        a: entity::Identifier,
+        b: entity::Identifier,
+    ) -> bool
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        let component_index = Registry::LEN - Registry::INDEX - 1;
+        let (Some(a_location), Some(b_location)) =
+            (self.entity_allocator.get(a), self.entity_allocator.get(b))
+        else {
+            return false;
+        };
+        if
+        // SAFETY: The `component_index` obtained from `R::LEN - R::INDEX - 1` is guaranteed to be
+        // a valid index into each location's identifier, since an identifier has `R::LEN` bits.
+        unsafe {
+            !a_location.identifier.get_unchecked(component_index)
+                || !b_location.identifier.get_unchecked(component_index)
+        } {
+            return false;
+        }
+
+        // SAFETY: `self.entity_allocator` contains entries for the entities stored in this
+        // world's archetypes. Also, each `location.index` is invariantly guaranteed to be a valid
+        // index in its archetype, and it was verified above that both archetypes contain
+        // `Component`.
+        let (a_component, b_component) = unsafe {
+            (
+                self.archetypes
+                    .get_unchecked_mut(a_location.identifier)
+                    .component_ptr_unchecked::<Component, Index>(a_location.index),
+                self.archetypes
+                    .get_unchecked_mut(b_location.identifier)
+                    .component_ptr_unchecked::<Component, Index>(b_location.index),
+            )
+        };
+        // SAFETY: Both pointers point to valid, initialized `Component`s. They are either
+        // entirely non-overlapping or exactly equal (if `a` and `b` are the same entity), both of
+        // which are permitted by `ptr::swap()`.
+        unsafe {
+            ptr::swap(a_component, b_component);
+        }
+
+        true
+    }
+
+    /// Query for components contained within several distinct entities simultaneously.
+    ///
+    /// Returns the `Views` of each entity associated with the given [`entity::Identifier`]s, in
+    /// the same order as the identifiers. This allows mutable access to the components of multiple
+    /// entities at once, such as when applying a constraint between bodies.
+    ///
+    /// Returns [`None`] if any of the identifiers are not associated with an entity, if any
+    /// identifier is given more than once, or if any of the entities do not match the `Views` and
+    /// `Filter`. Requiring distinct identifiers guarantees that mutable views never alias.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Position(f32);
+    ///
+    /// type Registry = Registry!(Position);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let a = world.insert(entity!(Position(1.0)));
+    /// let b = world.insert(entity!(Position(4.0)));
+    ///
+    /// let [result!(a_position), result!(b_position)] = world
+    ///     .query_many(Query::<Views!(&mut Position)>::new(), [a, b])
+    ///     .unwrap();
+    /// let midpoint = (a_position.0 + b_position.0) / 2.0;
+    /// a_position.0 = midpoint;
+    /// b_position.0 = midpoint;
+    ///
+    /// // The same entity cannot be viewed twice.
+    /// assert!(world
+    ///     .query_many(Query::<Views!(&mut Position)>::new(), [a, a])
+    ///     .is_none());
+    /// ```
+    ///
+    /// [`None`]: Option::None
+    pub fn query_many<'a, Views, Filter, Indices, const N: usize>(
+        &'a mut self,
+        #[allow(unused_variables)] query: Query<Views, Filter>,
+        entity_identifiers: [entity::Identifier; N],
+    ) -> Option<[Views; N]>
+    where
+        Views: view::Views<'a>,
+        Registry: ContainsQuery<'a, Filter, Views, Indices>,
+    {
+        // Ensure no entity is viewed more than once, as that could create aliasing mutable views.
+        for (i, entity_identifier) in entity_identifiers.iter().enumerate() {
+            if entity_identifiers[..i].contains(entity_identifier) {
+                return None;
+            }
+        }
+
+        let locations = entity_identifiers
+            .map(|entity_identifier| self.entity_allocator.get(entity_identifier));
+        for location in &locations {
+            // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+            // identifier is generic over.
+            if !unsafe {
+                <Registry as contains::filter::Sealed<
+                    And<Filter, Views>,
+                    And<Registry::FilterIndices, Registry::ViewsFilterIndices>,
+                >>::filter((*location)?.identifier)
+            } {
+                return None;
+            }
+        }
+
+        let archetypes = ptr::addr_of_mut!(self.archetypes);
+        Some(locations.map(|location| {
+            // SAFETY: Every location was verified to be `Some` above.
+            let location = unsafe { location.unwrap_unchecked() };
+            // SAFETY: Since the archetype wasn't filtered out by the views, then each component
+            // viewed by `Views` is also identified by the archetype's identifier.
+            //
+            // `self.entity_allocator` contains entries for entities stored in `self.archetypes`.
+            // As such, `location.index` is guaranteed to be a valid index to a row within this
+            // archetype, since they share the same archetype identifier.
+            //
+            // Finally, each location refers to a distinct entity, and therefore to a distinct row,
+            // so none of the views created here alias each other.
+            unsafe {
+                (*archetypes)
+                    .get_unchecked_mut(location.identifier)
+                    .view_row_unchecked::<Views, (
+                        Registry::ViewsContainments,
+                        Registry::ViewsIndices,
+                        Registry::ViewsCanonicalContainments,
+                    )>(location.index)
+                    .reshape()
+            }
+        }))
+    }
+
+    /// Add a clone of `component` to every entity matching the given [`Views`] and [`Filter`].
+    ///
+    /// Entities that already contain a `Component` have it updated to the new value, just as with
+    /// [`Entry::add()`]. Otherwise, all matching entities sharing a shape are moved to their new
+    /// archetype at once, rather than one at a time, making this much cheaper than calling
+    /// [`Entry::add()`] for each entity individually. Entity identifiers remain valid.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Tag;
+    ///
+    /// type Registry = Registry!(Foo, Bar, Tag);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(1), Bar(true)));
+    /// world.insert(entity!(Foo(2)));
+    /// world.insert(entity!(Bar(false)));
+    ///
+    /// world.add_component_to_all(Query::<Views!(&Foo)>::new(), Tag);
+    ///
+    /// assert_eq!(world.query(Query::<Views!(&Tag)>::new()).iter.count(), 2);
+    /// ```
+    ///
+    /// [`Entry::add()`]: crate::world::Entry::add()
+    /// [`Filter`]: crate::query::filter::Filter
+    /// [`Views`]: trait@crate::query::view::Views
+    pub fn add_component_to_all<'a, Component, Views, Filter, ComponentIndex, Indices>(
+        &'a mut self,
+        #[allow(unused_variables)] query: Query<Views, Filter>,
+        component: Component,
+    ) where
+        Component: Clone + component::Component,
+        Views: view::Views<'a>,
+        Registry: ContainsComponent<Component, ComponentIndex>
+            + ContainsQuery<'a, Filter, Views, Indices>,
+    {
+        let component_index = Registry::LEN - Registry::INDEX - 1;
+
+        let mut updated_identifiers = Vec::new();
+        let mut moved_identifiers = Vec::new();
+        for archetype in self.archetypes.iter() {
+            if archetype.is_empty() {
+                continue;
+            }
+            // SAFETY: Archetypes are not removed by this method, so the identifier will not
+            // outlive the archetype.
+            let identifier = unsafe { archetype.identifier() };
+            // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+            // identifier is generic over.
+            if unsafe {
+                <Registry as contains::filter::Sealed<
+                    And<Filter, Views>,
+                    And<Registry::FilterIndices, Registry::ViewsFilterIndices>,
+                >>::filter(identifier)
+            } {
+                // SAFETY: `component_index` is guaranteed to be a valid index into the identifier,
+                // since an identifier has `Registry::LEN` bits.
+                if unsafe { identifier.get_unchecked(component_index) } {
+                    updated_identifiers.push(identifier);
+                } else {
+                    moved_identifiers.push(identifier);
+                }
+            }
+        }
+
+        for identifier in updated_identifiers {
+            // SAFETY: An archetype with this identifier is guaranteed to exist, since it was
+            // obtained from the archetypes above.
+            let archetype = unsafe { self.archetypes.get_unchecked_mut(identifier) };
+            for index in 0..archetype.len() {
+                // SAFETY: `Component` is contained within the archetype, since its bit is set in
+                // the archetype's identifier. Also, `index` is a valid index within the archetype.
+                unsafe {
+                    archetype.set_component_unchecked(index, component.clone());
+                }
+            }
+        }
+
+        // SAFETY: Each identifier was obtained from an archetype within this `World` that does not
+        // contain `Component`. No archetypes have been removed since.
+        unsafe {
+            self.move_archetypes_adding_component::<Component, ComponentIndex>(
+                moved_identifiers,
+                &component,
+            );
+        }
+
+        self.auto_compact();
+    }
+
+    /// Moves the entities of each archetype identified in `identifiers` to the archetype
+    /// identified by the same components with the addition of `Component`, giving each of them a
+    /// clone of `component`.
+    ///
+    /// # Safety
+    /// Each identifier must identify an archetype within this `World` that does not contain
+    /// `Component`. The identifiers must be unique.
+    unsafe fn move_archetypes_adding_component<Component, ComponentIndex>(
+        &mut self,
+        identifiers: Vec<archetype::IdentifierRef<Registry>>,
+        component: &Component,
+    ) where
+        Component: Clone + component::Component,
+        Registry: ContainsComponent<Component, ComponentIndex>,
+    {
+        let component_index = Registry::LEN - Registry::INDEX - 1;
+
+        let archetypes = ptr::addr_of_mut!(self.archetypes);
+        for identifier in identifiers {
+            // Create new identifier buffer.
+            let mut raw_identifier_buffer = identifier.as_vec();
+            // Set the component's bit.
+            // SAFETY: `component_index` is guaranteed to be a valid index to a bit in
+            // `raw_identifier_buffer`.
+            *unsafe { raw_identifier_buffer.get_unchecked_mut(component_index / 8) } |=
+                1 << (component_index % 8);
+            let identifier_buffer =
+                // SAFETY: Since `raw_identifier_buffer` was obtained from a valid identifier, it
+                // is of the proper length (which is `(R::LEN + 7) / 8`).
+                unsafe { archetype::Identifier::<Registry>::new(raw_identifier_buffer) };
+
+            // SAFETY: `archetypes` is a valid pointer to `self.archetypes`. The destination
+            // archetype is obtained before the source archetype, since inserting a new archetype
+            // may move the archetypes within the table.
+            let destination = unsafe { &mut *archetypes }.get_mut_or_insert_new(identifier_buffer)
+                as *mut Archetype<Registry>;
+            // SAFETY: An archetype with this identifier is guaranteed to exist by the safety
+            // contract of this method. No archetypes are inserted after `destination` is obtained,
+            // so it remains valid.
+            let source = unsafe { (*archetypes).get_unchecked_mut(identifier) };
+            // SAFETY: `destination` is identified by the same components as `source`, with the
+            // addition of `Component`, which `source` is guaranteed by the safety contract of this
+            // method to not contain. Since the identifiers differ, `destination` and `source` are
+            // distinct archetypes.
+            //
+            // The registry is invariantly guaranteed to not contain any duplicates.
+            //
+            // `self.entity_allocator` contains entries for the entities stored in `source`, and
+            // does not outlive `destination`, since both are stored within this `World`.
+            let rows = unsafe {
+                (*destination).append_with_component(source, component, &mut self.entity_allocator)
+            };
+            for index in rows {
+                // SAFETY: `index` is one of the rows the entities were just moved to, and
+                // `Component` is contained in `destination`, since its bit was set in the
+                // identifier.
+                unsafe {
+                    (*destination).notify_component_unchecked::<Component, ComponentIndex>(
+                        index,
+                        &mut self.observers.on_add,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Remove `Component` from every entity matching the given [`Filter`].
+    ///
+    /// Entities that do not contain a `Component` are left unchanged. All matching entities
+    /// sharing a shape are moved to their new archetype at once, rather than one at a time, making
+    /// this much cheaper than calling [`Entry::remove()`] for each entity individually. Entity
+    /// identifiers remain valid.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    /// struct Tag;
+    ///
+    /// type Registry = Registry!(Foo, Bar, Tag);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(1), Bar(true), Tag));
+    /// world.insert(entity!(Foo(2), Tag));
+    /// world.insert(entity!(Bar(false), Tag));
+    ///
+    /// world.remove_component_from_all::<Tag, filter::Has<Foo>, _, _>();
+    ///
+    /// assert_eq!(world.query(Query::<Views!(&Tag)>::new()).iter.count(), 1);
+    /// ```
+    ///
+    /// [`Entry::remove()`]: crate::world::Entry::remove()
+    /// [`Filter`]: crate::query::filter::Filter
+    pub fn remove_component_from_all<Component, Filter, ComponentIndex, FilterIndices>(&mut self)
+    where
+        Component: component::Component,
+        Registry:
+            ContainsComponent<Component, ComponentIndex> + ContainsFilter<Filter, FilterIndices>,
+    {
+        let component_index = Registry::LEN - Registry::INDEX - 1;
+
+        let mut identifiers = Vec::new();
+        for archetype in self.archetypes.iter() {
+            if archetype.is_empty() {
+                continue;
+            }
+            // SAFETY: Archetypes are not removed until the entities have been moved, so the
+            // identifier will not outlive the archetype.
+            let identifier = unsafe { archetype.identifier() };
+            // SAFETY: `component_index` is guaranteed to be a valid index into the identifier,
+            // since an identifier has `Registry::LEN` bits.
+            if unsafe { identifier.get_unchecked(component_index) }
+                // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+                // identifier is generic over.
+                && unsafe {
+                    <Registry as contains::filter::Sealed<Filter, FilterIndices>>::filter(
+                        identifier,
+                    )
+                }
+            {
+                identifiers.push(identifier);
+            }
+        }
+        if identifiers.is_empty() {
+            return;
+        }
+
+        let archetypes = ptr::addr_of_mut!(self.archetypes);
+        for identifier in identifiers {
+            // Create new identifier buffer.
+            let mut raw_identifier_buffer = identifier.as_vec();
+            // Unset the component's bit.
+            // SAFETY: `component_index` is guaranteed to be a valid index to a bit in
+            // `raw_identifier_buffer`.
+            *unsafe { raw_identifier_buffer.get_unchecked_mut(component_index / 8) } &=
+                !(1 << (component_index % 8));
+            let identifier_buffer =
+                // SAFETY: Since `raw_identifier_buffer` was obtained from a valid identifier, it
+                // is of the proper length (which is `(R::LEN + 7) / 8`).
+                unsafe { archetype::Identifier::<Registry>::new(raw_identifier_buffer) };
+
+            // SAFETY: `archetypes` is a valid pointer to `self.archetypes`. The destination
+            // archetype is obtained before the source archetype, since inserting a new archetype
+            // may move the archetypes within the table.
+            let destination = unsafe { &mut *archetypes }.get_mut_or_insert_new(identifier_buffer)
+                as *mut Archetype<Registry>;
+            // SAFETY: An archetype with this identifier is guaranteed to exist, since it was
+            // obtained from the archetypes above. No archetypes are inserted after `destination`
+            // is obtained, so it remains valid.
+            let source = unsafe { (*archetypes).get_unchecked_mut(identifier) };
+            for index in 0..source.len() {
+                // SAFETY: `index` is less than the length of `source`, and `Component` is
+                // contained in `source`, since its bit is set in the identifier.
+                unsafe {
+                    source.notify_component_unchecked::<Component, ComponentIndex>(
+                        index,
+                        &mut self.observers.on_remove,
+                    );
+                }
+            }
+            // SAFETY: `destination` is identified by the same components as `source`, except for
+            // `Component`, which `source` contains and `destination` does not, since its bit was
+            // unset. Since the identifiers differ, `destination` and `source` are distinct
+            // archetypes.
+            //
+            // The registry is invariantly guaranteed to not contain any duplicates.
+            //
+            // `self.entity_allocator` contains entries for the entities stored in `source`, and
+            // does not outlive `destination`, since both are stored within this `World`.
+            unsafe {
+                (*destination)
+                    .append_without_component::<Component>(source, &mut self.entity_allocator);
+            }
+        }
+
+        self.auto_compact();
+    }
+
+    /// Overwrite `Component` with clones of `value` for every entity made up of exactly the
+    /// components of `Entity`.
+    ///
+    /// Only the entities stored in the archetype for `Entity` are modified, regardless of
+    /// component order. The archetype's column of `Component`s is overwritten in place, which is
+    /// much faster than assigning the value through a query. If `Entity` does not contain
+    /// `Component`, or no such entities exist, nothing happens.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Entity,
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone)]
+    /// struct Damage(u32);
+    /// struct Health(u32);
+    ///
+    /// type Registry = Registry!(Damage, Health);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Damage(5), Health(100)));
+    /// world.insert(entity!(Damage(12), Health(50)));
+    ///
+    /// world.fill_component::<_, Entity!(Health, Damage), _, _>(Damage(0));
+    ///
+    /// assert!(world
+    ///     .query(Query::<Views!(&Damage)>::new())
+    ///     .iter
+    ///     .all(|result!(damage)| damage.0 == 0));
+    /// ```
+    pub fn fill_component<Component, Entity, ComponentIndex, Indices>(&mut self, value: Component)
+    where
+        Component: Clone + component::Component,
+        Registry: ContainsComponent<Component, ComponentIndex> + ContainsEntity<Entity, Indices>,
+    {
+        if let Some(column) = self
+            .archetypes
+            .get_mut_for_entity::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>()
+            .and_then(Archetype::column_mut::<Component, ComponentIndex>)
+        {
+            column.fill(value);
+        }
+    }
+
+    /// Overwrite `Component` with clones of `value` for every entity matching the given
+    /// [`Filter`].
+    ///
+    /// This is the same as [`fill_component()`], but overwrites the column of `Component`s in
+    /// every archetype matching `Filter`. Entities that do not contain a `Component` are left
+    /// unchanged.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         filter,
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone)]
+    /// struct Damage(u32);
+    /// struct Health(u32);
+    ///
+    /// type Registry = Registry!(Damage, Health);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Damage(5), Health(100)));
+    /// world.insert(entity!(Damage(12)));
+    ///
+    /// world.fill_component_filtered::<_, filter::None, _, _>(Damage(0));
+    ///
+    /// assert!(world
+    ///     .query(Query::<Views!(&Damage)>::new())
+    ///     .iter
+    ///     .all(|result!(damage)| damage.0 == 0));
+    /// ```
+    ///
+    /// [`fill_component()`]: World::fill_component()
+    /// [`Filter`]: crate::query::filter::Filter
+    pub fn fill_component_filtered<Component, Filter, ComponentIndex, FilterIndices>(
+        &mut self,
+        value: Component,
+    ) where
+        Component: Clone + component::Component,
+        Registry:
+            ContainsComponent<Component, ComponentIndex> + ContainsFilter<Filter, FilterIndices>,
+    {
+        for archetype in self.archetypes.iter_mut() {
+            // SAFETY: The `R` on which `filter()` is called is the same `R` over which the
+            // identifier is generic over. Additionally, the identifier reference created here will
+            // not outlive `archetype`.
+            if unsafe {
+                <Registry as contains::filter::Sealed<Filter, FilterIndices>>::filter(
+                    archetype.identifier(),
+                )
+            } {
+                if let Some(column) = archetype.column_mut::<Component, ComponentIndex>() {
+                    column.fill(value.clone());
+                }
+            }
+        }
+    }
+
+    /// Remove the entity associated with an [`entity::Identifier`].
+    ///
+    /// If the entity has already been removed, this method will do nothing.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// world.remove(entity_identifier);
+    /// ```
+    pub fn remove(&mut self, entity_identifier: entity::Identifier) {
+        // Get location of entity.
+        if let Some(location) = self.entity_allocator.get(entity_identifier) {
+            // SAFETY: An archetype with this identifier is guaranteed to exist, since there is an
+            // allocated location for it in the entity allocator.
+            let archetype = unsafe { self.archetypes.get_unchecked_mut(location.identifier) };
+            // SAFETY: `location.index` is invariantly guaranteed to be a valid index in the
+            // archetype.
+            unsafe {
+                archetype.notify_rows(
+                    location.index..location.index + 1,
+                    &mut self.observers.on_remove,
+                );
+            }
+            // Remove row from Archetype.
+            // SAFETY: `self.entity_allocator` contains entries for the entities stored in this
+            // world's archetypes. Also, `location.index` is invariantly guaranteed to be a valid
+            // index in the archetype.
+            unsafe {
+                archetype.remove_row_unchecked(
+                    location.index,
+                    &mut self.entity_allocator,
+                    &mut self.observers.moves,
+                );
+            }
+            let archetype_emptied = archetype.is_empty();
+            // Free slot in entity allocator.
+            // SAFETY: It was verified above that `self.entity_allocator` contains a valid slot for
+            // `entity_identifier`.
+            unsafe {
+                self.entity_allocator.free_unchecked(entity_identifier);
+            }
+
+            self.len -= 1;
+
+            if archetype_emptied {
+                self.auto_compact();
+            }
+        }
+    }
+
+    /// Removes all entities.
+    ///
+    /// Keeps the allocated memory for reuse.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// world.clear();
+    /// ```
+    pub fn clear(&mut self) {
+        if !self.observers.on_remove.is_empty() {
+            for archetype in self.archetypes.iter_mut() {
+                // SAFETY: The rows `0..archetype.len()` are all within the bounds of the
+                // archetype.
+                unsafe {
+                    archetype.notify_rows(0..archetype.len(), &mut self.observers.on_remove);
+                }
+            }
+        }
+        // SAFETY: `self.entity_allocator` contains entries for the entities stored in this world's
+        // archetypes.
+        unsafe {
+            self.archetypes.clear(&mut self.entity_allocator);
+        }
+        self.len = 0;
+    }
+
+    /// Removes all entities made up of exactly the components of `Entity`.
+    ///
+    /// Only the entities stored in the archetype for `Entity` are removed, regardless of component
+    /// order; entities containing any other set of components are left untouched. Keeps the
+    /// allocated memory for reuse.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(false)));
+    /// world.insert(entity!(Foo(100)));
+    ///
+    /// world.clear_archetype::<Entity!(Foo), _>();
+    ///
+    /// assert_eq!(world.len(), 1);
+    /// assert_eq!(world.len_of::<Entity!(Foo), _>(), 0);
+    /// ```
+    pub fn clear_archetype<Entity, Indices>(&mut self)
+    where
+        Registry: ContainsEntity<Entity, Indices>,
+    {
+        if let Some(archetype) = self.archetypes.get_mut_for_entity::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>() {
+            // SAFETY: The rows `0..archetype.len()` are all within the bounds of the archetype.
+            unsafe {
+                archetype.notify_rows(0..archetype.len(), &mut self.observers.on_remove);
+            }
+            self.len -= archetype.len();
+            // SAFETY: `self.entity_allocator` contains entries for the entities stored in this
+            // world's archetypes.
+            unsafe {
+                archetype.clear(&mut self.entity_allocator);
+            }
+            self.auto_compact();
+        }
+    }
+
+    /// Removes all entities, returning an iterator over the entities containing the components of
+    /// `Entity`.
+    ///
+    /// The components are moved out of the `World` and yielded by value as an `Entity`. Any other
+    /// components an entity contains are dropped, and entities that do not contain all of the
+    /// components of `Entity` are dropped without being yielded. The entities are not yielded in
+    /// any specified order.
+    ///
+    /// The `World` is empty after the returned [`Drain`] is dropped, even if it was not fully
+    /// consumed. Keeps the allocated memory for reuse.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(usize);
+    /// #[derive(Debug, PartialEq)]
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    /// world.insert(entity!(Foo(100)));
+    ///
+    /// let mut foos = world
+    ///     .drain::<Entity!(Foo), _>()
+    ///     .map(|(foo, _)| foo.0)
+    ///     .collect::<Vec<_>>();
+    /// foos.sort();
+    ///
+    /// assert_eq!(foos, vec![42, 100]);
+    /// assert!(world.is_empty());
+    /// ```
+    ///
+    /// [`Drain`]: crate::world::Drain
+    pub fn drain<Entity, Indices>(&mut self) -> Drain<Registry, Entity, Indices, S>
+    where
+        Registry: ContainsDrain<Entity, Indices>,
+    {
+        Drain::new(
+            &mut self.archetypes,
+            &mut self.entity_allocator,
+            &mut self.len,
+            &mut self.observers.on_remove,
+            self.auto_compact,
+        )
+    }
+
+    /// Registers a callback to be called whenever a `Component` is added to an entity.
+    ///
+    /// The callback is given the [`entity::Identifier`] of the entity and a reference to the added
+    /// component. It is called for each entity containing `Component` that is inserted through
+    /// [`insert()`], [`extend()`], `par_extend()`, [`insert_batch_mixed()`], or [`merge()`], and
+    /// whenever `Component` is added to an existing entity through [`Entry::add()`]. Replacing a
+    /// component that an entity already contains is not considered an addition.
+    ///
+    /// Callbacks are not given access to the `World`, so they are called as soon as the component
+    /// is in place. Callbacks are called in the order they were registered. They are not cloned
+    /// when the `World` is cloned.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use std::sync::{
+    ///     Arc,
+    ///     Mutex,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    ///
+    /// let added = Arc::new(Mutex::new(Vec::new()));
+    /// let added_clone = Arc::clone(&added);
+    /// world.on_add(move |entity_identifier, foo: &Foo| {
+    ///     added_clone.lock().unwrap().push((entity_identifier, foo.0));
+    /// });
+    ///
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    ///
+    /// assert_eq!(*added.lock().unwrap(), vec![(entity_identifier, 42)]);
+    /// ```
+    ///
+    /// [`Entry::add()`]: crate::world::Entry::add()
+    /// [`extend()`]: World::extend()
+    /// [`insert()`]: World::insert()
+    /// [`insert_batch_mixed()`]: World::insert_batch_mixed()
+    /// [`merge()`]: World::merge()
+    pub fn on_add<Component, Index, Function>(&mut self, function: Function)
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+        Function: FnMut(entity::Identifier, &Component) + Send + Sync + 'static,
+    {
+        self.observers.on_add.push(function);
+    }
+
+    /// Registers a callback to be called whenever a `Component` is removed from an entity.
+    ///
+    /// The callback is given the [`entity::Identifier`] of the entity and a reference to the
+    /// component before it is dropped or moved out of the `World`. It is called for each entity
+    /// containing `Component` that is removed through [`remove()`], [`clear()`], or [`drain()`],
+    /// and whenever `Component` is removed from an existing entity through [`Entry::remove()`].
+    ///
+    /// Callbacks are not given access to the `World`, so they are called while the component is
+    /// still in place. Callbacks are called in the order they were registered. They are not cloned
+    /// when the `World` is cloned.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use std::sync::{
+    ///     Arc,
+    ///     Mutex,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    ///
+    /// let removed = Arc::new(Mutex::new(Vec::new()));
+    /// let removed_clone = Arc::clone(&removed);
+    /// world.on_remove(move |entity_identifier, foo: &Foo| {
+    ///     removed_clone
+    ///         .lock()
+    ///         .unwrap()
+    ///         .push((entity_identifier, foo.0));
+    /// });
+    ///
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    /// world.entry(entity_identifier).unwrap().remove::<Foo, _>();
+    ///
+    /// assert_eq!(*removed.lock().unwrap(), vec![(entity_identifier, 42)]);
+    /// ```
+    ///
+    /// [`clear()`]: World::clear()
+    /// [`drain()`]: World::drain()
+    /// [`Entry::remove()`]: crate::world::Entry::remove()
+    /// [`remove()`]: World::remove()
+    pub fn on_remove<Component, Index, Function>(&mut self, function: Function)
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+        Function: FnMut(entity::Identifier, &Component) + Send + Sync + 'static,
+    {
+        self.observers.on_remove.push(function);
+    }
+
+    /// Registers a callback to be called whenever an entity is moved to a different row within its
+    /// archetype.
+    ///
+    /// Entities made up of the same components are stored densely within an archetype. When an
+    /// entity is removed from an archetype, the entity in the archetype's last row is moved into
+    /// the removed entity's row to keep the storage packed. This happens when an entity is removed
+    /// through [`remove()`], and when an entity changes archetypes through [`Entry::add()`] or
+    /// [`Entry::remove()`]. No callback is made if the removed entity was already in the last row.
+    ///
+    /// The callback is given the [`entity::Identifier`] of the moved entity, its old row index, its
+    /// new row index, and the [`ArchetypeId`] of its archetype. The row indices are the same as
+    /// those given by the [`Index`] view. This allows external buffers indexed by archetype
+    /// position to mirror the move.
+    ///
+    /// Callbacks are not given access to the `World`, and are called in the order they were
+    /// registered. They are not cloned when the `World` is cloned.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use std::sync::{
+    ///     Arc,
+    ///     Mutex,
+    /// };
+    ///
+    /// struct Foo(u32);
+    ///
+    /// type Registry = Registry!(Foo);
+    ///
+    /// let mut world = World::<Registry>::new();
+    ///
+    /// let moves = Arc::new(Mutex::new(Vec::new()));
+    /// let moves_clone = Arc::clone(&moves);
+    /// world.on_move(
+    ///     move |entity_identifier, old_index, new_index, _archetype_id| {
+    ///         moves_clone
+    ///             .lock()
+    ///             .unwrap()
+    ///             .push((entity_identifier, old_index, new_index));
+    ///     },
+    /// );
+    ///
+    /// let removed_entity_identifier = world.insert(entity!(Foo(1)));
+    /// let moved_entity_identifier = world.insert(entity!(Foo(2)));
+    /// world.remove(removed_entity_identifier);
+    ///
+    /// assert_eq!(
+    ///     *moves.lock().unwrap(),
+    ///     vec![(moved_entity_identifier, 1, 0)]
+    /// );
+    /// ```
+    ///
+    /// [`ArchetypeId`]: crate::query::view::ArchetypeId
+    /// [`Entry::add()`]: crate::world::Entry::add()
+    /// [`Entry::remove()`]: crate::world::Entry::remove()
+    /// [`Index`]: crate::query::view::Index
+    /// [`remove()`]: World::remove()
+    pub fn on_move<Function>(&mut self, function: Function)
+    where
+        Function:
+            FnMut(entity::Identifier, usize, usize, view::ArchetypeId) + Send + Sync + 'static,
+    {
+        self.observers.moves.push(function);
+    }
+
+    /// Returns the number of entities in the world.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{entities, Registry, World};
+    ///
+    /// #[derive(Clone)]
+    /// struct Foo(usize);
+    /// #[derive(Clone)]
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.extend(entities!((Foo(42), Bar(false)); 100));
+    ///
+    /// assert_eq!(world.len(), 100);
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of entities in the world made up of exactly the components of `Entity`.
+    ///
+    /// Unlike a query filtered with [`filter::Has`], this only counts entities whose components
+    /// are exactly those of `Entity`, regardless of order. The count is obtained directly from the
+    /// archetype storing entities of this type, without iterating over any entities. If no
+    /// entities of type `Entity` have been stored, this returns `0`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(false)));
+    /// world.insert(entity!(Foo(100)));
+    ///
+    /// assert_eq!(world.len_of::<Entity!(Foo), _>(), 1);
+    /// assert_eq!(world.len_of::<Entity!(Bar), _>(), 0);
+    /// ```
+    ///
+    /// [`filter::Has`]: crate::query::filter::Has
+    #[must_use]
+    pub fn len_of<Entity, Indices>(&self) -> usize
+    where
+        Registry: ContainsEntity<Entity, Indices>,
+    {
+        self.archetypes
+            .get_for_entity::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>()
+            .map_or(0, Archetype::len)
+    }
+
+    /// Returns the number of entities in the world that contain a `Component`.
+    ///
+    /// This counts every entity containing `Component`, regardless of which other components it
+    /// contains. It gives the same count as iterating over a query filtered with
+    /// [`filter::Has<Component>`], but is obtained by summing the lengths of the archetypes
+    /// containing `Component` without iterating over any entities.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(false)));
+    /// world.insert(entity!(Foo(100)));
+    /// world.insert(entity!(Bar(true)));
+    ///
+    /// assert_eq!(world.count_component::<Foo, _>(), 2);
+    /// ```
+    ///
+    /// [`filter::Has<Component>`]: crate::query::filter::Has
+    #[must_use]
+    pub fn count_component<Component, Index>(&self) -> usize
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        self.archetypes
+            .iter()
+            .filter(|archetype| archetype.contains_component::<Component, Index>())
+            .map(Archetype::len)
+            .sum()
+    }
+
+    /// Returns the column of `Component`s stored for entities made up of exactly the components
+    /// of `Entity`.
+    ///
+    /// Entities with the same set of components are stored together in an archetype, with each
+    /// component stored contiguously. This returns that contiguous column directly, allowing
+    /// zero-copy access to the components for bulk operations on entities of a known shape. The
+    /// order of the column matches the order of the archetype's entities, as yielded by
+    /// [`view::Index`].
+    ///
+    /// Returns [`None`] if no entities of type `Entity` have been stored, or if `Entity` does not
+    /// contain `Component`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(1), Bar(false)));
+    /// world.insert(entity!(Foo(2), Bar(true)));
+    /// world.insert(entity!(Foo(3)));
+    ///
+    /// assert_eq!(
+    ///     world.component_column::<Foo, Entity!(Foo, Bar), _, _>(),
+    ///     Some([Foo(1), Foo(2)].as_slice())
+    /// );
+    /// ```
+    ///
+    /// [`None`]: Option::None
+    /// [`view::Index`]: crate::query::view::Index
+    #[must_use]
+    pub fn component_column<Component, Entity, ComponentIndex, EntityIndices>(
+        &self,
+    ) -> Option<&[Component]>
+    where
+        Component: component::Component,
+        Registry:
+            ContainsComponent<Component, ComponentIndex> + ContainsEntity<Entity, EntityIndices>,
+    {
+        self.archetypes
+            .get_for_entity::<<Registry as contains::entity::Sealed<Entity, EntityIndices>>::Canonical, <Registry as contains::entity::Sealed<Entity, EntityIndices>>::CanonicalContainments>()?
+            .column::<Component, ComponentIndex>()
+    }
+
+    /// Returns the column of `Component`s stored for entities made up of exactly the components
+    /// of `Entity` mutably.
+    ///
+    /// This is the mutable counterpart to [`component_column()`]. Since only the components are
+    /// exposed, entities can not be added to or removed from the column.
+    ///
+    /// Returns [`None`] if no entities of type `Entity` have been stored, or if `Entity` does not
+    /// contain `Component`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(1), Bar(false)));
+    /// world.insert(entity!(Foo(2), Bar(true)));
+    ///
+    /// for foo in world
+    ///     .component_column_mut::<Foo, Entity!(Foo, Bar), _, _>()
+    ///     .unwrap()
+    /// {
+    ///     foo.0 *= 10;
+    /// }
+    ///
+    /// assert_eq!(
+    ///     world.component_column::<Foo, Entity!(Foo, Bar), _, _>(),
+    ///     Some([Foo(10), Foo(20)].as_slice())
+    /// );
+    /// ```
+    ///
+    /// [`component_column()`]: World::component_column()
+    /// [`None`]: Option::None
+    #[must_use]
+    pub fn component_column_mut<Component, Entity, ComponentIndex, EntityIndices>(
+        &mut self,
+    ) -> Option<&mut [Component]>
+    where
+        Component: component::Component,
+        Registry:
+            ContainsComponent<Component, ComponentIndex> + ContainsEntity<Entity, EntityIndices>,
+    {
+        self.archetypes
+            .get_mut_for_entity::<<Registry as contains::entity::Sealed<Entity, EntityIndices>>::Canonical, <Registry as contains::entity::Sealed<Entity, EntityIndices>>::CanonicalContainments>()?
+            .column_mut::<Component, ComponentIndex>()
+    }
+
+    /// Returns a pointer to the column of `Component`s stored for entities made up of exactly the
+    /// components of `Entity`, along with the number of components in the column.
+    ///
+    /// This is intended for passing component data across an FFI boundary, such as to a renderer
+    /// written in C, without copying it. The components are stored contiguously, so the column
+    /// can be read as an array of `Component`s of the returned length, with a stride of
+    /// `size_of::<Component>()`. For access from Rust, prefer the safe [`component_column()`].
+    ///
+    /// Returns [`None`] if no entities of type `Entity` have been stored, or if `Entity` does not
+    /// contain `Component`.
+    ///
+    /// # Invalidation
+    /// The returned pointer is only valid until the `World` is next modified structurally. Any
+    /// insertion or removal of entities, adding or removing of components, or reallocation of
+    /// storage (such as through [`reserve()`] or [`shrink_to_fit()`]) may move the column,
+    /// leaving the pointer dangling. The pointer must also not be used to mutate the components,
+    /// and must not be dereferenced while the components are borrowed mutably elsewhere.
+    ///
+    /// For an empty column, the pointer is dangling but well-aligned, and must not be
+    /// dereferenced.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// #[repr(C)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    /// struct Velocity(f32);
+    ///
+    /// type Registry = Registry!(Position, Velocity);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Position { x: 1.0, y: 2.0 }, Velocity(0.5)));
+    /// world.insert(entity!(Position { x: 3.0, y: 4.0 }, Velocity(1.5)));
+    ///
+    /// let (pointer, length) = world
+    ///     .export_column::<Position, Entity!(Position, Velocity), _, _>()
+    ///     .unwrap();
+    ///
+    /// // SAFETY: The world has not been modified since the column was exported.
+    /// let positions = unsafe { core::slice::from_raw_parts(pointer, length) };
+    /// assert_eq!(positions[1], Position { x: 3.0, y: 4.0 });
+    /// ```
+    ///
+    /// [`component_column()`]: World::component_column()
+    /// [`None`]: Option::None
+    /// [`reserve()`]: World::reserve()
+    /// [`shrink_to_fit()`]: World::shrink_to_fit()
+    #[must_use]
+    pub fn export_column<Component, Entity, ComponentIndex, EntityIndices>(
+        &self,
+    ) -> Option<(*const Component, usize)>
+    where
+        Component: component::Component,
+        Registry:
+            ContainsComponent<Component, ComponentIndex> + ContainsEntity<Entity, EntityIndices>,
+    {
+        self.component_column::<Component, Entity, ComponentIndex, EntityIndices>()
+            .map(|column| (column.as_ptr(), column.len()))
+    }
+
+    /// Returns `true` if the world contains no entities.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    ///
+    /// assert!(world.is_empty());
+    ///
+    /// world.insert(entity!(Foo(42), Bar(false)));
+    ///
+    /// assert!(!world.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the component identified by `type_id` is contained in the `Registry`.
+    ///
+    /// This allows checking for a component at runtime, without requiring a `ContainsComponent`
+    /// bound. The `TypeId`s of the registry's components are collected once when the `World` is
+    /// created, so this does not need to traverse the `Registry`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use core::any::TypeId;
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    /// struct Baz(char);
+    ///
+    /// let world = World::<Registry!(Foo, Bar)>::new();
+    ///
+    /// assert!(world.is_registered(TypeId::of::<Foo>()));
+    /// assert!(!world.is_registered(TypeId::of::<Baz>()));
+    /// ```
+    #[must_use]
+    pub fn is_registered(&self, type_id: TypeId) -> bool {
+        self.component_type_ids.contains(&type_id)
+    }
+
+    /// Returns `true` if both worlds contain the same entities and resources, ignoring the values
+    /// of the entities' [`entity::Identifier`]s.
+    ///
+    /// Unlike [`PartialEq`], which requires each entity to have the same identifier in both
+    /// worlds, this only requires that each world contain the same multiset of entities. This is
+    /// useful for comparing worlds built through different sequences of insertions and removals,
+    /// such as in round-trip tests.
+    ///
+    /// Note that this comparison is quadratic in the number of entities within each archetype,
+    /// since components are only required to implement `PartialEq`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(PartialEq)]
+    /// struct Foo(usize);
+    /// #[derive(PartialEq)]
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world_a = World::<Registry>::new();
+    /// world_a.insert(entity!(Foo(1)));
+    /// world_a.insert(entity!(Foo(2), Bar(true)));
+    ///
+    /// let mut world_b = World::<Registry>::new();
+    /// let entity_identifier = world_b.insert(entity!(Foo(3)));
+    /// world_b.remove(entity_identifier);
+    /// world_b.insert(entity!(Foo(2), Bar(true)));
+    /// world_b.insert(entity!(Foo(1)));
+    ///
+    /// assert!(world_a != world_b);
+    /// assert!(world_a.structurally_eq(&world_b));
+    /// ```
+    ///
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    /// [`PartialEq`]: core::cmp::PartialEq
+    #[must_use]
+    pub fn structurally_eq(&self, other: &Self) -> bool
+    where
+        Registry: registry::PartialEq,
+        Resources: PartialEq,
+    {
+        self.len == other.len
+            && self.archetypes.component_multiset_eq(&other.archetypes)
+            && self.resources == other.resources
+    }
+
+    /// Returns the number of components in the `Registry` of this world.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// assert_eq!(World::<Registry>::registry_len(), 2);
+    /// ```
+    #[must_use]
+    pub const fn registry_len() -> usize {
+        Registry::LEN
+    }
+
+    /// Returns the names of the components in the `Registry` of this world, in the order they
+    /// were defined.
+    ///
+    /// Each name is obtained using [`type_name()`]. As such, the names are intended for
+    /// diagnostic purposes, and their exact contents are not guaranteed to be stable.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let names = World::<Registry>::component_names();
+    ///
+    /// assert_eq!(names.len(), 2);
+    /// assert!(names[0].ends_with("Foo"));
+    /// assert!(names[1].ends_with("Bar"));
+    /// ```
+    ///
+    /// [`type_name()`]: core::any::type_name()
+    #[must_use]
+    pub fn component_names() -> Vec<&'static str> {
+        let mut names = Vec::with_capacity(Registry::LEN);
+        Registry::extend_component_names(&mut names);
+        names
+    }
+
+    /// Asserts at compile time that `Component` is contained in the `Registry` of this world.
+    ///
+    /// This does nothing at run time. Its purpose is to surface a missing component as a single,
+    /// clear error: if `Component` is not in the `Registry`, compilation fails on the
+    /// [`ContainsComponent`] bound of this method, rather than on an inferred index deep within a
+    /// query or component access. This is useful as a guard in generic code that is parameterized
+    /// over component types, as well as for documenting which components a piece of code expects
+    /// to be registered.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     registry,
+    ///     registry::ContainsComponent,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// fn count<R, I>(world: &World<R>) -> usize
+    /// where
+    ///     R: registry::Registry + ContainsComponent<Foo, I>,
+    /// {
+    ///     World::<R>::assert_registered::<Foo, I>();
+    ///     world.count_component::<Foo, I>()
+    /// }
+    ///
+    /// World::<Registry>::assert_registered::<Bar, _>();
+    /// assert_eq!(count(&World::<Registry>::new()), 0);
+    /// ```
+    ///
+    /// Using a component that is not registered fails to compile.
+    ///
+    /// ``` compile_fail
+    /// use brood::{
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// World::<Registry!(Foo)>::assert_registered::<Bar, _>();
+    /// ```
+    ///
+    /// [`ContainsComponent`]: crate::registry::ContainsComponent
+    #[inline]
+    pub const fn assert_registered<Component, Index>()
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+    }
+
+    /// Shrinks the allocated capacity of the internal storage as much as possible.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{entities, Registry, World};
+    ///
+    /// #[derive(Clone)]
+    /// struct Foo(usize);
+    /// #[derive(Clone)]
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    ///
+    /// world.extend(entities!((Foo(42), Bar(false)); 10));
+    /// world.clear();
+    /// world.extend(entities!((Foo(42), Bar(false)); 3));
+    ///
+    /// // This will reduce the current allocation.
+    /// world.shrink_to_fit();
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.archetypes.shrink_to_fit();
+        self.entity_allocator.shrink_to_fit();
+    }
+
+    /// Shrinks the allocated capacity of the internal storage as much as possible, in parallel.
+    ///
+    /// This is the same as [`shrink_to_fit()`], except that the storage for each distinct
+    /// combination of components is shrunk in parallel. This is useful when compacting large
+    /// `World`s with many different combinations of components, such as after loading.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{entities, Registry, World};
+    ///
+    /// #[derive(Clone)]
+    /// struct Foo(usize);
+    /// #[derive(Clone)]
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    ///
+    /// world.extend(entities!((Foo(42), Bar(false)); 10));
+    /// world.extend(entities!((Foo(42)); 10));
+    /// world.clear();
+    /// world.extend(entities!((Foo(42), Bar(false)); 3));
+    ///
+    /// // This will reduce the current allocations.
+    /// world.par_shrink_to_fit();
+    /// ```
+    ///
+    /// [`shrink_to_fit()`]: World::shrink_to_fit()
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+    pub fn par_shrink_to_fit(&mut self)
+    where
+        Registry: Send,
+    {
+        self.archetypes.par_shrink_to_fit();
+        self.entity_allocator.shrink_to_fit();
+    }
+
+    /// Shrinks the allocated capacity of the internal storage, leaving headroom for future
+    /// growth.
+    ///
+    /// The storage for entities made up of the same components is only shrunk if its capacity
+    /// exceeds the number of those entities multiplied by `min_capacity_ratio`, in which case it
+    /// is shrunk to that multiple. Otherwise, it is left unchanged. This avoids repeatedly freeing
+    /// and reallocating storage in `World`s whose number of entities fluctuates, at the cost of
+    /// retaining some unused memory. Storage for entities that are no longer present at all is
+    /// removed entirely.
+    ///
+    /// A `min_capacity_ratio` of `1.0` shrinks the storage as much as possible, as in
+    /// [`shrink_to_fit()`]. Ratios less than `1.0` are treated as `1.0`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entities,
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone)]
+    /// struct Foo(usize);
+    /// #[derive(Clone)]
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    ///
+    /// world.reserve_exact::<Entity!(Foo, Bar), _>(100);
+    /// world.extend(entities!((Foo(42), Bar(false)); 10));
+    ///
+    /// // Shrinks the storage, keeping room for twice the current number of entities.
+    /// world.shrink_to(2.0);
+    /// assert_eq!(world.capacity::<Entity!(Foo, Bar), _>(), 20);
+    ///
+    /// // The storage is already within the ratio, so nothing is shrunk.
+    /// world.shrink_to(4.0);
+    /// assert_eq!(world.capacity::<Entity!(Foo, Bar), _>(), 20);
+    /// ```
+    ///
+    /// [`shrink_to_fit()`]: World::shrink_to_fit()
+    pub fn shrink_to(&mut self, min_capacity_ratio: f32) {
+        self.archetypes.shrink_to(min_capacity_ratio);
+    }
+
+    /// Sets the ratio of empty storage above which it is removed automatically.
+    ///
+    /// Entities are stored separately for each distinct combination of components. When the last
+    /// entity with a combination of components is removed or changes its components, the storage
+    /// for that combination remains, and is only removed by [`shrink_to_fit()`] or
+    /// [`shrink_to()`]. In `World`s where entities frequently change their components, this
+    /// empty storage can accumulate over time, slowing down queries that must visit it.
+    ///
+    /// When a ratio is set, the empty storage is removed whenever removing an entity or changing
+    /// its components leaves the number of empty storages greater than `max_empty_ratio`
+    /// multiplied by the total number of storages. Unlike [`shrink_to_fit()`], the capacity of
+    /// storage that is not empty is left unchanged. A ratio of `0.0` removes empty storage as soon
+    /// as it becomes empty. Passing `None` disables automatic removal, which is the default. The
+    /// ratio is kept when the `World` is cloned, including through [`clone_reordered()`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.set_auto_compact(Some(0.25));
+    ///
+    /// world.insert(entity!(Foo(1)));
+    /// let entity_identifier = world.insert(entity!(Foo(2), Bar(true)));
+    ///
+    /// // The storage for entities with `Foo` and `Bar` becomes empty, and is removed.
+    /// world.entry(entity_identifier).unwrap().remove::<Bar, _>();
+    /// assert_eq!(world.memory_usage_per_archetype().count(), 1);
+    /// ```
+    ///
+    /// [`clone_reordered()`]: World::clone_reordered()
+    /// [`shrink_to()`]: World::shrink_to()
+    /// [`shrink_to_fit()`]: World::shrink_to_fit()
+    pub fn set_auto_compact(&mut self, max_empty_ratio: Option<f32>) {
+        self.auto_compact = max_empty_ratio;
+    }
+
+    /// Removes empty archetypes if automatic compaction is enabled and its ratio is exceeded.
+    ///
+    /// This should be called after an operation leaves an archetype empty.
+    fn auto_compact(&mut self) {
+        if let Some(max_empty_ratio) = self.auto_compact {
+            self.archetypes.compact(max_empty_ratio);
+        }
+    }
+
+    /// Converts the `World` into a [`FrozenWorld`], which only allows queries and other read
+    /// operations.
+    ///
+    /// The storage is first shrunk as much as possible, as with [`shrink_to_fit()`], and the
+    /// storage for entities of differing components is reordered, so that iteration order
+    /// depends only on which combinations of components are stored. This means that two `World`s
+    /// containing the same kinds of entities iterate over them in the same order once frozen,
+    /// regardless of how they were built, provided their hasher is deterministic, as the default
+    /// hasher is.
+    ///
+    /// The `World` can be recovered using [`FrozenWorld::thaw()`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(false)));
+    ///
+    /// let frozen_world = world.freeze();
+    /// assert_eq!(frozen_world.len(), 1);
+    /// ```
+    ///
+    /// [`FrozenWorld`]: crate::world::FrozenWorld
+    /// [`FrozenWorld::thaw()`]: crate::world::FrozenWorld::thaw()
+    /// [`shrink_to_fit()`]: World::shrink_to_fit()
+    #[must_use]
+    pub fn freeze(mut self) -> FrozenWorld<Registry, Resources, S> {
+        self.shrink_to_fit();
+        self.archetypes.sort();
+        FrozenWorld::new(self)
+    }
+
+    /// Shrinks the allocated capacity of the storage for entities of type `E` as much as possible.
+    ///
+    /// Unlike [`shrink_to_fit()`], only the storage for entities containing exactly the
+    /// components of `E` is touched. If no entities of type `E` are stored, their storage is
+    /// removed entirely.
+    ///
+    /// Note that the storage is shared by all entities that contain the components of `E`,
+    /// regardless of order.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entities,
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone)]
+    /// struct Foo(usize);
+    /// #[derive(Clone)]
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    ///
+    /// world.extend(entities!((Foo(42), Bar(false)); 10));
+    /// world.clear();
+    /// world.extend(entities!((Foo(42), Bar(false)); 3));
+    ///
+    /// // This will reduce the current allocation for entities of type `(Foo, Bar)` only.
+    /// world.shrink_archetype::<Entity!(Foo, Bar), _>();
+    /// ```
+    ///
+    /// [`shrink_to_fit()`]: World::shrink_to_fit()
+    pub fn shrink_archetype<Entity, Indices>(&mut self)
+    where
+        Registry: ContainsEntity<Entity, Indices>,
+    {
+        self.archetypes.shrink_archetype_to_fit::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>();
+    }
+
+    /// Reserve capacity for at least `additional` more entities of type `E`.
+    ///
+    /// Note that the capacity is reserved for all future entities that contain the components of
+    /// `E`, regardless of order.
+    ///
+    /// # Panics
+    /// Panics if the new capacity for entities of type `E` exceeds `isize::MAX` bytes.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    ///
+    /// world.reserve::<Entity!(Foo, Bar), _>(10);
+    /// ```
+    pub fn reserve<Entity, Indices>(&mut self, additional: usize)
+    where
+        Registry: ContainsEntity<Entity, Indices>,
+    {
+        // SAFETY: Since the canonical entity form is used, the archetype obtained is guaranteed to
+        // be the unique archetype for entities of type `Entity`.
+        //
+        // Additionally, the same entity type is used for the call to `reserve`, meaning that the
+        // set of components in the entity are guaranteed to be the same set as those in the
+        // archetype.
+        unsafe {
+            self.archetypes
+                .get_mut_or_insert_new_for_entity::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>()
+                .reserve::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical>(additional);
+        }
+    }
+
+    /// Reserve capacity for exactly `additional` more entities of type `E`.
+    ///
+    /// Unlike [`reserve()`], this will not deliberately over-allocate to avoid frequent
+    /// reallocations. This is useful when the final number of entities is known ahead of time.
+    /// Note that the allocator may still give more space than is requested.
+    ///
+    /// Note that the capacity is reserved for all future entities that contain the components of
+    /// `E`, regardless of order.
+    ///
+    /// # Panics
+    /// Panics if the new capacity for entities of type `E` exceeds `isize::MAX` bytes.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    ///
+    /// world.reserve_exact::<Entity!(Foo, Bar), _>(10);
+    /// ```
+    ///
+    /// [`reserve()`]: World::reserve()
+    pub fn reserve_exact<Entity, Indices>(&mut self, additional: usize)
+    where
+        Registry: ContainsEntity<Entity, Indices>,
+    {
+        // SAFETY: Since the canonical entity form is used, the archetype obtained is guaranteed to
+        // be the unique archetype for entities of type `Entity`.
+        //
+        // Additionally, the same entity type is used for the call to `reserve_exact`, meaning that
+        // the set of components in the entity are guaranteed to be the same set as those in the
+        // archetype.
+        unsafe {
+            self.archetypes
+                .get_mut_or_insert_new_for_entity::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>()
+                .reserve_exact::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical>(additional);
+        }
+    }
+
+    /// Reserve capacity for at least `additional` more entities of every type currently stored.
+    ///
+    /// Unlike [`reserve()`], which targets entities made up of a single set of components, this
+    /// reserves capacity in the storage for every set of components the `World` currently knows
+    /// about, including storage that was previously created by [`reserve()`] but is currently
+    /// empty. This is useful before inserting a large batch of entities whose components vary.
+    ///
+    /// # Panics
+    /// Panics if the new capacity for entities of any type exceeds `isize::MAX` bytes.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    /// world.insert(entity!(Foo(100)));
+    ///
+    /// world.reserve_all(10);
+    ///
+    /// assert!(world.capacity::<Entity!(Foo, Bar), _>() >= 11);
+    /// assert!(world.capacity::<Entity!(Foo), _>() >= 11);
+    /// ```
+    ///
+    /// [`reserve()`]: World::reserve()
+    pub fn reserve_all(&mut self, additional: usize) {
+        self.archetypes.reserve_all(additional);
+    }
+
+    /// Returns the number of entities of type `E` the `World` can hold without reallocating.
+    ///
+    /// If no entities of type `E` have been stored and no capacity has been reserved for them,
+    /// this returns `0`.
+    ///
+    /// Note that the capacity is shared by all entities that contain the components of `E`,
+    /// regardless of order.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     Entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(usize);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// assert_eq!(world.capacity::<Entity!(Foo, Bar), _>(), 0);
+    ///
+    /// world.reserve_exact::<Entity!(Foo, Bar), _>(10);
+    /// assert!(world.capacity::<Entity!(Foo, Bar), _>() >= 10);
+    /// ```
+    #[must_use]
+    pub fn capacity<Entity, Indices>(&self) -> usize
+    where
+        Registry: ContainsEntity<Entity, Indices>,
+    {
+        self.archetypes
+            .get_for_entity::<<Registry as contains::entity::Sealed<Entity, Indices>>::Canonical, <Registry as contains::entity::Sealed<Entity, Indices>>::CanonicalContainments>()
+            .map_or(0, Archetype::capacity)
+    }
+
+    /// Returns an estimate of the number of bytes allocated for storing entities.
+    ///
+    /// This is the sum of the allocated capacities of the component columns and entity identifier
+    /// columns of every archetype, each multiplied by the size of the type it stores. Memory used
+    /// by other bookkeeping, such as the entity allocator and resources, is not included. As such,
+    /// this is a coarse figure intended for tuning memory usage, for example by checking the effect
+    /// of [`shrink_to_fit()`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u64);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// assert_eq!(world.memory_usage(), 0);
+    ///
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    /// assert!(world.memory_usage() >= 9);
+    /// ```
+    ///
+    /// [`shrink_to_fit()`]: World::shrink_to_fit()
+    #[must_use]
+    pub fn memory_usage(&self) -> usize {
+        self.archetypes.iter().map(Archetype::memory_usage).sum()
+    }
+
+    /// Returns an iterator over an estimate of the number of bytes allocated by each archetype.
+    ///
+    /// Each item is the bytes identifying the archetype, in the same format as returned by
+    /// [`archetype_identifier()`], paired with the archetype's estimated memory usage, calculated
+    /// the same way as for [`memory_usage()`]. This can be used to find which combinations of
+    /// components use the most memory. The archetypes are not yielded in any specified order.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u64);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    /// world.insert(entity!(Bar(false)));
+    ///
+    /// let heaviest = world
+    ///     .memory_usage_per_archetype()
+    ///     .max_by_key(|&(_, bytes)| bytes)
+    ///     .map(|(identifier, _)| identifier);
+    /// assert_eq!(heaviest, Some(vec![0b11]));
+    /// ```
+    ///
+    /// [`archetype_identifier()`]: World::archetype_identifier()
+    /// [`memory_usage()`]: World::memory_usage()
+    pub fn memory_usage_per_archetype(&self) -> impl Iterator<Item = (Vec<u8>, usize)> + '_ {
+        self.archetypes.iter().map(|archetype| {
+            (
+                // SAFETY: The `IdentifierRef` does not outlive `archetype`.
+                unsafe { archetype.identifier() }.as_vec(),
+                archetype.memory_usage(),
+            )
+        })
+    }
+
+    /// Returns the number of entities containing each component.
+    ///
+    /// The returned map is keyed by the [`TypeId`] of each component. Components that are not
+    /// contained in any entity are not included.
+    ///
+    /// This is computed in a single pass over the archetypes, and is intended as a diagnostic aid
+    /// for understanding the composition of the `World`. The [`TypeId`]s can be paired with the
+    /// names returned by [`component_names()`] to present this information in a readable form.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    /// use core::any::TypeId;
+    ///
+    /// struct Foo(u64);
+    /// struct Bar(bool);
+    /// struct Baz(char);
+    ///
+    /// type Registry = Registry!(Foo, Bar, Baz);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(Foo(42), Bar(true)));
+    /// world.insert(entity!(Foo(100)));
+    ///
+    /// let histogram = world.component_histogram();
+    ///
+    /// assert_eq!(histogram.get(&TypeId::of::<Foo>()), Some(&2));
+    /// assert_eq!(histogram.get(&TypeId::of::<Bar>()), Some(&1));
+    /// assert_eq!(histogram.get(&TypeId::of::<Baz>()), None);
+    /// ```
+    ///
+    /// [`component_names()`]: World::component_names()
+    /// [`TypeId`]: core::any::TypeId
+    #[must_use]
+    pub fn component_histogram(&self) -> HashMap<TypeId, usize, FnvBuildHasher> {
+        let mut histogram =
+            HashMap::with_capacity_and_hasher(Registry::LEN, FnvBuildHasher::default());
+        let mut type_ids = Vec::new();
+        for archetype in self.archetypes.iter() {
+            if archetype.is_empty() {
+                continue;
+            }
+            // SAFETY: The iterator returned by `archetype.identifier().iter()` is generic over the
+            // same `Registry` on which `extend_component_type_ids()` is called. Additionally, the
+            // iterator does not outlive the archetype identifier, as it is only used within this
+            // statement.
+            unsafe {
+                Registry::extend_component_type_ids(&mut type_ids, archetype.identifier().iter());
+            }
+            for type_id in type_ids.drain(..) {
+                *histogram.entry(type_id).or_insert(0) += archetype.len();
+            }
+        }
+        histogram
+    }
+
+    /// View a single resource immutably.
+    ///
+    /// The `Index` parameter can be inferred.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     resources,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Resource(u32);
+    ///
+    /// let world = World::<Registry!(), _>::with_resources(resources!(Resource(100)));
+    ///
+    /// assert_eq!(world.get::<Resource, _>(), &Resource(100));
+    /// ```
+    pub fn get<Resource, Index>(&self) -> &Resource
+    where
+        Resources: ContainsResource<Resource, Index>,
+    {
+        self.resources.get()
+    }
+
+    /// View a single resource mutably.
+    ///
+    /// The `Index` parameter can be inferred.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     resources,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Resource(u32);
+    ///
+    /// let mut world = World::<Registry!(), _>::with_resources(resources!(Resource(100)));
+    ///
+    /// world.get_mut::<Resource, _>().0 *= 2;
+    /// assert_eq!(world.get::<Resource, _>(), &Resource(200));
+    /// ```
+    pub fn get_mut<Resource, Index>(&mut self) -> &mut Resource
+    where
+        Resources: ContainsResource<Resource, Index>,
+    {
+        self.resources.get_mut()
+    }
+
+    /// View multiple resources at once.
+    ///
+    /// Any subset of the stored resources can be viewed, each either immutably or mutably. The
+    /// views must be disjoint: each resource may appear at most once within `Views`, so a resource
+    /// can never be borrowed mutably more than once, or both mutably and immutably. This is checked
+    /// at compile-time, so viewing a resource that is not stored or viewing the same resource
+    /// twice will fail to compile:
+    ///
+    /// ``` compile_fail
+    /// use brood::{
+    ///     query::Views,
+    ///     resources,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct ResourceA(u32);
+    ///
+    /// let mut world = World::<Registry!(), _>::with_resources(resources!(ResourceA(0)));
+    ///
+    /// // This does not compile, because `ResourceA` is viewed twice.
+    /// world.view_resources::<Views!(&mut ResourceA, &ResourceA), _>();
+    /// ```
+    ///
+    /// All generic parameters besides `Views` can be omitted.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     resources,
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct ResourceA(u32);
+    /// #[derive(Debug, PartialEq)]
+    /// struct ResourceB(char);
+    ///
+    /// let mut world =
+    ///     World::<Registry!(), _>::with_resources(resources!(ResourceA(0), ResourceB('a')));
+    ///
+    /// let result!(a, b) = world.view_resources::<Views!(&ResourceA, &mut ResourceB), _>();
+    ///
+    /// assert_eq!(a, &ResourceA(0));
+    ///
+    /// b.0 = 'b';
+    /// assert_eq!(b, &mut ResourceB('b'));
+    /// ```
+    pub fn view_resources<'a, Views, Indices>(&'a mut self) -> Views
+    where
+        Resources: ContainsViews<'a, Views, Indices>,
+    {
+        self.resources.view()
+    }
+}
+
+impl<Registry, Resources, S> World<Registry, Resources, S>
+where
+    Registry: registry::Clone,
+    Resources: Clone,
+    S: BuildHasher + Clone,
+{
+    /// Takes a snapshot of the `World`, to later be restored using [`restore_from()`].
+    ///
+    /// This is equivalent to calling [`clone()`], and exists to make checkpointing a `World` more
+    /// explicit, such as for rewinding gameplay or for rollback networking. All entities and
+    /// resources are copied in memory, without any serialization.
+    ///
+    /// Any [`entity::Identifier`]s that are valid for the `World` are also valid for the snapshot,
+    /// referring to the same entities. Observers are not included in the snapshot.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone)]
+    /// struct Position(u32);
+    ///
+    /// let mut world = World::<Registry!(Position)>::new();
+    /// let entity_identifier = world.insert(entity!(Position(0)));
+    ///
+    /// let snapshot = world.snapshot();
+    ///
+    /// for result!(position) in world.query(Query::<Views!(&mut Position)>::new()).iter {
+    ///     position.0 += 1;
+    /// }
+    ///
+    /// world.restore_from(&snapshot);
+    ///
+    /// let mut entry = world.entry(entity_identifier).unwrap();
+    /// let result!(position) = entry.query(Query::<Views!(&Position)>::new()).unwrap();
+    /// assert_eq!(position.0, 0);
+    /// ```
+    ///
+    /// [`clone()`]: Clone::clone()
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    /// [`restore_from()`]: World::restore_from()
+    #[must_use]
+    pub fn snapshot(&self) -> Self {
+        self.clone()
+    }
+
+    /// Restores the `World` to the state stored in a snapshot taken with [`snapshot()`].
+    ///
+    /// This is equivalent to calling [`clone_from()`], reusing the `World`'s existing allocations
+    /// where possible. This makes repeatedly restoring from snapshots cheaper than replacing the
+    /// `World` with a new clone.
+    ///
+    /// # Entity Identifiers
+    /// After restoring, exactly the [`entity::Identifier`]s that were valid when the snapshot was
+    /// taken are valid again, and each refers to the same entity it did at that time. This holds
+    /// even if the entity was removed in the meantime.
+    ///
+    /// Identifiers of entities inserted after the snapshot was taken are invalid after restoring.
+    /// These identifiers should be discarded, since the `World` may allocate the same identifiers
+    /// again when new entities are inserted after restoring.
+    ///
+    /// Observers registered on the `World` are kept.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone)]
+    /// struct Foo(u32);
+    ///
+    /// let mut world = World::<Registry!(Foo)>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42)));
+    ///
+    /// let snapshot = world.snapshot();
+    ///
+    /// world.remove(entity_identifier);
+    /// let new_entity_identifier = world.insert(entity!(Foo(100)));
+    ///
+    /// world.restore_from(&snapshot);
+    ///
+    /// assert!(world.contains(entity_identifier));
+    /// assert!(!world.contains(new_entity_identifier));
+    /// ```
+    ///
+    /// [`clone_from()`]: Clone::clone_from()
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    /// [`snapshot()`]: World::snapshot()
+    pub fn restore_from(&mut self, snapshot: &Self) {
+        self.clone_from(snapshot);
+    }
+
+    /// Clones the `World` into a `World` using the registry `NewOrder`, which must contain exactly
+    /// the same components as `Registry`, in any order.
+    ///
+    /// The order of components in a registry determines the order of the bits in each archetype
+    /// identifier, as well as the order of each archetype's component columns. This allows the
+    /// registry order to be tuned, such as by declaring frequently accessed components first,
+    /// without changing the original `World`. Every archetype's identifier and columns are
+    /// remapped to the new order.
+    ///
+    /// Any [`entity::Identifier`]s that are valid for the `World` are also valid for the clone,
+    /// referring to the same entities. As with [`clone()`], observers are not cloned.
+    ///
+    /// Cloning into a registry that does not contain exactly the same components fails to
+    /// compile. To convert into a registry with additional components, use [`migrate()`].
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     Query,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone)]
+    /// struct Position(f32);
+    /// #[derive(Clone)]
+    /// struct Velocity(f32);
+    ///
+    /// let mut world = World::<Registry!(Position, Velocity)>::new();
+    /// let entity_identifier = world.insert(entity!(Position(1.0), Velocity(2.0)));
+    ///
+    /// let mut reordered = world.clone_reordered::<Registry!(Velocity, Position), _, _>();
+    ///
+    /// let mut entry = reordered.entry(entity_identifier).unwrap();
+    /// let result!(position, velocity) = entry
+    ///     .query(Query::<Views!(&Position, &Velocity)>::new())
+    ///     .unwrap();
+    /// assert_eq!(position.0, 1.0);
+    /// assert_eq!(velocity.0, 2.0);
+    /// ```
+    ///
+    /// [`clone()`]: Clone::clone()
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    /// [`migrate()`]: World::migrate()
+    #[must_use]
+    pub fn clone_reordered<NewOrder, Indices, ReverseIndices>(
+        &self,
+    ) -> World<NewOrder, Resources, S>
+    where
+        NewOrder: ContainsRegistry<Registry, Indices>,
+        Registry: ContainsRegistry<NewOrder, ReverseIndices>,
+    {
+        self.clone().migrate()
+    }
+}
+
+impl<Registry, Resources, S> World<Registry, Resources, S>
+where
+    Registry: registry::Diff,
+    S: BuildHasher,
+{
+    /// Computes the changes made to this `World` since `previous`.
+    ///
+    /// Entities are matched by their [`entity::Identifier`]s. Every entity in `previous` that is
+    /// no longer in this `World` is recorded as removed. Every entity in this `World` that was not
+    /// in `previous` is recorded as added, along with all of its components. For every other
+    /// entity, each component is compared using `PartialEq`, and any added, changed, or removed
+    /// components are recorded. Entities whose components are all unchanged are not recorded.
+    ///
+    /// Resources are not compared.
+    ///
+    /// The returned [`WorldDiff`] can be applied to a copy of `previous` using [`apply_diff()`].
+    /// This is useful for replicating a `World` over a network, where sending only the changes is
+    /// much cheaper than sending the entire `World`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Position(u32);
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Health(u32);
+    ///
+    /// type Registry = Registry!(Position, Health);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let player = world.insert(entity!(Position(0), Health(100)));
+    /// world.insert(entity!(Position(5)));
+    /// let previous = world.snapshot();
+    ///
+    /// world.entry(player).unwrap().add(Position(1));
+    ///
+    /// let diff = world.diff(&previous);
+    /// assert!(diff.removed().is_empty());
+    /// assert_eq!(diff.changed().collect::<Vec<_>>(), vec![player]);
+    /// ```
+    ///
+    /// [`apply_diff()`]: World::apply_diff()
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    #[must_use]
+    pub fn diff<PreviousResources, PreviousS>(
+        &self,
+        previous: &World<Registry, PreviousResources, PreviousS>,
+    ) -> WorldDiff<Registry>
+    where
+        PreviousS: BuildHasher,
+    {
+        let mut diff = WorldDiff::new();
+
+        for archetype in previous.archetypes.iter() {
+            diff.removed.extend(
+                archetype
+                    .entity_identifiers()
+                    .iter()
+                    .copied()
+                    .filter(|&entity_identifier| {
+                        !self.entity_allocator.is_active(entity_identifier)
+                    }),
+            );
+        }
+
+        for archetype in self.archetypes.iter() {
+            for (index, &entity_identifier) in archetype.entity_identifiers().iter().enumerate() {
+                let previous_row =
+                    previous
+                        .entity_allocator
+                        .get(entity_identifier)
+                        .and_then(|location| {
+                            Some((
+                                previous.archetypes.get(location.identifier)?,
+                                location.index,
+                            ))
+                        });
+                let added = previous_row.is_none();
+                // SAFETY: `index` is the index of a row within `archetype`, since it was obtained
+                // from the archetype's entity identifiers. `previous_row` contains the location of
+                // an entity stored within `previous`, which is guaranteed to be a valid row within
+                // its archetype.
+                let (changes, any_changed) = unsafe { archetype.row_changes(index, previous_row) };
+                if added || any_changed {
+                    diff.changed.push((entity_identifier, changes));
+                }
+            }
+        }
+
+        diff
+    }
+
+    /// Applies the changes recorded in `diff` to this `World`.
+    ///
+    /// The removed entities are removed, the added entities are inserted using the same
+    /// [`entity::Identifier`]s, and the changed components of all other entities are updated. This
+    /// brings a `World` that is equal to the `previous` `World` passed to [`diff()`] up to date
+    /// with the `World` the diff was computed on.
+    ///
+    /// # Errors
+    /// Returns a [`SpawnError`] if an added entity can not be inserted using its identifier, which
+    /// can only happen if this `World` was not equal to the previous `World` when the diff was
+    /// computed. Every added entity is checked before any changes are made, so in that case the
+    /// `World` is left unchanged.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Position(u32);
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Health(u32);
+    ///
+    /// type Registry = Registry!(Position, Health);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let player = world.insert(entity!(Position(0), Health(100)));
+    /// let mut replica = world.snapshot();
+    ///
+    /// world.entry(player).unwrap().remove::<Health, _>();
+    /// world.insert(entity!(Position(5)));
+    ///
+    /// let diff = world.diff(&replica);
+    /// replica.apply_diff(diff).unwrap();
+    ///
+    /// assert!(world.diff(&replica).is_empty());
+    /// ```
+    ///
+    /// [`diff()`]: World::diff()
+    /// [`entity::Identifier`]: crate::entity::Identifier
+    pub fn apply_diff(
+        &mut self,
+        diff: WorldDiff<Registry>,
+    ) -> core::result::Result<(), SpawnError> {
+        // The slots of removed entities are vacated before any entities are added, so their
+        // generations are recorded to check the added entities against.
+        let mut vacated = HashMap::with_hasher(FnvBuildHasher::default());
+        for &entity_identifier in &diff.removed {
+            if self.contains(entity_identifier) {
+                vacated.insert(entity_identifier.index, entity_identifier.generation);
+            }
+        }
+        let mut added = HashMap::with_hasher(FnvBuildHasher::default());
+        for &(entity_identifier, _) in &diff.changed {
+            if let Some(&generation) = added.get(&entity_identifier.index) {
+                if generation == entity_identifier.generation {
+                    continue;
+                }
+                return Err(SpawnError::Occupied);
+            }
+            if let Some(&generation) = vacated.get(&entity_identifier.index) {
+                if entity_identifier.generation < generation.wrapping_add(1) {
+                    return Err(SpawnError::GenerationRegression);
+                }
+            } else if self.contains(entity_identifier) {
+                continue;
+            } else {
+                self.entity_allocator.check_vacant(entity_identifier)?;
+            }
+            added.insert(entity_identifier.index, entity_identifier.generation);
+        }
+
+        for entity_identifier in diff.removed {
+            self.remove(entity_identifier);
+        }
+
+        for (entity_identifier, changes) in diff.changed {
+            if !self.contains(entity_identifier) {
+                self.entity_allocator.check_vacant(entity_identifier)?;
+
+                self.len += 1;
+
+                let archetype = self.archetypes.get_mut_or_insert_new(
+                    // SAFETY: The buffer is of length `(R::LEN + 7) / 8`.
+                    unsafe { archetype::Identifier::new(vec![0; (Registry::LEN + 7) / 8]) },
+                );
+                // SAFETY: The archetype identifies no components, which are the components of an
+                // empty entity. `entity_identifier` was checked to be vacant above, and
+                // `self.entity_allocator` is guaranteed to live as long as the archetype.
+                unsafe {
+                    archetype.push_at(entity::Null, entity_identifier, &mut self.entity_allocator);
+                }
+            }
+
+            if let Some(mut entry) = self.entry(entity_identifier) {
+                entry.modify(|modifier| Registry::apply_changes(changes, modifier));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Registry, S> World<Registry, resource::Dynamic, S>
+where
+    Registry: registry::Registry,
+{
+    /// Insert a resource at runtime, returning the previously stored resource of the same type,
+    /// if one existed.
+    ///
+    /// This is only available on `World`s using [`resource::Dynamic`] resources.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     resource,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Resource(u32);
+    ///
+    /// let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+    ///
+    /// world.insert_resource(Resource(42));
+    /// assert_eq!(world.get_resource::<Resource>(), Some(&Resource(42)));
+    /// ```
+    pub fn insert_resource<Resource>(&mut self, resource: Resource) -> Option<Resource>
+    where
+        Resource: resource::Resource + Send + Sync,
+    {
+        self.resources.insert(resource)
+    }
+
+    /// Remove a resource at runtime, returning it if it was stored.
+    ///
+    /// This is only available on `World`s using [`resource::Dynamic`] resources.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     resource,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Resource(u32);
+    ///
+    /// let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+    /// world.insert_resource(Resource(42));
+    ///
+    /// assert_eq!(world.remove_resource::<Resource>(), Some(Resource(42)));
+    /// assert_eq!(world.get_resource::<Resource>(), None);
+    /// ```
+    pub fn remove_resource<Resource>(&mut self) -> Option<Resource>
+    where
+        Resource: resource::Resource,
+    {
+        self.resources.remove()
+    }
+
+    /// View a single runtime resource immutably.
+    ///
+    /// Returns [`None`] if no resource of type `Resource` is stored.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     resource,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Resource(u32);
+    ///
+    /// let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+    /// world.insert_resource(Resource(42));
+    ///
+    /// assert_eq!(world.get_resource::<Resource>(), Some(&Resource(42)));
+    /// ```
+    ///
+    /// [`None`]: Option::None
+    #[must_use]
+    pub fn get_resource<Resource>(&self) -> Option<&Resource>
+    where
+        Resource: resource::Resource,
+    {
+        self.resources.get()
+    }
+
+    /// View a single runtime resource mutably.
+    ///
+    /// Returns [`None`] if no resource of type `Resource` is stored.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     resource,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Resource(u32);
+    ///
+    /// let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+    /// world.insert_resource(Resource(42));
+    ///
+    /// world.get_resource_mut::<Resource>().unwrap().0 = 100;
+    /// assert_eq!(world.get_resource::<Resource>(), Some(&Resource(100)));
+    /// ```
+    ///
+    /// [`None`]: Option::None
+    #[must_use]
+    pub fn get_resource_mut<Resource>(&mut self) -> Option<&mut Resource>
+    where
+        Resource: resource::Resource,
+    {
+        self.resources.get_mut()
+    }
+
+    /// View multiple resources at once, returning [`None`] if the views cannot be borrowed.
+    ///
+    /// This is the runtime-checked counterpart of [`view_resources()`]. The `Views` are only
+    /// borrowed if every viewed resource is stored and the views are disjoint: no resource may be
+    /// viewed mutably more than once, or both mutably and immutably. A resource may be viewed
+    /// immutably any number of times.
+    ///
+    /// This is only available on `World`s using [`resource::Dynamic`] resources.
+    ///
+    /// # Example
+    /// ```
+    /// use brood::{
+    ///     query::{
+    ///         result,
+    ///         Views,
+    ///     },
+    ///     resource,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct ResourceA(u32);
+    /// #[derive(Debug, PartialEq)]
+    /// struct ResourceB(char);
+    ///
+    /// let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+    /// world.insert_resource(ResourceA(0));
+    /// world.insert_resource(ResourceB('a'));
+    ///
+    /// let result!(a, b) = world
+    ///     .try_view_resources::<Views!(&mut ResourceA, &mut ResourceB)>()
+    ///     .unwrap();
+    /// a.0 = 1;
+    /// b.0 = 'b';
+    ///
+    /// assert_eq!(world.get_resource::<ResourceA>(), Some(&ResourceA(1)));
+    /// assert_eq!(world.get_resource::<ResourceB>(), Some(&ResourceB('b')));
+    /// ```
+    ///
+    /// [`None`]: Option::None
+    /// [`view_resources()`]: World::view_resources()
+    #[must_use]
+    pub fn try_view_resources<'a, Views>(&'a mut self) -> Option<Views>
+    where
+        Views: resource::DynamicViews<'a>,
+    {
+        self.resources.try_view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ComponentChange,
+        SpawnError,
+        World,
+    };
+    #[cfg(feature = "rayon")]
+    use crate::system::{
+        ParReduceSystem,
+        ParSystem,
+    };
+    use crate::{
+        entities,
+        entity,
+        packed,
+        query::{
+            filter,
+            result,
+            result::{
+                ArchetypeColumns,
+                SingleError,
+            },
+            view,
+            Result,
+            Views,
+        },
+        registry,
+        resource,
+        resources,
+        system::{
+            schedule,
+            schedule::task,
+            System,
+        },
+        Entity,
+        Query,
+        Registry,
+        Resources,
+    };
+    use alloc::{
+        sync::Arc,
+        vec,
+        vec::Vec,
+    };
+    use claims::{
+        assert_err_eq,
+        assert_none,
+        assert_ok,
+        assert_some,
+        assert_some_eq,
+    };
+    use core::{
+        any::{
+            type_name,
+            TypeId,
+        },
+        hash::BuildHasher,
+        mem::size_of,
+        ops::ControlFlow,
+        sync::atomic::{
+            AtomicU32,
+            AtomicUsize,
+            Ordering,
+        },
+        time::Duration,
+    };
+    use fnv::FnvBuildHasher;
+    use hashbrown::HashSet;
+    #[cfg(feature = "rayon")]
+    use rayon::iter::ParallelIterator;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    struct A(u32);
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    struct B(char);
+
+    type Registry = Registry!(A, B);
+
+    #[test]
+    fn insert() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(42), B('f')));
+    }
+
+    #[test]
+    fn insert_different_entity_types() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+    }
+
+    #[test]
+    fn insert_into() {
+        let mut world = World::<Registry>::new();
+
+        let handle = world.archetype_handle::<Entity!(A, B), _>();
+        let entity_identifier = world.insert_into(&handle, entity!(A(1), B('a')));
+        world.insert_into(&handle, entity!(A(2), B('b')));
+
+        assert_eq!(world.len(), 2);
+        assert_eq!(world.len_of::<Entity!(A, B), _>(), 2);
+        let entity = assert_some!(world.entity_mut(entity_identifier));
+        assert_some_eq!(entity.get::<A, _>(), &A(1));
+        assert_some_eq!(entity.get::<B, _>(), &B('a'));
+    }
+
+    #[test]
+    fn insert_into_existing_archetype() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(B('a'), A(1)));
+        let handle = world.archetype_handle::<Entity!(A, B), _>();
+        world.insert_into(&handle, entity!(A(2), B('b')));
+
+        assert_eq!(world.archetypes.iter().count(), 1);
+        assert_eq!(world.len_of::<Entity!(A, B), _>(), 2);
+    }
+
+    #[test]
+    fn insert_into_after_archetype_removed() {
+        let mut world = World::<Registry>::new();
+
+        let handle = world.archetype_handle::<Entity!(A), _>();
+        let entity_identifier = world.insert_into(&handle, entity!(A(1)));
+        world.remove(entity_identifier);
+        world.shrink_to_fit();
+        assert_eq!(world.archetypes.iter().count(), 0);
+
+        world.insert_into(&handle, entity!(A(2)));
+        world.insert_into(&handle, entity!(A(3)));
+
+        assert_eq!(world.archetypes.iter().count(), 1);
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![2, 3]);
+    }
+
+    #[test]
+    fn insert_into_other_world() {
+        let world = World::<Registry>::new();
+        let handle = world.archetype_handle::<Entity!(A, B), _>();
+
+        let mut other_world = World::<Registry>::new();
+        other_world.insert(entity!(A(1), B('a')));
+        other_world.insert_into(&handle, entity!(A(2), B('b')));
+
+        assert_eq!(other_world.archetypes.iter().count(), 1);
+        assert_eq!(other_world.len_of::<Entity!(A, B), _>(), 2);
+    }
+
+    #[test]
+    fn insert_into_different_hasher() {
+        use core::hash::Hasher;
+
+        #[derive(Clone)]
+        struct SeededBuildHasher;
+
+        impl BuildHasher for SeededBuildHasher {
+            type Hasher = <FnvBuildHasher as BuildHasher>::Hasher;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                let mut hasher = FnvBuildHasher::default().build_hasher();
+                hasher.write_u64(42);
+                hasher
+            }
+        }
+
+        let world = World::<Registry, resource::Null, _>::with_hasher(SeededBuildHasher);
+        let handle = world.archetype_handle::<Entity!(A, B), _>();
+
+        let mut other_world = World::<Registry>::new();
+        other_world.insert(entity!(A(1), B('a')));
+        other_world.insert_into(&handle, entity!(A(2), B('b')));
+
+        assert_eq!(other_world.archetypes.iter().count(), 1);
+        assert_eq!(other_world.len_of::<Entity!(A, B), _>(), 2);
+    }
+
+    #[test]
+    fn insert_at() {
+        let mut world = World::<Registry>::new();
+        let entity_identifier = entity::Identifier::new(2, 3);
+
+        assert_ok!(world.insert_at(entity_identifier, entity!(A(42), B('f'))));
+
+        assert_eq!(world.len(), 1);
+        let entity = assert_some!(world.entity_mut(entity_identifier));
+        assert_some_eq!(entity.get::<A, _>(), &A(42));
+        assert_some_eq!(entity.get::<B, _>(), &B('f'));
+    }
+
+    #[test]
+    fn insert_at_fills_gap() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(0)));
+
+        assert_ok!(world.insert_at(entity::Identifier::new(3, 0), entity!(A(3))));
+
+        // The skipped indices are given out by later insertions, starting at generation 0.
+        assert_eq!(world.insert(entity!(A(1))), entity::Identifier::new(1, 0));
+        assert_eq!(world.insert(entity!(A(2))), entity::Identifier::new(2, 0));
+        assert_eq!(world.insert(entity!(A(4))), entity::Identifier::new(4, 0));
+        assert_eq!(world.len(), 5);
+    }
+
+    #[test]
+    fn insert_at_into_gap() {
+        let mut world = World::<Registry>::new();
+
+        assert_ok!(world.insert_at(entity::Identifier::new(3, 0), entity!(A(3))));
+        assert_ok!(world.insert_at(entity::Identifier::new(1, 0), entity!(A(1))));
+
+        assert_eq!(world.insert(entity!(A(0))), entity::Identifier::new(0, 0));
+        assert_eq!(world.insert(entity!(A(2))), entity::Identifier::new(2, 0));
+        assert_eq!(world.insert(entity!(A(4))), entity::Identifier::new(4, 0));
+    }
+
+    #[test]
+    fn insert_at_occupied() {
+        let mut world = World::<Registry>::new();
+        let entity_identifier = world.insert(entity!(A(1)));
+
+        assert_err_eq!(
+            world.insert_at(entity::Identifier::new(0, 5), entity!(A(2))),
+            SpawnError::Occupied
+        );
+
+        assert_eq!(world.len(), 1);
+        let entity = assert_some!(world.entity_mut(entity_identifier));
+        assert_some_eq!(entity.get::<A, _>(), &A(1));
+    }
+
+    #[test]
+    fn insert_at_generation_regression() {
+        let mut world = World::<Registry>::new();
+        let entity_identifier = world.insert(entity!(A(1)));
+        world.remove(entity_identifier);
+
+        assert_err_eq!(
+            world.insert_at(entity_identifier, entity!(A(2))),
+            SpawnError::GenerationRegression
+        );
+
+        assert_eq!(world.len(), 0);
+        assert!(!world.contains(entity_identifier));
+    }
+
+    #[test]
+    fn insert_at_newer_generation() {
+        let mut world = World::<Registry>::new();
+        let old_entity_identifier = world.insert(entity!(A(1)));
+        world.remove(old_entity_identifier);
+        let entity_identifier = entity::Identifier::new(0, 5);
+
+        assert_ok!(world.insert_at(entity_identifier, entity!(A(2))));
+
+        assert!(world.contains(entity_identifier));
+        assert!(!world.contains(old_entity_identifier));
+        assert!(!world.contains(entity::Identifier::new(0, 4)));
+
+        // Reusing the index continues from the placed generation.
+        world.remove(entity_identifier);
+        assert_eq!(world.insert(entity!(A(3))), entity::Identifier::new(0, 6));
+    }
+
+    #[test]
+    fn extend() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(42), B('f')); 100));
+    }
+
+    #[test]
+    fn extend_multiple_times() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(42), B('f')); 100));
+        world.extend(entities!((A(1), B('c')); 50));
+    }
+
+    #[test]
+    fn extend_different_entity_types() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')); 100));
+        world.extend(entities!((A(2)); 200));
+        world.extend(entities!((B('b')); 300));
+        world.extend(entities!((); 400));
+    }
+
+    #[test]
+    fn extend_from_empty_twice() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(42), B('f')); 100));
+        world.clear();
+        world.extend(entities!((A(1), B('c')); 50));
+    }
+
+    #[test]
+    fn extend_from_iter() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifiers = world.extend(
+            (0..3)
+                .map(|i| entity!(A(i), B('a')))
+                .collect::<entities::Batch<_>>(),
+        );
+
+        assert_eq!(world.len(), 3);
+        let mut result = world
+            .query(Query::<Views!(&A, &B)>::new())
+            .iter
+            .map(|result!(a, b)| (a.0, b.0))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![(0, 'a'), (1, 'a'), (2, 'a')]);
+        assert_eq!(entity_identifiers.len(), 3);
+    }
+
+    #[test]
+    fn extend_reuses_freed_identifiers() {
+        let mut world = World::<Registry>::new();
+
+        let removed = world.extend(entities!((A(1)); 3));
+        for entity_identifier in removed {
+            world.remove(entity_identifier);
+        }
+        let entity_identifiers = world.extend(entities!((A(2), B('a')); 5));
+
+        assert_eq!(world.len(), 5);
+        assert_eq!(entity_identifiers.len(), 5);
+        for entity_identifier in entity_identifiers {
+            let mut entry = assert_some!(world.entry(entity_identifier));
+            assert_eq!(
+                entry
+                    .query(Query::<Views!(&A, &B)>::new())
+                    .map(|result!(a, b)| (*a, b.clone())),
+                Some((A(2), B('a')))
+            );
+        }
+    }
+
+    #[test]
+    fn extend_iter() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifiers = world
+            .extend_iter(entities!((A(42), B('f')); 100))
+            .collect::<Vec<_>>();
+
+        assert_eq!(world.len(), 100);
+        assert_eq!(entity_identifiers.len(), 100);
+        for entity_identifier in entity_identifiers {
+            let mut entry = assert_some!(world.entry(entity_identifier));
+            assert_eq!(
+                entry
+                    .query(Query::<Views!(&A, &B)>::new())
+                    .map(|result!(a, b)| (*a, b.clone())),
+                Some((A(42), B('f')))
+            );
+        }
+    }
+
+    #[test]
+    fn extend_iter_len() {
+        let mut world = World::<Registry>::new();
+        world.extend(entities!((A(1), B('a')); 10));
+
+        let mut entity_identifiers = world.extend_iter(entities!((A(2), B('b')); 5));
+
+        assert_eq!(entity_identifiers.len(), 5);
+        entity_identifiers.next();
+        assert_eq!(entity_identifiers.len(), 4);
+    }
+
+    #[test]
+    fn extend_iter_not_consumed() {
+        let mut world = World::<Registry>::new();
+
+        drop(world.extend_iter(entities!((A(42), B('f')); 100)));
+
+        assert_eq!(world.len(), 100);
+        assert_eq!(
+            world.query(Query::<Views!(&A, &B)>::new()).iter.count(),
+            100
+        );
+    }
+
+    #[test]
+    fn merge() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+
+        let mut other = World::<Registry>::new();
+        let other_entity_identifiers = [
+            other.insert(entity!(A(2), B('b'))),
+            other.insert(entity!(A(3))),
+            other.insert(entity!()),
+        ];
+
+        let mapping = world.merge(other);
+
+        assert_eq!(world.len(), 4);
+        assert_eq!(mapping.len(), 3);
+        for (old_entity_identifier, new_entity_identifier) in mapping {
+            assert!(other_entity_identifiers.contains(&old_entity_identifier));
+            let mut entry = assert_some!(world.entry(new_entity_identifier));
+            let result = entry
+                .query(Query::<Views!(Option<&A>, Option<&B>)>::new())
+                .map(|result!(a, b)| (a.copied(), b.cloned()));
+            if old_entity_identifier == other_entity_identifiers[0] {
+                assert_eq!(result, Some((Some(A(2)), Some(B('b')))));
+            } else if old_entity_identifier == other_entity_identifiers[1] {
+                assert_eq!(result, Some((Some(A(3)), None)));
+            } else {
+                assert_eq!(result, Some((None, None)));
+            }
+        }
+
+        let mut result = world
+            .query(Query::<Views!(&A, &B)>::new())
+            .iter
+            .map(|result!(a, b)| (a.0, b.0))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![(1, 'a'), (2, 'b')]);
+    }
+
+    #[test]
+    fn merge_empty() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+
+        assert!(world.merge(World::<Registry>::new()).is_empty());
+
+        assert_eq!(world.len(), 1);
+    }
+
+    #[test]
+    fn merge_keeps_resources() {
+        let mut world = World::<Registry, _>::with_resources(resources!(A(1)));
+
+        let mut other = World::<Registry, _>::with_resources(resources!(B('a')));
+        other.insert(entity!(A(2)));
+
+        world.merge(other);
+
+        assert_eq!(world.len(), 1);
+        assert_eq!(world.get::<A, _>(), &A(1));
+    }
+
+    #[test]
+    fn migrate() {
+        #[derive(Debug, Eq, PartialEq)]
+        struct C(Vec<u32>);
+
+        let mut world = World::<Registry>::new();
+        let entity_identifier_a = world.insert(entity!(A(1), B('a')));
+        let entity_identifier_b = world.insert(entity!(A(2)));
+        let entity_identifier_c = world.insert(entity!(B('c')));
+        let entity_identifier_d = world.insert(entity!());
+
+        let mut world = world.migrate::<Registry!(B, C, A), _>();
+
+        assert_eq!(world.len(), 4);
+        assert_eq!(
+            assert_some!(world.entry(entity_identifier_a))
+                .query(Query::<Views!(Option<&A>, Option<&B>, Option<&C>)>::new())
+                .map(|result!(a, b, c)| (a.copied(), b.cloned(), c.is_some())),
+            Some((Some(A(1)), Some(B('a')), false))
+        );
+        assert_eq!(
+            assert_some!(world.entry(entity_identifier_b))
+                .query(Query::<Views!(Option<&A>, Option<&B>, Option<&C>)>::new())
+                .map(|result!(a, b, c)| (a.copied(), b.cloned(), c.is_some())),
+            Some((Some(A(2)), None, false))
+        );
+        assert_eq!(
+            assert_some!(world.entry(entity_identifier_c))
+                .query(Query::<Views!(Option<&A>, Option<&B>, Option<&C>)>::new())
+                .map(|result!(a, b, c)| (a.copied(), b.cloned(), c.is_some())),
+            Some((None, Some(B('c')), false))
+        );
+        assert!(world.contains(entity_identifier_d));
+
+        assert_some!(world.entry(entity_identifier_a)).add(C(vec![1, 2, 3]));
+        world.insert(entity!(C(vec![4]), A(3)));
+        world.remove(entity_identifier_b);
+
+        let mut result = world
+            .query(Query::<Views!(&A, Option<&B>, Option<&C>)>::new())
+            .iter
+            .map(|result!(a, b, c)| (a.0, b.map(|b| b.0), c.map(|c| c.0.len())))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![(1, Some('a'), Some(3)), (3, None, Some(1))]);
+    }
+
+    #[test]
+    fn migrate_same_registry() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut world = world.migrate::<Registry, _>();
+
+        let mut result = world
+            .query(Query::<Views!(&A, Option<&B>)>::new())
+            .iter
+            .map(|result!(a, b)| (a.0, b.map(|b| b.0)))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![(1, Some('a')), (2, None)]);
+    }
+
+    #[test]
+    fn migrate_empty() {
+        let world = World::<Registry>::new();
+
+        let world = world.migrate::<Registry!(B, A), _>();
+
+        assert!(world.is_empty());
+    }
+
+    #[test]
+    fn migrate_keeps_allocations() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1)));
+        let removed_entity_identifier = world.insert(entity!(A(2)));
+        world.insert(entity!(B('a')));
+        world.remove(removed_entity_identifier);
+
+        let mut migrated_world = World::<Registry>::new();
+        migrated_world.insert(entity!(A(1)));
+        let removed_entity_identifier = migrated_world.insert(entity!(A(2)));
+        migrated_world.insert(entity!(B('a')));
+        migrated_world.remove(removed_entity_identifier);
+        let mut migrated_world = migrated_world.migrate::<Registry!(B, A), _>();
+
+        assert!(!migrated_world.contains(removed_entity_identifier));
+        for _ in 0..2 {
+            assert_eq!(
+                migrated_world.insert(entity!(A(3))),
+                world.insert(entity!(A(3)))
+            );
+        }
+    }
+
+    #[test]
+    fn migrate_keeps_resources() {
+        let world = World::<Registry, _>::with_resources(resources!(A(1)));
+
+        let world = world.migrate::<Registry!(B, A), _>();
+
+        assert_eq!(world.get::<A, _>(), &A(1));
+    }
+
+    #[test]
+    fn migrate_keeps_observers() {
+        let mut world = World::<Registry>::new();
+        let (added, _removed) = observe_a(&mut world);
+
+        let mut world = world.migrate::<Registry!(B, A), _>();
+        world.insert(entity!(A(1)));
+
+        assert_eq!(added.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn insert_batch_mixed() {
+        let mut world = World::<Registry>::new();
+
+        let mut entity_identifiers = world
+            .insert_batch_mixed(vec![
+                entity!(Some(A(1)), Some(B('a'))),
+                entity!(Some(A(2)), None),
+                entity!(None, Some(B('b'))),
+                entity!(Some(A(3)), Some(B('c'))),
+            ])
+            .into_iter();
+
+        assert_eq!(world.len(), 4);
+        assert_eq!(world.len_of::<Entity!(A, B), _>(), 2);
+        assert_eq!(world.len_of::<Entity!(A), _>(), 1);
+        assert_eq!(world.len_of::<Entity!(B), _>(), 1);
+        let entity = assert_some!(world.entity_mut(assert_some!(entity_identifiers.next())));
+        assert_some_eq!(entity.get::<A, _>(), &A(1));
+        assert_some_eq!(entity.get::<B, _>(), &B('a'));
+        let entity = assert_some!(world.entity_mut(assert_some!(entity_identifiers.next())));
+        assert_some_eq!(entity.get::<A, _>(), &A(2));
+        assert_none!(entity.get::<B, _>());
+        let entity = assert_some!(world.entity_mut(assert_some!(entity_identifiers.next())));
+        assert_none!(entity.get::<A, _>());
+        assert_some_eq!(entity.get::<B, _>(), &B('b'));
+        let entity = assert_some!(world.entity_mut(assert_some!(entity_identifiers.next())));
+        assert_some_eq!(entity.get::<A, _>(), &A(3));
+        assert_some_eq!(entity.get::<B, _>(), &B('c'));
+    }
+
+    #[test]
+    fn insert_batch_mixed_reordered_components() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifiers =
+            world.insert_batch_mixed(vec![entity!(Some(B('a')), Some(A(1))); 3]);
+
+        assert_eq!(entity_identifiers.len(), 3);
+        assert_eq!(world.len_of::<Entity!(A, B), _>(), 3);
+    }
+
+    #[test]
+    fn insert_batch_mixed_no_components() {
+        let mut world = World::<Registry>::new();
+
+        world.insert_batch_mixed(vec![entity!(None::<A>, None::<B>); 2]);
+
+        assert_eq!(world.len(), 2);
+        assert_eq!(world.len_of::<Entity!(), _>(), 2);
+    }
+
+    #[test]
+    fn insert_batch_mixed_empty() {
+        let mut world = World::<Registry>::new();
+
+        assert!(world
+            .insert_batch_mixed(Vec::<Entity!(Option<A>, Option<B>)>::new())
+            .is_empty());
+        assert!(world.is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_extend() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifiers = world.par_extend(entities!((A(42), B('f')); 100));
+
+        assert_eq!(world.len(), 100);
+        assert_eq!(entity_identifiers.len(), 100);
+        for entity_identifier in entity_identifiers {
+            let mut entry = world.entry(entity_identifier).unwrap();
+            assert_eq!(
+                entry
+                    .query(Query::<Views!(&A, &B)>::new())
+                    .map(|result!(a, b)| (a.0, b.0)),
+                Some((42, 'f'))
+            );
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_extend_multiple_times() {
+        let mut world = World::<Registry>::new();
+
+        world.par_extend(entities!((A(42), B('f')); 100));
+        world.par_extend(entities!((B('c'), A(1)); 50));
+
+        assert_eq!(world.len(), 150);
+        let mut result = world
+            .query(Query::<Views!(&A, &B)>::new())
+            .iter
+            .map(|result!(a, b)| (a.0, b.0))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, [vec![(1, 'c'); 50], vec![(42, 'f'); 100]].concat());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_extend_different_entity_types() {
+        let mut world = World::<Registry>::new();
+
+        world.par_extend(entities!((A(1), B('a')); 100));
+        world.par_extend(entities!((A(2)); 200));
+        world.par_extend(entities!((B('b')); 300));
+
+        assert_eq!(world.len(), 600);
+    }
+
+    #[test]
+    fn query() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(B('a'), A(1)));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(&B, &A)>::new())
+            .iter
+            .map(|result!(b, a)| (b.0, a.0))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![('a', 1)]);
+    }
+
+    #[test]
+    fn query_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[test]
+    fn query_mut_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(&mut B)>::new())
+            .iter
+            .map(|result!(b)| b.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec!['a', 'b']);
+    }
+
+    #[test]
+    fn query_option_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(Option<&A>)>::new())
+            .iter
+            .map(|result!(a)| a.map(|a| a.0))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![None, None, Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn query_option_mut_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(Option<&mut B>)>::new())
+            .iter
+            .map(|result!(b)| b.map(|b| b.0))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![None, None, Some('a'), Some('b')]);
+    }
+
+    #[test]
+    fn query_copied() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(view::Copied<A>)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_by_key(|a| a.0);
+        assert_eq!(result, vec![A(1), A(2)]);
+    }
+
+    #[test]
+    fn query_copied_with_other_views() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .query(Query::<Views!(&mut B, view::Copied<A>)>::new())
+            .iter
+            .map(|result!(b, a)| (b.0, a.0))
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![('a', A(1))]);
+    }
+
+    #[test]
+    fn query_index() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+        world.insert(entity!(B('c')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(&A, view::Index)>::new())
+            .iter
+            .map(|result!(a, index)| (a.0, index.0))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![(1, 0), (2, 1), (3, 0)]);
+    }
+
+    #[test]
+    fn query_index_only() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+        world.insert(entity!(B('c')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(view::Index)>::new())
+            .iter
+            .map(|result!(index)| index.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn query_archetype_id() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+
+        let result = world
+            .query(Query::<Views!(&A, view::ArchetypeId)>::new())
+            .iter
+            .map(|result!(a, archetype_id)| (a.0, archetype_id))
+            .collect::<Vec<_>>();
+        assert_eq!(result.len(), 3);
+        let archetype_id_1 = assert_some!(result.iter().find(|(a, _)| *a == 1)).1;
+        let archetype_id_2 = assert_some!(result.iter().find(|(a, _)| *a == 2)).1;
+        let archetype_id_3 = assert_some!(result.iter().find(|(a, _)| *a == 3)).1;
+        assert_eq!(archetype_id_1, archetype_id_2);
+        assert_ne!(archetype_id_1, archetype_id_3);
+    }
+
+    #[test]
+    fn query_archetype_id_and_index_unique() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+        world.insert(entity!(B('c')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(view::ArchetypeId, view::Index)>::new())
+            .iter
+            .map(|result!(archetype_id, index)| (archetype_id, index))
+            .collect::<Vec<_>>();
+        result.sort();
+        result.dedup();
+        assert_eq!(result.len(), 5);
+    }
+
+    #[test]
+    fn query_archetype_id_stable_across_queries() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut first = world
+            .query(Query::<Views!(&A, view::ArchetypeId)>::new())
+            .iter
+            .map(|result!(a, archetype_id)| (a.0, archetype_id))
+            .collect::<Vec<_>>();
+        first.sort();
+        let mut second = world
+            .query(Query::<Views!(view::ArchetypeId, view::Index, &A)>::new())
+            .iter
+            .map(|result!(archetype_id, _index, a)| (a.0, archetype_id))
+            .collect::<Vec<_>>();
+        second.sort();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn for_each() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        world.for_each::<Views!(&mut A), filter::None, _, _>(|result!(a)| {
+            a.0 += 10;
+        });
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![11, 12]);
+    }
+
+    #[test]
+    fn for_each_filtered() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        let mut count = 0;
+        world.for_each::<Views!(&A), filter::Has<B>, _, _>(|result!(a)| {
+            assert_eq!(a.0, 1);
+            count += 1;
+        });
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn query_pairs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('a')));
+        world.insert(entity!(B('b')));
+        world.insert(entity!(B('c')));
+
+        let mut pairs = Vec::new();
+        world.query_pairs(
+            Query::<Views!(&A), filter::Not<filter::Has<B>>>::new(),
+            Query::<Views!(&B), filter::Not<filter::Has<A>>>::new(),
+            |result!(a), result!(b)| pairs.push((a.0, b.0)),
+        );
+        pairs.sort_unstable();
+
+        assert_eq!(
+            pairs,
+            vec![(1, 'a'), (1, 'b'), (1, 'c'), (2, 'a'), (2, 'b'), (2, 'c')]
+        );
+    }
+
+    #[test]
+    fn query_pairs_mutable() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('a')));
+        world.insert(entity!(B('b')));
+
+        world.query_pairs(
+            Query::<Views!(&mut A)>::new(),
+            Query::<Views!(&mut B)>::new(),
+            |result!(a), result!(b)| {
+                a.0 += 1;
+                b.0 = 'z';
+            },
+        );
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![3, 4]);
+        assert!(world
+            .query(Query::<Views!(&B)>::new())
+            .iter
+            .all(|result!(b)| b.0 == 'z'));
+    }
+
+    #[test]
+    fn query_pairs_same_entity() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+
+        let mut pairs = Vec::new();
+        world.query_pairs(
+            Query::<Views!(&mut A, entity::Identifier)>::new(),
+            Query::<Views!(&B, entity::Identifier)>::new(),
+            |result!(a, identifier), result!(b, other_identifier)| {
+                a.0 += 1;
+                pairs.push((*identifier, b.0, *other_identifier));
+            },
+        );
+
+        assert_eq!(pairs, vec![(entity_identifier, 'a', entity_identifier)]);
+        assert_eq!(
+            world
+                .query(Query::<Views!(&A)>::new())
+                .iter
+                .map(|result!(a)| a.0)
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn query_pairs_empty() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+
+        let mut count = 0;
+        world.query_pairs(
+            Query::<Views!(&A)>::new(),
+            Query::<Views!(&B)>::new(),
+            |_, _| count += 1,
+        );
+
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn matching_archetypes() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.extend(entities!((A(2)); 3));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .matching_archetypes::<filter::Has<A>, _>()
+            .map(|archetype| (archetype.len(), archetype.contains::<B, _>()))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+
+        assert_eq!(result, vec![(1, true), (3, false)]);
+    }
+
+    #[test]
+    fn matching_archetypes_none_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        assert_eq!(
+            world
+                .matching_archetypes::<filter::None, _>()
+                .map(|archetype| archetype.len())
+                .sum::<usize>(),
+            3
+        );
+    }
+
+    #[test]
+    fn matching_archetypes_skips_empty() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1)));
+        world.remove(entity_identifier);
+
+        assert_eq!(world.matching_archetypes::<filter::None, _>().count(), 0);
+    }
+
+    #[test]
+    fn matching_archetypes_column() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+
+        let archetype = assert_some!(world.matching_archetypes::<filter::Has<A>, _>().next());
+        let mut column = assert_some!(archetype.column::<A, _>()).to_vec();
+        column.sort_unstable_by_key(|a| a.0);
+        assert_eq!(column, vec![A(1), A(2)]);
+        assert_none!(archetype.column::<B, _>());
+    }
+
+    #[test]
+    fn matching_archetypes_column_mut() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        for mut archetype in world.matching_archetypes::<filter::None, _>() {
+            for a in assert_some!(archetype.column_mut::<A, _>()) {
+                a.0 += 10;
+            }
+        }
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![11, 12]);
+    }
+
+    #[test]
+    fn matching_archetypes_entity_identifiers() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1)));
+        let second = world.insert(entity!(A(2)));
+
+        let archetype = assert_some!(world.matching_archetypes::<filter::None, _>().next());
+        let column = assert_some!(archetype.column::<A, _>());
+        for (identifier, a) in archetype.entity_identifiers().iter().zip(column) {
+            if *identifier == first {
+                assert_eq!(a, &A(1));
+            } else {
+                assert_eq!(*identifier, second);
+                assert_eq!(a, &A(2));
+            }
+        }
+        assert_eq!(archetype.entity_identifiers().len(), 2);
+    }
+
+    #[test]
+    fn query_entity_identifiers() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .query(Query::<
+                Views!(entity::Identifier),
+                filter::And<filter::Has<A>, filter::Has<B>>,
+            >::new())
+            .iter
+            .map(|result!(identifier)| identifier)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![entity_identifier]);
+    }
+
+    #[test]
+    fn query_len() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.extend(entities!((A(2)); 3));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        assert_eq!(world.query(Query::<Views!(&A)>::new()).iter.len(), 4);
+        assert_eq!(world.query(Query::<Views!(&A, &B)>::new()).iter.len(), 1);
+        assert_eq!(
+            world.query(Query::<Views!(Option<&A>)>::new()).iter.len(),
+            6
+        );
+    }
+
+    #[test]
+    fn query_len_with_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.extend(entities!((A(2)); 3));
+
+        assert_eq!(
+            world
+                .query(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new())
+                .iter
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn query_size_hint_after_next() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.extend(entities!((A(2)); 3));
+
+        let mut iter = world.query(Query::<Views!(&A)>::new()).iter;
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+
+        for remaining in (0..4).rev() {
+            assert_some!(iter.next());
+            assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        }
+        assert_none!(iter.next());
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn query_excluding() {
+        let mut world = World::<Registry>::new();
+
+        let excluded_a = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        let excluded_b = world.insert(entity!(A(3)));
+        world.insert(entity!(A(4)));
+        world.insert(entity!(B('c')));
+
+        let mut excluded = HashSet::with_hasher(FnvBuildHasher::default());
+        excluded.insert(excluded_a);
+        excluded.insert(excluded_b);
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .excluding(&excluded)
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![2, 4]);
+    }
+
+    #[test]
+    fn query_excluding_empty_set() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .excluding(&HashSet::with_hasher(FnvBuildHasher::default()))
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[test]
+    fn query_excluding_mut() {
+        let mut world = World::<Registry>::new();
+
+        let excluded_identifier = world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+
+        let mut excluded = HashSet::with_hasher(FnvBuildHasher::default());
+        excluded.insert(excluded_identifier);
+
+        for result!(a) in world
+            .query(Query::<Views!(&mut A)>::new())
+            .iter
+            .excluding(&excluded)
+        {
+            a.0 += 10;
+        }
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 12]);
+    }
+
+    #[test]
+    fn query_excluding_with_filter() {
+        let mut world = World::<Registry>::new();
+
+        let excluded_identifier = world.insert(entity!(A(1), B('a')));
+        let entity_identifier = world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+
+        let mut excluded = HashSet::with_hasher(FnvBuildHasher::default());
+        excluded.insert(excluded_identifier);
+
+        let result = world
+            .query(Query::<Views!(entity::Identifier), filter::Has<B>>::new())
+            .iter
+            .excluding(&excluded)
+            .map(|result!(identifier)| identifier)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![entity_identifier]);
+    }
+
+    #[test]
+    fn query_has_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .query(Query::<Views!(&A), filter::Has<B>>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![1]);
+    }
+
+    #[test]
+    fn query_not_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .query(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![2]);
+    }
+
+    #[test]
+    fn query_and_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .query(Query::<
+                Views!(&A),
+                filter::And<filter::Has<A>, filter::Has<B>>,
+            >::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![1]);
+    }
+
+    #[test]
+    fn query_is_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .query(Query::<Views!(&A), filter::Is<Entity!(A)>>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![2]);
+    }
+
+    #[test]
+    fn query_is_filter_empty() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        let entity_identifier = world.insert(entity!());
+
+        let result = world
+            .query(Query::<Views!(entity::Identifier), filter::Is<Entity!()>>::new())
+            .iter
+            .map(|result!(identifier)| identifier)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![entity_identifier]);
+    }
+
+    #[test]
+    fn query_or_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<
+                Views!(&A),
+                filter::Or<filter::Has<A>, filter::Has<B>>,
+            >::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[test]
+    fn query_all_of_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .query(Query::<Views!(&A), filter::AllOf<Entity!(A, B)>>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![1]);
+    }
+
+    #[test]
+    fn query_all_of_filter_empty() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut result = world
+            .query(Query::<Views!(&A), filter::AllOf<Entity!()>>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[test]
+    fn query_none_of_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        let entity_identifier = world.insert(entity!());
+
+        let result = world
+            .query(Query::<
+                Views!(entity::Identifier),
+                filter::NoneOf<Entity!(A, B)>,
+            >::new())
+            .iter
+            .map(|result!(identifier)| identifier)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![entity_identifier]);
+    }
+
+    #[test]
+    fn query_all_of_and_none_of_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .query(Query::<
+                Views!(&A),
+                filter::And<filter::AllOf<Entity!(A)>, filter::NoneOf<Entity!(B)>>,
+            >::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![2]);
+    }
+
+    #[test]
+    fn query_all_tags_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .query(Query::<Views!(&A), filter::AllTags!(A, B)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![1]);
+    }
+
+    #[test]
+    fn query_all_tags_filter_empty() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut result = world
+            .query(Query::<Views!(&A), filter::AllTags!()>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[test]
+    fn query_ordered() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!(A(3)));
+
+        let result = world
+            .query_ordered(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        // The archetype identified by only `A` sorts before the archetype identified by `A` and
+        // `B`.
+        assert_eq!(result, vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn query_ordered_independent_of_archetype_creation_order() {
+        let mut world_a = World::<Registry>::new();
+        world_a.insert(entity!(A(1), B('a')));
+        world_a.insert(entity!(A(2)));
+
+        let mut world_b = World::<Registry>::new();
+        world_b.insert(entity!(A(2)));
+        world_b.insert(entity!(A(1), B('a')));
+
+        let result_a = world_a
+            .query_ordered(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        let result_b = world_b
+            .query_ordered(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result_a, result_b);
+    }
+
+    #[test]
+    fn query_ordered_with_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        let result = world
+            .query_ordered(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![2]);
+    }
+
+    #[test]
+    fn query_ordered_len() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        let mut iter = world.query_ordered(Query::<Views!(&A)>::new()).iter;
+        assert_eq!(iter.len(), 2);
+        iter.next();
+        assert_eq!(iter.len(), 1);
+        iter.next();
+        assert_eq!(iter.len(), 0);
+        assert_none!(iter.next());
+    }
+
+    #[test]
+    fn query_cached() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        let mut result = world
+            .query_cached(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[test]
+    fn query_cached_repeated() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        for _ in 0..3 {
+            for result!(a) in world.query_cached(Query::<Views!(&mut A)>::new()).iter {
+                a.0 += 1;
+            }
+        }
+
+        let mut result = world
+            .query_cached(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![4, 5]);
+    }
+
+    #[test]
+    fn query_cached_includes_entities_inserted_into_cached_archetypes() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+        assert_eq!(
+            world.query_cached(Query::<Views!(&A)>::new()).iter.count(),
+            1
+        );
+
+        world.insert(entity!(A(2)));
+        assert_eq!(
+            world.query_cached(Query::<Views!(&A)>::new()).iter.count(),
+            2
+        );
+    }
+
+    #[test]
+    fn query_cached_invalidated_by_new_archetype() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+        assert_eq!(
+            world.query_cached(Query::<Views!(&A)>::new()).iter.count(),
+            1
+        );
+
+        world.insert(entity!(A(2), B('a')));
+        let mut result = world
+            .query_cached(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[test]
+    fn query_cached_invalidated_by_removed_archetype() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        assert_eq!(
+            world.query_cached(Query::<Views!(&A)>::new()).iter.count(),
+            2
+        );
+
+        world.remove(entity_identifier);
+        world.shrink_to_fit();
+        assert_eq!(
+            world
+                .query_cached(Query::<Views!(&A)>::new())
+                .iter
+                .map(|result!(a)| a.0)
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn query_cached_different_queries() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        assert_eq!(
+            world.query_cached(Query::<Views!(&A)>::new()).iter.count(),
+            2
+        );
+        let mut result = world
+            .query_cached(Query::<Views!(&B)>::new())
+            .iter
+            .map(|result!(b)| b.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec!['a', 'b']);
+        assert_eq!(
+            world
+                .query_cached(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new())
+                .iter
+                .map(|result!(a)| a.0)
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+        assert_eq!(
+            world
+                .query_cached(Query::<Views!(Option<&A>)>::new())
+                .iter
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn query_cached_mutable_and_immutable_views_share_cache() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+        assert_eq!(
+            world
+                .query_cached(Query::<Views!(&mut A)>::new())
+                .iter
+                .count(),
+            1
+        );
+
+        world.insert(entity!(A(2), B('a')));
+        assert_eq!(
+            world.query_cached(Query::<Views!(&A)>::new()).iter.count(),
+            2
+        );
+        assert_eq!(
+            world
+                .query_cached(Query::<Views!(&mut A)>::new())
+                .iter
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn query_cached_separate_worlds() {
+        let mut world_a = World::<Registry>::new();
+        let mut world_b = World::<Registry>::new();
+
+        world_a.insert(entity!(A(1), B('a')));
+        world_b.insert(entity!(A(2)));
+
+        assert_eq!(
+            world_a
+                .query_cached(Query::<Views!(&A)>::new())
+                .iter
+                .count(),
+            1
+        );
+        assert_eq!(
+            world_b
+                .query_cached(Query::<Views!(&A)>::new())
+                .iter
+                .map(|result!(a)| a.0)
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn query_cached_clone() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+        assert_eq!(
+            world.query_cached(Query::<Views!(&A)>::new()).iter.count(),
+            1
+        );
+
+        let mut cloned_world = world.clone();
+        drop(world);
+        cloned_world.insert(entity!(A(2)));
+        assert_eq!(
+            cloned_world
+                .query_cached(Query::<Views!(&A)>::new())
+                .iter
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn query_cached_len() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        let mut iter = world.query_cached(Query::<Views!(&A)>::new()).iter;
+        assert_eq!(iter.len(), 2);
+        iter.next();
+        assert_eq!(iter.len(), 1);
+        iter.next();
+        assert_eq!(iter.len(), 0);
+        assert_none!(iter.next());
+    }
+
+    #[test]
+    fn query_views_different_order() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(&B, &A)>::new())
+            .iter
+            .map(|result!(b, a)| (a.0, b.0))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![(1, 'a')]);
+    }
+
+    #[test]
+    fn query_resources() {
+        let mut world = World::<Registry!(), _>::with_resources(resources!(A(42), B('a')));
+
+        let result!(a, b) = world
+            .query(Query::<Views!(), filter::None, Views!(&A, &mut B)>::new())
+            .resources;
+        b.0 = 'b';
+
+        assert_eq!(a, &A(42));
+        assert_eq!(b, &mut B('b'));
+    }
+
+    #[test]
+    fn query_resources_reshaped() {
+        let mut world = World::<Registry!(), _>::with_resources(resources!(A(42), B('a')));
+
+        let result!(b, a) = world
+            .query(Query::<Views!(), filter::None, Views!(&B, &mut A)>::new())
+            .resources;
+        a.0 = 100;
+
+        assert_eq!(a, &A(100));
+        assert_eq!(b, &mut B('a'));
+    }
+
+    #[test]
+    fn query_split() {
+        let mut world = World::<Registry, _>::with_resources(resources!(A(0), B('a')));
+        world.insert(entity!(A(1), B('b')));
+        world.insert(entity!(A(2)));
+
+        let (result!(total, b), iter) = world
+            .query(Query::<Views!(&A), filter::None, Views!(&mut A, &B)>::new())
+            .split();
+        for result!(a) in iter {
+            total.0 += a.0;
+        }
+
+        assert_eq!(b, &B('a'));
+        assert_eq!(world.get::<A, _>(), &A(3));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_split() {
+        let mut world = World::<Registry, _>::with_resources(resources!(A(0)));
+        world.extend(entities!((A(1), B('a')); 100));
+
+        let (result!(total), iter) = world
+            .par_query(Query::<Views!(&A), filter::None, Views!(&mut A)>::new())
+            .split();
+        total.0 = iter.map(|result!(a)| a.0).sum();
+
+        assert_eq!(world.get::<A, _>(), &A(100));
+    }
+
+    #[test]
+    fn query_empty() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(B('a'), A(1)));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let count = world.query(Query::<Views!()>::new()).iter.count();
+
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn query_first() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(B('b')));
+
+        let result!(a) = assert_some!(world.query(Query::<Views!(&A)>::new()).first());
+        assert_eq!(a, &A(1));
+    }
+
+    #[test]
+    fn query_first_no_matches() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(B('a')));
+
+        assert_none!(world.query(Query::<Views!(&A)>::new()).first());
+    }
+
+    #[test]
+    fn query_single() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(B('b')));
+
+        let result!(a) = assert_ok!(world.query(Query::<Views!(&A)>::new()).single());
+        assert_eq!(a, &A(1));
+    }
+
+    #[test]
+    fn query_single_no_matches() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(B('a')));
+
+        assert_err_eq!(
+            world.query(Query::<Views!(&A)>::new()).single(),
+            SingleError::NoMatches
+        );
+    }
+
+    #[test]
+    fn query_single_multiple_matches() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        assert_err_eq!(
+            world.query(Query::<Views!(&A)>::new()).single(),
+            SingleError::MultipleMatches
+        );
+    }
+
+    #[test]
+    fn query_try_for_each_break() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')); 10));
+        world.extend(entities!((A(2)); 10));
+        world.insert(entity!(B('b')));
+
+        let mut visited = 0;
+        assert_eq!(
+            world
+                .query(Query::<Views!(&A)>::new())
+                .try_for_each(|result!(_a)| {
+                    visited += 1;
+                    if visited == 5 {
+                        ControlFlow::Break(visited)
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                }),
+            ControlFlow::Break(5)
+        );
+        assert_eq!(visited, 5);
+    }
+
+    #[test]
+    fn query_try_for_each_continue() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')); 10));
+        world.extend(entities!((A(2)); 10));
+        world.insert(entity!(B('b')));
+
+        let mut sum = 0;
+        assert_eq!(
+            world
+                .query(Query::<Views!(&A)>::new())
+                .try_for_each(|result!(a)| {
+                    sum += a.0;
+                    ControlFlow::<()>::Continue(())
+                }),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(sum, 30);
+    }
+
+    #[test]
+    fn query_limited() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')); 3));
+        world.extend(entities!((A(1)); 3));
+        world.insert(entity!(B('b')));
+
+        let mut count = 0;
+        for result!(a) in world.query(Query::<Views!(&mut A)>::new()).limited(4).iter {
+            a.0 = 2;
+            count += 1;
+        }
+
+        assert_eq!(count, 4);
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![1, 1, 2, 2, 2, 2]);
+    }
+
+    #[test]
+    fn query_limited_stops_mid_archetype() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 10));
+
+        let mut iter = world.query(Query::<Views!(&A)>::new()).limited(3).iter;
+
+        assert_eq!(iter.len(), 3);
+        assert_some!(iter.next());
+        assert_some!(iter.next());
+        assert_some!(iter.next());
+        assert_none!(iter.next());
+    }
+
+    #[test]
+    fn query_limited_more_than_matches() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 2));
+        world.insert(entity!(B('a')));
+
+        assert_eq!(
+            world
+                .query(Query::<Views!(&A)>::new())
+                .limited(5)
+                .iter
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn query_limited_keeps_resources() {
+        let mut world = World::<Registry, _>::with_resources(resources!(A(0)));
+
+        world.extend(entities!((A(1)); 5));
+
+        let result = world
+            .query(Query::<Views!(&A), filter::None, Views!(&mut A)>::new())
+            .limited(2);
+        let result!(total) = result.resources;
+        for result!(a) in result.iter {
+            total.0 += a.0;
+        }
+
+        assert_eq!(world.get::<A, _>(), &A(2));
+    }
+
+    #[test]
+    fn query_rev() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')), (A(2), B('b')), (A(3), B('c'))));
+        world.extend(entities!((A(4)), (A(5))));
+        world.insert(entity!(B('d')));
+
+        let forward = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        let mut backward = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .rev()
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn query_rev_optional_views() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(B('b')));
+
+        let forward = world
+            .query(Query::<Views!(Option<&mut A>, &B, entity::Identifier)>::new())
+            .iter
+            .map(|result!(a, b, identifier)| (a.map(|a| a.0), b.0, identifier))
+            .collect::<Vec<_>>();
+        let mut backward = world
+            .query(Query::<Views!(Option<&mut A>, &B, entity::Identifier)>::new())
+            .iter
+            .rev()
+            .map(|result!(a, b, identifier)| (a.map(|a| a.0), b.0, identifier))
+            .collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn query_rev_meets_in_middle() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 3));
+        world.extend(entities!((A(1), B('a')); 3));
+
+        let mut iter = world.query(Query::<Views!(&mut A)>::new()).iter;
+        let mut count = 0;
+        while let Some(result!(a)) = iter.next() {
+            a.0 += 1;
+            count += 1;
+            if let Some(result!(a)) = iter.next_back() {
+                a.0 += 1;
+                count += 1;
+            }
+            assert_eq!(iter.len(), 6 - count);
+        }
+
+        assert_eq!(count, 6);
+        assert!(world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .all(|result!(a)| a.0 == 2));
+    }
+
+    #[test]
+    fn query_rev_within_single_archetype() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)), (A(2)), (A(3))));
+
+        let mut iter = world.query(Query::<Views!(&A)>::new()).iter;
+
+        assert_some_eq!(iter.next_back().map(|result!(a)| a.0), 3);
+        assert_some_eq!(iter.next().map(|result!(a)| a.0), 1);
+        assert_some_eq!(iter.next_back().map(|result!(a)| a.0), 2);
+        assert_none!(iter.next());
+        assert_none!(iter.next_back());
+    }
+
+    #[test]
+    fn query_skip_archetypes() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 3));
+        world.extend(entities!((A(2), B('a')); 2));
+
+        let iter = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .skip_archetypes(1);
+        let len = iter.len();
+        let results = iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+
+        assert_eq!(results.len(), len);
+        assert!(results == vec![1; 3] || results == vec![2; 2]);
+    }
+
+    #[test]
+    fn query_skip_archetypes_all() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 3));
+        world.extend(entities!((A(2), B('a')); 2));
+
+        assert_eq!(
+            world
+                .query(Query::<Views!(&A)>::new())
+                .iter
+                .skip_archetypes(5)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn query_take_archetypes() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 3));
+        world.extend(entities!((A(2), B('a')); 2));
+
+        let iter = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .take_archetypes(1);
+        let len = iter.len();
+        let results = iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+
+        assert_eq!(results.len(), len);
+        assert!(results == vec![1; 3] || results == vec![2; 2]);
+    }
+
+    #[test]
+    fn query_take_archetypes_zero() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 3));
+
+        assert_eq!(
+            world
+                .query(Query::<Views!(&A)>::new())
+                .iter
+                .take_archetypes(0)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn query_take_archetypes_rev() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 3));
+        world.extend(entities!((A(2), B('a')); 2));
+        world.extend(entities!((B('b')); 4));
+
+        let forward = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .take_archetypes(1)
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        let mut backward = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .take_archetypes(1)
+            .rev()
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn query_archetype_shards() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 3));
+        world.insert(entity!(B('a')));
+        world.extend(entities!((A(2), B('b')); 2));
+        world.insert(entity!());
+
+        let mut results = Vec::new();
+        for shard in 0..3 {
+            results.extend(
+                world
+                    .query(Query::<Views!(&A)>::new())
+                    .iter
+                    .skip_archetypes(shard)
+                    .take_archetypes(1)
+                    .map(|result!(a)| a.0),
+            );
+        }
+        results.sort_unstable();
+
+        assert_eq!(results, vec![1, 1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn query_peek_identifier() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 3));
+        world.insert(entity!(B('a')));
+        world.extend(entities!((A(2), B('b')); 3));
+
+        let mut iter = world
+            .query(Query::<Views!(&mut A, entity::Identifier)>::new())
+            .iter;
+        let mut count = 0;
+        while let Some(entity_identifier) = iter.peek_identifier() {
+            assert_eq!(iter.peek_identifier(), Some(entity_identifier));
+            let result!(a, viewed_identifier) = assert_some!(iter.next());
+            assert_eq!(viewed_identifier, entity_identifier);
+            a.0 += 1;
+            count += 1;
+        }
+
+        assert_eq!(count, 6);
+        assert_none!(iter.next());
+    }
+
+    #[test]
+    fn query_peek_identifier_meets_in_middle() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)); 3));
+        world.extend(entities!((A(1), B('a')); 3));
+
+        let mut iter = world
+            .query(Query::<Views!(&A, entity::Identifier)>::new())
+            .iter;
+        let mut count = 0;
+        while let Some(entity_identifier) = iter.peek_identifier() {
+            let result!(_a, viewed_identifier) = assert_some!(iter.next());
+            assert_eq!(viewed_identifier, entity_identifier);
+            count += 1;
+            if iter.next_back().is_some() {
+                count += 1;
+            }
+        }
+
+        assert_eq!(count, 6);
+        assert_none!(iter.next());
+    }
+
+    #[test]
+    fn query_peek_identifier_within_back_archetype() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1)), (A(2)), (A(3))));
+
+        let mut iter = world
+            .query(Query::<Views!(&A, entity::Identifier)>::new())
+            .iter;
+        assert_some!(iter.next_back());
+
+        let entity_identifier = assert_some!(iter.peek_identifier());
+        let result!(a, viewed_identifier) = assert_some!(iter.next());
+        assert_eq!(viewed_identifier, entity_identifier);
+        assert_eq!(a.0, 1);
+    }
+
+    #[test]
+    fn query_peek_identifier_empty() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(B('a')));
+
+        assert_none!(world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .peek_identifier());
+    }
+
+    #[test]
+    fn query_gated_open() {
+        let mut world = World::<Registry, _>::with_resources(resources!(A(1)));
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(B('b')));
+
+        let mut result = world
+            .query_gated(
+                Query::<Views!(&B), filter::None, Views!(&A)>::new(),
+                |result!(a)| a.0 == 1,
+            )
+            .iter
+            .map(|result!(b)| b.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec!['a', 'b']);
+    }
+
+    #[test]
+    fn query_gated_closed() {
+        let mut world = World::<Registry, _>::with_resources(resources!(A(1)));
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(B('b')));
+
+        let result = world.query_gated(
+            Query::<Views!(&B), filter::None, Views!(&mut A)>::new(),
+            |result!(a)| a.0 == 2,
+        );
+        let result!(a) = result.resources;
+        a.0 += 1;
+        assert_eq!(result.iter.count(), 0);
+        assert_eq!(world.get::<A, _>(), &A(2));
+    }
+
+    #[test]
+    fn query_gated_closed_size_hint() {
+        let mut world = World::<Registry, _>::with_resources(resources!(A(1)));
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(B('b')));
+
+        let mut iter = world
+            .query_gated(
+                Query::<Views!(&B), filter::None, Views!(&A)>::new(),
+                |result!(a)| a.0 == 2,
+            )
+            .iter;
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_none!(iter.next_back());
+        assert_none!(iter.next());
+    }
+
+    #[test]
+    fn query_archetype_columns() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+        world.insert(entity!(B('c')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .archetype_columns()
+            .map(|columns| {
+                let len = columns.len();
+                let result!(a) = columns.into_slices();
+                assert_eq!(a.len(), len);
+                a.iter().map(|a| a.0).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn query_archetype_columns_mut() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        for mut columns in world
+            .query(Query::<Views!(&mut A)>::new())
+            .iter
+            .archetype_columns()
+        {
+            let result!(a) = columns.slices_mut();
+            for a in a.iter_mut() {
+                a.0 += 1;
+            }
+        }
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![2, 3]);
+    }
+
+    #[test]
+    fn query_archetype_columns_len_without_columns() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+
+        let columns = world
+            .query(Query::<Views!(Option<&B>)>::new())
+            .iter
+            .archetype_columns()
+            .collect::<Vec<_>>();
+        assert_eq!(columns.len(), 1);
+        assert_eq!(columns[0].len(), 2);
+        assert!(!columns[0].is_empty());
+        let result!(b) = columns[0].slices();
+        assert_none!(b);
+    }
+
+    #[test]
+    fn query_archetype_slices_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+        world.insert(entity!(B('c')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .archetype_slices()
+            .map(|result!(a)| a.iter().map(|a| a.0).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn query_archetype_slices_copied() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+        world.insert(entity!(B('c')));
+        world.insert(entity!());
+
+        let mut result = world
+            .query(Query::<Views!(view::Copied<A>)>::new())
+            .iter
+            .archetype_slices()
+            .map(|result!(a)| a.iter().map(|a| a.0).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn query_archetype_slices_index() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+        world.insert(entity!(B('c')));
+
+        let mut result = world
+            .query(Query::<Views!(&A, view::Index)>::new())
+            .iter
+            .archetype_slices()
+            .map(|result!(a, indices)| (a.len(), indices))
+            .collect::<Vec<_>>();
+        result.sort_by_key(|(len, _)| *len);
+        assert_eq!(result, vec![(1, 0..1), (2, 0..2)]);
+    }
+
+    #[test]
+    fn query_archetype_slices_archetype_id() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+
+        let archetype_ids = world
+            .query(Query::<Views!(view::ArchetypeId)>::new())
+            .iter
+            .archetype_slices()
+            .map(|result!(archetype_id)| archetype_id)
+            .collect::<Vec<_>>();
+        let mut entity_archetype_ids = world
+            .query(Query::<Views!(view::ArchetypeId)>::new())
+            .iter
+            .map(|result!(archetype_id)| archetype_id)
+            .collect::<Vec<_>>();
+        entity_archetype_ids.dedup();
+        assert_eq!(archetype_ids.len(), 2);
+        assert_eq!(archetype_ids, entity_archetype_ids);
+    }
+
+    #[test]
+    fn query_archetype_slices_mut_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+
+        for result!(a, b) in world
+            .query(Query::<Views!(&mut A, &B)>::new())
+            .iter
+            .archetype_slices()
+        {
+            assert_eq!(a.len(), b.len());
+            for a in a {
+                a.0 += 10;
+            }
+        }
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![3, 11, 12]);
+    }
+
+    #[test]
+    fn query_archetype_slices_option_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut result = world
+            .query(Query::<Views!(&A, Option<&B>)>::new())
+            .iter
+            .archetype_slices()
+            .map(|result!(a, b)| {
+                (
+                    a.iter().map(|a| a.0).collect::<Vec<_>>(),
+                    b.map(|b| b.iter().map(|b| b.0).collect::<Vec<_>>()),
+                )
+            })
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![(vec![1], Some(vec!['a'])), (vec![2], None)]);
+    }
+
+    #[test]
+    fn query_archetype_slices_option_mut_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        for result!(b) in world
+            .query(Query::<Views!(Option<&mut B>), filter::Has<A>>::new())
+            .iter
+            .archetype_slices()
+        {
+            if let Some(b) = b {
+                for b in b {
+                    b.0 = 'z';
+                }
+            }
+        }
+
+        let result = world
+            .query(Query::<Views!(&B)>::new())
+            .iter
+            .map(|result!(b)| b.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec!['z']);
+    }
+
+    #[test]
+    fn query_archetype_slices_entity_identifiers() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let result = world
+            .query(Query::<Views!(&B, entity::Identifier)>::new())
+            .iter
+            .archetype_slices()
+            .map(|result!(b, identifiers)| (b.len(), identifiers.to_vec()))
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![(1, vec![entity_identifier])]);
+    }
+
+    #[test]
+    fn query_archetype_slices_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let result = world
+            .query(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new())
+            .iter
+            .archetype_slices()
+            .map(|result!(a)| a.iter().map(|a| a.0).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![vec![2]]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .par_query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_copied() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .par_query(Query::<Views!(view::Copied<A>)>::new())
+            .iter
+            .map(|result!(a)| a.0 .0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_index() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+        world.insert(entity!(B('c')));
+        world.insert(entity!());
+
+        let mut result = world
+            .par_query(Query::<Views!(&A, view::Index)>::new())
+            .iter
+            .map(|result!(a, index)| (a.0, index.0))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![(1, 0), (2, 1), (3, 0)]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_archetype_id() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+
+        let mut par_result = world
+            .par_query(Query::<Views!(&A, view::Index, view::ArchetypeId)>::new())
+            .iter
+            .map(|result!(a, index, archetype_id)| (a.0, index, archetype_id))
+            .collect::<Vec<_>>();
+        par_result.sort();
+        let mut result = world
+            .query(Query::<Views!(&A, view::Index, view::ArchetypeId)>::new())
+            .iter
+            .map(|result!(a, index, archetype_id)| (a.0, index, archetype_id))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(par_result, result);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_for_each() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        world.par_for_each::<Views!(&mut A, Option<&B>), filter::None, _, _>(|result!(a, b)| {
+            if b.is_some() {
+                a.0 += 10;
+            }
+        });
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![2, 11]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_for_each_entity() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier_a = world.insert(entity!(A(1), B('a')));
+        let removed_entity_identifier = world.insert(entity!(A(2)));
+        let entity_identifier_b = world.insert(entity!(B('b')));
+        let entity_identifier_c = world.insert(entity!());
+        world.remove(removed_entity_identifier);
+
+        let count = AtomicUsize::new(0);
+        let index_sum = AtomicUsize::new(0);
+        world.par_for_each_entity(|entity_identifier| {
+            assert_ne!(entity_identifier, removed_entity_identifier);
+            count.fetch_add(1, Ordering::Relaxed);
+            index_sum.fetch_add(entity_identifier.index, Ordering::Relaxed);
+        });
+
+        assert_eq!(count.into_inner(), 3);
+        assert_eq!(
+            index_sum.into_inner(),
+            entity_identifier_a.index + entity_identifier_b.index + entity_identifier_c.index
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_for_each_entity_empty() {
+        let world = World::<Registry>::new();
+
+        let count = AtomicUsize::new(0);
+        world.par_for_each_entity(|_| {
+            count.fetch_add(1, Ordering::Relaxed);
+        });
+
+        assert_eq!(count.into_inner(), 0);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_archetype_slices() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+        world.insert(entity!(B('c')));
+        world.insert(entity!());
+
+        let mut result = world
+            .par_query(Query::<Views!(&A)>::new())
+            .iter
+            .archetype_slices()
+            .map(|result!(a)| a.iter().map(|a| a.0).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![vec![1, 2], vec![3]]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_archetype_slices_mut_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+
+        world
+            .par_query(Query::<Views!(&mut A, Option<&B>)>::new())
+            .iter
+            .archetype_slices()
+            .for_each(|result!(a, b)| {
+                let increment = if b.is_some() { 10 } else { 20 };
+                for a in a {
+                    a.0 += increment;
+                }
+            });
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![11, 12, 23]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_archetype_slices_filtered() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3)));
+
+        let result = world
+            .par_query(Query::<
+                Views!(&A, entity::Identifier),
+                filter::Not<filter::Has<B>>,
+            >::new())
+            .iter
+            .archetype_slices()
+            .map(|result!(a, identifiers)| (a.len(), identifiers.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![(1, 1)]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_mut_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .par_query(Query::<Views!(&mut B)>::new())
+            .iter
+            .map(|result!(b)| b.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec!['a', 'b']);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_option_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .par_query(Query::<Views!(Option<&A>)>::new())
+            .iter
+            .map(|result!(a)| a.map(|a| a.0))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![None, None, Some(1), Some(2)]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_option_mut_refs() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .par_query(Query::<Views!(Option<&mut B>)>::new())
+            .iter
+            .map(|result!(b)| b.map(|b| b.0))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![None, None, Some('a'), Some('b')]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_entity_identifiers() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .par_query(Query::<
+                Views!(entity::Identifier),
+                filter::And<filter::Has<A>, filter::Has<B>>,
+            >::new())
+            .iter
+            .map(|result!(identifier)| identifier)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![entity_identifier]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_has_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .par_query(Query::<Views!(&A), filter::Has<B>>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![1]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_not_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .par_query(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![2]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_and_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let result = world
+            .par_query(Query::<
+                Views!(&A),
+                filter::And<filter::Has<A>, filter::Has<B>>,
+            >::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![1]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_or_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut result = world
+            .par_query(Query::<
+                Views!(&A),
+                filter::Or<filter::Has<A>, filter::Has<B>>,
+            >::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_resources() {
+        let mut world = World::<Registry!(), _>::with_resources(resources!(A(42), B('a')));
+
+        let result!(a, b) = world
+            .par_query(Query::<Views!(), filter::None, Views!(&A, &mut B)>::new())
+            .resources;
+        b.0 = 'b';
+
+        assert_eq!(a, &A(42));
+        assert_eq!(b, &mut B('b'));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_resources_reshaped() {
+        let mut world = World::<Registry!(), _>::with_resources(resources!(A(42), B('a')));
+
+        let result!(b, a) = world
+            .par_query(Query::<Views!(), filter::None, Views!(&B, &mut A)>::new())
+            .resources;
+        a.0 = 100;
+
+        assert_eq!(a, &A(100));
+        assert_eq!(b, &mut B('a'));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_empty() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(B('a'), A(1)));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let count = world.par_query(Query::<Views!()>::new()).iter.count();
+
+        assert_eq!(count, 4);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_find_any() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(B('b')));
+
+        let result!(a) = assert_some!(world.par_query(Query::<Views!(&A)>::new()).find_any());
+        assert_eq!(a, &A(1));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_find_map_any() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')); 100));
+        world.insert(entity!(A(2)));
+
+        assert_some_eq!(
+            world
+                .par_query(Query::<Views!(&A)>::new())
+                .find_map_any(|result!(a)| (a.0 == 2).then_some(a.0)),
+            2
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_find_map_any_no_matches() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')); 100));
+
+        assert_none!(world
+            .par_query(Query::<Views!(&A)>::new())
+            .find_map_any(|result!(a)| (a.0 == 2).then_some(a.0)));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_find_any_no_matches() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(B('a')));
+
+        assert_none!(world.par_query(Query::<Views!(&A)>::new()).find_any());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_with_min_len() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')); 100));
+
+        let chunk_lens = world
+            .par_query(Query::<Views!(&A)>::new())
+            .iter
+            .with_min_len(100)
+            .fold(|| 0, |len, _| len + 1)
+            .collect::<Vec<_>>();
+        assert_eq!(chunk_lens, vec![100]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_with_min_len_multiple_archetypes() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')); 100));
+        world.extend(entities!((A(2)); 50));
+
+        let mut chunk_lens = world
+            .par_query(Query::<Views!(&A)>::new())
+            .iter
+            .with_min_len(100)
+            .fold(|| 0, |len, _| len + 1)
+            .collect::<Vec<_>>();
+        chunk_lens.sort_unstable();
+        // Each archetype is split separately, so the smaller archetype is a chunk of its own.
+        assert_eq!(chunk_lens, vec![50, 100]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_with_max_len() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')); 100));
+
+        let chunk_lens = world
+            .par_query(Query::<Views!(&mut A)>::new())
+            .iter
+            .with_max_len(10)
+            .fold(
+                || 0,
+                |len, result!(a)| {
+                    a.0 += 1;
+                    len + 1
+                },
+            )
+            .collect::<Vec<_>>();
+        assert!(chunk_lens.len() >= 10);
+        assert!(chunk_lens.iter().all(|&len| len <= 10));
+        assert_eq!(chunk_lens.iter().sum::<usize>(), 100);
+        assert!(world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .all(|result!(a)| a.0 == 2));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_query_with_min_len_and_filter() {
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(1), B('a')); 100));
+        world.extend(entities!((A(2)); 50));
+
+        let result = world
+            .par_query(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new())
+            .iter
+            .with_min_len(16)
+            .with_max_len(32)
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![2; 50]);
+    }
+
+    #[test]
+    fn system_refs() {
+        struct TestSystem;
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![1, 2]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_system(&mut TestSystem);
+    }
+
+    #[test]
+    fn system_mut_refs() {
+        struct TestSystem;
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(&'a mut B);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(b)| b.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec!['a', 'b']);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_system(&mut TestSystem);
+    }
+
+    #[test]
+    fn system_option_refs() {
+        struct TestSystem;
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(Option<&'a A>);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results
+                    .iter
+                    .map(|result!(a)| a.map(|a| a.0))
+                    .collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![None, None, Some(1), Some(2)]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_system(&mut TestSystem);
+    }
+
+    #[test]
+    fn system_option_mut_refs() {
+        struct TestSystem;
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(Option<&'a mut B>);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results
+                    .iter
+                    .map(|result!(b)| b.map(|b| b.0))
+                    .collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![None, None, Some('a'), Some('b')]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_system(&mut TestSystem);
+    }
+
+    #[test]
+    fn system_entity_identifier() {
+        struct TestSystem {
+            entity_identifier: entity::Identifier,
+        }
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(entity::Identifier);
+            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let result = query_results
+                    .iter
+                    .map(|result!(entity_identifier)| entity_identifier)
+                    .collect::<Vec<_>>();
+                assert_eq!(result, vec![self.entity_identifier]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_system(&mut TestSystem { entity_identifier });
+    }
+
+    #[test]
+    fn system_has_filter() {
+        struct TestSystem;
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::Has<B>;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                assert_eq!(result, vec![1]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_system(&mut TestSystem);
+    }
+
+    #[test]
+    fn system_not_filter() {
+        struct TestSystem;
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::Not<filter::Has<B>>;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                assert_eq!(result, vec![2]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_system(&mut TestSystem);
+    }
+
+    #[test]
+    fn system_and_filter() {
+        struct TestSystem;
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                assert_eq!(result, vec![1]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_system(&mut TestSystem);
+    }
+
+    #[test]
+    fn system_or_filter() {
+        struct TestSystem;
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::Or<filter::Has<A>, filter::Has<B>>;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![1, 2]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_system(&mut TestSystem);
+    }
+
+    #[test]
+    fn system_resource_views() {
+        struct Counter(usize);
+
+        struct TestSystem;
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(&'a A, &'a B);
+            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
+            type ResourceViews<'a> = Views!(&'a mut Counter);
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let result!(counter) = query_results.resources;
+                counter.0 = query_results.iter.count();
+            }
+        }
+
+        let mut world = World::<Registry, _>::with_resources(resources!(Counter(0)));
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_system(&mut TestSystem);
+
+        assert_eq!(world.get::<Counter, _>().0, 1);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_system_refs() {
+        struct TestSystem;
+
+        impl ParSystem for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![1, 2]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_par_system(&mut TestSystem);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_system_mut_refs() {
+        struct TestSystem;
+
+        impl ParSystem for TestSystem {
+            type Views<'a> = Views!(&'a mut B);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(b)| b.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec!['a', 'b']);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_par_system(&mut TestSystem);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_system_option_refs() {
+        struct TestSystem;
+
+        impl ParSystem for TestSystem {
+            type Views<'a> = Views!(Option<&'a A>);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results
+                    .iter
+                    .map(|result!(a)| a.map(|a| a.0))
+                    .collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![None, None, Some(1), Some(2)]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_par_system(&mut TestSystem);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_system_option_mut_refs() {
+        struct TestSystem;
+
+        impl ParSystem for TestSystem {
+            type Views<'a> = Views!(Option<&'a mut B>);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results
+                    .iter
+                    .map(|result!(b)| b.map(|b| b.0))
+                    .collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![None, None, Some('a'), Some('b')]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_par_system(&mut TestSystem);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_system_entity_identifier() {
+        struct TestSystem {
+            entity_identifier: entity::Identifier,
+        }
+
+        impl ParSystem for TestSystem {
+            type Views<'a> = Views!(entity::Identifier);
+            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let result = query_results
+                    .iter
+                    .map(|result!(entity_identifier)| entity_identifier)
+                    .collect::<Vec<_>>();
+                assert_eq!(result, vec![self.entity_identifier]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_par_system(&mut TestSystem { entity_identifier });
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_system_has_filter() {
+        struct TestSystem;
+
+        impl ParSystem for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::Has<B>;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                assert_eq!(result, vec![1]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_par_system(&mut TestSystem);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_system_not_filter() {
+        struct TestSystem;
+
+        impl ParSystem for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::Not<filter::Has<B>>;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                assert_eq!(result, vec![2]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_par_system(&mut TestSystem);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_system_and_filter() {
+        struct TestSystem;
+
+        impl ParSystem for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                assert_eq!(result, vec![1]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_par_system(&mut TestSystem);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_system_or_filter() {
+        struct TestSystem;
+
+        impl ParSystem for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::Or<filter::Has<A>, filter::Has<B>>;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![1, 2]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_par_system(&mut TestSystem);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_system_resource_views() {
+        struct Counter(usize);
+
+        struct TestSystem;
+
+        impl ParSystem for TestSystem {
+            type Views<'a> = Views!(&'a A, &'a B);
+            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
+            type ResourceViews<'a> = Views!(&'a mut Counter);
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let result!(counter) = query_results.resources;
+                counter.0 = query_results.iter.count();
+            }
+        }
+
+        let mut world = World::<Registry, _>::with_resources(resources!(Counter(0)));
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.run_par_system(&mut TestSystem);
+
+        assert_eq!(world.get::<Counter, _>().0, 1);
+    }
+
+    #[cfg(feature = "rayon")]
+    struct SumA;
+
+    #[cfg(feature = "rayon")]
+    impl ParReduceSystem for SumA {
+        type Views<'a> = Views!(&'a A);
+        type Filter = filter::None;
+        type Output = u32;
+
+        fn identity(&self) -> Self::Output {
+            0
+        }
+
+        fn map<'a>(&self, result!(a): Self::Views<'a>) -> Self::Output {
+            a.0
+        }
+
+        fn reduce(&self, a: Self::Output, b: Self::Output) -> Self::Output {
+            a + b
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_reduce_system() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+        world.extend(entities!((A(3)); 100));
+
+        assert_eq!(world.run_par_reduce_system(&SumA), 303);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_reduce_system_empty() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(B('a')));
+
+        assert_eq!(world.run_par_reduce_system(&SumA), 0);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_reduce_system_filter() {
+        struct CountBWithoutA;
+
+        impl ParReduceSystem for CountBWithoutA {
+            type Views<'a> = Views!(&'a B);
+            type Filter = filter::Not<filter::Has<A>>;
+            type Output = usize;
+
+            fn identity(&self) -> Self::Output {
+                0
+            }
+
+            fn map<'a>(&self, _views: Self::Views<'a>) -> Self::Output {
+                1
+            }
+
+            fn reduce(&self, a: Self::Output, b: Self::Output) -> Self::Output {
+                a + b
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(B('b')));
+        world.insert(entity!(B('c')));
+        world.insert(entity!(A(2)));
+
+        assert_eq!(world.run_par_reduce_system(&CountBWithoutA), 2);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_reduce_system_mut_refs() {
+        struct IncrementAndSumA;
+
+        impl ParReduceSystem for IncrementAndSumA {
+            type Views<'a> = Views!(&'a mut A);
+            type Filter = filter::None;
+            type Output = u32;
+
+            fn identity(&self) -> Self::Output {
+                0
+            }
+
+            fn map<'a>(&self, result!(a): Self::Views<'a>) -> Self::Output {
+                a.0 += 1;
+                a.0
+            }
+
+            fn reduce(&self, a: Self::Output, b: Self::Output) -> Self::Output {
+                a + b
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        assert_eq!(world.run_par_reduce_system(&IncrementAndSumA), 5);
+        assert_eq!(world.run_par_reduce_system(&SumA), 5);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn schedule() {
+        struct TestSystem;
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![1, 2]);
+            }
+        }
+
+        struct TestParSystem;
+
+        impl ParSystem for TestParSystem {
+            type Views<'a> = Views!(&'a mut B);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(b)| b.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec!['a', 'b']);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut schedule = schedule!(task::System(TestSystem), task::ParSystem(TestParSystem));
+
+        world.run_schedule(&mut schedule);
+    }
+
+    #[test]
+    fn schedule_runs_tasks_in_order() {
+        struct Increment;
+
+        impl System for Increment {
+            type Views<'a> = Views!(&'a mut A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(a) in query_results.iter {
+                    a.0 += 1;
+                }
+            }
+        }
+
+        struct Check;
+
+        impl System for Check {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![2, 3]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        let mut schedule = schedule!(task::System(Increment), task::System(Check));
+
+        world.run_schedule(&mut schedule);
+    }
+
+    struct CountingClock(AtomicU32);
+
+    impl schedule::Clock for CountingClock {
+        fn now(&self) -> Duration {
+            Duration::from_secs(self.0.fetch_add(1, Ordering::Relaxed).into())
+        }
+    }
+
+    #[test]
+    fn run_schedule_instrumented() {
+        struct Increment;
+
+        impl System for Increment {
+            type Views<'a> = Views!(&'a mut A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(a) in query_results.iter {
+                    a.0 += 1;
+                }
+            }
+        }
+
+        struct Check;
+
+        impl System for Check {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![2, 3]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut schedule = schedule!(task::System(Increment), task::System(Check));
+        let report =
+            world.run_schedule_instrumented(&mut schedule, &CountingClock(AtomicU32::new(0)));
+
+        // The tasks conflict, so they are run one after the other in separate stages.
+        assert_eq!(
+            report
+                .tasks()
+                .iter()
+                .map(|task| (task.name(), task.duration()))
+                .collect::<Vec<_>>(),
+            vec![
+                (type_name::<Increment>(), Duration::from_secs(1)),
+                (type_name::<Check>(), Duration::from_secs(1))
+            ]
+        );
+        assert_eq!(
+            report.stages(),
+            &[Duration::from_secs(3), Duration::from_secs(3)]
+        );
+        assert_eq!(report.total(), Duration::from_secs(6));
+    }
+
+    #[test]
+    fn run_schedule_instrumented_empty() {
+        let mut world = World::<Registry>::new();
+
+        let report =
+            world.run_schedule_instrumented(&mut schedule!(), &CountingClock(AtomicU32::new(0)));
+
+        assert!(report.tasks().is_empty());
+        assert!(report.stages().is_empty());
+        assert_eq!(report.total(), Duration::ZERO);
+    }
+
+    #[test]
+    fn schedule_copied() {
+        struct Increment;
+
+        impl System for Increment {
+            type Views<'a> = Views!(&'a mut A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(a) in query_results.iter {
+                    a.0 += 1;
+                }
+            }
+        }
+
+        struct CheckCopied(Vec<u32>);
+
+        impl System for CheckCopied {
+            type Views<'a> = Views!(view::Copied<A>);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results
+                    .iter
+                    .map(|result!(a)| a.0 .0)
+                    .collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, self.0);
+            }
+        }
+
+        struct Check;
+
+        impl System for Check {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![2, 3]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        let mut schedule = schedule!(
+            task::System(CheckCopied(vec![1, 2])),
+            task::System(Increment),
+            task::System(CheckCopied(vec![2, 3])),
+            task::System(Check)
+        );
+
+        world.run_schedule(&mut schedule);
+    }
+
+    #[test]
+    fn schedule_index() {
+        struct AddIndex;
+
+        impl System for AddIndex {
+            type Views<'a> = Views!(&'a mut A, view::Index);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(a, index) in query_results.iter {
+                    a.0 += u32::try_from(index.0).unwrap();
+                }
+            }
+        }
+
+        struct CheckIndex;
+
+        impl System for CheckIndex {
+            type Views<'a> = Views!(view::Index, &'a B);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results
+                    .iter
+                    .map(|result!(index, b)| (index.0, b.0))
+                    .collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![(0, 'a'), (0, 'c'), (1, 'b')]);
+            }
+        }
+
+        struct Check;
+
+        impl System for Check {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
+                result.sort();
+                assert_eq!(result, vec![1, 3, 4]);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(4)));
+        world.insert(entity!(B('c')));
+
+        let mut schedule = schedule!(
+            task::System(AddIndex),
+            task::System(CheckIndex),
+            task::System(Check)
+        );
+
+        world.run_schedule(&mut schedule);
+    }
+
+    #[test]
+    fn schedule_archetype_id() {
+        struct CountA(Arc<AtomicU32>);
+
+        impl System for CountA {
+            type Views<'a> = Views!(&'a mut A, view::ArchetypeId);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut archetype_ids = query_results
+                    .iter
+                    .map(|result!(_a, archetype_id)| archetype_id)
+                    .collect::<Vec<_>>();
+                archetype_ids.sort();
+                archetype_ids.dedup();
+                self.0.store(
+                    u32::try_from(archetype_ids.len()).unwrap(),
+                    Ordering::Relaxed,
+                );
+            }
+        }
+
+        struct CountB(Arc<AtomicU32>);
+
+        impl System for CountB {
+            type Views<'a> = Views!(view::ArchetypeId, &'a B);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut archetype_ids = query_results
+                    .iter
+                    .map(|result!(archetype_id, _b)| archetype_id)
+                    .collect::<Vec<_>>();
+                archetype_ids.sort();
+                archetype_ids.dedup();
+                self.0.store(
+                    u32::try_from(archetype_ids.len()).unwrap(),
+                    Ordering::Relaxed,
+                );
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(4)));
+        world.insert(entity!(B('c')));
+
+        let count_a = Arc::new(AtomicU32::new(0));
+        let count_b = Arc::new(AtomicU32::new(0));
+        let mut schedule = schedule!(
+            task::System(CountA(Arc::clone(&count_a))),
+            task::System(CountB(Arc::clone(&count_b)))
+        );
+
+        world.run_schedule(&mut schedule);
+
+        assert_eq!(count_a.load(Ordering::Relaxed), 2);
+        assert_eq!(count_b.load(Ordering::Relaxed), 2);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn schedule_initialize_finalize() {
+        struct TestSystem {
+            initial_len: Option<usize>,
+        }
+
+        impl System for TestSystem {
+            type Views<'a> = Views!(&'a A);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn initialize<R, Resources, H>(&mut self, world: &mut World<R, Resources, H>)
+            where
+                R: registry::Registry,
+                H: BuildHasher,
+            {
+                self.initial_len = Some(world.len());
+            }
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                assert_eq!(self.initial_len, Some(4));
+                assert_eq!(query_results.iter.count(), 2);
+            }
+
+            fn finalize<R, Resources, H>(&mut self, world: &mut World<R, Resources, H>)
+            where
+                R: registry::Registry,
+                H: BuildHasher,
+            {
+                world.clear();
+            }
+        }
+
+        struct TestParSystem {
+            initial_len: Option<usize>,
+        }
+
+        impl ParSystem for TestParSystem {
+            type Views<'a> = Views!(&'a mut B);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn initialize<R, Resources, H>(&mut self, world: &mut World<R, Resources, H>)
+            where
+                R: registry::Registry,
+                H: BuildHasher,
+            {
+                self.initial_len = Some(world.len());
+            }
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: ParallelIterator<Item = Self::Views<'a>>,
+            {
+                assert_eq!(self.initial_len, Some(4));
+                assert_eq!(query_results.iter.count(), 2);
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut schedule = schedule!(
+            task::System(TestSystem { initial_len: None }),
+            task::ParSystem(TestParSystem { initial_len: None })
+        );
+
+        world.run_schedule(&mut schedule);
+
+        assert!(world.is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn schedule_dynamic_optimization() {
+        #[derive(Clone)]
+        struct A(u32);
+        #[derive(Clone)]
+        struct B(u32);
+        #[derive(Clone)]
+        struct C(u32);
+
+        type Registry = Registry!(A, B, C);
+
+        struct Foo;
+
+        impl System for Foo {
+            type Views<'a> = Views!(&'a mut A, &'a mut B);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(a, b) in query_results.iter {
+                    core::mem::swap(&mut a.0, &mut b.0);
+                }
+            }
+        }
+
+        struct Bar;
+
+        impl System for Bar {
+            type Views<'a> = Views!(&'a mut A, &'a mut C);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(a, c) in query_results.iter {
+                    core::mem::swap(&mut a.0, &mut c.0);
+                }
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(0), B(0)); 1000));
+        world.extend(entities!((A(0), C(0)); 1000));
+
+        let mut schedule = schedule!(task::System(Foo), task::System(Bar));
+
+        world.run_schedule(&mut schedule);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn schedule_dynamic_optimization_three_stages() {
+        #[derive(Clone)]
+        struct A(u32);
+        #[derive(Clone)]
+        struct B(u32);
+        #[derive(Clone)]
+        struct C(u32);
+
+        type Registry = Registry!(A, B, C);
+
+        struct Foo;
+
+        impl System for Foo {
+            type Views<'a> = Views!(&'a mut A, &'a mut B);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(a, b) in query_results.iter {
+                    core::mem::swap(&mut a.0, &mut b.0);
+                }
+            }
+        }
+
+        struct Bar;
+
+        impl System for Bar {
+            type Views<'a> = Views!(&'a mut A, &'a mut C);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(a, c) in query_results.iter {
+                    core::mem::swap(&mut a.0, &mut c.0);
+                }
+            }
+        }
+
+        struct Baz;
+
+        impl System for Baz {
+            type Views<'a> = Views!(&'a mut A, &'a mut B, &'a mut C);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
+            ) where
+                R: registry::Registry,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(a, _b, c) in query_results.iter {
+                    core::mem::swap(&mut a.0, &mut c.0);
+                }
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((A(0), B(0)); 1000));
+        world.extend(entities!((A(0), C(0)); 1000));
+
+        let mut schedule = schedule!(task::System(Foo), task::System(Bar), task::System(Baz));
+
+        world.run_schedule(&mut schedule);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn schedule_dynamic_optimization_entry_views() {
+        #[derive(Clone)]
+        struct A(u32);
+        #[derive(Clone)]
+        struct B(u32);
+        #[derive(Clone)]
+        struct C(u32);
+
+        type Registry = Registry!(A, B, C);
+
+        struct Foo;
+
+        impl System for Foo {
+            type Views<'a> = Views!(entity::Identifier);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!(&'a mut A, &'a mut B);
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                mut query_results: Result<
+                    'a,
+                    R,
+                    S,
+                    I,
+                    Self::ResourceViews<'a>,
+                    Self::EntryViews<'a>,
+                    E,
+                >,
+            ) where
+                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(identifier) in query_results.iter {
+                    if let Some(result!(b)) = query_results
+                        .entries
+                        .entry(identifier)
+                        .map(|mut entry| entry.query(Query::<Views!(&mut B)>::new()))
+                        .flatten()
+                    {
+                        b.0 += 1;
+                    }
+                }
+            }
+        }
+
+        struct Bar;
+
+        impl System for Bar {
+            type Views<'a> = Views!(entity::Identifier);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!(&'a mut A, &'a mut C);
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                mut query_results: Result<
+                    'a,
+                    R,
+                    S,
+                    I,
+                    Self::ResourceViews<'a>,
+                    Self::EntryViews<'a>,
+                    E,
+                >,
+            ) where
+                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                for result!(identifier) in query_results.iter {
+                    if let Some(result!(c)) = query_results
+                        .entries
+                        .entry(identifier)
+                        .map(|mut entry| entry.query(Query::<Views!(&mut C)>::new()))
+                        .flatten()
+                    {
+                        c.0 += 1;
+                    }
+                }
+            }
+        }
+
+        let mut world = World::<Registry>::new();
+
+        world.extend(entities!((B(0)); 1000));
+        world.extend(entities!((C(0)); 1000));
+
+        let mut schedule = schedule!(task::System(Foo), task::System(Bar));
+
+        world.run_schedule(&mut schedule);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn schedule_dynamic_optimization_compatible_resource_views() {
+        #[derive(Clone)]
+        struct A(u32);
+        #[derive(Clone)]
+        struct B(u32);
+        #[derive(Clone)]
+        struct C(u32);
+
+        type Registry = Registry!(A, B, C);
+
+        struct Foo;
+
+        impl System for Foo {
+            type Views<'a> = Views!(&'a mut A, &'a mut B);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!(&'a A);
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                _query_results: Result<
+                    'a,
+                    R,
+                    S,
+                    I,
+                    Self::ResourceViews<'a>,
+                    Self::EntryViews<'a>,
+                    E,
+                >,
+            ) where
+                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+            }
+        }
+
+        struct Bar;
+
+        impl System for Bar {
+            type Views<'a> = Views!(&'a mut A, &'a mut C);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!(&'a A);
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                _query_results: Result<
+                    'a,
+                    R,
+                    S,
+                    I,
+                    Self::ResourceViews<'a>,
+                    Self::EntryViews<'a>,
+                    E,
+                >,
+            ) where
+                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+            }
+        }
+
+        let mut world = World::<Registry, _>::with_resources(resources!(A(0)));
+
+        let mut schedule = schedule!(task::System(Foo), task::System(Bar));
+
+        world.run_schedule(&mut schedule);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn schedule_dynamic_optimization_compatible_resource_views_with_multiple_resource_views() {
+        #[derive(Clone)]
+        struct A(u32);
+        #[derive(Clone)]
+        struct B(u32);
+        #[derive(Clone)]
+        struct C(u32);
+
+        type Registry = Registry!(A, B, C);
+
+        struct Foo;
+
+        impl System for Foo {
+            type Views<'a> = Views!(&'a mut A, &'a mut B);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!(&'a B);
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                _query_results: Result<
+                    'a,
+                    R,
+                    S,
+                    I,
+                    Self::ResourceViews<'a>,
+                    Self::EntryViews<'a>,
+                    E,
+                >,
+            ) where
+                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+            }
+        }
+
+        struct Bar;
+
+        impl System for Bar {
+            type Views<'a> = Views!(&'a mut A, &'a mut C);
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!(&'a B);
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                _query_results: Result<
+                    'a,
+                    R,
+                    S,
+                    I,
+                    Self::ResourceViews<'a>,
+                    Self::EntryViews<'a>,
+                    E,
+                >,
+            ) where
+                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+            }
+        }
+
+        let mut world = World::<Registry, _>::with_resources(resources!(A(0), B(0), C(0)));
+
+        let mut schedule = schedule!(task::System(Foo), task::System(Bar));
+
+        world.run_schedule(&mut schedule);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn schedule_dynamic_optimization_incompatible_resource_views() {
+        #[derive(Clone)]
+        struct A(u32);
+        #[derive(Clone)]
+        struct B(u32);
+        #[derive(Clone)]
+        struct C(u32);
+
+        struct Foo;
+
+        impl System for Foo {
+            type Views<'a> = Views!();
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!(&'a mut A, &'a mut B);
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<
+                    'a,
+                    R,
+                    S,
+                    I,
+                    Self::ResourceViews<'a>,
+                    Self::EntryViews<'a>,
+                    E,
+                >,
+            ) where
+                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let result!(a, b) = query_results.resources;
+                core::mem::swap(&mut a.0, &mut b.0);
+            }
+        }
+
+        struct Bar;
+
+        impl System for Bar {
+            type Views<'a> = Views!();
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!(&'a mut A, &'a mut C);
+            type EntryViews<'a> = Views!();
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                query_results: Result<
+                    'a,
+                    R,
+                    S,
+                    I,
+                    Self::ResourceViews<'a>,
+                    Self::EntryViews<'a>,
+                    E,
+                >,
+            ) where
+                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let result!(a, c) = query_results.resources;
+                core::mem::swap(&mut a.0, &mut c.0);
+            }
+        }
+
+        let mut world = World::<Registry!(), _>::with_resources(resources!(A(0), B(0), C(0)));
+
+        let mut schedule = schedule!(task::System(Foo), task::System(Bar));
+
+        world.run_schedule(&mut schedule);
+    }
+
+    #[test]
+    fn contains() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        assert!(world.contains(entity_identifier));
+    }
+
+    #[test]
+    fn not_contains() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.remove(entity_identifier);
+
+        assert!(!world.contains(entity_identifier));
+    }
+
+    #[test]
+    fn archetype_identifier() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(B('a')));
+
+        assert_some_eq!(world.archetype_identifier(entity_identifier), vec![0b10]);
+    }
+
+    #[test]
+    fn archetype_identifier_all_components() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+
+        assert_some_eq!(world.archetype_identifier(entity_identifier), vec![0b11]);
+    }
+
+    #[test]
+    fn archetype_identifier_removed_entity() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1)));
+        world.remove(entity_identifier);
+
+        assert_none!(world.archetype_identifier(entity_identifier));
+    }
+
+    #[test]
+    fn archetype_key_entry() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(A(2), B('b')));
+        let third = world.insert(entity!(A(3)));
+
+        let key = assert_some!(world.entry(first)).archetype_key();
+        assert_eq!(key.as_bytes(), [0b11]);
+        assert_eq!(assert_some!(world.entry(second)).archetype_key(), key);
+        assert_ne!(assert_some!(world.entry(third)).archetype_key(), key);
+    }
+
+    #[test]
+    fn archetype_key_stable_after_archetype_removed() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1)));
+        let key = assert_some!(world.entry(entity_identifier)).archetype_key();
+        world.remove(entity_identifier);
+        world.shrink_to_fit();
+        let entity_identifier = world.insert(entity!(A(2)));
+
+        assert_eq!(
+            assert_some!(world.entry(entity_identifier)).archetype_key(),
+            key
+        );
+    }
+
+    #[test]
+    fn archetype_key_from_archetype_id() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(B('a')));
+        world.insert(entity!(A(1)));
+        let result!(archetype_id) = assert_some!(world
+            .query(Query::<Views!(view::ArchetypeId), filter::Has<B>>::new())
+            .iter
+            .next());
+
+        assert_some_eq!(
+            world.archetype_key(archetype_id),
+            assert_some!(world.entry(entity_identifier)).archetype_key()
+        );
+    }
+
+    #[test]
+    fn archetype_key_from_archetype_id_not_found() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1)));
+        let result!(archetype_id) = assert_some!(world
+            .query(Query::<Views!(view::ArchetypeId)>::new())
+            .iter
+            .next());
+
+        assert_none!(World::<Registry>::new().archetype_key(archetype_id));
+    }
+
+    #[test]
+    fn archetype_key_archetype_view() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1)));
+
+        let archetype = assert_some!(world.matching_archetypes::<filter::None, _>().next());
+        let key = archetype.key();
+        assert_eq!(
+            assert_some!(world.entry(entity_identifier)).archetype_key(),
+            key
+        );
+    }
+
+    #[test]
+    fn entry_add_component() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        let entity_identifier = world.insert(entity!());
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        entry.add(A(3));
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn entry_set_existing_component() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        entry.add(A(3));
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![2, 3]);
+    }
+
+    #[test]
+    fn entry_replace_existing_component() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        assert_some_eq!(entry.replace(A(3)), A(1));
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!(a, &A(3));
+        assert_eq!(b, &B('a'));
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![2, 3]);
+    }
+
+    #[test]
+    fn entry_replace_missing_component() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        let entity_identifier = world.insert(entity!(B('b')));
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        assert_none!(entry.replace(A(2)));
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!(a, &A(2));
+        assert_eq!(b, &B('b'));
+
+        assert_eq!(world.len_of::<Entity!(A, B), _>(), 2);
+    }
+
+    #[test]
+    fn entry_remove_component() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        entry.remove::<A, _>();
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![2]);
+    }
+
+    #[test]
+    fn entry_remove_component_dropped_once() {
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counted(usize);
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(self.0, Ordering::Relaxed);
+            }
+        }
+
+        let mut world = World::<Registry!(A, Counted)>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), Counted(1)));
+        world.insert(entity!(A(2), Counted(10)));
+
+        assert_some!(world.entry(entity_identifier)).remove::<Counted, _>();
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+
+        drop(world);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 11);
+    }
+
+    #[test]
+    fn entry_query() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+
+        let result!(queried_identifier, a, b) = assert_some!(entry.query(Query::<
+            Views!(entity::Identifier, &A, Option<&B>),
+            filter::None,
+        >::new()));
+        assert_eq!(queried_identifier, entity_identifier);
+        assert_eq!(a.0, 1);
+        let b = assert_some!(b);
+        assert_eq!(b.0, 'a');
+    }
+
+    #[test]
+    fn entry_query_mut() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+
+        let result!(a, b) =
+            assert_some!(entry.query(Query::<Views!(&mut A, Option<&mut B>)>::new()));
+        assert_eq!(a.0, 1);
+        let b = assert_some!(b);
+        assert_eq!(b.0, 'a');
+    }
+
+    #[test]
+    fn entry_unchecked() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut entry = unsafe { world.entry_unchecked(entity_identifier) };
+        entry.remove::<B, _>();
+
+        let result!(queried_identifier, a) = assert_some!(entry.query(Query::<
+            Views!(entity::Identifier, &A),
+            filter::Not<filter::Has<B>>,
+        >::new()));
+        assert_eq!(queried_identifier, entity_identifier);
+        assert_eq!(a.0, 1);
+    }
+
+    #[test]
+    fn entry_unchecked_after_reinsertion() {
+        let mut world = World::<Registry>::new();
+
+        let removed_identifier = world.insert(entity!(A(1)));
+        world.remove(removed_identifier);
+        let entity_identifier = world.insert(entity!(B('a')));
+
+        let mut entry = unsafe { world.entry_unchecked(entity_identifier) };
+        entry.add(A(2));
+
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!(a.0, 2);
+        assert_eq!(b.0, 'a');
+    }
+
+    #[test]
+    fn entry_modify() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        entry.modify(|modifier| {
+            modifier.remove::<A, _>();
+            modifier.add(B('a'));
+        });
+
+        let result!(queried_identifier, b) = assert_some!(entry.query(Query::<
+            Views!(entity::Identifier, &B),
+            filter::Not<filter::Has<A>>,
+        >::new()));
+        assert_eq!(queried_identifier, entity_identifier);
+        assert_eq!(b.0, 'a');
+    }
+
+    #[test]
+    fn entry_modify_replace() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        entry.modify(|modifier| {
+            modifier.add(A(3));
+            modifier.add(A(4));
+        });
+
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!(a.0, 4);
+        assert_eq!(b.0, 'a');
+    }
+
+    #[test]
+    fn entry_modify_add_then_remove() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1)));
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        entry.modify(|modifier| {
+            modifier.add(B('a'));
+            modifier.remove::<B, _>();
+        });
+
+        let result!(a) =
+            assert_some!(entry.query(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new()));
+        assert_eq!(a.0, 1);
+    }
+
+    #[test]
+    fn entry_modify_remove_then_add() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        entry.modify(|modifier| {
+            modifier.remove::<A, _>();
+            modifier.remove::<B, _>();
+            modifier.add(B('b'));
+        });
+
+        let result!(b) =
+            assert_some!(entry.query(Query::<Views!(&B), filter::Not<filter::Has<A>>>::new()));
+        assert_eq!(b.0, 'b');
+    }
+
+    #[test]
+    fn entry_modify_empty() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        entry.modify(|_| {});
+
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!(a.0, 1);
+        assert_eq!(b.0, 'a');
+    }
+
+    #[test]
+    fn apply_deferred() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2), B('a')));
+        world.insert(entity!(A(3)));
+
+        let query_result = world.query(Query::<Views!(&mut A, entity::Identifier)>::new());
+        let mut entries = query_result.entries;
+        for result!(a, entity_identifier) in query_result.iter {
+            a.0 += 10;
+            let mut entry = assert_some!(entries.entry(entity_identifier));
+            if a.0 % 2 == 0 {
+                entry.remove::<B, _>();
+            } else {
+                entry.add(B('b'));
+            }
+        }
+        let deferred = entries.into_deferred();
+        world.apply_deferred(deferred);
+
+        let mut result = world
+            .query(Query::<Views!(&A, Option<&B>)>::new())
+            .iter
+            .map(|result!(a, b)| (a.0, b.map(|b| b.0)))
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![(11, Some('b')), (12, None), (13, Some('b'))]);
+    }
+
+    #[test]
+    fn apply_deferred_empty() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1)));
+
+        let deferred = world
+            .query(Query::<Views!(&A)>::new())
+            .entries
+            .into_deferred();
+        assert!(deferred.is_empty());
+        world.apply_deferred(deferred);
+
+        assert_eq!(world.len(), 1);
+    }
+
+    #[test]
+    fn entry_query_fails() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1), B('a')));
+        let entity_identifier = world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+
+        assert_none!(entry.query(Query::<Views!(entity::Identifier, &A, &B)>::new()));
+    }
+
+    #[test]
+    fn entry_get_disjoint_mut() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+
+        let (b, a) = assert_some!(entry.get_disjoint_mut::<B, A, _>());
+        a.0 = 2;
+        b.0 = 'b';
+
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!(a.0, 2);
+        assert_eq!(b.0, 'b');
+    }
+
+    #[test]
+    fn entry_get_disjoint_mut_missing_component() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1)));
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+
+        assert_none!(entry.get_disjoint_mut::<A, B, _>());
+    }
+
+    #[test]
+    fn entry_component_type_ids() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(B('a'), A(1)));
+
+        let entry = assert_some!(world.entry(entity_identifier));
+
+        assert_eq!(
+            entry.component_type_ids().collect::<Vec<_>>(),
+            vec![TypeId::of::<A>(), TypeId::of::<B>()]
+        );
+    }
+
+    #[test]
+    fn entry_component_type_ids_after_remove() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        entry.remove::<A, _>();
+
+        assert_eq!(
+            entry.component_type_ids().collect::<Vec<_>>(),
+            vec![TypeId::of::<B>()]
+        );
     }
 
     #[test]
-    fn insert_different_entity_types() {
+    fn entry_component_type_ids_empty() {
         let mut world = World::<Registry>::new();
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        let entity_identifier = world.insert(entity!());
+
+        let entry = assert_some!(world.entry(entity_identifier));
+
+        assert_eq!(entry.component_type_ids().count(), 0);
     }
 
     #[test]
-    fn extend() {
+    fn spawn() {
         let mut world = World::<Registry>::new();
 
-        world.extend(entities!((A(42), B('f')); 100));
+        let entity_identifier = world.spawn(entity!(A(42))).identifier();
+
+        assert!(world.contains(entity_identifier));
+        assert_eq!(world.len(), 1);
+        let entity = assert_some!(world.entity_mut(entity_identifier));
+        assert_some_eq!(entity.get::<A, _>(), &A(42));
+        assert_none!(entity.get::<B, _>());
     }
 
     #[test]
-    fn extend_multiple_times() {
+    fn spawn_chained() {
         let mut world = World::<Registry>::new();
 
-        world.extend(entities!((A(42), B('f')); 100));
-        world.extend(entities!((A(1), B('c')); 50));
+        let entity_identifier = world
+            .spawn(entity!(A(1)))
+            .add(B('a'))
+            .add(A(2))
+            .remove::<A, _>()
+            .identifier();
+
+        let mut result = world
+            .query(Query::<Views!(entity::Identifier, Option<&A>, &B)>::new())
+            .iter
+            .collect::<Vec<_>>();
+        assert_eq!(result.len(), 1);
+        let result!(identifier, a, b) = result.pop().unwrap();
+        assert_eq!(identifier, entity_identifier);
+        assert_none!(a);
+        assert_eq!(b, &B('a'));
     }
 
     #[test]
-    fn extend_different_entity_types() {
+    fn entity_mut_get_mut() {
         let mut world = World::<Registry>::new();
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
 
-        world.extend(entities!((A(1), B('a')); 100));
-        world.extend(entities!((A(2)); 200));
-        world.extend(entities!((B('b')); 300));
-        world.extend(entities!((); 400));
+        let mut entity = assert_some!(world.entity_mut(entity_identifier));
+        assert_some!(entity.get_mut::<A, _>()).0 = 2;
+        assert_some_eq!(entity.get::<A, _>(), &A(2));
+        assert!(entity.contains::<B, _>());
     }
 
     #[test]
-    fn extend_from_empty_twice() {
+    fn entity_mut_not_present() {
         let mut world = World::<Registry>::new();
+        let entity_identifier = world.insert(entity!(A(1)));
+        world.remove(entity_identifier);
 
-        world.extend(entities!((A(42), B('f')); 100));
-        world.clear();
-        world.extend(entities!((A(1), B('c')); 50));
+        assert_none!(world.entity_mut(entity_identifier));
     }
 
     #[test]
-    fn query() {
+    fn query_many() {
         let mut world = World::<Registry>::new();
 
-        world.insert(entity!(B('a'), A(1)));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(A(2)));
+        world.insert(entity!(A(3)));
+
+        let [result!(first_a), result!(second_a)] =
+            assert_some!(world.query_many(Query::<Views!(&mut A)>::new(), [first, second]));
+        core::mem::swap(first_a, second_a);
+
+        let [result!(first_a), result!(second_a)] =
+            assert_some!(world.query_many(Query::<Views!(&A)>::new(), [first, second]));
+        assert_eq!(first_a, &A(2));
+        assert_eq!(second_a, &A(1));
+    }
+
+    #[test]
+    fn query_many_reversed() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1)));
+        let second = world.insert(entity!(A(2)));
+
+        let [result!(second_a), result!(first_a)] =
+            assert_some!(world.query_many(Query::<Views!(&A)>::new(), [second, first]));
+        assert_eq!(first_a, &A(1));
+        assert_eq!(second_a, &A(2));
+    }
+
+    #[test]
+    fn query_many_empty() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+
+        assert_some!(world.query_many(Query::<Views!(&mut A)>::new(), []));
+    }
+
+    #[test]
+    fn query_many_duplicate() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1)));
+        let second = world.insert(entity!(A(2)));
+
+        assert_none!(world.query_many(Query::<Views!(&mut A)>::new(), [first, second, first]));
+    }
+
+    #[test]
+    fn query_many_removed_entity() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1)));
+        let second = world.insert(entity!(A(2)));
+        world.remove(second);
+
+        assert_none!(world.query_many(Query::<Views!(&mut A)>::new(), [first, second]));
+    }
+
+    #[test]
+    fn query_many_filtered_out() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(A(2)));
+
+        assert_none!(world.query_many(Query::<Views!(&mut A, &B)>::new(), [first, second]));
+        assert_none!(world.query_many(
+            Query::<Views!(&mut A), filter::Not<filter::Has<B>>>::new(),
+            [first, second]
+        ));
+    }
+
+    #[test]
+    fn add_component_to_all() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1)));
+        let second = world.insert(entity!(A(2)));
+        let third = world.insert(entity!(A(3), B('b')));
+        let fourth = world.insert(entity!(B('c')));
+
+        world.add_component_to_all(Query::<Views!(&A)>::new(), B('a'));
+
+        let mut result = world
+            .query(Query::<Views!(entity::Identifier, &B)>::new())
+            .iter
+            .map(|result!(identifier, b)| (identifier, b.0))
+            .collect::<Vec<_>>();
+        result.sort_by_key(|&(identifier, _)| {
+            [first, second, third, fourth]
+                .iter()
+                .position(|&other| other == identifier)
+        });
+        assert_eq!(
+            result,
+            vec![(first, 'a'), (second, 'a'), (third, 'a'), (fourth, 'c')]
+        );
+        assert_eq!(world.len(), 4);
+    }
+
+    #[test]
+    fn add_component_to_all_filtered() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2), B('b')));
         world.insert(entity!());
 
+        world.add_component_to_all(
+            Query::<Views!(), filter::Not<filter::Has<A>>>::new(),
+            B('a'),
+        );
+
         let mut result = world
-            .query(Query::<Views!(&B, &A)>::new())
+            .query(Query::<Views!(&B, Option<&A>)>::new())
             .iter
-            .map(|result!(b, a)| (b.0, a.0))
+            .map(|result!(b, a)| (b.0, a.map(|a| a.0)))
             .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![('a', 1)]);
+        result.sort_unstable();
+        assert_eq!(result, vec![('a', None), ('b', Some(2))]);
     }
 
     #[test]
-    fn query_refs() {
+    fn add_component_to_all_into_existing_archetype() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1), B('b')));
+        let second = world.insert(entity!(A(2)));
+        let third = world.insert(entity!(A(3)));
+
+        world.add_component_to_all(
+            Query::<Views!(), filter::Not<filter::Has<B>>>::new(),
+            B('a'),
+        );
+
+        assert_eq!(world.len_of::<Entity!(A, B), _>(), 3);
+        assert_eq!(world.len_of::<Entity!(A), _>(), 0);
+        for (identifier, expected) in [(first, (1, 'b')), (second, (2, 'a')), (third, (3, 'a'))] {
+            let mut entry = assert_some!(world.entry(identifier));
+            let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+            assert_eq!((a.0, b.0), expected);
+        }
+    }
+
+    #[test]
+    fn add_component_to_all_then_remove() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1)));
+        let second = world.insert(entity!(A(2)));
+
+        world.add_component_to_all(Query::<Views!(&A)>::new(), B('a'));
+        world.remove(first);
+
+        assert!(!world.contains(first));
+        let mut entry = assert_some!(world.entry(second));
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!((a.0, b.0), (2, 'a'));
+    }
+
+    #[test]
+    fn add_component_to_all_empty() {
+        let mut world = World::<Registry>::new();
+
+        world.add_component_to_all(Query::<Views!()>::new(), B('a'));
+
+        assert!(world.is_empty());
+        assert_eq!(world.archetypes.iter().count(), 0);
+    }
+
+    #[test]
+    fn add_component_to_all_observed() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2), B('b')));
+        let count = Arc::new(AtomicU32::new(0));
+        let count_clone = Arc::clone(&count);
+        world.on_add(move |_, _: &B| {
+            count_clone.fetch_add(1, Ordering::Relaxed);
+        });
+
+        world.add_component_to_all(Query::<Views!(&A)>::new(), B('a'));
+
+        // Only the entity that did not already contain `B` had it added.
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn remove_component_from_all() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(A(2), B('b')));
+        let third = world.insert(entity!(B('c')));
+        let fourth = world.insert(entity!(A(4)));
+
+        world.remove_component_from_all::<B, filter::None, _, _>();
+
+        assert_eq!(world.query(Query::<Views!(&B)>::new()).iter.count(), 0);
+        assert_eq!(world.len_of::<Entity!(A), _>(), 3);
+        assert_eq!(world.len_of::<Entity!(), _>(), 1);
+        assert_eq!(world.len(), 4);
+        for (identifier, expected) in [
+            (first, Some(1)),
+            (second, Some(2)),
+            (third, None),
+            (fourth, Some(4)),
+        ] {
+            let mut entry = assert_some!(world.entry(identifier));
+            let result!(a) = assert_some!(entry.query(Query::<Views!(Option<&A>)>::new()));
+            assert_eq!(a.map(|a| a.0), expected);
+        }
+    }
+
+    #[test]
+    fn remove_component_from_all_filtered() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
         world.insert(entity!(B('b')));
-        world.insert(entity!());
+
+        world.remove_component_from_all::<B, filter::Has<A>, _, _>();
 
         let mut result = world
-            .query(Query::<Views!(&A)>::new())
+            .query(Query::<Views!(Option<&A>, Option<&B>)>::new())
             .iter
-            .map(|result!(a)| a.0)
+            .map(|result!(a, b)| (a.map(|a| a.0), b.map(|b| b.0)))
             .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![1, 2]);
+        result.sort_unstable();
+        assert_eq!(result, vec![(None, Some('b')), (Some(1), None)]);
     }
 
     #[test]
-    fn query_mut_refs() {
+    fn remove_component_from_all_then_remove() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(A(2), B('b')));
+
+        world.remove_component_from_all::<B, filter::None, _, _>();
+        world.remove(first);
+
+        assert!(!world.contains(first));
+        let mut entry = assert_some!(world.entry(second));
+        let result!(a) = assert_some!(entry.query(Query::<Views!(&A)>::new()));
+        assert_eq!(a.0, 2);
+    }
+
+    #[test]
+    fn remove_component_from_all_drops() {
+        struct Counted(Arc<AtomicU32>);
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        let count = Arc::new(AtomicU32::new(0));
+        let mut world = World::<Registry!(A, Counted)>::new();
+        world.insert(entity!(A(1), Counted(Arc::clone(&count))));
+        world.insert(entity!(A(2), Counted(Arc::clone(&count))));
+
+        world.remove_component_from_all::<Counted, filter::None, _, _>();
+
+        assert_eq!(count.load(Ordering::Relaxed), 2);
+        assert_eq!(world.len_of::<Entity!(A), _>(), 2);
+    }
+
+    #[test]
+    fn remove_component_from_all_observed() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        let count = Arc::new(AtomicU32::new(0));
+        let count_clone = Arc::clone(&count);
+        world.on_remove(move |_, _: &B| {
+            count_clone.fetch_add(1, Ordering::Relaxed);
+        });
+
+        world.remove_component_from_all::<B, filter::None, _, _>();
+
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn remove_component_from_all_auto_compact() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
+        world.insert(entity!(A(1), B('a')));
+
+        world.remove_component_from_all::<B, filter::None, _, _>();
+
+        assert_eq!(world.memory_usage_per_archetype().count(), 1);
+        assert_eq!(world.len_of::<Entity!(A), _>(), 1);
+    }
+
+    #[test]
+    fn fill_component() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(A(2), B('b')));
+        let third = world.insert(entity!(A(3)));
+
+        world.fill_component::<_, Entity!(B, A), _, _>(A(0));
+
+        for (identifier, expected) in [(first, 0), (second, 0), (third, 3)] {
+            let mut entry = assert_some!(world.entry(identifier));
+            let result!(a) = assert_some!(entry.query(Query::<Views!(&A)>::new()));
+            assert_eq!(a.0, expected);
+        }
+    }
+
+    #[test]
+    fn fill_component_not_contained() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(B('a')));
+
+        world.fill_component::<_, Entity!(B), _, _>(A(0));
+
+        assert_eq!(world.query(Query::<Views!(&A)>::new()).iter.count(), 0);
+        assert_eq!(world.len(), 1);
+    }
+
+    #[test]
+    fn fill_component_no_archetype() {
+        let mut world = World::<Registry>::new();
+
+        world.fill_component::<_, Entity!(A), _, _>(A(0));
+
+        assert!(world.is_empty());
+        assert_eq!(world.archetypes.iter().count(), 0);
+    }
+
+    #[test]
+    fn fill_component_filtered() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
         world.insert(entity!(B('b')));
-        world.insert(entity!());
+
+        world.fill_component_filtered::<_, filter::Has<A>, _, _>(B('z'));
 
         let mut result = world
-            .query(Query::<Views!(&mut B)>::new())
+            .query(Query::<Views!(Option<&A>, Option<&B>)>::new())
             .iter
-            .map(|result!(b)| b.0)
+            .map(|result!(a, b)| (a.map(|a| a.0), b.map(|b| b.0)))
             .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec!['a', 'b']);
+        result.sort_unstable();
+        assert_eq!(
+            result,
+            vec![(None, Some('b')), (Some(1), Some('z')), (Some(2), None)]
+        );
+    }
+
+    #[test]
+    fn swap_component_same_archetype() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(A(2), B('b')));
+
+        assert!(world.swap_component::<A, _>(first, second));
+
+        let mut entry = assert_some!(world.entry(first));
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!(a, &A(2));
+        assert_eq!(b, &B('a'));
+        let mut entry = assert_some!(world.entry(second));
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!(a, &A(1));
+        assert_eq!(b, &B('b'));
+    }
+
+    #[test]
+    fn swap_component_different_archetypes() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(A(2)));
+
+        assert!(world.swap_component::<A, _>(first, second));
+
+        let mut entry = assert_some!(world.entry(first));
+        let result!(a) = assert_some!(entry.query(Query::<Views!(&A)>::new()));
+        assert_eq!(a, &A(2));
+        let mut entry = assert_some!(world.entry(second));
+        let result!(a) = assert_some!(entry.query(Query::<Views!(&A)>::new()));
+        assert_eq!(a, &A(1));
+    }
+
+    #[test]
+    fn swap_component_same_entity() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1), B('a')));
+
+        assert!(world.swap_component::<A, _>(first, first));
+
+        let mut entry = assert_some!(world.entry(first));
+        let result!(a) = assert_some!(entry.query(Query::<Views!(&A)>::new()));
+        assert_eq!(a, &A(1));
+    }
+
+    #[test]
+    fn swap_component_missing_component() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(B('b')));
+
+        assert!(!world.swap_component::<A, _>(first, second));
+        assert!(!world.swap_component::<A, _>(second, first));
+
+        let mut entry = assert_some!(world.entry(first));
+        let result!(a) = assert_some!(entry.query(Query::<Views!(&A)>::new()));
+        assert_eq!(a, &A(1));
+    }
+
+    #[test]
+    fn swap_component_removed_entity() {
+        let mut world = World::<Registry>::new();
+
+        let first = world.insert(entity!(A(1)));
+        let second = world.insert(entity!(A(2)));
+        world.remove(second);
+
+        assert!(!world.swap_component::<A, _>(first, second));
+
+        let mut entry = assert_some!(world.entry(first));
+        let result!(a) = assert_some!(entry.query(Query::<Views!(&A)>::new()));
+        assert_eq!(a, &A(1));
+    }
+
+    #[test]
+    fn no_entry_found() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
+
+        world.remove(entity_identifier);
+
+        assert_none!(world.entry(entity_identifier));
     }
 
     #[test]
-    fn query_option_refs() {
+    fn entry_multiple_shape_changes() {
         let mut world = World::<Registry>::new();
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        let mut entry = assert_some!(world.entry(entity_identifier));
 
-        let mut result = world
-            .query(Query::<Views!(Option<&A>)>::new())
-            .iter
-            .map(|result!(a)| a.map(|a| a.0))
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![None, None, Some(1), Some(2)]);
+        entry.remove::<B, _>();
+        entry.remove::<A, _>();
+
+        assert_none!(
+            entry.query(Query::<Views!(), filter::Or<filter::Has<A>, filter::Has<B>>>::new())
+        );
     }
 
     #[test]
-    fn query_option_mut_refs() {
+    fn remove() {
         let mut world = World::<Registry>::new();
 
-        world.insert(entity!(A(1), B('a')));
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
         world.insert(entity!(B('b')));
         world.insert(entity!());
 
+        world.remove(entity_identifier);
+
         let mut result = world
-            .query(Query::<Views!(Option<&mut B>)>::new())
+            .query(Query::<Views!(&A)>::new())
             .iter
-            .map(|result!(b)| b.map(|b| b.0))
+            .map(|result!(a)| a.0)
             .collect::<Vec<_>>();
         result.sort();
-        assert_eq!(result, vec![None, None, Some('a'), Some('b')]);
+        assert_eq!(result, vec![2]);
+        assert_eq!(world.len(), 3);
     }
 
     #[test]
-    fn query_entity_identifiers() {
+    fn remove_already_removed() {
         let mut world = World::<Registry>::new();
 
         let entity_identifier = world.insert(entity!(A(1), B('a')));
@@ -1302,19 +11591,15 @@ mod tests {
         world.insert(entity!(B('b')));
         world.insert(entity!());
 
-        let result = world
-            .query(Query::<
-                Views!(entity::Identifier),
-                filter::And<filter::Has<A>, filter::Has<B>>,
-            >::new())
-            .iter
-            .map(|result!(identifier)| identifier)
-            .collect::<Vec<_>>();
-        assert_eq!(result, vec![entity_identifier]);
+        world.remove(entity_identifier);
+        assert_eq!(world.len(), 3);
+        world.remove(entity_identifier);
+
+        assert_eq!(world.len(), 3);
     }
 
     #[test]
-    fn query_has_filter() {
+    fn clear() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
@@ -1322,53 +11607,44 @@ mod tests {
         world.insert(entity!(B('b')));
         world.insert(entity!());
 
-        let result = world
-            .query(Query::<Views!(&A), filter::Has<B>>::new())
+        world.clear();
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
             .iter
             .map(|result!(a)| a.0)
             .collect::<Vec<_>>();
-        assert_eq!(result, vec![1]);
+        result.sort();
+        assert_eq!(result, Vec::new());
+        assert_eq!(world.len(), 0);
     }
 
     #[test]
-    fn query_not_filter() {
+    fn drain() {
         let mut world = World::<Registry>::new();
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
 
-        let result = world
-            .query(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        assert_eq!(result, vec![2]);
+        let mut result = world.drain::<Entity!(A, B), _>().collect::<Vec<_>>();
+        result.sort_by_key(|(a, _)| a.0);
+        assert_eq!(result, vec![entity!(A(1), B('a')), entity!(A(2), B('b'))]);
+        assert_eq!(world.len(), 0);
+        assert!(!world.contains(entity_identifier));
     }
 
     #[test]
-    fn query_and_filter() {
+    fn drain_reordered() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
 
-        let result = world
-            .query(Query::<
-                Views!(&A),
-                filter::And<filter::Has<A>, filter::Has<B>>,
-            >::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        assert_eq!(result, vec![1]);
+        let result = world.drain::<Entity!(B, A), _>().collect::<Vec<_>>();
+        assert_eq!(result, vec![entity!(B('a'), A(1))]);
     }
 
     #[test]
-    fn query_or_filter() {
+    fn drain_subset_of_components() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
@@ -1377,422 +11653,401 @@ mod tests {
         world.insert(entity!());
 
         let mut result = world
-            .query(Query::<
-                Views!(&A),
-                filter::Or<filter::Has<A>, filter::Has<B>>,
-            >::new())
-            .iter
-            .map(|result!(a)| a.0)
+            .drain::<Entity!(A), _>()
+            .map(|(a, _)| a.0)
             .collect::<Vec<_>>();
         result.sort();
         assert_eq!(result, vec![1, 2]);
+        assert_eq!(world.len(), 0);
+        assert_eq!(world.query(Query::<Views!()>::new()).iter.count(), 0);
     }
 
     #[test]
-    fn query_views_different_order() {
+    fn drain_not_consumed() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
         world.insert(entity!(B('b')));
-        world.insert(entity!());
 
-        let mut result = world
-            .query(Query::<Views!(&B, &A)>::new())
-            .iter
-            .map(|result!(b, a)| (a.0, b.0))
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![(1, 'a')]);
+        let mut drain = world.drain::<Entity!(A), _>();
+        assert_some!(drain.next());
+        drop(drain);
+
+        assert_eq!(world.len(), 0);
+        assert_eq!(world.query(Query::<Views!()>::new()).iter.count(), 0);
     }
 
     #[test]
-    fn query_resources() {
-        let mut world = World::<Registry!(), _>::with_resources(resources!(A(42), B('a')));
-
-        let result!(a, b) = world
-            .query(Query::<Views!(), filter::None, Views!(&A, &mut B)>::new())
-            .resources;
-        b.0 = 'b';
+    fn drain_then_insert() {
+        let mut world = World::<Registry>::new();
 
-        assert_eq!(a, &A(42));
-        assert_eq!(b, &mut B('b'));
-    }
+        world.insert(entity!(A(1), B('a')));
+        world.drain::<Entity!(), _>().for_each(drop);
 
-    #[test]
-    fn query_resources_reshaped() {
-        let mut world = World::<Registry!(), _>::with_resources(resources!(A(42), B('a')));
+        let entity_identifier = world.insert(entity!(A(2), B('b')));
 
-        let result!(b, a) = world
-            .query(Query::<Views!(), filter::None, Views!(&B, &mut A)>::new())
-            .resources;
-        a.0 = 100;
+        assert_eq!(world.len(), 1);
+        let result = world
+            .query(Query::<Views!(&A, &B, entity::Identifier)>::new())
+            .iter
+            .map(|result!(a, b, identifier)| (a.0, b.0, identifier))
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![(2, 'b', entity_identifier)]);
+    }
 
-        assert_eq!(a, &A(100));
-        assert_eq!(b, &mut B('a'));
+    /// Registers observers summing the values of added and removed `A` components.
+    fn observe_a(world: &mut World<Registry>) -> (Arc<AtomicU32>, Arc<AtomicU32>) {
+        let added = Arc::new(AtomicU32::new(0));
+        let removed = Arc::new(AtomicU32::new(0));
+        let added_clone = Arc::clone(&added);
+        world.on_add(move |_, a: &A| {
+            added_clone.fetch_add(a.0, Ordering::Relaxed);
+        });
+        let removed_clone = Arc::clone(&removed);
+        world.on_remove(move |_, a: &A| {
+            removed_clone.fetch_add(a.0, Ordering::Relaxed);
+        });
+        (added, removed)
     }
 
     #[test]
-    fn query_empty() {
+    fn on_add_insert() {
         let mut world = World::<Registry>::new();
+        let (added, removed) = observe_a(&mut world);
 
-        world.insert(entity!(B('a'), A(1)));
+        world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
         world.insert(entity!(B('b')));
-        world.insert(entity!());
 
-        let count = world.query(Query::<Views!()>::new()).iter.count();
-
-        assert_eq!(count, 4);
+        assert_eq!(added.load(Ordering::Relaxed), 3);
+        assert_eq!(removed.load(Ordering::Relaxed), 0);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_refs() {
+    fn on_add_insert_at() {
         let mut world = World::<Registry>::new();
+        let (added, _) = observe_a(&mut world);
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        assert_ok!(world.insert_at(entity::Identifier::new(1, 0), entity!(A(1), B('a'))));
+        assert_err_eq!(
+            world.insert_at(entity::Identifier::new(1, 0), entity!(A(2))),
+            SpawnError::Occupied
+        );
 
-        let mut result = world
-            .par_query(Query::<Views!(&A)>::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![1, 2]);
+        assert_eq!(added.load(Ordering::Relaxed), 1);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_mut_refs() {
+    fn on_add_extend() {
         let mut world = World::<Registry>::new();
+        let (added, _) = observe_a(&mut world);
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert(entity!(A(100)));
+        world.extend(entities!((A(1), B('a')); 5));
 
-        let mut result = world
-            .par_query(Query::<Views!(&mut B)>::new())
-            .iter
-            .map(|result!(b)| b.0)
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec!['a', 'b']);
+        assert_eq!(added.load(Ordering::Relaxed), 105);
     }
 
     #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_option_refs() {
+    fn on_add_par_extend() {
         let mut world = World::<Registry>::new();
+        let (added, _) = observe_a(&mut world);
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert(entity!(A(100)));
+        world.par_extend(entities!((A(1), B('a')); 5));
 
-        let mut result = world
-            .par_query(Query::<Views!(Option<&A>)>::new())
-            .iter
-            .map(|result!(a)| a.map(|a| a.0))
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![None, None, Some(1), Some(2)]);
+        assert_eq!(added.load(Ordering::Relaxed), 105);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_option_mut_refs() {
+    fn on_add_merge() {
         let mut world = World::<Registry>::new();
+        let (added, _) = observe_a(&mut world);
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        let mut other = World::<Registry>::new();
+        other.insert(entity!(A(100)));
+        other.extend(entities!((A(1), B('a')); 5));
+        world.merge(other);
 
-        let mut result = world
-            .par_query(Query::<Views!(Option<&mut B>)>::new())
-            .iter
-            .map(|result!(b)| b.map(|b| b.0))
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![None, None, Some('a'), Some('b')]);
+        assert_eq!(added.load(Ordering::Relaxed), 105);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_entity_identifiers() {
+    fn on_add_insert_batch_mixed() {
         let mut world = World::<Registry>::new();
+        let (added, _) = observe_a(&mut world);
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert_batch_mixed(vec![
+            entity!(Some(A(1)), Some(B('a'))),
+            entity!(Some(A(2)), None),
+            entity!(None, Some(B('b'))),
+        ]);
 
-        let result = world
-            .par_query(Query::<
-                Views!(entity::Identifier),
-                filter::And<filter::Has<A>, filter::Has<B>>,
-            >::new())
-            .iter
-            .map(|result!(identifier)| identifier)
-            .collect::<Vec<_>>();
-        assert_eq!(result, vec![entity_identifier]);
+        assert_eq!(added.load(Ordering::Relaxed), 3);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_has_filter() {
+    fn on_add_entry_add() {
         let mut world = World::<Registry>::new();
+        let entity_identifier = world.insert(entity!(B('a')));
+        let (added, _) = observe_a(&mut world);
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.entry(entity_identifier).unwrap().add(A(1));
+        // Replacing an existing component is not an addition.
+        world.entry(entity_identifier).unwrap().add(A(2));
 
-        let result = world
-            .par_query(Query::<Views!(&A), filter::Has<B>>::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        assert_eq!(result, vec![1]);
+        assert_eq!(added.load(Ordering::Relaxed), 1);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_not_filter() {
+    fn on_add_on_remove_entry_modify() {
         let mut world = World::<Registry>::new();
-
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
-
-        let result = world
-            .par_query(Query::<Views!(&A), filter::Not<filter::Has<B>>>::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        assert_eq!(result, vec![2]);
+        let first = world.insert(entity!(B('a')));
+        let second = world.insert(entity!(A(10), B('b')));
+        let (added, removed) = observe_a(&mut world);
+
+        world.entry(first).unwrap().modify(|modifier| {
+            modifier.add(A(1));
+            modifier.remove::<B, _>();
+        });
+        world.entry(second).unwrap().modify(|modifier| {
+            // Removing and re-adding an existing component is a replacement.
+            modifier.remove::<A, _>();
+            modifier.add(A(2));
+            modifier.remove::<B, _>();
+        });
+
+        assert_eq!(added.load(Ordering::Relaxed), 1);
+        assert_eq!(removed.load(Ordering::Relaxed), 0);
+
+        world.entry(second).unwrap().modify(|modifier| {
+            modifier.remove::<A, _>();
+        });
+
+        assert_eq!(removed.load(Ordering::Relaxed), 2);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_and_filter() {
+    fn on_add_on_remove_apply_deferred() {
         let mut world = World::<Registry>::new();
+        let first = world.insert(entity!(B('a')));
+        let second = world.insert(entity!(A(10)));
+        let (added, removed) = observe_a(&mut world);
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        let mut entries = world.query(Query::<Views!()>::new()).entries;
+        assert_some!(entries.entry(first)).add(A(1));
+        assert_some!(entries.entry(second)).remove::<A, _>();
+        let deferred = entries.into_deferred();
 
-        let result = world
-            .par_query(Query::<
-                Views!(&A),
-                filter::And<filter::Has<A>, filter::Has<B>>,
-            >::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        assert_eq!(result, vec![1]);
+        assert_eq!(added.load(Ordering::Relaxed), 0);
+        assert_eq!(removed.load(Ordering::Relaxed), 0);
+
+        world.apply_deferred(deferred);
+
+        assert_eq!(added.load(Ordering::Relaxed), 1);
+        assert_eq!(removed.load(Ordering::Relaxed), 10);
+    }
+
+    #[test]
+    fn on_add_multiple_callbacks() {
+        let mut world = World::<Registry>::new();
+        let (first, _) = observe_a(&mut world);
+        let (second, _) = observe_a(&mut world);
+
+        world.insert(entity!(A(1)));
+
+        assert_eq!(first.load(Ordering::Relaxed), 1);
+        assert_eq!(second.load(Ordering::Relaxed), 1);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_or_filter() {
+    fn on_add_other_component() {
         let mut world = World::<Registry>::new();
+        let (added, _) = observe_a(&mut world);
+        let count = Arc::new(AtomicU32::new(0));
+        let count_clone = Arc::clone(&count);
+        world.on_add(move |_, _: &B| {
+            count_clone.fetch_add(1, Ordering::Relaxed);
+        });
 
         world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
         world.insert(entity!(B('b')));
-        world.insert(entity!());
 
-        let mut result = world
-            .par_query(Query::<
-                Views!(&A),
-                filter::Or<filter::Has<A>, filter::Has<B>>,
-            >::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![1, 2]);
+        assert_eq!(added.load(Ordering::Relaxed), 1);
+        assert_eq!(count.load(Ordering::Relaxed), 2);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_resources() {
-        let mut world = World::<Registry!(), _>::with_resources(resources!(A(42), B('a')));
+    fn on_remove_remove() {
+        let mut world = World::<Registry>::new();
+        let (_, removed) = observe_a(&mut world);
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
 
-        let result!(a, b) = world
-            .par_query(Query::<Views!(), filter::None, Views!(&A, &mut B)>::new())
-            .resources;
-        b.0 = 'b';
+        world.remove(entity_identifier);
+        // Removing an already-removed entity does nothing.
+        world.remove(entity_identifier);
 
-        assert_eq!(a, &A(42));
-        assert_eq!(b, &mut B('b'));
+        assert_eq!(removed.load(Ordering::Relaxed), 1);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_resources_reshaped() {
-        let mut world = World::<Registry!(), _>::with_resources(resources!(A(42), B('a')));
+    fn on_remove_entry_remove() {
+        let mut world = World::<Registry>::new();
+        let (_, removed) = observe_a(&mut world);
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
 
-        let result!(b, a) = world
-            .par_query(Query::<Views!(), filter::None, Views!(&B, &mut A)>::new())
-            .resources;
-        a.0 = 100;
+        world.entry(entity_identifier).unwrap().remove::<B, _>();
+        world.entry(entity_identifier).unwrap().remove::<A, _>();
+        // Removing a component that is not present does nothing.
+        world.entry(entity_identifier).unwrap().remove::<A, _>();
 
-        assert_eq!(a, &A(100));
-        assert_eq!(b, &mut B('a'));
+        assert_eq!(removed.load(Ordering::Relaxed), 1);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_query_empty() {
+    fn on_remove_clear() {
         let mut world = World::<Registry>::new();
-
-        world.insert(entity!(B('a'), A(1)));
-        world.insert(entity!(A(2)));
+        let (_, removed) = observe_a(&mut world);
+        world.insert(entity!(A(1), B('a')));
+        world.extend(entities!((A(2)); 5));
         world.insert(entity!(B('b')));
-        world.insert(entity!());
 
-        let count = world.par_query(Query::<Views!()>::new()).iter.count();
+        world.clear();
 
-        assert_eq!(count, 4);
+        assert_eq!(removed.load(Ordering::Relaxed), 11);
     }
 
     #[test]
-    fn system_refs() {
-        struct TestSystem;
+    fn on_remove_drain() {
+        let mut world = World::<Registry>::new();
+        let (_, removed) = observe_a(&mut world);
+        world.insert(entity!(A(1), B('a')));
 
-        impl System for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        world.drain::<Entity!(A), _>().for_each(drop);
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec![1, 2]);
-            }
-        }
+        assert_eq!(removed.load(Ordering::Relaxed), 1);
+    }
 
+    #[test]
+    fn on_remove_drain_not_consumed() {
         let mut world = World::<Registry>::new();
-
+        let (_, removed) = observe_a(&mut world);
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert(entity!(A(4)));
 
-        world.run_system(&mut TestSystem);
+        let mut drain = world.drain::<Entity!(B), _>();
+        assert_some!(drain.next());
+        drop(drain);
+
+        assert_eq!(removed.load(Ordering::Relaxed), 7);
+    }
+
+    /// Registers a move callback recording the number of moves, along with the old and new
+    /// indices of the last move.
+    fn observe_moves(world: &mut World<Registry>) -> Arc<(AtomicU32, AtomicUsize, AtomicUsize)> {
+        let moves = Arc::new((AtomicU32::new(0), AtomicUsize::new(0), AtomicUsize::new(0)));
+        let moves_clone = Arc::clone(&moves);
+        world.on_move(move |_, old_index, new_index, _| {
+            moves_clone.0.fetch_add(1, Ordering::Relaxed);
+            moves_clone.1.store(old_index, Ordering::Relaxed);
+            moves_clone.2.store(new_index, Ordering::Relaxed);
+        });
+        moves
     }
 
     #[test]
-    fn system_mut_refs() {
-        struct TestSystem;
+    fn on_move_remove() {
+        let mut world = World::<Registry>::new();
+        let moves = observe_moves(&mut world);
+        let entity_identifier = world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(A(3)));
 
-        impl System for TestSystem {
-            type Views<'a> = Views!(&'a mut B);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        world.remove(entity_identifier);
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results.iter.map(|result!(b)| b.0).collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec!['a', 'b']);
-            }
-        }
+        assert_eq!(moves.0.load(Ordering::Relaxed), 1);
+        assert_eq!(moves.1.load(Ordering::Relaxed), 2);
+        assert_eq!(moves.2.load(Ordering::Relaxed), 0);
+    }
 
+    #[test]
+    fn on_move_remove_last_row() {
         let mut world = World::<Registry>::new();
+        let moves = observe_moves(&mut world);
+        world.insert(entity!(A(1)));
+        let entity_identifier = world.insert(entity!(A(2)));
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.remove(entity_identifier);
 
-        world.run_system(&mut TestSystem);
+        assert_eq!(moves.0.load(Ordering::Relaxed), 0);
     }
 
     #[test]
-    fn system_option_refs() {
-        struct TestSystem;
+    fn on_move_entry_add() {
+        let mut world = World::<Registry>::new();
+        let moves = observe_moves(&mut world);
+        let entity_identifier = world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
 
-        impl System for TestSystem {
-            type Views<'a> = Views!(Option<&'a A>);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        assert_some!(world.entry(entity_identifier)).add(B('a'));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results
-                    .iter
-                    .map(|result!(a)| a.map(|a| a.0))
-                    .collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec![None, None, Some(1), Some(2)]);
-            }
-        }
+        assert_eq!(moves.0.load(Ordering::Relaxed), 1);
+        assert_eq!(moves.1.load(Ordering::Relaxed), 1);
+        assert_eq!(moves.2.load(Ordering::Relaxed), 0);
+    }
 
+    #[test]
+    fn on_move_entry_remove() {
         let mut world = World::<Registry>::new();
-
+        let moves = observe_moves(&mut world);
         world.insert(entity!(A(1), B('a')));
+        let entity_identifier = world.insert(entity!(A(2), B('b')));
+        world.insert(entity!(A(3), B('c')));
+
+        assert_some!(world.entry(entity_identifier)).remove::<B, _>();
+
+        assert_eq!(moves.0.load(Ordering::Relaxed), 1);
+        assert_eq!(moves.1.load(Ordering::Relaxed), 2);
+        assert_eq!(moves.2.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn on_move_index_matches_view() {
+        let mut world = World::<Registry>::new();
+        let moved = Arc::new(AtomicUsize::new(usize::MAX));
+        let moved_clone = Arc::clone(&moved);
+        world.on_move(move |_, _, new_index, _| {
+            moved_clone.store(new_index, Ordering::Relaxed);
+        });
+        let entity_identifier = world.insert(entity!(A(1)));
         world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.remove(entity_identifier);
 
-        world.run_system(&mut TestSystem);
+        let result!(a, index) = assert_some!(world
+            .query(Query::<Views!(&A, view::Index)>::new())
+            .iter
+            .next());
+        assert_eq!(a, &A(2));
+        assert_eq!(index.0, moved.load(Ordering::Relaxed));
     }
 
     #[test]
-    fn system_option_mut_refs() {
-        struct TestSystem;
+    fn observers_not_cloned() {
+        let mut world = World::<Registry>::new();
+        let (added, _) = observe_a(&mut world);
 
-        impl System for TestSystem {
-            type Views<'a> = Views!(Option<&'a mut B>);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        let mut cloned_world = world.clone();
+        cloned_world.insert(entity!(A(1)));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results
-                    .iter
-                    .map(|result!(b)| b.map(|b| b.0))
-                    .collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec![None, None, Some('a'), Some('b')]);
-            }
-        }
+        assert_eq!(added.load(Ordering::Relaxed), 0);
+    }
 
+    #[test]
+    fn len() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
@@ -1800,1383 +12055,1430 @@ mod tests {
         world.insert(entity!(B('b')));
         world.insert(entity!());
 
-        world.run_system(&mut TestSystem);
+        assert_eq!(world.len(), 4);
     }
 
     #[test]
-    fn system_entity_identifier() {
-        struct TestSystem {
-            entity_identifier: entity::Identifier,
-        }
-
-        impl System for TestSystem {
-            type Views<'a> = Views!(entity::Identifier);
-            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
-
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let result = query_results
-                    .iter
-                    .map(|result!(entity_identifier)| entity_identifier)
-                    .collect::<Vec<_>>();
-                assert_eq!(result, vec![self.entity_identifier]);
-            }
-        }
-
+    fn len_of() {
         let mut world = World::<Registry>::new();
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
+        world.insert(entity!(A(3)));
         world.insert(entity!());
 
-        world.run_system(&mut TestSystem { entity_identifier });
+        assert_eq!(world.len_of::<Entity!(A), _>(), 2);
+        assert_eq!(world.len_of::<Entity!(A, B), _>(), 1);
+        assert_eq!(world.len_of::<Entity!(), _>(), 1);
     }
 
     #[test]
-    fn system_has_filter() {
-        struct TestSystem;
-
-        impl System for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::Has<B>;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
-
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                assert_eq!(result, vec![1]);
-            }
-        }
-
+    fn len_of_any_component_order() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert(entity!(B('b'), A(2)));
 
-        world.run_system(&mut TestSystem);
+        assert_eq!(world.len_of::<Entity!(B, A), _>(), 2);
+        assert_eq!(world.len_of::<Entity!(A, B), _>(), 2);
     }
 
     #[test]
-    fn system_not_filter() {
-        struct TestSystem;
-
-        impl System for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::Not<filter::Has<B>>;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+    fn len_of_no_archetype() {
+        let world = World::<Registry>::new();
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                assert_eq!(result, vec![2]);
-            }
-        }
+        assert_eq!(world.len_of::<Entity!(A), _>(), 0);
+    }
+
+    #[test]
+    fn len_of_after_remove() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert(entity!(A(1)));
+        world.remove(entity_identifier);
+
+        assert_eq!(world.len_of::<Entity!(A), _>(), 0);
+    }
 
+    #[test]
+    fn count_component() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
+        world.insert(entity!(A(3)));
         world.insert(entity!(B('b')));
         world.insert(entity!());
 
-        world.run_system(&mut TestSystem);
+        assert_eq!(world.count_component::<A, _>(), 3);
+        assert_eq!(world.count_component::<B, _>(), 2);
     }
 
     #[test]
-    fn system_and_filter() {
-        struct TestSystem;
-
-        impl System for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+    fn count_component_empty() {
+        let world = World::<Registry>::new();
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                assert_eq!(result, vec![1]);
-            }
-        }
+        assert_eq!(world.count_component::<A, _>(), 0);
+    }
 
+    #[test]
+    fn count_component_after_remove() {
         let mut world = World::<Registry>::new();
 
-        world.insert(entity!(A(1), B('a')));
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.remove(entity_identifier);
 
-        world.run_system(&mut TestSystem);
+        assert_eq!(world.count_component::<A, _>(), 1);
+        assert_eq!(world.count_component::<B, _>(), 0);
     }
 
     #[test]
-    fn system_or_filter() {
-        struct TestSystem;
+    fn snapshot() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
 
-        impl System for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::Or<filter::Has<A>, filter::Has<B>>;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        let snapshot = world.snapshot();
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec![1, 2]);
-            }
-        }
+        assert_eq!(world, snapshot);
+    }
 
+    #[test]
+    fn restore_from() {
         let mut world = World::<Registry>::new();
-
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
+        let snapshot = world.snapshot();
+
+        for result!(a) in world.query(Query::<Views!(&mut A)>::new()).iter {
+            a.0 += 10;
+        }
         world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.restore_from(&snapshot);
 
-        world.run_system(&mut TestSystem);
+        // The worlds are not considered equal, because `world` retains the archetype created
+        // after the snapshot was taken, but the entities stored are the same.
+        assert_eq!(world.len(), 2);
+        let mut result = world
+            .query(Query::<Views!(&A, Option<&B>)>::new())
+            .iter
+            .map(|result!(a, b)| (a.0, b.map(|b| b.0)))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![(1, Some('a')), (2, None)]);
     }
 
     #[test]
-    fn system_resource_views() {
-        struct Counter(usize);
+    fn restore_from_identifiers_stable() {
+        let mut world = World::<Registry>::new();
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        let snapshot = world.snapshot();
 
-        struct TestSystem;
+        world.remove(entity_identifier);
+        let new_entity_identifier = world.insert(entity!(A(2), B('b')));
+        world.restore_from(&snapshot);
 
-        impl System for TestSystem {
-            type Views<'a> = Views!(&'a A, &'a B);
-            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
-            type ResourceViews<'a> = Views!(&'a mut Counter);
-            type EntryViews<'a> = Views!();
+        assert!(world.contains(entity_identifier));
+        assert!(!world.contains(new_entity_identifier));
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        let result!(a, b) = assert_some!(entry.query(Query::<Views!(&A, &B)>::new()));
+        assert_eq!(a, &A(1));
+        assert_eq!(b, &B('a'));
+    }
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let result!(counter) = query_results.resources;
-                counter.0 = query_results.iter.count();
-            }
-        }
+    #[test]
+    fn restore_from_keeps_observers() {
+        let mut world = World::<Registry>::new();
+        let snapshot = world.snapshot();
+        let count = Arc::new(AtomicU32::new(0));
+        let observer_count = count.clone();
+        world.on_add::<A, _, _>(move |_, _| {
+            observer_count.fetch_add(1, Ordering::Relaxed);
+        });
+
+        world.restore_from(&snapshot);
+        world.insert(entity!(A(1)));
 
-        let mut world = World::<Registry, _>::with_resources(resources!(Counter(0)));
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn clone_reordered() {
+        let mut world = World::<Registry>::new();
+        let entity_identifier_a = world.insert(entity!(A(1), B('a')));
+        let entity_identifier_b = world.insert(entity!(A(2)));
+        let entity_identifier_c = world.insert(entity!(B('c')));
+
+        let mut reordered = world.clone_reordered::<Registry!(B, A), _, _>();
+
+        assert_eq!(reordered.len(), 3);
+        assert_eq!(
+            assert_some!(reordered.entry(entity_identifier_a))
+                .query(Query::<Views!(Option<&A>, Option<&B>)>::new())
+                .map(|result!(a, b)| (a.copied(), b.cloned())),
+            Some((Some(A(1)), Some(B('a'))))
+        );
+        assert_eq!(
+            assert_some!(reordered.entry(entity_identifier_b))
+                .query(Query::<Views!(Option<&A>, Option<&B>)>::new())
+                .map(|result!(a, b)| (a.copied(), b.cloned())),
+            Some((Some(A(2)), None))
+        );
+        assert_eq!(
+            assert_some!(reordered.entry(entity_identifier_c))
+                .query(Query::<Views!(Option<&A>, Option<&B>)>::new())
+                .map(|result!(a, b)| (a.copied(), b.cloned())),
+            Some((None, Some(B('c'))))
+        );
+    }
 
+    #[test]
+    fn clone_reordered_leaves_original() {
+        let mut world = World::<Registry>::new();
         world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        let original = world.snapshot();
 
-        world.run_system(&mut TestSystem);
+        let mut reordered = world.clone_reordered::<Registry!(B, A), _, _>();
+        for result!(a) in reordered.query(Query::<Views!(&mut A)>::new()).iter {
+            a.0 += 1;
+        }
 
-        assert_eq!(world.get::<Counter, _>().0, 1);
+        assert_eq!(world, original);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_system_refs() {
-        struct TestSystem;
+    fn clone_reordered_keeps_allocations() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1)));
+        let removed_entity_identifier = world.insert(entity!(A(2)));
+        world.remove(removed_entity_identifier);
 
-        impl ParSystem for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        let mut reordered = world.clone_reordered::<Registry!(B, A), _, _>();
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec![1, 2]);
-            }
-        }
+        assert!(!reordered.contains(removed_entity_identifier));
+        assert_eq!(
+            reordered.insert(entity!(B('a'))),
+            world.insert(entity!(B('a')))
+        );
+    }
 
-        let mut world = World::<Registry>::new();
+    #[test]
+    fn clone_reordered_keeps_resources() {
+        let world = World::<Registry, _>::with_resources(resources!(A(1)));
+
+        let reordered = world.clone_reordered::<Registry!(B, A), _, _>();
+
+        assert_eq!(reordered.get::<A, _>(), &A(1));
+    }
 
+    #[test]
+    fn diff_empty() {
+        let mut world = World::<Registry>::new();
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
 
-        world.run_par_system(&mut TestSystem);
+        let diff = world.diff(&world.snapshot());
+
+        assert!(diff.is_empty());
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_system_mut_refs() {
-        struct TestSystem;
+    fn diff_changed_components() {
+        let mut world = World::<Registry>::new();
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(A(2)));
+        let third = world.insert(entity!(B('c')));
+        let previous = world.snapshot();
+
+        assert_some!(world.entry(first)).add(A(10));
+        assert_some!(world.entry(second)).add(B('b'));
+        assert_some!(world.entry(third)).remove::<B, _>();
+
+        let diff = world.diff(&previous);
+
+        assert!(diff.removed().is_empty());
+        let mut changed = diff.changed().collect::<Vec<_>>();
+        changed.sort_by_key(|entity_identifier| entity_identifier.index);
+        assert_eq!(changed, vec![first, second, third]);
+        assert_some_eq!(diff.get::<A, _>(first), &ComponentChange::Changed(A(10)));
+        assert_some_eq!(diff.get::<B, _>(first), &ComponentChange::Unchanged);
+        assert_some_eq!(diff.get::<A, _>(second), &ComponentChange::Unchanged);
+        assert_some_eq!(diff.get::<B, _>(second), &ComponentChange::Added(B('b')));
+        assert_some_eq!(diff.get::<A, _>(third), &ComponentChange::Unchanged);
+        assert_some_eq!(diff.get::<B, _>(third), &ComponentChange::Removed);
+    }
 
-        impl ParSystem for TestSystem {
-            type Views<'a> = Views!(&'a mut B);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+    #[test]
+    fn diff_added_and_removed_entities() {
+        let mut world = World::<Registry>::new();
+        let removed = world.insert(entity!(A(1)));
+        let unchanged = world.insert(entity!(A(2)));
+        let previous = world.snapshot();
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results.iter.map(|result!(b)| b.0).collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec!['a', 'b']);
-            }
-        }
+        world.remove(removed);
+        let added = world.insert(entity!(A(3), B('c')));
+        let added_empty = world.insert(entity!());
 
-        let mut world = World::<Registry>::new();
+        let diff = world.diff(&previous);
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        assert_eq!(diff.removed(), &[removed]);
+        assert_none!(diff.get::<A, _>(unchanged));
+        assert_some_eq!(diff.get::<A, _>(added), &ComponentChange::Added(A(3)));
+        assert_some_eq!(diff.get::<B, _>(added), &ComponentChange::Added(B('c')));
+        assert_some_eq!(diff.get::<A, _>(added_empty), &ComponentChange::Unchanged);
+    }
 
-        world.run_par_system(&mut TestSystem);
+    #[test]
+    fn apply_diff() {
+        let mut world = World::<Registry>::new();
+        let first = world.insert(entity!(A(1), B('a')));
+        let second = world.insert(entity!(A(2)));
+        let third = world.insert(entity!(B('c')));
+        let mut replica = world.snapshot();
+
+        assert_some!(world.entry(first)).add(A(10));
+        assert_some!(world.entry(second)).add(B('b'));
+        world.remove(third);
+        let fourth = world.insert(entity!(A(4)));
+        let fifth = world.insert(entity!());
+
+        assert_ok!(replica.apply_diff(world.diff(&replica)));
+
+        assert!(world.diff(&replica).is_empty());
+        assert!(replica.diff(&world).is_empty());
+        assert_eq!(replica.len(), 4);
+        assert!(!replica.contains(third));
+        assert!(replica.contains(fourth));
+        assert!(replica.contains(fifth));
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_system_option_refs() {
-        struct TestSystem;
+    fn apply_diff_occupied() {
+        let mut world = World::<Registry>::new();
+        let previous = world.snapshot();
+        world.insert(entity!(A(1)));
 
-        impl ParSystem for TestSystem {
-            type Views<'a> = Views!(Option<&'a A>);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        // Occupy the same index with a newer generation.
+        let mut other = World::<Registry>::new();
+        let entity_identifier = other.insert(entity!(A(2)));
+        other.remove(entity_identifier);
+        other.insert(entity!(A(3)));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results
-                    .iter
-                    .map(|result!(a)| a.map(|a| a.0))
-                    .collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec![None, None, Some(1), Some(2)]);
-            }
-        }
+        assert_err_eq!(
+            other.apply_diff(world.diff(&previous)),
+            SpawnError::Occupied
+        );
+    }
 
+    #[test]
+    fn apply_diff_error_leaves_world_unchanged() {
         let mut world = World::<Registry>::new();
+        let first = world.insert(entity!(A(1)));
+        let second = world.insert(entity!(A(2)));
+        let previous = world.snapshot();
+        world.remove(first);
+        assert_some!(world.entry(second)).add(B('b'));
+        world.insert(entity!(A(3)));
+        world.insert(entity!(A(4)));
+
+        // Occupy the index of the last added entity with a newer generation.
+        let mut other = previous.snapshot();
+        let entity_identifier = other.insert(entity!(A(5)));
+        other.remove(entity_identifier);
+        other.insert(entity!(A(6)));
+        let before = other.snapshot();
+
+        assert_err_eq!(
+            other.apply_diff(world.diff(&previous)),
+            SpawnError::Occupied
+        );
+        assert_eq!(other, before);
+    }
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+    #[test]
+    fn structurally_eq() {
+        let mut world_a = World::<Registry>::new();
+        world_a.insert(entity!(A(1), B('a')));
+        world_a.insert(entity!(A(2), B('b')));
+        world_a.insert(entity!(A(3)));
+
+        let mut world_b = World::<Registry>::new();
+        world_b.insert(entity!(A(3)));
+        world_b.insert(entity!(B('b'), A(2)));
+        world_b.insert(entity!(A(1), B('a')));
+
+        assert!(world_a.structurally_eq(&world_b));
+        assert!(world_b.structurally_eq(&world_a));
+    }
 
-        world.run_par_system(&mut TestSystem);
+    #[test]
+    fn structurally_eq_different_identifiers() {
+        let mut world_a = World::<Registry>::new();
+        world_a.insert(entity!(A(1)));
+
+        let mut world_b = World::<Registry>::new();
+        let entity_identifier = world_b.insert(entity!(A(1)));
+        world_b.remove(entity_identifier);
+        world_b.insert(entity!(A(1)));
+
+        assert_ne!(world_a, world_b);
+        assert!(world_a.structurally_eq(&world_b));
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_system_option_mut_refs() {
-        struct TestSystem;
+    fn structurally_eq_duplicate_entities() {
+        let mut world_a = World::<Registry>::new();
+        world_a.insert(entity!(A(1)));
+        world_a.insert(entity!(A(1)));
+        world_a.insert(entity!(A(2)));
+
+        let mut world_b = World::<Registry>::new();
+        world_b.insert(entity!(A(1)));
+        world_b.insert(entity!(A(2)));
+        world_b.insert(entity!(A(2)));
+
+        assert!(!world_a.structurally_eq(&world_b));
+    }
 
-        impl ParSystem for TestSystem {
-            type Views<'a> = Views!(Option<&'a mut B>);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+    #[test]
+    fn structurally_eq_different_components() {
+        let mut world_a = World::<Registry>::new();
+        world_a.insert(entity!(A(1), B('a')));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results
-                    .iter
-                    .map(|result!(b)| b.map(|b| b.0))
-                    .collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec![None, None, Some('a'), Some('b')]);
-            }
-        }
+        let mut world_b = World::<Registry>::new();
+        world_b.insert(entity!(A(1), B('b')));
+
+        assert!(!world_a.structurally_eq(&world_b));
+    }
+
+    #[test]
+    fn structurally_eq_ignores_empty_archetypes() {
+        let mut world_a = World::<Registry>::new();
+        world_a.insert(entity!(A(1)));
+        let entity_identifier = world_a.insert(entity!(B('a')));
+        world_a.remove(entity_identifier);
+
+        let mut world_b = World::<Registry>::new();
+        world_b.insert(entity!(A(1)));
+
+        assert!(world_a.structurally_eq(&world_b));
+    }
+
+    #[test]
+    fn clear_archetype() {
+        let mut world = World::<Registry>::new();
+
+        let a_b = world.insert(entity!(A(1), B('a')));
+        let a = world.insert(entity!(A(2)));
+        world.insert(entity!(A(3)));
+
+        world.clear_archetype::<Entity!(A), _>();
 
+        assert_eq!(world.len(), 1);
+        assert!(world.contains(a_b));
+        assert!(!world.contains(a));
+        assert_eq!(world.len_of::<Entity!(A), _>(), 0);
+    }
+
+    #[test]
+    fn clear_archetype_any_component_order() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
 
-        world.run_par_system(&mut TestSystem);
+        world.clear_archetype::<Entity!(B, A), _>();
+
+        assert!(world.is_empty());
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_system_entity_identifier() {
-        struct TestSystem {
-            entity_identifier: entity::Identifier,
-        }
+    fn clear_archetype_no_archetype() {
+        let mut world = World::<Registry>::new();
 
-        impl ParSystem for TestSystem {
-            type Views<'a> = Views!(entity::Identifier);
-            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        world.insert(entity!(A(1)));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let result = query_results
-                    .iter
-                    .map(|result!(entity_identifier)| entity_identifier)
-                    .collect::<Vec<_>>();
-                assert_eq!(result, vec![self.entity_identifier]);
-            }
-        }
+        world.clear_archetype::<Entity!(B), _>();
+
+        assert_eq!(world.len(), 1);
+    }
 
+    #[test]
+    fn clear_archetype_reuses_slots() {
         let mut world = World::<Registry>::new();
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        let old = world.insert(entity!(A(1)));
+        world.clear_archetype::<Entity!(A), _>();
+        let new = world.insert(entity!(A(2)));
 
-        world.run_par_system(&mut TestSystem { entity_identifier });
+        assert_eq!(old.index(), new.index());
+        assert!(!world.contains(old));
+        assert!(world.contains(new));
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_system_has_filter() {
-        struct TestSystem;
+    fn clear_archetype_notifies_on_remove() {
+        let mut world = World::<Registry>::new();
+        let (_added, removed) = observe_a(&mut world);
 
-        impl ParSystem for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::Has<B>;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(A(4), B('a')));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                assert_eq!(result, vec![1]);
-            }
-        }
+        world.clear_archetype::<Entity!(A), _>();
+
+        assert_eq!(removed.load(Ordering::Relaxed), 3);
+    }
 
+    #[test]
+    fn component_column() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert(entity!(B('b'), A(3)));
 
-        world.run_par_system(&mut TestSystem);
+        assert_some_eq!(
+            world.component_column::<A, Entity!(A, B), _, _>(),
+            [A(1), A(3)].as_slice()
+        );
+        assert_some_eq!(
+            world.component_column::<B, Entity!(B, A), _, _>(),
+            [B('a'), B('b')].as_slice()
+        );
+        assert_some_eq!(
+            world.component_column::<A, Entity!(A), _, _>(),
+            [A(2)].as_slice()
+        );
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_system_not_filter() {
-        struct TestSystem;
-
-        impl ParSystem for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::Not<filter::Has<B>>;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+    fn component_column_not_in_entity() {
+        let mut world = World::<Registry>::new();
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                assert_eq!(result, vec![2]);
-            }
-        }
+        world.insert(entity!(A(1)));
 
-        let mut world = World::<Registry>::new();
+        assert_none!(world.component_column::<B, Entity!(A), _, _>());
+    }
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+    #[test]
+    fn component_column_no_archetype() {
+        let world = World::<Registry>::new();
 
-        world.run_par_system(&mut TestSystem);
+        assert_none!(world.component_column::<A, Entity!(A), _, _>());
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_system_and_filter() {
-        struct TestSystem;
+    fn component_column_matches_index() {
+        let mut world = World::<Registry>::new();
 
-        impl ParSystem for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2), B('b')));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                assert_eq!(result, vec![1]);
-            }
+        let column = assert_some!(world.component_column::<A, Entity!(A, B), _, _>()).to_vec();
+        for result!(a, index) in world
+            .query(Query::<Views!(&A, view::Index), filter::Has<B>>::new())
+            .iter
+        {
+            assert_some_eq!(column.get(index.0), a);
         }
+    }
 
+    #[test]
+    fn component_column_mut() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert(entity!(A(3), B('b')));
 
-        world.run_par_system(&mut TestSystem);
+        for a in assert_some!(world.component_column_mut::<A, Entity!(A, B), _, _>()) {
+            a.0 += 10;
+        }
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![2, 11, 13]);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_system_or_filter() {
-        struct TestSystem;
+    fn component_column_mut_not_in_entity() {
+        let mut world = World::<Registry>::new();
 
-        impl ParSystem for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::Or<filter::Has<A>, filter::Has<B>>;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        world.insert(entity!(A(1)));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec![1, 2]);
-            }
-        }
+        assert_none!(world.component_column_mut::<B, Entity!(A), _, _>());
+    }
 
+    #[test]
+    fn export_column() {
         let mut world = World::<Registry>::new();
 
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert(entity!(A(3), B('b')));
 
-        world.run_par_system(&mut TestSystem);
+        let (pointer, length) = assert_some!(world.export_column::<A, Entity!(A, B), _, _>());
+
+        assert_eq!(length, 2);
+        assert_eq!(
+            unsafe { core::slice::from_raw_parts(pointer, length) },
+            [A(1), A(3)].as_slice()
+        );
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn par_system_resource_views() {
-        struct Counter(usize);
+    fn export_column_not_in_entity() {
+        let mut world = World::<Registry>::new();
 
-        struct TestSystem;
+        world.insert(entity!(A(1)));
 
-        impl ParSystem for TestSystem {
-            type Views<'a> = Views!(&'a A, &'a B);
-            type Filter = filter::And<filter::Has<A>, filter::Has<B>>;
-            type ResourceViews<'a> = Views!(&'a mut Counter);
-            type EntryViews<'a> = Views!();
+        assert_none!(world.export_column::<B, Entity!(A), _, _>());
+    }
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let result!(counter) = query_results.resources;
-                counter.0 = query_results.iter.count();
-            }
-        }
+    #[test]
+    fn export_column_no_archetype() {
+        let world = World::<Registry>::new();
 
-        let mut world = World::<Registry, _>::with_resources(resources!(Counter(0)));
+        assert_none!(world.export_column::<A, Entity!(A), _, _>());
+    }
+
+    #[test]
+    fn is_empty() {
+        let mut world = World::<Registry>::new();
+
+        assert!(world.is_empty());
 
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
         world.insert(entity!(B('b')));
         world.insert(entity!());
 
-        world.run_par_system(&mut TestSystem);
-
-        assert_eq!(world.get::<Counter, _>().0, 1);
+        assert!(!world.is_empty());
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn schedule() {
-        struct TestSystem;
-
-        impl System for TestSystem {
-            type Views<'a> = Views!(&'a A);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+    fn is_registered() {
+        let world = World::<Registry>::new();
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results.iter.map(|result!(a)| a.0).collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec![1, 2]);
-            }
-        }
+        assert!(world.is_registered(TypeId::of::<A>()));
+        assert!(world.is_registered(TypeId::of::<B>()));
+        assert!(!world.is_registered(TypeId::of::<u32>()));
+    }
 
-        struct TestParSystem;
+    #[test]
+    fn is_registered_empty_registry() {
+        let world = World::<Registry!()>::new();
 
-        impl ParSystem for TestParSystem {
-            type Views<'a> = Views!(&'a mut B);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        assert!(!world.is_registered(TypeId::of::<A>()));
+    }
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: ParallelIterator<Item = Self::Views<'a>>,
-            {
-                let mut result = query_results.iter.map(|result!(b)| b.0).collect::<Vec<_>>();
-                result.sort();
-                assert_eq!(result, vec!['a', 'b']);
-            }
-        }
+    #[test]
+    fn is_registered_clone() {
+        let world = World::<Registry>::new();
 
-        let mut world = World::<Registry>::new();
+        assert!(world.clone().is_registered(TypeId::of::<A>()));
+    }
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+    #[test]
+    fn registry_len() {
+        assert_eq!(World::<Registry>::registry_len(), 2);
+    }
 
-        let mut schedule = schedule!(task::System(TestSystem), task::ParSystem(TestParSystem));
+    #[test]
+    fn registry_len_empty() {
+        assert_eq!(World::<Registry!()>::registry_len(), 0);
+    }
 
-        world.run_schedule(&mut schedule);
+    #[test]
+    fn component_names() {
+        assert_eq!(
+            World::<Registry>::component_names(),
+            vec![type_name::<A>(), type_name::<B>()]
+        );
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn schedule_dynamic_optimization() {
-        #[derive(Clone)]
-        struct A(u32);
-        #[derive(Clone)]
-        struct B(u32);
-        #[derive(Clone)]
-        struct C(u32);
+    fn assert_registered() {
+        World::<Registry>::assert_registered::<A, _>();
+        World::<Registry>::assert_registered::<B, _>();
+    }
 
-        type Registry = Registry!(A, B, C);
+    #[test]
+    fn component_names_empty() {
+        assert!(World::<Registry!()>::component_names().is_empty());
+    }
 
-        struct Foo;
+    #[test]
+    fn shrink_to_fit() {
+        let mut world = World::<Registry>::new();
 
-        impl System for Foo {
-            type Views<'a> = Views!(&'a mut A, &'a mut B);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        world.extend(entities!((A(1), B('a')); 10));
+        world.clear();
+        world.extend(entities!((A(2), B('b')); 3));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                for result!(a, b) in query_results.iter {
-                    core::mem::swap(&mut a.0, &mut b.0);
-                }
-            }
-        }
+        world.shrink_to_fit();
+    }
 
-        struct Bar;
+    #[test]
+    fn shrink_to_fit_removes_table() {
+        let mut world = World::<Registry>::new();
 
-        impl System for Bar {
-            type Views<'a> = Views!(&'a mut A, &'a mut C);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        world.insert(entity!(A(1)));
+        let entity_identifier = world.insert(entity!(B('a')));
+        world.remove(entity_identifier);
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                for result!(a, c) in query_results.iter {
-                    core::mem::swap(&mut a.0, &mut c.0);
-                }
-            }
-        }
+        world.shrink_to_fit();
+    }
 
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_shrink_to_fit() {
         let mut world = World::<Registry>::new();
 
-        world.extend(entities!((A(0), B(0)); 1000));
-        world.extend(entities!((A(0), C(0)); 1000));
+        world.extend(entities!((A(1), B('a')); 10));
+        world.extend(entities!((A(1)); 10));
+        world.clear();
+        world.extend(entities!((A(2), B('b')); 3));
 
-        let mut schedule = schedule!(task::System(Foo), task::System(Bar));
+        world.par_shrink_to_fit();
 
-        world.run_schedule(&mut schedule);
+        assert_eq!(world.len(), 3);
+        assert_eq!(world.capacity::<Entity!(A, B), _>(), 3);
     }
 
     #[cfg(feature = "rayon")]
     #[test]
-    fn schedule_dynamic_optimization_three_stages() {
-        #[derive(Clone)]
-        struct A(u32);
-        #[derive(Clone)]
-        struct B(u32);
-        #[derive(Clone)]
-        struct C(u32);
+    fn par_shrink_to_fit_removes_table() {
+        let mut world = World::<Registry>::new();
 
-        type Registry = Registry!(A, B, C);
+        world.insert(entity!(A(1)));
+        let entity_identifier = world.insert(entity!(B('a')));
+        world.remove(entity_identifier);
 
-        struct Foo;
+        world.par_shrink_to_fit();
 
-        impl System for Foo {
-            type Views<'a> = Views!(&'a mut A, &'a mut B);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        assert_eq!(world.archetypes.iter().count(), 1);
+    }
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                for result!(a, b) in query_results.iter {
-                    core::mem::swap(&mut a.0, &mut b.0);
-                }
-            }
-        }
+    #[test]
+    fn shrink_to() {
+        let mut world = World::<Registry>::new();
 
-        struct Bar;
+        world.reserve_exact::<Entity!(A, B), _>(100);
+        world.extend(entities!((A(1), B('a')); 10));
 
-        impl System for Bar {
-            type Views<'a> = Views!(&'a mut A, &'a mut C);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        world.shrink_to(1.5);
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                for result!(a, c) in query_results.iter {
-                    core::mem::swap(&mut a.0, &mut c.0);
-                }
-            }
-        }
+        assert_eq!(world.capacity::<Entity!(A, B), _>(), 15);
+        assert_eq!(world.len(), 10);
+    }
 
-        struct Baz;
+    #[test]
+    fn shrink_to_within_ratio() {
+        let mut world = World::<Registry>::new();
 
-        impl System for Baz {
-            type Views<'a> = Views!(&'a mut A, &'a mut B, &'a mut C);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!();
+        world.reserve_exact::<Entity!(A, B), _>(15);
+        world.extend(entities!((A(1), B('a')); 10));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<R, S, I, Self::ResourceViews<'a>, Self::EntryViews<'a>, E>,
-            ) where
-                R: registry::Registry,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                for result!(a, _b, c) in query_results.iter {
-                    core::mem::swap(&mut a.0, &mut c.0);
-                }
-            }
-        }
+        world.shrink_to(2.0);
 
+        assert_eq!(world.capacity::<Entity!(A, B), _>(), 15);
+    }
+
+    #[test]
+    fn shrink_to_ratio_below_one() {
         let mut world = World::<Registry>::new();
 
-        world.extend(entities!((A(0), B(0)); 1000));
-        world.extend(entities!((A(0), C(0)); 1000));
+        world.reserve_exact::<Entity!(A, B), _>(100);
+        world.extend(entities!((A(1), B('a')); 10));
 
-        let mut schedule = schedule!(task::System(Foo), task::System(Bar), task::System(Baz));
+        world.shrink_to(0.5);
 
-        world.run_schedule(&mut schedule);
+        assert_eq!(world.capacity::<Entity!(A, B), _>(), 10);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn schedule_dynamic_optimization_entry_views() {
-        #[derive(Clone)]
-        struct A(u32);
-        #[derive(Clone)]
-        struct B(u32);
-        #[derive(Clone)]
-        struct C(u32);
+    fn shrink_to_removes_table() {
+        let mut world = World::<Registry>::new();
 
-        type Registry = Registry!(A, B, C);
+        world.insert(entity!(A(1)));
+        let entity_identifier = world.insert(entity!(B('a')));
+        world.remove(entity_identifier);
 
-        struct Foo;
+        world.shrink_to(2.0);
 
-        impl System for Foo {
-            type Views<'a> = Views!(entity::Identifier);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!(&'a mut A, &'a mut B);
+        assert_eq!(world.capacity::<Entity!(B), _>(), 0);
+        assert_eq!(world.capacity::<Entity!(A), _>(), 2);
+    }
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                mut query_results: Result<
-                    'a,
-                    R,
-                    S,
-                    I,
-                    Self::ResourceViews<'a>,
-                    Self::EntryViews<'a>,
-                    E,
-                >,
-            ) where
-                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                for result!(identifier) in query_results.iter {
-                    if let Some(result!(b)) = query_results
-                        .entries
-                        .entry(identifier)
-                        .map(|mut entry| entry.query(Query::<Views!(&mut B)>::new()))
-                        .flatten()
-                    {
-                        b.0 += 1;
-                    }
-                }
-            }
-        }
+    #[test]
+    fn auto_compact_disabled_by_default() {
+        let mut world = World::<Registry>::new();
 
-        struct Bar;
+        world.insert(entity!(A(1)));
+        let entity_identifier = world.insert(entity!(B('a')));
+        world.remove(entity_identifier);
 
-        impl System for Bar {
-            type Views<'a> = Views!(entity::Identifier);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!();
-            type EntryViews<'a> = Views!(&'a mut A, &'a mut C);
+        assert_eq!(world.memory_usage_per_archetype().count(), 2);
+    }
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                mut query_results: Result<
-                    'a,
-                    R,
-                    S,
-                    I,
-                    Self::ResourceViews<'a>,
-                    Self::EntryViews<'a>,
-                    E,
-                >,
-            ) where
-                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                for result!(identifier) in query_results.iter {
-                    if let Some(result!(c)) = query_results
-                        .entries
-                        .entry(identifier)
-                        .map(|mut entry| entry.query(Query::<Views!(&mut C)>::new()))
-                        .flatten()
-                    {
-                        c.0 += 1;
-                    }
-                }
-            }
-        }
+    #[test]
+    fn auto_compact_remove() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
+
+        world.insert(entity!(A(1)));
+        let entity_identifier = world.insert(entity!(B('a')));
+        world.remove(entity_identifier);
 
+        assert_eq!(world.memory_usage_per_archetype().count(), 1);
+        assert_eq!(world.len(), 1);
+    }
+
+    #[test]
+    fn auto_compact_within_ratio() {
         let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.5));
 
-        world.extend(entities!((B(0)); 1000));
-        world.extend(entities!((C(0)); 1000));
+        world.insert(entity!(A(1)));
+        world.insert(entity!(B('a')));
+        let entity_identifier = world.insert(entity!(A(2), B('b')));
+        world.remove(entity_identifier);
 
-        let mut schedule = schedule!(task::System(Foo), task::System(Bar));
+        assert_eq!(world.memory_usage_per_archetype().count(), 3);
+    }
 
-        world.run_schedule(&mut schedule);
+    #[test]
+    fn auto_compact_entry_add() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
+
+        let entity_identifier = world.insert(entity!(A(1)));
+        assert_some!(world.entry(entity_identifier)).add(B('a'));
+
+        assert_eq!(world.memory_usage_per_archetype().count(), 1);
+        assert_some_eq!(
+            world
+                .query(Query::<Views!(&A, &B)>::new())
+                .iter
+                .map(|result!(a, b)| (*a, b.0))
+                .next(),
+            (A(1), 'a')
+        );
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn schedule_dynamic_optimization_compatible_resource_views() {
-        #[derive(Clone)]
-        struct A(u32);
-        #[derive(Clone)]
-        struct B(u32);
-        #[derive(Clone)]
-        struct C(u32);
+    fn auto_compact_entry_remove() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
 
-        type Registry = Registry!(A, B, C);
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        let mut entry = assert_some!(world.entry(entity_identifier));
+        entry.remove::<B, _>();
+        entry.add(B('b'));
+        entry.remove::<A, _>();
 
-        struct Foo;
+        assert_eq!(world.memory_usage_per_archetype().count(), 1);
+        assert_eq!(
+            world.archetype_identifier(entity_identifier),
+            Some(vec![0b10])
+        );
+    }
 
-        impl System for Foo {
-            type Views<'a> = Views!(&'a mut A, &'a mut B);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!(&'a A);
-            type EntryViews<'a> = Views!();
+    #[test]
+    fn auto_compact_entry_modify() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                _query_results: Result<
-                    'a,
-                    R,
-                    S,
-                    I,
-                    Self::ResourceViews<'a>,
-                    Self::EntryViews<'a>,
-                    E,
-                >,
-            ) where
-                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-            }
-        }
+        let entity_identifier = world.insert(entity!(A(1)));
+        assert_some!(world.entry(entity_identifier)).modify(|modifier| {
+            modifier.remove::<A, _>();
+            modifier.add(B('a'));
+        });
 
-        struct Bar;
+        assert_eq!(world.memory_usage_per_archetype().count(), 1);
+    }
 
-        impl System for Bar {
-            type Views<'a> = Views!(&'a mut A, &'a mut C);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!(&'a A);
-            type EntryViews<'a> = Views!();
+    #[test]
+    fn auto_compact_apply_deferred() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                _query_results: Result<
-                    'a,
-                    R,
-                    S,
-                    I,
-                    Self::ResourceViews<'a>,
-                    Self::EntryViews<'a>,
-                    E,
-                >,
-            ) where
-                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-            }
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+
+        let query_result = world.query(Query::<Views!(entity::Identifier)>::new());
+        let mut entries = query_result.entries;
+        for result!(entity_identifier) in query_result.iter {
+            assert_some!(entries.entry(entity_identifier)).add(B('a'));
         }
+        let deferred = entries.into_deferred();
+        world.apply_deferred(deferred);
 
-        let mut world = World::<Registry, _>::with_resources(resources!(A(0)));
+        assert_eq!(world.memory_usage_per_archetype().count(), 1);
+        assert_eq!(world.query(Query::<Views!(&A, &B)>::new()).iter.count(), 2);
+    }
 
-        let mut schedule = schedule!(task::System(Foo), task::System(Bar));
+    #[test]
+    fn auto_compact_add_component_to_all() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
 
-        world.run_schedule(&mut schedule);
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+
+        world.add_component_to_all(Query::<Views!(&A)>::new(), B('a'));
+
+        assert_eq!(world.memory_usage_per_archetype().count(), 1);
+        assert_eq!(world.len_of::<Entity!(A, B), _>(), 2);
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn schedule_dynamic_optimization_compatible_resource_views_with_multiple_resource_views() {
-        #[derive(Clone)]
-        struct A(u32);
-        #[derive(Clone)]
-        struct B(u32);
-        #[derive(Clone)]
-        struct C(u32);
+    fn auto_compact_clear_archetype() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
 
-        type Registry = Registry!(A, B, C);
+        world.insert(entity!(A(1)));
+        world.insert(entity!(B('a')));
 
-        struct Foo;
+        world.clear_archetype::<Entity!(B), _>();
 
-        impl System for Foo {
-            type Views<'a> = Views!(&'a mut A, &'a mut B);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!(&'a B);
-            type EntryViews<'a> = Views!();
+        assert_eq!(world.memory_usage_per_archetype().count(), 1);
+        assert_eq!(world.len(), 1);
+    }
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                _query_results: Result<
-                    'a,
-                    R,
-                    S,
-                    I,
-                    Self::ResourceViews<'a>,
-                    Self::EntryViews<'a>,
-                    E,
-                >,
-            ) where
-                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-            }
-        }
+    #[test]
+    fn auto_compact_drain() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
 
-        struct Bar;
+        world.insert(entity!(A(1)));
+        world.insert(entity!(B('a')));
 
-        impl System for Bar {
-            type Views<'a> = Views!(&'a mut A, &'a mut C);
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!(&'a B);
-            type EntryViews<'a> = Views!();
+        assert_eq!(world.drain::<Entity!(A), _>().count(), 1);
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                _query_results: Result<
-                    'a,
-                    R,
-                    S,
-                    I,
-                    Self::ResourceViews<'a>,
-                    Self::EntryViews<'a>,
-                    E,
-                >,
-            ) where
-                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-            }
-        }
+        assert_eq!(world.memory_usage_per_archetype().count(), 0);
+        assert!(world.is_empty());
+    }
 
-        let mut world = World::<Registry, _>::with_resources(resources!(A(0), B(0), C(0)));
+    #[test]
+    fn auto_compact_drain_not_consumed() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
 
-        let mut schedule = schedule!(task::System(Foo), task::System(Bar));
+        world.insert(entity!(A(1)));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('a')));
 
-        world.run_schedule(&mut schedule);
+        drop(world.drain::<Entity!(A), _>());
+
+        assert_eq!(world.memory_usage_per_archetype().count(), 0);
+        assert!(world.is_empty());
     }
 
-    #[cfg(feature = "rayon")]
     #[test]
-    fn schedule_dynamic_optimization_incompatible_resource_views() {
-        #[derive(Clone)]
-        struct A(u32);
-        #[derive(Clone)]
-        struct B(u32);
-        #[derive(Clone)]
-        struct C(u32);
+    fn auto_compact_clone_reordered() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
 
-        struct Foo;
+        world.insert(entity!(A(1)));
+        let entity_identifier = world.insert(entity!(B('a')));
 
-        impl System for Foo {
-            type Views<'a> = Views!();
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!(&'a mut A, &'a mut B);
-            type EntryViews<'a> = Views!();
+        let mut reordered = world.clone_reordered::<Registry!(B, A), _, _>();
+        reordered.remove(entity_identifier);
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<
-                    'a,
-                    R,
-                    S,
-                    I,
-                    Self::ResourceViews<'a>,
-                    Self::EntryViews<'a>,
-                    E,
-                >,
-            ) where
-                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let result!(a, b) = query_results.resources;
-                core::mem::swap(&mut a.0, &mut b.0);
-            }
-        }
+        assert_eq!(reordered.memory_usage_per_archetype().count(), 1);
+    }
 
-        struct Bar;
+    #[test]
+    fn auto_compact_keeps_capacity() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
 
-        impl System for Bar {
-            type Views<'a> = Views!();
-            type Filter = filter::None;
-            type ResourceViews<'a> = Views!(&'a mut A, &'a mut C);
-            type EntryViews<'a> = Views!();
+        world.reserve::<Entity!(A), _>(10);
+        world.insert(entity!(A(1)));
+        let entity_identifier = world.insert(entity!(B('a')));
+        world.remove(entity_identifier);
 
-            fn run<'a, R, S, I, E>(
-                &mut self,
-                query_results: Result<
-                    'a,
-                    R,
-                    S,
-                    I,
-                    Self::ResourceViews<'a>,
-                    Self::EntryViews<'a>,
-                    E,
-                >,
-            ) where
-                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
-                I: Iterator<Item = Self::Views<'a>>,
-            {
-                let result!(a, c) = query_results.resources;
-                core::mem::swap(&mut a.0, &mut c.0);
-            }
-        }
+        assert!(world.capacity::<Entity!(A), _>() >= 10);
+        assert_eq!(world.capacity::<Entity!(B), _>(), 0);
+    }
 
-        let mut world = World::<Registry!(), _>::with_resources(resources!(A(0), B(0), C(0)));
+    #[test]
+    fn auto_compact_disabled() {
+        let mut world = World::<Registry>::new();
+        world.set_auto_compact(Some(0.0));
+        world.set_auto_compact(None);
+
+        world.insert(entity!(A(1)));
+        let entity_identifier = world.insert(entity!(B('a')));
+        world.remove(entity_identifier);
+
+        assert_eq!(world.memory_usage_per_archetype().count(), 2);
+    }
+
+    #[test]
+    fn freeze() {
+        let mut world = World::<Registry>::new();
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.reserve::<Entity!(B), _>(10);
+
+        let frozen_world = world.freeze();
+
+        assert_eq!(frozen_world.len(), 2);
+        assert!(!frozen_world.is_empty());
+        assert!(frozen_world.contains(entity_identifier));
+        assert_eq!(frozen_world.count_component::<A, _>(), 2);
+        assert_eq!(frozen_world.count_component::<B, _>(), 1);
+        let world = frozen_world.thaw();
+        assert_eq!(world.capacity::<Entity!(B), _>(), 0);
+    }
+
+    #[test]
+    fn freeze_query() {
+        let mut world = World::<Registry>::new();
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
 
-        let mut schedule = schedule!(task::System(Foo), task::System(Bar));
+        let mut frozen_world = world.freeze();
+        for result!(a) in frozen_world
+            .query(Query::<Views!(&mut A), filter::Has<B>>::new())
+            .iter
+        {
+            a.0 += 10;
+        }
 
-        world.run_schedule(&mut schedule);
+        let mut result = frozen_world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, vec![2, 11]);
     }
 
     #[test]
-    fn contains() {
-        let mut world = World::<Registry>::new();
+    fn freeze_query_resources() {
+        let mut world = World::<Registry, _>::with_resources(resources!(A(42)));
+        world.insert(entity!(B('a')));
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        let mut frozen_world = world.freeze();
+        let result!(a) = frozen_world
+            .query(Query::<Views!(&B), filter::None, Views!(&A)>::new())
+            .resources;
 
-        assert!(world.contains(entity_identifier));
+        assert_eq!(a, &A(42));
+        assert_eq!(frozen_world.get::<A, _>(), &A(42));
     }
 
     #[test]
-    fn not_contains() {
+    fn freeze_iteration_order() {
+        let mut world_a = World::<Registry>::new();
+        world_a.insert(entity!(A(1), B('a')));
+        world_a.insert(entity!(A(2)));
+        world_a.insert(entity!(B('b')));
+        let mut world_b = World::<Registry>::new();
+        let entity_identifier = world_b.insert(entity!());
+        world_b.insert(entity!(B('b')));
+        world_b.insert(entity!(A(2)));
+        world_b.insert(entity!(A(1), B('a')));
+        world_b.remove(entity_identifier);
+
+        let mut frozen_world_a = world_a.freeze();
+        let mut frozen_world_b = world_b.freeze();
+
+        assert_eq!(
+            frozen_world_a
+                .query(Query::<Views!(Option<&A>, Option<&B>)>::new())
+                .iter
+                .collect::<Vec<_>>(),
+            frozen_world_b
+                .query(Query::<Views!(Option<&A>, Option<&B>)>::new())
+                .iter
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn freeze_thaw() {
         let mut world = World::<Registry>::new();
+        let entity_identifier = world.insert(entity!(A(1)));
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        let mut world = world.freeze().thaw();
         world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
-
         world.remove(entity_identifier);
 
+        assert_eq!(world.len(), 1);
         assert!(!world.contains(entity_identifier));
     }
 
+    #[cfg(feature = "rayon")]
     #[test]
-    fn entry_add_component() {
+    fn freeze_par_query() {
         let mut world = World::<Registry>::new();
-
         world.insert(entity!(A(1), B('a')));
         world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        let entity_identifier = world.insert(entity!());
 
-        let mut entry = assert_some!(world.entry(entity_identifier));
-        entry.add(A(3));
+        let mut frozen_world = world.freeze();
 
-        let mut result = world
-            .query(Query::<Views!(&A)>::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![1, 2, 3]);
+        assert_eq!(
+            frozen_world
+                .par_query(Query::<Views!(&A)>::new())
+                .iter
+                .map(|result!(a)| a.0)
+                .sum::<u32>(),
+            3
+        );
     }
 
     #[test]
-    fn entry_set_existing_component() {
+    fn shrink_archetype() {
         let mut world = World::<Registry>::new();
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.reserve_exact::<Entity!(A, B), _>(100);
+        world.reserve_exact::<Entity!(A), _>(100);
+        world.extend(entities!((A(1), B('a')); 3));
 
-        let mut entry = assert_some!(world.entry(entity_identifier));
-        entry.add(A(3));
+        world.shrink_archetype::<Entity!(A, B), _>();
 
-        let mut result = world
-            .query(Query::<Views!(&A)>::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![2, 3]);
+        assert!(world.capacity::<Entity!(A, B), _>() < 100);
+        assert!(world.capacity::<Entity!(A), _>() >= 100);
+        assert_eq!(world.len(), 3);
     }
 
     #[test]
-    fn entry_remove_component() {
+    fn shrink_archetype_reordered() {
         let mut world = World::<Registry>::new();
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.reserve_exact::<Entity!(A, B), _>(100);
+        world.extend(entities!((A(1), B('a')); 3));
 
-        let mut entry = assert_some!(world.entry(entity_identifier));
-        entry.remove::<A, _>();
+        world.shrink_archetype::<Entity!(B, A), _>();
 
-        let mut result = world
-            .query(Query::<Views!(&A)>::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![2]);
+        assert!(world.capacity::<Entity!(A, B), _>() < 100);
     }
 
     #[test]
-    fn entry_query() {
+    fn shrink_archetype_removes_table() {
         let mut world = World::<Registry>::new();
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert(entity!(A(1)));
+        let entity_identifier = world.insert(entity!(B('a')));
+        world.remove(entity_identifier);
 
-        let mut entry = assert_some!(world.entry(entity_identifier));
+        world.shrink_archetype::<Entity!(B), _>();
 
-        let result!(queried_identifier, a, b) = assert_some!(entry.query(Query::<
-            Views!(entity::Identifier, &A, Option<&B>),
-            filter::None,
-        >::new()));
-        assert_eq!(queried_identifier, entity_identifier);
-        assert_eq!(a.0, 1);
-        let b = assert_some!(b);
-        assert_eq!(b.0, 'a');
+        assert_eq!(world.capacity::<Entity!(B), _>(), 0);
+        assert_eq!(world.len(), 1);
+
+        // The archetype can be recreated.
+        let entity_identifier = world.insert(entity!(B('b')));
+        assert!(world.contains(entity_identifier));
+        assert_eq!(world.len(), 2);
     }
 
     #[test]
-    fn entry_query_mut() {
+    fn shrink_archetype_nonexistent() {
         let mut world = World::<Registry>::new();
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert(entity!(A(1)));
 
-        let mut entry = assert_some!(world.entry(entity_identifier));
+        world.shrink_archetype::<Entity!(B), _>();
 
-        let result!(a, b) =
-            assert_some!(entry.query(Query::<Views!(&mut A, Option<&mut B>)>::new()));
-        assert_eq!(a.0, 1);
-        let b = assert_some!(b);
-        assert_eq!(b.0, 'a');
+        assert_eq!(world.len(), 1);
     }
 
     #[test]
-    fn entry_query_fails() {
+    fn reserve() {
         let mut world = World::<Registry>::new();
 
-        world.insert(entity!(A(1), B('a')));
-        let entity_identifier = world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.reserve::<Entity!(A, B), _>(10);
+    }
 
-        let mut entry = assert_some!(world.entry(entity_identifier));
+    #[test]
+    fn reserve_in_existing_archetype() {
+        let mut world = World::<Registry>::new();
 
-        assert_none!(entry.query(Query::<Views!(entity::Identifier, &A, &B)>::new()));
+        world.insert(entity!(A(1)));
+        world.reserve::<Entity!(A), _>(10);
     }
 
     #[test]
-    fn no_entry_found() {
+    fn reserve_creates_new_archetypes() {
         let mut world = World::<Registry>::new();
+        world.insert(entity!(A(42)));
+        world.extend(entities!((B('a')); 5));
+        world.extend(entities!((A(100), B('b')); 10));
+        let mut source_world = World::<Registry>::new();
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.clone_from(&source_world);
 
-        world.remove(entity_identifier);
+        source_world.reserve::<Entity!(A), _>(0);
+        source_world.reserve::<Entity!(B), _>(0);
+        source_world.reserve::<Entity!(A, B), _>(0);
 
-        assert_none!(world.entry(entity_identifier));
+        assert_eq!(world, source_world);
     }
 
     #[test]
-    fn entry_multiple_shape_changes() {
+    fn reserve_exact() {
         let mut world = World::<Registry>::new();
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        let mut entry = assert_some!(world.entry(entity_identifier));
-
-        entry.remove::<B, _>();
-        entry.remove::<A, _>();
+        world.reserve_exact::<Entity!(A, B), _>(10);
 
-        assert_none!(
-            entry.query(Query::<Views!(), filter::Or<filter::Has<A>, filter::Has<B>>>::new())
-        );
+        assert!(world.capacity::<Entity!(A, B), _>() >= 10);
     }
 
     #[test]
-    fn remove() {
+    fn reserve_exact_in_existing_archetype() {
         let mut world = World::<Registry>::new();
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert(entity!(A(1)));
+        world.reserve_exact::<Entity!(A), _>(10);
 
-        world.remove(entity_identifier);
+        assert!(world.capacity::<Entity!(A), _>() >= 11);
+    }
 
-        let mut result = world
-            .query(Query::<Views!(&A)>::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, vec![2]);
+    #[test]
+    fn reserve_all() {
+        let mut world = World::<Registry>::new();
+
+        world.insert(entity!(A(1)));
+        world.extend(entities!((A(1), B('a')); 2));
+        world.reserve::<Entity!(B), _>(0);
+        world.reserve_all(10);
+
+        assert!(world.capacity::<Entity!(A), _>() >= 11);
+        assert!(world.capacity::<Entity!(A, B), _>() >= 12);
+        assert!(world.capacity::<Entity!(B), _>() >= 10);
+        assert_eq!(world.capacity::<Entity!(), _>(), 0);
         assert_eq!(world.len(), 3);
     }
 
     #[test]
-    fn remove_already_removed() {
+    fn reserve_all_empty_world() {
         let mut world = World::<Registry>::new();
 
-        let entity_identifier = world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.reserve_all(10);
 
-        world.remove(entity_identifier);
-        assert_eq!(world.len(), 3);
-        world.remove(entity_identifier);
+        assert_eq!(world.capacity::<Entity!(A), _>(), 0);
+    }
 
-        assert_eq!(world.len(), 3);
+    #[test]
+    fn capacity_no_archetype() {
+        let world = World::<Registry>::new();
+
+        assert_eq!(world.capacity::<Entity!(A, B), _>(), 0);
     }
 
     #[test]
-    fn clear() {
+    fn capacity_after_insert() {
         let mut world = World::<Registry>::new();
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.extend(entities!((A(1), B('a')); 5));
 
-        world.clear();
+        assert!(world.capacity::<Entity!(A, B), _>() >= 5);
+    }
 
-        let mut result = world
-            .query(Query::<Views!(&A)>::new())
-            .iter
-            .map(|result!(a)| a.0)
-            .collect::<Vec<_>>();
-        result.sort();
-        assert_eq!(result, Vec::new());
-        assert_eq!(world.len(), 0);
+    #[test]
+    fn insert_with_hint() {
+        let mut world = World::<Registry>::new();
+
+        let entity_identifier = world.insert_with_hint(entity!(A(1), B('a')), 100);
+
+        assert!(world.contains(entity_identifier));
+        assert_eq!(world.len(), 1);
+        assert!(world.capacity::<Entity!(A, B), _>() >= 100);
     }
 
     #[test]
-    fn len() {
+    fn insert_with_hint_does_not_reallocate() {
         let mut world = World::<Registry>::new();
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        world.insert_with_hint(entity!(A(0)), 100);
+        let capacity = world.capacity::<Entity!(A), _>();
+        for i in 1..100 {
+            world.insert_with_hint(entity!(A(i)), 100);
+        }
 
-        assert_eq!(world.len(), 4);
+        assert_eq!(world.len(), 100);
+        assert_eq!(world.capacity::<Entity!(A), _>(), capacity);
     }
 
     #[test]
-    fn is_empty() {
+    fn insert_with_hint_smaller_than_len() {
         let mut world = World::<Registry>::new();
+        world.extend(entities!((A(0)); 10));
 
-        assert!(world.is_empty());
+        world.insert_with_hint(entity!(A(10)), 1);
 
-        world.insert(entity!(A(1), B('a')));
-        world.insert(entity!(A(2)));
-        world.insert(entity!(B('b')));
-        world.insert(entity!());
+        assert_eq!(world.len(), 11);
+        assert!(world.capacity::<Entity!(A), _>() >= 11);
+    }
+
+    #[test]
+    fn capacity_any_component_order() {
+        let mut world = World::<Registry>::new();
+
+        world.reserve_exact::<Entity!(A, B), _>(10);
+
+        assert_eq!(
+            world.capacity::<Entity!(B, A), _>(),
+            world.capacity::<Entity!(A, B), _>()
+        );
+    }
+
+    #[test]
+    fn capacity_zero_sized_components() {
+        struct C;
+
+        let mut world = World::<Registry!(C)>::new();
+
+        world.reserve_exact::<Entity!(C), _>(10);
+
+        assert!(world.capacity::<Entity!(C), _>() >= 10);
+        assert!(world.capacity::<Entity!(C), _>() < usize::MAX);
+    }
+
+    #[test]
+    fn memory_usage_empty() {
+        let world = World::<Registry>::new();
+
+        assert_eq!(world.memory_usage(), 0);
+        assert_eq!(world.memory_usage_per_archetype().count(), 0);
+    }
+
+    #[test]
+    fn memory_usage_reserved() {
+        let mut world = World::<Registry>::new();
+
+        world.reserve_exact::<Entity!(A, B), _>(10);
+
+        assert!(
+            world.memory_usage()
+                >= 10 * (size_of::<A>() + size_of::<B>() + size_of::<entity::Identifier>())
+        );
+    }
+
+    #[test]
+    fn memory_usage_zero_sized_components() {
+        struct C;
+
+        let mut world = World::<Registry!(C)>::new();
+
+        world.reserve_exact::<Entity!(C), _>(10);
+
+        assert!(world.memory_usage() >= 10 * size_of::<entity::Identifier>());
+        assert!(world.memory_usage() < 20 * size_of::<entity::Identifier>());
+    }
+
+    #[test]
+    fn memory_usage_many_zero_sized_markers() {
+        #[derive(Clone)]
+        struct M1;
+        #[derive(Clone)]
+        struct M2;
+        #[derive(Clone)]
+        struct M3;
+        #[derive(Clone)]
+        struct M4;
+
+        type Registry = Registry!(A, M1, M2, M3, M4);
+
+        let mut marked = World::<Registry>::new();
+        let mut unmarked = World::<Registry>::new();
+
+        for i in 0..5000 {
+            marked.insert(entity!(A(i), M1, M2, M3, M4));
+            unmarked.insert(entity!(A(i)));
+        }
+        marked.extend(entities!((A(0), M1, M2, M3, M4); 5000));
+        unmarked.extend(entities!((A(0)); 5000));
+
+        // The marker components should not contribute to the memory used by the `World`.
+        assert_eq!(marked.memory_usage(), unmarked.memory_usage());
+    }
+
+    #[test]
+    fn zero_sized_components_dropped() {
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Clone)]
+        struct Marker;
+
+        impl Drop for Marker {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
 
-        assert!(!world.is_empty());
-    }
+        let mut world = World::<Registry!(A, B, Marker)>::new();
 
-    #[test]
-    fn shrink_to_fit() {
-        let mut world = World::<Registry>::new();
+        let entity_identifier = world.insert(entity!(A(1), Marker));
+        world.extend(entities!((A(2), Marker); 10));
+        assert_some!(world.entry(entity_identifier)).add(B('a'));
+        assert_some!(world.entry(entity_identifier)).remove::<A, _>();
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 0);
 
-        world.extend(entities!((A(1), B('a')); 10));
-        world.clear();
-        world.extend(entities!((A(2), B('b')); 3));
+        assert_some!(world.entry(entity_identifier)).remove::<Marker, _>();
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
 
-        world.shrink_to_fit();
+        drop(world);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 11);
     }
 
     #[test]
-    fn shrink_to_fit_removes_table() {
+    fn memory_usage_per_archetype() {
         let mut world = World::<Registry>::new();
 
-        world.insert(entity!(A(1)));
-        let entity_identifier = world.insert(entity!(B('a')));
-        world.remove(entity_identifier);
+        world.reserve_exact::<Entity!(A, B), _>(100);
+        world.reserve_exact::<Entity!(B), _>(1);
 
-        world.shrink_to_fit();
+        let usage = world.memory_usage_per_archetype().collect::<Vec<_>>();
+
+        assert_eq!(usage.len(), 2);
+        let a_b_usage = assert_some!(usage.iter().find(|(identifier, _)| *identifier == [0b11])).1;
+        let b_usage = assert_some!(usage.iter().find(|(identifier, _)| *identifier == [0b10])).1;
+        assert!(b_usage < a_b_usage);
+        assert_eq!(a_b_usage + b_usage, world.memory_usage());
     }
 
     #[test]
-    fn reserve() {
+    fn component_histogram() {
         let mut world = World::<Registry>::new();
 
-        world.reserve::<Entity!(A, B), _>(10);
-    }
+        world.extend(entities!((A(1), B('a')); 3));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+        world.insert(entity!());
 
-    #[test]
-    fn reserve_in_existing_archetype() {
-        let mut world = World::<Registry>::new();
+        let histogram = world.component_histogram();
 
-        world.insert(entity!(A(1)));
-        world.reserve::<Entity!(A), _>(10);
+        assert_eq!(histogram.len(), 2);
+        assert_some_eq!(histogram.get(&TypeId::of::<A>()), &4);
+        assert_some_eq!(histogram.get(&TypeId::of::<B>()), &4);
     }
 
     #[test]
-    fn reserve_creates_new_archetypes() {
+    fn component_histogram_empty_archetypes() {
         let mut world = World::<Registry>::new();
-        world.insert(entity!(A(42)));
-        world.extend(entities!((B('a')); 5));
-        world.extend(entities!((A(100), B('b')); 10));
-        let mut source_world = World::<Registry>::new();
 
-        world.clone_from(&source_world);
+        let entity_identifier = world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.remove(entity_identifier);
 
-        source_world.reserve::<Entity!(A), _>(0);
-        source_world.reserve::<Entity!(B), _>(0);
-        source_world.reserve::<Entity!(A, B), _>(0);
+        let histogram = world.component_histogram();
 
-        assert_eq!(world, source_world);
+        assert_eq!(histogram.len(), 1);
+        assert_some_eq!(histogram.get(&TypeId::of::<A>()), &1);
+        assert_none!(histogram.get(&TypeId::of::<B>()));
     }
 
     #[test]
@@ -3298,6 +13600,117 @@ mod tests {
         assert_eq!(b, &B('a'));
     }
 
+    #[test]
+    fn view_resources() {
+        let mut world = World::<Registry!(), _>::with_resources(resources!(A(42), B('a')));
+
+        let result!(b, a) = world.view_resources::<Views!(&mut B, &mut A), _>();
+        a.0 = 100;
+        b.0 = 'b';
+
+        assert_eq!(world.get::<A, _>(), &A(100));
+        assert_eq!(world.get::<B, _>(), &B('b'));
+    }
+
+    #[test]
+    fn view_resources_subset() {
+        let mut world = World::<Registry!(), _>::with_resources(resources!(A(42), B('a')));
+
+        let result!(b) = world.view_resources::<Views!(&mut B), _>();
+        b.0 = 'b';
+
+        assert_eq!(world.get::<B, _>(), &B('b'));
+    }
+
+    #[test]
+    fn insert_resource() {
+        let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+
+        assert_none!(world.insert_resource(A(42)));
+
+        assert_eq!(world.get_resource::<A>(), Some(&A(42)));
+        assert_none!(world.get_resource::<B>());
+    }
+
+    #[test]
+    fn insert_resource_replaces() {
+        let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+        world.insert_resource(A(1));
+
+        assert_eq!(world.insert_resource(A(2)), Some(A(1)));
+        assert_eq!(world.get_resource::<A>(), Some(&A(2)));
+    }
+
+    #[test]
+    fn remove_resource() {
+        let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+        world.insert_resource(A(42));
+
+        assert_eq!(world.remove_resource::<A>(), Some(A(42)));
+        assert_none!(world.get_resource::<A>());
+        assert_none!(world.remove_resource::<A>());
+    }
+
+    #[test]
+    fn get_resource_mut() {
+        let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+        world.insert_resource(B('a'));
+
+        world.get_resource_mut::<B>().unwrap().0 = 'b';
+
+        assert_eq!(world.get_resource::<B>(), Some(&B('b')));
+    }
+
+    #[test]
+    fn try_view_resources() {
+        let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+        world.insert_resource(A(42));
+        world.insert_resource(B('a'));
+
+        let result!(b, a) = world
+            .try_view_resources::<Views!(&mut B, &mut A)>()
+            .unwrap();
+        a.0 = 100;
+        b.0 = 'b';
+
+        assert_eq!(world.get_resource::<A>(), Some(&A(100)));
+        assert_eq!(world.get_resource::<B>(), Some(&B('b')));
+    }
+
+    #[test]
+    fn try_view_resources_not_present() {
+        let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+        world.insert_resource(A(42));
+
+        assert_none!(world.try_view_resources::<Views!(&A, &B)>());
+    }
+
+    #[test]
+    fn try_view_resources_not_disjoint() {
+        let mut world = World::<Registry!(), _>::with_resources(resource::Dynamic::new());
+        world.insert_resource(A(42));
+        world.insert_resource(B('a'));
+
+        assert_none!(world.try_view_resources::<Views!(&mut A, &B, &A)>());
+        assert_none!(world.try_view_resources::<Views!(&B, &mut A, &mut A)>());
+        assert_some!(world.try_view_resources::<Views!(&A, &mut B, &A)>());
+    }
+
+    #[test]
+    fn query_with_dynamic_resources() {
+        let mut world = World::<Registry, _>::with_resources(resource::Dynamic::new());
+        world.insert(entity!(A(1), B('a')));
+        world.insert_resource(A(42));
+
+        let result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(result, vec![1]);
+    }
+
     #[test]
     fn query_with_entries() {
         let mut world = World::<Registry>::new();
@@ -3409,4 +13822,293 @@ mod tests {
 
         world.run_par_system(&mut EntrySystem { entity_identifier });
     }
+
+    /// A hash builder counting the number of hashers it builds.
+    #[derive(Clone, Default)]
+    struct CountingBuildHasher(Arc<AtomicU32>);
+
+    impl BuildHasher for CountingBuildHasher {
+        type Hasher = <FnvBuildHasher as BuildHasher>::Hasher;
+
+        fn build_hasher(&self) -> Self::Hasher {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            FnvBuildHasher::default().build_hasher()
+        }
+    }
+
+    #[test]
+    fn with_hasher() {
+        let hash_builder = CountingBuildHasher::default();
+        let mut world = World::<Registry, resource::Null, _>::with_hasher(hash_builder.clone());
+
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+        world.insert(entity!(B('b')));
+
+        let mut result = world
+            .query(Query::<Views!(&A)>::new())
+            .iter
+            .map(|result!(a)| a.0)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+        assert!(hash_builder.0.load(Ordering::Relaxed) > 0);
+    }
+
+    #[test]
+    fn with_resources_and_hasher() {
+        let world = World::<Registry, _, _>::with_resources_and_hasher(
+            resources!(A(42)),
+            CountingBuildHasher::default(),
+        );
+
+        assert_eq!(world.get::<A, _>(), &A(42));
+    }
+
+    #[test]
+    fn builder_no_resources() {
+        let world: World<Registry, resource::Null> = World::builder().build();
+
+        assert!(world.is_empty());
+    }
+
+    #[test]
+    fn builder_resources_in_order() {
+        let world: World<Registry, Resources!(A, B)> =
+            World::builder().resource(A(42)).resource(B('a')).build();
+
+        assert_eq!(world.get::<A, _>(), &A(42));
+        assert_eq!(world.get::<B, _>().0, 'a');
+    }
+
+    #[test]
+    fn builder_conditional_resource() {
+        let condition = true;
+        let builder = World::<Registry>::builder().resource(A(1));
+        let b = if condition { B('a') } else { B('b') };
+        let world = builder.resource(b).build();
+
+        assert_eq!(world.get::<B, _>().0, 'a');
+    }
+
+    #[test]
+    fn builder_with_hasher() {
+        let hash_builder = CountingBuildHasher::default();
+        let mut world = World::<Registry>::builder()
+            .hasher(hash_builder.clone())
+            .resource(A(42))
+            .build();
+
+        world.insert(entity!(A(1), B('a')));
+
+        assert_eq!(world.get::<A, _>(), &A(42));
+        assert!(hash_builder.0.load(Ordering::Relaxed) > 0);
+    }
+
+    #[test]
+    fn with_hasher_default() {
+        let mut world = World::<Registry, resource::Null, CountingBuildHasher>::default();
+
+        world.insert(entity!(A(1)));
+
+        assert_eq!(world.len(), 1);
+    }
+
+    #[test]
+    fn with_hasher_entry_changes_shape() {
+        let mut world =
+            World::<Registry, resource::Null, _>::with_hasher(CountingBuildHasher::default());
+        let entity_identifier = world.insert(entity!(A(1)));
+
+        assert_some!(world.entry(entity_identifier)).add(B('a'));
+        world.remove(entity_identifier);
+
+        assert!(world.is_empty());
+    }
+
+    #[test]
+    fn with_hasher_clone_eq() {
+        let mut world =
+            World::<Registry, resource::Null, _>::with_hasher(CountingBuildHasher::default());
+        world.insert(entity!(A(1), B('a')));
+        world.insert(entity!(A(2)));
+
+        assert_eq!(world, world.clone());
+    }
+
+    #[test]
+    fn with_hasher_run_schedule() {
+        struct EntrySystem {
+            entity_identifier: entity::Identifier,
+        }
+
+        impl System for EntrySystem {
+            type Views<'a> = Views!();
+            type Filter = filter::None;
+            type ResourceViews<'a> = Views!();
+            type EntryViews<'a> = Views!(&'a A);
+
+            fn initialize<R, Resources, H>(&mut self, world: &mut World<R, Resources, H>)
+            where
+                R: registry::Registry,
+                H: BuildHasher,
+            {
+                assert_eq!(world.len(), 1);
+            }
+
+            fn run<'a, R, S, I, E>(
+                &mut self,
+                mut query_result: Result<
+                    'a,
+                    R,
+                    S,
+                    I,
+                    Self::ResourceViews<'a>,
+                    Self::EntryViews<'a>,
+                    E,
+                >,
+            ) where
+                R: registry::ContainsViews<'a, Self::EntryViews<'a>, E>,
+                I: Iterator<Item = Self::Views<'a>>,
+            {
+                let mut entry = assert_some!(query_result.entries.entry(self.entity_identifier));
+                let result!(a) = assert_some!(entry.query(Query::<Views!(&A)>::new()));
+                assert_eq!(a, &A(42));
+            }
+        }
+
+        let mut world =
+            World::<Registry, resource::Null, _>::with_hasher(CountingBuildHasher::default());
+        let entity_identifier = world.insert(entity!(A(42)));
+
+        world.run_schedule(&mut schedule!(task::System(EntrySystem {
+            entity_identifier
+        })));
+    }
+
+    type PackedRegistry = Registry!(u32, u64);
+
+    #[test]
+    fn packed_round_trip() {
+        let mut world = World::<PackedRegistry>::new();
+        world.insert(entity!(1_u32, 2_u64));
+        world.extend(entities!((3_u32); 10));
+        world.insert(entity!(4_u64));
+        world.insert(entity!());
+
+        let mut bytes = Vec::new();
+        assert_ok!(world.write_packed(&mut bytes));
+        let read_world = assert_ok!(World::<PackedRegistry>::read_packed(&mut bytes.as_slice()));
+
+        assert_eq!(read_world, world);
+    }
+
+    #[test]
+    fn packed_round_trip_empty() {
+        let world = World::<PackedRegistry>::new();
+
+        let mut bytes = Vec::new();
+        assert_ok!(world.write_packed(&mut bytes));
+        let read_world = assert_ok!(World::<PackedRegistry>::read_packed(&mut bytes.as_slice()));
+
+        assert_eq!(read_world, world);
+    }
+
+    #[test]
+    fn packed_preserves_removed_entities() {
+        let mut world = World::<PackedRegistry>::new();
+        let entity_identifier = world.insert(entity!(1_u32));
+        world.insert(entity!(2_u32));
+        world.remove(entity_identifier);
+
+        let mut bytes = Vec::new();
+        assert_ok!(world.write_packed(&mut bytes));
+        let mut read_world =
+            assert_ok!(World::<PackedRegistry>::read_packed(&mut bytes.as_slice()));
+
+        assert!(!read_world.contains(entity_identifier));
+        assert_eq!(read_world.insert(entity!()), world.insert(entity!()));
+    }
+
+    #[test]
+    fn packed_with_resources() {
+        let mut world = World::<PackedRegistry>::new();
+        world.insert(entity!(1_u32));
+
+        let mut bytes = Vec::new();
+        assert_ok!(world.write_packed(&mut bytes));
+        let read_world = assert_ok!(World::<PackedRegistry, _>::read_packed_with_resources(
+            &mut bytes.as_slice(),
+            resources!(A(42))
+        ));
+
+        assert_eq!(read_world.len(), 1);
+        assert_eq!(read_world.get::<A, _>(), &A(42));
+    }
+
+    #[test]
+    fn packed_registry_mismatch() {
+        let mut world = World::<Registry!(u32)>::new();
+        world.insert(entity!(1_u32));
+
+        let mut bytes = Vec::new();
+        assert_ok!(world.write_packed(&mut bytes));
+
+        assert_err_eq!(
+            World::<PackedRegistry>::read_packed(&mut bytes.as_slice()),
+            packed::Error::RegistryMismatch
+        );
+    }
+
+    #[test]
+    fn packed_unexpected_end() {
+        let mut world = World::<PackedRegistry>::new();
+        world.insert(entity!(1_u32, 2_u64));
+
+        let mut bytes = Vec::new();
+        assert_ok!(world.write_packed(&mut bytes));
+        bytes.pop();
+
+        assert_err_eq!(
+            World::<PackedRegistry>::read_packed(&mut bytes.as_slice()),
+            packed::Error::Read(packed::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn packed_invalid_archetype_identifier() {
+        let mut bytes = Vec::new();
+        // Registry length, slots, free entities, and archetypes.
+        for value in [2_u64, 0, 0, 1] {
+            bytes.extend(value.to_le_bytes());
+        }
+        // An archetype identifier with a bit set beyond the registry's components.
+        bytes.push(0b100);
+        bytes.extend(0_u64.to_le_bytes());
+
+        assert_err_eq!(
+            World::<PackedRegistry>::read_packed(&mut bytes.as_slice()),
+            packed::Error::Invalid
+        );
+    }
+
+    #[test]
+    fn packed_invalid_entity_allocator() {
+        let mut bytes = Vec::new();
+        // Registry length, slots, free entities, and archetypes.
+        for value in [2_u64, 0, 0, 1] {
+            bytes.extend(value.to_le_bytes());
+        }
+        // An archetype containing an entity that has no slot.
+        bytes.push(0b01);
+        for value in [1_u64, 0, 0] {
+            bytes.extend(value.to_le_bytes());
+        }
+        bytes.extend(1_u32.to_le_bytes());
+
+        assert_err_eq!(
+            World::<PackedRegistry>::read_packed(&mut bytes.as_slice()),
+            packed::Error::Invalid
+        );
+    }
 }
@@ -0,0 +1,321 @@
+use crate::{
+    archetype::Archetype,
+    component,
+    registry,
+    registry::ContainsComponent,
+    world::Callbacks,
+};
+use alloc::vec::Vec;
+use core::{
+    marker::PhantomData,
+    mem::{
+        drop,
+        size_of,
+    },
+};
+
+/// A set of structural changes to be applied to a single entity at once.
+///
+/// Components added and removed through a `Modifier` are not applied immediately. Instead, once
+/// all changes have been made, the entity is moved directly to the storage for its final set of
+/// components, rather than being moved once for every component added or removed.
+///
+/// This struct is provided to the closure passed to [`Entry::modify()`].
+///
+/// # Example
+/// ``` rust
+/// use brood::{
+///     entity,
+///     Registry,
+///     World,
+/// };
+///
+/// struct Foo(u32);
+/// struct Bar(bool);
+/// struct Baz(f64);
+///
+/// type Registry = Registry!(Foo, Bar, Baz);
+///
+/// let mut world = World::<Registry>::new();
+/// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+/// let mut entry = world.entry(entity_identifier).unwrap();
+///
+/// entry.modify(|modifier| {
+///     modifier.remove::<Bar, _>();
+///     modifier.add(Baz(1.5));
+/// });
+/// ```
+///
+/// [`Entry::modify()`]: crate::world::Entry::modify()
+pub struct Modifier<Registry>
+where
+    Registry: registry::Registry,
+{
+    /// The raw identifier of the archetype the entity will be moved to.
+    pub(crate) identifier: Vec<u8>,
+    pub(crate) additions: Vec<Addition<Registry>>,
+    pub(crate) removals: Vec<Removal<Registry>>,
+
+    /// The added components are owned by the `Modifier`, so it is only `Send` or `Sync` if the
+    /// components of the `Registry` are.
+    registry: PhantomData<Registry>,
+}
+
+/// A component added through a [`Modifier`].
+pub(crate) struct Addition<R>
+where
+    R: registry::Registry,
+{
+    /// The index of the component's bit within an archetype identifier.
+    pub(crate) component_index: usize,
+    /// The component, stored as unaligned bytes.
+    pub(crate) bytes: Vec<u8>,
+    /// Drops the component stored in `bytes`.
+    drop: unsafe fn(*mut u8),
+    /// Moves the component stored in `bytes` into an existing row of an archetype.
+    pub(crate) set: unsafe fn(&mut Archetype<R>, usize, *const u8),
+    /// Notifies the callbacks of the component stored in a row of an archetype.
+    pub(crate) notify: unsafe fn(&mut Archetype<R>, usize, &mut Callbacks),
+}
+
+/// A component removed through a [`Modifier`].
+pub(crate) struct Removal<R>
+where
+    R: registry::Registry,
+{
+    /// The index of the component's bit within an archetype identifier.
+    pub(crate) component_index: usize,
+    /// Notifies the callbacks of the component stored in a row of an archetype.
+    pub(crate) notify: unsafe fn(&mut Archetype<R>, usize, &mut Callbacks),
+}
+
+/// # Safety
+/// `bytes` must point to a valid, properly initialized `C`, which may be unaligned. The `C` must
+/// not be read again after this call.
+unsafe fn drop_component<C>(bytes: *mut u8) {
+    // SAFETY: `bytes` is guaranteed by the safety contract of this function to point to a valid,
+    // possibly unaligned `C`.
+    drop(unsafe { bytes.cast::<C>().read_unaligned() });
+}
+
+/// # Safety
+/// `C` must be contained within `archetype`, and `index` must be a valid index within
+/// `archetype`. `bytes` must point to a valid, properly initialized `C`, which may be unaligned.
+/// The `C` must not be read again after this call.
+unsafe fn set_component<R, C, I>(archetype: &mut Archetype<R>, index: usize, bytes: *const u8)
+where
+    R: ContainsComponent<C, I> + registry::Registry,
+    C: component::Component,
+{
+    // SAFETY: `C` is guaranteed to be contained within `archetype`, `index` is guaranteed to be a
+    // valid index, and `bytes` is guaranteed to point to a valid, possibly unaligned `C`.
+    unsafe {
+        archetype.set_component_unchecked::<C, I>(index, bytes.cast::<C>().read_unaligned());
+    }
+}
+
+/// # Safety
+/// `C` must be contained within `archetype`, and `index` must be a valid index within
+/// `archetype`.
+unsafe fn notify_component<R, C, I>(
+    archetype: &mut Archetype<R>,
+    index: usize,
+    callbacks: &mut Callbacks,
+) where
+    R: ContainsComponent<C, I> + registry::Registry,
+    C: component::Component,
+{
+    // SAFETY: `C` is guaranteed to be contained within `archetype`, and `index` is guaranteed to
+    // be a valid index.
+    unsafe { archetype.notify_component_unchecked::<C, I>(index, callbacks) }
+}
+
+impl<Registry> Modifier<Registry>
+where
+    Registry: registry::Registry,
+{
+    pub(crate) fn new(identifier: Vec<u8>) -> Self {
+        Self {
+            identifier,
+            additions: Vec::new(),
+            removals: Vec::new(),
+
+            registry: PhantomData,
+        }
+    }
+
+    /// Recomputes the target identifier, given the `identifier` of the entity's current
+    /// components.
+    ///
+    /// This is required when the entity may have changed since the `Modifier` was created.
+    pub(crate) fn rebase(&mut self, identifier: Vec<u8>) {
+        self.identifier = identifier;
+        for removal in &self.removals {
+            // SAFETY: `removal.component_index` was obtained from the registry, and is therefore a
+            // valid index to a bit in `self.identifier`.
+            *unsafe {
+                self.identifier
+                    .get_unchecked_mut(removal.component_index / 8)
+            } &= !(1 << (removal.component_index % 8));
+        }
+        // Components that were removed and then added again are contained in both lists, so
+        // additions must be applied last.
+        for addition in &self.additions {
+            // SAFETY: `addition.component_index` was obtained from the registry, and is therefore
+            // a valid index to a bit in `self.identifier`.
+            *unsafe {
+                self.identifier
+                    .get_unchecked_mut(addition.component_index / 8)
+            } |= 1 << (addition.component_index % 8);
+        }
+    }
+
+    /// Returns whether the bit at `component_index` is set in the target identifier.
+    pub(crate) fn contains_index(&self, component_index: usize) -> bool {
+        self.identifier
+            .get(component_index / 8)
+            .map_or(false, |byte| byte & (1 << (component_index % 8)) != 0)
+    }
+
+    /// Add a component to the entity.
+    ///
+    /// If the component already exists, it is updated to the new value. If the component was
+    /// previously added through this `Modifier`, the previously added value is dropped.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42)));
+    /// let mut entry = world.entry(entity_identifier).unwrap();
+    ///
+    /// entry.modify(|modifier| modifier.add(Bar(true)));
+    /// ```
+    pub fn add<Component, Index>(&mut self, component: Component)
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        let component_index = Registry::LEN - Registry::INDEX - 1;
+
+        let mut bytes = Vec::<u8>::with_capacity(size_of::<Component>());
+        // SAFETY: `bytes` has been allocated with enough capacity to hold a `Component`, and the
+        // write is unaligned.
+        unsafe {
+            bytes
+                .as_mut_ptr()
+                .cast::<Component>()
+                .write_unaligned(component);
+        }
+        // SAFETY: The `Component` was just written to the allocation, which has a capacity of
+        // `size_of::<Component>()`.
+        unsafe { bytes.set_len(size_of::<Component>()) };
+        let addition = Addition {
+            component_index,
+            bytes,
+            drop: drop_component::<Component>,
+            set: set_component::<Registry, Component, Index>,
+            notify: notify_component::<Registry, Component, Index>,
+        };
+
+        if let Some(existing) = self
+            .additions
+            .iter_mut()
+            .find(|addition| addition.component_index == component_index)
+        {
+            // SAFETY: The bytes of an addition always contain a valid component of the type its
+            // `drop` function was created for, and the component is replaced immediately after.
+            unsafe { (existing.drop)(existing.bytes.as_mut_ptr()) };
+            *existing = addition;
+        } else {
+            self.additions.push(addition);
+        }
+
+        // SAFETY: `component_index` is guaranteed to be a valid index to a bit in
+        // `self.identifier`, since the identifier was created for `Registry`.
+        *unsafe { self.identifier.get_unchecked_mut(component_index / 8) } |=
+            1 << (component_index % 8);
+    }
+
+    /// Remove a component from the entity.
+    ///
+    /// If the component is not present within the entity, nothing happens. If the component was
+    /// previously added through this `Modifier`, the added value is dropped.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Foo(u32);
+    /// struct Bar(bool);
+    ///
+    /// type Registry = Registry!(Foo, Bar);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// let entity_identifier = world.insert(entity!(Foo(42), Bar(true)));
+    /// let mut entry = world.entry(entity_identifier).unwrap();
+    ///
+    /// entry.modify(|modifier| modifier.remove::<Bar, _>());
+    /// ```
+    pub fn remove<Component, Index>(&mut self)
+    where
+        Component: component::Component,
+        Registry: ContainsComponent<Component, Index>,
+    {
+        let component_index = Registry::LEN - Registry::INDEX - 1;
+
+        if let Some(position) = self
+            .additions
+            .iter()
+            .position(|addition| addition.component_index == component_index)
+        {
+            let mut addition = self.additions.swap_remove(position);
+            // SAFETY: The bytes of an addition always contain a valid component of the type its
+            // `drop` function was created for, and the addition is discarded afterward.
+            unsafe { (addition.drop)(addition.bytes.as_mut_ptr()) };
+        }
+
+        if !self
+            .removals
+            .iter()
+            .any(|removal| removal.component_index == component_index)
+        {
+            self.removals.push(Removal {
+                component_index,
+                notify: notify_component::<Registry, Component, Index>,
+            });
+        }
+
+        // SAFETY: `component_index` is guaranteed to be a valid index to a bit in
+        // `self.identifier`, since the identifier was created for `Registry`.
+        *unsafe { self.identifier.get_unchecked_mut(component_index / 8) } &=
+            !(1 << (component_index % 8));
+    }
+}
+
+impl<Registry> Drop for Modifier<Registry>
+where
+    Registry: registry::Registry,
+{
+    fn drop(&mut self) {
+        for addition in &mut self.additions {
+            // SAFETY: The bytes of an addition always contain a valid component of the type its
+            // `drop` function was created for, and the additions are not used again.
+            unsafe { (addition.drop)(addition.bytes.as_mut_ptr()) };
+        }
+    }
+}
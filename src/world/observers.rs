@@ -0,0 +1,135 @@
+//! Callbacks observing components being added to or removed from entities, and entities being
+//! moved within archetypes.
+//!
+//! Component callbacks are registered per component type through [`World::on_add()`] and
+//! [`World::on_remove()`]. Since the component types are not known to the registry ahead of time,
+//! the callbacks for each component are stored type-erased and keyed by the component's
+//! [`TypeId`]. Move callbacks are registered through [`World::on_move()`].
+//!
+//! [`World::on_add()`]: crate::World::on_add()
+//! [`World::on_move()`]: crate::World::on_move()
+//! [`World::on_remove()`]: crate::World::on_remove()
+
+use crate::{
+    component::Component,
+    entity,
+    query::view::ArchetypeId,
+};
+use alloc::{
+    boxed::Box,
+    vec::Vec,
+};
+use core::any::{
+    Any,
+    TypeId,
+};
+use fnv::FnvBuildHasher;
+use hashbrown::HashMap;
+
+/// A single callback observing a component of type `C`.
+type Callback<C> = Box<dyn FnMut(entity::Identifier, &C) + Send + Sync>;
+
+/// Callbacks observing a single kind of event, stored by component type.
+///
+/// Each value is a `Vec<Callback<C>>`, where `C` is the component type identified by the key.
+#[derive(Default)]
+pub struct Callbacks {
+    callbacks: HashMap<TypeId, Box<dyn Any + Send + Sync>, FnvBuildHasher>,
+}
+
+impl Callbacks {
+    /// Registers a new callback for the component `C`.
+    pub(crate) fn push<C, F>(&mut self, callback: F)
+    where
+        C: Component,
+        F: FnMut(entity::Identifier, &C) + Send + Sync + 'static,
+    {
+        let callbacks = self
+            .callbacks
+            .entry(TypeId::of::<C>())
+            .or_insert_with(|| Box::new(Vec::<Callback<C>>::new()));
+        // SAFETY: Callbacks are always stored under the `TypeId` of the component they observe.
+        unsafe {
+            callbacks
+                .downcast_mut::<Vec<Callback<C>>>()
+                .unwrap_unchecked()
+        }
+        .push(Box::new(callback));
+    }
+
+    /// Returns the callbacks registered for the component `C`, if there are any.
+    pub(crate) fn get_mut<C>(&mut self) -> Option<&mut Vec<Callback<C>>>
+    where
+        C: Component,
+    {
+        self.callbacks.get_mut(&TypeId::of::<C>()).map(|callbacks| {
+            // SAFETY: Callbacks are always stored under the `TypeId` of the component they
+            // observe.
+            unsafe {
+                callbacks
+                    .downcast_mut::<Vec<Callback<C>>>()
+                    .unwrap_unchecked()
+            }
+        })
+    }
+
+    /// Calls every callback registered for the component `C`.
+    pub(crate) fn notify<C>(&mut self, entity_identifier: entity::Identifier, component: &C)
+    where
+        C: Component,
+    {
+        if let Some(callbacks) = self.get_mut::<C>() {
+            for callback in callbacks {
+                callback(entity_identifier, component);
+            }
+        }
+    }
+
+    /// Returns whether no callbacks have been registered.
+    ///
+    /// This allows skipping the walk over an archetype's components entirely in the common case.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.callbacks.is_empty()
+    }
+}
+
+/// A single callback observing an entity being moved within an archetype.
+type MoveCallback = Box<dyn FnMut(entity::Identifier, usize, usize, ArchetypeId) + Send + Sync>;
+
+/// Callbacks observing entities being moved to a new row within their archetype.
+#[derive(Default)]
+pub struct MoveCallbacks {
+    callbacks: Vec<MoveCallback>,
+}
+
+impl MoveCallbacks {
+    /// Registers a new callback.
+    pub(crate) fn push<F>(&mut self, callback: F)
+    where
+        F: FnMut(entity::Identifier, usize, usize, ArchetypeId) + Send + Sync + 'static,
+    {
+        self.callbacks.push(Box::new(callback));
+    }
+
+    /// Calls every registered callback for the entity moved from row `old_index` to row
+    /// `new_index` of the archetype identified by `archetype_id`.
+    pub(crate) fn notify(
+        &mut self,
+        entity_identifier: entity::Identifier,
+        old_index: usize,
+        new_index: usize,
+        archetype_id: ArchetypeId,
+    ) {
+        for callback in &mut self.callbacks {
+            callback(entity_identifier, old_index, new_index, archetype_id);
+        }
+    }
+}
+
+/// All observers registered on a `World`.
+#[derive(Default)]
+pub(crate) struct Observers {
+    pub(crate) on_add: Callbacks,
+    pub(crate) on_remove: Callbacks,
+    pub(crate) moves: MoveCallbacks,
+}
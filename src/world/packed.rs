@@ -0,0 +1,221 @@
+use crate::{
+    archetype::Archetype,
+    archetypes::Archetypes,
+    entity,
+    packed,
+    packed::{
+        read_u64,
+        read_usize,
+        write_u64,
+        Read,
+        Write,
+    },
+    registry,
+    resource,
+    World,
+};
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+
+impl<Registry, S> World<Registry, resource::Null, S>
+where
+    Registry: registry::Packed,
+    S: BuildHasher + Clone + Default,
+{
+    /// Reads a `World` from the [packed] layout, as written by [`write_packed()`].
+    ///
+    /// The `World` must have been written using the same [`Registry`], on a target with the same
+    /// memory layout for each of its components. Resources are not included in the packed layout;
+    /// to read a `World` containing resources, use [`read_packed_with_resources()`] instead.
+    ///
+    /// # Errors
+    /// Returns an error if `reader` fails, or if the input is not a valid packed `World` for this
+    /// `Registry`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// type Registry = Registry!(u32, f64);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(42_u32, 1.5_f64));
+    ///
+    /// let mut bytes = Vec::new();
+    /// world.write_packed(&mut bytes).unwrap();
+    ///
+    /// let read_world = World::<Registry>::read_packed(&mut bytes.as_slice()).unwrap();
+    /// assert_eq!(read_world, world);
+    /// ```
+    ///
+    /// [packed]: crate::packed
+    /// [`read_packed_with_resources()`]: World::read_packed_with_resources()
+    /// [`Registry`]: crate::registry::Registry
+    /// [`write_packed()`]: World::write_packed()
+    pub fn read_packed<R>(reader: &mut R) -> Result<Self, packed::Error<R::Error>>
+    where
+        R: Read + ?Sized,
+    {
+        Self::read_packed_with_resources(reader, resource::Null)
+    }
+}
+
+impl<Registry, Resources, S> World<Registry, Resources, S>
+where
+    Registry: registry::Packed,
+    S: BuildHasher + Clone + Default,
+{
+    /// Reads a `World` from the [packed] layout, as written by [`write_packed()`], containing the
+    /// given resources.
+    ///
+    /// The `World` must have been written using the same [`Registry`], on a target with the same
+    /// memory layout for each of its components.
+    ///
+    /// # Errors
+    /// Returns an error if `reader` fails, or if the input is not a valid packed `World` for this
+    /// `Registry`.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     resources,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// struct Time(u64);
+    ///
+    /// type Registry = Registry!(u32);
+    ///
+    /// let mut world = World::<Registry>::new();
+    /// world.insert(entity!(42_u32));
+    ///
+    /// let mut bytes = Vec::new();
+    /// world.write_packed(&mut bytes).unwrap();
+    ///
+    /// let read_world = World::<Registry, _>::read_packed_with_resources(
+    ///     &mut bytes.as_slice(),
+    ///     resources!(Time(0)),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(read_world.len(), 1);
+    /// ```
+    ///
+    /// [packed]: crate::packed
+    /// [`Registry`]: crate::registry::Registry
+    /// [`write_packed()`]: World::write_packed()
+    pub fn read_packed_with_resources<R>(
+        reader: &mut R,
+        resources: Resources,
+    ) -> Result<Self, packed::Error<R::Error>>
+    where
+        R: Read + ?Sized,
+    {
+        if read_usize(reader)? != Registry::LEN {
+            return Err(packed::Error::RegistryMismatch);
+        }
+
+        let slots = read_usize(reader)?;
+        let free_len = read_usize(reader)?;
+        // The identifiers are pushed as they are read, rather than allocated up front, so that a
+        // corrupted length can't cause a large allocation.
+        let mut free = Vec::new();
+        for _ in 0..free_len {
+            let index = read_usize(reader)?;
+            let generation = read_u64(reader).map_err(packed::Error::Read)?;
+            free.push(entity::Identifier::new(index, generation));
+        }
+
+        let archetypes_len = read_usize(reader)?;
+        let mut archetypes = Archetypes::with_hasher(S::default());
+        let mut len = 0;
+        for _ in 0..archetypes_len {
+            let archetype = Archetype::read_packed(reader)?;
+            len += archetype.len();
+            archetypes
+                .insert(archetype)
+                .map_err(|_| packed::Error::Invalid)?;
+        }
+
+        let entity_allocator = entity::Allocator::from_parts(slots, free, &archetypes)
+            .ok_or(packed::Error::Invalid)?;
+
+        Ok(Self::from_raw_parts(
+            archetypes,
+            entity_allocator,
+            len,
+            resources,
+        ))
+    }
+}
+
+impl<Registry, Resources, S> World<Registry, Resources, S>
+where
+    Registry: registry::Packed,
+    S: BuildHasher,
+{
+    /// Writes the entities of this `World` to `writer` in the [packed] layout.
+    ///
+    /// The raw bytes of each component column are written directly, which is much faster than
+    /// serializing each component individually. The written `World` can be read using
+    /// [`read_packed()`]. Resources are not written.
+    ///
+    /// # Errors
+    /// Returns an error if `writer` fails.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use brood::{
+    ///     entity,
+    ///     Registry,
+    ///     World,
+    /// };
+    ///
+    /// let mut world = World::<Registry!(u32)>::new();
+    /// world.insert(entity!(42_u32));
+    ///
+    /// let mut bytes = Vec::new();
+    /// world.write_packed(&mut bytes).unwrap();
+    /// ```
+    ///
+    /// [packed]: crate::packed
+    /// [`read_packed()`]: World::read_packed()
+    pub fn write_packed<W>(&self, writer: &mut W) -> Result<(), W::Error>
+    where
+        W: Write + ?Sized,
+    {
+        write_u64(writer, Registry::LEN as u64)?;
+
+        write_u64(writer, self.entity_allocator.slots.len() as u64)?;
+        write_u64(writer, self.entity_allocator.free.len() as u64)?;
+        for &index in &self.entity_allocator.free {
+            write_u64(writer, index as u64)?;
+            write_u64(
+                writer,
+                // SAFETY: `index` is invariantly guaranteed to be a valid index into `slots`.
+                unsafe { self.entity_allocator.slots.get_unchecked(index) }.generation,
+            )?;
+        }
+
+        // Empty archetypes are not written, since they do not contain any entities.
+        write_u64(
+            writer,
+            self.archetypes
+                .iter()
+                .filter(|archetype| !archetype.is_empty())
+                .count() as u64,
+        )?;
+        for archetype in self.archetypes.iter() {
+            if !archetype.is_empty() {
+                archetype.write_packed(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use core::fmt;
+
+/// An error returned by [`World::insert_at()`].
+///
+/// This indicates that an entity could not be stored at the requested [`entity::Identifier`].
+///
+/// [`entity::Identifier`]: crate::entity::Identifier
+/// [`World::insert_at()`]: crate::World::insert_at()
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SpawnError {
+    /// The identifier's index is already in use by a live entity.
+    Occupied,
+    /// The identifier's generation is not newer than a generation previously used at the same
+    /// index.
+    ///
+    /// Reusing an older generation could cause stale identifiers to refer to the new entity.
+    GenerationRegression,
+}
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Occupied => f.write_str("entity index is already occupied by a live entity"),
+            Self::GenerationRegression => {
+                f.write_str("entity generation is not newer than a previously used generation")
+            }
+        }
+    }
+}